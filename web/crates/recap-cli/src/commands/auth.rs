@@ -0,0 +1,53 @@
+//! Auth commands
+//!
+//! Token maintenance for scripts that call the desktop app with a saved
+//! login. Access tokens expire after 7 days; `auth refresh` trades the
+//! 30-day refresh token from login for a new one.
+
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use crate::output::{print_success, write_csv, OutputFormat};
+use super::Context;
+
+#[derive(Subcommand)]
+pub enum AuthAction {
+    /// Exchange a refresh token for a new access token
+    Refresh {
+        /// Refresh token returned by login
+        refresh_token: String,
+    },
+}
+
+/// New access token (JSON/CSV output)
+#[derive(Debug, Serialize)]
+pub struct RefreshResult {
+    pub access_token: String,
+    pub token_type: String,
+}
+
+pub async fn execute(ctx: &Context, action: AuthAction) -> Result<()> {
+    match action {
+        AuthAction::Refresh { refresh_token } => refresh(ctx, &refresh_token),
+    }
+}
+
+fn refresh(ctx: &Context, refresh_token: &str) -> Result<()> {
+    let access_token = recap_core::auth::refresh_access_token(refresh_token)
+        .map_err(|e| anyhow::anyhow!("Invalid refresh token: {}", e))?;
+
+    let result = RefreshResult {
+        access_token,
+        token_type: "bearer".to_string(),
+    };
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&result)?),
+        OutputFormat::Csv => write_csv(std::slice::from_ref(&result), std::io::stdout().lock())?,
+        OutputFormat::Table => {
+            print_success("Issued new access token", ctx.quiet);
+            println!("{}", result.access_token);
+        }
+    }
+    Ok(())
+}
@@ -1,20 +1,32 @@
 //! Claude session commands
 //!
-//! Commands for listing and viewing Claude Code sessions.
+//! Commands for listing, viewing, and summarizing Claude Code sessions.
+//! `list --unimported` shows sessions that sync did not turn into work items.
 
 use anyhow::Result;
 use chrono::{DateTime, NaiveDate};
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tabled::Tabled;
 
-use recap_core::{parse_session_fast, parse_session_full, ParsedSession};
+use recap_core::services::llm::LlmService;
+use recap_core::services::{
+    build_rule_based_session_summary, create_llm_service, extract_session_content, parse_error_usage,
+    save_usage_log,
+};
+use recap_core::services::ToolCall;
+use recap_core::{parse_session_fast, parse_session_full, ParsedSession, SessionMetadata};
 
-use crate::output::{print_output, print_info};
+use crate::output::{csv_not_supported, print_output, print_info};
+use super::work::helpers::{claude_projects_dirs, get_or_create_default_user, parse_user_date, user_today};
 use super::Context;
 
+/// Sessions summarized by the LLM at the same time
+const SUMMARIZE_CONCURRENCY: usize = 4;
+
 #[derive(Subcommand)]
 pub enum ClaudeAction {
     /// List all Claude sessions
@@ -26,15 +38,61 @@ pub enum ClaudeAction {
         /// Filter by date (YYYY-MM-DD)
         #[arg(long, short)]
         date: Option<String>,
+
+        /// Sort field
+        #[arg(long, value_enum, default_value = "date")]
+        sort: SessionSort,
+
+        /// Sort in descending order (default)
+        #[arg(long, conflicts_with = "asc")]
+        desc: bool,
+
+        /// Sort in ascending order
+        #[arg(long)]
+        asc: bool,
+
+        /// Only sessions without a work item, with the likely reason
+        #[arg(long)]
+        unimported: bool,
     },
 
     /// Show session details
     Show {
         /// Session ID (UUID from filename)
         session_id: String,
+
+        /// List every tool call in order, with its time and key input
+        #[arg(long)]
+        timeline: bool,
+    },
+
+    /// Summarize all sessions of a day, grouped by project
+    Summarize {
+        /// Summarize today's sessions
+        #[arg(long, required_unless_present = "date")]
+        all_today: bool,
+
+        /// Summarize sessions on this date (YYYY-MM-DD, yesterday)
+        #[arg(long, short, conflicts_with = "all_today")]
+        date: Option<String>,
+
+        /// Use rule-based summaries instead of the LLM
+        #[arg(long)]
+        offline: bool,
     },
 }
 
+/// Sort field for session listing
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum SessionSort {
+    /// Session length (first to last message)
+    Duration,
+    /// Number of messages
+    Messages,
+    /// Session start date
+    Date,
+}
+
 /// Session row for table display
 #[derive(Debug, Serialize, Tabled)]
 pub struct SessionRow {
@@ -50,6 +108,33 @@ pub struct SessionRow {
     pub messages: String,
     #[tabled(rename = "First Message")]
     pub first_message: String,
+    /// Full session start timestamp (sort key)
+    #[tabled(skip)]
+    #[serde(skip)]
+    pub start_ts: String,
+    /// Duration in minutes (sort key)
+    #[tabled(skip)]
+    #[serde(skip)]
+    pub duration_minutes: i64,
+    /// Message count (sort key)
+    #[tabled(skip)]
+    #[serde(skip)]
+    pub message_count: usize,
+}
+
+/// Session without a work item, for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct UnimportedSessionRow {
+    #[tabled(rename = "Session ID")]
+    pub session_id: String,
+    #[tabled(rename = "Project")]
+    pub project: String,
+    #[tabled(rename = "Date")]
+    pub date: String,
+    #[tabled(rename = "Messages")]
+    pub messages: usize,
+    #[tabled(rename = "Reason")]
+    pub reason: String,
 }
 
 /// Session detail for JSON output
@@ -64,7 +149,58 @@ pub struct SessionDetail {
     pub message_count: usize,
     pub first_message: Option<String>,
     pub tool_usage: Vec<ToolUsageRow>,
+    /// Tool calls in order (only with `--timeline`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tool_timeline: Vec<ToolCallRow>,
     pub files_modified: Vec<String>,
+    /// The `cwd` moved between projects, so `project` covers only part of the session
+    pub multi_project: bool,
+    /// Project directories the session worked in
+    pub project_paths: Vec<String>,
+}
+
+/// Session summary row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct SessionSummaryRow {
+    #[tabled(rename = "Session ID")]
+    pub session_id: String,
+    #[tabled(rename = "Time")]
+    pub time: String,
+    #[tabled(rename = "Summary")]
+    pub summary: String,
+    /// `llm` or `rule`
+    #[tabled(rename = "Method")]
+    pub method: String,
+}
+
+/// Session summaries of one project, for JSON output
+#[derive(Debug, Serialize)]
+pub struct ProjectSessionSummaries {
+    pub project: String,
+    pub sessions: Vec<SessionSummaryRow>,
+}
+
+/// One tool call of `claude show --timeline`
+#[derive(Debug, Serialize)]
+pub struct ToolCallRow {
+    /// `HH:MM:SS` in the session's timestamp offset, `-` if unknown
+    pub time: String,
+    pub tool: String,
+    pub detail: Option<String>,
+}
+
+impl From<&ToolCall> for ToolCallRow {
+    fn from(call: &ToolCall) -> Self {
+        let time = call.timestamp.as_deref()
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.format("%H:%M:%S").to_string())
+            .unwrap_or_else(|| "-".to_string());
+        Self {
+            time,
+            tool: call.tool_name.clone(),
+            detail: call.detail.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Tabled)]
@@ -77,17 +213,26 @@ pub struct ToolUsageRow {
 
 pub async fn execute(ctx: &Context, action: ClaudeAction) -> Result<()> {
     match action {
-        ClaudeAction::List { project, date } => list_sessions(ctx, project, date).await,
-        ClaudeAction::Show { session_id } => show_session(ctx, session_id).await,
+        ClaudeAction::List { project, date, sort, desc: _, asc, unimported } => {
+            list_sessions(ctx, project, date, sort, asc, unimported).await
+        }
+        ClaudeAction::Show { session_id, timeline } => show_session(ctx, session_id, timeline).await,
+        ClaudeAction::Summarize { all_today: _, date, offline } => {
+            summarize_day(ctx, date, offline).await
+        }
     }
 }
 
-async fn list_sessions(ctx: &Context, project_filter: Option<String>, date_filter: Option<String>) -> Result<()> {
-    let claude_home = get_claude_home()
-        .ok_or_else(|| anyhow::anyhow!("Claude home directory not found. Expected at ~/.claude"))?;
-
-    let projects_dir = claude_home.join("projects");
-    if !projects_dir.exists() {
+async fn list_sessions(
+    ctx: &Context,
+    project_filter: Option<String>,
+    date_filter: Option<String>,
+    sort: SessionSort,
+    ascending: bool,
+    unimported: bool,
+) -> Result<()> {
+    let projects_dirs = claude_projects_dirs(ctx).await;
+    if projects_dirs.is_empty() {
         print_info("No Claude projects directory found.", ctx.quiet);
         return Ok(());
     }
@@ -100,10 +245,30 @@ async fn list_sessions(ctx: &Context, project_filter: Option<String>, date_filte
         None
     };
 
+    if unimported {
+        let imported = imported_session_ids(&ctx.db.pool).await?;
+        let mut rows: Vec<UnimportedSessionRow> = projects_dirs
+            .iter()
+            .flat_map(|dir| unimported_sessions(dir, &imported))
+            .collect();
+        rows.retain(|row| matches_list_filters(&row.project, &row.date, project_filter.as_deref(), filter_date));
+        rows.sort_by(|a, b| {
+            let ordering = a.date.cmp(&b.date).then_with(|| a.session_id.cmp(&b.session_id));
+            if ascending { ordering } else { ordering.reverse() }
+        });
+
+        if rows.is_empty() {
+            print_info("Every session has a work item.", ctx.quiet);
+        } else {
+            print_output(&rows, ctx.format)?;
+        }
+        return Ok(());
+    }
+
     let mut rows: Vec<SessionRow> = Vec::new();
 
-    // Iterate through project directories
-    let entries = fs::read_dir(&projects_dir)?;
+    // Iterate through project directories under every root
+    let entries = projects_dirs.iter().filter_map(|dir| fs::read_dir(dir).ok()).flatten();
     for entry in entries.flatten() {
         let path = entry.path();
         if !path.is_dir() {
@@ -125,35 +290,16 @@ async fn list_sessions(ctx: &Context, project_filter: Option<String>, date_filte
                 let file_path = file_entry.path();
                 if file_path.extension().map(|e| e == "jsonl").unwrap_or(false) {
                     if let Some(session) = parse_session_for_list(&file_path) {
-                        // Apply project filter
-                        if let Some(ref filter) = project_filter {
-                            let project_lower = session.project.to_lowercase();
-                            let filter_lower = filter.to_lowercase();
-                            if !project_lower.contains(&filter_lower) {
-                                continue;
-                            }
+                        if matches_list_filters(&session.project, &session.date, project_filter.as_deref(), filter_date) {
+                            rows.push(session);
                         }
-
-                        // Apply date filter
-                        if let Some(filter_date) = filter_date {
-                            if let Ok(session_date) = NaiveDate::parse_from_str(&session.date, "%Y-%m-%d") {
-                                if session_date != filter_date {
-                                    continue;
-                                }
-                            } else {
-                                continue;
-                            }
-                        }
-
-                        rows.push(session);
                     }
                 }
             }
         }
     }
 
-    // Sort by date descending
-    rows.sort_by(|a, b| b.date.cmp(&a.date));
+    sort_sessions(&mut rows, sort, ascending);
 
     if rows.is_empty() {
         print_info("No sessions found matching the criteria.", ctx.quiet);
@@ -164,17 +310,17 @@ async fn list_sessions(ctx: &Context, project_filter: Option<String>, date_filte
     Ok(())
 }
 
-async fn show_session(ctx: &Context, session_id: String) -> Result<()> {
-    let claude_home = get_claude_home()
-        .ok_or_else(|| anyhow::anyhow!("Claude home directory not found. Expected at ~/.claude"))?;
-
-    let projects_dir = claude_home.join("projects");
-    if !projects_dir.exists() {
+async fn show_session(ctx: &Context, session_id: String, timeline: bool) -> Result<()> {
+    let projects_dirs = claude_projects_dirs(ctx).await;
+    if projects_dirs.is_empty() {
         return Err(anyhow::anyhow!("No Claude projects directory found."));
     }
 
-    // Find session file by ID
-    let session_path = find_session_by_id(&projects_dir, &session_id)?;
+    // Find session file by ID, trying each root in order
+    let session_path = projects_dirs
+        .iter()
+        .find_map(|dir| find_session_by_id(dir, &session_id).ok())
+        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
 
     // Parse full session details
     let parsed = parse_session_full(&session_path)
@@ -187,6 +333,8 @@ async fn show_session(ctx: &Context, session_id: String) -> Result<()> {
 
     let project_name = extract_project_name(&parsed.cwd);
     let (date, duration, start_time, end_time) = calculate_session_timing(&parsed);
+    let multi_project = parsed.is_multi_project();
+    let project_paths = parsed.project_paths();
 
     let detail = SessionDetail {
         session_id: session_id_from_path,
@@ -201,7 +349,14 @@ async fn show_session(ctx: &Context, session_id: String) -> Result<()> {
             tool: t.tool_name.clone(),
             count: t.count,
         }).collect(),
+        tool_timeline: if timeline {
+            parsed.tool_calls.iter().map(ToolCallRow::from).collect()
+        } else {
+            Vec::new()
+        },
         files_modified: parsed.files_modified,
+        multi_project,
+        project_paths,
     };
 
     // Print based on format
@@ -212,15 +367,269 @@ async fn show_session(ctx: &Context, session_id: String) -> Result<()> {
         crate::output::OutputFormat::Table => {
             print_session_detail_table(&detail, ctx.quiet);
         }
+        crate::output::OutputFormat::Csv => return Err(csv_not_supported("session details")),
     }
 
     Ok(())
 }
 
+async fn summarize_day(ctx: &Context, date: Option<String>, offline: bool) -> Result<()> {
+    let date = match date {
+        Some(d) => parse_user_date(ctx, &d).await?,
+        None => user_today(ctx).await,
+    };
+
+    let sessions: Vec<(PathBuf, ParsedSession)> = claude_projects_dirs(ctx)
+        .await
+        .iter()
+        .flat_map(|dir| find_sessions_on(dir, date))
+        .collect();
+    if sessions.is_empty() {
+        print_info(&format!("No sessions found for {}.", date), ctx.quiet);
+        return Ok(());
+    }
+
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let llm = if offline {
+        None
+    } else {
+        let llm = create_llm_service(&ctx.db.pool, &user_id).await.map_err(anyhow::Error::msg)?;
+        if llm.is_configured() {
+            Some(llm)
+        } else {
+            print_info("LLM not configured, using rule-based summaries.", ctx.quiet);
+            None
+        }
+    };
+
+    print_info(&format!("Summarizing {} session(s) from {}...", sessions.len(), date), ctx.quiet);
+    let grouped = summarize_sessions(&ctx.db.pool, &user_id, &sessions, llm.as_ref()).await;
+
+    match ctx.format {
+        crate::output::OutputFormat::Json => {
+            let projects: Vec<ProjectSessionSummaries> = grouped
+                .into_iter()
+                .map(|(project, sessions)| ProjectSessionSummaries { project, sessions })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&projects)?);
+        }
+        crate::output::OutputFormat::Table => {
+            for (project, rows) in grouped {
+                println!("\n{} ({} session(s))", project, rows.len());
+                print_output(&rows, ctx.format)?;
+            }
+        }
+        crate::output::OutputFormat::Csv => return Err(csv_not_supported("summaries grouped by project")),
+    }
+
+    Ok(())
+}
+
+/// Summarize `sessions`, grouped by project name.
+///
+/// Without `llm` every summary is rule-based. LLM calls run
+/// [`SUMMARIZE_CONCURRENCY`] at a time and are logged to LLM usage; a failed
+/// call falls back to the rule-based summary.
+async fn summarize_sessions(
+    pool: &sqlx::SqlitePool,
+    user_id: &str,
+    sessions: &[(PathBuf, ParsedSession)],
+    llm: Option<&LlmService>,
+) -> BTreeMap<String, Vec<SessionSummaryRow>> {
+    let mut grouped: BTreeMap<String, Vec<SessionSummaryRow>> = BTreeMap::new();
+
+    for chunk in sessions.chunks(SUMMARIZE_CONCURRENCY) {
+        let futs: Vec<_> = chunk
+            .iter()
+            .map(|(path, parsed)| summarize_one(pool, user_id, path, parsed, llm))
+            .collect();
+        let summaries = futures::future::join_all(futs).await;
+
+        for ((path, parsed), (summary, method)) in chunk.iter().zip(summaries) {
+            let session_id = path.file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("unknown");
+            let (_, _, start_time, end_time) = calculate_session_timing(parsed);
+            let time = match (start_time, end_time) {
+                (Some(start), Some(end)) => format!("{}-{}", start, end),
+                _ => "-".to_string(),
+            };
+
+            grouped.entry(extract_project_name(&parsed.cwd)).or_default().push(SessionSummaryRow {
+                session_id: truncate_string(session_id, 12),
+                time,
+                summary,
+                method: method.to_string(),
+            });
+        }
+    }
+
+    grouped
+}
+
+/// Summary and method (`llm` or `rule`) for one session
+async fn summarize_one(
+    pool: &sqlx::SqlitePool,
+    user_id: &str,
+    path: &PathBuf,
+    parsed: &ParsedSession,
+    llm: Option<&LlmService>,
+) -> (String, &'static str) {
+    let Some(llm) = llm else {
+        return (build_rule_based_session_summary(parsed), "rule");
+    };
+
+    let content = extract_session_content(path);
+    if content.is_empty() {
+        return (build_rule_based_session_summary(parsed), "rule");
+    }
+
+    match llm.summarize_session(&content).await {
+        Ok((summary, usage)) => {
+            let _ = save_usage_log(pool, user_id, &usage).await;
+            (summary.trim().to_string(), "llm")
+        }
+        Err(e) => {
+            if let Some(usage) = parse_error_usage(&e) {
+                let _ = save_usage_log(pool, user_id, &usage).await;
+            }
+            log::warn!("LLM summarization failed for {}, using rule-based: {}", path.display(), e);
+            (build_rule_based_session_summary(parsed), "rule")
+        }
+    }
+}
+
+/// Sessions under `projects_dir` that started on `date`, oldest first
+fn find_sessions_on(projects_dir: &Path, date: NaiveDate) -> Vec<(PathBuf, ParsedSession)> {
+    let date = date.to_string();
+    let mut sessions = Vec::new();
+
+    let Ok(entries) = fs::read_dir(projects_dir) else {
+        return sessions;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = path.file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.starts_with('.'))
+            .unwrap_or(true);
+        if !path.is_dir() || hidden {
+            continue;
+        }
+
+        let Ok(files) = fs::read_dir(&path) else {
+            continue;
+        };
+        for file_path in files.flatten().map(|f| f.path()) {
+            if file_path.extension().map(|e| e != "jsonl").unwrap_or(true) {
+                continue;
+            }
+            if let Some(parsed) = parse_session_full(&file_path) {
+                if calculate_session_timing(&parsed).0 == date {
+                    sessions.push((file_path, parsed));
+                }
+            }
+        }
+    }
+
+    sessions.sort_by(|a, b| a.1.first_timestamp.cmp(&b.1.first_timestamp));
+    sessions
+}
+
+/// Session IDs that already have a Claude work item
+async fn imported_session_ids(pool: &sqlx::SqlitePool) -> Result<HashSet<String>> {
+    let ids: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT session_id FROM work_items WHERE session_id IS NOT NULL
+        UNION
+        SELECT source_id FROM work_items WHERE source = 'claude_code' AND source_id IS NOT NULL
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(ids.into_iter().collect())
+}
+
+/// Sessions under `projects_dir` whose ID is not in `imported`
+fn unimported_sessions(projects_dir: &Path, imported: &HashSet<String>) -> Vec<UnimportedSessionRow> {
+    let mut rows = Vec::new();
+
+    let Ok(entries) = fs::read_dir(projects_dir) else {
+        return rows;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = path.file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.starts_with('.'))
+            .unwrap_or(true);
+        if !path.is_dir() || hidden {
+            continue;
+        }
+
+        let Ok(files) = fs::read_dir(&path) else {
+            continue;
+        };
+        for file_path in files.flatten().map(|f| f.path()) {
+            if file_path.extension().map(|e| e != "jsonl").unwrap_or(true) {
+                continue;
+            }
+            let session_id = file_path.file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("unknown")
+                .to_string();
+            if imported.contains(&session_id) {
+                continue;
+            }
+
+            let metadata = parse_session_fast(&file_path);
+            let reason = unimported_reason(metadata.as_ref()).to_string();
+            let row = match metadata {
+                Some(m) => UnimportedSessionRow {
+                    session_id: truncate_string(&session_id, 12),
+                    project: extract_project_name(m.cwd.as_deref().unwrap_or_default()),
+                    date: calculate_date_and_duration(&m.first_ts, &m.last_ts).0,
+                    messages: m.message_count,
+                    reason,
+                },
+                None => UnimportedSessionRow {
+                    session_id: truncate_string(&session_id, 12),
+                    project: "-".to_string(),
+                    date: "-".to_string(),
+                    messages: 0,
+                    reason,
+                },
+            };
+            rows.push(row);
+        }
+    }
+    rows
+}
+
+/// Why sync skips a session, mirroring the checks in the Claude source.
+/// Sessions that pass them are just waiting for the next sync.
+fn unimported_reason(metadata: Option<&SessionMetadata>) -> &'static str {
+    match metadata {
+        None => "Unreadable or no timestamps",
+        Some(m) if m.message_count == 0 => "No meaningful message (too short or warmup)",
+        Some(m) if m.cwd.as_deref().is_none_or(|cwd| cwd.is_empty() || cwd == "/") => "No project directory",
+        Some(_) => "Not synced yet",
+    }
+}
+
 // ============ Helper Functions ============
 
-fn get_claude_home() -> Option<PathBuf> {
-    dirs::home_dir().map(|h| h.join(".claude"))
+/// Project substring (case-insensitive) and exact date filters of `claude list`
+fn matches_list_filters(project: &str, date: &str, project_filter: Option<&str>, filter_date: Option<NaiveDate>) -> bool {
+    if let Some(filter) = project_filter {
+        if !project.to_lowercase().contains(&filter.to_lowercase()) {
+            return false;
+        }
+    }
+    match filter_date {
+        Some(filter_date) => NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok_and(|d| d == filter_date),
+        None => true,
+    }
 }
 
 fn find_session_by_id(projects_dir: &PathBuf, session_id: &str) -> Result<PathBuf> {
@@ -252,6 +661,18 @@ fn find_session_by_id(projects_dir: &PathBuf, session_id: &str) -> Result<PathBu
     Err(anyhow::anyhow!("Session not found: {}", session_id))
 }
 
+/// Sort session rows by the given field (descending unless `ascending`)
+fn sort_sessions(rows: &mut [SessionRow], sort: SessionSort, ascending: bool) {
+    rows.sort_by(|a, b| {
+        let ordering = match sort {
+            SessionSort::Duration => a.duration_minutes.cmp(&b.duration_minutes),
+            SessionSort::Messages => a.message_count.cmp(&b.message_count),
+            SessionSort::Date => a.start_ts.cmp(&b.start_ts),
+        };
+        if ascending { ordering } else { ordering.reverse() }
+    });
+}
+
 fn parse_session_for_list(path: &PathBuf) -> Option<SessionRow> {
     let metadata = parse_session_fast(path)?;
 
@@ -263,6 +684,7 @@ fn parse_session_for_list(path: &PathBuf) -> Option<SessionRow> {
     let project = extract_project_name(&metadata.cwd.unwrap_or_default());
 
     let (date, duration) = calculate_date_and_duration(&metadata.first_ts, &metadata.last_ts);
+    let duration_minutes = duration_in_minutes(&metadata.first_ts, &metadata.last_ts);
 
     let first_message = metadata.first_msg
         .map(|m| truncate_string(&m, 40))
@@ -275,9 +697,22 @@ fn parse_session_for_list(path: &PathBuf) -> Option<SessionRow> {
         duration,
         messages: metadata.message_count.to_string(),
         first_message,
+        start_ts: metadata.first_ts,
+        duration_minutes,
+        message_count: metadata.message_count,
     })
 }
 
+fn duration_in_minutes(first_ts: &str, last_ts: &str) -> i64 {
+    match (
+        DateTime::parse_from_rfc3339(first_ts),
+        DateTime::parse_from_rfc3339(last_ts),
+    ) {
+        (Ok(start), Ok(end)) => end.signed_duration_since(start).num_minutes(),
+        _ => 0,
+    }
+}
+
 fn extract_project_name(cwd: &str) -> String {
     if cwd.is_empty() {
         return "unknown".to_string();
@@ -357,6 +792,12 @@ fn print_session_detail_table(detail: &SessionDetail, quiet: bool) {
     if !quiet {
         println!("Session: {}", detail.session_id);
         println!("Project: {}", detail.project);
+        if detail.multi_project {
+            println!("Warning: session spans {} projects:", detail.project_paths.len());
+            for path in &detail.project_paths {
+                println!("  - {}", path);
+            }
+        }
         println!("Date: {}", detail.date);
 
         if let (Some(start), Some(end)) = (&detail.start_time, &detail.end_time) {
@@ -382,6 +823,18 @@ fn print_session_detail_table(detail: &SessionDetail, quiet: bool) {
             println!();
         }
 
+        if !detail.tool_timeline.is_empty() {
+            println!("Tool Timeline:");
+            let width = detail.tool_timeline.iter().map(|c| c.tool.len()).max().unwrap_or(0);
+            for call in &detail.tool_timeline {
+                match &call.detail {
+                    Some(d) => println!("  {}  {:<width$}  {}", call.time, call.tool, d, width = width),
+                    None => println!("  {}  {}", call.time, call.tool),
+                }
+            }
+            println!();
+        }
+
         if !detail.files_modified.is_empty() {
             println!("Files Modified:");
             for file in &detail.files_modified {
@@ -396,6 +849,7 @@ fn print_session_detail_table(detail: &SessionDetail, quiet: bool) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use recap_core::test_support::db_with_user;
 
     #[test]
     fn test_extract_project_name_full_path() {
@@ -456,9 +910,9 @@ mod tests {
     }
 
     #[test]
-    fn test_get_claude_home() {
+    fn test_default_claude_home() {
         // Should return Some on most systems
-        let home = get_claude_home();
+        let home = recap_core::services::default_claude_home();
         if let Some(path) = home {
             assert!(path.ends_with(".claude"));
         }
@@ -483,11 +937,64 @@ mod tests {
             duration: "1.5h".to_string(),
             messages: "10".to_string(),
             first_message: "Help me...".to_string(),
+            start_ts: "2026-01-16T09:00:00Z".to_string(),
+            duration_minutes: 90,
+            message_count: 10,
         };
         assert_eq!(row.session_id, "abc123");
         assert_eq!(row.project, "recap");
     }
 
+    fn sort_row(id: &str, start_ts: &str, duration_minutes: i64, message_count: usize) -> SessionRow {
+        SessionRow {
+            session_id: id.to_string(),
+            project: "recap".to_string(),
+            date: start_ts[..10].to_string(),
+            duration: "-".to_string(),
+            messages: message_count.to_string(),
+            first_message: "-".to_string(),
+            start_ts: start_ts.to_string(),
+            duration_minutes,
+            message_count,
+        }
+    }
+
+    #[test]
+    fn test_sort_sessions_by_duration_longest_first() {
+        let mut rows = vec![
+            sort_row("short", "2026-01-16T09:00:00Z", 15, 40),
+            sort_row("longest", "2026-01-14T09:00:00Z", 240, 10),
+            sort_row("medium", "2026-01-15T09:00:00Z", 60, 20),
+        ];
+        sort_sessions(&mut rows, SessionSort::Duration, false);
+        let ids: Vec<&str> = rows.iter().map(|r| r.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["longest", "medium", "short"]);
+
+        sort_sessions(&mut rows, SessionSort::Duration, true);
+        assert_eq!(rows[0].session_id, "short");
+    }
+
+    #[test]
+    fn test_sort_sessions_by_messages_and_date() {
+        let mut rows = vec![
+            sort_row("a", "2026-01-16T09:00:00Z", 15, 40),
+            sort_row("b", "2026-01-14T09:00:00Z", 240, 10),
+            sort_row("c", "2026-01-16T13:00:00Z", 60, 20),
+        ];
+        sort_sessions(&mut rows, SessionSort::Messages, false);
+        assert_eq!(rows[0].session_id, "a");
+
+        sort_sessions(&mut rows, SessionSort::Date, false);
+        let ids: Vec<&str> = rows.iter().map(|r| r.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_duration_in_minutes() {
+        assert_eq!(duration_in_minutes("2026-01-16T09:00:00Z", "2026-01-16T11:30:00Z"), 150);
+        assert_eq!(duration_in_minutes("invalid", "invalid"), 0);
+    }
+
     #[test]
     fn test_session_detail_serialization() {
         let detail = SessionDetail {
@@ -500,11 +1007,108 @@ mod tests {
             message_count: 5,
             first_message: Some("Test message".to_string()),
             tool_usage: vec![],
+            tool_timeline: vec![],
             files_modified: vec![],
+            multi_project: false,
+            project_paths: vec!["/work/test".to_string()],
         };
 
         let json = serde_json::to_string(&detail).unwrap();
         assert!(json.contains("test-123"));
+        assert!(json.contains("\"multi_project\":false"));
         assert!(json.contains("2026-01-16"));
+        // Compact unless --timeline was given
+        assert!(!json.contains("tool_timeline"));
+    }
+
+    #[test]
+    fn test_tool_call_row_time() {
+        let call = |timestamp: Option<&str>| ToolCall {
+            timestamp: timestamp.map(str::to_string),
+            tool_name: "Bash".to_string(),
+            detail: Some("git status".to_string()),
+        };
+        assert_eq!(ToolCallRow::from(&call(Some("2026-01-16T09:05:30+08:00"))).time, "09:05:30");
+        assert_eq!(ToolCallRow::from(&call(None)).time, "-");
+    }
+
+    fn write_session(dir: &Path, id: &str, cwd: &str, timestamp: &str, message: &str) {
+        fs::create_dir_all(dir).unwrap();
+        let line = serde_json::json!({
+            "cwd": cwd,
+            "timestamp": timestamp,
+            "message": {"role": "user", "content": message},
+        });
+        fs::write(dir.join(format!("{}.jsonl", id)), format!("{}\n", line)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unimported_lists_only_sessions_without_items() {
+        let (db, temp_dir) = db_with_user().await;
+        let projects_dir = temp_dir.path().join("projects");
+        let alpha = projects_dir.join("-work-alpha");
+        write_session(&alpha, "imported-1", "/work/alpha", "2026-01-15T09:00:00Z", "Implement the login page");
+        write_session(&alpha, "filtered-1", "/work/alpha", "2026-01-15T10:00:00Z", "warmup");
+
+        sqlx::query(
+            "INSERT INTO work_items (id, user_id, source, title, hours, date, session_id) VALUES ('w1', 'u1', 'claude_code', 'Login', 1.0, '2026-01-15', 'imported-1')",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let imported = imported_session_ids(&db.pool).await.unwrap();
+        let rows = unimported_sessions(&projects_dir, &imported);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].session_id, "filtered-1");
+        assert_eq!(rows[0].project, "alpha");
+        assert_eq!(rows[0].date, "2026-01-15");
+        assert_eq!(rows[0].reason, "No meaningful message (too short or warmup)");
+    }
+
+    #[test]
+    fn test_unimported_reason() {
+        let metadata = |cwd: Option<&str>, message_count| SessionMetadata {
+            cwd: cwd.map(str::to_string),
+            first_ts: "2026-01-15T09:00:00Z".to_string(),
+            last_ts: "2026-01-15T10:00:00Z".to_string(),
+            first_msg: None,
+            message_count,
+        };
+        assert_eq!(unimported_reason(None), "Unreadable or no timestamps");
+        assert_eq!(unimported_reason(Some(&metadata(Some("/"), 3))), "No project directory");
+        assert_eq!(unimported_reason(Some(&metadata(Some("/work/alpha"), 3))), "Not synced yet");
+    }
+
+    #[tokio::test]
+    async fn test_summarize_offline_groups_sessions_without_llm() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let projects_dir = temp_dir.path().join("projects");
+        let alpha = projects_dir.join("-work-alpha");
+        let beta = projects_dir.join("-work-beta");
+        write_session(&alpha, "a1", "/work/alpha", "2026-01-15T09:00:00Z", "Implement the login page");
+        write_session(&alpha, "a2", "/work/alpha", "2026-01-15T14:00:00Z", "Fix flaky login tests");
+        write_session(&beta, "b1", "/work/beta", "2026-01-15T11:00:00Z", "Write the release notes");
+        write_session(&beta, "b2", "/work/beta", "2026-01-16T10:00:00Z", "Next day session");
+
+        let db = recap_core::Database::open(temp_dir.path().join("test.db")).await.unwrap();
+        let day = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let sessions = find_sessions_on(&projects_dir, day);
+        assert_eq!(sessions.len(), 3);
+
+        let grouped = summarize_sessions(&db.pool, "u1", &sessions, None).await;
+        assert_eq!(grouped.keys().collect::<Vec<_>>(), vec!["alpha", "beta"]);
+        let alpha_rows = &grouped["alpha"];
+        assert_eq!(alpha_rows.len(), 2);
+        assert_eq!(alpha_rows[0].summary, "Implement the login page");
+        assert_eq!(alpha_rows[1].summary, "Fix flaky login tests");
+        assert_eq!(grouped["beta"][0].session_id, "b1");
+        assert!(grouped.values().flatten().all(|r| r.method == "rule"));
+
+        let (usage_logs,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM llm_usage_logs")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(usage_logs, 0);
     }
 }
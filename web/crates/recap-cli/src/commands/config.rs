@@ -5,14 +5,16 @@
 use anyhow::Result;
 use clap::Subcommand;
 use serde::Serialize;
+use std::path::PathBuf;
 use tabled::Tabled;
 
 use crate::output::{print_output, print_success, print_info, print_error};
+use super::work::helpers::claude_home;
 use super::Context;
 
 #[derive(Subcommand)]
 pub enum ConfigAction {
-    /// Show current configuration
+    /// Show effective configuration and where each value comes from (db, env, default)
     Show,
 
     /// Set a configuration value
@@ -32,6 +34,52 @@ pub enum ConfigAction {
 
     /// List all configuration keys and values
     List,
+
+    /// Update background sync intervals and source toggles
+    SetSync {
+        /// Data sync interval in minutes (5, 15, 30, 60)
+        #[arg(long)]
+        interval: Option<u32>,
+
+        /// Compaction interval in minutes (30, 60, 180, 360, 720, 1440)
+        #[arg(long)]
+        compaction: Option<u32>,
+
+        /// Sync local Git repositories (on/off)
+        #[arg(long)]
+        git: Option<String>,
+
+        /// Sync Claude Code sessions (on/off)
+        #[arg(long)]
+        claude: Option<String>,
+
+        /// Sync GitLab (on/off)
+        #[arg(long)]
+        gitlab: Option<String>,
+
+        /// Sync Jira/Tempo (on/off)
+        #[arg(long)]
+        jira: Option<String>,
+
+        /// Auto-generate timeline summaries (on/off)
+        #[arg(long)]
+        auto_summaries: Option<String>,
+    },
+
+    /// Check settings for inconsistencies (no network calls)
+    Validate,
+}
+
+/// Sync source toggles and intervals from `config set-sync`
+#[derive(Debug, Default)]
+struct SyncSettingsUpdate {
+    interval: Option<u32>,
+    compaction: Option<u32>,
+    git: Option<bool>,
+    claude: Option<bool>,
+    gitlab: Option<bool>,
+    jira: Option<bool>,
+    auto_summaries: Option<bool>,
 }
 
 /// Config row for table display
@@ -41,7 +89,9 @@ pub struct ConfigRow {
     pub key: String,
     #[tabled(rename = "Value")]
     pub value: String,
-    #[tabled(rename = "Source")]
+    /// Where the value comes from: `db`, `env` or `default`
+    #[tabled(rename = "Origin")]
+    #[serde(rename = "origin")]
     pub source: String,
 }
 
@@ -51,6 +101,20 @@ pub async fn execute(ctx: &Context, action: ConfigAction) -> Result<()> {
         ConfigAction::Set { key, value } => set_config(ctx, key, value).await,
         ConfigAction::Get { key } => get_config(ctx, key).await,
         ConfigAction::List => list_config(ctx).await,
+        ConfigAction::Validate => validate_config(ctx).await,
+        ConfigAction::SetSync { interval, compaction, git, claude, gitlab, jira, auto_summaries } => {
+            let parse_toggle = |v: Option<String>| v.as_deref().map(parse_bool).transpose();
+            let update = SyncSettingsUpdate {
+                interval,
+                compaction,
+                git: parse_toggle(git)?,
+                claude: parse_toggle(claude)?,
+                gitlab: parse_toggle(gitlab)?,
+                jira: parse_toggle(jira)?,
+                auto_summaries: parse_toggle(auto_summaries)?,
+            };
+            set_sync_config(ctx, update).await
+        }
     }
 }
 
@@ -140,6 +204,22 @@ async fn set_config(ctx: &Context, key: String, value: String) -> Result<()> {
             update_user_setting(&ctx.db, &user_id, "llm_base_url", &value).await?;
             print_success(&format!("Set llm_base_url = {}", value), ctx.quiet);
         }
+        "llm_cache_ttl_hours" => {
+            let hours = parse_i64(&value)?;
+            if hours < 0 {
+                return Err(anyhow::anyhow!("llm_cache_ttl_hours must be 0 (disabled) or greater"));
+            }
+            update_user_setting_i64(&ctx.db, &user_id, "llm_cache_ttl_hours", hours).await?;
+            print_success(&format!("Set llm_cache_ttl_hours = {}", hours), ctx.quiet);
+        }
+        "llm_cache_max_entries" => {
+            let entries = parse_i64(&value)?;
+            if entries < 1 {
+                return Err(anyhow::anyhow!("llm_cache_max_entries must be 1 or greater"));
+            }
+            update_user_setting_i64(&ctx.db, &user_id, "llm_cache_max_entries", entries).await?;
+            print_success(&format!("Set llm_cache_max_entries = {}", entries), ctx.quiet);
+        }
 
         // Work hour settings
         "daily_work_hours" => {
@@ -150,11 +230,114 @@ async fn set_config(ctx: &Context, key: String, value: String) -> Result<()> {
             update_user_setting_f64(&ctx.db, &user_id, "daily_work_hours", hours).await?;
             print_success(&format!("Set daily_work_hours = {}", hours), ctx.quiet);
         }
+        "max_session_hours" => {
+            let hours = parse_f64(&value)?;
+            if hours <= 0.0 || hours > 24.0 {
+                return Err(anyhow::anyhow!("max_session_hours must be between 0 and 24"));
+            }
+            update_user_setting_f64(&ctx.db, &user_id, "max_session_hours", hours).await?;
+            print_success(&format!("Set max_session_hours = {}", hours), ctx.quiet);
+        }
         "normalize_hours" => {
             let normalize = parse_bool(&value)?;
             update_user_setting_bool(&ctx.db, &user_id, "normalize_hours", normalize).await?;
             print_success(&format!("Set normalize_hours = {}", normalize), ctx.quiet);
         }
+        "normalize_weighting" => {
+            let weighting = recap_core::services::NormalizeWeighting::parse(&value).map_err(|e| anyhow::anyhow!(e))?;
+            update_user_setting(&ctx.db, &user_id, "normalize_weighting", weighting.as_str()).await?;
+            print_success(&format!("Set normalize_weighting = {}", weighting.as_str()), ctx.quiet);
+        }
+        "category_weights" => {
+            recap_core::services::parse_category_weights(&value).map_err(|e| anyhow::anyhow!(e))?;
+            update_user_setting(&ctx.db, &user_id, "category_weights", &value).await?;
+            print_success(&format!("Set category_weights = {}", value), ctx.quiet);
+        }
+
+        // Git sync settings
+        "min_commit_lines" => {
+            let lines = parse_i64(&value)?;
+            if lines < 0 {
+                return Err(anyhow::anyhow!("min_commit_lines must be 0 or greater"));
+            }
+            update_user_setting_i64(&ctx.db, &user_id, "min_commit_lines", lines).await?;
+            print_success(&format!("Set min_commit_lines = {}", lines), ctx.quiet);
+        }
+        "include_coauthored" => {
+            let include = parse_bool(&value)?;
+            update_user_setting_bool(&ctx.db, &user_id, "include_coauthored", include).await?;
+            print_success(&format!("Set include_coauthored = {}", include), ctx.quiet);
+        }
+        "fold_trivial_commit_hours" => {
+            let fold = parse_bool(&value)?;
+            update_user_setting_bool(&ctx.db, &user_id, "fold_trivial_commit_hours", fold).await?;
+            print_success(&format!("Set fold_trivial_commit_hours = {}", fold), ctx.quiet);
+        }
+
+        // Files hidden from file-level reports
+        "exclude_paths" => {
+            let patterns = recap_core::services::parse_exclude_paths(&value);
+            recap_core::services::PathExcludeFilter::new(&patterns).map_err(|e| anyhow::anyhow!(e))?;
+            update_user_setting(&ctx.db, &user_id, "exclude_paths", &patterns.join(",")).await?;
+            print_success(&format!("Set exclude_paths = {}", patterns.join(",")), ctx.quiet);
+        }
+
+        // Report push settings
+        "report_push_url" => {
+            update_user_setting(&ctx.db, &user_id, "report_push_url", &value).await?;
+            print_success(&format!("Set report_push_url = {}", value), ctx.quiet);
+        }
+        "report_push_token" => {
+            update_user_setting(&ctx.db, &user_id, "report_push_token", &value).await?;
+            print_success("Set report_push_token = ****", ctx.quiet);
+        }
+
+        // Notion report export settings
+        "notion_token" => {
+            update_user_setting(&ctx.db, &user_id, "notion_token", &value).await?;
+            print_success("Set notion_token = ****", ctx.quiet);
+        }
+        "notion_database_id" => {
+            update_user_setting(&ctx.db, &user_id, "notion_database_id", &value).await?;
+            print_success(&format!("Set notion_database_id = {}", value), ctx.quiet);
+        }
+
+        // Backup settings
+        "db_backup_enabled" => {
+            let enabled = parse_bool(&value)?;
+            update_user_setting_bool(&ctx.db, &user_id, "db_backup_enabled", enabled).await?;
+            print_success(&format!("Set db_backup_enabled = {}", enabled), ctx.quiet);
+        }
+        "db_backup_keep" => {
+            let keep = parse_i64(&value)?;
+            if keep < 1 {
+                return Err(anyhow::anyhow!("db_backup_keep must be 1 or greater"));
+            }
+            update_user_setting_i64(&ctx.db, &user_id, "db_backup_keep", keep).await?;
+            print_success(&format!("Set db_backup_keep = {}", keep), ctx.quiet);
+        }
+
+        // Trash settings
+        "trash_retention_days" => {
+            let days = parse_i64(&value)?;
+            if days < 1 {
+                return Err(anyhow::anyhow!("trash_retention_days must be 1 or greater"));
+            }
+            update_user_setting_i64(&ctx.db, &user_id, "trash_retention_days", days).await?;
+            print_success(&format!("Set trash_retention_days = {}", days), ctx.quiet);
+        }
+
+        // Debug settings
+        "llm_debug_log" => {
+            let enabled = parse_bool(&value)?;
+            update_user_setting_bool(&ctx.db, &user_id, "llm_debug_log", enabled).await?;
+            print_success(&format!("Set llm_debug_log = {}", enabled), ctx.quiet);
+            if enabled {
+                if let Some(path) = recap_core::services::default_llm_debug_log_path() {
+                    print_info(&format!("LLM prompts and responses will be logged to {}", path.display()), ctx.quiet);
+                }
+            }
+        }
 
         _ => {
             print_error(&format!("Unknown config key: {}", key));
@@ -162,8 +345,16 @@ async fn set_config(ctx: &Context, key: String, value: String) -> Result<()> {
                 "Available keys:\n  \
                  Jira: jira_url, jira_email, jira_pat, tempo_token\n  \
                  GitLab: gitlab_url, gitlab_pat\n  \
-                 LLM: llm_provider, llm_model, llm_api_key, llm_base_url\n  \
-                 Work: daily_work_hours, normalize_hours",
+                 LLM: llm_provider, llm_model, llm_api_key, llm_base_url,\n       \
+                      llm_cache_ttl_hours, llm_cache_max_entries\n  \
+                 Work: daily_work_hours, max_session_hours, normalize_hours, normalize_weighting,\n        \
+                       category_weights\n  \
+                 Git: min_commit_lines, include_coauthored, fold_trivial_commit_hours\n  \
+                 Report: report_push_url, report_push_token, exclude_paths\n  \
+                 Notion: notion_token, notion_database_id\n  \
+                 Backup: db_backup_enabled, db_backup_keep\n  \
+                 Trash: trash_retention_days\n  \
+                 Debug: llm_debug_log",
                 ctx.quiet
             );
         }
@@ -172,6 +363,73 @@ async fn set_config(ctx: &Context, key: String, value: String) -> Result<()> {
     Ok(())
 }
 
+async fn set_sync_config(ctx: &Context, update: SyncSettingsUpdate) -> Result<()> {
+    validate_sync_intervals(update.interval, update.compaction)?;
+
+    let user_id = get_default_user_id(&ctx.db).await?;
+
+    // Only overwrite the columns that were provided
+    sqlx::query(
+        r#"
+        UPDATE users SET
+            sync_interval_minutes = COALESCE(?, sync_interval_minutes),
+            compaction_interval_minutes = COALESCE(?, compaction_interval_minutes),
+            sync_git = COALESCE(?, sync_git),
+            sync_claude = COALESCE(?, sync_claude),
+            sync_gitlab = COALESCE(?, sync_gitlab),
+            sync_jira = COALESCE(?, sync_jira),
+            auto_generate_summaries = COALESCE(?, auto_generate_summaries),
+            updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(update.interval)
+    .bind(update.compaction)
+    .bind(update.git)
+    .bind(update.claude)
+    .bind(update.gitlab)
+    .bind(update.jira)
+    .bind(update.auto_summaries)
+    .bind(chrono::Utc::now())
+    .bind(&user_id)
+    .execute(&ctx.db.pool)
+    .await?;
+
+    print_success("Sync configuration updated", ctx.quiet);
+
+    let rows = get_sync_config(&ctx.db, &user_id).await?;
+    print_output(&rows, ctx.format)?;
+    Ok(())
+}
+
+async fn get_sync_config(db: &recap_core::Database, user_id: &str) -> Result<Vec<ConfigRow>> {
+    let settings: SyncSettings = sqlx::query_as(
+        r#"
+        SELECT sync_interval_minutes, compaction_interval_minutes,
+               sync_git, sync_claude, sync_gitlab, sync_jira,
+               auto_generate_summaries
+        FROM users WHERE id = ?
+        "#,
+    )
+    .bind(user_id)
+    .fetch_one(&db.pool)
+    .await?;
+
+    let on_off = |default: bool| move |v: Option<bool>| if v.unwrap_or(default) { "on" } else { "off" }.to_string();
+
+    Ok(vec![
+        db_row("sync_interval_minutes", settings.sync_interval_minutes, |v| v.unwrap_or(15).to_string()),
+        db_row("compaction_interval_minutes", settings.compaction_interval_minutes, |v| {
+            v.unwrap_or(60).to_string()
+        }),
+        db_row("sync_git", settings.sync_git, on_off(true)),
+        db_row("sync_claude", settings.sync_claude, on_off(true)),
+        db_row("sync_gitlab", settings.sync_gitlab, on_off(false)),
+        db_row("sync_jira", settings.sync_jira, on_off(false)),
+        db_row("auto_generate_summaries", settings.auto_generate_summaries, on_off(true)),
+    ])
+}
+
 async fn get_all_config(ctx: &Context) -> Result<Vec<ConfigRow>> {
     let mut rows = Vec::new();
 
@@ -180,28 +438,51 @@ async fn get_all_config(ctx: &Context) -> Result<Vec<ConfigRow>> {
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| "Unknown".to_string());
 
+    rows.push(env_row("RECAP_DB_PATH", db_path));
+
+    // JWT secret: without the env var it is read from (or generated into) the app data dir
+    let jwt_secret = std::env::var("RECAP_JWT_SECRET").ok();
+    rows.push(env_row("RECAP_JWT_SECRET", mask_token(&jwt_secret)));
+
+    let user_id = get_default_user_id(&ctx.db).await.ok();
+
+    // Claude path: the claude_session_path setting, else the platform default
+    let claude_session_path: Option<String> =
+        sqlx::query_scalar("SELECT claude_session_path FROM users WHERE id = ?")
+            .bind(&user_id)
+            .fetch_optional(&ctx.db.pool)
+            .await?
+            .flatten()
+            .filter(|p: &String| !p.trim().is_empty());
+    let homes = recap_core::services::claude_homes_or_default(claude_session_path.as_deref());
+    let join_or = |paths: Vec<String>, empty: &str| {
+        if paths.is_empty() { empty.to_string() } else { paths.join(", ") }
+    };
     rows.push(ConfigRow {
-        key: "RECAP_DB_PATH".to_string(),
-        value: db_path,
-        source: if std::env::var("RECAP_DB_PATH").is_ok() { "env" } else { "default" }.to_string(),
+        key: "claude_session_path".to_string(),
+        value: join_or(homes.iter().map(|p| p.to_string_lossy().to_string()).collect(), "-"),
+        source: if claude_session_path.is_some() { "db" } else { "default" }.to_string(),
     });
-
-    // Claude path
-    let claude_path = get_claude_path();
     rows.push(ConfigRow {
         key: "claude_projects_path".to_string(),
-        value: claude_path.clone().unwrap_or_else(|| "Not found".to_string()),
-        source: if claude_path.is_some() { "detected" } else { "n/a" }.to_string(),
+        value: join_or(homes.into_iter().filter_map(|h| get_claude_path(Some(h))).collect(), "Not found"),
+        source: if claude_session_path.is_some() { "db" } else { "default" }.to_string(),
     });
 
     // User settings from database
-    if let Ok(user_id) = get_default_user_id(&ctx.db).await {
+    if let Some(user_id) = user_id {
         let user: Option<UserSettings> = sqlx::query_as(
             r#"
             SELECT jira_url, jira_email, jira_pat, tempo_token,
                    gitlab_pat, gitlab_url,
                    llm_provider, llm_model, llm_api_key, llm_base_url,
-                   daily_work_hours, normalize_hours
+                   llm_cache_ttl_hours, llm_cache_max_entries,
+                   daily_work_hours, max_session_hours, normalize_hours, normalize_weighting, category_weights,
+                   min_commit_lines, include_coauthored, fold_trivial_commit_hours,
+                   report_push_url, report_push_token, exclude_paths,
+                   notion_token, notion_database_id,
+                   db_backup_enabled, db_backup_keep, trash_retention_days,
+                   llm_debug_log
             FROM users WHERE id = ?
             "#
         )
@@ -211,73 +492,78 @@ async fn get_all_config(ctx: &Context) -> Result<Vec<ConfigRow>> {
 
         if let Some(settings) = user {
             // Jira settings
-            rows.push(ConfigRow {
-                key: "jira_url".to_string(),
-                value: settings.jira_url.unwrap_or_else(|| "-".to_string()),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "jira_email".to_string(),
-                value: settings.jira_email.unwrap_or_else(|| "-".to_string()),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "jira_pat".to_string(),
-                value: mask_token(&settings.jira_pat),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "tempo_token".to_string(),
-                value: mask_token(&settings.tempo_token),
-                source: "db".to_string(),
-            });
+            rows.push(db_row("jira_url", settings.jira_url, |v| v.unwrap_or_else(|| "-".to_string())));
+            rows.push(db_row("jira_email", settings.jira_email, |v| v.unwrap_or_else(|| "-".to_string())));
+            rows.push(db_row("jira_pat", settings.jira_pat, |v| mask_token(&v)));
+            rows.push(db_row("tempo_token", settings.tempo_token, |v| mask_token(&v)));
 
             // GitLab settings
-            rows.push(ConfigRow {
-                key: "gitlab_pat".to_string(),
-                value: mask_token(&settings.gitlab_pat),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "gitlab_url".to_string(),
-                value: settings.gitlab_url.unwrap_or_else(|| "-".to_string()),
-                source: "db".to_string(),
-            });
+            rows.push(db_row("gitlab_pat", settings.gitlab_pat, |v| mask_token(&v)));
+            rows.push(db_row("gitlab_url", settings.gitlab_url, |v| v.unwrap_or_else(|| "-".to_string())));
 
             // LLM settings
-            rows.push(ConfigRow {
-                key: "llm_provider".to_string(),
-                value: settings.llm_provider.unwrap_or_else(|| "openai".to_string()),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "llm_model".to_string(),
-                value: settings.llm_model.unwrap_or_else(|| "gpt-5-nano".to_string()),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "llm_api_key".to_string(),
-                value: mask_token(&settings.llm_api_key),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "llm_base_url".to_string(),
-                value: settings.llm_base_url.unwrap_or_else(|| "-".to_string()),
-                source: "db".to_string(),
-            });
+            rows.push(db_row("llm_provider", settings.llm_provider, |v| v.unwrap_or_else(|| "openai".to_string())));
+            rows.push(db_row("llm_model", settings.llm_model, |v| v.unwrap_or_else(|| "gpt-5-nano".to_string())));
+            rows.push(db_row("llm_api_key", settings.llm_api_key, |v| mask_token(&v)));
+            rows.push(db_row("llm_base_url", settings.llm_base_url, |v| v.unwrap_or_else(|| "-".to_string())));
+            rows.push(db_row("llm_cache_ttl_hours", settings.llm_cache_ttl_hours, |v| {
+                v.unwrap_or(recap_core::services::DEFAULT_LLM_CACHE_TTL_HOURS).to_string()
+            }));
+            rows.push(db_row("llm_cache_max_entries", settings.llm_cache_max_entries, |v| {
+                v.unwrap_or(recap_core::services::DEFAULT_LLM_CACHE_MAX_ENTRIES).to_string()
+            }));
 
             // Work hour settings
-            rows.push(ConfigRow {
-                key: "daily_work_hours".to_string(),
-                value: settings.daily_work_hours.unwrap_or(8.0).to_string(),
-                source: "db".to_string(),
-            });
-            rows.push(ConfigRow {
-                key: "normalize_hours".to_string(),
-                value: settings.normalize_hours.unwrap_or(true).to_string(),
-                source: "db".to_string(),
-            });
+            rows.push(db_row("daily_work_hours", settings.daily_work_hours, |v| v.unwrap_or(8.0).to_string()));
+            // Unset: sessions are capped at daily_work_hours
+            rows.push(db_row("max_session_hours", settings.max_session_hours, |v| {
+                v.map(|h| h.to_string()).unwrap_or_else(|| "-".to_string())
+            }));
+            rows.push(db_row("normalize_hours", settings.normalize_hours, |v| v.unwrap_or(true).to_string()));
+            rows.push(db_row("normalize_weighting", settings.normalize_weighting, |v| {
+                v.unwrap_or_else(|| "even".to_string())
+            }));
+            rows.push(db_row("category_weights", settings.category_weights, |v| {
+                v.unwrap_or_else(|| "-".to_string())
+            }));
+
+            // Git sync settings
+            rows.push(db_row("min_commit_lines", settings.min_commit_lines, |v| v.unwrap_or(0).to_string()));
+            rows.push(db_row("include_coauthored", settings.include_coauthored, |v| v.unwrap_or(true).to_string()));
+            rows.push(db_row("fold_trivial_commit_hours", settings.fold_trivial_commit_hours, |v| {
+                v.unwrap_or(false).to_string()
+            }));
+
+            // Report push settings
+            rows.push(db_row("report_push_url", settings.report_push_url, |v| v.unwrap_or_else(|| "-".to_string())));
+            rows.push(db_row("report_push_token", settings.report_push_token, |v| mask_token(&v)));
+            rows.push(db_row("exclude_paths", settings.exclude_paths, |v| {
+                v.unwrap_or_else(|| recap_core::services::DEFAULT_EXCLUDE_PATHS.join(","))
+            }));
+
+            // Notion report export settings
+            rows.push(db_row("notion_token", settings.notion_token, |v| mask_token(&v)));
+            rows.push(db_row("notion_database_id", settings.notion_database_id, |v| {
+                v.unwrap_or_else(|| "-".to_string())
+            }));
+
+            // Backup settings
+            rows.push(db_row("db_backup_enabled", settings.db_backup_enabled, |v| v.unwrap_or(false).to_string()));
+            rows.push(db_row("db_backup_keep", settings.db_backup_keep, |v| {
+                v.unwrap_or(recap_core::services::DEFAULT_DB_BACKUP_KEEP).to_string()
+            }));
+
+            // Trash settings
+            rows.push(db_row("trash_retention_days", settings.trash_retention_days, |v| {
+                v.unwrap_or(recap_core::services::DEFAULT_TRASH_RETENTION_DAYS).to_string()
+            }));
+
+            // Debug settings
+            rows.push(db_row("llm_debug_log", settings.llm_debug_log, |v| v.unwrap_or(false).to_string()));
         }
+
+        // Background sync settings
+        rows.extend(get_sync_config(&ctx.db, &user_id).await?);
     }
 
     Ok(rows)
@@ -296,13 +582,78 @@ struct UserSettings {
     llm_model: Option<String>,
     llm_api_key: Option<String>,
     llm_base_url: Option<String>,
+    llm_cache_ttl_hours: Option<i64>,
+    llm_cache_max_entries: Option<i64>,
     // Work hour settings
     daily_work_hours: Option<f64>,
+    max_session_hours: Option<f64>,
     normalize_hours: Option<bool>,
+    normalize_weighting: Option<String>,
+    category_weights: Option<String>,
+    // Git sync settings
+    min_commit_lines: Option<i64>,
+    include_coauthored: Option<bool>,
+    fold_trivial_commit_hours: Option<bool>,
+    // Report push settings
+    report_push_url: Option<String>,
+    report_push_token: Option<String>,
+    exclude_paths: Option<String>,
+    // Notion report export settings
+    notion_token: Option<String>,
+    notion_database_id: Option<String>,
+    // Backup settings
+    db_backup_enabled: Option<bool>,
+    db_backup_keep: Option<i64>,
+    // Trash settings
+    trash_retention_days: Option<i64>,
+    // Debug settings
+    llm_debug_log: Option<bool>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SyncSettings {
+    sync_interval_minutes: Option<i64>,
+    compaction_interval_minutes: Option<i64>,
+    sync_git: Option<bool>,
+    sync_claude: Option<bool>,
+    sync_gitlab: Option<bool>,
+    sync_jira: Option<bool>,
+    auto_generate_summaries: Option<bool>,
+}
+
+/// Allowed data sync intervals (minutes), matching the settings UI
+const VALID_SYNC_INTERVALS: &[u32] = &[5, 15, 30, 60];
+
+/// Allowed compaction intervals (minutes): 30min, 1h, 3h, 6h, 12h, 24h
+const VALID_COMPACTION_INTERVALS: &[u32] = &[30, 60, 180, 360, 720, 1440];
+
+/// Validate sync and compaction intervals against the allowed sets
+fn validate_sync_intervals(interval: Option<u32>, compaction: Option<u32>) -> Result<()> {
+    let join = |values: &[u32]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+
+    if let Some(minutes) = interval {
+        if !VALID_SYNC_INTERVALS.contains(&minutes) {
+            return Err(anyhow::anyhow!(
+                "Invalid sync interval: {}. Valid options: {}",
+                minutes,
+                join(VALID_SYNC_INTERVALS)
+            ));
+        }
+    }
+    if let Some(minutes) = compaction {
+        if !VALID_COMPACTION_INTERVALS.contains(&minutes) {
+            return Err(anyhow::anyhow!(
+                "Invalid compaction interval: {}. Valid options: {}",
+                minutes,
+                join(VALID_COMPACTION_INTERVALS)
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// Valid LLM providers
-const VALID_LLM_PROVIDERS: &[&str] = &["openai", "anthropic", "ollama", "openai-compatible"];
+const VALID_LLM_PROVIDERS: &[&str] = &["openai", "anthropic", "gemini", "ollama", "openai-compatible"];
 
 /// Validate LLM provider
 fn validate_llm_provider(provider: &str) -> Result<()> {
@@ -336,6 +687,33 @@ fn parse_f64(value: &str) -> Result<f64> {
     })
 }
 
+/// Parse integer value from string
+fn parse_i64(value: &str) -> Result<i64> {
+    value.parse::<i64>().map_err(|_| {
+        anyhow::anyhow!("Invalid integer: {}. Please provide a whole number", value)
+    })
+}
+
+/// Row for a users-table setting: `db` when the column is set, else `default`
+fn db_row<T>(key: &str, stored: Option<T>, display: impl FnOnce(Option<T>) -> String) -> ConfigRow {
+    let source = if stored.is_some() { "db" } else { "default" };
+    ConfigRow {
+        key: key.to_string(),
+        value: display(stored),
+        source: source.to_string(),
+    }
+}
+
+/// Row for a setting read from an environment variable of the same name
+fn env_row(key: &str, value: String) -> ConfigRow {
+    let source = if std::env::var_os(key).is_some() { "env" } else { "default" };
+    ConfigRow {
+        key: key.to_string(),
+        value,
+        source: source.to_string(),
+    }
+}
+
 fn mask_token(token: &Option<String>) -> String {
     match token {
         Some(t) if !t.is_empty() => "****".to_string(),
@@ -343,9 +721,8 @@ fn mask_token(token: &Option<String>) -> String {
     }
 }
 
-fn get_claude_path() -> Option<String> {
-    let home = dirs::home_dir()?;
-    let claude_path = home.join(".claude").join("projects");
+fn get_claude_path(claude_home: Option<PathBuf>) -> Option<String> {
+    let claude_path = claude_home?.join("projects");
     if claude_path.exists() {
         Some(claude_path.to_string_lossy().to_string())
     } else {
@@ -353,6 +730,213 @@ fn get_claude_path() -> Option<String> {
     }
 }
 
+/// Severity of a `config validate` finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueLevel {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for IssueLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueLevel::Error => write!(f, "error"),
+            IssueLevel::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// One inconsistency found by `config validate`
+#[derive(Debug, Serialize, Tabled)]
+pub struct ConfigIssue {
+    #[tabled(rename = "Level")]
+    pub level: IssueLevel,
+    #[tabled(rename = "Key")]
+    pub key: String,
+    #[tabled(rename = "Problem")]
+    pub problem: String,
+    #[tabled(rename = "Fix")]
+    pub hint: String,
+}
+
+impl ConfigIssue {
+    fn new(level: IssueLevel, key: &str, problem: &str, hint: &str) -> Self {
+        Self {
+            level,
+            key: key.to_string(),
+            problem: problem.to_string(),
+            hint: hint.to_string(),
+        }
+    }
+}
+
+/// User settings checked by `config validate`
+#[derive(Debug, Default, sqlx::FromRow)]
+struct ValidationSettings {
+    jira_url: Option<String>,
+    jira_pat: Option<String>,
+    tempo_token: Option<String>,
+    gitlab_url: Option<String>,
+    gitlab_pat: Option<String>,
+    llm_provider: Option<String>,
+    llm_api_key: Option<String>,
+    report_push_url: Option<String>,
+    report_push_token: Option<String>,
+    normalize_weighting: Option<String>,
+    category_weights: Option<String>,
+    sync_git: Option<bool>,
+    sync_claude: Option<bool>,
+    sync_gitlab: Option<bool>,
+    sync_jira: Option<bool>,
+    auto_generate_summaries: Option<bool>,
+}
+
+/// Configured data sources, for checks that depend on more than settings
+#[derive(Debug, Default)]
+struct ConfiguredSources {
+    git_repos: i64,
+    gitlab_projects: i64,
+    claude_projects_dir: bool,
+}
+
+async fn validate_config(ctx: &Context) -> Result<()> {
+    let user_id = get_default_user_id(&ctx.db).await?;
+    let settings: ValidationSettings = sqlx::query_as(
+        r#"
+        SELECT jira_url, jira_pat, tempo_token, gitlab_url, gitlab_pat,
+               llm_provider, llm_api_key, report_push_url, report_push_token,
+               normalize_weighting, category_weights,
+               sync_git, sync_claude, sync_gitlab, sync_jira, auto_generate_summaries
+        FROM users WHERE id = ?
+        "#,
+    )
+    .bind(&user_id)
+    .fetch_one(&ctx.db.pool)
+    .await?;
+
+    let git_repos: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM git_repos WHERE user_id = ? AND enabled = 1")
+        .bind(&user_id)
+        .fetch_one(&ctx.db.pool)
+        .await?;
+    let gitlab_projects: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM gitlab_projects WHERE user_id = ? AND enabled = 1")
+        .bind(&user_id)
+        .fetch_one(&ctx.db.pool)
+        .await?;
+    let sources = ConfiguredSources {
+        git_repos,
+        gitlab_projects,
+        claude_projects_dir: claude_home(ctx).await.is_some_and(|h| h.join("projects").is_dir()),
+    };
+
+    let issues = find_config_issues(&settings, &sources);
+    if issues.is_empty() {
+        print_success("Configuration is consistent", ctx.quiet);
+        return Ok(());
+    }
+    print_output(&issues, ctx.format)?;
+
+    let errors = issues.iter().filter(|i| i.level == IssueLevel::Error).count();
+    if errors > 0 {
+        return Err(anyhow::anyhow!("{} configuration error(s) found", errors));
+    }
+    Ok(())
+}
+
+/// Cross-field consistency checks over the user's settings
+fn find_config_issues(settings: &ValidationSettings, sources: &ConfiguredSources) -> Vec<ConfigIssue> {
+    use IssueLevel::{Error, Warning};
+
+    let is_set = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+    let mut issues = Vec::new();
+
+    // Jira / Tempo
+    let jira_configured = is_set(&settings.jira_url) && is_set(&settings.jira_pat);
+    if is_set(&settings.tempo_token) && !is_set(&settings.jira_url) {
+        issues.push(ConfigIssue::new(
+            Error,
+            "tempo_token",
+            "Tempo token is set but jira_url is not",
+            "recap config set jira_url <url>",
+        ));
+    }
+    if is_set(&settings.jira_url) && !is_set(&settings.jira_pat) {
+        issues.push(ConfigIssue::new(
+            Error,
+            "jira_pat",
+            "jira_url is set but there is no Jira token",
+            "recap config set jira_pat <token>",
+        ));
+    }
+    if settings.sync_jira.unwrap_or(false) && !jira_configured {
+        issues.push(ConfigIssue::new(
+            Error,
+            "sync_jira",
+            "Jira sync is on but Jira is not configured",
+            "Set jira_url and jira_pat, or recap config set-sync --jira off",
+        ));
+    }
+
+    // GitLab
+    let sync_gitlab = settings.sync_gitlab.unwrap_or(false);
+    if sync_gitlab && !(is_set(&settings.gitlab_url) && is_set(&settings.gitlab_pat)) {
+        issues.push(ConfigIssue::new(
+            Error,
+            "sync_gitlab",
+            "GitLab sync is on but GitLab is not configured",
+            "Set gitlab_url and gitlab_pat, or recap config set-sync --gitlab off",
+        ));
+    }
+
+    // Sync sources
+    let sync_git = settings.sync_git.unwrap_or(true);
+    let sync_claude = settings.sync_claude.unwrap_or(true);
+    let has_source = (sync_git && sources.git_repos > 0)
+        || (sync_claude && sources.claude_projects_dir)
+        || (sync_gitlab && sources.gitlab_projects > 0);
+    if (sync_git || sync_claude || sync_gitlab) && !has_source {
+        issues.push(ConfigIssue::new(
+            Warning,
+            "sync",
+            "Sync is enabled but no sources are available",
+            "recap source add git <path>, or set claude_session_path",
+        ));
+    }
+
+    // LLM
+    let provider = settings.llm_provider.as_deref().unwrap_or("openai");
+    if settings.auto_generate_summaries.unwrap_or(true) && provider != "ollama" && !is_set(&settings.llm_api_key) {
+        issues.push(ConfigIssue::new(
+            Warning,
+            "llm_api_key",
+            "Summaries are on but no LLM API key is set; rule-based summaries will be used",
+            "recap config set llm_api_key <key>, or recap config set-sync --auto-summaries off",
+        ));
+    }
+
+    // Report push
+    if is_set(&settings.report_push_token) && !is_set(&settings.report_push_url) {
+        issues.push(ConfigIssue::new(
+            Warning,
+            "report_push_token",
+            "Report push token is set but report_push_url is not",
+            "recap config set report_push_url <url>",
+        ));
+    }
+
+    // Hour normalization
+    if settings.normalize_weighting.as_deref() == Some("category") && !is_set(&settings.category_weights) {
+        issues.push(ConfigIssue::new(
+            Warning,
+            "category_weights",
+            "Category weighting is selected but no category weights are set; all categories weigh 1",
+            "recap config set category_weights feature=2,chore=0.5",
+        ));
+    }
+
+    issues
+}
+
 async fn get_default_user_id(db: &recap_core::Database) -> Result<String> {
     let user: Option<(String,)> = sqlx::query_as("SELECT id FROM users LIMIT 1")
         .fetch_optional(&db.pool)
@@ -392,6 +976,20 @@ async fn update_user_setting_f64(db: &recap_core::Database, user_id: &str, key:
     Ok(())
 }
 
+async fn update_user_setting_i64(db: &recap_core::Database, user_id: &str, key: &str, value: i64) -> Result<()> {
+    let query = format!("UPDATE users SET {} = ?, updated_at = ? WHERE id = ?", key);
+    let now = chrono::Utc::now();
+
+    sqlx::query(&query)
+        .bind(value)
+        .bind(now)
+        .bind(user_id)
+        .execute(&db.pool)
+        .await?;
+
+    Ok(())
+}
+
 async fn update_user_setting_bool(db: &recap_core::Database, user_id: &str, key: &str, value: bool) -> Result<()> {
     let query = format!("UPDATE users SET {} = ?, updated_at = ? WHERE id = ?", key);
     let now = chrono::Utc::now();
@@ -432,7 +1030,7 @@ mod tests {
     #[test]
     fn test_get_claude_path_doesnt_panic() {
         // Just verify it doesn't panic
-        let _ = get_claude_path();
+        let _ = get_claude_path(recap_core::services::default_claude_home());
     }
 
     #[test]
@@ -496,6 +1094,18 @@ mod tests {
         assert_eq!(row.source, "default");
     }
 
+    #[test]
+    fn test_db_row_origin() {
+        let set = db_row("daily_work_hours", Some(7.5), |v| v.unwrap_or(8.0).to_string());
+        assert_eq!((set.value.as_str(), set.source.as_str()), ("7.5", "db"));
+
+        let unset = db_row("daily_work_hours", None::<f64>, |v| v.unwrap_or(8.0).to_string());
+        assert_eq!((unset.value.as_str(), unset.source.as_str()), ("8", "default"));
+
+        let json = serde_json::to_value(&unset).unwrap();
+        assert_eq!(json["origin"], "default");
+    }
+
     #[test]
     fn test_user_settings_fields() {
         // Test that UserSettings struct can hold all expected fields
@@ -510,8 +1120,25 @@ mod tests {
             llm_model: Some("gpt-4".to_string()),
             llm_api_key: Some("sk-123".to_string()),
             llm_base_url: Some("https://api.openai.com".to_string()),
+            llm_cache_ttl_hours: Some(24),
+            llm_cache_max_entries: Some(500),
             daily_work_hours: Some(8.0),
+            max_session_hours: Some(10.0),
             normalize_hours: Some(true),
+            normalize_weighting: Some("hours".to_string()),
+            category_weights: Some("feature=2".to_string()),
+            min_commit_lines: Some(5),
+            include_coauthored: Some(true),
+            fold_trivial_commit_hours: Some(false),
+            report_push_url: Some("https://hooks.example.com/recap".to_string()),
+            report_push_token: Some("push-secret".to_string()),
+            exclude_paths: Some("*.lock".to_string()),
+            notion_token: Some("secret_notion".to_string()),
+            notion_database_id: Some("db-123".to_string()),
+            db_backup_enabled: Some(true),
+            db_backup_keep: Some(7),
+            trash_retention_days: Some(30),
+            llm_debug_log: Some(false),
         };
 
         assert!(settings.jira_url.is_some());
@@ -541,8 +1168,25 @@ mod tests {
             llm_model: None,
             llm_api_key: None,
             llm_base_url: None,
+            llm_cache_ttl_hours: None,
+            llm_cache_max_entries: None,
             daily_work_hours: None,
+            max_session_hours: None,
             normalize_hours: None,
+            normalize_weighting: None,
+            category_weights: None,
+            min_commit_lines: None,
+            include_coauthored: None,
+            fold_trivial_commit_hours: None,
+            report_push_url: None,
+            report_push_token: None,
+            exclude_paths: None,
+            notion_token: None,
+            notion_database_id: None,
+            db_backup_enabled: None,
+            db_backup_keep: None,
+            trash_retention_days: None,
+            llm_debug_log: None,
         };
 
         assert!(settings.jira_url.is_none());
@@ -565,6 +1209,11 @@ mod tests {
         assert!(validate_llm_provider("anthropic").is_ok());
     }
 
+    #[test]
+    fn test_validate_llm_provider_gemini() {
+        assert!(validate_llm_provider("gemini").is_ok());
+    }
+
     #[test]
     fn test_validate_llm_provider_ollama() {
         assert!(validate_llm_provider("ollama").is_ok());
@@ -650,6 +1299,34 @@ mod tests {
         assert!(parse_f64("").is_err());
     }
 
+    #[test]
+    fn test_validate_sync_intervals_valid() {
+        for interval in [5, 15, 30, 60] {
+            assert!(validate_sync_intervals(Some(interval), None).is_ok());
+        }
+        for compaction in [30, 60, 180, 360, 720, 1440] {
+            assert!(validate_sync_intervals(None, Some(compaction)).is_ok());
+        }
+        assert!(validate_sync_intervals(None, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_sync_intervals_out_of_range() {
+        let err = validate_sync_intervals(Some(10), None).unwrap_err().to_string();
+        assert!(err.contains("sync interval"));
+        assert!(err.contains("5, 15, 30, 60"));
+
+        let err = validate_sync_intervals(Some(15), Some(90)).unwrap_err().to_string();
+        assert!(err.contains("compaction interval"));
+    }
+
+    #[test]
+    fn test_parse_i64() {
+        assert_eq!(parse_i64("5").unwrap(), 5);
+        assert_eq!(parse_i64("0").unwrap(), 0);
+        assert!(parse_i64("2.5").unwrap_err().to_string().contains("Invalid integer"));
+    }
+
     // ========================================================================
     // Config Row Tests for New Fields
     // ========================================================================
@@ -703,10 +1380,110 @@ mod tests {
 
     #[test]
     fn test_valid_llm_providers_count() {
-        assert_eq!(VALID_LLM_PROVIDERS.len(), 4);
+        assert_eq!(VALID_LLM_PROVIDERS.len(), 5);
         assert!(VALID_LLM_PROVIDERS.contains(&"openai"));
         assert!(VALID_LLM_PROVIDERS.contains(&"anthropic"));
+        assert!(VALID_LLM_PROVIDERS.contains(&"gemini"));
         assert!(VALID_LLM_PROVIDERS.contains(&"ollama"));
         assert!(VALID_LLM_PROVIDERS.contains(&"openai-compatible"));
     }
+
+    /// Settings with nothing inconsistent, to break one field at a time
+    fn consistent_settings() -> (ValidationSettings, ConfiguredSources) {
+        let settings = ValidationSettings {
+            jira_url: Some("https://jira.example.com".to_string()),
+            jira_pat: Some("token".to_string()),
+            tempo_token: Some("tempo".to_string()),
+            llm_provider: Some("openai".to_string()),
+            llm_api_key: Some("sk-123".to_string()),
+            ..Default::default()
+        };
+        let sources = ConfiguredSources {
+            git_repos: 1,
+            ..Default::default()
+        };
+        (settings, sources)
+    }
+
+    fn issue_keys(settings: &ValidationSettings, sources: &ConfiguredSources) -> Vec<(IssueLevel, String)> {
+        find_config_issues(settings, sources)
+            .into_iter()
+            .map(|i| (i.level, i.key))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_consistent_settings_have_no_issues() {
+        let (settings, sources) = consistent_settings();
+        assert!(find_config_issues(&settings, &sources).is_empty());
+    }
+
+    #[test]
+    fn test_validate_jira_and_tempo_inconsistencies() {
+        let (mut settings, sources) = consistent_settings();
+        settings.jira_url = None;
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Error, "tempo_token".to_string())]);
+
+        let (mut settings, sources) = consistent_settings();
+        settings.jira_pat = Some(" ".to_string());
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Error, "jira_pat".to_string())]);
+
+        let (mut settings, sources) = consistent_settings();
+        settings.jira_url = None;
+        settings.jira_pat = None;
+        settings.tempo_token = None;
+        settings.sync_jira = Some(true);
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Error, "sync_jira".to_string())]);
+    }
+
+    #[test]
+    fn test_validate_gitlab_sync_without_gitlab() {
+        let (mut settings, sources) = consistent_settings();
+        settings.sync_gitlab = Some(true);
+        settings.gitlab_url = Some("https://gitlab.example.com".to_string());
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Error, "sync_gitlab".to_string())]);
+    }
+
+    #[test]
+    fn test_validate_sync_without_sources() {
+        let (settings, _) = consistent_settings();
+        let issues = find_config_issues(&settings, &ConfiguredSources::default());
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].level, issues[0].key.as_str()), (IssueLevel::Warning, "sync"));
+        assert!(issues[0].hint.contains("recap source add git"));
+
+        // A Claude projects directory is a source when Claude sync is on
+        let sources = ConfiguredSources {
+            claude_projects_dir: true,
+            ..Default::default()
+        };
+        assert!(find_config_issues(&settings, &sources).is_empty());
+    }
+
+    #[test]
+    fn test_validate_summaries_without_llm_key() {
+        let (mut settings, sources) = consistent_settings();
+        settings.llm_api_key = None;
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Warning, "llm_api_key".to_string())]);
+
+        // Ollama needs no key, and summaries can be turned off
+        settings.llm_provider = Some("ollama".to_string());
+        assert!(find_config_issues(&settings, &sources).is_empty());
+        settings.llm_provider = None;
+        settings.auto_generate_summaries = Some(false);
+        assert!(find_config_issues(&settings, &sources).is_empty());
+    }
+
+    #[test]
+    fn test_validate_report_push_and_category_weights() {
+        let (mut settings, sources) = consistent_settings();
+        settings.report_push_token = Some("push".to_string());
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Warning, "report_push_token".to_string())]);
+
+        let (mut settings, sources) = consistent_settings();
+        settings.normalize_weighting = Some("category".to_string());
+        assert_eq!(issue_keys(&settings, &sources), vec![(IssueLevel::Warning, "category_weights".to_string())]);
+        settings.category_weights = Some("feature=2".to_string());
+        assert!(find_config_issues(&settings, &sources).is_empty());
+    }
 }
@@ -1,30 +1,29 @@
 //! Dashboard heatmap command
 //!
-//! Show daily hours heatmap data.
+//! Show daily hours heatmap data. With `--format json`, every day in the
+//! window is emitted with a 0–4 intensity level for web frontends.
 
 use anyhow::Result;
-use chrono::{Datelike, Duration};
+use chrono::{Datelike, Duration, NaiveDate};
 use std::collections::HashMap;
 
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
 use crate::commands::Context;
+use crate::output::OutputFormat;
 use super::helpers::get_default_user_id;
-use super::types::HeatmapRow;
+use super::types::{HeatmapDay, HeatmapRow};
+
+/// Highest heatmap intensity level
+const MAX_LEVEL: u8 = 4;
 
 pub async fn show_heatmap(ctx: &Context, weeks: u32) -> Result<()> {
-    let today = chrono::Local::now().date_naive();
+    let today = user_today(ctx).await;
     let start_date = today - Duration::days((weeks * 7) as i64);
 
     let user_id = get_default_user_id(&ctx.db).await?;
 
     // Query daily hours
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?"
-    )
-    .bind(&user_id)
-    .bind(start_date.to_string())
-    .bind(today.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let items = fetch_items_in_range(ctx, Some(&user_id), start_date, today).await?;
 
     // Aggregate by date
     let mut daily_map: HashMap<String, (f64, i64)> = HashMap::new();
@@ -34,6 +33,12 @@ pub async fn show_heatmap(ctx: &Context, weeks: u32) -> Result<()> {
         entry.1 += 1;
     }
 
+    if ctx.format == OutputFormat::Json {
+        let days = heatmap_days(&daily_map, start_date, today);
+        println!("{}", serde_json::to_string_pretty(&days)?);
+        return Ok(());
+    }
+
     println!("╔══════════════════════════════════════════════════════════════╗");
     println!("║  工作熱力圖 (過去 {} 週)", weeks);
     println!("╚══════════════════════════════════════════════════════════════╝");
@@ -104,3 +109,79 @@ pub async fn show_heatmap(ctx: &Context, weeks: u32) -> Result<()> {
 
     Ok(())
 }
+
+/// Every day from `start` to `end`, zero-filled, with levels bucketed
+/// against the busiest day of each Monday-to-Sunday week
+pub fn heatmap_days(daily_map: &HashMap<String, (f64, i64)>, start: NaiveDate, end: NaiveDate) -> Vec<HeatmapDay> {
+    let mut days: Vec<(NaiveDate, f64, i64)> = Vec::new();
+    let mut current = start;
+    while current <= end {
+        let (hours, count) = daily_map.get(&current.to_string()).cloned().unwrap_or((0.0, 0));
+        days.push((current, hours, count));
+        current += Duration::days(1);
+    }
+
+    let mut week_max: HashMap<NaiveDate, f64> = HashMap::new();
+    for (date, hours, _) in &days {
+        let max = week_max.entry(week_of(*date)).or_insert(0.0);
+        *max = max.max(*hours);
+    }
+
+    days.into_iter()
+        .map(|(date, hours, count)| HeatmapDay {
+            date: date.to_string(),
+            hours,
+            count,
+            level: heat_level(hours, week_max[&week_of(date)]),
+        })
+        .collect()
+}
+
+fn week_of(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// 0 for no hours, otherwise 1–4 by quarters of `max`
+fn heat_level(hours: f64, max: f64) -> u8 {
+    if hours <= 0.0 || max <= 0.0 {
+        return 0;
+    }
+    ((hours / max * MAX_LEVEL as f64).ceil() as u8).clamp(1, MAX_LEVEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_heatmap_days_fills_gaps_with_zero() {
+        let daily: HashMap<String, (f64, i64)> = [("2026-03-03".to_string(), (2.0, 1))].into_iter().collect();
+        let days = heatmap_days(&daily, date("2026-03-02"), date("2026-03-04"));
+
+        let dates: Vec<&str> = days.iter().map(|d| d.date.as_str()).collect();
+        assert_eq!(dates, vec!["2026-03-02", "2026-03-03", "2026-03-04"]);
+        assert_eq!(days[0], HeatmapDay { date: "2026-03-02".to_string(), hours: 0.0, count: 0, level: 0 });
+        assert_eq!(days[1].level, MAX_LEVEL);
+    }
+
+    #[test]
+    fn test_levels_are_relative_to_each_week() {
+        let daily: HashMap<String, (f64, i64)> = [
+            ("2026-03-02".to_string(), (8.0, 3)),
+            ("2026-03-03".to_string(), (2.0, 1)),
+            ("2026-03-04".to_string(), (5.0, 2)),
+            // Next week: a light day is still that week's busiest
+            ("2026-03-09".to_string(), (1.0, 1)),
+        ]
+        .into_iter()
+        .collect();
+        let days = heatmap_days(&daily, date("2026-03-02"), date("2026-03-09"));
+
+        let levels: Vec<u8> = days.iter().map(|d| d.level).collect();
+        assert_eq!(levels, vec![4, 1, 3, 0, 0, 0, 0, 4]);
+    }
+}
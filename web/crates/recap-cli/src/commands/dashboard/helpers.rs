@@ -3,18 +3,11 @@
 //! Shared utilities for dashboard commands.
 
 use anyhow::Result;
-use chrono::{Duration, NaiveDate};
+use chrono::NaiveDate;
 
-/// Parse a date string into NaiveDate
-pub fn parse_date(s: &str) -> Result<NaiveDate> {
-    if s == "today" {
-        return Ok(chrono::Local::now().date_naive());
-    }
-    if s == "yesterday" {
-        return Ok(chrono::Local::now().date_naive() - Duration::days(1));
-    }
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|_| anyhow::anyhow!("Invalid date format: {}. Use YYYY-MM-DD", s))
+/// Parse a date string into NaiveDate, resolving keywords relative to `today`
+pub fn parse_date(s: &str, today: NaiveDate) -> Result<NaiveDate> {
+    recap_core::parse_date_arg(s, today).map_err(anyhow::Error::msg)
 }
 
 /// Extract project name from title with [project] format
@@ -78,7 +71,7 @@ mod tests {
 
     #[test]
     fn test_parse_date_valid() {
-        let date = parse_date("2025-01-15").unwrap();
+        let date = parse_date("2025-01-15", chrono::Local::now().date_naive()).unwrap();
         assert_eq!(date.year(), 2025);
         assert_eq!(date.month(), 1);
         assert_eq!(date.day(), 15);
@@ -87,21 +80,21 @@ mod tests {
     #[test]
     fn test_parse_date_today() {
         let today = chrono::Local::now().date_naive();
-        let parsed = parse_date("today").unwrap();
+        let parsed = parse_date("today", today).unwrap();
         assert_eq!(parsed, today);
     }
 
     #[test]
     fn test_parse_date_yesterday() {
-        let yesterday = chrono::Local::now().date_naive() - Duration::days(1);
-        let parsed = parse_date("yesterday").unwrap();
+        let yesterday = chrono::Local::now().date_naive() - chrono::Duration::days(1);
+        let parsed = parse_date("yesterday", chrono::Local::now().date_naive()).unwrap();
         assert_eq!(parsed, yesterday);
     }
 
     #[test]
     fn test_parse_date_invalid() {
-        assert!(parse_date("invalid").is_err());
-        assert!(parse_date("2025/01/15").is_err());
+        assert!(parse_date("invalid", chrono::Local::now().date_naive()).is_err());
+        assert!(parse_date("2025/01/15", chrono::Local::now().date_naive()).is_err());
     }
 
     #[test]
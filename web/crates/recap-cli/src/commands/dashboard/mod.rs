@@ -14,16 +14,17 @@ use anyhow::Result;
 use crate::commands::Context;
 
 // Re-export public types
-pub use types::{DashboardAction, HeatmapRow, ProjectRow, SourceRow, StatsRow, TimelineRow};
+pub use types::{DashboardAction, HeatmapDay, HeatmapRow, ProjectRow, SourceRow, StatsRow, TimelineRow};
 
 pub async fn execute(ctx: &Context, action: DashboardAction) -> Result<()> {
     match action {
         DashboardAction::Stats { start, end, week, month } => {
             stats::show_stats(ctx, start, end, week, month).await
         }
-        DashboardAction::Timeline { date } => {
-            timeline::show_timeline(ctx, date).await
-        }
+        DashboardAction::Timeline { date, ics } => match ics {
+            Some(path) => timeline::export_timeline_ics(ctx, date, &path).await,
+            None => timeline::show_timeline(ctx, date).await,
+        },
         DashboardAction::Heatmap { weeks } => {
             heatmap::show_heatmap(ctx, weeks).await
         }
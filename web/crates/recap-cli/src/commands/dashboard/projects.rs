@@ -6,15 +6,16 @@ use anyhow::Result;
 use chrono::{Datelike, Duration};
 use std::collections::HashMap;
 
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
 use crate::commands::Context;
 use crate::output::print_info;
 use super::helpers::{clean_title, extract_project_name, get_default_user_id, parse_date, truncate};
 
 pub async fn show_projects(ctx: &Context, start: Option<String>, end: Option<String>) -> Result<()> {
-    let today = chrono::Local::now().date_naive();
+    let today = user_today(ctx).await;
 
     let (start_date, end_date) = if let (Some(s), Some(e)) = (start, end) {
-        (parse_date(&s)?, parse_date(&e)?)
+        (parse_date(&s, today)?, parse_date(&e, today)?)
     } else {
         // Default: this week
         let weekday = today.weekday().num_days_from_monday();
@@ -25,14 +26,7 @@ pub async fn show_projects(ctx: &Context, start: Option<String>, end: Option<Str
 
     let user_id = get_default_user_id(&ctx.db).await?;
 
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?"
-    )
-    .bind(&user_id)
-    .bind(start_date.to_string())
-    .bind(end_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let items = fetch_items_in_range(ctx, Some(&user_id), start_date, end_date).await?;
 
     if items.is_empty() {
         print_info(&format!("沒有 {} ~ {} 的工作記錄", start_date, end_date), ctx.quiet);
@@ -3,9 +3,10 @@
 //! Show statistics summary for work items.
 
 use anyhow::Result;
-use chrono::{Datelike, Duration, NaiveDate};
+use recap_core::services::{period_bounds, PeriodUnit};
 use std::collections::HashMap;
 
+use crate::commands::work::helpers::{fetch_items_in_range, get_week_start_day, user_today};
 use crate::commands::Context;
 use crate::output::print_output;
 use super::helpers::{extract_project_name, get_default_user_id, parse_date, truncate};
@@ -18,39 +19,22 @@ pub async fn show_stats(
     _week: bool,
     month: bool,
 ) -> Result<()> {
-    let today = chrono::Local::now().date_naive();
+    let today = user_today(ctx).await;
 
     let (start_date, end_date) = if month {
-        // This month
-        let start = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
-        let end = if today.month() == 12 {
-            NaiveDate::from_ymd_opt(today.year() + 1, 1, 1).unwrap() - Duration::days(1)
-        } else {
-            NaiveDate::from_ymd_opt(today.year(), today.month() + 1, 1).unwrap() - Duration::days(1)
-        };
-        (start, end)
+        period_bounds(today, PeriodUnit::Month, 0)
     } else if let (Some(s), Some(e)) = (start, end) {
-        (parse_date(&s)?, parse_date(&e)?)
+        (parse_date(&s, today)?, parse_date(&e, today)?)
     } else {
-        // Default: this week (Monday to Sunday)
-        let weekday = today.weekday().num_days_from_monday();
-        let start = today - Duration::days(weekday as i64);
-        let end = start + Duration::days(6);
-        (start, end)
+        // Default: this week, starting on the user's week_start_day
+        period_bounds(today, PeriodUnit::Week, get_week_start_day(&ctx.db).await)
     };
 
     // Get user_id
     let user_id = get_default_user_id(&ctx.db).await?;
 
     // Query work items
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?"
-    )
-    .bind(&user_id)
-    .bind(start_date.to_string())
-    .bind(end_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let items = fetch_items_in_range(ctx, Some(&user_id), start_date, end_date).await?;
 
     let total_items = items.len() as i64;
     let total_hours: f64 = items.iter().map(|i| i.hours).sum();
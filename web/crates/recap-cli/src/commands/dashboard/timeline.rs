@@ -1,32 +1,28 @@
 //! Dashboard timeline command
 //!
-//! Show work timeline for a specific date.
+//! Show work timeline for a specific date, or export it as an .ics calendar.
 
 use anyhow::Result;
+use recap_core::services::{build_ics, CalendarEvent};
+use std::path::Path;
 
+use crate::commands::work::helpers::{fetch_items_in_range, parse_user_date, user_timezone, user_today};
 use crate::commands::Context;
-use crate::output::{print_info, print_output};
-use super::helpers::{clean_title, extract_project_name, get_default_user_id, parse_date, truncate};
+use crate::output::{print_info, print_output, print_success};
+use super::helpers::{clean_title, extract_project_name, get_default_user_id, truncate};
 use super::types::TimelineRow;
 
 pub async fn show_timeline(ctx: &Context, date: Option<String>) -> Result<()> {
     let target_date = match date {
-        Some(d) => parse_date(&d)?,
-        None => chrono::Local::now().date_naive(),
+        Some(d) => parse_user_date(ctx, &d).await?,
+        None => user_today(ctx).await,
     };
 
     let user_id = get_default_user_id(&ctx.db).await?;
 
-    // Query work items for the date (claude_code source has timing info)
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        r#"SELECT * FROM work_items
-           WHERE user_id = ? AND date = ?
-           ORDER BY start_time ASC, created_at ASC"#
-    )
-    .bind(&user_id)
-    .bind(target_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    // Work items on the date in the user's timezone (claude_code source has timing info)
+    let mut items = fetch_items_in_range(ctx, Some(&user_id), target_date, target_date).await?;
+    items.sort_by(|a, b| a.start_time.cmp(&b.start_time).then_with(|| a.created_at.cmp(&b.created_at)));
 
     if items.is_empty() {
         print_info(&format!("沒有 {} 的工作記錄", target_date), ctx.quiet);
@@ -98,3 +94,46 @@ pub async fn show_timeline(ctx: &Context, date: Option<String>) -> Result<()> {
 
     Ok(())
 }
+
+/// Write the date's timed sessions to `path` as an iCalendar file.
+///
+/// Items without both a start and an end time are skipped. Event UIDs come
+/// from the session ID (item ID for non-session items), so re-exporting a
+/// day updates the events imported before.
+pub async fn export_timeline_ics(ctx: &Context, date: Option<String>, path: &Path) -> Result<()> {
+    let target_date = match date {
+        Some(d) => parse_user_date(ctx, &d).await?,
+        None => user_today(ctx).await,
+    };
+
+    let user_id = get_default_user_id(&ctx.db).await?;
+    let timezone = user_timezone(ctx).await;
+    let mut items = fetch_items_in_range(ctx, Some(&user_id), target_date, target_date).await?;
+    items.sort_by(|a, b| a.start_time.cmp(&b.start_time).then_with(|| a.created_at.cmp(&b.created_at)));
+
+    let events: Vec<CalendarEvent> = items
+        .iter()
+        .filter_map(|item| {
+            // `[project] title`, falling back to the project directory name
+            let project = item.title.starts_with('[').then(|| extract_project_name(&item.title)).or_else(|| {
+                let path = Path::new(item.project_path.as_deref()?);
+                Some(path.file_name()?.to_string_lossy().into_owned())
+            });
+            CalendarEvent::from_times(
+                item.session_id.as_deref().unwrap_or(&item.id),
+                &clean_title(&item.title),
+                project.as_deref(),
+                item.start_time.as_deref()?,
+                item.end_time.as_deref()?,
+                timezone.as_deref(),
+            )
+        })
+        .collect();
+
+    std::fs::write(path, build_ics(&events, chrono::Utc::now()))?;
+    print_success(
+        &format!("Exported {} session(s) on {} to {}", events.len(), target_date, path.display()),
+        ctx.quiet,
+    );
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 use clap::Subcommand;
 use serde::Serialize;
+use std::path::PathBuf;
 use tabled::Tabled;
 
 #[derive(Subcommand)]
@@ -29,9 +30,13 @@ pub enum DashboardAction {
 
     /// Show work timeline for a specific date
     Timeline {
-        /// Date to show (YYYY-MM-DD), defaults to today
+        /// Date to show (YYYY-MM-DD, today, yesterday), defaults to today
         #[arg(short, long)]
         date: Option<String>,
+
+        /// Write the timed sessions to this .ics calendar file instead of printing
+        #[arg(long, value_name = "FILE")]
+        ics: Option<PathBuf>,
     },
 
     /// Show daily hours heatmap data
@@ -111,6 +116,16 @@ pub struct HeatmapRow {
     pub visual: String,
 }
 
+/// One day of the heatmap for JSON output
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HeatmapDay {
+    pub date: String,
+    pub hours: f64,
+    pub count: i64,
+    /// Intensity 0–4 relative to the busiest day of its week
+    pub level: u8,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,196 @@
+//! Database commands
+//!
+//! Manual backups and restores of the SQLite database. Backups are
+//! `VACUUM INTO` snapshots, the same format the background service writes
+//! when `db_backup_enabled` is set. `repair-hashes` recomputes dedup hashes
+//! after the hashing algorithm changes.
+
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+use std::path::PathBuf;
+use tabled::Tabled;
+
+use crate::output::{print_info, print_output, print_success, write_csv, OutputFormat};
+use super::Context;
+
+#[derive(Subcommand)]
+pub enum DbAction {
+    /// Snapshot the database (default: ~/.recap/backups)
+    Backup {
+        /// Directory to write the backup to
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+
+    /// Replace the database with a backup
+    Restore {
+        /// Backup file to restore
+        file: PathBuf,
+    },
+
+    /// Recompute work item content hashes with the current algorithm
+    RepairHashes {
+        /// Only report what would change (the default)
+        #[arg(long, conflicts_with = "apply")]
+        dry_run: bool,
+
+        /// Write the new hashes and flag collisions
+        #[arg(long)]
+        apply: bool,
+    },
+}
+
+/// Result of a backup or restore (JSON/CSV output)
+#[derive(Debug, Serialize)]
+pub struct DbBackupResult {
+    /// Backup file that was written or restored
+    pub backup: String,
+    /// Database file that was restored over (restore only)
+    pub database: Option<String>,
+}
+
+/// Result of `repair-hashes` (JSON output)
+#[derive(Debug, Serialize)]
+pub struct HashRepairResult {
+    pub applied: bool,
+    #[serde(flatten)]
+    pub plan: recap_core::services::HashRepairPlan,
+}
+
+/// One affected item of `repair-hashes`
+#[derive(Debug, Serialize, Tabled)]
+pub struct HashRepairRow {
+    #[tabled(rename = "Item")]
+    pub item_id: String,
+    #[tabled(rename = "Action")]
+    pub action: String,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+pub async fn execute(ctx: &Context, action: DbAction) -> Result<()> {
+    match action {
+        DbAction::Backup { dir } => backup(ctx, dir).await,
+        DbAction::Restore { file } => restore(ctx, file).await,
+        DbAction::RepairHashes { dry_run: _, apply } => repair_hashes(ctx, apply).await,
+    }
+}
+
+async fn backup(ctx: &Context, dir: Option<PathBuf>) -> Result<()> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => recap_core::services::default_backup_dir()
+            .ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?,
+    };
+
+    let path = recap_core::services::backup_database(&ctx.db.pool, &dir)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let result = DbBackupResult {
+        backup: path.display().to_string(),
+        database: None,
+    };
+    print_result(ctx, &result, &format!("Backed up database to {}", result.backup))
+}
+
+async fn restore(ctx: &Context, file: PathBuf) -> Result<()> {
+    recap_core::services::verify_backup(&file)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    // Keep a copy of the current data in case the wrong backup was picked
+    if let Some(dir) = recap_core::services::default_backup_dir() {
+        let current = recap_core::services::backup_database(&ctx.db.pool, &dir)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        print_info(&format!("Saved current database to {}", current.display()), ctx.quiet);
+    }
+
+    let db_path = recap_core::db::get_db_path()?;
+    ctx.db.pool.close().await;
+    recap_core::services::restore_database(&file, &db_path)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let result = DbBackupResult {
+        backup: file.display().to_string(),
+        database: Some(db_path.display().to_string()),
+    };
+    print_result(
+        ctx,
+        &result,
+        &format!("Restored {} from {}", db_path.display(), file.display()),
+    )
+}
+
+pub(crate) async fn repair_hashes(ctx: &Context, apply: bool) -> Result<()> {
+    let plan = if apply {
+        recap_core::services::apply_hash_repair(&ctx.db.pool).await
+    } else {
+        recap_core::services::plan_hash_repair(&ctx.db.pool).await
+    }
+    .map_err(|e| anyhow::anyhow!(e))?;
+
+    if ctx.format == OutputFormat::Json {
+        let result = HashRepairResult { applied: apply, plan };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    let rows = hash_repair_rows(&plan);
+    if ctx.format == OutputFormat::Csv {
+        return write_csv(&rows, std::io::stdout().lock());
+    }
+
+    if plan.is_clean() {
+        print_success(&format!("All {} content hashes are current", plan.checked), ctx.quiet);
+        return Ok(());
+    }
+    print_output(&rows, ctx.format)?;
+    let summary = format!(
+        "{} of {} hashes {}, {} collision(s) ({} duplicate item(s)) {}",
+        plan.updates.len(),
+        plan.checked,
+        if apply { "updated" } else { "would change" },
+        plan.collisions.len(),
+        plan.duplicate_count(),
+        if apply { "flagged" } else { "would be flagged" },
+    );
+    if apply {
+        print_success(&summary, ctx.quiet);
+    } else {
+        print_info(&format!("{} (dry run, use --apply to write)", summary), ctx.quiet);
+    }
+    Ok(())
+}
+
+fn hash_repair_rows(plan: &recap_core::services::HashRepairPlan) -> Vec<HashRepairRow> {
+    let updates = plan.updates.iter().map(|u| HashRepairRow {
+        item_id: u.id.clone(),
+        action: "rehash".to_string(),
+        detail: format!("{} -> {}", u.old_hash, u.new_hash),
+    });
+    let duplicates = plan.collisions.iter().flat_map(|c| {
+        c.duplicate_ids.iter().map(move |id| HashRepairRow {
+            item_id: id.clone(),
+            action: "flag".to_string(),
+            detail: format!(
+                "Duplicate of {}, tagged {}",
+                c.kept_id,
+                recap_core::services::HASH_COLLISION_TAG
+            ),
+        })
+    });
+    updates.chain(duplicates).collect()
+}
+
+fn print_result(ctx: &Context, result: &DbBackupResult, message: &str) -> Result<()> {
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(result)?),
+        OutputFormat::Csv => write_csv(std::slice::from_ref(result), std::io::stdout().lock())?,
+        OutputFormat::Table => print_success(message, ctx.quiet),
+    }
+    Ok(())
+}
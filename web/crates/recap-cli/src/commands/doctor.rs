@@ -0,0 +1,495 @@
+//! Doctor command
+//!
+//! Diagnoses common data problems and, with `--fix`, repairs them.
+//! Without `--fix` nothing is written: each check only reports what it would do.
+
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::path::Path;
+use tabled::Tabled;
+
+use crate::output::{print_error, print_info, print_output};
+use super::Context;
+
+/// Columns added by migrations that older databases may be missing
+const REQUIRED_COLUMNS: &[(&str, &str)] = &[
+    ("users", "username"),
+    ("users", "timezone"),
+    ("users", "llm_debug_log"),
+    ("users", "report_push_url"),
+    ("work_items", "parent_id"),
+    ("work_items", "project_path"),
+    ("work_items", "content_hash"),
+];
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Ok,
+    Issue,
+    Fixed,
+    Failed,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            CheckStatus::Ok => "OK",
+            CheckStatus::Issue => "Issue",
+            CheckStatus::Fixed => "Fixed",
+            CheckStatus::Failed => "Failed",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Check row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct DoctorRow {
+    #[tabled(rename = "Check")]
+    pub check: String,
+    #[tabled(rename = "Status")]
+    pub status: CheckStatus,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+impl DoctorRow {
+    fn new(check: &str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            check: check.to_string(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+pub async fn execute(ctx: &Context, fix: bool) -> Result<()> {
+    let db_path = recap_core::db::get_db_path()?;
+    let data_dir = db_path.parent().unwrap_or(Path::new("."));
+
+    let rows = vec![
+        check_data_dir(data_dir, fix)?,
+        check_schema(&ctx.db, fix).await?,
+        check_usernames(&ctx.db.pool, fix).await?,
+        check_duplicate_git_repos(&ctx.db.pool, fix).await?,
+        check_dangling_parents(&ctx.db.pool, fix).await?,
+    ];
+    print_output(&rows, ctx.format)?;
+
+    let issues = rows.iter().filter(|r| r.status == CheckStatus::Issue).count();
+    let failed = rows.iter().filter(|r| r.status == CheckStatus::Failed).count();
+    if issues > 0 {
+        print_info(
+            &format!("{} issue(s) found. Run `recap doctor --fix` to repair them.", issues),
+            ctx.quiet,
+        );
+    }
+    if failed > 0 {
+        print_error(&format!("{} fix(es) could not be applied", failed));
+    }
+
+    Ok(())
+}
+
+/// The data directory holding the database exists
+fn check_data_dir(data_dir: &Path, fix: bool) -> Result<DoctorRow> {
+    const CHECK: &str = "Data directory";
+    let dir = data_dir.display().to_string();
+
+    if data_dir.is_dir() {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Ok, dir));
+    }
+    if !fix {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Issue, format!("{} is missing, would create it", dir)));
+    }
+
+    match std::fs::create_dir_all(data_dir) {
+        Ok(()) => {
+            log::info!("doctor: created data directory {}", dir);
+            Ok(DoctorRow::new(CHECK, CheckStatus::Fixed, format!("Created {}", dir)))
+        }
+        Err(e) => Ok(DoctorRow::new(CHECK, CheckStatus::Failed, format!("Could not create {}: {}", dir, e))),
+    }
+}
+
+/// Every column added by migrations is present
+async fn check_schema(db: &recap_core::Database, fix: bool) -> Result<DoctorRow> {
+    const CHECK: &str = "Schema";
+
+    let missing = missing_columns(&db.pool).await?;
+    if missing.is_empty() {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Ok, "All migrations applied"));
+    }
+    if !fix {
+        return Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Issue,
+            format!("Missing {}, would re-run migrations", missing.join(", ")),
+        ));
+    }
+
+    db.run_migrations().await?;
+    log::info!("doctor: re-ran migrations for missing columns {}", missing.join(", "));
+
+    let still_missing = missing_columns(&db.pool).await?;
+    if still_missing.is_empty() {
+        Ok(DoctorRow::new(CHECK, CheckStatus::Fixed, format!("Restored {}", missing.join(", "))))
+    } else {
+        Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Failed,
+            format!("Still missing {}", still_missing.join(", ")),
+        ))
+    }
+}
+
+/// `table.column` entries of [`REQUIRED_COLUMNS`] that do not exist
+async fn missing_columns(pool: &SqlitePool) -> Result<Vec<String>> {
+    let mut missing = Vec::new();
+    for (table, column) in REQUIRED_COLUMNS {
+        let found: Option<(String,)> =
+            sqlx::query_as("SELECT name FROM pragma_table_info(?) WHERE name = ?")
+                .bind(table)
+                .bind(column)
+                .fetch_optional(pool)
+                .await?;
+        if found.is_none() {
+            missing.push(format!("{}.{}", table, column));
+        }
+    }
+    Ok(missing)
+}
+
+/// Every user has a username (defaults to the display name)
+async fn check_usernames(pool: &SqlitePool, fix: bool) -> Result<DoctorRow> {
+    const CHECK: &str = "Usernames";
+
+    let users: Vec<(String, String)> =
+        sqlx::query_as("SELECT id, name FROM users WHERE username IS NULL")
+            .fetch_all(pool)
+            .await?;
+    if users.is_empty() {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Ok, "All users have a username"));
+    }
+    if !fix {
+        return Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Issue,
+            format!("{} user(s) without a username, would set username = name", users.len()),
+        ));
+    }
+
+    // Row by row: the unique index rejects a name already taken as a username
+    let mut failed = Vec::new();
+    for (id, name) in &users {
+        let result = sqlx::query("UPDATE users SET username = ? WHERE id = ?")
+            .bind(name)
+            .bind(id)
+            .execute(pool)
+            .await;
+        match result {
+            Ok(_) => log::info!("doctor: set username of user {} to '{}'", id, name),
+            Err(e) => {
+                log::warn!("doctor: could not set username of user {}: {}", id, e);
+                failed.push(name.clone());
+            }
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(DoctorRow::new(CHECK, CheckStatus::Fixed, format!("Set username for {} user(s)", users.len())))
+    } else {
+        Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Failed,
+            format!("Username already taken: {}", failed.join(", ")),
+        ))
+    }
+}
+
+/// No git repo is configured twice under different spellings of its path
+async fn check_duplicate_git_repos(pool: &SqlitePool, fix: bool) -> Result<DoctorRow> {
+    const CHECK: &str = "Git repo paths";
+
+    let repos: Vec<(String, String, String, bool)> = sqlx::query_as(
+        "SELECT id, user_id, path, enabled FROM git_repos ORDER BY created_at, id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut groups: HashMap<(String, String), Vec<RepoEntry>> = HashMap::new();
+    for (id, user_id, path, enabled) in repos {
+        groups
+            .entry((user_id, canonical_repo_path(&path)))
+            .or_default()
+            .push((id, path, enabled));
+    }
+    let mut duplicates: Vec<_> = groups.into_iter().filter(|(_, rows)| rows.len() > 1).collect();
+    duplicates.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if duplicates.is_empty() {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Ok, "No duplicate repos"));
+    }
+    let paths: Vec<&str> = duplicates.iter().map(|((_, canonical), _)| canonical.as_str()).collect();
+    if !fix {
+        return Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Issue,
+            format!("Duplicates of {}, would merge into one entry each", paths.join(", ")),
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+    for ((_, canonical), rows) in &duplicates {
+        // Keep the oldest entry; it stays enabled if any copy was
+        let (keep_id, _, _) = &rows[0];
+        let enabled = rows.iter().any(|(_, _, enabled)| *enabled);
+        for (id, path, _) in &rows[1..] {
+            sqlx::query("DELETE FROM git_repos WHERE id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            log::info!("doctor: removed duplicate git repo {} ({})", id, path);
+        }
+        sqlx::query("UPDATE git_repos SET path = ?, enabled = ? WHERE id = ?")
+            .bind(canonical)
+            .bind(enabled)
+            .bind(keep_id)
+            .execute(&mut *tx)
+            .await?;
+        log::info!("doctor: canonicalized git repo {} to {}", keep_id, canonical);
+    }
+    tx.commit().await?;
+
+    Ok(DoctorRow::new(CHECK, CheckStatus::Fixed, format!("Merged duplicates of {}", paths.join(", "))))
+}
+
+/// A `git_repos` row as `(id, path, enabled)`
+type RepoEntry = (String, String, bool);
+
+/// Canonical form of a repo path: symlinks resolved, no trailing slash
+fn canonical_repo_path(path: &str) -> String {
+    let trimmed = match path.trim_end_matches('/') {
+        "" => "/",
+        p => p,
+    };
+    std::fs::canonicalize(trimmed)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| trimmed.to_string())
+}
+
+/// No work item points at a parent that no longer exists
+async fn check_dangling_parents(pool: &SqlitePool, fix: bool) -> Result<DoctorRow> {
+    const CHECK: &str = "Parent links";
+    const DANGLING: &str = "parent_id IS NOT NULL
+        AND NOT EXISTS (SELECT 1 FROM work_items p WHERE p.id = work_items.parent_id)";
+
+    let (count,): (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM work_items WHERE {}", DANGLING))
+        .fetch_one(pool)
+        .await?;
+    if count == 0 {
+        return Ok(DoctorRow::new(CHECK, CheckStatus::Ok, "No dangling parent_id"));
+    }
+    if !fix {
+        return Ok(DoctorRow::new(
+            CHECK,
+            CheckStatus::Issue,
+            format!("{} item(s) with a missing parent, would clear parent_id", count),
+        ));
+    }
+
+    let result = sqlx::query(&format!("UPDATE work_items SET parent_id = NULL WHERE {}", DANGLING))
+        .execute(pool)
+        .await?;
+    log::info!("doctor: cleared {} dangling parent_id(s)", result.rows_affected());
+
+    Ok(DoctorRow::new(
+        CHECK,
+        CheckStatus::Fixed,
+        format!("Cleared parent_id on {} item(s)", result.rows_affected()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::test_support::{db_with_user, insert_user};
+    use tempfile::TempDir;
+
+    async fn insert_repo(pool: &SqlitePool, id: &str, path: &str, enabled: bool, created_at: &str) {
+        sqlx::query("INSERT INTO git_repos (id, user_id, path, name, enabled, created_at) VALUES (?, 'u1', ?, 'repo', ?, ?)")
+            .bind(id)
+            .bind(path)
+            .bind(enabled)
+            .bind(created_at)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_check_data_dir_creates_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("recap-data");
+
+        let row = check_data_dir(&data_dir, false).unwrap();
+        assert_eq!(row.status, CheckStatus::Issue);
+        assert!(!data_dir.exists());
+
+        let row = check_data_dir(&data_dir, true).unwrap();
+        assert_eq!(row.status, CheckStatus::Fixed);
+        assert!(data_dir.is_dir());
+
+        assert_eq!(check_data_dir(&data_dir, false).unwrap().status, CheckStatus::Ok);
+    }
+
+    #[tokio::test]
+    async fn test_check_schema_reruns_migrations() {
+        let (db, dir) = db_with_user().await;
+        db.pool.close().await;
+
+        // Simulate a database from before the llm_debug_log migration, on a
+        // single connection so no pooled connection holds the old schema
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(&format!("sqlite:{}", dir.path().join("test.db").display()))
+            .await
+            .unwrap();
+        sqlx::query("ALTER TABLE users DROP COLUMN llm_debug_log")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let db = recap_core::Database { pool };
+
+        let row = check_schema(&db, false).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Issue);
+        assert!(row.detail.contains("users.llm_debug_log"));
+        assert_eq!(missing_columns(&db.pool).await.unwrap(), vec!["users.llm_debug_log"]);
+
+        let row = check_schema(&db, true).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Fixed);
+        assert!(missing_columns(&db.pool).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_usernames_sets_username_from_name() {
+        let (db, _dir) = db_with_user().await;
+        sqlx::query("UPDATE users SET username = NULL")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let row = check_usernames(&db.pool, false).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Issue);
+        let (username,): (Option<String>,) = sqlx::query_as("SELECT username FROM users WHERE id = 'u1'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(username, None);
+
+        let row = check_usernames(&db.pool, true).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Fixed);
+        let (username,): (Option<String>,) = sqlx::query_as("SELECT username FROM users WHERE id = 'u1'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(username.as_deref(), Some("Test"));
+    }
+
+    #[tokio::test]
+    async fn test_check_usernames_reports_taken_name() {
+        let (db, _dir) = db_with_user().await;
+        insert_user(&db, "u2").await;
+        sqlx::query("UPDATE users SET username = CASE id WHEN 'u2' THEN 'Test' END")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let row = check_usernames(&db.pool, true).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Failed);
+        assert!(row.detail.contains("Test"));
+    }
+
+    #[tokio::test]
+    async fn test_check_duplicate_git_repos_merges_into_canonical_path() {
+        let (db, _dir) = db_with_user().await;
+        let repo_dir = TempDir::new().unwrap();
+        let canonical = std::fs::canonicalize(repo_dir.path()).unwrap().to_string_lossy().to_string();
+        insert_repo(&db.pool, "r1", &format!("{}/", canonical), false, "2026-01-01T00:00:00Z").await;
+        insert_repo(&db.pool, "r2", &canonical, true, "2026-01-02T00:00:00Z").await;
+        insert_repo(&db.pool, "r3", "/elsewhere/other", true, "2026-01-03T00:00:00Z").await;
+
+        let row = check_duplicate_git_repos(&db.pool, false).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Issue);
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM git_repos")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+
+        let row = check_duplicate_git_repos(&db.pool, true).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Fixed);
+        let repos: Vec<(String, String, bool)> = sqlx::query_as("SELECT id, path, enabled FROM git_repos ORDER BY id")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            repos,
+            vec![
+                ("r1".to_string(), canonical, true),
+                ("r3".to_string(), "/elsewhere/other".to_string(), true),
+            ]
+        );
+
+        assert_eq!(check_duplicate_git_repos(&db.pool, false).await.unwrap().status, CheckStatus::Ok);
+    }
+
+    #[tokio::test]
+    async fn test_check_dangling_parents_clears_missing_parent() {
+        let (db, _dir) = db_with_user().await;
+        let now = chrono::Utc::now();
+        // The orphan can only be written with foreign keys off, as after a bad delete
+        let mut conn = db.pool.acquire().await.unwrap();
+        sqlx::query("PRAGMA foreign_keys = OFF").execute(&mut *conn).await.unwrap();
+        for (id, parent_id) in [("parent", None), ("child", Some("parent")), ("orphan", Some("deleted"))] {
+            sqlx::query(
+                "INSERT INTO work_items (id, user_id, source, title, hours, date, parent_id, created_at, updated_at)
+                 VALUES (?, 'u1', 'manual', ?, 1.0, '2026-01-15', ?, ?, ?)"
+            )
+            .bind(id)
+            .bind(id)
+            .bind(parent_id)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        }
+        sqlx::query("PRAGMA foreign_keys = ON").execute(&mut *conn).await.unwrap();
+        drop(conn);
+
+        let row = check_dangling_parents(&db.pool, false).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Issue);
+        assert!(row.detail.starts_with("1 item(s)"));
+
+        let row = check_dangling_parents(&db.pool, true).await.unwrap();
+        assert_eq!(row.status, CheckStatus::Fixed);
+        let parents: Vec<(String, Option<String>)> =
+            sqlx::query_as("SELECT id, parent_id FROM work_items ORDER BY id")
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            parents,
+            vec![
+                ("child".to_string(), Some("parent".to_string())),
+                ("orphan".to_string(), None),
+                ("parent".to_string(), None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! LLM commands
+//!
+//! Browse the LLM usage log written by summaries and compaction, with the
+//! estimated spend of the filtered window.
+
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::output::{print_info, print_output, OutputFormat};
+use super::work::helpers::{get_or_create_default_user, parse_user_date};
+use super::Context;
+
+#[derive(Subcommand)]
+pub enum LlmAction {
+    /// List LLM calls, newest first
+    Logs {
+        /// Only calls on or after this date (YYYY-MM-DD, today, yesterday)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Only calls on or before this date (YYYY-MM-DD, today, yesterday)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Only calls to this provider (e.g. openai, anthropic, ollama)
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Only calls made for this purpose (e.g. project_summary)
+        #[arg(long)]
+        purpose: Option<String>,
+
+        /// Page number, starting at 1
+        #[arg(long, default_value = "1")]
+        page: i64,
+
+        /// Logs per page (at most 100)
+        #[arg(long, default_value = "50")]
+        per_page: i64,
+    },
+}
+
+/// One LLM call for table/CSV output
+#[derive(Debug, Serialize, Tabled)]
+pub struct LlmUsageLogRow {
+    #[tabled(rename = "Time")]
+    pub created_at: String,
+    #[tabled(rename = "Provider")]
+    pub provider: String,
+    #[tabled(rename = "Model")]
+    pub model: String,
+    #[tabled(rename = "Purpose")]
+    pub purpose: String,
+    #[tabled(rename = "Tokens")]
+    pub tokens: String,
+    #[tabled(rename = "Cost")]
+    pub cost: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+}
+
+impl From<recap_core::services::LlmUsageLog> for LlmUsageLogRow {
+    fn from(log: recap_core::services::LlmUsageLog) -> Self {
+        Self {
+            created_at: log.created_at,
+            provider: log.provider,
+            model: log.model,
+            purpose: log.purpose,
+            tokens: log.total_tokens.map_or_else(|| "-".to_string(), |t| t.to_string()),
+            cost: log.estimated_cost.map_or_else(|| "-".to_string(), |c| format!("${:.4}", c)),
+            status: log.status,
+        }
+    }
+}
+
+pub async fn execute(ctx: &Context, action: LlmAction) -> Result<()> {
+    match action {
+        LlmAction::Logs { start, end, provider, purpose, page, per_page } => {
+            let query = recap_core::services::LlmUsageLogQuery {
+                start_date: match start {
+                    Some(s) => Some(parse_user_date(ctx, &s).await?.to_string()),
+                    None => None,
+                },
+                end_date: match end {
+                    Some(e) => Some(parse_user_date(ctx, &e).await?.to_string()),
+                    None => None,
+                },
+                provider,
+                purpose,
+                page: Some(page),
+                per_page: Some(per_page),
+            };
+            list_logs(ctx, &query).await
+        }
+    }
+}
+
+async fn list_logs(ctx: &Context, query: &recap_core::services::LlmUsageLogQuery) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let page = recap_core::services::get_usage_logs(&ctx.db.pool, &user_id, query)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    if ctx.format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&page)?);
+        return Ok(());
+    }
+
+    if page.logs.items.is_empty() {
+        print_info("No LLM calls match.", ctx.quiet);
+        return Ok(());
+    }
+
+    let summary = format!(
+        "Page {} of {} ({} call(s), ${:.4} estimated)",
+        page.logs.page, page.logs.pages, page.logs.total, page.total_cost
+    );
+    let rows: Vec<LlmUsageLogRow> = page.logs.items.into_iter().map(LlmUsageLogRow::from).collect();
+    print_output(&rows, ctx.format)?;
+    if ctx.format == OutputFormat::Table {
+        print_info(&summary, ctx.quiet);
+    }
+    Ok(())
+}
@@ -2,9 +2,14 @@
 //!
 //! Contains all CLI command implementations.
 
+pub mod auth;
 pub mod claude;
 pub mod config;
 pub mod dashboard;
+pub mod db;
+pub mod doctor;
+pub mod llm;
+pub mod project;
 pub mod report;
 pub mod source;
 pub mod sync;
@@ -21,4 +26,6 @@ pub struct Context {
     pub quiet: bool,
     /// Enable debug logging
     pub debug: bool,
+    /// Timezone override from `--tz`, used instead of the user's setting
+    pub timezone: Option<String>,
 }
@@ -0,0 +1,72 @@
+//! Project commands
+//!
+//! Hide or show projects, same as the visibility toggle in the desktop app's
+//! project view.
+
+use anyhow::Result;
+use clap::Subcommand;
+use serde::Serialize;
+
+use crate::output::{print_success, write_csv, OutputFormat};
+use super::work::helpers::get_or_create_default_user;
+use super::Context;
+
+#[derive(Subcommand)]
+pub enum ProjectAction {
+    /// Hide projects from work items, reports and snapshots
+    Hide {
+        /// Project names
+        #[arg(required = true)]
+        names: Vec<String>,
+    },
+
+    /// Show previously hidden projects
+    Show {
+        /// Project names
+        #[arg(required = true)]
+        names: Vec<String>,
+    },
+}
+
+/// Result of a bulk visibility change (JSON output)
+#[derive(Debug, Serialize)]
+pub struct VisibilityChange {
+    pub hidden: bool,
+    pub requested: usize,
+    /// Projects whose visibility actually changed
+    pub changed: usize,
+}
+
+pub async fn execute(ctx: &Context, action: ProjectAction) -> Result<()> {
+    match action {
+        ProjectAction::Hide { names } => set_visibility(ctx, names, true).await,
+        ProjectAction::Show { names } => set_visibility(ctx, names, false).await,
+    }
+}
+
+async fn set_visibility(ctx: &Context, names: Vec<String>, hidden: bool) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let changed = recap_core::services::set_projects_visibility(&ctx.db.pool, &user_id, &names, hidden)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let result = VisibilityChange {
+        hidden,
+        requested: names.len(),
+        changed,
+    };
+
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&result)?),
+        OutputFormat::Csv => write_csv(std::slice::from_ref(&result), std::io::stdout().lock())?,
+        OutputFormat::Table => {
+            let verb = if hidden { "Hid" } else { "Showed" };
+            print_success(
+                &format!("{} {} of {} project(s)", verb, result.changed, result.requested),
+                ctx.quiet,
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,253 @@
+//! Calendar report
+//!
+//! A month grid of logged hours per day, one row per week, colored by how
+//! close each day comes to the `daily_work_hours` goal.
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::commands::work::get_daily_goal;
+use crate::commands::work::helpers::{fetch_items_in_range, get_week_start_day, user_today};
+use crate::commands::Context;
+use crate::output::{write_csv, OutputFormat};
+use super::monthly_csv::{last_day_of_month, parse_month};
+
+/// Weekday names, Sunday first to match `week_start_day` (0=Sun)
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Width of one day cell, e.g. `15  7.5`
+const CELL_WIDTH: usize = 7;
+
+/// One day of the calendar (CSV output)
+#[derive(Debug, Serialize)]
+struct CalendarDay {
+    date: NaiveDate,
+    hours: f64,
+}
+
+pub async fn show_calendar(ctx: &Context, month: Option<String>) -> Result<()> {
+    let month_start = match month {
+        Some(m) => parse_month(&m)?,
+        None => {
+            let today = user_today(ctx).await;
+            NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap_or(today)
+        }
+    };
+
+    let items = fetch_items_in_range(ctx, None, month_start, last_day_of_month(month_start)).await?;
+    let hours = daily_hours(&items, month_start);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            let map: BTreeMap<String, f64> = hours.iter().map(|(d, h)| (d.to_string(), *h)).collect();
+            println!("{}", serde_json::to_string_pretty(&map)?);
+        }
+        OutputFormat::Csv => {
+            let days: Vec<CalendarDay> = hours.iter().map(|(d, h)| CalendarDay { date: *d, hours: *h }).collect();
+            write_csv(&days, std::io::stdout().lock())?;
+        }
+        OutputFormat::Table => {
+            let week_start_day = get_week_start_day(&ctx.db).await;
+            let goal = get_daily_goal(&ctx.db.pool).await?;
+            print!("{}", render_calendar(month_start, &hours, week_start_day, goal));
+        }
+    }
+
+    Ok(())
+}
+
+/// Total hours for every day of the month, zero for days without work
+pub fn daily_hours(items: &[recap_core::WorkItem], month_start: NaiveDate) -> BTreeMap<NaiveDate, f64> {
+    let month_end = last_day_of_month(month_start);
+    let mut hours: BTreeMap<NaiveDate, f64> = month_start
+        .iter_days()
+        .take_while(|d| *d <= month_end)
+        .map(|d| (d, 0.0))
+        .collect();
+
+    for item in items {
+        if let Some(total) = hours.get_mut(&item.date) {
+            *total += item.hours;
+        }
+    }
+    for total in hours.values_mut() {
+        *total = (*total * 100.0).round() / 100.0;
+    }
+    hours
+}
+
+/// Weeks of the month as rows of seven cells, `None` outside the month.
+///
+/// `week_start_day` follows the user setting: 0=Sun, 1=Mon, ..., 6=Sat.
+pub fn calendar_weeks(month_start: NaiveDate, week_start_day: u32) -> Vec<[Option<NaiveDate>; 7]> {
+    let month_end = last_day_of_month(month_start);
+    let offset = (month_start.weekday().num_days_from_sunday() + 7 - week_start_day % 7) % 7;
+    let mut day = month_start - Duration::days(offset as i64);
+
+    let mut weeks = Vec::new();
+    while day <= month_end {
+        let mut week = [None; 7];
+        for cell in week.iter_mut() {
+            if day >= month_start && day <= month_end {
+                *cell = Some(day);
+            }
+            day += Duration::days(1);
+        }
+        weeks.push(week);
+    }
+    weeks
+}
+
+/// Month grid with a weekday header, a legend and the month total
+pub fn render_calendar(
+    month_start: NaiveDate,
+    hours: &BTreeMap<NaiveDate, f64>,
+    week_start_day: u32,
+    goal: f64,
+) -> String {
+    let mut out = format!("{}\n", month_start.format("%B %Y").to_string().bold());
+
+    let header: Vec<String> = (0..7)
+        .map(|i| format!("{:>width$}", WEEKDAYS[((week_start_day + i) % 7) as usize], width = CELL_WIDTH))
+        .collect();
+    out.push_str(&header.join(" "));
+    out.push('\n');
+
+    for week in calendar_weeks(month_start, week_start_day) {
+        let cells: Vec<String> = week
+            .iter()
+            .map(|cell| match cell {
+                Some(date) => day_cell(date.day(), hours.get(date).copied().unwrap_or(0.0), goal),
+                None => " ".repeat(CELL_WIDTH),
+            })
+            .collect();
+        out.push_str(cells.join(" ").trim_end());
+        out.push('\n');
+    }
+
+    let total = hours.values().fold(0.0, |acc, h| acc + h);
+    let days_worked = hours.values().filter(|h| **h > 0.0).count();
+    out.push_str(&format!(
+        "\n{} >= {:.1}h  {} >= {:.1}h  {} < {:.1}h\n",
+        "■".green(),
+        goal,
+        "■".yellow(),
+        goal / 2.0,
+        "■".red(),
+        goal / 2.0
+    ));
+    out.push_str(&format!("Total: {:.1}h over {} day(s)\n", total, days_worked));
+    out
+}
+
+/// `day hours`, with the hours colored against the daily goal
+fn day_cell(day: u32, hours: f64, goal: f64) -> String {
+    // Pad before coloring so escape codes don't break the alignment
+    let text = if hours > 0.0 {
+        format!("{:>4.1}", hours)
+    } else {
+        format!("{:>4}", "·")
+    };
+    let colored = if hours <= 0.0 {
+        text.dimmed()
+    } else if hours >= goal {
+        text.green()
+    } else if hours >= goal / 2.0 {
+        text.yellow()
+    } else {
+        text.red()
+    };
+    format!("{:>2} {}", day, colored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(date: &str, hours: f64) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "manual".to_string(),
+            source_id: None,
+            source_url: None,
+            title: "Work".to_string(),
+            description: None,
+            hours,
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            jira_issue_key: None,
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: None,
+            end_time: None,
+            project_path: None,
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_grid_has_one_cell_per_day() {
+        // February 2026 starts on a Sunday
+        let month = parse_month("2026-02").unwrap();
+
+        let weeks = calendar_weeks(month, 1);
+        assert_eq!(weeks.len(), 5);
+        let days: Vec<NaiveDate> = weeks.iter().flatten().flatten().copied().collect();
+        assert_eq!(days.len(), 28);
+        assert_eq!(days[0], date("2026-02-01"));
+        // Monday start puts the 1st (a Sunday) in the last column
+        assert_eq!(weeks[0][6], Some(date("2026-02-01")));
+        assert!(weeks[0][..6].iter().all(Option::is_none));
+
+        // Sunday start fits the month in exactly four weeks
+        let weeks = calendar_weeks(month, 0);
+        assert_eq!(weeks.len(), 4);
+        assert_eq!(weeks.iter().flatten().flatten().count(), 28);
+        assert_eq!(weeks[0][0], Some(date("2026-02-01")));
+
+        let weeks = calendar_weeks(parse_month("2026-03").unwrap(), 1);
+        assert_eq!(weeks.iter().flatten().flatten().count(), 31);
+    }
+
+    #[test]
+    fn test_daily_totals() {
+        let month = parse_month("2026-02").unwrap();
+        let items = vec![
+            item("2026-02-02", 3.0),
+            item("2026-02-02", 1.5),
+            item("2026-02-14", 8.0),
+            // Outside the month
+            item("2026-03-01", 5.0),
+        ];
+
+        let hours = daily_hours(&items, month);
+        assert_eq!(hours.len(), 28);
+        assert_eq!(hours[&date("2026-02-02")], 4.5);
+        assert_eq!(hours[&date("2026-02-14")], 8.0);
+        assert_eq!(hours[&date("2026-02-03")], 0.0);
+        assert_eq!(hours.values().fold(0.0, |acc, h| acc + h), 12.5);
+
+        let rendered = render_calendar(month, &hours, 1, 8.0);
+        assert!(rendered.contains("Total: 12.5h over 2 day(s)"));
+    }
+}
@@ -3,34 +3,129 @@
 //! Export work items to various formats.
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use std::collections::HashMap;
 
+use recap_core::services::http_export::{export_report_to_notion, NotionExportConfig};
+
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
 use crate::commands::Context;
-use crate::output::{print_info, print_success};
-use super::helpers::{get_user_name, resolve_date_range};
+use crate::output::{print_error, print_info, print_success};
+use super::helpers::{get_default_user_id, get_user_name, resolve_date_range};
 
 pub async fn export_excel(
     ctx: &Context,
     start: Option<String>,
     end: Option<String>,
     output: String,
+    template: Option<String>,
 ) -> Result<()> {
-    let (start_date, end_date) = resolve_date_range(start, end)?;
+    // Validate the template before doing any work
+    let template = template.map(|path| load_template(&path)).transpose()?;
+
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
 
     print_info(&format!("Exporting work items from {} to {}", start_date, end_date), ctx.quiet);
 
-    // Fetch work items
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE date >= ? AND date <= ? ORDER BY date"
-    )
-    .bind(start_date.to_string())
-    .bind(end_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let Some((metadata, excel_items, projects)) = build_report(ctx, start_date, end_date).await? else {
+        print_info("No work items found in this date range.", ctx.quiet);
+        return Ok(());
+    };
 
-    if items.is_empty() {
+    // Generate report
+    let mut generator = recap_core::ExcelReportGenerator::new()?;
+    if let Some(template) = template {
+        generator = generator.with_template(template)?;
+    }
+    generator.create_personal_report(&metadata, &excel_items, &projects)?;
+    generator.save(&output)?;
+
+    print_success(&format!("Exported {} items to {}", excel_items.len(), output), ctx.quiet);
+    Ok(())
+}
+
+/// Render per-project hours as a PDF
+pub async fn export_pdf(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    output: String,
+    font: Option<String>,
+) -> Result<()> {
+    // Resolve the font before doing any work
+    let generator = match font {
+        Some(path) => recap_core::PdfReportGenerator::from_font_file(shellexpand::tilde(&path).as_ref())?,
+        None => recap_core::PdfReportGenerator::with_system_font()?,
+    };
+
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
+
+    print_info(&format!("Exporting project hours from {} to {}", start_date, end_date), ctx.quiet);
+
+    let Some((metadata, _, projects)) = build_report(ctx, start_date, end_date).await? else {
+        print_info("No work items found in this date range.", ctx.quiet);
+        return Ok(());
+    };
+
+    generator.save(&metadata, &projects, &output)?;
+
+    print_success(&format!("Exported {} project(s) to {}", projects.len(), output), ctx.quiet);
+    Ok(())
+}
+
+/// Push one page per project into the configured Notion database
+pub async fn export_notion(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<()> {
+    let user_id = get_default_user_id(&ctx.db).await?;
+    let config = NotionExportConfig::load(&ctx.db.pool, &user_id).await?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Notion is not configured. Run: recap config set notion_token <token> \
+             and recap config set notion_database_id <id>"
+        )
+    })?;
+
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
+
+    print_info(&format!("Exporting project totals from {} to {} to Notion", start_date, end_date), ctx.quiet);
+
+    let Some((metadata, _, projects)) = build_report(ctx, start_date, end_date).await? else {
         print_info("No work items found in this date range.", ctx.quiet);
         return Ok(());
+    };
+
+    let result = export_report_to_notion(&ctx.db.pool, &user_id, &config, &metadata, &projects).await?;
+    for row in result.rows.iter().filter(|r| r.status == "error") {
+        print_error(&format!(
+            "{}: {}",
+            row.project_name,
+            row.error_message.as_deref().unwrap_or("unknown error")
+        ));
+    }
+    if result.failed > 0 && result.created + result.updated == 0 {
+        return Err(anyhow::anyhow!("Notion export failed for all {} project(s)", result.failed));
+    }
+
+    print_success(&format!(
+        "Notion: {} created, {} updated, {} failed",
+        result.created, result.updated, result.failed
+    ), ctx.quiet);
+    Ok(())
+}
+
+/// Work items, per-project totals and metadata for a date range; `None` when empty
+async fn build_report(
+    ctx: &Context,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Option<(recap_core::ReportMetadata, Vec<recap_core::ExcelWorkItem>, Vec<recap_core::ProjectSummary>)>> {
+    // Fetch work items
+    let items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
+
+    if items.is_empty() {
+        return Ok(None);
     }
 
     // Convert to Excel format
@@ -76,11 +171,12 @@ pub async fn export_excel(
         generated_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
     };
 
-    // Generate report
-    let mut generator = recap_core::ExcelReportGenerator::new()?;
-    generator.create_personal_report(&metadata, &excel_items, &projects)?;
-    generator.save(&output)?;
+    Ok(Some((metadata, excel_items, projects)))
+}
 
-    print_success(&format!("Exported {} items to {}", excel_items.len(), output), ctx.quiet);
-    Ok(())
+/// Read an Excel column template from a JSON file
+fn load_template(path: &str) -> Result<recap_core::ExcelTemplate> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read template {}: {}", path, e))?;
+    recap_core::ExcelTemplate::from_json(&json)
 }
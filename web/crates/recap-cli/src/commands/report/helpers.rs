@@ -5,17 +5,19 @@
 use anyhow::Result;
 use chrono::{Datelike, NaiveDate};
 
-/// Resolve date range from optional start and end dates
-pub fn resolve_date_range(start: Option<String>, end: Option<String>) -> Result<(NaiveDate, NaiveDate)> {
-    let today = chrono::Local::now().date_naive();
-
+/// Resolve date range from optional start and end dates, relative to `today`
+pub fn resolve_date_range(
+    start: Option<String>,
+    end: Option<String>,
+    today: NaiveDate,
+) -> Result<(NaiveDate, NaiveDate)> {
     let end_date = match end {
-        Some(e) => parse_date(&e)?,
+        Some(e) => parse_date(&e, today)?,
         None => today,
     };
 
     let start_date = match start {
-        Some(s) => parse_date(&s)?,
+        Some(s) => parse_date(&s, today)?,
         None => {
             // Default to start of current month
             NaiveDate::from_ymd_opt(today.year(), today.month(), 1)
@@ -26,17 +28,9 @@ pub fn resolve_date_range(start: Option<String>, end: Option<String>) -> Result<
     Ok((start_date, end_date))
 }
 
-/// Parse date string supporting common formats
-pub fn parse_date(s: &str) -> Result<NaiveDate> {
-    if s == "today" {
-        return Ok(chrono::Local::now().date_naive());
-    }
-    if s == "yesterday" {
-        return Ok(chrono::Local::now().date_naive() - chrono::Duration::days(1));
-    }
-
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|_| anyhow::anyhow!("Invalid date format: {}. Use YYYY-MM-DD", s))
+/// Parse date string supporting common formats, resolving keywords relative to `today`
+pub fn parse_date(s: &str, today: NaiveDate) -> Result<NaiveDate> {
+    recap_core::parse_date_arg(s, today).map_err(anyhow::Error::msg)
 }
 
 /// Get user name from database
@@ -48,13 +42,23 @@ pub async fn get_user_name(db: &recap_core::Database) -> Result<String> {
     Ok(user.map(|(name,)| name).unwrap_or_else(|| "CLI User".to_string()))
 }
 
+/// Get the default user's id
+pub async fn get_default_user_id(db: &recap_core::Database) -> Result<String> {
+    let user: Option<(String,)> = sqlx::query_as("SELECT id FROM users LIMIT 1")
+        .fetch_optional(&db.pool)
+        .await?;
+
+    user.map(|(id,)| id)
+        .ok_or_else(|| anyhow::anyhow!("No user found. Run 'recap work add' first to create a default user."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_parse_date_valid() {
-        let date = parse_date("2025-01-15").unwrap();
+        let date = parse_date("2025-01-15", chrono::Local::now().date_naive()).unwrap();
         assert_eq!(date.year(), 2025);
         assert_eq!(date.month(), 1);
         assert_eq!(date.day(), 15);
@@ -63,27 +67,27 @@ mod tests {
     #[test]
     fn test_parse_date_today() {
         let today = chrono::Local::now().date_naive();
-        let parsed = parse_date("today").unwrap();
+        let parsed = parse_date("today", today).unwrap();
         assert_eq!(parsed, today);
     }
 
     #[test]
     fn test_parse_date_yesterday() {
         let yesterday = chrono::Local::now().date_naive() - chrono::Duration::days(1);
-        let parsed = parse_date("yesterday").unwrap();
+        let parsed = parse_date("yesterday", chrono::Local::now().date_naive()).unwrap();
         assert_eq!(parsed, yesterday);
     }
 
     #[test]
     fn test_parse_date_invalid() {
-        assert!(parse_date("invalid").is_err());
-        assert!(parse_date("2025/01/15").is_err());
-        assert!(parse_date("").is_err());
+        assert!(parse_date("invalid", chrono::Local::now().date_naive()).is_err());
+        assert!(parse_date("2025/01/15", chrono::Local::now().date_naive()).is_err());
+        assert!(parse_date("", chrono::Local::now().date_naive()).is_err());
     }
 
     #[test]
     fn test_parse_date_error_message() {
-        let err = parse_date("bad").unwrap_err();
+        let err = parse_date("bad", chrono::Local::now().date_naive()).unwrap_err();
         assert!(err.to_string().contains("bad"));
         assert!(err.to_string().contains("YYYY-MM-DD"));
     }
@@ -93,6 +97,7 @@ mod tests {
         let (start, end) = resolve_date_range(
             Some("2025-01-01".to_string()),
             Some("2025-01-31".to_string()),
+            chrono::Local::now().date_naive(),
         ).unwrap();
 
         assert_eq!(start.to_string(), "2025-01-01");
@@ -105,6 +110,7 @@ mod tests {
         let (start, end) = resolve_date_range(
             Some("2025-01-01".to_string()),
             None,
+            today,
         ).unwrap();
 
         assert_eq!(start.to_string(), "2025-01-01");
@@ -117,6 +123,7 @@ mod tests {
         let (start, end) = resolve_date_range(
             None,
             Some("2025-01-31".to_string()),
+            today,
         ).unwrap();
 
         assert_eq!(start.day(), 1);
@@ -126,7 +133,7 @@ mod tests {
 
     #[test]
     fn test_resolve_date_range_defaults() {
-        let result = resolve_date_range(None, None);
+        let result = resolve_date_range(None, None, chrono::Local::now().date_naive());
         assert!(result.is_ok());
 
         let (start, end) = result.unwrap();
@@ -143,6 +150,7 @@ mod tests {
         let (start, end) = resolve_date_range(
             Some("today".to_string()),
             Some("today".to_string()),
+            today,
         ).unwrap();
 
         assert_eq!(start, today);
@@ -0,0 +1,241 @@
+//! Cached LLM summary command
+//!
+//! Reads back a project summary from the `project_summaries` cache shared
+//! with the desktop app, without calling the LLM.
+
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Utc, Weekday};
+use sqlx::SqlitePool;
+
+use crate::commands::work::helpers::{query_items_in_range, user_timezone};
+use crate::commands::Context;
+use crate::output::{csv_not_supported, print_info, OutputFormat};
+use super::project::derive_project_name;
+use super::types::{CachedSummary, SummaryKind, SummaryTimeUnit};
+
+pub async fn show_llm_summary(
+    ctx: &Context,
+    project: String,
+    time_unit: SummaryTimeUnit,
+    period: Option<String>,
+    kind: SummaryKind,
+) -> Result<()> {
+    let period_start = period.as_deref().map(|p| parse_period(time_unit, p)).transpose()?;
+    let summary = find_cached_summary(&ctx.db.pool, &project, kind, time_unit, period_start).await?;
+
+    let Some(summary) = summary else {
+        if matches!(ctx.format, OutputFormat::Json) {
+            println!("null");
+            return Ok(());
+        }
+        print_info(&format!(
+            "No cached {} summary for '{}' ({}{}). Generate one from the project view in the desktop app.",
+            kind.as_str(),
+            project,
+            time_unit.as_str(),
+            period.map(|p| format!(" {}", p)).unwrap_or_default()
+        ), ctx.quiet);
+        return Ok(());
+    };
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        }
+        OutputFormat::Csv => return Err(csv_not_supported("LLM summaries")),
+        OutputFormat::Table => {
+            let timezone = user_timezone(ctx).await;
+            let changed = items_changed_since(&ctx.db.pool, &summary, timezone.as_deref()).await?;
+
+            println!("Project: {}", summary.project_name);
+            println!(
+                "Period:  {} ({} to {})",
+                summary.period_label.as_deref().unwrap_or(&summary.period_start),
+                summary.period_start,
+                summary.period_end
+            );
+            println!("Model:   {}", summary.llm_model.as_deref().unwrap_or("-"));
+            println!("Created: {} UTC ({})", summary.created_at, describe_age(&summary.created_at));
+            if summary.orphaned {
+                println!("Status:  orphaned (the work items it summarized were removed)");
+            } else if changed > 0 {
+                println!("Status:  stale ({} work item(s) changed since)", changed);
+            } else {
+                println!("Status:  fresh");
+            }
+            println!("\n{}", summary.summary.trim_end());
+        }
+    }
+
+    Ok(())
+}
+
+/// First day of the period named by `period` for `time_unit`.
+///
+/// Accepts `2026-02-03` (day), `2026-W06` (ISO week), `2026-02` (month),
+/// `2026-Q1` (quarter) and `2026` (year); any time unit also takes a date
+/// inside the period.
+pub fn parse_period(time_unit: SummaryTimeUnit, period: &str) -> Result<NaiveDate> {
+    let invalid = || anyhow::anyhow!("Invalid {} period: {}", time_unit.as_str(), period);
+    let period = period.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(period, "%Y-%m-%d") {
+        return Ok(match time_unit {
+            SummaryTimeUnit::Day => date,
+            SummaryTimeUnit::Week => date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64),
+            SummaryTimeUnit::Month => date.with_day(1).ok_or_else(invalid)?,
+            SummaryTimeUnit::Quarter => {
+                NaiveDate::from_ymd_opt(date.year(), (date.month0() / 3) * 3 + 1, 1).ok_or_else(invalid)?
+            }
+            SummaryTimeUnit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1).ok_or_else(invalid)?,
+        });
+    }
+
+    let date = match time_unit {
+        SummaryTimeUnit::Day => None,
+        SummaryTimeUnit::Week => period.split_once("-W").and_then(|(year, week)| {
+            NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, Weekday::Mon)
+        }),
+        SummaryTimeUnit::Month => NaiveDate::parse_from_str(&format!("{}-01", period), "%Y-%m-%d").ok(),
+        SummaryTimeUnit::Quarter => period.split_once("-Q").and_then(|(year, quarter)| {
+            let quarter: u32 = quarter.parse().ok().filter(|q| (1..=4).contains(q))?;
+            NaiveDate::from_ymd_opt(year.parse().ok()?, (quarter - 1) * 3 + 1, 1)
+        }),
+        SummaryTimeUnit::Year => period.parse().ok().and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1)),
+    };
+    date.ok_or_else(invalid)
+}
+
+/// Cached summary for a project and time unit: the given period, or the latest one
+pub async fn find_cached_summary(
+    pool: &SqlitePool,
+    project: &str,
+    kind: SummaryKind,
+    time_unit: SummaryTimeUnit,
+    period_start: Option<NaiveDate>,
+) -> Result<Option<CachedSummary>> {
+    let summary = sqlx::query_as(
+        r#"SELECT project_name, summary_type, time_unit, period_start, period_end, period_label,
+                  summary, llm_model, data_hash, COALESCE(orphaned, 0) AS orphaned,
+                  datetime(created_at) AS created_at
+           FROM project_summaries
+           WHERE project_name = ? AND summary_type = ? AND time_unit = ?
+             AND (? IS NULL OR period_start = ?)
+           ORDER BY period_start DESC, created_at DESC
+           LIMIT 1"#
+    )
+    .bind(project)
+    .bind(kind.as_str())
+    .bind(time_unit.as_str())
+    .bind(period_start.map(|d| d.to_string()))
+    .bind(period_start.map(|d| d.to_string()))
+    .fetch_optional(pool)
+    .await?;
+    Ok(summary)
+}
+
+/// Number of the project's work items in the summary period updated after it was generated
+async fn items_changed_since(pool: &SqlitePool, summary: &CachedSummary, timezone: Option<&str>) -> Result<usize> {
+    let (Ok(start), Ok(end), Ok(created)) = (
+        NaiveDate::parse_from_str(&summary.period_start, "%Y-%m-%d"),
+        NaiveDate::parse_from_str(&summary.period_end, "%Y-%m-%d"),
+        NaiveDateTime::parse_from_str(&summary.created_at, "%Y-%m-%d %H:%M:%S"),
+    ) else {
+        return Ok(0);
+    };
+    let created = created.and_utc();
+
+    let items = query_items_in_range(pool, None, start, end, timezone).await?;
+    Ok(items
+        .iter()
+        .filter(|item| derive_project_name(item) == summary.project_name && item.updated_at > created)
+        .count())
+}
+
+/// "3 days ago" style age of a `YYYY-MM-DD HH:MM:SS` UTC timestamp
+fn describe_age(created_at: &str) -> String {
+    let Ok(created) = NaiveDateTime::parse_from_str(created_at, "%Y-%m-%d %H:%M:%S") else {
+        return "unknown age".to_string();
+    };
+    let age = Utc::now() - created.and_utc();
+    if age.num_days() > 0 {
+        format!("{} day(s) ago", age.num_days())
+    } else if age.num_hours() > 0 {
+        format!("{} hour(s) ago", age.num_hours())
+    } else {
+        format!("{} minute(s) ago", age.num_minutes().max(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_period() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(parse_period(SummaryTimeUnit::Week, "2026-W06").unwrap(), date("2026-02-02"));
+        assert_eq!(parse_period(SummaryTimeUnit::Week, "2026-02-05").unwrap(), date("2026-02-02"));
+        assert_eq!(parse_period(SummaryTimeUnit::Month, "2026-02").unwrap(), date("2026-02-01"));
+        assert_eq!(parse_period(SummaryTimeUnit::Quarter, "2026-Q2").unwrap(), date("2026-04-01"));
+        assert_eq!(parse_period(SummaryTimeUnit::Year, "2026").unwrap(), date("2026-01-01"));
+        assert_eq!(parse_period(SummaryTimeUnit::Day, "2026-02-03").unwrap(), date("2026-02-03"));
+        assert!(parse_period(SummaryTimeUnit::Quarter, "2026-Q5").is_err());
+        assert!(parse_period(SummaryTimeUnit::Day, "2026-W06").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_cached_summary_by_project_unit_and_period() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = recap_core::Database::open(temp_dir.path().join("test.db")).await.unwrap();
+        let pool = &db.pool;
+        for (id, unit, start, end, label, text) in [
+            ("w05", "week", "2026-01-26", "2026-02-01", "2026 W05", "Sync rework"),
+            ("w06", "week", "2026-02-02", "2026-02-08", "2026 W06", "Notion export"),
+            ("m02", "month", "2026-02-01", "2026-02-28", "2026-02", "February"),
+        ] {
+            sqlx::query(
+                r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit,
+                                                  period_start, period_end, period_label, summary, llm_model)
+                   VALUES (?, 'u1', 'recap', 'report', ?, ?, ?, ?, ?, 'gpt-5-nano')"#
+            )
+            .bind(id)
+            .bind(unit)
+            .bind(start)
+            .bind(end)
+            .bind(label)
+            .bind(text)
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+
+        let week6 = parse_period(SummaryTimeUnit::Week, "2026-W06").unwrap();
+        let found = find_cached_summary(pool, "recap", SummaryKind::Report, SummaryTimeUnit::Week, Some(week6))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.summary, "Notion export");
+        assert_eq!(found.period_label.as_deref(), Some("2026 W06"));
+        assert_eq!(found.llm_model.as_deref(), Some("gpt-5-nano"));
+        assert!(!found.orphaned);
+
+        // Without a period, the latest one for the time unit
+        let latest = find_cached_summary(pool, "recap", SummaryKind::Report, SummaryTimeUnit::Month, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.summary, "February");
+
+        let week4 = parse_period(SummaryTimeUnit::Week, "2026-W04").unwrap();
+        assert!(find_cached_summary(pool, "recap", SummaryKind::Report, SummaryTimeUnit::Week, Some(week4))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(find_cached_summary(pool, "recap", SummaryKind::Timeline, SummaryTimeUnit::Week, Some(week6))
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
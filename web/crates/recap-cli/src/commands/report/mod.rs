@@ -1,11 +1,21 @@
 //! Report commands
 //!
-//! Commands for generating work reports: summary, export.
+//! Commands for generating work reports: summary, export, weekly (and its
+//! Tempo readiness check), project, top files, push, monthly CSV, calendar,
+//! cached LLM summaries.
 
+mod calendar;
 mod export;
 mod helpers;
+mod llm_summary;
+mod monthly_csv;
+mod project;
+mod push;
 mod summary;
+mod tempo_ready;
+mod top_files;
 mod types;
+mod weekly;
 
 use anyhow::Result;
 
@@ -14,13 +24,48 @@ use crate::commands::Context;
 // Re-export public types
 pub use types::{DateSummaryRow, ReportAction, SummaryRow};
 
+use types::ExportTarget;
+
 pub async fn execute(ctx: &Context, action: ReportAction) -> Result<()> {
     match action {
         ReportAction::Summary { start, end, group_by } => {
             summary::show_summary(ctx, start, end, group_by).await
         }
-        ReportAction::Export { start, end, output } => {
-            export::export_excel(ctx, start, end, output).await
+        ReportAction::Export { start, end, output, template, target, font } => match target {
+            ExportTarget::Excel => {
+                let output = output.unwrap_or_else(|| "work_report.xlsx".to_string());
+                export::export_excel(ctx, start, end, output, template).await
+            }
+            ExportTarget::Pdf => {
+                let output = output.unwrap_or_else(|| "work_report.pdf".to_string());
+                export::export_pdf(ctx, start, end, output, font).await
+            }
+            ExportTarget::Notion => export::export_notion(ctx, start, end).await,
+        }
+        ReportAction::Weekly { last, tempo_ready, week } => {
+            if tempo_ready {
+                tempo_ready::check_tempo_ready(ctx, &week).await
+            } else {
+                weekly::show_weekly(ctx, last).await
+            }
+        }
+        ReportAction::Project { name, start, end } => {
+            project::show_project(ctx, name, start, end).await
+        }
+        ReportAction::TopFiles { start, end, project, limit } => {
+            top_files::show_top_files(ctx, start, end, project, limit).await
+        }
+        ReportAction::Push { start, end, url, token } => {
+            push::push_report(ctx, start, end, url, token).await
+        }
+        ReportAction::MonthlyCsv { month, output, skip_empty } => {
+            monthly_csv::export_monthly_csv(ctx, month, output, skip_empty).await
+        }
+        ReportAction::Calendar { month } => {
+            calendar::show_calendar(ctx, month).await
+        }
+        ReportAction::LlmSummary { project, time_unit, period, kind } => {
+            llm_summary::show_llm_summary(ctx, project, time_unit, period, kind).await
         }
     }
 }
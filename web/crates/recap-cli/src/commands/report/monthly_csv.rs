@@ -0,0 +1,245 @@
+//! Monthly CSV report
+//!
+//! One row per work day for timesheet/payroll import: date, total hours,
+//! projects worked on and notes.
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
+use crate::commands::Context;
+use crate::output::{print_info, print_success};
+use super::weekly::project_name;
+
+const CSV_HEADER: &str = "date,total_hours,projects,notes";
+
+/// One day in the monthly timesheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthlyCsvRow {
+    pub date: NaiveDate,
+    /// Total hours, rounded to the nearest 0.25h
+    pub total_hours: f64,
+    /// Distinct project names, `; `-separated
+    pub projects: String,
+    /// Distinct work item titles, `; `-separated
+    pub notes: String,
+}
+
+pub async fn export_monthly_csv(
+    ctx: &Context,
+    month: Option<String>,
+    output: Option<String>,
+    skip_empty: bool,
+) -> Result<()> {
+    let month_start = match month {
+        Some(m) => parse_month(&m)?,
+        None => {
+            let today = user_today(ctx).await;
+            NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap_or(today)
+        }
+    };
+    let month_end = last_day_of_month(month_start);
+    let output = output.unwrap_or_else(|| format!("timesheet-{}.csv", month_start.format("%Y-%m")));
+
+    print_info(&format!("Exporting timesheet for {}", month_start.format("%Y-%m")), ctx.quiet);
+
+    let items = fetch_items_in_range(ctx, None, month_start, month_end).await?;
+    let rows = build_monthly_rows(&items, month_start, skip_empty);
+    std::fs::write(&output, to_csv(&rows))
+        .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", output, e))?;
+
+    print_success(&format!("Exported {} days to {}", rows.len(), output), ctx.quiet);
+    Ok(())
+}
+
+/// Parse `YYYY-MM` into the first day of that month
+pub fn parse_month(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", s.trim()), "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid month: {}. Use YYYY-MM format.", s))
+}
+
+pub fn last_day_of_month(month_start: NaiveDate) -> NaiveDate {
+    let (year, month) = if month_start.month() == 12 {
+        (month_start.year() + 1, 1)
+    } else {
+        (month_start.year(), month_start.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(month_start) - Duration::days(1)
+}
+
+/// Rows for every weekday of the month, plus weekend days with logged work.
+///
+/// Weekdays without work are zero-filled unless `skip_empty` is set.
+pub fn build_monthly_rows(
+    items: &[recap_core::WorkItem],
+    month_start: NaiveDate,
+    skip_empty: bool,
+) -> Vec<MonthlyCsvRow> {
+    let month_end = last_day_of_month(month_start);
+
+    let mut by_date: BTreeMap<NaiveDate, Vec<&recap_core::WorkItem>> = BTreeMap::new();
+    for item in items.iter().filter(|i| i.date >= month_start && i.date <= month_end) {
+        by_date.entry(item.date).or_default().push(item);
+    }
+
+    month_start
+        .iter_days()
+        .take_while(|d| *d <= month_end)
+        .filter_map(|date| {
+            let day_items = by_date.get(&date).map(Vec::as_slice).unwrap_or(&[]);
+            let is_weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+            if day_items.is_empty() && (skip_empty || is_weekend) {
+                return None;
+            }
+
+            // fold from 0.0: an empty f64 sum is -0.0, which would print as "-0.00"
+            let total = day_items.iter().fold(0.0, |acc, i| acc + i.hours);
+            Some(MonthlyCsvRow {
+                date,
+                total_hours: recap_core::services::round_hours(total),
+                projects: join_distinct(day_items.iter().map(|i| project_name(i))),
+                notes: join_distinct(day_items.iter().map(|i| i.title.clone())),
+            })
+        })
+        .collect()
+}
+
+/// Join values in first-seen order, dropping duplicates
+fn join_distinct(values: impl Iterator<Item = String>) -> String {
+    let mut seen: Vec<String> = Vec::new();
+    for value in values {
+        if !seen.contains(&value) {
+            seen.push(value);
+        }
+    }
+    seen.join("; ")
+}
+
+/// Render rows as CSV with a header line
+pub fn to_csv(rows: &[MonthlyCsvRow]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{:.2},{},{}\n",
+            row.date,
+            row.total_hours,
+            csv_field(&row.projects),
+            csv_field(&row.notes),
+        ));
+    }
+    csv
+}
+
+/// Quote a field if it contains a delimiter, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(date: &str, hours: f64, project: &str, title: &str) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "manual".to_string(),
+            source_id: None,
+            source_url: None,
+            title: title.to_string(),
+            description: None,
+            hours,
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            jira_issue_key: None,
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: None,
+            end_time: None,
+            project_path: Some(format!("/home/dev/{}", project)),
+        }
+    }
+
+    #[test]
+    fn test_full_month_rows_and_daily_totals() {
+        // February 2026 starts on a Sunday and has 20 weekdays
+        let month = parse_month("2026-02").unwrap();
+        let items = vec![
+            item("2026-02-02", 3.0, "recap", "Fix sync"),
+            item("2026-02-02", 1.3, "infra", "Deploy, rollback"),
+            item("2026-02-02", 0.5, "recap", "Fix sync"),
+            item("2026-02-27", 8.0, "recap", "Release"),
+            // Weekend work still gets a row
+            item("2026-02-07", 2.0, "docs", "Write guide"),
+            // Outside the month
+            item("2026-03-02", 5.0, "recap", "Next month"),
+        ];
+
+        let rows = build_monthly_rows(&items, month, false);
+        assert_eq!(rows.len(), 21);
+        assert_eq!(rows[0].date.to_string(), "2026-02-02");
+        assert_eq!(rows.last().unwrap().date.to_string(), "2026-02-27");
+        assert!(rows.iter().all(|r| r.date.month() == 2));
+
+        let by_date: BTreeMap<String, &MonthlyCsvRow> =
+            rows.iter().map(|r| (r.date.to_string(), r)).collect();
+        // 4.8h rounds to the nearest quarter hour
+        assert_eq!(by_date["2026-02-02"].total_hours, 4.75);
+        assert_eq!(by_date["2026-02-02"].projects, "recap; infra");
+        assert_eq!(by_date["2026-02-02"].notes, "Fix sync; Deploy, rollback");
+        assert_eq!(by_date["2026-02-07"].total_hours, 2.0);
+        assert_eq!(by_date["2026-02-27"].total_hours, 8.0);
+        assert_eq!(by_date["2026-02-03"].total_hours, 0.0);
+        assert_eq!(by_date["2026-02-03"].projects, "");
+
+        let total: f64 = rows.iter().map(|r| r.total_hours).sum();
+        assert_eq!(total, 14.75);
+
+        let csv = to_csv(&rows);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 22);
+        assert_eq!(lines[0], "date,total_hours,projects,notes");
+        assert_eq!(lines[1], "2026-02-02,4.75,recap; infra,\"Fix sync; Deploy, rollback\"");
+        assert_eq!(lines[2], "2026-02-03,0.00,,");
+    }
+
+    #[test]
+    fn test_skip_empty_keeps_only_days_with_work() {
+        let month = parse_month("2026-02").unwrap();
+        let items = vec![
+            item("2026-02-02", 3.0, "recap", "Fix sync"),
+            item("2026-02-07", 2.0, "docs", "Write guide"),
+        ];
+        let rows = build_monthly_rows(&items, month, true);
+        let dates: Vec<String> = rows.iter().map(|r| r.date.to_string()).collect();
+        assert_eq!(dates, vec!["2026-02-02", "2026-02-07"]);
+    }
+
+    #[test]
+    fn test_parse_month() {
+        assert_eq!(parse_month("2026-12").unwrap().to_string(), "2026-12-01");
+        assert_eq!(last_day_of_month(parse_month("2026-12").unwrap()).to_string(), "2026-12-31");
+        assert_eq!(last_day_of_month(parse_month("2028-02").unwrap()).to_string(), "2028-02-29");
+        assert!(parse_month("2026-13").is_err());
+        assert!(parse_month("feb").is_err());
+    }
+}
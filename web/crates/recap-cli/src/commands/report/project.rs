@@ -0,0 +1,315 @@
+//! Project report command
+//!
+//! Everything about one project in a single view: hours, activity range,
+//! commits, sessions, most-touched files and the latest LLM summary.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+
+use crate::commands::work::helpers::{query_items_in_range, user_timezone, user_today};
+use crate::commands::Context;
+use crate::output::{csv_not_supported, print_info, print_output, OutputFormat};
+use super::helpers::resolve_date_range;
+use super::types::{ProjectFileRow, ProjectReport, ProjectReportSummary};
+
+/// Number of files listed in the report
+const TOP_FILES: usize = 10;
+
+pub async fn show_project(
+    ctx: &Context,
+    name: String,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<()> {
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
+    let timezone = user_timezone(ctx).await;
+    let report =
+        build_project_report(&ctx.db.pool, &name, start_date, end_date, timezone.as_deref()).await?;
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Csv => return Err(csv_not_supported("project reports")),
+        OutputFormat::Table => {
+            if report.total_items == 0 {
+                print_info(&format!(
+                    "No work items found for project '{}' from {} to {}.",
+                    name, start_date, end_date
+                ), ctx.quiet);
+                return Ok(());
+            }
+
+            println!("Project: {}", report.project);
+            if let Some(path) = &report.project_path {
+                println!("Path:    {}", path);
+            }
+            println!("Range:   {} to {}", report.start, report.end);
+            if let (Some(first), Some(last)) = (&report.first_activity, &report.last_activity) {
+                println!("Active:  {} to {}", first, last);
+            }
+            println!("Hours:   {:.1} across {} item(s)", report.total_hours, report.total_items);
+            println!("Commits: {}", report.commit_count);
+            println!("Sessions: {}", report.session_count);
+
+            if !report.top_files.is_empty() {
+                println!("\nTop files:");
+                print_output(&report.top_files, ctx.format)?;
+            }
+
+            if let Some(summary) = &report.latest_summary {
+                let label = summary.period_label.as_deref().unwrap_or(&summary.period_start);
+                println!("\nLatest summary ({}):", label);
+                println!("{}", summary.summary);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Assemble the report for `name` from work items, snapshots and project summaries
+///
+/// Work items are bucketed into days in `timezone`.
+pub async fn build_project_report(
+    pool: &SqlitePool,
+    name: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    timezone: Option<&str>,
+) -> Result<ProjectReport> {
+    let items = query_items_in_range(pool, None, start, end, timezone).await?;
+
+    let project_items: Vec<&recap_core::WorkItem> = items
+        .iter()
+        .filter(|item| derive_project_name(item) == name)
+        .collect();
+
+    let total_hours: f64 = project_items.iter().map(|i| i.hours).sum();
+    let first_activity = project_items.iter().map(|i| i.date).min().map(|d| d.to_string());
+    let last_activity = project_items.iter().map(|i| i.date).max().map(|d| d.to_string());
+
+    let session_count = project_items
+        .iter()
+        .filter_map(|i| i.session_id.as_deref())
+        .collect::<HashSet<_>>()
+        .len();
+
+    // Commits come from git work items and from commits recorded in snapshots
+    let mut commit_hashes: HashSet<String> = project_items
+        .iter()
+        .filter_map(|i| {
+            i.commit_hash.clone().or_else(|| {
+                matches!(i.source.as_str(), "git" | "commit")
+                    .then(|| i.source_id.clone())
+                    .flatten()
+            })
+        })
+        .collect();
+
+    let project_paths: HashSet<&str> = project_items
+        .iter()
+        .filter_map(|i| i.project_path.as_deref())
+        .collect();
+
+    let snapshot_start = format!("{}T00:00:00", start);
+    let snapshot_end = format!("{}T23:59:59", end);
+    let mut file_counts: HashMap<String, usize> = HashMap::new();
+
+    for project_path in &project_paths {
+        let snapshots: Vec<(Option<String>, Option<String>)> = sqlx::query_as(
+            r#"SELECT files_modified, git_commits FROM snapshot_raw_data
+               WHERE project_path = ? AND hour_bucket >= ? AND hour_bucket <= ?"#
+        )
+        .bind(project_path)
+        .bind(&snapshot_start)
+        .bind(&snapshot_end)
+        .fetch_all(pool)
+        .await?;
+
+        for (files_json, commits_json) in snapshots {
+            if let Some(files) = files_json.and_then(|j| serde_json::from_str::<Vec<String>>(&j).ok()) {
+                for file in files {
+                    *file_counts.entry(file).or_insert(0) += 1;
+                }
+            }
+            if let Some(commits) = commits_json
+                .and_then(|j| serde_json::from_str::<Vec<recap_core::CommitSnapshot>>(&j).ok())
+            {
+                commit_hashes.extend(commits.into_iter().map(|c| c.hash));
+            }
+        }
+    }
+
+    let mut top_files: Vec<ProjectFileRow> = file_counts
+        .into_iter()
+        .map(|(path, changes)| ProjectFileRow { path, changes })
+        .collect();
+    top_files.sort_by(|a, b| b.changes.cmp(&a.changes).then_with(|| a.path.cmp(&b.path)));
+    top_files.truncate(TOP_FILES);
+
+    let latest_summary: Option<ProjectReportSummary> = sqlx::query_as(
+        r#"SELECT period_start, period_end, period_label, summary FROM project_summaries
+           WHERE project_name = ? AND COALESCE(orphaned, 0) = 0 AND period_start <= ? AND period_end >= ?
+           ORDER BY period_end DESC, created_at DESC
+           LIMIT 1"#
+    )
+    .bind(name)
+    .bind(end.to_string())
+    .bind(start.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    let project_path = project_items.iter().find_map(|i| i.project_path.clone());
+
+    Ok(ProjectReport {
+        project: name.to_string(),
+        project_path,
+        start: start.to_string(),
+        end: end.to_string(),
+        first_activity,
+        last_activity,
+        total_hours,
+        total_items: project_items.len(),
+        commit_count: commit_hashes.len(),
+        session_count,
+        top_files,
+        latest_summary,
+    })
+}
+
+/// Project name of a work item, matching the desktop app's project view:
+/// the last segment of `project_path`, else a legacy `[Project]` title prefix.
+pub(super) fn derive_project_name(item: &recap_core::WorkItem) -> String {
+    if let Some(last) = item
+        .project_path
+        .as_deref()
+        .and_then(|p| std::path::Path::new(p).file_name())
+        .and_then(|n| n.to_str())
+    {
+        return last.to_string();
+    }
+
+    if let Some(rest) = item.title.strip_prefix('[') {
+        if let Some(name) = rest.split(']').next().filter(|n| !n.is_empty()) {
+            return name.to_string();
+        }
+    }
+
+    "unknown".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::test_support::db_with_user;
+
+    /// Insert a work item; items with a commit hash are git items, the rest Claude sessions
+    async fn insert_item(
+        pool: &SqlitePool,
+        id: &str,
+        hours: f64,
+        date: &str,
+        project_path: &str,
+        session_id: Option<&str>,
+        commit_hash: Option<&str>,
+    ) {
+        let now = chrono::Utc::now();
+        let source = if commit_hash.is_some() { "git" } else { "claude_code" };
+        sqlx::query(
+            r#"INSERT INTO work_items (id, user_id, source, source_id, title, hours, date, project_path,
+                                       session_id, commit_hash, created_at, updated_at)
+               VALUES (?, 'u1', ?, ?, 'work', ?, ?, ?, ?, ?, ?, ?)"#
+        )
+        .bind(id)
+        .bind(source)
+        .bind(commit_hash)
+        .bind(hours)
+        .bind(date)
+        .bind(project_path)
+        .bind(session_id)
+        .bind(commit_hash)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_snapshot(pool: &SqlitePool, session_id: &str, hour_bucket: &str, files: &str, commits: &str) {
+        sqlx::query(
+            r#"INSERT INTO snapshot_raw_data (id, user_id, session_id, project_path, hour_bucket, files_modified, git_commits)
+               VALUES (?, 'u1', ?, '/home/dev/recap', ?, ?, ?)"#
+        )
+        .bind(format!("{}-{}", session_id, hour_bucket))
+        .bind(session_id)
+        .bind(hour_bucket)
+        .bind(files)
+        .bind(commits)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_build_project_report() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+
+        insert_item(pool, "s1", 2.0, "2026-01-12", "/home/dev/recap", Some("sess-1"), None).await;
+        insert_item(pool, "s2", 1.5, "2026-01-14", "/home/dev/recap", Some("sess-2"), None).await;
+        insert_item(pool, "c1", 0.5, "2026-01-14", "/home/dev/recap", None, Some("aaa111")).await;
+        insert_item(pool, "other", 4.0, "2026-01-14", "/home/dev/other", Some("sess-3"), None).await;
+        insert_item(pool, "outside", 3.0, "2026-02-01", "/home/dev/recap", None, Some("ccc333")).await;
+
+        // One commit duplicates the git work item, one is only in snapshots
+        insert_snapshot(
+            pool, "sess-1", "2026-01-12T10:00:00",
+            r#"["src/main.rs","src/lib.rs"]"#,
+            r#"[{"hash":"aaa111","message":"m","timestamp":"t","additions":1,"deletions":0}]"#,
+        ).await;
+        insert_snapshot(
+            pool, "sess-2", "2026-01-14T15:00:00",
+            r#"["src/main.rs"]"#,
+            r#"[{"hash":"bbb222","message":"m","timestamp":"t","additions":1,"deletions":0}]"#,
+        ).await;
+
+        sqlx::query(
+            r#"INSERT INTO project_summaries (id, user_id, project_name, period_start, period_end, period_label, summary)
+               VALUES ('ps1', 'u1', 'recap', '2026-01-12', '2026-01-18', 'W03', 'Reworked sync')"#
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        let report = build_project_report(pool, "recap", start, end, None).await.unwrap();
+
+        assert!((report.total_hours - 4.0).abs() < 0.001);
+        assert_eq!(report.total_items, 3);
+        assert_eq!(report.commit_count, 2);
+        assert_eq!(report.session_count, 2);
+        assert_eq!(report.first_activity.as_deref(), Some("2026-01-12"));
+        assert_eq!(report.last_activity.as_deref(), Some("2026-01-14"));
+        assert_eq!(report.top_files[0].path, "src/main.rs");
+        assert_eq!(report.top_files[0].changes, 2);
+        assert_eq!(report.latest_summary.unwrap().summary, "Reworked sync");
+    }
+
+    #[tokio::test]
+    async fn test_build_project_report_unknown_project() {
+        let (db, _dir) = db_with_user().await;
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+
+        let report = build_project_report(&db.pool, "missing", start, end, None).await.unwrap();
+
+        assert_eq!(report.total_items, 0);
+        assert_eq!(report.commit_count, 0);
+        assert!(report.first_activity.is_none());
+        assert!(report.latest_summary.is_none());
+    }
+}
@@ -0,0 +1,110 @@
+//! Report push command
+//!
+//! Sends the summary report as JSON to a webhook endpoint.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::time::Duration;
+
+use recap_core::services::http_export::{HttpExportClient, HttpExportConfig};
+
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
+use crate::commands::Context;
+use crate::output::{print_info, print_success};
+use super::helpers::resolve_date_range;
+use super::summary::{group_by_date, group_by_project, group_by_source};
+use super::types::SummaryGroup;
+
+/// Attempts made before giving up on the endpoint
+const PUSH_MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry, doubled after each attempt
+const PUSH_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Summary report payload sent to the endpoint
+#[derive(Debug, Serialize)]
+pub struct SummaryReport {
+    pub start: String,
+    pub end: String,
+    pub generated_at: String,
+    pub total_hours: f64,
+    pub total_items: usize,
+    pub by_date: Vec<SummaryGroup>,
+    pub by_project: Vec<SummaryGroup>,
+    pub by_source: Vec<SummaryGroup>,
+}
+
+pub async fn push_report(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    url: Option<String>,
+    token: Option<String>,
+) -> Result<()> {
+    let (stored_url, stored_token) = get_push_settings(&ctx.db).await?;
+    let url = url.or(stored_url).ok_or_else(|| {
+        anyhow::anyhow!("No push URL. Pass --url or run: recap config set report_push_url <url>")
+    })?;
+    let token = token.or(stored_token).filter(|t| !t.is_empty());
+
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
+    let items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
+    let report = build_summary_report(&items, start_date, end_date);
+
+    print_info(&format!(
+        "Pushing report {} to {} to {} (token: {})",
+        start_date,
+        end_date,
+        url,
+        if token.is_some() { "****" } else { "-" }
+    ), ctx.quiet);
+
+    let client = HttpExportClient::new(HttpExportConfig::bearer_post(&url, token))?;
+    let result = client
+        .push_json(&serde_json::to_value(&report)?, PUSH_MAX_ATTEMPTS, PUSH_INITIAL_BACKOFF)
+        .await;
+
+    if !result.success {
+        return Err(anyhow::anyhow!(
+            "Push failed after {} attempt(s): {}",
+            result.attempts,
+            result.message
+        ));
+    }
+
+    print_success(&format!(
+        "Pushed {} item(s), {:.1}h: {}",
+        report.total_items, report.total_hours, result.message
+    ), ctx.quiet);
+
+    Ok(())
+}
+
+/// Build the summary report for `items` in `start..=end`
+pub fn build_summary_report(
+    items: &[recap_core::WorkItem],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> SummaryReport {
+    SummaryReport {
+        start: start.to_string(),
+        end: end.to_string(),
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        total_hours: items.iter().map(|i| i.hours).sum(),
+        total_items: items.len(),
+        by_date: group_by_date(items),
+        by_project: group_by_project(items),
+        by_source: group_by_source(items),
+    }
+}
+
+/// Stored `report_push_url` and `report_push_token`
+async fn get_push_settings(db: &recap_core::Database) -> Result<(Option<String>, Option<String>)> {
+    let settings: Option<(Option<String>, Option<String>)> =
+        sqlx::query_as("SELECT report_push_url, report_push_token FROM users LIMIT 1")
+            .fetch_optional(&db.pool)
+            .await?;
+
+    Ok(settings.unwrap_or((None, None)))
+}
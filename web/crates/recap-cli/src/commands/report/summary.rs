@@ -5,10 +5,11 @@
 use anyhow::Result;
 use std::collections::HashMap;
 
+use crate::commands::work::helpers::{fetch_items_in_range, user_today};
 use crate::commands::Context;
 use crate::output::{print_error, print_info, print_output};
 use super::helpers::resolve_date_range;
-use super::types::{DateSummaryRow, SummaryRow};
+use super::types::{DateSummaryRow, SummaryGroup, SummaryRow};
 
 pub async fn show_summary(
     ctx: &Context,
@@ -16,18 +17,12 @@ pub async fn show_summary(
     end: Option<String>,
     group_by: String,
 ) -> Result<()> {
-    let (start_date, end_date) = resolve_date_range(start, end)?;
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
 
     print_info(&format!("Work summary from {} to {}", start_date, end_date), ctx.quiet);
 
     // Fetch work items in date range
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE date >= ? AND date <= ? ORDER BY date"
-    )
-    .bind(start_date.to_string())
-    .bind(end_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
 
     if items.is_empty() {
         print_info("No work items found in this date range.", ctx.quiet);
@@ -52,74 +47,78 @@ pub async fn show_summary(
 }
 
 async fn show_by_date(ctx: &Context, items: &[recap_core::WorkItem]) -> Result<()> {
-    let mut by_date: HashMap<String, (f64, usize)> = HashMap::new();
-
-    for item in items {
-        let entry = by_date.entry(item.date.to_string()).or_insert((0.0, 0));
-        entry.0 += item.hours;
-        entry.1 += 1;
-    }
-
-    let mut rows: Vec<DateSummaryRow> = by_date
+    let rows: Vec<DateSummaryRow> = group_by_date(items)
         .into_iter()
-        .map(|(date, (hours, count))| DateSummaryRow {
-            date,
-            hours: format!("{:.1}", hours),
-            items: count.to_string(),
+        .map(|group| DateSummaryRow {
+            date: group.group,
+            hours: format!("{:.1}", group.hours),
+            items: group.items.to_string(),
         })
         .collect();
-
-    rows.sort_by(|a, b| a.date.cmp(&b.date));
     print_output(&rows, ctx.format)?;
 
     Ok(())
 }
 
 async fn show_by_project(ctx: &Context, items: &[recap_core::WorkItem]) -> Result<()> {
-    let mut by_project: HashMap<String, (f64, usize)> = HashMap::new();
+    print_output(&to_summary_rows(group_by_project(items)), ctx.format)?;
 
-    for item in items {
-        let project = item.category.clone().unwrap_or_else(|| "Uncategorized".to_string());
-        let entry = by_project.entry(project).or_insert((0.0, 0));
-        entry.0 += item.hours;
-        entry.1 += 1;
-    }
+    Ok(())
+}
+
+async fn show_by_source(ctx: &Context, items: &[recap_core::WorkItem]) -> Result<()> {
+    print_output(&to_summary_rows(group_by_source(items)), ctx.format)?;
 
-    let mut rows: Vec<SummaryRow> = by_project
+    Ok(())
+}
+
+fn to_summary_rows(groups: Vec<SummaryGroup>) -> Vec<SummaryRow> {
+    groups
         .into_iter()
-        .map(|(group, (hours, count))| SummaryRow {
-            group,
-            hours: format!("{:.1}", hours),
-            items: count.to_string(),
+        .map(|group| SummaryRow {
+            group: group.group,
+            hours: format!("{:.1}", group.hours),
+            items: group.items.to_string(),
         })
-        .collect();
+        .collect()
+}
 
-    rows.sort_by(|a, b| b.hours.partial_cmp(&a.hours).unwrap_or(std::cmp::Ordering::Equal));
-    print_output(&rows, ctx.format)?;
+/// Hours and item counts per day, oldest first
+pub fn group_by_date(items: &[recap_core::WorkItem]) -> Vec<SummaryGroup> {
+    let mut groups = aggregate(items, |item| item.date.to_string());
+    groups.sort_by(|a, b| a.group.cmp(&b.group));
+    groups
+}
 
-    Ok(())
+/// Hours and item counts per category, most hours first
+pub fn group_by_project(items: &[recap_core::WorkItem]) -> Vec<SummaryGroup> {
+    let mut groups = aggregate(items, |item| {
+        item.category.clone().unwrap_or_else(|| "Uncategorized".to_string())
+    });
+    groups.sort_by(|a, b| b.hours.partial_cmp(&a.hours).unwrap_or(std::cmp::Ordering::Equal));
+    groups
 }
 
-async fn show_by_source(ctx: &Context, items: &[recap_core::WorkItem]) -> Result<()> {
-    let mut by_source: HashMap<String, (f64, usize)> = HashMap::new();
+/// Hours and item counts per source, most hours first
+pub fn group_by_source(items: &[recap_core::WorkItem]) -> Vec<SummaryGroup> {
+    let mut groups = aggregate(items, |item| item.source.clone());
+    groups.sort_by(|a, b| b.hours.partial_cmp(&a.hours).unwrap_or(std::cmp::Ordering::Equal));
+    groups
+}
 
+fn aggregate<F>(items: &[recap_core::WorkItem], key: F) -> Vec<SummaryGroup>
+where
+    F: Fn(&recap_core::WorkItem) -> String,
+{
+    let mut groups: HashMap<String, (f64, usize)> = HashMap::new();
     for item in items {
-        let entry = by_source.entry(item.source.clone()).or_insert((0.0, 0));
+        let entry = groups.entry(key(item)).or_insert((0.0, 0));
         entry.0 += item.hours;
         entry.1 += 1;
     }
 
-    let mut rows: Vec<SummaryRow> = by_source
+    groups
         .into_iter()
-        .map(|(group, (hours, count))| SummaryRow {
-            group,
-            hours: format!("{:.1}", hours),
-            items: count.to_string(),
-        })
-        .collect();
-
-    rows.sort_by(|a, b| b.hours.partial_cmp(&a.hours).unwrap_or(std::cmp::Ordering::Equal));
-    print_output(&rows, ctx.format)?;
-
-    Ok(())
+        .map(|(group, (hours, items))| SummaryGroup { group, hours, items })
+        .collect()
 }
@@ -0,0 +1,231 @@
+//! Tempo readiness check
+//!
+//! `report weekly --tempo-ready`: a go/no-go checklist for submitting a week
+//! to Tempo. Items already synced are left alone; every other item needs a
+//! Jira issue (its own or its project's mapping) and positive hours, and no
+//! day may add up to more than [`MAX_DAILY_HOURS`].
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDate};
+use recap_core::services::{period_bounds, PeriodUnit};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::commands::work::helpers::{
+    fetch_items_in_range, get_or_create_default_user, get_week_start_day, parse_user_date, user_today,
+};
+use crate::commands::Context;
+use crate::output::{print_info, print_output, print_success, OutputFormat};
+use super::types::{TempoReadiness, TempoReadyCheck};
+use super::weekly::project_name;
+
+/// Most hours a single day can plausibly hold
+pub const MAX_DAILY_HOURS: f64 = 24.0;
+
+/// Items named in a failed check before the rest are summarized as "and N more"
+const LISTED_ITEMS: usize = 3;
+
+pub async fn check_tempo_ready(ctx: &Context, week: &str) -> Result<()> {
+    let week_start_day = get_week_start_day(&ctx.db).await;
+    let day = match week {
+        "current" => user_today(ctx).await,
+        "last" => user_today(ctx).await - Duration::weeks(1),
+        date => parse_user_date(ctx, date).await?,
+    };
+    let (start, end) = period_bounds(day, PeriodUnit::Week, week_start_day);
+
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let mapped_projects: HashSet<String> = recap_core::services::list_project_issue_mappings(&ctx.db.pool, &user_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .into_iter()
+        .map(|m| m.project_path)
+        .collect();
+
+    let items = fetch_items_in_range(ctx, None, start, end).await?;
+    let checks = tempo_ready_checks(&items, &mapped_projects);
+    let failed = checks.iter().filter(|c| !c.passed).count();
+
+    match ctx.format {
+        OutputFormat::Json => {
+            let readiness = TempoReadiness {
+                week_start: start.to_string(),
+                week_end: end.to_string(),
+                ready: failed == 0,
+                checks,
+            };
+            println!("{}", serde_json::to_string_pretty(&readiness)?);
+        }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print_info(&format!("Tempo readiness for {} to {}", start, end), ctx.quiet);
+            print_output(&checks, ctx.format)?;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!(
+            "Week of {} is not ready for Tempo: {} check(s) failed",
+            start,
+            failed
+        ));
+    }
+    if ctx.format == OutputFormat::Table {
+        print_success(&format!("Week of {} is ready for Tempo", start), ctx.quiet);
+    }
+    Ok(())
+}
+
+/// Checklist for `items` of one week; `mapped_projects` holds project paths
+/// that have a default Jira issue
+pub fn tempo_ready_checks(items: &[recap_core::WorkItem], mapped_projects: &HashSet<String>) -> Vec<TempoReadyCheck> {
+    let unsynced: Vec<&recap_core::WorkItem> = items.iter().filter(|i| !i.synced_to_tempo).collect();
+
+    let unmapped: Vec<&recap_core::WorkItem> = unsynced
+        .iter()
+        .copied()
+        .filter(|i| i.jira_issue_key.is_none())
+        .filter(|i| i.project_path.as_ref().is_none_or(|p| !mapped_projects.contains(p)))
+        .collect();
+    let no_hours: Vec<&recap_core::WorkItem> = unsynced.iter().copied().filter(|i| i.hours <= 0.0).collect();
+
+    let mut daily: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+    for item in items {
+        *daily.entry(item.date).or_insert(0.0) += item.hours;
+    }
+    let over: Vec<String> = daily
+        .iter()
+        .filter(|(_, hours)| **hours > MAX_DAILY_HOURS)
+        .map(|(date, hours)| format!("{} has {:.1}h", date, hours))
+        .collect();
+
+    vec![
+        TempoReadyCheck {
+            check: "Mapped to a Jira issue".to_string(),
+            passed: unmapped.is_empty(),
+            detail: if unmapped.is_empty() {
+                format!("{} unsynced item(s) mapped", unsynced.len())
+            } else {
+                format!("{} item(s) without an issue: {}", unmapped.len(), list_items(&unmapped))
+            },
+        },
+        TempoReadyCheck {
+            check: "Positive hours".to_string(),
+            passed: no_hours.is_empty(),
+            detail: if no_hours.is_empty() {
+                "All unsynced items have hours".to_string()
+            } else {
+                format!("{} item(s) with no hours: {}", no_hours.len(), list_items(&no_hours))
+            },
+        },
+        TempoReadyCheck {
+            check: format!("Daily totals ≤ {}h", MAX_DAILY_HOURS),
+            passed: over.is_empty(),
+            detail: if over.is_empty() {
+                match daily.iter().max_by(|a, b| a.1.total_cmp(b.1)) {
+                    Some((date, hours)) => format!("Busiest day {} with {:.1}h", date, hours),
+                    None => "No items this week".to_string(),
+                }
+            } else {
+                over.join(", ")
+            },
+        },
+    ]
+}
+
+/// `2026-03-02 Fix login (recap), ...` for the first few items
+fn list_items(items: &[&recap_core::WorkItem]) -> String {
+    let mut listed: Vec<String> = items
+        .iter()
+        .take(LISTED_ITEMS)
+        .map(|i| format!("{} {} ({})", i.date, i.title, project_name(i)))
+        .collect();
+    if items.len() > LISTED_ITEMS {
+        listed.push(format!("and {} more", items.len() - LISTED_ITEMS));
+    }
+    listed.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, date: &str, hours: f64, jira: Option<&str>, project: &str) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "manual".to_string(),
+            source_id: None,
+            source_url: None,
+            title: title.to_string(),
+            description: None,
+            hours,
+            date: date.parse().unwrap(),
+            jira_issue_key: jira.map(str::to_string),
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: None,
+            end_time: None,
+            project_path: Some(format!("/home/dev/{}", project)),
+        }
+    }
+
+    fn passed(checks: &[TempoReadyCheck]) -> Vec<bool> {
+        checks.iter().map(|c| c.passed).collect()
+    }
+
+    #[test]
+    fn test_unmapped_item_blocks_the_week() {
+        let items = vec![
+            item("Fix login", "2026-03-02", 2.0, Some("ACME-1"), "web"),
+            item("Invoice rounding", "2026-03-03", 3.0, None, "billing"),
+            // Covered by the project mapping
+            item("Release notes", "2026-03-03", 1.0, None, "docs"),
+        ];
+        let mapped: HashSet<String> = ["/home/dev/docs".to_string()].into_iter().collect();
+
+        let checks = tempo_ready_checks(&items, &mapped);
+        assert_eq!(passed(&checks), vec![false, true, true]);
+        assert_eq!(
+            checks[0].detail,
+            "1 item(s) without an issue: 2026-03-03 Invoice rounding (billing)"
+        );
+    }
+
+    #[test]
+    fn test_hours_and_daily_totals() {
+        let mut synced = item("Old sync", "2026-03-02", 0.0, None, "web");
+        synced.synced_to_tempo = true;
+        let items = vec![
+            synced,
+            item("Zero", "2026-03-03", 0.0, Some("ACME-1"), "web"),
+            item("Long day", "2026-03-04", 20.0, Some("ACME-1"), "web"),
+            item("Longer day", "2026-03-04", 6.5, Some("ACME-1"), "web"),
+        ];
+
+        let checks = tempo_ready_checks(&items, &HashSet::new());
+        // The synced item is neither unmapped nor counted as missing hours
+        assert_eq!(passed(&checks), vec![true, false, false]);
+        assert_eq!(checks[1].detail, "1 item(s) with no hours: 2026-03-03 Zero (web)");
+        assert_eq!(checks[2].detail, "2026-03-04 has 26.5h");
+    }
+
+    #[test]
+    fn test_empty_week_is_ready() {
+        let checks = tempo_ready_checks(&[], &HashSet::new());
+        assert_eq!(passed(&checks), vec![true, true, true]);
+        assert_eq!(checks[2].detail, "No items this week");
+    }
+}
@@ -0,0 +1,152 @@
+//! Top files report
+//!
+//! Ranks the most-edited files across a period from the `files_modified`
+//! lists of hourly session snapshots. Paths matching the `exclude_paths`
+//! setting (lockfiles by default) are left out.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+use crate::commands::work::helpers::user_today;
+use crate::commands::Context;
+use crate::output::{print_info, print_output};
+use super::helpers::resolve_date_range;
+use super::types::TopFileRow;
+
+pub async fn show_top_files(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    project: Option<String>,
+    limit: usize,
+) -> Result<()> {
+    let (start_date, end_date) = resolve_date_range(start, end, user_today(ctx).await)?;
+
+    let user_id: Option<String> = sqlx::query_scalar("SELECT id FROM users LIMIT 1")
+        .fetch_optional(&ctx.db.pool)
+        .await?;
+    let patterns = recap_core::services::get_exclude_paths(&ctx.db.pool, user_id.as_deref().unwrap_or("")).await;
+    let exclude = recap_core::services::PathExcludeFilter::new(&patterns).map_err(|e| anyhow::anyhow!(e))?;
+
+    let rows = build_top_files(&ctx.db.pool, start_date, end_date, project.as_deref(), &exclude, limit).await?;
+
+    if rows.is_empty() {
+        print_info(
+            &format!("No file edits found in snapshots from {} to {}.", start_date, end_date),
+            ctx.quiet,
+        );
+        return Ok(());
+    }
+
+    print_output(&rows, ctx.format)?;
+    Ok(())
+}
+
+/// Files ranked by the number of snapshot hours they were modified in.
+///
+/// Ties are ordered by path. `project` matches the last segment of the
+/// snapshot's project path, as in the desktop app's project view.
+pub async fn build_top_files(
+    pool: &SqlitePool,
+    start: NaiveDate,
+    end: NaiveDate,
+    project: Option<&str>,
+    exclude: &recap_core::services::PathExcludeFilter,
+    limit: usize,
+) -> Result<Vec<TopFileRow>> {
+    let snapshots: Vec<(String, Option<String>)> = sqlx::query_as(
+        r#"SELECT project_path, files_modified FROM snapshot_raw_data
+           WHERE hour_bucket >= ? AND hour_bucket <= ?"#
+    )
+    .bind(format!("{}T00:00:00", start))
+    .bind(format!("{}T23:59:59", end))
+    .fetch_all(pool)
+    .await?;
+
+    let mut edits: HashMap<(String, String), usize> = HashMap::new();
+    for (project_path, files_json) in snapshots {
+        let project_name = std::path::Path::new(&project_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(&project_path)
+            .to_string();
+        if project.is_some_and(|p| p != project_name) {
+            continue;
+        }
+
+        let files = files_json
+            .and_then(|j| serde_json::from_str::<Vec<String>>(&j).ok())
+            .unwrap_or_default();
+        for file in files.into_iter().filter(|f| !exclude.is_excluded(f)) {
+            *edits.entry((file, project_name.clone())).or_insert(0) += 1;
+        }
+    }
+
+    let mut ranked: Vec<((String, String), usize)> = edits.into_iter().collect();
+    ranked.sort_by(|(a, a_edits), (b, b_edits)| b_edits.cmp(a_edits).then_with(|| a.cmp(b)));
+
+    Ok(ranked
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(idx, ((path, project), edits))| TopFileRow {
+            rank: idx + 1,
+            path,
+            project,
+            edits,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn insert_snapshot(pool: &SqlitePool, session: &str, project_path: &str, hour: &str, files: &[&str]) {
+        sqlx::query(
+            r#"INSERT INTO snapshot_raw_data (id, user_id, session_id, project_path, hour_bucket, files_modified)
+               VALUES (?, 'u1', ?, ?, ?, ?)"#
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(session)
+        .bind(project_path)
+        .bind(hour)
+        .bind(serde_json::to_string(files).unwrap())
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_repeated_edits_rank_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = recap_core::Database::open(dir.path().join("test.db")).await.unwrap();
+        let pool = &db.pool;
+
+        insert_snapshot(pool, "s1", "/home/dev/recap", "2026-03-02T09:00:00", &["src/sync.rs", "src/lib.rs", "Cargo.lock"]).await;
+        insert_snapshot(pool, "s1", "/home/dev/recap", "2026-03-02T10:00:00", &["src/sync.rs", "Cargo.lock"]).await;
+        insert_snapshot(pool, "s2", "/home/dev/recap", "2026-03-03T14:00:00", &["src/sync.rs", "src/lib.rs"]).await;
+        insert_snapshot(pool, "s3", "/home/dev/infra", "2026-03-03T15:00:00", &["deploy.sh", "Cargo.lock"]).await;
+        // Outside the range
+        insert_snapshot(pool, "s4", "/home/dev/recap", "2026-03-10T09:00:00", &["src/lib.rs"]).await;
+
+        let start = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+        let defaults: Vec<String> = recap_core::services::DEFAULT_EXCLUDE_PATHS.iter().map(|p| p.to_string()).collect();
+        let exclude = recap_core::services::PathExcludeFilter::new(&defaults).unwrap();
+
+        let rows = build_top_files(pool, start, end, None, &exclude, 10).await.unwrap();
+        let ranked: Vec<(usize, &str, usize)> = rows.iter().map(|r| (r.rank, r.path.as_str(), r.edits)).collect();
+        assert_eq!(ranked, vec![(1, "src/sync.rs", 3), (2, "src/lib.rs", 2), (3, "deploy.sh", 1)]);
+
+        let rows = build_top_files(pool, start, end, Some("recap"), &exclude, 1).await.unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].path.as_str(), rows[0].project.as_str()), ("src/sync.rs", "recap"));
+
+        // Without excludes the lockfile shows up
+        let rows = build_top_files(pool, start, end, Some("recap"), &Default::default(), 10).await.unwrap();
+        assert!(rows.iter().any(|r| r.path == "Cargo.lock" && r.edits == 2));
+    }
+}
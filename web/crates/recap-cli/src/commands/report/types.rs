@@ -2,10 +2,58 @@
 //!
 //! Types for report commands.
 
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use serde::Serialize;
 use tabled::Tabled;
 
+/// Destination for `report export`
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum ExportTarget {
+    Excel,
+    Notion,
+    Pdf,
+}
+
+/// Time unit of a cached project summary
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum SummaryTimeUnit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl SummaryTimeUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+    }
+}
+
+/// Kind of cached project summary
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum SummaryKind {
+    /// Summary generated from the project view
+    Report,
+    /// Summary generated for the project timeline
+    Timeline,
+}
+
+impl SummaryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Report => "report",
+            Self::Timeline => "timeline",
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum ReportAction {
     /// Show work summary for a date range
@@ -23,7 +71,7 @@ pub enum ReportAction {
         group_by: String,
     },
 
-    /// Export work items to Excel
+    /// Export work items to Excel, PDF or a Notion database
     Export {
         /// Start date (YYYY-MM-DD), defaults to start of current month
         #[arg(short, long)]
@@ -33,9 +81,136 @@ pub enum ReportAction {
         #[arg(short, long)]
         end: Option<String>,
 
-        /// Output file path (default: work_report.xlsx)
-        #[arg(short, long, default_value = "work_report.xlsx")]
-        output: String,
+        /// Output file path (default: work_report.xlsx, or work_report.pdf for PDF)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// JSON file mapping Details sheet columns to work item fields, e.g.
+        /// {"columns": [{"header": "Ticket", "field": "jira_key"}]}
+        #[arg(long)]
+        template: Option<String>,
+
+        /// Where to export: an Excel file, a PDF of per-project hours, or one
+        /// page per project in the Notion database set by notion_token /
+        /// notion_database_id
+        #[arg(long, value_enum, default_value = "excel")]
+        target: ExportTarget,
+
+        /// TrueType font (.ttf) to embed in the PDF, so non-Latin project
+        /// names render (default: $RECAP_PDF_FONT, else a system font)
+        #[arg(long)]
+        font: Option<String>,
+    },
+
+    /// Show per-week totals and top projects for recent weeks
+    Weekly {
+        /// Number of most recent weeks to show (including this week)
+        #[arg(short, long, default_value = "1")]
+        last: usize,
+
+        /// Check whether a week can be submitted to Tempo instead: every unsynced
+        /// item mapped to an issue, positive hours, no day over 24h.
+        /// Exits non-zero when it is not ready.
+        #[arg(long, conflicts_with = "last")]
+        tempo_ready: bool,
+
+        /// Week to check with --tempo-ready: current, last, or any date in the week
+        #[arg(long, default_value = "current", requires = "tempo_ready")]
+        week: String,
+    },
+
+    /// Show a detailed report for a single project
+    Project {
+        /// Project name (as listed in the desktop app's project view)
+        name: String,
+
+        /// Start date (YYYY-MM-DD), defaults to start of current month
+        #[arg(short, long)]
+        start: Option<String>,
+
+        /// End date (YYYY-MM-DD), defaults to today
+        #[arg(short, long)]
+        end: Option<String>,
+    },
+
+    /// Rank the most-edited files from session snapshots
+    TopFiles {
+        /// Start date (YYYY-MM-DD), defaults to start of current month
+        #[arg(short, long)]
+        start: Option<String>,
+
+        /// End date (YYYY-MM-DD), defaults to today
+        #[arg(short, long)]
+        end: Option<String>,
+
+        /// Only count files in this project
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Number of files to list
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// POST the summary report as JSON to a webhook
+    #[command(alias = "send-to-webhook")]
+    Push {
+        /// Start date (YYYY-MM-DD), defaults to start of current month
+        #[arg(short, long)]
+        start: Option<String>,
+
+        /// End date (YYYY-MM-DD), defaults to today
+        #[arg(short, long)]
+        end: Option<String>,
+
+        /// Endpoint URL (default: report_push_url setting)
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Bearer token (default: report_push_token setting)
+        #[arg(long)]
+        token: Option<String>,
+    },
+
+    /// Export a monthly timesheet CSV (date, total_hours, projects, notes)
+    MonthlyCsv {
+        /// Month (YYYY-MM), defaults to the current month
+        #[arg(short, long)]
+        month: Option<String>,
+
+        /// Output file path (default: timesheet-YYYY-MM.csv)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Omit weekdays with no logged work instead of writing zero rows
+        #[arg(long)]
+        skip_empty: bool,
+    },
+
+    /// Show a month calendar with logged hours per day
+    Calendar {
+        /// Month (YYYY-MM), defaults to the current month
+        #[arg(short, long)]
+        month: Option<String>,
+    },
+
+    /// Show the cached LLM summary of a project
+    LlmSummary {
+        /// Project name (as listed in the desktop app's project view)
+        project: String,
+
+        /// Summary time unit
+        #[arg(long, value_enum, default_value = "week")]
+        time_unit: SummaryTimeUnit,
+
+        /// Period, e.g. 2026-W06, 2026-02, 2026-Q1, 2026 or a date inside it;
+        /// defaults to the latest cached one
+        #[arg(short, long)]
+        period: Option<String>,
+
+        /// Which cached summary to read
+        #[arg(long = "type", value_enum, default_value = "report")]
+        kind: SummaryKind,
     },
 }
 
@@ -50,6 +225,14 @@ pub struct SummaryRow {
     pub items: String,
 }
 
+/// Aggregated hours and item count for one group
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryGroup {
+    pub group: String,
+    pub hours: f64,
+    pub items: usize,
+}
+
 /// Date summary row
 #[derive(Debug, Serialize, Tabled)]
 pub struct DateSummaryRow {
@@ -61,6 +244,156 @@ pub struct DateSummaryRow {
     pub items: String,
 }
 
+/// Hours spent on a single project
+#[derive(Debug, Serialize)]
+pub struct ProjectHours {
+    pub name: String,
+    pub hours: f64,
+}
+
+/// Summary of a single week (JSON output)
+#[derive(Debug, Serialize)]
+pub struct WeekSummary {
+    pub week: String,
+    pub start: String,
+    pub end: String,
+    pub total_hours: f64,
+    pub items: usize,
+    /// Change vs previous week: ↑, ↓, → (or - for the first week)
+    pub trend: String,
+    pub top_projects: Vec<ProjectHours>,
+}
+
+/// One line of the `report weekly --tempo-ready` checklist
+#[derive(Debug, Serialize, Tabled)]
+pub struct TempoReadyCheck {
+    #[tabled(rename = "Check")]
+    pub check: String,
+    #[tabled(rename = "OK", display_with = "display_passed")]
+    pub passed: bool,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+fn display_passed(passed: &bool) -> String {
+    if *passed { "✓" } else { "✗" }.to_string()
+}
+
+/// `report weekly --tempo-ready` result
+#[derive(Debug, Serialize)]
+pub struct TempoReadiness {
+    pub week_start: String,
+    pub week_end: String,
+    pub ready: bool,
+    pub checks: Vec<TempoReadyCheck>,
+}
+
+/// Week summary row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct WeekSummaryRow {
+    #[tabled(rename = "Week")]
+    pub week: String,
+    #[tabled(rename = "Start")]
+    pub start: String,
+    #[tabled(rename = "Hours")]
+    pub hours: String,
+    #[tabled(rename = "Trend")]
+    pub trend: String,
+    #[tabled(rename = "Top Projects")]
+    pub top_projects: String,
+}
+
+impl From<&WeekSummary> for WeekSummaryRow {
+    fn from(week: &WeekSummary) -> Self {
+        let top_projects = if week.top_projects.is_empty() {
+            "-".to_string()
+        } else {
+            week.top_projects
+                .iter()
+                .map(|p| format!("{} ({:.1}h)", p.name, p.hours))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        Self {
+            week: week.week.clone(),
+            start: week.start.clone(),
+            hours: format!("{:.1}", week.total_hours),
+            trend: week.trend.clone(),
+            top_projects,
+        }
+    }
+}
+
+/// Detailed single-project report (JSON output)
+#[derive(Debug, Serialize)]
+pub struct ProjectReport {
+    pub project: String,
+    pub project_path: Option<String>,
+    pub start: String,
+    pub end: String,
+    /// Earliest and latest work item dates within the range
+    pub first_activity: Option<String>,
+    pub last_activity: Option<String>,
+    pub total_hours: f64,
+    pub total_items: usize,
+    pub commit_count: usize,
+    pub session_count: usize,
+    pub top_files: Vec<ProjectFileRow>,
+    pub latest_summary: Option<ProjectReportSummary>,
+}
+
+/// File touched within a project, with the number of snapshot hours it was modified in
+#[derive(Debug, Serialize, Tabled)]
+pub struct ProjectFileRow {
+    #[tabled(rename = "File")]
+    pub path: String,
+    #[tabled(rename = "Changes")]
+    pub changes: usize,
+}
+
+/// Ranked file for `report top-files`
+#[derive(Debug, Serialize, Tabled, PartialEq)]
+pub struct TopFileRow {
+    #[tabled(rename = "#")]
+    pub rank: usize,
+    #[tabled(rename = "File")]
+    pub path: String,
+    #[tabled(rename = "Project")]
+    pub project: String,
+    /// Number of snapshot hours the file was modified in
+    #[tabled(rename = "Edits")]
+    pub edits: usize,
+}
+
+/// Latest LLM summary stored for a project
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct ProjectReportSummary {
+    pub period_start: String,
+    pub period_end: String,
+    pub period_label: Option<String>,
+    pub summary: String,
+}
+
+/// Row of the `project_summaries` cache
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct CachedSummary {
+    pub project_name: String,
+    pub summary_type: String,
+    pub time_unit: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub period_label: Option<String>,
+    pub summary: String,
+    /// Model that generated the summary; unknown for older rows
+    pub llm_model: Option<String>,
+    pub data_hash: Option<String>,
+    /// The summarized work items were removed
+    pub orphaned: bool,
+    /// UTC, `YYYY-MM-DD HH:MM:SS`
+    pub created_at: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,229 @@
+//! Weekly report commands
+//!
+//! Multi-week summaries with per-week totals, top projects and trend.
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+use recap_core::services::{iter_periods, period_bounds, PeriodUnit};
+use std::collections::HashMap;
+
+use crate::commands::work::helpers::{fetch_items_in_range, get_week_start_day, user_today};
+use crate::commands::Context;
+use crate::output::{print_info, print_output, OutputFormat};
+use super::types::{ProjectHours, WeekSummary, WeekSummaryRow};
+
+/// Number of projects listed per week
+const TOP_PROJECTS: usize = 3;
+
+pub async fn show_weekly(ctx: &Context, last: usize) -> Result<()> {
+    if last == 0 {
+        return Err(anyhow::anyhow!("--last must be at least 1"));
+    }
+
+    let week_start_day = get_week_start_day(&ctx.db).await;
+    let today = user_today(ctx).await;
+    let week_starts = recent_week_starts(today, week_start_day, last);
+
+    let range_start = week_starts[0];
+    let range_end = week_starts[week_starts.len() - 1] + Duration::days(6);
+
+    let items = fetch_items_in_range(ctx, None, range_start, range_end).await?;
+
+    let summaries = summarize_weeks(&items, &week_starts);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&summaries)?);
+        }
+        OutputFormat::Table => {
+            print_info(&format!("Last {} week(s): {} to {}", last, range_start, range_end), ctx.quiet);
+            let rows: Vec<WeekSummaryRow> = summaries.iter().map(WeekSummaryRow::from).collect();
+            print_output(&rows, ctx.format)?;
+        }
+        OutputFormat::Csv => {
+            let rows: Vec<WeekSummaryRow> = summaries.iter().map(WeekSummaryRow::from).collect();
+            print_output(&rows, ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Start dates of the most recent `count` weeks, oldest first.
+///
+/// `week_start_day` follows the user setting: 0=Sun, 1=Mon, ..., 6=Sat.
+pub fn recent_week_starts(today: NaiveDate, week_start_day: u32, count: usize) -> Vec<NaiveDate> {
+    let earliest = today - Duration::weeks(count.saturating_sub(1) as i64);
+    let (first, _) = period_bounds(earliest, PeriodUnit::Week, week_start_day);
+
+    iter_periods(first, today, PeriodUnit::Week)
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// Build per-week summaries (oldest first) with a trend against the previous week
+pub fn summarize_weeks(items: &[recap_core::WorkItem], week_starts: &[NaiveDate]) -> Vec<WeekSummary> {
+    let mut summaries: Vec<WeekSummary> = Vec::with_capacity(week_starts.len());
+
+    for &start in week_starts {
+        let end = start + Duration::days(6);
+        let week_items: Vec<&recap_core::WorkItem> = items
+            .iter()
+            .filter(|i| i.date >= start && i.date <= end)
+            .collect();
+
+        let total_hours: f64 = week_items.iter().map(|i| i.hours).sum();
+
+        let mut by_project: HashMap<String, f64> = HashMap::new();
+        for item in &week_items {
+            *by_project.entry(project_name(item)).or_insert(0.0) += item.hours;
+        }
+        let mut top_projects: Vec<ProjectHours> = by_project
+            .into_iter()
+            .map(|(name, hours)| ProjectHours { name, hours })
+            .collect();
+        top_projects.sort_by(|a, b| {
+            b.hours
+                .partial_cmp(&a.hours)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        top_projects.truncate(TOP_PROJECTS);
+
+        let trend = summaries
+            .last()
+            .map(|prev| trend_indicator(prev.total_hours, total_hours))
+            .unwrap_or("-")
+            .to_string();
+
+        summaries.push(WeekSummary {
+            week: format!("W{:02}", start.iso_week().week()),
+            start: start.to_string(),
+            end: end.to_string(),
+            total_hours,
+            items: week_items.len(),
+            trend,
+            top_projects,
+        });
+    }
+
+    summaries
+}
+
+fn trend_indicator(previous: f64, current: f64) -> &'static str {
+    if (current - previous).abs() < 0.05 {
+        "→"
+    } else if current > previous {
+        "↑"
+    } else {
+        "↓"
+    }
+}
+
+/// Project directory name, falling back to the category
+pub fn project_name(item: &recap_core::WorkItem) -> String {
+    item.project_path
+        .as_deref()
+        .and_then(|p| std::path::Path::new(p).file_name())
+        .and_then(|n| n.to_str())
+        .map(|n| n.to_string())
+        .or_else(|| item.category.clone())
+        .unwrap_or_else(|| "Uncategorized".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(date: &str, hours: f64, project: &str) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "manual".to_string(),
+            source_id: None,
+            source_url: None,
+            title: "work".to_string(),
+            description: None,
+            hours,
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            jira_issue_key: None,
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: None,
+            end_time: None,
+            project_path: Some(format!("/home/dev/{}", project)),
+        }
+    }
+
+    #[test]
+    fn test_recent_week_starts_monday() {
+        // 2026-01-15 is a Thursday
+        let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let starts = recent_week_starts(today, 1, 3);
+        let expected: Vec<String> = vec!["2025-12-29", "2026-01-05", "2026-01-12"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(starts.iter().map(|d| d.to_string()).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_recent_week_starts_sunday() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let starts = recent_week_starts(today, 0, 1);
+        assert_eq!(starts[0].to_string(), "2026-01-11");
+    }
+
+    #[test]
+    fn test_summarize_weeks_totals_and_trend() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 29).unwrap();
+        let starts = recent_week_starts(today, 1, 4);
+        // Weeks: 01-05, 01-12, 01-19, 01-26
+        let items = vec![
+            item("2026-01-05", 4.0, "recap"),
+            item("2026-01-07", 2.0, "infra"),
+            item("2026-01-13", 8.0, "recap"),
+            item("2026-01-14", 1.0, "docs"),
+            item("2026-01-20", 3.0, "infra"),
+            item("2026-01-27", 3.0, "recap"),
+            // Outside the window
+            item("2026-01-02", 5.0, "recap"),
+        ];
+
+        let weeks = summarize_weeks(&items, &starts);
+        assert_eq!(weeks.len(), 4);
+
+        let totals: Vec<f64> = weeks.iter().map(|w| w.total_hours).collect();
+        assert_eq!(totals, vec![6.0, 9.0, 3.0, 3.0]);
+
+        let trends: Vec<&str> = weeks.iter().map(|w| w.trend.as_str()).collect();
+        assert_eq!(trends, vec!["-", "↑", "↓", "→"]);
+
+        assert_eq!(weeks[1].top_projects[0].name, "recap");
+        assert_eq!(weeks[1].top_projects[0].hours, 8.0);
+        assert_eq!(weeks[0].start, "2026-01-05");
+        assert_eq!(weeks[0].end, "2026-01-11");
+    }
+
+    #[test]
+    fn test_summarize_weeks_empty_week() {
+        let starts = vec![NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()];
+        let weeks = summarize_weeks(&[], &starts);
+        assert_eq!(weeks[0].total_hours, 0.0);
+        assert!(weeks[0].top_projects.is_empty());
+    }
+}
@@ -1,13 +1,18 @@
 //! Source management commands
 //!
 //! Commands for managing data sources: git repos, Claude, GitLab.
+//! `source validate` health-checks every configured source before a sync.
 
 use anyhow::Result;
 use clap::Subcommand;
 use serde::Serialize;
+use std::path::PathBuf;
 use tabled::Tabled;
 
+use recap_core::services::{JiraAuthType, JiraClient, TempoClient};
+
 use crate::output::{print_output, print_success, print_error, print_info};
+use super::work::helpers::{claude_home, get_or_create_default_user};
 use super::Context;
 
 #[derive(Subcommand)]
@@ -26,6 +31,18 @@ pub enum SourceAction {
         #[command(subcommand)]
         source_type: RemoveSourceType,
     },
+
+    /// Check that every configured source is reachable; exits non-zero if any is broken
+    Validate,
+
+    /// Choose how a git repo's commit hours are estimated
+    SetEstimation {
+        /// Repository path or name
+        repo: String,
+
+        /// commit_interval (default), diff_size, or fixed:<hours> (e.g. fixed:0.5)
+        strategy: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -43,6 +60,20 @@ pub enum RemoveSourceType {
     Git {
         /// Path to the git repository
         path: String,
+
+        /// Also delete work items synced from this repository
+        #[arg(long)]
+        purge_items: bool,
+    },
+
+    /// Remove a tracked GitLab project
+    Gitlab {
+        /// Project path with namespace (e.g. group/project) or project name
+        project: String,
+
+        /// Also delete work items synced from this project
+        #[arg(long)]
+        purge_items: bool,
     },
 }
 
@@ -59,11 +90,68 @@ pub struct SourceRow {
     pub status: String,
 }
 
+/// Outcome of validating one source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceHealth {
+    Ok,
+    Broken,
+}
+
+impl std::fmt::Display for SourceHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            SourceHealth::Ok => "OK",
+            SourceHealth::Broken => "Broken",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Validation row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct SourceCheckRow {
+    #[tabled(rename = "Source")]
+    pub source: String,
+    #[tabled(rename = "Status")]
+    pub status: SourceHealth,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+impl SourceCheckRow {
+    fn new(source: impl Into<String>, result: std::result::Result<String, String>) -> Self {
+        let (status, detail) = match result {
+            Ok(detail) => (SourceHealth::Ok, detail),
+            Err(detail) => (SourceHealth::Broken, detail),
+        };
+        Self {
+            source: source.into(),
+            status,
+            detail,
+        }
+    }
+}
+
+/// Credentials checked by `source validate`
+#[derive(Debug, Default, sqlx::FromRow)]
+struct SourceCredentials {
+    jira_url: Option<String>,
+    jira_email: Option<String>,
+    jira_pat: Option<String>,
+    tempo_token: Option<String>,
+    gitlab_url: Option<String>,
+    gitlab_pat: Option<String>,
+    sync_claude: Option<bool>,
+}
+
 pub async fn execute(ctx: &Context, action: SourceAction) -> Result<()> {
     match action {
         SourceAction::List => list_sources(ctx).await,
         SourceAction::Add { source_type } => add_source(ctx, source_type).await,
         SourceAction::Remove { source_type } => remove_source(ctx, source_type).await,
+        SourceAction::Validate => validate_sources(ctx).await,
+        SourceAction::SetEstimation { repo, strategy } => set_estimation(ctx, repo, strategy).await,
     }
 }
 
@@ -93,7 +181,7 @@ async fn list_sources(ctx: &Context) -> Result<()> {
     }
 
     // Check Claude connection
-    let claude_path = get_claude_projects_path();
+    let claude_path = get_claude_projects_path(claude_home(ctx).await);
     rows.push(SourceRow {
         source_type: "claude".to_string(),
         name: "Claude Code".to_string(),
@@ -127,6 +215,143 @@ async fn list_sources(ctx: &Context) -> Result<()> {
     Ok(())
 }
 
+async fn validate_sources(ctx: &Context) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let creds: SourceCredentials = sqlx::query_as(
+        r#"
+        SELECT jira_url, jira_email, jira_pat, tempo_token, gitlab_url, gitlab_pat, sync_claude
+        FROM users WHERE id = ?
+        "#,
+    )
+    .bind(&user_id)
+    .fetch_optional(&ctx.db.pool)
+    .await?
+    .unwrap_or_default();
+    let set = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+
+    let mut rows = Vec::new();
+
+    let git_repos: Vec<recap_core::GitRepo> = sqlx::query_as(
+        "SELECT * FROM git_repos WHERE user_id = ? AND enabled = 1 ORDER BY name"
+    )
+    .bind(&user_id)
+    .fetch_all(&ctx.db.pool)
+    .await?;
+    for repo in git_repos {
+        rows.push(SourceCheckRow::new(format!("git: {}", repo.name), check_git_repo(&repo.path)));
+    }
+
+    if creds.sync_claude.unwrap_or(true) {
+        let result = get_claude_projects_path(claude_home(ctx).await)
+            .ok_or_else(|| "Claude projects directory not found".to_string());
+        rows.push(SourceCheckRow::new("claude", result));
+    }
+
+    let gitlab_projects: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM gitlab_projects WHERE user_id = ? AND enabled = 1"
+    )
+    .bind(&user_id)
+    .fetch_one(&ctx.db.pool)
+    .await?;
+    let gitlab_url = set(&creds.gitlab_url);
+    let gitlab_pat = set(&creds.gitlab_pat);
+    if gitlab_projects > 0 || gitlab_pat.is_some() {
+        let result = match (gitlab_url, gitlab_pat) {
+            (Some(url), Some(pat)) => recap_core::services::get_gitlab_user(&url, &pat)
+                .await
+                .map(|user| format!("Authenticated as {}", user.username)),
+            _ => Err("gitlab_url and gitlab_pat must both be set".to_string()),
+        };
+        rows.push(SourceCheckRow::new("gitlab", result));
+    }
+
+    let jira_url = set(&creds.jira_url);
+    let jira_pat = set(&creds.jira_pat);
+    if let (Some(url), Some(pat)) = (&jira_url, &jira_pat) {
+        rows.push(SourceCheckRow::new("jira", ping_jira(url, pat, set(&creds.jira_email)).await));
+    }
+    if let Some(token) = set(&creds.tempo_token) {
+        let result = match &jira_url {
+            Some(url) => ping_tempo(url, &token).await,
+            None => Err("tempo_token is set but jira_url is not".to_string()),
+        };
+        rows.push(SourceCheckRow::new("tempo", result));
+    }
+
+    if rows.is_empty() {
+        print_info("No sources configured.", ctx.quiet);
+        return Ok(());
+    }
+    print_output(&rows, ctx.format)?;
+
+    let broken = rows.iter().filter(|r| r.status == SourceHealth::Broken).count();
+    if broken > 0 {
+        return Err(anyhow::anyhow!("{} source(s) broken", broken));
+    }
+    print_success(&format!("All {} source(s) OK", rows.len()), ctx.quiet);
+    Ok(())
+}
+
+/// The repo exists and `git log` can read its last commit
+fn check_git_repo(path: &str) -> std::result::Result<String, String> {
+    if !std::path::Path::new(path).exists() {
+        return Err(format!("{} does not exist", path));
+    }
+    if !is_valid_git_repo(path) {
+        return Err(format!("{} is not a git repository", path));
+    }
+    get_last_commit_info(path)
+        .map(|(hash, date)| format!("Last commit {} at {}", hash, date))
+        .ok_or_else(|| format!("Could not read commits in {}", path))
+}
+
+/// Authenticated `myself` call; Basic auth (Jira Cloud) when an email is set, PAT otherwise
+async fn ping_jira(url: &str, pat: &str, email: Option<String>) -> std::result::Result<String, String> {
+    let auth_type = if email.is_some() { JiraAuthType::Basic } else { JiraAuthType::Pat };
+    let client = JiraClient::new(url, pat, email.as_deref(), auth_type).map_err(|e| e.to_string())?;
+    client
+        .get_myself()
+        .await
+        .map(|user| {
+            let name = user.display_name.or(user.name).unwrap_or_else(|| "Unknown".to_string());
+            format!("Authenticated as {}", name)
+        })
+        .map_err(|e| format!("Connection failed: {}", e))
+}
+
+async fn ping_tempo(jira_url: &str, token: &str) -> std::result::Result<String, String> {
+    let client = TempoClient::new(jira_url, token).map_err(|e| e.to_string())?;
+    match client.test_connection().await {
+        Ok((true, detail)) => Ok(detail),
+        Ok((false, detail)) => Err(detail),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+async fn set_estimation(ctx: &Context, repo: String, strategy: String) -> Result<()> {
+    let strategy = recap_core::EstimationStrategy::parse(&strategy).map_err(anyhow::Error::msg)?;
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let expanded_path = shellexpand::tilde(&repo).to_string();
+
+    let path: Option<String> = sqlx::query_scalar(
+        "SELECT path FROM git_repos WHERE user_id = ? AND (path = ? OR name = ?) ORDER BY path = ? DESC LIMIT 1"
+    )
+    .bind(&user_id)
+    .bind(&expanded_path)
+    .bind(&repo)
+    .bind(&expanded_path)
+    .fetch_optional(&ctx.db.pool)
+    .await?;
+    let path = path.ok_or_else(|| anyhow::anyhow!("Git repo not found: {}", repo))?;
+
+    recap_core::services::set_repo_estimation_strategy(&ctx.db.pool, &user_id, &path, strategy)
+        .await
+        .map_err(anyhow::Error::msg)?;
+
+    print_success(&format!("Estimation for {}: {}", path, strategy), ctx.quiet);
+    Ok(())
+}
+
 async fn add_source(ctx: &Context, source_type: AddSourceType) -> Result<()> {
     match source_type {
         AddSourceType::Git { path } => add_git_source(ctx, path).await,
@@ -190,23 +415,96 @@ async fn add_git_source(ctx: &Context, path: String) -> Result<()> {
 
 async fn remove_source(ctx: &Context, source_type: RemoveSourceType) -> Result<()> {
     match source_type {
-        RemoveSourceType::Git { path } => remove_git_source(ctx, path).await,
+        RemoveSourceType::Git { path, purge_items } => {
+            remove_git_source(ctx, path, purge_items).await
+        }
+        RemoveSourceType::Gitlab { project, purge_items } => {
+            remove_gitlab_source(ctx, project, purge_items).await
+        }
     }
 }
 
-async fn remove_git_source(ctx: &Context, path: String) -> Result<()> {
+async fn remove_git_source(ctx: &Context, path: String, purge_items: bool) -> Result<()> {
     let expanded = shellexpand::tilde(&path);
     let expanded_path = expanded.to_string();
 
-    let result = sqlx::query("DELETE FROM git_repos WHERE path = ?")
-        .bind(&expanded_path)
-        .execute(&ctx.db.pool)
+    let repo: Option<(String, String)> = sqlx::query_as(
+        "SELECT id, user_id FROM git_repos WHERE path = ?"
+    )
+    .bind(&expanded_path)
+    .fetch_optional(&ctx.db.pool)
+    .await?;
+
+    let Some((repo_id, user_id)) = repo else {
+        print_error(&format!("Git repo not found: {}", path));
+        return Ok(());
+    };
+
+    // Remove the repo and (optionally) its items together
+    let mut tx = ctx.db.pool.begin().await?;
+
+    sqlx::query("DELETE FROM git_repos WHERE id = ?")
+        .bind(&repo_id)
+        .execute(&mut *tx)
         .await?;
 
-    if result.rows_affected() > 0 {
-        print_success(&format!("Removed git repo: {}", path), ctx.quiet);
+    let purged = if purge_items {
+        recap_core::services::purge_git_repo_items(&mut tx, &user_id, &expanded_path)
+            .await
+            .map_err(anyhow::Error::msg)?
     } else {
-        print_error(&format!("Git repo not found: {}", path));
+        0
+    };
+
+    tx.commit().await?;
+
+    print_success(&format!("Removed git repo: {}", path), ctx.quiet);
+    if purge_items {
+        print_info(&format!("Deleted {} work item(s)", purged), ctx.quiet);
+    }
+
+    Ok(())
+}
+
+async fn remove_gitlab_source(ctx: &Context, project: String, purge_items: bool) -> Result<()> {
+    let found: Option<recap_core::GitLabProject> = sqlx::query_as(
+        "SELECT * FROM gitlab_projects WHERE path_with_namespace = ? OR name = ? LIMIT 1"
+    )
+    .bind(&project)
+    .bind(&project)
+    .fetch_optional(&ctx.db.pool)
+    .await?;
+
+    let Some(found) = found else {
+        print_error(&format!("GitLab project not found: {}", project));
+        return Ok(());
+    };
+
+    let mut tx = ctx.db.pool.begin().await?;
+
+    sqlx::query("DELETE FROM gitlab_projects WHERE id = ?")
+        .bind(&found.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let purged = if purge_items {
+        recap_core::services::purge_gitlab_project_items(
+            &mut tx,
+            &found.user_id,
+            &found.gitlab_url,
+            &found.path_with_namespace,
+        )
+        .await
+        .map_err(anyhow::Error::msg)?
+    } else {
+        0
+    };
+
+    tx.commit().await?;
+
+    print_success(&format!("Removed GitLab project: {}", found.path_with_namespace), ctx.quiet);
+    if purge_items {
+        print_info(&format!("Deleted {} work item(s)", purged), ctx.quiet);
     }
 
     Ok(())
@@ -218,9 +516,23 @@ fn is_valid_git_repo(path: &str) -> bool {
     git_path.is_dir() || git_path.is_file()
 }
 
-fn get_claude_projects_path() -> Option<String> {
-    let home = dirs::home_dir()?;
-    let claude_path = home.join(".claude").join("projects");
+/// Short hash and date of the last commit, or `None` if `git log` fails
+fn get_last_commit_info(path: &str) -> Option<(String, String)> {
+    let output = recap_core::utils::create_command("git")
+        .args(["log", "-1", "--format=%h|%ci"])
+        .current_dir(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (hash, date) = stdout.trim().split_once('|')?;
+    Some((hash.to_string(), date.to_string()))
+}
+
+fn get_claude_projects_path(claude_home: Option<PathBuf>) -> Option<String> {
+    let claude_path = claude_home?.join("projects");
     if claude_path.exists() {
         Some(claude_path.to_string_lossy().to_string())
     } else {
@@ -228,35 +540,6 @@ fn get_claude_projects_path() -> Option<String> {
     }
 }
 
-async fn get_or_create_default_user(db: &recap_core::Database) -> Result<String> {
-    let user: Option<(String,)> = sqlx::query_as("SELECT id FROM users LIMIT 1")
-        .fetch_optional(&db.pool)
-        .await?;
-
-    if let Some((id,)) = user {
-        return Ok(id);
-    }
-
-    let id = uuid::Uuid::new_v4().to_string();
-    let now = chrono::Utc::now();
-    let password_hash = recap_core::auth::hash_password("cli_user")?;
-
-    sqlx::query(
-        r#"
-        INSERT INTO users (id, email, password_hash, name, username, created_at, updated_at)
-        VALUES (?, 'cli@localhost', ?, 'CLI User', 'cli', ?, ?)
-        "#
-    )
-    .bind(&id)
-    .bind(&password_hash)
-    .bind(now)
-    .bind(now)
-    .execute(&db.pool)
-    .await?;
-
-    Ok(id)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,7 +581,18 @@ mod tests {
     #[test]
     fn test_get_claude_projects_path() {
         // This test just ensures the function doesn't panic
-        let _ = get_claude_projects_path();
+        let _ = get_claude_projects_path(recap_core::services::default_claude_home());
+    }
+
+    #[test]
+    fn test_check_git_repo_without_commits_is_broken() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join(".git")).unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        let detail = check_git_repo(path).unwrap_err();
+        assert!(detail.starts_with("Could not read commits"));
+        assert!(check_git_repo("/nonexistent/repo").unwrap_err().ends_with("does not exist"));
     }
 
     #[test]
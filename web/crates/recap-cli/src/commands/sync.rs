@@ -4,10 +4,14 @@
 
 use anyhow::Result;
 use clap::Subcommand;
+use indicatif::{ProgressBar, ProgressStyle};
+use recap_core::SyncProgressUpdate;
 use serde::Serialize;
+use std::path::PathBuf;
 use tabled::Tabled;
 
-use crate::output::{print_output, print_success, print_info};
+use crate::output::{print_output, print_success, print_info, OutputFormat};
+use super::work::helpers::claude_homes;
 use super::Context;
 
 #[derive(Subcommand)]
@@ -21,10 +25,34 @@ pub enum SyncAction {
         /// Specific project paths to sync
         #[arg(short, long)]
         project: Option<Vec<String>>,
+
+        /// Show a per-project breakdown of sync results
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Exit with an error if any source fails (other sources still sync)
+        #[arg(long, alias = "source-error-exit")]
+        strict: bool,
+
+        /// Show a live progress bar (current source, projects scanned, items found)
+        #[arg(long)]
+        progress: bool,
+
+        /// Re-scan every Claude session, not just those modified since the last sync
+        #[arg(long)]
+        full: bool,
     },
 
     /// Show sync status for all sources
-    Status,
+    Status {
+        /// Keep refreshing until Ctrl-C (with --quiet, print only when the status changes)
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Seconds between refreshes in watch mode
+        #[arg(long, default_value = "2", requires = "watch", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
 
     /// Run data compaction (hourly → daily → weekly → monthly summaries)
     Compact {
@@ -39,7 +67,7 @@ pub enum SyncAction {
 }
 
 /// Sync status row for table display
-#[derive(Debug, Serialize, Tabled)]
+#[derive(Debug, Clone, PartialEq, Serialize, Tabled)]
 pub struct SyncStatusRow {
     #[tabled(rename = "Source")]
     pub source: String,
@@ -53,14 +81,32 @@ pub struct SyncStatusRow {
     pub status: String,
 }
 
+/// Per-project sync result row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct ProjectSyncRow {
+    #[tabled(rename = "Project")]
+    pub project: String,
+    #[tabled(rename = "Path")]
+    pub path: String,
+    #[tabled(rename = "Created")]
+    pub created: usize,
+    #[tabled(rename = "Updated")]
+    pub updated: usize,
+    #[tabled(rename = "Skipped")]
+    pub skipped: usize,
+}
+
 pub async fn execute(ctx: &Context, action: SyncAction) -> Result<()> {
     match action {
-        SyncAction::Run { source, project } => {
-            run_sync(ctx, source, project).await
+        SyncAction::Run { source, project, verbose, strict, progress, full } => {
+            run_sync(ctx, source, project, verbose, strict, progress, full).await
         }
-        SyncAction::Status => {
+        SyncAction::Status { watch: false, .. } => {
             show_status(ctx).await
         }
+        SyncAction::Status { watch: true, interval } => {
+            watch_status(ctx, interval).await
+        }
         SyncAction::Compact { verify, limit } => {
             run_compaction(ctx, verify, limit).await
         }
@@ -71,10 +117,24 @@ async fn run_sync(
     ctx: &Context,
     source: Option<String>,
     project_paths: Option<Vec<String>>,
+    verbose: bool,
+    strict: bool,
+    progress: bool,
+    full: bool,
 ) -> Result<()> {
     // Get default user
     let user_id = get_default_user_id(&ctx.db).await?;
 
+    // Hidden bars ignore updates, so --quiet never draws one
+    let bar = if progress && !ctx.quiet {
+        new_progress_bar()
+    } else {
+        ProgressBar::hidden()
+    };
+
+    // Sources that failed, as "source: error"
+    let mut failures: Vec<String> = Vec::new();
+
     let sources_to_sync = match source {
         Some(s) => vec![s],
         None => vec!["claude".to_string(), "git".to_string()],
@@ -87,14 +147,29 @@ async fn run_sync(
             "claude" => {
                 let paths = match &project_paths {
                     Some(p) => p.clone(),
-                    None => find_claude_projects()?,
+                    None => {
+                        let mut paths = Vec::new();
+                        for home in claude_homes(ctx).await {
+                            paths.extend(find_claude_projects(Some(home))?);
+                        }
+                        paths
+                    }
                 };
 
                 if paths.is_empty() {
                     print_info("  No Claude projects found.", ctx.quiet);
                 } else {
                     print_info(&format!("  Found {} Claude project(s)", paths.len()), ctx.quiet);
-                    let result = recap_core::sync_claude_projects(&ctx.db.pool, &user_id, &paths).await;
+                    let on_progress = |p: &SyncProgressUpdate| update_progress_bar(&bar, p);
+                    // The session cursor covers all projects, so --project always scans fully
+                    let result = if project_paths.is_some() {
+                        recap_core::sync_claude_projects_with_progress(&ctx.db.pool, &user_id, &paths, on_progress)
+                            .await
+                    } else {
+                        recap_core::sync_claude_projects_incremental(&ctx.db.pool, &user_id, &paths, full, on_progress)
+                            .await
+                    };
+                    bar.finish_and_clear();
 
                     match result {
                         Ok(r) => {
@@ -106,17 +181,32 @@ async fn run_sync(
                                 "    Work items: {} created, {} updated",
                                 r.work_items_created, r.work_items_updated
                             ), ctx.quiet);
+                            if verbose && !r.per_project.is_empty() {
+                                let rows: Vec<ProjectSyncRow> = r
+                                    .per_project
+                                    .into_iter()
+                                    .map(|p| ProjectSyncRow {
+                                        project: p.project,
+                                        path: p.project_path,
+                                        created: p.created,
+                                        updated: p.updated,
+                                        skipped: p.skipped,
+                                    })
+                                    .collect();
+                                print_output(&rows, ctx.format)?;
+                            }
                         }
                         Err(e) => {
                             print_info(&format!("    Error: {}", e), ctx.quiet);
+                            failures.push(format!("claude: {}", e));
                         }
                     }
                 }
             }
             "git" => {
                 // Get configured git repos
-                let repos: Vec<(String, String)> = sqlx::query_as(
-                    "SELECT path, name FROM git_repos WHERE user_id = ? AND enabled = 1"
+                let repos: Vec<(String, String, Option<String>)> = sqlx::query_as(
+                    "SELECT path, name, estimation_strategy FROM git_repos WHERE user_id = ? AND enabled = 1"
                 )
                 .bind(&user_id)
                 .fetch_all(&ctx.db.pool)
@@ -125,12 +215,74 @@ async fn run_sync(
                 if repos.is_empty() {
                     print_info("  No git repos configured. Use 'recap source add git <path>'", ctx.quiet);
                 } else {
-                    for (path, name) in repos {
-                        print_info(&format!("  Syncing git repo: {} ({})", name, path), ctx.quiet);
-                        // Note: Git sync would use the worklog service
-                        // For now, just indicate it's configured
-                        print_success(&format!("    Git repo {} is configured", name), ctx.quiet);
+                    let min_commit_lines = get_min_commit_lines(&ctx.db, &user_id).await;
+                    let include_coauthored = get_include_coauthored(&ctx.db, &user_id).await;
+                    let fold_hours = get_fold_trivial_commit_hours(&ctx.db, &user_id).await;
+                    let today = chrono::Local::now().date_naive();
+                    let mut git_progress = SyncProgressUpdate {
+                        source: "git".to_string(),
+                        projects_scanned: 0,
+                        projects_total: repos.len(),
+                        items_found: 0,
+                    };
+                    update_progress_bar(&bar, &git_progress);
+
+                    for (path, name, strategy) in repos {
+                        git_progress.projects_scanned += 1;
+                        bar.suspend(|| print_info(&format!("  Syncing git repo: {} ({})", name, path), ctx.quiet));
+                        if !std::path::Path::new(&path).is_dir() {
+                            bar.suspend(|| {
+                                print_info(&format!("    Error: repository not found at {}", path), ctx.quiet)
+                            });
+                            failures.push(format!("git {}: repository not found at {}", name, path));
+                            update_progress_bar(&bar, &git_progress);
+                            continue;
+                        }
+                        let author = recap_core::get_git_user_email(&path);
+                        let strategy = strategy
+                            .and_then(|s| recap_core::EstimationStrategy::parse(&s).ok())
+                            .unwrap_or_default();
+                        let commits = recap_core::get_user_commits_for_date(
+                            &path, &today, author.as_deref(), include_coauthored, strategy,
+                        );
+                        let total = commits.len();
+                        let kept = recap_core::filter_trivial_commits(commits, min_commit_lines, fold_hours);
+                        let skipped = total - kept.len();
+                        let co_authored = kept.iter().filter(|c| c.co_authored).count();
+
+                        let mut created = 0;
+                        let mut updated = 0;
+                        let mut errors = 0;
+                        for commit in &kept {
+                            let params = recap_core::services::WorkItemParams::from_commit(&user_id, &path, commit);
+                            match recap_core::services::upsert_work_item(&ctx.db.pool, params).await {
+                                Ok(r) if r.is_created() => created += 1,
+                                Ok(r) if r.is_updated() => updated += 1,
+                                Ok(_) => {}
+                                Err(e) => {
+                                    bar.suspend(|| print_info(&format!("    Error: {}", e), ctx.quiet));
+                                    errors += 1;
+                                }
+                            }
+                        }
+                        if errors > 0 {
+                            failures.push(format!("git {}: {} commit(s) failed to save", name, errors));
+                        }
+
+                        bar.suspend(|| {
+                            print_success(&format!(
+                                "    Git repo {}: {} commit(s) today ({} co-authored), {} trivial skipped (< {} lines)",
+                                name, kept.len(), co_authored, skipped, min_commit_lines
+                            ), ctx.quiet);
+                            print_success(&format!(
+                                "    Work items: {} created, {} updated",
+                                created, updated
+                            ), ctx.quiet);
+                        });
+                        git_progress.items_found += created + updated;
+                        update_progress_bar(&bar, &git_progress);
                     }
+                    bar.finish_and_clear();
                 }
             }
             "gitlab" => {
@@ -138,20 +290,61 @@ async fn run_sync(
             }
             _ => {
                 print_info(&format!("  Unknown source: {}", src), ctx.quiet);
+                failures.push(format!("{}: unknown source", src));
             }
         }
     }
 
+    if strict && !failures.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Sync failed for {} source(s):\n  {}",
+            failures.len(),
+            failures.join("\n  ")
+        ));
+    }
+
     print_success("Sync completed", ctx.quiet);
     Ok(())
 }
 
+/// Bar for `sync run --progress`, drawn on stderr
+fn new_progress_bar() -> ProgressBar {
+    let bar = ProgressBar::new(0);
+    if let Ok(style) = ProgressStyle::with_template("{spinner} [{bar:30}] {pos}/{len} {msg}") {
+        bar.set_style(style.progress_chars("=> "));
+    }
+    bar.enable_steady_tick(std::time::Duration::from_millis(120));
+    bar
+}
+
+fn update_progress_bar(bar: &ProgressBar, progress: &SyncProgressUpdate) {
+    bar.set_length(progress.projects_total as u64);
+    bar.set_position(progress.projects_scanned as u64);
+    bar.set_message(progress_message(progress));
+}
+
+/// Bar message: current source, projects scanned and items found
+fn progress_message(progress: &SyncProgressUpdate) -> String {
+    format!(
+        "{}: {}/{} project(s) scanned, {} item(s) found",
+        progress.source, progress.projects_scanned, progress.projects_total, progress.items_found
+    )
+}
+
 async fn run_compaction(ctx: &Context, verify: bool, limit: usize) -> Result<()> {
     let user_id = get_default_user_id(&ctx.db).await?;
 
     print_info("Running data compaction...", ctx.quiet);
     print_info("  hourly → daily → weekly → monthly summaries", ctx.quiet);
 
+    // Hours left in progress by a killed run are redone this cycle
+    let reset = recap_core::services::reset_stale_compaction_progress(&ctx.db.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    if reset > 0 {
+        print_info(&format!("  Resuming {} interrupted hour(s)", reset), ctx.quiet);
+    }
+
     // Run the same compaction function as the UI "立即壓縮" button
     let result = recap_core::services::compaction::run_compaction_cycle(
         &ctx.db.pool,
@@ -270,18 +463,69 @@ async fn run_compaction(ctx: &Context, verify: bool, limit: usize) -> Result<()>
 }
 
 async fn show_status(ctx: &Context) -> Result<()> {
+    let rows = fetch_status_rows(ctx).await?;
+
+    if rows.is_empty() {
+        print_info("No sync history found. Run 'recap sync run' to start syncing.", ctx.quiet);
+        return Ok(());
+    }
+
+    print_output(&rows, ctx.format)?;
+    Ok(())
+}
+
+/// Redraw the status every `interval_secs` until Ctrl-C.
+///
+/// The table view clears the screen between frames. With `--quiet` or a
+/// JSON/CSV format, a frame is only printed when the status has changed.
+async fn watch_status(ctx: &Context, interval_secs: u64) -> Result<()> {
+    let redraw = !ctx.quiet && ctx.format == OutputFormat::Table;
+    let interval = std::time::Duration::from_secs(interval_secs);
+    let mut previous: Option<Vec<SyncStatusRow>> = None;
+
+    loop {
+        let rows = fetch_status_rows(ctx).await?;
+
+        if redraw {
+            // Clear screen and move the cursor home
+            print!("\x1b[2J\x1b[H");
+            println!(
+                "Every {}s, updated {} (Ctrl-C to stop)\n",
+                interval_secs,
+                chrono::Local::now().format("%H:%M:%S")
+            );
+            if rows.is_empty() {
+                println!("No sync history found.");
+            } else {
+                print_output(&rows, ctx.format)?;
+            }
+        } else if status_changed(previous.as_deref(), &rows) {
+            print_output(&rows, ctx.format)?;
+        }
+        previous = Some(rows);
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a watch frame differs from the one before it (the first frame always does)
+fn status_changed(previous: Option<&[SyncStatusRow]>, rows: &[SyncStatusRow]) -> bool {
+    previous != Some(rows)
+}
+
+async fn fetch_status_rows(ctx: &Context) -> Result<Vec<SyncStatusRow>> {
     let statuses: Vec<recap_core::SyncStatus> = sqlx::query_as(
         "SELECT * FROM sync_status ORDER BY source, source_path"
     )
     .fetch_all(&ctx.db.pool)
     .await?;
 
-    if statuses.is_empty() {
-        print_info("No sync history found. Run 'recap sync run' to start syncing.", ctx.quiet);
-        return Ok(());
-    }
-
-    let rows: Vec<SyncStatusRow> = statuses
+    let rows = statuses
         .into_iter()
         .map(|s| SyncStatusRow {
             source: s.source,
@@ -294,8 +538,42 @@ async fn show_status(ctx: &Context) -> Result<()> {
         })
         .collect();
 
-    print_output(&rows, ctx.format)?;
-    Ok(())
+    Ok(rows)
+}
+
+/// Minimum changed lines for a commit to count (0 = keep all)
+async fn get_min_commit_lines(db: &recap_core::Database, user_id: &str) -> i32 {
+    sqlx::query_as::<_, (Option<i64>,)>("SELECT min_commit_lines FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(&db.pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|(v,)| v)
+        .unwrap_or(0) as i32
+}
+
+async fn get_include_coauthored(db: &recap_core::Database, user_id: &str) -> bool {
+    sqlx::query_as::<_, (Option<bool>,)>("SELECT include_coauthored FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(&db.pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|(v,)| v)
+        .unwrap_or(true)
+}
+
+/// Whether skipped trivial commits add their hours to kept ones (default off)
+async fn get_fold_trivial_commit_hours(db: &recap_core::Database, user_id: &str) -> bool {
+    sqlx::query_as::<_, (Option<bool>,)>("SELECT fold_trivial_commit_hours FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(&db.pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|(v,)| v)
+        .unwrap_or(false)
 }
 
 async fn get_default_user_id(db: &recap_core::Database) -> Result<String> {
@@ -316,11 +594,10 @@ async fn get_default_user_id(db: &recap_core::Database) -> Result<String> {
     }
 }
 
-fn find_claude_projects() -> Result<Vec<String>> {
-    let home = dirs::home_dir()
-        .ok_or_else(|| anyhow::anyhow!("Home directory not found"))?;
-
-    let claude_projects = home.join(".claude").join("projects");
+fn find_claude_projects(claude_home: Option<PathBuf>) -> Result<Vec<String>> {
+    let claude_projects = claude_home
+        .ok_or_else(|| anyhow::anyhow!("Home directory not found"))?
+        .join("projects");
 
     if !claude_projects.exists() {
         return Ok(Vec::new());
@@ -352,7 +629,24 @@ mod tests {
     #[test]
     fn test_find_claude_projects_doesnt_crash() {
         // Just verify it doesn't panic
-        let _ = find_claude_projects();
+        let _ = find_claude_projects(recap_core::services::default_claude_home());
+    }
+
+    #[test]
+    fn test_progress_message_and_hidden_bar() {
+        let progress = SyncProgressUpdate {
+            source: "claude".to_string(),
+            projects_scanned: 2,
+            projects_total: 5,
+            items_found: 7,
+        };
+        assert_eq!(progress_message(&progress), "claude: 2/5 project(s) scanned, 7 item(s) found");
+
+        // The --quiet bar takes updates without drawing
+        let bar = ProgressBar::hidden();
+        update_progress_bar(&bar, &progress);
+        assert_eq!(bar.position(), 2);
+        assert_eq!(bar.length(), Some(5));
     }
 
     #[test]
@@ -401,6 +695,23 @@ mod tests {
         assert!(debug.contains("error"));
     }
 
+    #[test]
+    fn test_watch_prints_only_on_status_change() {
+        let row = |last_sync: &str| SyncStatusRow {
+            source: "claude".to_string(),
+            path: "-".to_string(),
+            last_sync: last_sync.to_string(),
+            items: "3".to_string(),
+            status: "success".to_string(),
+        };
+
+        let first = vec![row("2025-01-15 10:30")];
+        assert!(status_changed(None, &first));
+        assert!(status_changed(None, &[]));
+        assert!(!status_changed(Some(&first), &first.clone()));
+        assert!(status_changed(Some(&first), &[row("2025-01-15 10:45")]));
+    }
+
     #[test]
     fn test_find_claude_projects_empty_dir() {
         // Create a temp dir and set HOME to it
@@ -412,7 +723,7 @@ mod tests {
         let original_home = std::env::var("HOME").ok();
         std::env::set_var("HOME", temp_dir.path());
 
-        let result = find_claude_projects();
+        let result = find_claude_projects(recap_core::services::default_claude_home());
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
 
@@ -435,7 +746,7 @@ mod tests {
         let original_home = std::env::var("HOME").ok();
         std::env::set_var("HOME", temp_dir.path());
 
-        let result = find_claude_projects();
+        let result = find_claude_projects(recap_core::services::default_claude_home());
         assert!(result.is_ok());
         let projects = result.unwrap();
         assert_eq!(projects.len(), 2);
@@ -455,7 +766,7 @@ mod tests {
         let original_home = std::env::var("HOME").ok();
         std::env::set_var("HOME", temp_dir.path());
 
-        let result = find_claude_projects();
+        let result = find_claude_projects(recap_core::services::default_claude_home());
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
 
@@ -24,22 +24,101 @@ pub fn print_text_report(report: &TempoReport) {
     println!("總計: {:.1} 小時 / {} 項工作", report.total_hours, report.total_items);
 }
 
-/// Print report in markdown format
-pub fn print_markdown_report(report: &TempoReport) {
-    println!("# {} 工作報告", report.period);
-    println!();
-    println!("**期間:** {} ~ {}", report.start_date, report.end_date);
-    println!();
+/// Render the report as Markdown for pasting into a wiki.
+///
+/// An H2 per project with its summary as a quote and work items as bullets,
+/// followed by a table of per-project totals.
+pub fn to_markdown(report: &TempoReport) -> String {
+    let mut md = String::new();
+    md.push_str(&format!("# {} 工作報告\n\n", report.period));
+    md.push_str(&format!("**期間:** {} ~ {}\n\n", report.start_date, report.end_date));
 
     for project in &report.projects {
-        println!("## {} ({:.1} 小時)", project.project, project.hours);
-        println!();
-        for summary in &project.summary {
-            println!("- {}", summary);
+        md.push_str(&format!("## {} ({:.1} 小時)\n\n", project.project, project.hours));
+        if !project.summary.is_empty() {
+            for summary in &project.summary {
+                md.push_str(&format!("> {}\n", summary));
+            }
+            md.push('\n');
         }
-        println!();
+        for item in &project.items {
+            md.push_str(&format!("- {} {} ({:.1}h)\n", item.date, item.title, item.hours));
+        }
+        md.push('\n');
     }
 
-    println!("---");
-    println!("**總計:** {:.1} 小時 / {} 項工作", report.total_hours, report.total_items);
+    md.push_str("| 專案 | 小時 | 項目 |\n");
+    md.push_str("| --- | ---: | ---: |\n");
+    for project in &report.projects {
+        md.push_str(&format!(
+            "| {} | {:.1} | {} |\n",
+            escape_table_cell(&project.project),
+            project.hours,
+            project.items.len()
+        ));
+    }
+    md.push_str(&format!("| **總計** | **{:.1}** | **{}** |\n", report.total_hours, report.total_items));
+    md
+}
+
+fn escape_table_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::types::{ProjectSummary, WorkItemBrief};
+
+    fn fixture() -> TempoReport {
+        TempoReport {
+            period: "Weekly".to_string(),
+            start_date: "2026-03-02".to_string(),
+            end_date: "2026-03-08".to_string(),
+            total_hours: 6.5,
+            total_items: 3,
+            projects: vec![
+                ProjectSummary {
+                    project: "recap".to_string(),
+                    hours: 5.0,
+                    items: vec![
+                        WorkItemBrief { date: "2026-03-02".to_string(), title: "Add markdown export".to_string(), hours: 3.0 },
+                        WorkItemBrief { date: "2026-03-03".to_string(), title: "Fix sync retry".to_string(), hours: 2.0 },
+                    ],
+                    summary: vec!["Report exports".to_string()],
+                },
+                ProjectSummary {
+                    project: "infra|ops".to_string(),
+                    hours: 1.5,
+                    items: vec![
+                        WorkItemBrief { date: "2026-03-04".to_string(), title: "Rotate certs".to_string(), hours: 1.5 },
+                    ],
+                    summary: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_projects_and_items() {
+        let md = to_markdown(&fixture());
+
+        assert!(md.starts_with("# Weekly 工作報告\n\n**期間:** 2026-03-02 ~ 2026-03-08\n"));
+        assert!(md.contains("## recap (5.0 小時)\n\n> Report exports\n\n- 2026-03-02 Add markdown export (3.0h)\n- 2026-03-03 Fix sync retry (2.0h)\n"));
+        // No summary quote for projects without one
+        assert!(md.contains("## infra|ops (1.5 小時)\n\n- 2026-03-04 Rotate certs (1.5h)\n"));
+    }
+
+    #[test]
+    fn test_to_markdown_totals_table() {
+        let md = to_markdown(&fixture());
+
+        assert!(md.ends_with(
+            "| 專案 | 小時 | 項目 |\n\
+             | --- | ---: | ---: |\n\
+             | recap | 5.0 | 2 |\n\
+             | infra\\|ops | 1.5 | 1 |\n\
+             | **總計** | **6.5** | **3** |\n"
+        ));
+    }
 }
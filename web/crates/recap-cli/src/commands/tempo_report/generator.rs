@@ -3,12 +3,16 @@
 //! Main logic for generating tempo reports.
 
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use recap_core::services::project_issue_mappings::normalize_project_path;
+use recap_core::services::{list_project_issue_mappings, JiraIssueLookup};
+
+use crate::commands::work::helpers::{fetch_items_in_range, get_week_start_day, user_today};
 use crate::commands::Context;
-use crate::output::print_info;
-use super::format::{print_markdown_report, print_text_report};
-use super::helpers::{clean_title, extract_project_name, generate_smart_summary, get_default_user_id};
+use crate::output::{print_error, print_info};
+use super::format::{print_text_report, to_markdown};
+use super::helpers::{clean_title, extract_project_name, generate_smart_summary, get_default_user_id, jira_client};
 use super::period::resolve_period;
 use super::types::{Period, ProjectSummary, TempoReport, WorkItemBrief};
 
@@ -17,8 +21,10 @@ pub async fn generate_tempo_report(
     period: Period,
     date: Option<String>,
     output_format: String,
+    skip_validation: bool,
 ) -> Result<()> {
-    let (start_date, end_date, period_name) = resolve_period(&period, date)?;
+    let week_start_day = get_week_start_day(&ctx.db).await;
+    let (start_date, end_date, period_name) = resolve_period(&period, date, user_today(ctx).await, week_start_day)?;
 
     // Get user_id for LLM service
     let user_id = get_default_user_id(&ctx.db).await?;
@@ -32,13 +38,7 @@ pub async fn generate_tempo_report(
     }
 
     // Fetch work items
-    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE date >= ? AND date <= ? ORDER BY date"
-    )
-    .bind(start_date.to_string())
-    .bind(end_date.to_string())
-    .fetch_all(&ctx.db.pool)
-    .await?;
+    let items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
 
     if items.is_empty() {
         print_info(&format!("No work items found for {} ({} ~ {})",
@@ -46,6 +46,10 @@ pub async fn generate_tempo_report(
         return Ok(());
     }
 
+    if !skip_validation {
+        validate_issue_keys(ctx, &user_id, &items).await?;
+    }
+
     // Group by project
     let mut projects_map: HashMap<String, Vec<&recap_core::WorkItem>> = HashMap::new();
 
@@ -119,8 +123,8 @@ pub async fn generate_tempo_report(
         "json" => {
             println!("{}", serde_json::to_string_pretty(&report)?);
         }
-        "markdown" => {
-            print_markdown_report(&report);
+        "markdown" | "md" => {
+            print!("{}", to_markdown(&report));
         }
         _ => {
             print_text_report(&report);
@@ -129,3 +133,185 @@ pub async fn generate_tempo_report(
 
     Ok(())
 }
+
+/// Check every Jira issue key the report refers to and warn about the ones
+/// Jira doesn't know. Never fails the report: an unconfigured or unreachable
+/// Jira only produces a warning.
+async fn validate_issue_keys(ctx: &Context, user_id: &str, items: &[recap_core::WorkItem]) -> Result<()> {
+    let mappings: HashMap<String, String> = list_project_issue_mappings(&ctx.db.pool, user_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .into_iter()
+        .map(|m| (m.project_path, m.jira_issue_key))
+        .collect();
+
+    let references = referenced_issue_keys(items, &mappings);
+    if references.is_empty() {
+        return Ok(());
+    }
+
+    let Some(client) = jira_client(&ctx.db, user_id).await? else {
+        print_error("Warning: Jira is not configured; skipping issue key validation. Pass --skip-validation to silence this.");
+        return Ok(());
+    };
+
+    let keys: Vec<String> = references.keys().cloned().collect();
+    let lookup = client.batch_get_issues(&keys).await;
+    let unresolved = unresolved_issue_keys(&references, &lookup);
+    if unresolved.is_empty() {
+        return Ok(());
+    }
+
+    print_error(&format!(
+        "Warning: {} of {} Jira issue keys could not be resolved:",
+        unresolved.len(),
+        keys.len()
+    ));
+    for (key, projects, reason) in unresolved {
+        print_error(&format!("  {} (used by {}): {}", key, projects.join(", "), reason));
+    }
+    Ok(())
+}
+
+/// Issue keys referenced by `items`, each with the report projects using it.
+/// An item without its own key falls back to its project's mapping.
+fn referenced_issue_keys(
+    items: &[recap_core::WorkItem],
+    mappings: &HashMap<String, String>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let mut references: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for item in items {
+        let key = item
+            .jira_issue_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .or_else(|| {
+                item.project_path
+                    .as_deref()
+                    .and_then(|p| mappings.get(&normalize_project_path(p)))
+                    .map(String::as_str)
+            });
+        if let Some(key) = key {
+            references
+                .entry(key.to_string())
+                .or_default()
+                .insert(extract_project_name(&item.title));
+        }
+    }
+    references
+}
+
+/// Keys from `references` that `lookup` couldn't resolve, with their projects
+/// and the reason
+fn unresolved_issue_keys(
+    references: &BTreeMap<String, BTreeSet<String>>,
+    lookup: &JiraIssueLookup,
+) -> Vec<(String, Vec<String>, String)> {
+    references
+        .iter()
+        .filter_map(|(key, projects)| {
+            let reason = match lookup.get(key) {
+                Some(Ok(_)) => return None,
+                Some(Err(e)) => e.clone(),
+                None => "not returned by Jira".to_string(),
+            };
+            Some((key.clone(), projects.iter().cloned().collect(), reason))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::services::tempo::{JiraIssue, JiraIssueFields};
+
+    fn item(title: &str, issue_key: Option<&str>, project_path: &str) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "manual".to_string(),
+            source_id: None,
+            source_url: None,
+            title: title.to_string(),
+            description: None,
+            hours: 1.0,
+            date: chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            jira_issue_key: issue_key.map(String::from),
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: None,
+            end_time: None,
+            project_path: Some(project_path.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_referenced_issue_keys_uses_item_key_then_mapping() {
+        let items = vec![
+            item("[recap] fix sync", Some("PROJ-1"), "/repo/recap"),
+            item("[website] landing page", Some("PROJ-1"), "/repo/website"),
+            item("[recap] docs", None, "/repo/recap/"),
+            item("[scratch] notes", Some("  "), "/repo/scratch"),
+        ];
+        let mappings = HashMap::from([("/repo/recap".to_string(), "PROJ-2".to_string())]);
+
+        let references = referenced_issue_keys(&items, &mappings);
+
+        assert_eq!(references.len(), 2);
+        assert_eq!(
+            references["PROJ-1"].iter().collect::<Vec<_>>(),
+            vec!["recap", "website"]
+        );
+        assert_eq!(references["PROJ-2"].iter().collect::<Vec<_>>(), vec!["recap"]);
+    }
+
+    #[test]
+    fn test_unresolved_issue_keys_lists_projects_and_reason() {
+        let references = BTreeMap::from([
+            ("PROJ-1".to_string(), BTreeSet::from(["recap".to_string()])),
+            ("PROJ-9".to_string(), BTreeSet::from(["recap".to_string(), "website".to_string()])),
+            ("PROJ-10".to_string(), BTreeSet::from(["website".to_string()])),
+        ]);
+        let found = JiraIssue {
+            key: "PROJ-1".to_string(),
+            fields: JiraIssueFields { summary: None, description: None, assignee: None, issue_type: None },
+        };
+        let lookup = JiraIssueLookup::from([
+            ("PROJ-1".to_string(), Ok(found)),
+            ("PROJ-9".to_string(), Err("Issue PROJ-9 not found".to_string())),
+        ]);
+
+        let unresolved = unresolved_issue_keys(&references, &lookup);
+
+        assert_eq!(
+            unresolved,
+            vec![
+                (
+                    "PROJ-10".to_string(),
+                    vec!["website".to_string()],
+                    "not returned by Jira".to_string()
+                ),
+                (
+                    "PROJ-9".to_string(),
+                    vec!["recap".to_string(), "website".to_string()],
+                    "Issue PROJ-9 not found".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -5,6 +5,8 @@
 use anyhow::Result;
 use std::collections::HashMap;
 
+use recap_core::services::{JiraAuthType, JiraClient};
+
 /// Extract project name from title with [project] format
 pub fn extract_project_name(title: &str) -> String {
     if let Some(start) = title.find('[') {
@@ -163,6 +165,27 @@ pub async fn get_default_user_id(db: &recap_core::Database) -> Result<String> {
     }
 }
 
+/// Jira client for `user_id`, or `None` when jira_url/jira_pat aren't set
+pub async fn jira_client(db: &recap_core::Database, user_id: &str) -> Result<Option<JiraClient>> {
+    let (jira_url, jira_email, jira_pat): (Option<String>, Option<String>, Option<String>) =
+        sqlx::query_as("SELECT jira_url, jira_email, jira_pat FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_one(&db.pool)
+            .await?;
+
+    let (Some(jira_url), Some(jira_pat)) = (jira_url, jira_pat) else {
+        return Ok(None);
+    };
+
+    // Basic auth (Jira Cloud) when an email is set, PAT otherwise
+    let auth_type = if jira_email.is_some() {
+        JiraAuthType::Basic
+    } else {
+        JiraAuthType::Pat
+    };
+    Ok(Some(JiraClient::new(&jira_url, &jira_pat, jira_email.as_deref(), auth_type)?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,110 @@
+//! Project issue mappings
+//!
+//! Map projects to their default Jira issue for Tempo sync, same as picking
+//! an issue per project in the desktop app's Tempo sync view.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::commands::work::helpers::get_or_create_default_user;
+use crate::commands::Context;
+use crate::output::{print_info, print_output, print_success, write_csv, OutputFormat};
+use super::helpers::jira_client;
+
+#[derive(Debug, Serialize, Tabled)]
+pub struct MappingRow {
+    #[tabled(rename = "Project")]
+    pub project_path: String,
+    #[tabled(rename = "Issue")]
+    pub jira_issue_key: String,
+    #[tabled(rename = "Updated")]
+    pub updated_at: String,
+}
+
+pub async fn map_project(ctx: &Context, project_path: String, issue_key: String, no_validate: bool) -> Result<()> {
+    let issue_key = issue_key.trim().to_uppercase();
+    if !recap_core::services::is_valid_issue_key(&issue_key) {
+        return Err(anyhow!("Invalid Jira issue key: {}. Expected a key like PROJ-123.", issue_key));
+    }
+
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    if !no_validate {
+        validate_issue(ctx, &user_id, &issue_key).await?;
+    }
+
+    let project_path = resolve_project_path(&project_path);
+    let mapping = recap_core::services::save_project_issue_mapping(&ctx.db.pool, &user_id, &project_path, &issue_key)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&mapping)?),
+        OutputFormat::Csv => write_csv(std::slice::from_ref(&mapping), std::io::stdout().lock())?,
+        OutputFormat::Table => print_success(
+            &format!("Mapped {} to {}", mapping.project_path, mapping.jira_issue_key),
+            ctx.quiet,
+        ),
+    }
+    Ok(())
+}
+
+pub async fn list_mappings(ctx: &Context) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let rows: Vec<MappingRow> = recap_core::services::list_project_issue_mappings(&ctx.db.pool, &user_id)
+        .await
+        .map_err(|e| anyhow!(e))?
+        .into_iter()
+        .map(|m| MappingRow {
+            project_path: m.project_path,
+            jira_issue_key: m.jira_issue_key,
+            updated_at: m.updated_at,
+        })
+        .collect();
+
+    if rows.is_empty() && matches!(ctx.format, OutputFormat::Table) {
+        print_info("No project mappings.", ctx.quiet);
+        print_info("Use 'recap tempo map-project <path> <ISSUE-KEY>' to add one.", ctx.quiet);
+        return Ok(());
+    }
+    print_output(&rows, ctx.format)
+}
+
+pub async fn unmap_project(ctx: &Context, project_path: String) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let project_path = resolve_project_path(&project_path);
+    let removed = recap_core::services::delete_project_issue_mapping(&ctx.db.pool, &user_id, &project_path)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+    if !removed {
+        return Err(anyhow!("No mapping for {}", project_path));
+    }
+    print_success(&format!("Removed mapping for {}", project_path), ctx.quiet);
+    Ok(())
+}
+
+/// Absolute path for an existing directory, otherwise the path as given.
+///
+/// Mappings are keyed by the project paths the desktop app records, which
+/// are absolute.
+fn resolve_project_path(path: &str) -> String {
+    std::fs::canonicalize(path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+/// Check that `issue_key` exists in the configured Jira instance
+async fn validate_issue(ctx: &Context, user_id: &str, issue_key: &str) -> Result<()> {
+    let Some(client) = jira_client(&ctx.db, user_id).await? else {
+        return Err(anyhow!(
+            "Jira is not configured. Set jira_url and jira_pat with 'recap config set', or pass --no-validate."
+        ));
+    };
+
+    let (exists, _) = client.validate_issue_key(issue_key).await?;
+    if !exists {
+        return Err(anyhow!("Jira issue {} not found", issue_key));
+    }
+    Ok(())
+}
@@ -1,10 +1,12 @@
 //! Tempo report commands
 //!
-//! Generate smart work summaries for Tempo time logging.
+//! Generate smart work summaries for Tempo time logging and manage the
+//! project-to-issue mappings Tempo sync uses as defaults.
 
 mod format;
 mod generator;
 mod helpers;
+mod mappings;
 mod period;
 mod types;
 
@@ -17,8 +19,13 @@ pub use types::{Period, ProjectSummary, TempoReport, TempoReportAction, WorkItem
 
 pub async fn execute(ctx: &Context, action: TempoReportAction) -> Result<()> {
     match action {
-        TempoReportAction::Generate { period, date, output } => {
-            generator::generate_tempo_report(ctx, period, date, output).await
+        TempoReportAction::Generate { period, date, output, skip_validation } => {
+            generator::generate_tempo_report(ctx, period, date, output, skip_validation).await
         }
+        TempoReportAction::MapProject { project_path, issue_key, no_validate } => {
+            mappings::map_project(ctx, project_path, issue_key, no_validate).await
+        }
+        TempoReportAction::Mappings => mappings::list_mappings(ctx).await,
+        TempoReportAction::Unmap { project_path } => mappings::unmap_project(ctx, project_path).await,
     }
 }
@@ -3,13 +3,21 @@
 //! Functions for resolving report periods to date ranges.
 
 use anyhow::Result;
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, NaiveDate};
+use recap_core::services::{period_bounds, PeriodUnit};
 
 use super::types::Period;
 
-/// Resolve a period specification to a date range
-pub fn resolve_period(period: &Period, date: Option<String>) -> Result<(NaiveDate, NaiveDate, String)> {
-    let today = chrono::Local::now().date_naive();
+/// Resolve a period specification to a date range; the current period is relative to `today`.
+///
+/// Weeks start on `week_start_day` (0=Sun, 1=Mon, ..., 6=Sat); a `date` for a
+/// weekly report picks the week containing it.
+pub fn resolve_period(
+    period: &Period,
+    date: Option<String>,
+    today: NaiveDate,
+    week_start_day: u32,
+) -> Result<(NaiveDate, NaiveDate, String)> {
 
     match period {
         Period::Daily => {
@@ -21,16 +29,12 @@ pub fn resolve_period(period: &Period, date: Option<String>) -> Result<(NaiveDat
             Ok((target, target, format!("Daily ({})", target)))
         }
         Period::Weekly => {
-            let start = match date {
+            let day = match date {
                 Some(d) => NaiveDate::parse_from_str(&d, "%Y-%m-%d")
                     .map_err(|_| anyhow::anyhow!("Invalid date format. Use YYYY-MM-DD"))?,
-                None => {
-                    // Get Monday of current week
-                    let weekday = today.weekday().num_days_from_monday();
-                    today - Duration::days(weekday as i64)
-                }
+                None => today,
             };
-            let end = start + Duration::days(6);
+            let (start, end) = period_bounds(day, PeriodUnit::Week, week_start_day);
             Ok((start, end, format!("Weekly (W{})", start.iso_week().week())))
         }
         Period::Monthly => {
@@ -45,13 +49,9 @@ pub fn resolve_period(period: &Period, date: Option<String>) -> Result<(NaiveDat
                 }
                 None => (today.year(), today.month()),
             };
-            let start = NaiveDate::from_ymd_opt(year, month, 1)
+            let first = NaiveDate::from_ymd_opt(year, month, 1)
                 .ok_or_else(|| anyhow::anyhow!("Invalid month"))?;
-            let end = if month == 12 {
-                NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap() - Duration::days(1)
-            } else {
-                NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap() - Duration::days(1)
-            };
+            let (start, end) = period_bounds(first, PeriodUnit::Month, week_start_day);
             Ok((start, end, format!("Monthly ({}-{:02})", year, month)))
         }
         Period::Quarterly => {
@@ -62,15 +62,9 @@ pub fn resolve_period(period: &Period, date: Option<String>) -> Result<(NaiveDat
                     (today.year(), q)
                 }
             };
-            let start_month = (quarter - 1) * 3 + 1;
-            let end_month = quarter * 3;
-            let start = NaiveDate::from_ymd_opt(year, start_month, 1)
+            let first = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)
                 .ok_or_else(|| anyhow::anyhow!("Invalid quarter"))?;
-            let end = if end_month == 12 {
-                NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap() - Duration::days(1)
-            } else {
-                NaiveDate::from_ymd_opt(year, end_month + 1, 1).unwrap() - Duration::days(1)
-            };
+            let (start, end) = period_bounds(first, PeriodUnit::Quarter, week_start_day);
             Ok((start, end, format!("Quarterly ({}-Q{})", year, quarter)))
         }
         Period::SemiAnnual => {
@@ -81,14 +75,9 @@ pub fn resolve_period(period: &Period, date: Option<String>) -> Result<(NaiveDat
                     (today.year(), h)
                 }
             };
-            let (start_month, end_month) = if half == 1 { (1, 6) } else { (7, 12) };
-            let start = NaiveDate::from_ymd_opt(year, start_month, 1)
+            let first = NaiveDate::from_ymd_opt(year, if half == 1 { 1 } else { 7 }, 1)
                 .ok_or_else(|| anyhow::anyhow!("Invalid half"))?;
-            let end = if end_month == 12 {
-                NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap() - Duration::days(1)
-            } else {
-                NaiveDate::from_ymd_opt(year, end_month + 1, 1).unwrap() - Duration::days(1)
-            };
+            let (start, end) = period_bounds(first, PeriodUnit::Half, week_start_day);
             Ok((start, end, format!("Semi-Annual ({}-H{})", year, half)))
         }
     }
@@ -171,7 +160,7 @@ mod tests {
     #[test]
     fn test_resolve_period_daily_default() {
         let today = chrono::Local::now().date_naive();
-        let (start, end, name) = resolve_period(&Period::Daily, None).unwrap();
+        let (start, end, name) = resolve_period(&Period::Daily, None, chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start, today);
         assert_eq!(end, today);
         assert!(name.contains("Daily"));
@@ -179,24 +168,34 @@ mod tests {
 
     #[test]
     fn test_resolve_period_daily_specific() {
-        let (start, end, _) = resolve_period(&Period::Daily, Some("2025-06-15".to_string())).unwrap();
+        let (start, end, _) = resolve_period(&Period::Daily, Some("2025-06-15".to_string()), chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.to_string(), "2025-06-15");
         assert_eq!(end.to_string(), "2025-06-15");
     }
 
     #[test]
     fn test_resolve_period_weekly_default() {
-        let (start, end, name) = resolve_period(&Period::Weekly, None).unwrap();
+        let (start, end, name) = resolve_period(&Period::Weekly, None, chrono::Local::now().date_naive(), 1).unwrap();
         // Should be 7 days span
         let days = (end - start).num_days();
         assert_eq!(days, 6);
         assert!(name.contains("Weekly"));
     }
 
+    #[test]
+    fn test_resolve_period_weekly_follows_week_start_day() {
+        // 2026-03-04 is a Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let (start, end, _) = resolve_period(&Period::Weekly, None, today, 0).unwrap();
+        assert_eq!((start.to_string(), end.to_string()), ("2026-03-01".to_string(), "2026-03-07".to_string()));
+        let (start, _, _) = resolve_period(&Period::Weekly, Some("2026-03-04".to_string()), today, 1).unwrap();
+        assert_eq!(start.to_string(), "2026-03-02");
+    }
+
     #[test]
     fn test_resolve_period_monthly_default() {
         let today = chrono::Local::now().date_naive();
-        let (start, _end, name) = resolve_period(&Period::Monthly, None).unwrap();
+        let (start, _end, name) = resolve_period(&Period::Monthly, None, chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.day(), 1);
         assert_eq!(start.month(), today.month());
         assert!(name.contains("Monthly"));
@@ -204,14 +203,14 @@ mod tests {
 
     #[test]
     fn test_resolve_period_monthly_specific() {
-        let (start, end, _) = resolve_period(&Period::Monthly, Some("2025-02".to_string())).unwrap();
+        let (start, end, _) = resolve_period(&Period::Monthly, Some("2025-02".to_string()), chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.to_string(), "2025-02-01");
         assert_eq!(end.to_string(), "2025-02-28");
     }
 
     #[test]
     fn test_resolve_period_quarterly_default() {
-        let (start, _end, name) = resolve_period(&Period::Quarterly, None).unwrap();
+        let (start, _end, name) = resolve_period(&Period::Quarterly, None, chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.day(), 1);
         assert!(name.contains("Quarterly"));
         assert!(name.contains("-Q"));
@@ -219,14 +218,14 @@ mod tests {
 
     #[test]
     fn test_resolve_period_quarterly_specific() {
-        let (start, end, _) = resolve_period(&Period::Quarterly, Some("2025-Q1".to_string())).unwrap();
+        let (start, end, _) = resolve_period(&Period::Quarterly, Some("2025-Q1".to_string()), chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.to_string(), "2025-01-01");
         assert_eq!(end.to_string(), "2025-03-31");
     }
 
     #[test]
     fn test_resolve_period_semiannual_default() {
-        let (start, _end, name) = resolve_period(&Period::SemiAnnual, None).unwrap();
+        let (start, _end, name) = resolve_period(&Period::SemiAnnual, None, chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.day(), 1);
         assert!(name.contains("Semi-Annual"));
         assert!(name.contains("-H"));
@@ -234,14 +233,14 @@ mod tests {
 
     #[test]
     fn test_resolve_period_semiannual_h1() {
-        let (start, end, _) = resolve_period(&Period::SemiAnnual, Some("2025-H1".to_string())).unwrap();
+        let (start, end, _) = resolve_period(&Period::SemiAnnual, Some("2025-H1".to_string()), chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.to_string(), "2025-01-01");
         assert_eq!(end.to_string(), "2025-06-30");
     }
 
     #[test]
     fn test_resolve_period_semiannual_h2() {
-        let (start, end, _) = resolve_period(&Period::SemiAnnual, Some("2025-H2".to_string())).unwrap();
+        let (start, end, _) = resolve_period(&Period::SemiAnnual, Some("2025-H2".to_string()), chrono::Local::now().date_naive(), 1).unwrap();
         assert_eq!(start.to_string(), "2025-07-01");
         assert_eq!(end.to_string(), "2025-12-31");
     }
@@ -36,9 +36,35 @@ pub enum TempoReportAction {
         #[arg(short, long)]
         date: Option<String>,
 
-        /// Output format: text, json, or markdown
+        /// Output format: text, json, or markdown (wiki-ready, with a totals table)
         #[arg(short, long, default_value = "text")]
         output: String,
+
+        /// Don't check the report's Jira issue keys against Jira (offline use)
+        #[arg(long)]
+        skip_validation: bool,
+    },
+
+    /// Map a project to its default Jira issue for Tempo sync
+    MapProject {
+        /// Project directory
+        project_path: String,
+
+        /// Jira issue key (e.g. PROJ-123)
+        issue_key: String,
+
+        /// Skip checking that the issue exists in Jira
+        #[arg(long)]
+        no_validate: bool,
+    },
+
+    /// List project-to-issue mappings
+    Mappings,
+
+    /// Remove a project's issue mapping
+    Unmap {
+        /// Project directory
+        project_path: String,
     },
 }
 
@@ -0,0 +1,225 @@
+//! Work item category commands
+//!
+//! List, rename, and merge free-text work item categories, and assign them
+//! to uncategorized items by keyword rules.
+
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+use crate::commands::Context;
+use crate::output::{print_info, print_output, print_success, OutputFormat};
+use super::helpers::{get_or_create_default_user, parse_user_date, user_today};
+use super::types::{CategoryAction, CategoryRow, ClassifyRow};
+
+pub async fn execute(ctx: &Context, action: Option<CategoryAction>) -> Result<()> {
+    match action {
+        None | Some(CategoryAction::List) => list_categories(ctx).await,
+        Some(CategoryAction::Rename { old, new }) => {
+            let updated = rename_category(&ctx.db.pool, &old, &new).await?;
+            print_success(&format!("Renamed '{}' to '{}' ({} item(s) updated)", old, new, updated), ctx.quiet);
+            Ok(())
+        }
+        Some(CategoryAction::Merge { categories, into }) => {
+            let updated = merge_categories(&ctx.db.pool, &categories, &into).await?;
+            print_success(&format!(
+                "Merged {} into '{}' ({} item(s) updated)",
+                categories.join(", "), into, updated
+            ), ctx.quiet);
+            Ok(())
+        }
+    }
+}
+
+async fn list_categories(ctx: &Context) -> Result<()> {
+    let rows: Vec<(String, i64, f64)> = sqlx::query_as(
+        r#"SELECT category, COUNT(*), COALESCE(SUM(hours), 0)
+           FROM work_items
+           WHERE category IS NOT NULL AND category != '' AND deleted_at IS NULL
+           GROUP BY category
+           ORDER BY COUNT(*) DESC, category"#
+    )
+    .fetch_all(&ctx.db.pool)
+    .await?;
+
+    if rows.is_empty() {
+        print_info("No categories found.", ctx.quiet);
+        return Ok(());
+    }
+
+    let rows: Vec<CategoryRow> = rows
+        .into_iter()
+        .map(|(category, items, hours)| CategoryRow {
+            category,
+            items,
+            hours: format!("{:.1}", hours),
+        })
+        .collect();
+    print_output(&rows, ctx.format)
+}
+
+/// `work classify`: rule-based categories for uncategorized items in a range
+pub async fn classify_work_items(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    let end_date = match end {
+        Some(e) => parse_user_date(ctx, &e).await?,
+        None => user_today(ctx).await,
+    };
+    let start_date = match start {
+        Some(s) => parse_user_date(ctx, &s).await?,
+        None => end_date,
+    };
+    if start_date > end_date {
+        return Err(anyhow::anyhow!("Start date {} is after end date {}", start_date, end_date));
+    }
+
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let assignments =
+        recap_core::services::classify_uncategorized_items(&ctx.db.pool, &user_id, start_date, end_date, !dry_run)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+    if assignments.is_empty() {
+        if ctx.format == OutputFormat::Json {
+            println!("[]");
+        } else {
+            print_info("No uncategorized items match a category rule.", ctx.quiet);
+        }
+        return Ok(());
+    }
+
+    let count = assignments.len();
+    let rows: Vec<ClassifyRow> = assignments.into_iter().map(ClassifyRow::from).collect();
+    print_output(&rows, ctx.format)?;
+    if ctx.format == OutputFormat::Table {
+        if dry_run {
+            print_info(&format!("Dry run: {} item(s) would be categorized", count), ctx.quiet);
+        } else {
+            print_success(&format!("Categorized {} item(s)", count), ctx.quiet);
+        }
+    }
+    Ok(())
+}
+
+/// Rename a category on every work item that uses it. Returns the number of updated items.
+pub async fn rename_category(pool: &SqlitePool, old: &str, new: &str) -> Result<u64> {
+    merge_categories(pool, &[old.to_string()], new).await
+}
+
+/// Move every work item in `categories` to `into`. Returns the number of updated items.
+pub async fn merge_categories(pool: &SqlitePool, categories: &[String], into: &str) -> Result<u64> {
+    let into = into.trim();
+    if into.is_empty() {
+        return Err(anyhow::anyhow!("Target category must not be empty"));
+    }
+    if categories.is_empty() {
+        return Ok(0);
+    }
+
+    let placeholders = vec!["?"; categories.len()].join(", ");
+    let query = format!(
+        "UPDATE work_items SET category = ?, updated_at = ? WHERE category IN ({}) AND category != ?",
+        placeholders
+    );
+
+    let mut tx = pool.begin().await?;
+
+    let mut sqlx_query = sqlx::query(&query)
+        .bind(into)
+        .bind(chrono::Utc::now());
+    for category in categories {
+        sqlx_query = sqlx_query.bind(category);
+    }
+    let result = sqlx_query.bind(into).execute(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::test_support::db_with_user;
+
+    async fn insert_item(pool: &SqlitePool, id: &str, category: Option<&str>) {
+        let now = chrono::Utc::now();
+        sqlx::query(
+            "INSERT INTO work_items (id, user_id, source, title, hours, date, category, created_at, updated_at)
+             VALUES (?, 'u1', 'manual', 'work', 1.0, '2026-01-15', ?, ?, ?)"
+        )
+        .bind(id)
+        .bind(category)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn categories_by_id(pool: &SqlitePool) -> Vec<(String, Option<String>)> {
+        sqlx::query_as("SELECT id, category FROM work_items ORDER BY id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rename_category_updates_only_matching_items() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "a", Some("dev")).await;
+        insert_item(&db.pool, "b", Some("dev")).await;
+        insert_item(&db.pool, "c", Some("Dev")).await;
+        insert_item(&db.pool, "d", Some("meeting")).await;
+        insert_item(&db.pool, "e", None).await;
+
+        let updated = rename_category(&db.pool, "dev", "development").await.unwrap();
+
+        assert_eq!(updated, 2);
+        assert_eq!(
+            categories_by_id(&db.pool).await,
+            vec![
+                ("a".to_string(), Some("development".to_string())),
+                ("b".to_string(), Some("development".to_string())),
+                ("c".to_string(), Some("Dev".to_string())),
+                ("d".to_string(), Some("meeting".to_string())),
+                ("e".to_string(), None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_categories() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "a", Some("dev")).await;
+        insert_item(&db.pool, "b", Some("Dev")).await;
+        insert_item(&db.pool, "c", Some("development")).await;
+        insert_item(&db.pool, "d", Some("meeting")).await;
+
+        let sources = vec!["dev".to_string(), "Dev".to_string(), "development".to_string()];
+        let updated = merge_categories(&db.pool, &sources, "development").await.unwrap();
+
+        // Items already in the target category are not counted
+        assert_eq!(updated, 2);
+        let categories: Vec<Option<String>> =
+            categories_by_id(&db.pool).await.into_iter().map(|(_, c)| c).collect();
+        assert_eq!(
+            categories,
+            vec![
+                Some("development".to_string()),
+                Some("development".to_string()),
+                Some("development".to_string()),
+                Some("meeting".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_categories_rejects_empty_target() {
+        let (db, _dir) = db_with_user().await;
+        assert!(merge_categories(&db.pool, &["dev".to_string()], "  ").await.is_err());
+    }
+}
@@ -0,0 +1,213 @@
+//! Work item export
+//!
+//! Dumps work items as JSON (or CSV with `--format csv`). With `--anonymize`
+//! free-text fields are swapped for deterministic placeholders and links to
+//! external systems are dropped, so the dataset can be shared in a bug report
+//! while keeping its shape: dates, hours, sources and parent links survive.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::commands::Context;
+use crate::output::{print_success, write_csv, OutputFormat};
+use super::helpers::{fetch_items_in_range, parse_user_date, user_today};
+
+pub async fn export_work_items(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    output: Option<PathBuf>,
+    anonymize: bool,
+) -> Result<()> {
+    let mut items: Vec<recap_core::WorkItem> = if start.is_none() && end.is_none() {
+        sqlx::query_as("SELECT * FROM work_items WHERE deleted_at IS NULL ORDER BY date, created_at")
+            .fetch_all(&ctx.db.pool)
+            .await?
+    } else {
+        let start_date = match start {
+            Some(s) => parse_user_date(ctx, &s).await?,
+            None => NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date"),
+        };
+        let end_date = match end {
+            Some(e) => parse_user_date(ctx, &e).await?,
+            None => user_today(ctx).await,
+        };
+        fetch_items_in_range(ctx, None, start_date, end_date).await?
+    };
+
+    if anonymize {
+        anonymize_items(&mut items);
+    }
+
+    let mut buf = Vec::new();
+    match ctx.format {
+        OutputFormat::Csv => write_csv(&items, &mut buf)?,
+        OutputFormat::Json | OutputFormat::Table => {
+            serde_json::to_writer_pretty(&mut buf, &items)?;
+            buf.push(b'\n');
+        }
+    }
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, &buf)?;
+            print_success(&format!("Exported {} work items to {}", items.len(), path.display()), ctx.quiet);
+        }
+        None => {
+            use std::io::Write;
+            std::io::stdout().lock().write_all(&buf)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Deterministic placeholder per distinct original value
+struct Placeholders {
+    seen: HashMap<String, String>,
+    label: fn(usize) -> String,
+}
+
+impl Placeholders {
+    fn new(label: fn(usize) -> String) -> Self {
+        Self { seen: HashMap::new(), label }
+    }
+
+    fn get(&mut self, value: &str) -> String {
+        let next = self.seen.len() + 1;
+        let label = self.label;
+        self.seen.entry(value.to_string()).or_insert_with(|| label(next)).clone()
+    }
+
+    fn map(&mut self, value: Option<&str>) -> Option<String> {
+        value.map(|v| self.get(v))
+    }
+}
+
+/// `1 -> A`, `26 -> Z`, `27 -> AA`
+fn letters(mut n: usize) -> String {
+    let mut label = Vec::new();
+    while n > 0 {
+        n -= 1;
+        label.push(b'A' + (n % 26) as u8);
+        n /= 26;
+    }
+    label.reverse();
+    String::from_utf8(label).expect("ASCII letters")
+}
+
+/// Replace free text with placeholders and strip external links.
+///
+/// Equal values map to equal placeholders (numbered in order of first
+/// appearance), so grouping by title, project, category or Jira issue gives
+/// the same shape as the original data. URLs, source IDs and Tempo worklog
+/// IDs are removed, since they can embed hostnames or tokens.
+pub fn anonymize_items(items: &mut [recap_core::WorkItem]) {
+    let mut titles = Placeholders::new(|n| format!("Task {}", n));
+    let mut descriptions = Placeholders::new(|n| format!("Description {}", n));
+    let mut projects = Placeholders::new(|n| format!("Project {}", letters(n)));
+    let mut categories = Placeholders::new(|n| format!("Category {}", letters(n)));
+    let mut issues = Placeholders::new(|n| format!("ISSUE-{}", n));
+    let mut issue_titles = Placeholders::new(|n| format!("Issue {}", n));
+    let mut commits = Placeholders::new(|n| format!("commit-{}", n));
+    let mut sessions = Placeholders::new(|n| format!("session-{}", n));
+
+    for item in items.iter_mut() {
+        item.user_id = "user".to_string();
+        item.title = titles.get(&item.title);
+        item.description = descriptions.map(item.description.as_deref());
+        item.project_path = projects.map(item.project_path.as_deref());
+        item.category = categories.map(item.category.as_deref());
+        item.jira_issue_key = issues.map(item.jira_issue_key.as_deref());
+        item.jira_issue_suggested = issues.map(item.jira_issue_suggested.as_deref());
+        item.jira_issue_title = issue_titles.map(item.jira_issue_title.as_deref());
+        item.commit_hash = commits.map(item.commit_hash.as_deref());
+        item.session_id = sessions.map(item.session_id.as_deref());
+        item.tags = None;
+        item.source_id = None;
+        item.source_url = None;
+        item.tempo_worklog_id = None;
+        item.yearly_goal_id = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, title: &str, hours: f64, date: &str, project: &str) -> recap_core::WorkItem {
+        let now = chrono::Utc::now();
+        recap_core::WorkItem {
+            id: id.to_string(),
+            user_id: "u1".to_string(),
+            source: "claude_code".to_string(),
+            source_id: Some("sess-abc".to_string()),
+            source_url: Some("https://gitlab.internal/acme/api?private_token=glpat-secret".to_string()),
+            title: title.to_string(),
+            description: Some(format!("Worked on {}", title)),
+            hours,
+            date: date.parse().unwrap(),
+            jira_issue_key: Some("ACME-42".to_string()),
+            jira_issue_suggested: None,
+            jira_issue_title: Some("Acme billing".to_string()),
+            category: Some("billing".to_string()),
+            tags: Some(r#"["acme"]"#.to_string()),
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: Some("session".to_string()),
+            hours_estimated: Some(hours),
+            commit_hash: None,
+            session_id: Some("sess-abc".to_string()),
+            start_time: None,
+            end_time: None,
+            project_path: Some(project.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_anonymize_hides_text_and_keeps_shape() {
+        let originals = vec![
+            item("1", "Fix Acme invoice rounding", 2.5, "2026-03-02", "/home/dev/acme-api"),
+            item("2", "Acme SSO login", 1.0, "2026-03-02", "/home/dev/acme-web"),
+            item("3", "Fix Acme invoice rounding", 3.0, "2026-03-04", "/home/dev/acme-api"),
+        ];
+        let mut items = originals.clone();
+        anonymize_items(&mut items);
+
+        let json = serde_json::to_string(&items).unwrap();
+        for original in &originals {
+            assert!(!json.contains(&original.title));
+        }
+        for secret in ["acme", "Acme", "glpat-secret", "gitlab.internal", "ACME-42"] {
+            assert!(!json.contains(secret), "{} leaked", secret);
+        }
+
+        // Hours, dates and sources are untouched
+        let shape = |items: &[recap_core::WorkItem]| -> Vec<(f64, NaiveDate, String)> {
+            items.iter().map(|i| (i.hours, i.date, i.source.clone())).collect()
+        };
+        assert_eq!(shape(&items), shape(&originals));
+
+        // Equal values share a placeholder
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["Task 1", "Task 2", "Task 1"]);
+        let projects: Vec<Option<&str>> = items.iter().map(|i| i.project_path.as_deref()).collect();
+        assert_eq!(projects, vec![Some("Project A"), Some("Project B"), Some("Project A")]);
+        assert_eq!(items[0].jira_issue_key.as_deref(), Some("ISSUE-1"));
+        assert!(items.iter().all(|i| i.source_url.is_none() && i.source_id.is_none()));
+    }
+
+    #[test]
+    fn test_letters() {
+        assert_eq!(letters(1), "A");
+        assert_eq!(letters(26), "Z");
+        assert_eq!(letters(27), "AA");
+    }
+}
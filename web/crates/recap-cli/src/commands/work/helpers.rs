@@ -3,7 +3,11 @@
 //! Shared utilities for work item commands.
 
 use anyhow::Result;
-use chrono::NaiveDate;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::HashMap;
+use sqlx::SqlitePool;
+
+use crate::commands::Context;
 
 /// Truncate string to max characters with ellipsis
 pub fn truncate(s: &str, max_chars: usize) -> String {
@@ -16,26 +20,218 @@ pub fn truncate(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Commit hashes listed by `git rev-list <rev>` in `repo`
+pub fn rev_list_commits(repo: &std::path::Path, rev: &str) -> Result<Vec<String>> {
+    // A leading dash would be read as a rev-list option
+    if rev.is_empty() || rev.starts_with('-') {
+        anyhow::bail!("Invalid git ref: '{}'", rev);
+    }
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["rev-list", rev])
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git rev-list {} failed in {}: {}",
+            rev,
+            repo.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
 /// Parse date string supporting common formats
 pub fn parse_date(s: &str) -> Result<NaiveDate> {
-    if s == "today" {
-        return Ok(chrono::Local::now().date_naive());
+    recap_core::parse_date_arg(s, chrono::Local::now().date_naive()).map_err(anyhow::Error::msg)
+}
+
+/// Claude homes for the CLI user: the comma-separated `claude_session_path`
+/// setting, else the platform default
+pub async fn claude_homes(ctx: &Context) -> Vec<std::path::PathBuf> {
+    let configured: Option<String> = sqlx::query_scalar("SELECT claude_session_path FROM users LIMIT 1")
+        .fetch_optional(&ctx.db.pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten();
+    recap_core::services::claude_homes_or_default(configured.as_deref())
+}
+
+/// First Claude home for the CLI user, for display and single-path checks
+pub async fn claude_home(ctx: &Context) -> Option<std::path::PathBuf> {
+    claude_homes(ctx).await.into_iter().next()
+}
+
+/// Existing `projects` directories under every Claude home
+pub async fn claude_projects_dirs(ctx: &Context) -> Vec<std::path::PathBuf> {
+    claude_homes(ctx)
+        .await
+        .into_iter()
+        .map(|home| home.join("projects"))
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
+
+/// Timezone for this invocation: `--tz` if given, otherwise the user's setting
+pub async fn user_timezone(ctx: &Context) -> Option<String> {
+    if let Some(tz) = &ctx.timezone {
+        return Some(tz.clone());
     }
-    if s == "yesterday" {
-        return Ok(chrono::Local::now().date_naive() - chrono::Duration::days(1));
+
+    sqlx::query_scalar("SELECT timezone FROM users LIMIT 1")
+        .fetch_optional(&ctx.db.pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten()
+}
+
+/// Today's date in the user's timezone (system timezone if unset)
+pub async fn user_today(ctx: &Context) -> NaiveDate {
+    let timezone = user_timezone(ctx).await;
+    recap_core::today_in_timezone(timezone.as_deref(), chrono::Utc::now())
+}
+
+/// The user's `week_start_day` (0=Sun, 1=Mon, ..., 6=Sat), Monday if unset
+pub async fn get_week_start_day(db: &recap_core::Database) -> u32 {
+    let row: Option<(Option<i64>,)> = sqlx::query_as("SELECT week_start_day FROM users LIMIT 1")
+        .fetch_optional(&db.pool)
+        .await
+        .unwrap_or(None);
+
+    row.and_then(|(day,)| day)
+        .filter(|d| (0..=6).contains(d))
+        .unwrap_or(1) as u32
+}
+
+/// Parse a date like [`parse_date`], resolving `today`/`yesterday` in the user's timezone
+pub async fn parse_user_date(ctx: &Context, s: &str) -> Result<NaiveDate> {
+    recap_core::parse_date_arg(s, user_today(ctx).await).map_err(anyhow::Error::msg)
+}
+
+/// Parse a timestamp or date in the user's timezone.
+///
+/// Accepts RFC 3339, offset-less `YYYY-MM-DDTHH:MM:SS` and plain dates, which
+/// mean the start of that day.
+pub async fn parse_user_timestamp(ctx: &Context, s: &str) -> Result<DateTime<Utc>> {
+    let timezone = user_timezone(ctx).await;
+    if let Some(ts) = recap_core::parse_timestamp(s, timezone.as_deref()) {
+        return Ok(ts);
     }
 
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|_| anyhow::anyhow!("Invalid date format: {}. Use YYYY-MM-DD", s))
+    let date = parse_user_date(ctx, s)
+        .await
+        .map_err(|_| anyhow::anyhow!("Invalid timestamp: {}. Use YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS", s))?;
+    recap_core::parse_timestamp(&format!("{}T00:00:00", date), timezone.as_deref())
+        .ok_or_else(|| anyhow::anyhow!("Invalid timestamp: {}", s))
+}
+
+/// Day a work item falls on in `timezone`.
+///
+/// Items with a start time are bucketed by it; others keep their stored date.
+pub fn item_date(item: &recap_core::WorkItem, timezone: Option<&str>) -> NaiveDate {
+    timezone
+        .and(item.start_time.as_deref())
+        .and_then(|start| recap_core::date_in_timezone(start, timezone))
+        .unwrap_or(item.date)
+}
+
+/// Fetch work items whose day in the user's timezone is within `start..=end`.
+///
+/// See [`query_items_in_range`].
+pub async fn fetch_items_in_range(
+    ctx: &Context,
+    user_id: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<recap_core::WorkItem>> {
+    let timezone = user_timezone(ctx).await;
+    query_items_in_range(&ctx.db.pool, user_id, start, end, timezone.as_deref()).await
+}
+
+/// Fetch work items whose day in `timezone` (see [`item_date`]) is within `start..=end`.
+///
+/// Returned items have `date` set to that day, so callers can group by it directly.
+pub async fn query_items_in_range(
+    pool: &SqlitePool,
+    user_id: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+    timezone: Option<&str>,
+) -> Result<Vec<recap_core::WorkItem>> {
+    // A session can shift by at most one day between timezones
+    let items: Vec<recap_core::WorkItem> = sqlx::query_as(
+        r#"SELECT * FROM work_items
+           WHERE (? IS NULL OR user_id = ?) AND date >= ? AND date <= ? AND deleted_at IS NULL
+           ORDER BY date, created_at"#
+    )
+    .bind(user_id)
+    .bind(user_id)
+    .bind((start - Duration::days(1)).to_string())
+    .bind((end + Duration::days(1)).to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut items: Vec<recap_core::WorkItem> = items
+        .into_iter()
+        .filter_map(|mut item| {
+            item.date = item_date(&item, timezone);
+            (item.date >= start && item.date <= end).then_some(item)
+        })
+        .collect();
+    items.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.created_at.cmp(&b.created_at)));
+
+    Ok(items)
+}
+
+/// Fetch the children of all `parent_ids` in one query, grouped by parent ID.
+///
+/// Children of each parent are ordered by date and creation time.
+pub async fn fetch_children_by_parent(
+    pool: &SqlitePool,
+    parent_ids: &[String],
+) -> Result<HashMap<String, Vec<recap_core::WorkItem>>> {
+    let mut grouped: HashMap<String, Vec<recap_core::WorkItem>> = HashMap::new();
+    if parent_ids.is_empty() {
+        return Ok(grouped);
+    }
+
+    let placeholders = vec!["?"; parent_ids.len()].join(", ");
+    let query = format!(
+        "SELECT * FROM work_items WHERE parent_id IN ({}) AND deleted_at IS NULL ORDER BY date, created_at",
+        placeholders
+    );
+
+    let mut sqlx_query = sqlx::query_as::<_, recap_core::WorkItem>(&query);
+    for id in parent_ids {
+        sqlx_query = sqlx_query.bind(id);
+    }
+
+    for child in sqlx_query.fetch_all(pool).await? {
+        if let Some(parent_id) = child.parent_id.clone() {
+            grouped.entry(parent_id).or_default().push(child);
+        }
+    }
+
+    Ok(grouped)
 }
 
 /// Resolve a short ID to full ID
 pub async fn resolve_work_item_id(db: &recap_core::Database, id: &str) -> Result<String> {
     let pattern = format!("{}%", id);
-    let item: Option<(String,)> = sqlx::query_as("SELECT id FROM work_items WHERE id LIKE ? LIMIT 1")
-        .bind(&pattern)
-        .fetch_optional(&db.pool)
-        .await?;
+    // Trashed items only resolve through `work trash restore`
+    let item: Option<(String,)> =
+        sqlx::query_as("SELECT id FROM work_items WHERE id LIKE ? AND deleted_at IS NULL LIMIT 1")
+            .bind(&pattern)
+            .fetch_optional(&db.pool)
+            .await?;
 
     match item {
         Some((full_id,)) => Ok(full_id),
@@ -78,6 +274,7 @@ pub async fn get_or_create_default_user(db: &recap_core::Database) -> Result<Str
 #[cfg(test)]
 mod tests {
     use super::*;
+    use recap_core::test_support::db_with_user;
     use chrono::Datelike;
 
     #[test]
@@ -145,4 +342,40 @@ mod tests {
         assert_eq!(truncate("1234567890", 10), "1234567890");
         assert_eq!(truncate("12345678901", 10), "1234567...");
     }
+
+    #[tokio::test]
+    async fn test_query_items_in_range_buckets_by_timezone() {
+        let (db, _dir) = db_with_user().await;
+
+        // Session starting 17:30 UTC on Jan 15, which is 01:30 on Jan 16 in Taipei
+        let now = chrono::Utc::now();
+        sqlx::query(
+            r#"INSERT INTO work_items (id, user_id, source, title, hours, date, start_time, created_at, updated_at)
+               VALUES ('late', 'u1', 'claude_code', 'late session', 1.0, '2026-01-15', '2026-01-15T17:30:00Z', ?, ?)"#
+        )
+        .bind(now)
+        .bind(now)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let jan15 = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let jan16 = NaiveDate::from_ymd_opt(2026, 1, 16).unwrap();
+
+        let utc = query_items_in_range(&db.pool, None, jan15, jan16, Some("UTC")).await.unwrap();
+        assert_eq!(utc.len(), 1);
+        assert_eq!(utc[0].date, jan15);
+
+        let taipei = query_items_in_range(&db.pool, None, jan15, jan16, Some("Asia/Taipei")).await.unwrap();
+        assert_eq!(taipei.len(), 1);
+        assert_eq!(taipei[0].date, jan16);
+
+        // A single-day report only includes the session under the matching timezone
+        assert!(query_items_in_range(&db.pool, None, jan15, jan15, Some("Asia/Taipei")).await.unwrap().is_empty());
+        assert_eq!(query_items_in_range(&db.pool, None, jan16, jan16, Some("Asia/Taipei")).await.unwrap().len(), 1);
+
+        // Without a timezone the stored date is kept
+        let stored = query_items_in_range(&db.pool, None, jan15, jan15, None).await.unwrap();
+        assert_eq!(stored[0].date, jan15);
+    }
 }
@@ -0,0 +1,267 @@
+//! Work item import
+//!
+//! Bulk-loads legacy time logs: a JSON array of `CreateWorkItem`, or a CSV
+//! with `title,hours,date,category,jira` columns. Items are stored with
+//! `source = "imported"` and a content hash over title and date, so
+//! importing the same file again skips rows that are already there. Bad rows
+//! are collected and reported after the rest are imported.
+
+use anyhow::{Context as _, Result};
+use recap_core::CreateWorkItem;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::commands::Context;
+use crate::output::{print_error, print_info, print_output, print_success, OutputFormat};
+use super::helpers::{get_or_create_default_user, parse_date};
+use super::types::{ImportFailureRow, ImportFormat, ImportSummary};
+
+/// `source` of imported work items
+pub const IMPORTED_SOURCE: &str = "imported";
+
+/// Most hours a single imported row can hold
+const MAX_ROW_HOURS: f64 = 24.0;
+
+/// One CSV record
+#[derive(Debug, Deserialize)]
+struct CsvImportRow {
+    title: String,
+    hours: Option<f64>,
+    date: String,
+    category: Option<String>,
+    jira: Option<String>,
+}
+
+pub async fn import_work_items(
+    ctx: &Context,
+    path: PathBuf,
+    format: Option<ImportFormat>,
+    dry_run: bool,
+) -> Result<()> {
+    let format = match format {
+        Some(f) => f,
+        None => format_from_extension(&path)?,
+    };
+    let content = std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let rows = match format {
+        ImportFormat::Json => parse_json(&content)?,
+        ImportFormat::Csv => parse_csv(&content),
+    };
+
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let mut summary = ImportSummary {
+        dry_run,
+        ..Default::default()
+    };
+    let mut seen = HashSet::new();
+    let mut tx = ctx.db.pool.begin().await?;
+
+    for (row, parsed) in rows.into_iter().enumerate().map(|(i, r)| (i + 1, r)) {
+        let item = match parsed.and_then(validate) {
+            Ok(item) => item,
+            Err(error) => {
+                summary.failures.push(ImportFailureRow { row, error });
+                continue;
+            }
+        };
+
+        let hash = import_hash(&user_id, &item);
+        let exists: bool = sqlx::query_scalar("SELECT COUNT(*) > 0 FROM work_items WHERE user_id = ? AND content_hash = ?")
+            .bind(&user_id)
+            .bind(&hash)
+            .fetch_one(&mut *tx)
+            .await?;
+        if exists || !seen.insert(hash.clone()) {
+            summary.skipped += 1;
+            continue;
+        }
+
+        if !dry_run {
+            insert_item(&mut tx, &user_id, &item, &hash).await?;
+        }
+        summary.inserted += 1;
+    }
+
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+    summary.failed = summary.failures.len();
+
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&summary)?),
+        OutputFormat::Table | OutputFormat::Csv => {
+            let verb = if dry_run { "Would import" } else { "Imported" };
+            let message = format!(
+                "{} {} item(s), skipped {} duplicate(s), {} row(s) failed",
+                verb, summary.inserted, summary.skipped, summary.failed
+            );
+            if dry_run {
+                print_info(&format!("Dry run: {}", message), ctx.quiet);
+            } else {
+                print_success(&message, ctx.quiet);
+            }
+            if !summary.failures.is_empty() {
+                print_error("Rows that could not be imported:");
+                print_output(&summary.failures, ctx.format)?;
+            }
+        }
+    }
+
+    if summary.failed > 0 {
+        return Err(anyhow::anyhow!("{} row(s) failed to import", summary.failed));
+    }
+    Ok(())
+}
+
+fn format_from_extension(path: &Path) -> Result<ImportFormat> {
+    match path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+        Some("json") => Ok(ImportFormat::Json),
+        Some("csv") => Ok(ImportFormat::Csv),
+        _ => Err(anyhow::anyhow!(
+            "Cannot tell the format of {}; pass --input-format json or csv",
+            path.display()
+        )),
+    }
+}
+
+/// Each array element on its own, so one malformed item doesn't sink the file
+fn parse_json(content: &str) -> Result<Vec<Result<CreateWorkItem, String>>> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_str(content).context("Expected a JSON array of work items")?;
+    Ok(values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+        .collect())
+}
+
+fn parse_csv(content: &str) -> Vec<Result<CreateWorkItem, String>> {
+    let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(content.as_bytes());
+    reader
+        .deserialize::<CsvImportRow>()
+        .map(|record| {
+            let record = record.map_err(|e| e.to_string())?;
+            let date = parse_date(&record.date).map_err(|e| e.to_string())?;
+            Ok(CreateWorkItem {
+                title: record.title,
+                description: None,
+                hours: record.hours,
+                date,
+                source: None,
+                source_id: None,
+                jira_issue_key: record.jira.filter(|j| !j.is_empty()),
+                jira_issue_title: None,
+                category: record.category.filter(|c| !c.is_empty()),
+                tags: None,
+                project_name: None,
+            })
+        })
+        .collect()
+}
+
+fn validate(mut item: CreateWorkItem) -> Result<CreateWorkItem, String> {
+    item.title = item.title.trim().to_string();
+    if item.title.is_empty() {
+        return Err("title is empty".to_string());
+    }
+    match item.hours {
+        None => return Err("hours is missing".to_string()),
+        Some(h) if !(h > 0.0 && h <= MAX_ROW_HOURS) => {
+            return Err(format!("hours must be above 0 and at most {}, got {}", MAX_ROW_HOURS, h));
+        }
+        Some(_) => {}
+    }
+    if let Some(key) = &item.jira_issue_key {
+        if !recap_core::services::is_valid_issue_key(key) {
+            return Err(format!("'{}' is not a Jira issue key", key));
+        }
+    }
+    Ok(item)
+}
+
+/// Imported items have no source ID, so title and date identify them
+fn import_hash(user_id: &str, item: &CreateWorkItem) -> String {
+    recap_core::services::compute_content_hash(user_id, IMPORTED_SOURCE, "", &item.title, &item.date.to_string())
+}
+
+async fn insert_item(
+    conn: &mut sqlx::SqliteConnection,
+    user_id: &str,
+    item: &CreateWorkItem,
+    content_hash: &str,
+) -> Result<()> {
+    let now = chrono::Utc::now();
+    let tags = item.tags.as_ref().map(|t| serde_json::to_string(t).unwrap_or_default());
+    sqlx::query(
+        r#"
+        INSERT INTO work_items (id, user_id, source, source_id, title, description, hours, date,
+            jira_issue_key, jira_issue_title, category, tags, content_hash, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(user_id)
+    .bind(IMPORTED_SOURCE)
+    .bind(&item.source_id)
+    .bind(&item.title)
+    .bind(&item.description)
+    .bind(item.hours)
+    .bind(item.date)
+    .bind(&item.jira_issue_key)
+    .bind(&item.jira_issue_title)
+    .bind(&item.category)
+    .bind(&tags)
+    .bind(content_hash)
+    .bind(now)
+    .bind(now)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_collects_bad_rows() {
+        let csv = "title,hours,date,category,jira\n\
+                   Fix login,2,2026-03-02,Bugfix,ACME-1\n\
+                   Standup,lots,2026-03-02,,\n\
+                   Release notes,1.5,2026-03-03,,\n";
+        let rows = parse_csv(csv);
+        assert_eq!(rows.len(), 3);
+
+        let first = rows[0].as_ref().unwrap();
+        assert_eq!(first.title, "Fix login");
+        assert_eq!(first.jira_issue_key.as_deref(), Some("ACME-1"));
+        assert!(rows[1].is_err());
+        assert_eq!(rows[2].as_ref().unwrap().category, None);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_fields() {
+        let item = |title: &str, hours: Option<f64>, jira: Option<&str>| CreateWorkItem {
+            title: title.to_string(),
+            description: None,
+            hours,
+            date: "2026-03-02".parse().unwrap(),
+            source: None,
+            source_id: None,
+            jira_issue_key: jira.map(str::to_string),
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            project_name: None,
+        };
+
+        assert_eq!(validate(item("  Fix login ", Some(2.0), Some("ACME-1"))).unwrap().title, "Fix login");
+        assert_eq!(validate(item(" ", Some(2.0), None)).unwrap_err(), "title is empty");
+        assert_eq!(validate(item("x", None, None)).unwrap_err(), "hours is missing");
+        assert!(validate(item("x", Some(0.0), None)).is_err());
+        assert!(validate(item("x", Some(25.0), None)).is_err());
+        assert!(validate(item("x", Some(1.0), Some("acme 1"))).is_err());
+    }
+}
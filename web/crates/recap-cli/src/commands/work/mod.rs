@@ -1,23 +1,66 @@
 //! Work item commands
 //!
-//! Commands for managing work items: list, add, update, delete.
+//! Commands for managing work items: list, add, update, delete, prune, search, export, import, stats,
+//! classify, categories, trash, rehash.
 
+mod categories;
+mod export;
 pub mod helpers;
+mod import;
 mod mutations;
 mod queries;
+mod stats;
+mod trash;
 mod types;
 
 use anyhow::Result;
+use recap_core::WorkItemFilters;
+use std::path::Path;
 
 use crate::commands::Context;
 
 // Re-export public types
+pub use stats::get_daily_goal;
 pub use types::{WorkAction, WorkItemRow};
 
 pub async fn execute(ctx: &Context, action: WorkAction) -> Result<()> {
     match action {
-        WorkAction::List { date, start, end, source, limit } => {
-            queries::list_work_items(ctx, date, start, end, source, limit).await
+        WorkAction::List {
+            date, start, end, source, exclude_source, limit, all, page, per_page, group_children, synced_after,
+            synced_before, changed_since, repo, count_by, totals, ndjson, include_deleted,
+        } => {
+            // --limit is a page size with page 1; --per-page alone starts at page 1
+            let paginated = page.is_some() || per_page.is_some();
+            let per_page = per_page.or((!all && limit != 0).then_some(limit));
+            if paginated {
+                if per_page.is_none_or(|n| n <= 0) {
+                    anyhow::bail!("Page size must be at least 1");
+                }
+                if page.is_some_and(|p| p < 1) {
+                    anyhow::bail!("Pages start at 1");
+                }
+            }
+            let filters = WorkItemFilters {
+                source,
+                exclude_source: (!exclude_source.is_empty()).then_some(exclude_source),
+                page: paginated.then(|| page.unwrap_or(1)),
+                // No page size means all items, up to the safety cap
+                per_page,
+                synced_after: parse_optional_timestamp(ctx, synced_after).await?,
+                synced_before: parse_optional_timestamp(ctx, synced_before).await?,
+                commit_hashes: match changed_since {
+                    Some(rev) => Some(helpers::rev_list_commits(repo.as_deref().unwrap_or(Path::new(".")), &rev)?),
+                    None => None,
+                },
+                ..Default::default()
+            };
+            filters.validate_sources().map_err(|e| anyhow::anyhow!(e))?;
+            match count_by {
+                Some(field) => queries::count_work_items(ctx, date, start, end, filters, field).await,
+                None if ndjson => queries::stream_work_items(ctx, date, start, end, filters).await,
+                None if include_deleted => queries::list_work_items_with_deleted(ctx, date, start, end, filters).await,
+                None => queries::list_work_items(ctx, date, start, end, filters, group_children, totals).await,
+            }
         }
         WorkAction::Add { title, hours, date, description, category, jira } => {
             mutations::add_work_item(ctx, title, hours, date, description, category, jira).await
@@ -28,8 +71,45 @@ pub async fn execute(ctx: &Context, action: WorkAction) -> Result<()> {
         WorkAction::Delete { id, force } => {
             mutations::delete_work_item(ctx, id, force).await
         }
-        WorkAction::Show { id } => {
-            queries::show_work_item(ctx, id).await
+        WorkAction::Prune { start, end, source, category, force } => {
+            mutations::prune_work_items(ctx, start, end, source, category, force).await
         }
+        WorkAction::Show { id, full } => {
+            queries::show_work_item(ctx, id, full).await
+        }
+        WorkAction::Search { query, limit } => {
+            queries::search_work_items(ctx, query, limit).await
+        }
+        WorkAction::Export { start, end, output, anonymize } => {
+            export::export_work_items(ctx, start, end, output, anonymize).await
+        }
+        WorkAction::Import { path, format, dry_run } => {
+            import::import_work_items(ctx, path, format, dry_run).await
+        }
+        WorkAction::Stats { date, start, end, compare_to } => {
+            stats::show_stats(ctx, date, start, end, compare_to).await
+        }
+        WorkAction::Classify { start, end, dry_run } => {
+            categories::classify_work_items(ctx, start, end, dry_run).await
+        }
+        WorkAction::Categories { action } => {
+            categories::execute(ctx, action).await
+        }
+        WorkAction::Trash { action } => {
+            trash::execute(ctx, action).await
+        }
+        WorkAction::Rehash { dry_run } => {
+            crate::commands::db::repair_hashes(ctx, !dry_run).await
+        }
+    }
+}
+
+async fn parse_optional_timestamp(
+    ctx: &Context,
+    value: Option<String>,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+    match value {
+        Some(v) => Ok(Some(helpers::parse_user_timestamp(ctx, &v).await?)),
+        None => Ok(None),
     }
 }
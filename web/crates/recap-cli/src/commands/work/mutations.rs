@@ -5,8 +5,8 @@
 use anyhow::Result;
 
 use crate::commands::Context;
-use crate::output::{print_error, print_single, print_success};
-use super::helpers::{get_or_create_default_user, parse_date, resolve_work_item_id};
+use crate::output::{print_error, print_single, print_success, OutputFormat};
+use super::helpers::{get_or_create_default_user, parse_date, parse_user_date, resolve_work_item_id};
 use super::types::WorkItemRow;
 
 pub async fn add_work_item(
@@ -99,7 +99,7 @@ pub async fn update_work_item(
     }
 
     let query = format!(
-        "UPDATE work_items SET {} WHERE id = ?",
+        "UPDATE work_items SET {} WHERE id = ? AND deleted_at IS NULL",
         updates.join(", ")
     );
     bindings.push(full_id.clone());
@@ -109,7 +109,10 @@ pub async fn update_work_item(
         sqlx_query = sqlx_query.bind(binding);
     }
 
-    sqlx_query.execute(&ctx.db.pool).await?;
+    // The item may have been trashed since its id was resolved
+    if sqlx_query.execute(&ctx.db.pool).await?.rows_affected() == 0 {
+        anyhow::bail!("Work item not found: {}", id);
+    }
 
     print_success(&format!("Updated work item: {}", &full_id[..8]), ctx.quiet);
 
@@ -131,12 +134,94 @@ pub async fn delete_work_item(ctx: &Context, id: String, force: bool) -> Result<
         return Ok(());
     }
 
-    sqlx::query("DELETE FROM work_items WHERE id = ?")
-        .bind(&full_id)
-        .execute(&ctx.db.pool)
-        .await?;
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let trashed = recap_core::services::trash_work_item(&ctx.db.pool, &user_id, &full_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    if !trashed {
+        return Err(anyhow::anyhow!("Work item is already in the trash: {}", &full_id[..8]));
+    }
+
+    print_success(
+        &format!("Moved work item to trash: {} (undo with `recap work trash restore {}`)", &full_id[..8], &full_id[..8]),
+        ctx.quiet,
+    );
+
+    Ok(())
+}
 
-    print_success(&format!("Deleted work item: {}", &full_id[..8]), ctx.quiet);
+/// Delete all of the user's work items matching the filters.
+///
+/// Without `force` only the number of matching items is printed. At least
+/// one filter is required so a bare `prune` can't wipe every item.
+pub async fn prune_work_items(
+    ctx: &Context,
+    start: Option<String>,
+    end: Option<String>,
+    source: Option<String>,
+    category: Option<String>,
+    force: bool,
+) -> Result<()> {
+    if start.is_none() && end.is_none() && source.is_none() && category.is_none() {
+        return Err(anyhow::anyhow!(
+            "Refusing to prune without filters. Pass --start, --end, --source or --category."
+        ));
+    }
 
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let mut conditions = String::from("user_id = ?");
+    let mut bindings = vec![user_id];
+    if let Some(start) = start {
+        conditions.push_str(" AND date >= ?");
+        bindings.push(parse_user_date(ctx, &start).await?.to_string());
+    }
+    if let Some(end) = end {
+        conditions.push_str(" AND date <= ?");
+        bindings.push(parse_user_date(ctx, &end).await?.to_string());
+    }
+    if let Some(source) = source {
+        conditions.push_str(" AND source = ?");
+        bindings.push(source);
+    }
+    if let Some(category) = category {
+        conditions.push_str(" AND category = ?");
+        bindings.push(category);
+    }
+
+    let count_sql = format!("SELECT COUNT(*) FROM work_items WHERE {}", conditions);
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+    for value in &bindings {
+        count_query = count_query.bind(value);
+    }
+    let matching = count_query.fetch_one(&ctx.db.pool).await?;
+
+    if !force {
+        match ctx.format {
+            OutputFormat::Json => {
+                println!("{}", serde_json::json!({ "matching": matching, "deleted": 0 }));
+            }
+            _ => {
+                print_success(&format!("{} work item(s) match", matching), ctx.quiet);
+                if matching > 0 {
+                    print_error("Use --force to delete them");
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let delete_sql = format!("DELETE FROM work_items WHERE {}", conditions);
+    let mut delete_query = sqlx::query(&delete_sql);
+    for value in &bindings {
+        delete_query = delete_query.bind(value);
+    }
+    let deleted = delete_query.execute(&ctx.db.pool).await?.rows_affected();
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::json!({ "matching": matching, "deleted": deleted }));
+        }
+        _ => print_success(&format!("Deleted {} work item(s)", deleted), ctx.quiet),
+    }
     Ok(())
 }
@@ -3,45 +3,394 @@
 //! Read operations for work items.
 
 use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use futures::TryStreamExt;
+use recap_core::WorkItemFilters;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
 
 use crate::commands::Context;
-use crate::output::{print_output, print_single};
-use super::helpers::{parse_date, resolve_work_item_id};
-use super::types::WorkItemRow;
+use crate::output::{print_error, print_info, print_output, write_csv, OutputFormat};
+use super::helpers::{
+    fetch_children_by_parent, fetch_items_in_range, item_date, parse_user_date, resolve_work_item_id, user_timezone,
+};
+use super::types::{
+    work_item_fields, CountField, FieldCountRow, WorkItemAuditRow, WorkItemRow, WorkItemTreeRow, WorkListOutput, WorkListPage,
+    WorkListTotals,
+};
+
+/// Hard cap on rows returned by `work list --all`
+const WORK_LIST_MAX_ITEMS: i64 = 100_000;
 
 pub async fn list_work_items(
     ctx: &Context,
     date: Option<String>,
     start: Option<String>,
     end: Option<String>,
-    source: Option<String>,
-    limit: i64,
+    filters: WorkItemFilters,
+    group_children: bool,
+    totals: bool,
 ) -> Result<()> {
-    let mut query = String::from(
-        "SELECT * FROM work_items WHERE 1=1"
-    );
+    // Without a limit, fetch one row past the cap to tell whether it was hit
+    let limit = filters.per_page.unwrap_or(WORK_LIST_MAX_ITEMS + 1);
+    let range = resolve_list_range(ctx, date, start, end).await?;
+
+    let offset = filters.page.map_or(0, |page| (page - 1) * limit);
+
+    // Total matches are only counted for paginated output
+    let (items, total): (Vec<recap_core::WorkItem>, Option<i64>) = if let Some((start_date, end_date)) = range {
+        let mut items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
+        items.retain(|item| filters.matches_source(item));
+        items.retain(|item| filters.matches_synced_range(item));
+        items.retain(|item| filters.matches_commit(item));
+        if group_children {
+            items.retain(|item| item.parent_id.is_none());
+        }
+        let total = filters.page.map(|_| items.len() as i64);
+        items.reverse();
+        let items = items
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect();
+        (items, total)
+    } else {
+        let items = query_recent_items(&ctx.db.pool, &filters, group_children, limit, offset).await?;
+        let total = match filters.page {
+            Some(_) => Some(count_recent_items(&ctx.db.pool, &filters, group_children).await?),
+            None => None,
+        };
+        (items, total)
+    };
+    let items = apply_safety_cap(items, filters.per_page.is_none());
+    let page = total.map(|total| WorkListPage::new(filters.page.unwrap_or(1), limit, total));
+
+    if group_children {
+        let tree = nest_children(&ctx.db.pool, items).await?;
+        return match ctx.format {
+            OutputFormat::Json => {
+                match page {
+                    Some(page) => {
+                        let output = WorkListOutput { items: tree, page: Some(page), totals: None };
+                        println!("{}", serde_json::to_string_pretty(&output)?);
+                    }
+                    None => println!("{}", serde_json::to_string_pretty(&tree)?),
+                }
+                Ok(())
+            }
+            // CSV is flat, so children are listed under their parent as in the table
+            OutputFormat::Table | OutputFormat::Csv => {
+                let rows: Vec<WorkItemRow> = tree
+                    .into_iter()
+                    .flat_map(WorkItemTreeRow::into_table_rows)
+                    .collect();
+                print_output(&rows, ctx.format)?;
+                print_page_footer(ctx, page.as_ref());
+                Ok(())
+            }
+        };
+    }
+
+    let list_totals = totals.then(|| WorkListTotals::from_items(&items));
+    let mut rows: Vec<WorkItemRow> = items.into_iter().map(WorkItemRow::from).collect();
+    match (list_totals, ctx.format) {
+        (list_totals, OutputFormat::Json) if list_totals.is_some() || page.is_some() => {
+            let output = WorkListOutput { items: rows, page, totals: list_totals };
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        (Some(list_totals), OutputFormat::Table) if !rows.is_empty() => {
+            rows.push(list_totals.table_row());
+            print_output(&rows, ctx.format)?;
+            print_page_footer(ctx, page.as_ref());
+        }
+        _ => {
+            print_output(&rows, ctx.format)?;
+            print_page_footer(ctx, page.as_ref());
+        }
+    }
+
+    Ok(())
+}
+
+/// `work list --ndjson`: write each matching item as one line of JSON while
+/// reading rows from a cursor, so memory stays flat however many rows match.
+///
+/// Rows come newest first by stored date; with a date range they are narrowed
+/// to the user's local days as they stream, without re-sorting across the
+/// one-day margin. `per_page` (from `--limit`) caps the rows written.
+pub async fn stream_work_items(
+    ctx: &Context,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    filters: WorkItemFilters,
+) -> Result<()> {
+    let range = resolve_list_range(ctx, date, start, end).await?;
+    let timezone = user_timezone(ctx).await;
+
+    let (query, bindings) = build_list_query(&filters, range, false);
+    let mut sqlx_query = sqlx::query_as::<_, recap_core::WorkItem>(&query);
+    for binding in &bindings {
+        sqlx_query = sqlx_query.bind(binding);
+    }
+    let mut rows = sqlx_query.fetch(&ctx.db.pool);
+
+    let limit = filters.per_page.map(|n| n.max(0) as usize);
+    let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+    let mut written = 0;
+    while let Some(mut item) = rows.try_next().await? {
+        if limit.is_some_and(|limit| written >= limit) {
+            break;
+        }
+        if let Some((start_date, end_date)) = range {
+            item.date = item_date(&item, timezone.as_deref());
+            if item.date < start_date || item.date > end_date {
+                continue;
+            }
+        }
+        let line = serde_json::to_string(&WorkItemRow::from(item))?;
+        match writeln!(out, "{}", line) {
+            // The reader went away (e.g. `| head`); nothing more to write
+            Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+        written += 1;
+    }
+    match out.flush() {
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Build the newest-first list query for `filters`, widening `range` by a day
+/// on each side so rows can be re-dated in the user's timezone afterwards
+fn build_list_query(
+    filters: &WorkItemFilters,
+    range: Option<(NaiveDate, NaiveDate)>,
+    include_deleted: bool,
+) -> (String, Vec<String>) {
+    let mut query = String::from(if include_deleted {
+        "SELECT * FROM work_items WHERE 1 = 1"
+    } else {
+        "SELECT * FROM work_items WHERE deleted_at IS NULL"
+    });
     let mut bindings: Vec<String> = Vec::new();
+    push_filter_conditions(&mut query, &mut bindings, filters);
+    if let Some((start_date, end_date)) = range {
+        // A session can shift by at most one day between timezones
+        query.push_str(" AND date >= ? AND date <= ?");
+        bindings.push((start_date - Duration::days(1)).to_string());
+        bindings.push((end_date + Duration::days(1)).to_string());
+    }
+    query.push_str(" ORDER BY date DESC, created_at DESC");
+    (query, bindings)
+}
+
+/// A work item row together with its trash timestamp
+#[derive(sqlx::FromRow)]
+struct WorkItemWithDeletedAt {
+    #[sqlx(flatten)]
+    item: recap_core::WorkItem,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+/// List work items including trashed ones, showing when each was deleted
+pub async fn list_work_items_with_deleted(
+    ctx: &Context,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    filters: WorkItemFilters,
+) -> Result<()> {
+    let range = resolve_list_range(ctx, date, start, end).await?;
+    let timezone = user_timezone(ctx).await;
+
+    let (query, bindings) = build_list_query(&filters, range, true);
+    let mut sqlx_query = sqlx::query_as::<_, WorkItemWithDeletedAt>(&query);
+    for binding in &bindings {
+        sqlx_query = sqlx_query.bind(binding);
+    }
+    let mut rows = sqlx_query.fetch_all(&ctx.db.pool).await?;
 
-    // Handle date filtering
-    if let Some(d) = date {
-        let parsed_date = parse_date(&d)?;
-        query.push_str(" AND date = ?");
-        bindings.push(parsed_date.to_string());
+    if let Some((start_date, end_date)) = range {
+        rows.retain_mut(|row| {
+            row.item.date = item_date(&row.item, timezone.as_deref());
+            row.item.date >= start_date && row.item.date <= end_date
+        });
+    }
+    let limit = filters.per_page.unwrap_or(WORK_LIST_MAX_ITEMS).max(0) as usize;
+    let rows: Vec<WorkItemAuditRow> = rows
+        .into_iter()
+        .take(limit)
+        .map(|row| WorkItemAuditRow::new(row.item, row.deleted_at))
+        .collect();
+
+    print_output(&rows, ctx.format)?;
+    Ok(())
+}
+
+/// Print "Page X of Y" under table output of a paginated list
+fn print_page_footer(ctx: &Context, page: Option<&WorkListPage>) {
+    if let (Some(page), OutputFormat::Table) = (page, ctx.format) {
+        print_info(&page.footer(), ctx.quiet);
+    }
+}
+
+/// Count items matching the list filters per value of `field`, most common first
+pub async fn count_work_items(
+    ctx: &Context,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    filters: WorkItemFilters,
+    field: CountField,
+) -> Result<()> {
+    let range = resolve_list_range(ctx, date, start, end).await?;
+
+    let counts: Vec<(Option<String>, i64)> = if let Some((start_date, end_date)) = range {
+        // Day bucketing depends on the timezone, so range counts are done in memory
+        let mut items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
+        items.retain(|item| filters.matches_source(item));
+        items.retain(|item| filters.matches_synced_range(item));
+        items.retain(|item| filters.matches_commit(item));
+        items.iter().map(|item| (count_field_value(item, field), 1)).collect()
+    } else {
+        query_field_counts(&ctx.db.pool, &filters, field).await?
+    };
+
+    let rows = merge_field_counts(counts, field);
+    match ctx.format {
+        OutputFormat::Json => {
+            let map: serde_json::Map<String, serde_json::Value> = rows
+                .into_iter()
+                .map(|row| (row.value, row.count.into()))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&map)?);
+            Ok(())
+        }
+        OutputFormat::Table | OutputFormat::Csv => print_output(&rows, ctx.format),
+    }
+}
+
+/// Shown for items with no value in the counted field
+const NO_VALUE: &str = "(none)";
+
+/// Date range from `--date` or `--start`/`--end`, bucketed by day in the user's timezone
+async fn resolve_list_range(
+    ctx: &Context,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<Option<(NaiveDate, NaiveDate)>> {
+    Ok(if let Some(d) = date {
+        let parsed_date = parse_user_date(ctx, &d).await?;
+        Some((parsed_date, parsed_date))
     } else if let (Some(s), Some(e)) = (start, end) {
-        let start_date = parse_date(&s)?;
-        let end_date = parse_date(&e)?;
-        query.push_str(" AND date >= ? AND date <= ?");
-        bindings.push(start_date.to_string());
-        bindings.push(end_date.to_string());
+        Some((parse_user_date(ctx, &s).await?, parse_user_date(ctx, &e).await?))
+    } else {
+        None
+    })
+}
+
+/// Column grouped for `field`; projects are grouped by path and reduced to names afterwards
+fn count_field_column(field: CountField) -> &'static str {
+    match field {
+        CountField::Source => "source",
+        CountField::Category => "category",
+        CountField::Project => "project_path",
+        CountField::Jira => "jira_issue_key",
     }
+}
 
-    if let Some(src) = source {
-        query.push_str(" AND source = ?");
-        bindings.push(src);
+fn count_field_value(item: &recap_core::WorkItem, field: CountField) -> Option<String> {
+    match field {
+        CountField::Source => Some(item.source.clone()),
+        CountField::Category => item.category.clone(),
+        CountField::Project => item.project_path.clone(),
+        CountField::Jira => item.jira_issue_key.clone(),
+    }
+}
+
+/// Grouped count of items matching `filters`, keyed by the raw column value
+async fn query_field_counts(
+    pool: &SqlitePool,
+    filters: &WorkItemFilters,
+    field: CountField,
+) -> Result<Vec<(Option<String>, i64)>> {
+    let column = count_field_column(field);
+    let mut query = format!("SELECT {column}, COUNT(*) FROM work_items WHERE deleted_at IS NULL");
+    let mut bindings: Vec<String> = Vec::new();
+    push_filter_conditions(&mut query, &mut bindings, filters);
+    query.push_str(&format!(" GROUP BY {column}"));
+
+    let mut sqlx_query = sqlx::query_as::<_, (Option<String>, i64)>(&query);
+    for binding in &bindings {
+        sqlx_query = sqlx_query.bind(binding);
+    }
+
+    Ok(sqlx_query.fetch_all(pool).await?)
+}
+
+/// Combine raw counts into display rows, most common first then by value
+fn merge_field_counts(counts: Vec<(Option<String>, i64)>, field: CountField) -> Vec<FieldCountRow> {
+    let mut merged: HashMap<String, i64> = HashMap::new();
+    for (value, count) in counts {
+        let value = value.filter(|v| !v.trim().is_empty());
+        let key = match (field, value) {
+            (CountField::Project, Some(path)) => Path::new(&path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.to_string())
+                .unwrap_or(path),
+            (_, Some(value)) => value,
+            (_, None) => NO_VALUE.to_string(),
+        };
+        *merged.entry(key).or_insert(0) += count;
+    }
+
+    let mut rows: Vec<FieldCountRow> = merged
+        .into_iter()
+        .map(|(value, count)| FieldCountRow { value, count })
+        .collect();
+    rows.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    rows
+}
+
+/// Truncate an unlimited listing to [`WORK_LIST_MAX_ITEMS`], warning when rows were dropped
+fn apply_safety_cap(mut items: Vec<recap_core::WorkItem>, unlimited: bool) -> Vec<recap_core::WorkItem> {
+    if unlimited && items.len() as i64 > WORK_LIST_MAX_ITEMS {
+        items.truncate(WORK_LIST_MAX_ITEMS as usize);
+        print_error(&format!(
+            "Warning: more than {} items match; output truncated. Narrow the date range or filters.",
+            WORK_LIST_MAX_ITEMS
+        ));
     }
+    items
+}
+
+/// Most recent work items matching `filters`, newest first
+async fn query_recent_items(
+    pool: &SqlitePool,
+    filters: &WorkItemFilters,
+    top_level_only: bool,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<recap_core::WorkItem>> {
+    let mut query = String::from(
+        "SELECT * FROM work_items WHERE deleted_at IS NULL"
+    );
+    let mut bindings: Vec<String> = Vec::new();
+    push_filter_conditions(&mut query, &mut bindings, filters);
 
-    query.push_str(" ORDER BY date DESC, created_at DESC LIMIT ?");
+    if top_level_only {
+        query.push_str(" AND parent_id IS NULL");
+    }
+
+    query.push_str(" ORDER BY date DESC, created_at DESC LIMIT ? OFFSET ?");
     bindings.push(limit.to_string());
+    bindings.push(offset.to_string());
 
     // Build the query with bindings
     let mut sqlx_query = sqlx::query_as::<_, recap_core::WorkItem>(&query);
@@ -49,17 +398,109 @@ pub async fn list_work_items(
         sqlx_query = sqlx_query.bind(binding);
     }
 
-    let items: Vec<recap_core::WorkItem> = sqlx_query
-        .fetch_all(&ctx.db.pool)
+    Ok(sqlx_query.fetch_all(pool).await?)
+}
+
+/// Number of items `query_recent_items` would return without a limit
+async fn count_recent_items(pool: &SqlitePool, filters: &WorkItemFilters, top_level_only: bool) -> Result<i64> {
+    let mut query = String::from("SELECT COUNT(*) FROM work_items WHERE deleted_at IS NULL");
+    let mut bindings: Vec<String> = Vec::new();
+    push_filter_conditions(&mut query, &mut bindings, filters);
+
+    if top_level_only {
+        query.push_str(" AND parent_id IS NULL");
+    }
+
+    let mut sqlx_query = sqlx::query_scalar::<_, i64>(&query);
+    for binding in &bindings {
+        sqlx_query = sqlx_query.bind(binding);
+    }
+
+    Ok(sqlx_query.fetch_one(pool).await?)
+}
+
+/// Append the source, synced-at and commit conditions of `filters` to a `WHERE` query
+fn push_filter_conditions(query: &mut String, bindings: &mut Vec<String>, filters: &WorkItemFilters) {
+    if let Some(src) = &filters.source {
+        query.push_str(" AND source = ?");
+        bindings.push(src.clone());
+    }
+    if let Some(excluded) = filters.exclude_source.as_ref().filter(|e| !e.is_empty()) {
+        let placeholders = vec!["?"; excluded.len()].join(", ");
+        query.push_str(&format!(" AND source NOT IN ({})", placeholders));
+        bindings.extend(excluded.iter().cloned());
+    }
+
+    // synced_at is written both as RFC 3339 and as CURRENT_TIMESTAMP, so
+    // compare through datetime() to normalize the two
+    if filters.has_synced_range() {
+        query.push_str(" AND synced_to_tempo = 1");
+    }
+    if let Some(after) = filters.synced_after {
+        query.push_str(" AND datetime(synced_at) > datetime(?)");
+        bindings.push(after.format("%Y-%m-%d %H:%M:%S").to_string());
+    }
+    if let Some(before) = filters.synced_before {
+        query.push_str(" AND datetime(synced_at) < datetime(?)");
+        bindings.push(before.format("%Y-%m-%d %H:%M:%S").to_string());
+    }
+
+    // Passed as one JSON array so long rev-lists don't hit the variable limit;
+    // compared on the 8-character short hashes work items store
+    if let Some(hashes) = &filters.commit_hashes {
+        query.push_str(
+            " AND substr(commit_hash, 1, 8) IN (SELECT substr(value, 1, 8) FROM json_each(?))",
+        );
+        let hashes = serde_json::to_string(hashes).unwrap_or_else(|_| "[]".to_string());
+        bindings.push(hashes);
+    }
+}
+
+/// Attach each parent's children, fetched in one batched query
+async fn nest_children(
+    pool: &SqlitePool,
+    parents: Vec<recap_core::WorkItem>,
+) -> Result<Vec<WorkItemTreeRow>> {
+    let parent_ids: Vec<String> = parents.iter().map(|p| p.id.clone()).collect();
+    let mut children = fetch_children_by_parent(pool, &parent_ids).await?;
+
+    Ok(parents
+        .into_iter()
+        .map(|parent| {
+            let nested = children.remove(&parent.id).unwrap_or_default();
+            WorkItemTreeRow {
+                item: WorkItemRow::from(parent),
+                children: nested.into_iter().map(WorkItemRow::from).collect(),
+            }
+        })
+        .collect())
+}
+
+/// Full-text search over titles and descriptions, best match first
+pub async fn search_work_items(ctx: &Context, query: String, limit: i64) -> Result<()> {
+    let user_id: Option<String> = sqlx::query_scalar("SELECT id FROM users LIMIT 1")
+        .fetch_optional(&ctx.db.pool)
         .await?;
+    let items = match user_id {
+        Some(user_id) => recap_core::services::search_work_items(&ctx.db.pool, &user_id, &query, limit)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?,
+        None => Vec::new(),
+    };
+
+    if items.is_empty() && ctx.format == OutputFormat::Table {
+        print_info(&format!("No work items match \"{}\".", query.trim()), ctx.quiet);
+        return Ok(());
+    }
 
     let rows: Vec<WorkItemRow> = items.into_iter().map(WorkItemRow::from).collect();
     print_output(&rows, ctx.format)?;
-
     Ok(())
 }
 
-pub async fn show_work_item(ctx: &Context, id: String) -> Result<()> {
+/// Show one work item. JSON and CSV output carry every `WorkItem` field;
+/// the table lists the main fields, plus the verbose set with `full`.
+pub async fn show_work_item(ctx: &Context, id: String, full: bool) -> Result<()> {
     let full_id = resolve_work_item_id(&ctx.db, &id).await?;
 
     let item: recap_core::WorkItem = sqlx::query_as("SELECT * FROM work_items WHERE id = ?")
@@ -67,7 +508,380 @@ pub async fn show_work_item(ctx: &Context, id: String) -> Result<()> {
         .fetch_one(&ctx.db.pool)
         .await?;
 
-    print_single(&WorkItemRow::from(item), ctx.format)?;
+    match ctx.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&item)?),
+        OutputFormat::Csv => write_csv(std::slice::from_ref(&item), std::io::stdout().lock())?,
+        OutputFormat::Table => print_output(&work_item_fields(&item, full), ctx.format)?,
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::test_support::db_with_user;
+    use super::super::types::WorkItemField;
+    use tempfile::TempDir;
+
+    async fn insert_item(pool: &SqlitePool, id: &str, title: &str, parent_id: Option<&str>) {
+        let now = chrono::Utc::now();
+        sqlx::query(
+            "INSERT INTO work_items (id, user_id, source, title, hours, date, parent_id, created_at, updated_at)
+             VALUES (?, 'u1', 'manual', ?, 1.0, '2026-01-15', ?, ?, ?)"
+        )
+        .bind(id)
+        .bind(title)
+        .bind(parent_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn fetch_items(pool: &SqlitePool, ids: &[&str]) -> Vec<recap_core::WorkItem> {
+        let mut items = Vec::new();
+        for id in ids {
+            items.push(
+                sqlx::query_as("SELECT * FROM work_items WHERE id = ?")
+                    .bind(id)
+                    .fetch_one(pool)
+                    .await
+                    .unwrap(),
+            );
+        }
+        items
+    }
+
+    /// Repo with `main` (one commit) and `feature` (two more commits on top)
+    fn fixture_repo() -> (TempDir, Vec<String>) {
+        let dir = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .arg("-C")
+                .arg(dir.path())
+                .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init", "-q", "-b", "main"]);
+        let mut hashes = Vec::new();
+        for (i, message) in ["Initial", "Feature part 1", "Feature part 2"].iter().enumerate() {
+            if i == 1 {
+                git(&["checkout", "-q", "-b", "feature"]);
+            }
+            git(&["commit", "-q", "--allow-empty", "-m", message]);
+            hashes.push(git(&["rev-parse", "HEAD"]));
+        }
+        (dir, hashes)
+    }
+
+    #[tokio::test]
+    async fn test_changed_since_filters_to_branch_commits() {
+        use super::super::helpers::rev_list_commits;
+
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        let (repo, hashes) = fixture_repo();
+
+        for (id, hash) in [("main-0000", &hashes[0]), ("feat-1-0000", &hashes[1]), ("feat-2-0000", &hashes[2])] {
+            insert_item(pool, id, id, None).await;
+            sqlx::query("UPDATE work_items SET commit_hash = ? WHERE id = ?")
+                .bind(&hash[..8])
+                .bind(id)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        insert_item(pool, "manual-0000", "No commit", None).await;
+
+        let ids_for = |rev: &str| {
+            let filters = WorkItemFilters {
+                commit_hashes: Some(rev_list_commits(repo.path(), rev).unwrap()),
+                ..Default::default()
+            };
+            async move {
+                let items = query_recent_items(pool, &filters, false, 50, 0).await.unwrap();
+                assert!(items.iter().all(|item| filters.matches_commit(item)));
+                let mut ids: Vec<String> = items.into_iter().map(|i| i.id).collect();
+                ids.sort();
+                ids
+            }
+        };
+        assert_eq!(ids_for("main..feature").await, vec!["feat-1-0000", "feat-2-0000"]);
+        assert_eq!(ids_for("feature").await, vec!["feat-1-0000", "feat-2-0000", "main-0000"]);
+        assert_eq!(ids_for("main").await, vec!["main-0000"]);
+        assert!(rev_list_commits(repo.path(), "--all").is_err());
+        assert!(rev_list_commits(repo.path(), "no-such-branch").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_show_fields_include_commit_hash() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "commit-item-0000", "Fix sync cursor", None).await;
+        sqlx::query(
+            "UPDATE work_items SET source = 'git', commit_hash = 'a1b2c3d4e5f6', hours_source = 'commit_diff',
+                 source_url = 'https://github.com/acme/recap/commit/a1b2c3d4e5f6' WHERE id = 'commit-item-0000'"
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let item = fetch_items(&db.pool, &["commit-item-0000"]).await.remove(0);
+
+        let value = |fields: &[WorkItemField], name: &str| {
+            fields.iter().find(|f| f.field == name).map(|f| f.value.clone())
+        };
+        let fields = work_item_fields(&item, false);
+        assert_eq!(value(&fields, "Commit").as_deref(), Some("a1b2c3d4e5f6"));
+        assert_eq!(
+            value(&fields, "Source URL").as_deref(),
+            Some("https://github.com/acme/recap/commit/a1b2c3d4e5f6")
+        );
+        assert_eq!(value(&fields, "Hours Source"), None);
+
+        let full = work_item_fields(&item, true);
+        assert_eq!(value(&full, "Hours Source").as_deref(), Some("commit_diff"));
+        assert_eq!(value(&full, "Session").as_deref(), Some("-"));
+
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["commit_hash"], "a1b2c3d4e5f6");
+    }
+
+    #[tokio::test]
+    async fn test_nest_children_for_page_of_two_parents() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        insert_item(pool, "parent-a-0000", "Parent A", None).await;
+        insert_item(pool, "parent-b-0000", "Parent B", None).await;
+        insert_item(pool, "parent-c-0000", "Parent C", None).await;
+        insert_item(pool, "child-a1-0000", "Child A1", Some("parent-a-0000")).await;
+        insert_item(pool, "child-a2-0000", "Child A2", Some("parent-a-0000")).await;
+        insert_item(pool, "child-b1-0000", "Child B1", Some("parent-b-0000")).await;
+        insert_item(pool, "child-c1-0000", "Child C1", Some("parent-c-0000")).await;
+
+        // Page holds parents A and B; C's child must not leak in
+        let page = fetch_items(pool, &["parent-a-0000", "parent-b-0000"]).await;
+        let tree = nest_children(pool, page).await.unwrap();
+
+        let titles: Vec<(String, Vec<String>)> = tree
+            .iter()
+            .map(|t| (t.item.title.clone(), t.children.iter().map(|c| c.title.clone()).collect()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                ("Parent A".to_string(), vec!["Child A1".to_string(), "Child A2".to_string()]),
+                ("Parent B".to_string(), vec!["Child B1".to_string()]),
+            ]
+        );
+
+        let json = serde_json::to_value(&tree).unwrap();
+        assert_eq!(json[0]["title"], "Parent A");
+        assert_eq!(json[0]["children"][1]["title"], "Child A2");
+
+        let rows: Vec<WorkItemRow> = tree.into_iter().flat_map(WorkItemTreeRow::into_table_rows).collect();
+        let table_titles: Vec<&str> = rows.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(
+            table_titles,
+            vec!["Parent A", "  └ Child A1", "  └ Child A2", "Parent B", "  └ Child B1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_recent_items_synced_after() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        insert_item(pool, "early-0000", "Synced early", None).await;
+        insert_item(pool, "late-0000", "Synced late", None).await;
+        insert_item(pool, "late-sql-0000", "Synced late by Tempo sync", None).await;
+        insert_item(pool, "unsynced-0000", "Not synced", None).await;
+
+        let set_synced = |id: &'static str, synced: bool, synced_at: &'static str| async move {
+            sqlx::query("UPDATE work_items SET synced_to_tempo = ?, synced_at = ? WHERE id = ?")
+                .bind(synced)
+                .bind(synced_at)
+                .bind(id)
+                .execute(pool)
+                .await
+                .unwrap();
+        };
+        set_synced("early-0000", true, "2026-01-15T09:00:00+00:00").await;
+        set_synced("late-0000", true, "2026-01-15T11:30:00.250+00:00").await;
+        // Tempo sync stores CURRENT_TIMESTAMP format
+        set_synced("late-sql-0000", true, "2026-01-15 12:00:00").await;
+        set_synced("unsynced-0000", false, "2026-01-15T12:00:00+00:00").await;
+
+        let filters = WorkItemFilters {
+            synced_after: recap_core::parse_timestamp("2026-01-15T10:00:00Z", None),
+            ..Default::default()
+        };
+        let items = query_recent_items(pool, &filters, false, 50, 0).await.unwrap();
+        let mut ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["late-0000", "late-sql-0000"]);
+        assert!(items.iter().all(|item| filters.matches_synced_range(item)));
+
+        let filters = WorkItemFilters {
+            synced_after: recap_core::parse_timestamp("2026-01-15T10:00:00Z", None),
+            synced_before: recap_core::parse_timestamp("2026-01-15T12:00:00Z", None),
+            ..Default::default()
+        };
+        let items = query_recent_items(pool, &filters, false, 50, 0).await.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "late-0000");
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_listing_returns_more_than_100_items() {
+        let (db, _dir) = db_with_user().await;
+        for i in 0..150 {
+            insert_item(&db.pool, &format!("item-{:04}", i), "Bulk item", None).await;
+        }
+
+        let filters = WorkItemFilters::default();
+        let items = query_recent_items(&db.pool, &filters, false, WORK_LIST_MAX_ITEMS + 1, 0).await.unwrap();
+        assert_eq!(apply_safety_cap(items, true).len(), 150);
+    }
+
+    #[tokio::test]
+    async fn test_safety_cap_truncates_unlimited_listing() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "item-0000", "Bulk item", None).await;
+        let item = fetch_items(&db.pool, &["item-0000"]).await.remove(0);
+
+        let items = vec![item; WORK_LIST_MAX_ITEMS as usize + 1];
+        assert_eq!(apply_safety_cap(items.clone(), true).len(), WORK_LIST_MAX_ITEMS as usize);
+        // An explicit limit is never capped
+        assert_eq!(apply_safety_cap(items, false).len(), WORK_LIST_MAX_ITEMS as usize + 1);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_source_over_mixed_dataset() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        let items = [
+            ("cc-1", "claude_code", Some("/home/dev/recap")),
+            ("cc-2", "claude_code", Some("/home/dev/recap")),
+            ("cc-3", "claude_code", Some("/work/infra")),
+            ("git-1", "commit", Some("/home/dev/recap")),
+            ("git-2", "commit", None),
+            ("man-1", "manual", None),
+        ];
+        for (id, source, project_path) in items {
+            insert_item(pool, id, id, None).await;
+            sqlx::query("UPDATE work_items SET source = ?, project_path = ? WHERE id = ?")
+                .bind(source)
+                .bind(project_path)
+                .bind(id)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+
+        let counts = query_field_counts(pool, &WorkItemFilters::default(), CountField::Source)
+            .await
+            .unwrap();
+        let rows = merge_field_counts(counts, CountField::Source);
+        let by_source: Vec<(&str, i64)> = rows.iter().map(|r| (r.value.as_str(), r.count)).collect();
+        assert_eq!(by_source, vec![("claude_code", 3), ("commit", 2), ("manual", 1)]);
+
+        // Other filters still apply
+        let filters = WorkItemFilters {
+            source: Some("claude_code".to_string()),
+            ..Default::default()
+        };
+        let counts = query_field_counts(pool, &filters, CountField::Project).await.unwrap();
+        let rows = merge_field_counts(counts, CountField::Project);
+        let by_project: Vec<(&str, i64)> = rows.iter().map(|r| (r.value.as_str(), r.count)).collect();
+        assert_eq!(by_project, vec![("recap", 2), ("infra", 1)]);
+
+        let counts = query_field_counts(pool, &WorkItemFilters::default(), CountField::Jira)
+            .await
+            .unwrap();
+        let rows = merge_field_counts(counts, CountField::Jira);
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].value.as_str(), rows[0].count), (NO_VALUE, 6));
+    }
+
+    #[tokio::test]
+    async fn test_totals_footer_sums_displayed_hours() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        for (id, hours) in [("a", 1.25), ("b", 0.5), ("c", 3.0), ("d", 2.0)] {
+            insert_item(pool, id, id, None).await;
+            sqlx::query("UPDATE work_items SET hours = ? WHERE id = ?")
+                .bind(hours)
+                .bind(id)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+
+        let filters = WorkItemFilters {
+            per_page: Some(3),
+            ..Default::default()
+        };
+        let items = query_recent_items(pool, &filters, false, 3, 0).await.unwrap();
+        assert_eq!(items.len(), 3);
+
+        // Only the displayed (limited) items count
+        let totals = WorkListTotals::from_items(&items);
+        let displayed: f64 = items.iter().map(|i| i.hours).sum();
+        assert_eq!(totals.items, 3);
+        assert_eq!(totals.hours, displayed);
+
+        let footer = totals.table_row();
+        assert_eq!(footer.title, "Total (3 items)");
+        assert_eq!(footer.hours, format!("{:.1}", displayed));
+    }
+
+    #[tokio::test]
+    async fn test_exclude_aggregated_returns_only_leaf_items() {
+        let (db, _dir) = db_with_user().await;
+        let pool = &db.pool;
+        insert_item(pool, "parent", "Aggregated day", None).await;
+        insert_item(pool, "leaf-1", "Commit one", Some("parent")).await;
+        insert_item(pool, "leaf-2", "Commit two", Some("parent")).await;
+        insert_item(pool, "manual", "Manual entry", None).await;
+        sqlx::query("UPDATE work_items SET source = 'aggregated' WHERE id = 'parent'")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE work_items SET source = 'commit' WHERE id LIKE 'leaf-%'")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let filters = WorkItemFilters {
+            exclude_source: Some(vec!["aggregated".to_string()]),
+            ..Default::default()
+        };
+        let mut ids: Vec<String> = query_recent_items(pool, &filters, false, 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["leaf-1", "leaf-2", "manual"]);
+
+        // Combined with a positive source filter
+        let filters = WorkItemFilters {
+            source: Some("commit".to_string()),
+            exclude_source: Some(vec!["aggregated".to_string(), "manual".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(query_recent_items(pool, &filters, false, 10, 0).await.unwrap().len(), 2);
+
+        let filters = WorkItemFilters {
+            source: Some("manual".to_string()),
+            exclude_source: Some(vec!["manual".to_string()]),
+            ..Default::default()
+        };
+        assert!(filters.validate_sources().is_err());
+    }
+}
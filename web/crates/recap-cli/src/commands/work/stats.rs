@@ -0,0 +1,219 @@
+//! Work stats command
+//!
+//! Logged hours for a day or range, optionally compared to the daily goal.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use tabled::Tabled;
+
+use crate::commands::Context;
+use crate::output::print_single;
+use super::helpers::{fetch_items_in_range, parse_user_date, user_today};
+use super::types::CompareTarget;
+
+/// Goal used when the user has not set `daily_work_hours`
+const DEFAULT_DAILY_GOAL: f64 = 8.0;
+
+/// Logged hours over a date range
+#[derive(Debug, Serialize, Tabled)]
+pub struct WorkStats {
+    #[tabled(rename = "Start")]
+    pub start: String,
+    #[tabled(rename = "End")]
+    pub end: String,
+    /// Days with at least one logged item
+    #[tabled(rename = "Days")]
+    pub days: usize,
+    #[tabled(rename = "Items")]
+    pub items: usize,
+    #[tabled(rename = "Total", display_with = "format_hours")]
+    pub total: f64,
+    /// Average hours per day with logged work
+    #[tabled(rename = "Logged/Day", display_with = "format_hours")]
+    pub logged: f64,
+}
+
+impl WorkStats {
+    pub fn from_items(items: &[recap_core::WorkItem], start: NaiveDate, end: NaiveDate) -> Self {
+        let total: f64 = items.iter().map(|i| i.hours).sum();
+        let days = items.iter().map(|i| i.date).collect::<HashSet<_>>().len();
+        Self {
+            start: start.to_string(),
+            end: end.to_string(),
+            days,
+            items: items.len(),
+            total: round2(total),
+            logged: round2(total / days.max(1) as f64),
+        }
+    }
+}
+
+/// Average logged hours per day against the daily goal
+#[derive(Debug, Serialize, Tabled)]
+pub struct GoalComparison {
+    #[tabled(rename = "Start")]
+    pub start: String,
+    #[tabled(rename = "End")]
+    pub end: String,
+    #[tabled(rename = "Days")]
+    pub days: usize,
+    #[tabled(rename = "Logged", display_with = "format_hours")]
+    pub logged: f64,
+    #[tabled(rename = "Goal", display_with = "format_hours")]
+    pub goal: f64,
+    /// Logged hours as a percentage of the goal
+    #[tabled(rename = "%", display_with = "format_pct")]
+    pub pct: f64,
+    /// Surplus (positive) or deficit (negative) in hours
+    #[tabled(rename = "Delta", display_with = "format_delta")]
+    pub delta: f64,
+}
+
+impl GoalComparison {
+    pub fn new(stats: &WorkStats, goal: f64) -> Self {
+        let pct = if goal > 0.0 { stats.logged / goal * 100.0 } else { 0.0 };
+        Self {
+            start: stats.start.clone(),
+            end: stats.end.clone(),
+            days: stats.days,
+            logged: stats.logged,
+            goal,
+            pct: round2(pct),
+            delta: round2(stats.logged - goal),
+        }
+    }
+}
+
+pub async fn show_stats(
+    ctx: &Context,
+    date: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    compare_to: Option<CompareTarget>,
+) -> Result<()> {
+    let (start_date, end_date) = if let Some(d) = date {
+        let parsed_date = parse_user_date(ctx, &d).await?;
+        (parsed_date, parsed_date)
+    } else {
+        let end_date = match end {
+            Some(e) => parse_user_date(ctx, &e).await?,
+            None => user_today(ctx).await,
+        };
+        let start_date = match start {
+            Some(s) => parse_user_date(ctx, &s).await?,
+            None => end_date,
+        };
+        (start_date, end_date)
+    };
+    if start_date > end_date {
+        return Err(anyhow::anyhow!("Start date {} is after end date {}", start_date, end_date));
+    }
+
+    let items = fetch_items_in_range(ctx, None, start_date, end_date).await?;
+    let stats = WorkStats::from_items(&items, start_date, end_date);
+
+    match compare_to {
+        None => print_single(&stats, ctx.format),
+        Some(CompareTarget::Goal) => {
+            let goal = get_daily_goal(&ctx.db.pool).await?;
+            print_single(&GoalComparison::new(&stats, goal), ctx.format)
+        }
+    }
+}
+
+/// The user's `daily_work_hours` setting
+pub async fn get_daily_goal(pool: &SqlitePool) -> Result<f64> {
+    let goal: Option<Option<f64>> = sqlx::query_scalar("SELECT daily_work_hours FROM users LIMIT 1")
+        .fetch_optional(pool)
+        .await?;
+    Ok(goal.flatten().unwrap_or(DEFAULT_DAILY_GOAL))
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn format_hours(hours: &f64) -> String {
+    format!("{:.1}h", hours)
+}
+
+fn format_pct(pct: &f64) -> String {
+    format!("{:.0}%", pct)
+}
+
+fn format_delta(delta: &f64) -> String {
+    format!("{:+.1}h", delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recap_core::test_support::db_with_user;
+    use super::super::helpers::query_items_in_range;
+
+    async fn insert_item(pool: &SqlitePool, id: &str, hours: f64, date: &str) {
+        let now = chrono::Utc::now();
+        sqlx::query(
+            "INSERT INTO work_items (id, user_id, source, title, hours, date, created_at, updated_at)
+             VALUES (?, 'u1', 'manual', ?, ?, ?, ?, ?)"
+        )
+        .bind(id)
+        .bind(id)
+        .bind(hours)
+        .bind(date)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_six_hour_day_against_eight_hour_goal() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "a", 4.0, "2026-01-15").await;
+        insert_item(&db.pool, "b", 2.0, "2026-01-15").await;
+        insert_item(&db.pool, "other-day", 3.0, "2026-01-16").await;
+
+        let day = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let items = query_items_in_range(&db.pool, None, day, day, None).await.unwrap();
+        let stats = WorkStats::from_items(&items, day, day);
+        let goal = get_daily_goal(&db.pool).await.unwrap();
+        let comparison = GoalComparison::new(&stats, goal);
+
+        assert_eq!(comparison.logged, 6.0);
+        assert_eq!(comparison.goal, 8.0);
+        assert_eq!(comparison.pct, 75.0);
+        assert_eq!(comparison.delta, -2.0);
+
+        let json = serde_json::to_value(&comparison).unwrap();
+        assert_eq!(json["pct"], 75.0);
+        assert_eq!(json["delta"], -2.0);
+    }
+
+    #[tokio::test]
+    async fn test_range_averages_over_days_with_work() {
+        let (db, _dir) = db_with_user().await;
+        sqlx::query("UPDATE users SET daily_work_hours = 6.0")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        insert_item(&db.pool, "mon", 5.0, "2026-01-12").await;
+        insert_item(&db.pool, "tue", 9.0, "2026-01-13").await;
+
+        let start = NaiveDate::from_ymd_opt(2026, 1, 12).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 1, 18).unwrap();
+        let items = query_items_in_range(&db.pool, None, start, end, None).await.unwrap();
+        let stats = WorkStats::from_items(&items, start, end);
+        assert_eq!(stats.days, 2);
+        assert_eq!(stats.total, 14.0);
+
+        let comparison = GoalComparison::new(&stats, get_daily_goal(&db.pool).await.unwrap());
+        assert_eq!(comparison.logged, 7.0);
+        assert_eq!(comparison.delta, 1.0);
+        assert_eq!(format_delta(&comparison.delta), "+1.0h");
+    }
+}
@@ -0,0 +1,53 @@
+//! Work item trash commands
+//!
+//! `work delete` moves items to the trash; these list them and undo the
+//! delete. Items are purged for good by the app's background service once
+//! they've been trashed longer than `trash_retention_days`.
+
+use anyhow::Result;
+
+use crate::commands::Context;
+use crate::output::{print_info, print_output, print_success};
+use super::helpers::get_or_create_default_user;
+use super::types::{TrashAction, TrashRow};
+
+pub async fn execute(ctx: &Context, action: Option<TrashAction>) -> Result<()> {
+    match action {
+        None | Some(TrashAction::List) => list_trash(ctx).await,
+        Some(TrashAction::Restore { id }) => restore(ctx, &id).await,
+    }
+}
+
+async fn list_trash(ctx: &Context) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let items = recap_core::services::list_trash(&ctx.db.pool, &user_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    if items.is_empty() {
+        print_info("Trash is empty.", ctx.quiet);
+        return Ok(());
+    }
+
+    let rows: Vec<TrashRow> = items.into_iter().map(TrashRow::from).collect();
+    print_output(&rows, ctx.format)
+}
+
+async fn restore(ctx: &Context, id: &str) -> Result<()> {
+    let user_id = get_or_create_default_user(&ctx.db).await?;
+    let full_id: Option<String> = sqlx::query_scalar(
+        "SELECT id FROM work_items WHERE id LIKE ? AND user_id = ? AND deleted_at IS NOT NULL LIMIT 1",
+    )
+    .bind(format!("{}%", id))
+    .bind(&user_id)
+    .fetch_optional(&ctx.db.pool)
+    .await?;
+    let full_id = full_id.ok_or_else(|| anyhow::anyhow!("Work item not in trash: {}", id))?;
+
+    recap_core::services::restore_work_item(&ctx.db.pool, &user_id, &full_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    print_success(&format!("Restored work item: {}", &full_id[..8]), ctx.quiet);
+    Ok(())
+}
@@ -2,9 +2,11 @@
 //!
 //! Types for work item commands.
 
-use clap::Subcommand;
+use chrono::{DateTime, Utc};
+use clap::{Subcommand, ValueEnum};
 use serde::Serialize;
 use tabled::Tabled;
+use std::path::PathBuf;
 
 use super::helpers::truncate;
 
@@ -12,7 +14,7 @@ use super::helpers::truncate;
 pub enum WorkAction {
     /// List work items
     List {
-        /// Filter by date (YYYY-MM-DD), defaults to today
+        /// Filter by date (YYYY-MM-DD, today, yesterday)
         #[arg(short, long)]
         date: Option<String>,
 
@@ -28,9 +30,66 @@ pub enum WorkAction {
         #[arg(short, long)]
         source: Option<String>,
 
-        /// Maximum number of items to show
+        /// Leave out items from these sources (comma-separated or repeated)
+        #[arg(long, value_delimiter = ',')]
+        exclude_source: Vec<String>,
+
+        /// Maximum number of items to show (0 for all)
         #[arg(short, long, default_value = "50")]
         limit: i64,
+
+        /// Show all matching items, same as --limit 0
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        /// Page to show, starting at 1 (pages are --per-page or --limit items long)
+        #[arg(long, conflicts_with_all = ["all", "count_by"])]
+        page: Option<i64>,
+
+        /// Items per page; adds page/pages/total to the output
+        #[arg(long, conflicts_with_all = ["limit", "all", "count_by"])]
+        per_page: Option<i64>,
+
+        /// List top-level items only, with their children nested under each
+        /// (the limit applies to parents)
+        #[arg(long)]
+        group_children: bool,
+
+        /// Only items synced to Tempo after this time (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+        #[arg(long)]
+        synced_after: Option<String>,
+
+        /// Only items synced to Tempo before this time (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+        #[arg(long)]
+        synced_before: Option<String>,
+
+        /// Only items linked to commits listed by `git rev-list <REF>`, e.g. a
+        /// branch name or a range such as `main..feature`
+        #[arg(long, value_name = "REF")]
+        changed_since: Option<String>,
+
+        /// Repository for --changed-since (defaults to the current directory)
+        #[arg(long, requires = "changed_since")]
+        repo: Option<PathBuf>,
+
+        /// Print the number of matching items per value of a field instead of
+        /// the items themselves (ignores the limit)
+        #[arg(long, value_enum, conflicts_with = "group_children")]
+        count_by: Option<CountField>,
+
+        /// Append a totals row with the item count and hours of the listed items
+        /// (JSON output becomes `{ items, totals }`)
+        #[arg(long, conflicts_with_all = ["group_children", "count_by"])]
+        totals: bool,
+
+        /// Stream one JSON object per line as rows are read, instead of
+        /// collecting the whole list (for piping large lists into `jq`)
+        #[arg(long, conflicts_with_all = ["page", "per_page", "group_children", "count_by", "totals"])]
+        ndjson: bool,
+
+        /// Also list items in the trash, with a Deleted At column
+        #[arg(long, conflicts_with_all = ["page", "per_page", "group_children", "count_by", "totals", "ndjson"])]
+        include_deleted: bool,
     },
 
     /// Add a new work item
@@ -82,7 +141,7 @@ pub enum WorkAction {
         jira: Option<String>,
     },
 
-    /// Delete a work item
+    /// Move a work item to the trash
     Delete {
         /// Work item ID
         id: String,
@@ -92,11 +151,208 @@ pub enum WorkAction {
         force: bool,
     },
 
+    /// Delete every work item matching the filters (dry run unless --force)
+    Prune {
+        /// Only items on or after this date (YYYY-MM-DD, today, yesterday)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Only items on or before this date (YYYY-MM-DD, today, yesterday)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Only items from this source (e.g. gitlab, claude_code, manual)
+        #[arg(short, long)]
+        source: Option<String>,
+
+        /// Only items in this category
+        #[arg(short, long)]
+        category: Option<String>,
+
+        /// Delete the items instead of only counting them
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Show work item details
     Show {
         /// Work item ID
         id: String,
+
+        /// Also show session, hours source, estimated hours and project path
+        #[arg(long)]
+        full: bool,
+    },
+
+    /// Search titles and descriptions, best matches first
+    Search {
+        /// Words to search for; items must contain all of them
+        query: String,
+
+        /// Maximum number of results
+        #[arg(short, long, default_value = "20")]
+        limit: i64,
+    },
+
+    /// Export work items as JSON (or CSV with --format csv)
+    Export {
+        /// Only items on or after this date (YYYY-MM-DD, today, yesterday)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Only items on or before this date (defaults to today when --start is set)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Replace titles, descriptions, projects and Jira keys with placeholders
+        /// and drop URLs/IDs, for sharing sample data
+        #[arg(long)]
+        anonymize: bool,
     },
+
+    /// Import work items from a JSON array or a CSV (title,hours,date,category,jira)
+    Import {
+        /// File to import
+        path: PathBuf,
+
+        /// File format (defaults to the file extension)
+        #[arg(id = "input_format", long = "input-format", value_enum)]
+        format: Option<ImportFormat>,
+
+        /// Validate and count the rows without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Show logged hours for a day or range
+    Stats {
+        /// Single date (YYYY-MM-DD, today, yesterday)
+        #[arg(short, long, conflicts_with_all = ["start", "end"])]
+        date: Option<String>,
+
+        /// Range start (defaults to the end date)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Range end (defaults to today)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Compare the daily average against a target
+        #[arg(long, value_enum)]
+        compare_to: Option<CompareTarget>,
+    },
+
+    /// Assign categories to uncategorized items from title/description keywords
+    Classify {
+        /// Range start (defaults to the end date)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Range end (defaults to today)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Preview the assignments without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// List, rename, or merge work item categories
+    Categories {
+        #[command(subcommand)]
+        action: Option<CategoryAction>,
+    },
+
+    /// List or restore deleted work items
+    Trash {
+        #[command(subcommand)]
+        action: Option<TrashAction>,
+    },
+
+    /// Recompute content hashes and flag duplicate items (same as `db repair-hashes --apply`)
+    Rehash {
+        /// Only report what would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CategoryAction {
+    /// List categories with item counts (default)
+    List,
+
+    /// Rename a category on all work items
+    Rename {
+        /// Current category name (exact match)
+        old: String,
+
+        /// New category name
+        new: String,
+    },
+
+    /// Merge several categories into one
+    Merge {
+        /// Categories to merge (exact match)
+        #[arg(required = true)]
+        categories: Vec<String>,
+
+        /// Target category
+        #[arg(long)]
+        into: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TrashAction {
+    /// List trashed items, most recently deleted first (default)
+    List,
+
+    /// Take a work item out of the trash
+    Restore {
+        /// Work item ID
+        id: String,
+    },
+}
+
+/// Target for `work stats --compare-to`
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum CompareTarget {
+    /// The `daily_work_hours` setting
+    #[value(alias = "daily-goal")]
+    Goal,
+}
+
+/// Input file format for `work import`
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum ImportFormat {
+    Json,
+    Csv,
+}
+
+/// Field grouped by `work list --count-by`
+#[derive(Clone, Copy, ValueEnum, Debug, PartialEq, Eq)]
+pub enum CountField {
+    Source,
+    Category,
+    /// Project directory name
+    Project,
+    /// Jira issue key
+    Jira,
+}
+
+/// Item count for one field value
+#[derive(Debug, Serialize, Tabled)]
+pub struct FieldCountRow {
+    #[tabled(rename = "Value")]
+    pub value: String,
+    #[tabled(rename = "Count")]
+    pub count: i64,
 }
 
 /// Work item row for table display
@@ -116,6 +372,255 @@ pub struct WorkItemRow {
     pub jira: String,
 }
 
+/// Work item row for `work list --include-deleted`
+#[derive(Debug, Serialize, Tabled)]
+pub struct WorkItemAuditRow {
+    #[tabled(rename = "ID")]
+    pub id: String,
+    #[tabled(rename = "Date")]
+    pub date: String,
+    #[tabled(rename = "Title")]
+    pub title: String,
+    #[tabled(rename = "Hours")]
+    pub hours: String,
+    #[tabled(rename = "Source")]
+    pub source: String,
+    #[tabled(rename = "Jira")]
+    pub jira: String,
+    #[tabled(rename = "Deleted At", display_with = "display_deleted_at")]
+    pub deleted_at: Option<String>,
+}
+
+fn display_deleted_at(deleted_at: &Option<String>) -> String {
+    deleted_at.clone().unwrap_or_else(|| "-".to_string())
+}
+
+impl WorkItemAuditRow {
+    pub fn new(item: recap_core::WorkItem, deleted_at: Option<DateTime<Utc>>) -> Self {
+        let row = WorkItemRow::from(item);
+        Self {
+            id: row.id,
+            date: row.date,
+            title: row.title,
+            hours: row.hours,
+            source: row.source,
+            jira: row.jira,
+            deleted_at: deleted_at.map(|at| at.format("%Y-%m-%d %H:%M").to_string()),
+        }
+    }
+}
+
+/// One field of `work show` table output
+#[derive(Debug, Serialize, Tabled)]
+pub struct WorkItemField {
+    #[tabled(rename = "Field")]
+    pub field: String,
+    #[tabled(rename = "Value")]
+    pub value: String,
+}
+
+impl WorkItemField {
+    fn new(field: &str, value: Option<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            value: value.filter(|v| !v.is_empty()).unwrap_or_else(|| "-".to_string()),
+        }
+    }
+}
+
+/// Fields of `work show` table output, including where the item came from.
+///
+/// `full` adds the session, hours source, estimated hours and project path.
+pub fn work_item_fields(item: &recap_core::WorkItem, full: bool) -> Vec<WorkItemField> {
+    let mut fields = vec![
+        WorkItemField::new("ID", Some(item.id.clone())),
+        WorkItemField::new("Date", Some(item.date.to_string())),
+        WorkItemField::new("Title", Some(item.title.clone())),
+        WorkItemField::new("Hours", Some(format!("{:.1}", item.hours))),
+        WorkItemField::new("Source", Some(item.source.clone())),
+        WorkItemField::new("Jira", item.jira_issue_key.clone()),
+        WorkItemField::new("Source URL", item.source_url.clone()),
+        WorkItemField::new("Commit", item.commit_hash.clone()),
+    ];
+    if full {
+        fields.extend([
+            WorkItemField::new("Session", item.session_id.clone()),
+            WorkItemField::new("Hours Source", item.hours_source.clone()),
+            WorkItemField::new("Estimated Hours", item.hours_estimated.map(|h| format!("{:.1}", h))),
+            WorkItemField::new("Project Path", item.project_path.clone()),
+        ]);
+    }
+    fields
+}
+
+/// Item count and hours of a `work list --totals` result
+#[derive(Debug, Serialize, PartialEq)]
+pub struct WorkListTotals {
+    pub items: usize,
+    pub hours: f64,
+}
+
+impl WorkListTotals {
+    pub fn from_items(items: &[recap_core::WorkItem]) -> Self {
+        Self {
+            items: items.len(),
+            hours: items.iter().fold(0.0, |acc, i| acc + i.hours),
+        }
+    }
+
+    /// Footer row for table output
+    pub fn table_row(&self) -> WorkItemRow {
+        WorkItemRow {
+            id: String::new(),
+            date: String::new(),
+            title: format!("Total ({} items)", self.items),
+            hours: format!("{:.1}", self.hours),
+            source: String::new(),
+            jira: String::new(),
+        }
+    }
+}
+
+/// Pagination metadata of a `work list --page`/`--per-page` result
+#[derive(Debug, Serialize, PartialEq)]
+pub struct WorkListPage {
+    pub page: i64,
+    pub per_page: i64,
+    pub pages: i64,
+    pub total: i64,
+}
+
+impl WorkListPage {
+    pub fn new(page: i64, per_page: i64, total: i64) -> Self {
+        Self {
+            page,
+            per_page,
+            pages: (total + per_page - 1) / per_page,
+            total,
+        }
+    }
+
+    /// Footer line for table output
+    pub fn footer(&self) -> String {
+        format!("Page {} of {} ({} items)", self.page, self.pages.max(1), self.total)
+    }
+}
+
+/// JSON output of `work list` with `--totals` or pagination
+#[derive(Debug, Serialize)]
+pub struct WorkListOutput<T> {
+    pub items: T,
+    #[serde(flatten)]
+    pub page: Option<WorkListPage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub totals: Option<WorkListTotals>,
+}
+
+/// Parent work item with its children, for `work list --group-children`
+#[derive(Debug, Serialize)]
+pub struct WorkItemTreeRow {
+    #[serde(flatten)]
+    pub item: WorkItemRow,
+    pub children: Vec<WorkItemRow>,
+}
+
+impl WorkItemTreeRow {
+    /// Flatten into table rows, indenting children under their parent
+    pub fn into_table_rows(self) -> Vec<WorkItemRow> {
+        let mut rows = Vec::with_capacity(self.children.len() + 1);
+        rows.push(self.item);
+        rows.extend(self.children.into_iter().map(|mut child| {
+            child.title = format!("  └ {}", child.title);
+            child
+        }));
+        rows
+    }
+}
+
+/// Category row for table display
+#[derive(Debug, Serialize, Tabled)]
+pub struct CategoryRow {
+    #[tabled(rename = "Category")]
+    pub category: String,
+    #[tabled(rename = "Items")]
+    pub items: i64,
+    #[tabled(rename = "Hours")]
+    pub hours: String,
+}
+
+/// Trashed item row for `work trash list`
+#[derive(Debug, Serialize, Tabled)]
+pub struct TrashRow {
+    #[tabled(rename = "ID")]
+    pub id: String,
+    #[tabled(rename = "Date")]
+    pub date: String,
+    #[tabled(rename = "Title")]
+    pub title: String,
+    #[tabled(rename = "Hours")]
+    pub hours: f64,
+    #[tabled(rename = "Deleted")]
+    pub deleted_at: String,
+}
+
+impl From<recap_core::services::TrashedWorkItem> for TrashRow {
+    fn from(item: recap_core::services::TrashedWorkItem) -> Self {
+        Self {
+            id: item.id.chars().take(8).collect(),
+            date: item.date.to_string(),
+            title: truncate(&item.title, 40),
+            hours: item.hours,
+            deleted_at: item.deleted_at.format("%Y-%m-%d %H:%M").to_string(),
+        }
+    }
+}
+
+/// Category assignment row for `work classify`
+#[derive(Debug, Serialize, Tabled)]
+pub struct ClassifyRow {
+    #[tabled(rename = "ID")]
+    pub id: String,
+    #[tabled(rename = "Date")]
+    pub date: String,
+    #[tabled(rename = "Title")]
+    pub title: String,
+    #[tabled(rename = "Category")]
+    pub category: String,
+}
+
+/// Row `work import` could not import
+#[derive(Debug, Serialize, Tabled)]
+pub struct ImportFailureRow {
+    /// 1-based position in the file (array index or CSV record)
+    #[tabled(rename = "Row")]
+    pub row: usize,
+    #[tabled(rename = "Error")]
+    pub error: String,
+}
+
+/// Outcome of `work import`
+#[derive(Debug, Default, Serialize)]
+pub struct ImportSummary {
+    pub dry_run: bool,
+    /// Items written (or that would be, on a dry run)
+    pub inserted: usize,
+    /// Items already present, or repeated in the file
+    pub skipped: usize,
+    pub failed: usize,
+    pub failures: Vec<ImportFailureRow>,
+}
+
+impl From<recap_core::services::CategoryAssignment> for ClassifyRow {
+    fn from(assignment: recap_core::services::CategoryAssignment) -> Self {
+        Self {
+            id: assignment.id.chars().take(8).collect(),
+            date: assignment.date.to_string(),
+            title: truncate(&assignment.title, 40),
+            category: assignment.category,
+        }
+    }
+}
+
 impl From<recap_core::WorkItem> for WorkItemRow {
     fn from(item: recap_core::WorkItem) -> Self {
         Self {
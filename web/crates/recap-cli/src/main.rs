@@ -18,7 +18,7 @@ pub struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Output format: table (default) or json
+    /// Output format: table (default), json or csv
     #[arg(long, global = true, default_value = "table")]
     format: output::OutputFormat,
 
@@ -37,6 +37,16 @@ pub struct Cli {
     /// Log file path (default: ~/.recap/logs/recap-cli.log)
     #[arg(long, global = true)]
     log_file: Option<String>,
+
+    /// Timezone (IANA name, e.g. UTC or Asia/Taipei) for this invocation,
+    /// overriding the configured timezone when resolving and grouping dates
+    #[arg(long, global = true, value_parser = parse_tz)]
+    tz: Option<String>,
+}
+
+/// Validate `--tz` against the tz database
+fn parse_tz(s: &str) -> Result<String, String> {
+    recap_core::parse_timezone(s).map(|tz| tz.name().to_string())
 }
 
 #[derive(Subcommand)]
@@ -59,6 +69,12 @@ enum Commands {
         action: commands::source::SourceAction,
     },
 
+    /// Hide or show projects
+    Project {
+        #[command(subcommand)]
+        action: commands::project::ProjectAction,
+    },
+
     /// Generate reports
     Report {
         #[command(subcommand)]
@@ -88,6 +104,31 @@ enum Commands {
         #[command(subcommand)]
         action: commands::claude::ClaudeAction,
     },
+
+    /// Manage auth tokens
+    Auth {
+        #[command(subcommand)]
+        action: commands::auth::AuthAction,
+    },
+
+    /// Inspect LLM usage
+    Llm {
+        #[command(subcommand)]
+        action: commands::llm::LlmAction,
+    },
+
+    /// Back up or restore the database
+    Db {
+        #[command(subcommand)]
+        action: commands::db::DbAction,
+    },
+
+    /// Diagnose and repair common data problems
+    Doctor {
+        /// Apply the fixes (default is a dry run that only reports)
+        #[arg(long)]
+        fix: bool,
+    },
 }
 
 #[tokio::main]
@@ -124,6 +165,7 @@ async fn main() -> Result<()> {
         format: cli.format,
         quiet: cli.quiet,
         debug: cli.debug,
+        timezone: cli.tz,
     };
 
     // Execute command
@@ -131,11 +173,16 @@ async fn main() -> Result<()> {
         Commands::Work { action } => commands::work::execute(&ctx, action).await,
         Commands::Sync { action } => commands::sync::execute(&ctx, action).await,
         Commands::Source { action } => commands::source::execute(&ctx, action).await,
+        Commands::Project { action } => commands::project::execute(&ctx, action).await,
         Commands::Report { action } => commands::report::execute(&ctx, action).await,
         Commands::Config { action } => commands::config::execute(&ctx, action).await,
         Commands::Tempo { action } => commands::tempo_report::execute(&ctx, action).await,
         Commands::Dashboard { action } => commands::dashboard::execute(&ctx, action).await,
         Commands::Claude { action } => commands::claude::execute(&ctx, action).await,
+        Commands::Auth { action } => commands::auth::execute(&ctx, action).await,
+        Commands::Llm { action } => commands::llm::execute(&ctx, action).await,
+        Commands::Db { action } => commands::db::execute(&ctx, action).await,
+        Commands::Doctor { fix } => commands::doctor::execute(&ctx, fix).await,
     };
 
     if cli.debug {
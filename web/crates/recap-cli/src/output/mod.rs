@@ -1,9 +1,10 @@
 //! Output formatting module
 //!
-//! Provides table and JSON output formatting for CLI commands.
+//! Provides table, JSON and CSV output formatting for CLI commands.
 
 use serde::Serialize;
 use std::fmt::Display;
+use std::io::Write;
 use tabled::{Table, Tabled};
 
 /// Output format enum
@@ -12,6 +13,8 @@ pub enum OutputFormat {
     #[default]
     Table,
     Json,
+    /// CSV with a header row, for spreadsheet import
+    Csv,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -21,7 +24,8 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "table" => Ok(OutputFormat::Table),
             "json" => Ok(OutputFormat::Json),
-            _ => Err(format!("Invalid format: {}. Use 'table' or 'json'", s)),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Invalid format: {}. Use 'table', 'json' or 'csv'", s)),
         }
     }
 }
@@ -31,6 +35,7 @@ impl Display for OutputFormat {
         match self {
             OutputFormat::Table => write!(f, "table"),
             OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Csv => write!(f, "csv"),
         }
     }
 }
@@ -53,6 +58,7 @@ where
             let json = serde_json::to_string_pretty(data)?;
             println!("{}", json);
         }
+        OutputFormat::Csv => write_csv(data, std::io::stdout().lock())?,
     }
     Ok(())
 }
@@ -71,10 +77,33 @@ where
             let json = serde_json::to_string_pretty(data)?;
             println!("{}", json);
         }
+        OutputFormat::Csv => write_csv(std::slice::from_ref(data), std::io::stdout().lock())?,
     }
     Ok(())
 }
 
+/// Write rows as CSV, with a header row taken from the field names.
+///
+/// Fields containing commas, quotes or newlines are quoted. Nothing is
+/// written for an empty slice.
+pub fn write_csv<T, W>(data: &[T], out: W) -> anyhow::Result<()>
+where
+    T: Serialize,
+    W: Write,
+{
+    let mut writer = csv::Writer::from_writer(out);
+    for row in data {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Error for output that is nested and has no flat CSV form
+pub fn csv_not_supported(what: &str) -> anyhow::Error {
+    anyhow::anyhow!("CSV output is not supported for {}. Use --format json.", what)
+}
+
 /// Print a success message (respects quiet mode)
 pub fn print_success(message: &str, quiet: bool) {
     if !quiet {
@@ -112,6 +141,7 @@ mod tests {
         assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
         assert_eq!("TABLE".parse::<OutputFormat>().unwrap(), OutputFormat::Table);
         assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!("csv".parse::<OutputFormat>().unwrap(), OutputFormat::Csv);
         assert!("invalid".parse::<OutputFormat>().is_err());
     }
 
@@ -128,12 +158,14 @@ mod tests {
         assert!(err.contains("xml"));
         assert!(err.contains("table"));
         assert!(err.contains("json"));
+        assert!(err.contains("csv"));
     }
 
     #[test]
     fn test_output_format_display() {
         assert_eq!(OutputFormat::Table.to_string(), "table");
         assert_eq!(OutputFormat::Json.to_string(), "json");
+        assert_eq!(OutputFormat::Csv.to_string(), "csv");
     }
 
     #[test]
@@ -185,6 +217,24 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_write_csv_quotes_commas_and_newlines() {
+        let items = vec![
+            TestItem { name: "Fix sync, then deploy".to_string(), value: 1 },
+            TestItem { name: "Line one\nline \"two\"".to_string(), value: 2 },
+        ];
+        let mut out = Vec::new();
+        write_csv(&items, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "name,value\n\"Fix sync, then deploy\",1\n\"Line one\nline \"\"two\"\"\",2\n"
+        );
+
+        let mut out = Vec::new();
+        write_csv::<TestItem, _>(&[], &mut out).unwrap();
+        assert!(out.is_empty());
+    }
+
     #[test]
     fn test_print_single_table() {
         let item = TestItem { name: "single".to_string(), value: 99 };
@@ -7,103 +7,202 @@ use std::sync::OnceLock;
 use crate::models::{Claims, User};
 
 /// Get the path to the persisted JWT secret file in the app data directory
+#[cfg(not(feature = "strict_jwt"))]
 fn get_secret_file_path() -> Option<std::path::PathBuf> {
     directories::ProjectDirs::from("com", "recap", "Recap")
         .map(|dirs| dirs.data_dir().join(".jwt_secret"))
 }
 
-/// JWT secret key - reads from environment variable, persisted file, or auto-generates
-fn get_jwt_secret() -> &'static [u8] {
-    static JWT_SECRET: OnceLock<Vec<u8>> = OnceLock::new();
+/// Shortest `RECAP_JWT_SECRET` accepted without a warning (or at all with `strict_jwt`)
+pub const MIN_JWT_SECRET_BYTES: usize = 32;
 
-    JWT_SECRET.get_or_init(|| {
-        // 1. Check environment variable first
-        match std::env::var("RECAP_JWT_SECRET") {
-            Ok(secret) if secret.len() >= 32 => {
-                return secret.into_bytes();
-            }
-            Ok(secret) if !secret.is_empty() => {
-                eprintln!("WARNING: RECAP_JWT_SECRET is shorter than 32 characters. Consider using a longer secret.");
-                return secret.into_bytes();
-            }
-            _ => {}
+static JWT_SECRET: OnceLock<Result<Vec<u8>, String>> = OnceLock::new();
+
+/// JWT secret key.
+///
+/// With the `strict_jwt` feature (release builds) the secret must come from
+/// `RECAP_JWT_SECRET` and be at least [`MIN_JWT_SECRET_BYTES`] long; call
+/// this at startup to fail fast. Otherwise it falls back to a persisted or
+/// newly generated secret and never fails.
+pub fn get_jwt_secret() -> Result<&'static [u8], String> {
+    JWT_SECRET
+        .get_or_init(load_jwt_secret)
+        .as_ref()
+        .map(Vec::as_slice)
+        .map_err(Clone::clone)
+}
+
+/// Pin the secret for token tests, so they pass under `strict_jwt` without
+/// `RECAP_JWT_SECRET` and never touch the persisted secret file
+#[cfg(test)]
+fn set_test_jwt_secret() {
+    let _ = JWT_SECRET.set(Ok(b"recap-test-secret-0123456789abcdef".to_vec()));
+}
+
+#[cfg(feature = "strict_jwt")]
+fn load_jwt_secret() -> Result<Vec<u8>, String> {
+    strict_jwt_secret(std::env::var("RECAP_JWT_SECRET").ok())
+}
+
+#[cfg(not(feature = "strict_jwt"))]
+fn load_jwt_secret() -> Result<Vec<u8>, String> {
+    Ok(load_or_generate_jwt_secret())
+}
+
+/// Validate `RECAP_JWT_SECRET` for `strict_jwt` builds
+#[cfg(any(test, feature = "strict_jwt"))]
+fn strict_jwt_secret(env_secret: Option<String>) -> Result<Vec<u8>, String> {
+    match env_secret {
+        Some(secret) if secret.len() >= MIN_JWT_SECRET_BYTES => Ok(secret.into_bytes()),
+        Some(secret) if !secret.is_empty() => Err(format!(
+            "RECAP_JWT_SECRET is {} bytes; it must be at least {}",
+            secret.len(),
+            MIN_JWT_SECRET_BYTES
+        )),
+        _ => Err(format!(
+            "RECAP_JWT_SECRET must be set to a secret of at least {} bytes",
+            MIN_JWT_SECRET_BYTES
+        )),
+    }
+}
+
+/// Read the secret from the environment variable or persisted file, or
+/// generate and persist a new one
+#[cfg(not(feature = "strict_jwt"))]
+fn load_or_generate_jwt_secret() -> Vec<u8> {
+    // 1. Check environment variable first
+    match std::env::var("RECAP_JWT_SECRET") {
+        Ok(secret) if secret.len() >= MIN_JWT_SECRET_BYTES => {
+            return secret.into_bytes();
         }
+        Ok(secret) if !secret.is_empty() => {
+            eprintln!("WARNING: RECAP_JWT_SECRET is shorter than 32 characters. Consider using a longer secret.");
+            return secret.into_bytes();
+        }
+        _ => {}
+    }
 
-        // 2. Try to read from persisted file
-        if let Some(path) = get_secret_file_path() {
-            if let Ok(secret) = std::fs::read_to_string(&path) {
-                let secret = secret.trim().to_string();
-                if secret.len() >= 32 {
-                    log::info!("Loaded JWT secret from {}", path.display());
-                    return secret.into_bytes();
-                }
+    // 2. Try to read from persisted file
+    if let Some(path) = get_secret_file_path() {
+        if let Ok(secret) = std::fs::read_to_string(&path) {
+            let secret = secret.trim().to_string();
+            if secret.len() >= MIN_JWT_SECRET_BYTES {
+                log::info!("Loaded JWT secret from {}", path.display());
+                return secret.into_bytes();
             }
         }
+    }
 
-        // 3. Generate and persist a new secret
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
-        let secret: Vec<u8> = (0..64).map(|_| rng.gen::<u8>()).collect();
-        let hex_secret: String = secret.iter().map(|b| format!("{:02x}", b)).collect();
+    // 3. Generate and persist a new secret
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let secret: Vec<u8> = (0..64).map(|_| rng.gen::<u8>()).collect();
+    let hex_secret: String = secret.iter().map(|b| format!("{:02x}", b)).collect();
 
-        if let Some(path) = get_secret_file_path() {
-            if let Some(parent) = path.parent() {
-                let _ = std::fs::create_dir_all(parent);
-            }
-            match std::fs::write(&path, &hex_secret) {
-                Ok(_) => {
-                    // Set restrictive permissions on Unix
-                    #[cfg(unix)]
-                    {
-                        use std::os::unix::fs::PermissionsExt;
-                        let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
-                    }
-                    log::info!("Generated and saved JWT secret to {}", path.display());
-                }
-                Err(e) => {
-                    eprintln!("WARNING: Failed to save JWT secret to {}: {}. Tokens won't persist across restarts.", path.display(), e);
+    if let Some(path) = get_secret_file_path() {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        match std::fs::write(&path, &hex_secret) {
+            Ok(_) => {
+                // Set restrictive permissions on Unix
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
                 }
+                log::info!("Generated and saved JWT secret to {}", path.display());
+            }
+            Err(e) => {
+                eprintln!("WARNING: Failed to save JWT secret to {}: {}. Tokens won't persist across restarts.", path.display(), e);
             }
-        } else {
-            eprintln!("WARNING: Could not determine app data directory. Tokens won't persist across restarts.");
         }
+    } else {
+        eprintln!("WARNING: Could not determine app data directory. Tokens won't persist across restarts.");
+    }
+
+    hex_secret.into_bytes()
+}
 
-        hex_secret.into_bytes()
+/// [`get_jwt_secret`] as a jsonwebtoken error, for token encoding/decoding
+fn jwt_secret_key() -> Result<&'static [u8], jsonwebtoken::errors::Error> {
+    get_jwt_secret().map_err(|e| {
+        log::error!("JWT secret unavailable: {}", e);
+        jsonwebtoken::errors::ErrorKind::InvalidKeyFormat.into()
     })
 }
 
 const TOKEN_EXPIRY_DAYS: i64 = 7;
+const REFRESH_TOKEN_EXPIRY_DAYS: i64 = 30;
 
-/// Create a JWT token for a user
-pub fn create_token(user: &User) -> Result<String, jsonwebtoken::errors::Error> {
+/// `token_type` claim of access tokens
+pub const ACCESS_TOKEN_TYPE: &str = "access";
+/// `token_type` claim of refresh tokens
+pub const REFRESH_TOKEN_TYPE: &str = "refresh";
+
+fn encode_token(
+    sub: &str,
+    email: &str,
+    token_type: &str,
+    expiry_days: i64,
+) -> Result<String, jsonwebtoken::errors::Error> {
     let expiration = Utc::now()
-        .checked_add_signed(Duration::days(TOKEN_EXPIRY_DAYS))
+        .checked_add_signed(Duration::days(expiry_days))
         .expect("valid timestamp")
         .timestamp();
 
     let claims = Claims {
-        sub: user.id.clone(),
-        email: user.email.clone(),
+        sub: sub.to_string(),
+        email: email.to_string(),
         exp: expiration,
+        token_type: Some(token_type.to_string()),
     };
 
     encode(
         &Header::default(),
         &claims,
-        &EncodingKey::from_secret(get_jwt_secret()),
+        &EncodingKey::from_secret(jwt_secret_key()?),
     )
 }
 
-/// Verify and decode a JWT token
-pub fn verify_token(token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+fn decode_token(token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
     let token_data = decode::<Claims>(
         token,
-        &DecodingKey::from_secret(get_jwt_secret()),
+        &DecodingKey::from_secret(jwt_secret_key()?),
         &Validation::default(),
     )?;
     Ok(token_data.claims)
 }
 
+/// Create a JWT access token for a user
+pub fn create_token(user: &User) -> Result<String, jsonwebtoken::errors::Error> {
+    encode_token(&user.id, &user.email, ACCESS_TOKEN_TYPE, TOKEN_EXPIRY_DAYS)
+}
+
+/// Create a 30-day refresh token for a user, exchanged for new access
+/// tokens with [`refresh_access_token`]
+pub fn create_refresh_token(user: &User) -> Result<String, jsonwebtoken::errors::Error> {
+    encode_token(&user.id, &user.email, REFRESH_TOKEN_TYPE, REFRESH_TOKEN_EXPIRY_DAYS)
+}
+
+/// Verify and decode an access token. Refresh tokens are rejected.
+pub fn verify_token(token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+    let claims = decode_token(token)?;
+    if claims.token_type.as_deref() == Some(REFRESH_TOKEN_TYPE) {
+        return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+    }
+    Ok(claims)
+}
+
+/// Validate a refresh token and issue a new access token for its user
+pub fn refresh_access_token(refresh: &str) -> Result<String, jsonwebtoken::errors::Error> {
+    let claims = decode_token(refresh)?;
+    if claims.token_type.as_deref() != Some(REFRESH_TOKEN_TYPE) {
+        return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+    }
+    encode_token(&claims.sub, &claims.email, ACCESS_TOKEN_TYPE, TOKEN_EXPIRY_DAYS)
+}
+
 /// Hash a password
 pub fn hash_password(password: &str) -> Result<String, bcrypt::BcryptError> {
     bcrypt::hash(password, bcrypt::DEFAULT_COST)
@@ -133,4 +232,31 @@ mod tests {
         assert!(verify_password(password, &hash).unwrap());
         assert!(!verify_password("wrong_password", &hash).unwrap());
     }
+
+    #[test]
+    fn test_refresh_token_issues_access_token() {
+        set_test_jwt_secret();
+        let refresh =
+            encode_token("user-1", "user@example.com", REFRESH_TOKEN_TYPE, REFRESH_TOKEN_EXPIRY_DAYS).unwrap();
+        // Refresh tokens can't be used as access tokens
+        assert!(verify_token(&refresh).is_err());
+
+        let access = refresh_access_token(&refresh).unwrap();
+        let claims = verify_token(&access).unwrap();
+        assert_eq!(claims.sub, "user-1");
+        assert_eq!(claims.token_type.as_deref(), Some(ACCESS_TOKEN_TYPE));
+
+        // ...and access tokens can't be used to refresh
+        assert!(refresh_access_token(&access).is_err());
+        assert!(refresh_access_token("not-a-token").is_err());
+    }
+
+    #[test]
+    fn test_strict_jwt_secret_requires_long_env_secret() {
+        let long = "s".repeat(MIN_JWT_SECRET_BYTES);
+        assert_eq!(strict_jwt_secret(Some(long.clone())).unwrap(), long.into_bytes());
+        assert!(strict_jwt_secret(Some("short".to_string())).unwrap_err().contains("5 bytes"));
+        assert!(strict_jwt_secret(Some(String::new())).is_err());
+        assert!(strict_jwt_secret(None).is_err());
+    }
 }
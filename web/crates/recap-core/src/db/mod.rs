@@ -1,8 +1,31 @@
 //! Database module - SQLx with SQLite
 
 use crate::error::{Error, Result};
-use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::SqlitePool;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Pool size unless `RECAP_DB_MAX_CONNECTIONS` overrides it
+pub const DEFAULT_DB_MAX_CONNECTIONS: u32 = 5;
+
+/// SQLITE_BUSY retry window unless `RECAP_DB_BUSY_TIMEOUT_MS` overrides it
+pub const DEFAULT_DB_BUSY_TIMEOUT_MS: u64 = 5000;
+
+/// `PRAGMA user_version` once stored content hashes use `compute_content_hash`
+const CONTENT_HASH_DATA_VERSION: i64 = 1;
+
+/// Result of `PRAGMA wal_checkpoint(TRUNCATE)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalCheckpoint {
+    /// Another connection blocked the checkpoint from completing
+    pub busy: bool,
+    /// Frames in the WAL before truncation
+    pub log_frames: i64,
+    /// Frames copied back into the database file
+    pub checkpointed_frames: i64,
+}
 
 /// Database state
 #[derive(Clone)]
@@ -25,36 +48,70 @@ impl Database {
         }
 
         let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
-        log::info!("Connecting to database: {}", db_path.display());
-
-        let pool = SqlitePoolOptions::new()
-            .max_connections(5)
-            .connect(&db_url)
-            .await?;
-
-        // Enable WAL mode for better concurrent read/write performance
-        sqlx::query("PRAGMA journal_mode = WAL")
-            .execute(&pool)
-            .await?;
-
-        // Set busy timeout to 5 seconds — retry on SQLITE_BUSY instead of failing immediately
-        sqlx::query("PRAGMA busy_timeout = 5000")
-            .execute(&pool)
+        let max_connections = env_setting("RECAP_DB_MAX_CONNECTIONS", DEFAULT_DB_MAX_CONNECTIONS);
+        let busy_timeout_ms = env_setting("RECAP_DB_BUSY_TIMEOUT_MS", DEFAULT_DB_BUSY_TIMEOUT_MS);
+        log::info!(
+            "Connecting to database: {} (max {} connections, busy timeout {}ms)",
+            db_path.display(),
+            max_connections,
+            busy_timeout_ms
+        );
+
+        // Set on the connect options so every pooled connection gets them:
+        // WAL for concurrent read/write, a busy timeout to retry on SQLITE_BUSY
+        // instead of failing immediately, and NORMAL sync (safe with WAL)
+        let options = SqliteConnectOptions::from_str(&db_url)?
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_millis(busy_timeout_ms))
+            .synchronous(SqliteSynchronous::Normal);
+
+        // Migrate over a single connection first: a pooled connection opened
+        // midway through would keep a stale view of the FTS triggers and fail
+        // its first delete from `work_items` with "no such table"
+        let migration_pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options.clone())
             .await?;
+        Self { pool: migration_pool.clone() }.run_migrations().await?;
+        migration_pool.close().await;
 
-        // Synchronous NORMAL is safe with WAL and faster than FULL
-        sqlx::query("PRAGMA synchronous = NORMAL")
-            .execute(&pool)
+        let pool = SqlitePoolOptions::new()
+            .max_connections(max_connections)
+            .connect_with(options)
             .await?;
 
-        let db = Self { pool };
-        db.run_migrations().await?;
+        Ok(Self { pool })
+    }
 
-        Ok(db)
+    /// Copy the WAL back into the database file and truncate it.
+    ///
+    /// SQLite's auto-checkpoint never shrinks the `-wal` file and can be
+    /// starved by long write bursts (compaction), so callers run this after
+    /// heavy writes.
+    pub async fn checkpoint(&self) -> Result<WalCheckpoint> {
+        let (busy, log_frames, checkpointed_frames): (i64, i64, i64) =
+            sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)")
+                .fetch_one(&self.pool)
+                .await?;
+        let result = WalCheckpoint {
+            busy: busy != 0,
+            log_frames,
+            checkpointed_frames,
+        };
+        if result.busy {
+            log::warn!(
+                "WAL checkpoint blocked by a busy connection ({} of {} frames copied)",
+                result.checkpointed_frames,
+                result.log_frames
+            );
+        } else {
+            log::info!("WAL checkpoint: {} frames copied, WAL truncated", result.checkpointed_frames);
+        }
+        Ok(result)
     }
 
     /// Run database migrations
-    async fn run_migrations(&self) -> Result<()> {
+    pub async fn run_migrations(&self) -> Result<()> {
         log::info!("Running database migrations...");
 
         // Create users table
@@ -801,6 +858,295 @@ impl Database {
             .execute(&self.pool)
             .await?;
 
+        // Add min_commit_lines column (commits below this many changed lines are skipped)
+        sqlx::query("ALTER TABLE users ADD COLUMN min_commit_lines INTEGER DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Add GitLab/Jira sync toggles (previously only held in memory)
+        sqlx::query("ALTER TABLE users ADD COLUMN sync_gitlab BOOLEAN DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN sync_jira BOOLEAN DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Add include_coauthored column (count commits where the user is a Co-authored-by)
+        sqlx::query("ALTER TABLE users ADD COLUMN include_coauthored BOOLEAN DEFAULT 1")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Add llm_debug_log column (write LLM prompts/responses to a debug log file)
+        sqlx::query("ALTER TABLE users ADD COLUMN llm_debug_log BOOLEAN DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Add report push endpoint columns (recap report push)
+        sqlx::query("ALTER TABLE users ADD COLUMN report_push_url TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN report_push_token TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Add hour normalization weighting columns (how the daily target is distributed)
+        sqlx::query("ALTER TABLE users ADD COLUMN normalize_weighting TEXT DEFAULT 'even'")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN category_weights TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Create llm_response_cache table (prompt hash -> response) for compaction
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS llm_response_cache (
+                prompt_hash TEXT PRIMARY KEY,
+                response TEXT NOT NULL,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Add LLM response cache settings (0 TTL disables the cache)
+        sqlx::query("ALTER TABLE users ADD COLUMN llm_cache_ttl_hours INTEGER DEFAULT 720")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN llm_cache_max_entries INTEGER DEFAULT 1000")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // GitHub source: API base (GitHub Enterprise) and personal access token
+        sqlx::query("ALTER TABLE users ADD COLUMN github_url TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN github_pat TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Create github_projects table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS github_projects (
+                id TEXT PRIMARY KEY,
+                user_id TEXT NOT NULL,
+                github_repo_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                full_name TEXT NOT NULL,
+                html_url TEXT NOT NULL,
+                default_branch TEXT NOT NULL DEFAULT 'main',
+                enabled BOOLEAN NOT NULL DEFAULT 1,
+                last_synced DATETIME,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (user_id) REFERENCES users(id),
+                UNIQUE(user_id, github_repo_id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Scheduled database backups
+        sqlx::query("ALTER TABLE users ADD COLUMN db_backup_enabled BOOLEAN DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN db_backup_keep INTEGER DEFAULT 7")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Background sync retry settings
+        sqlx::query("ALTER TABLE users ADD COLUMN sync_retry_attempts INTEGER DEFAULT 3")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN sync_retry_base_delay_ms INTEGER DEFAULT 1000")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Glob patterns hidden from file-level reports (NULL = default lockfile list)
+        sqlx::query("ALTER TABLE users ADD COLUMN exclude_paths TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Notion report export target
+        sqlx::query("ALTER TABLE users ADD COLUMN notion_token TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("ALTER TABLE users ADD COLUMN notion_database_id TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Notion page created for each exported project/period, so re-exports update it
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS notion_report_pages (
+                user_id TEXT NOT NULL,
+                database_id TEXT NOT NULL,
+                project_name TEXT NOT NULL,
+                start_date TEXT NOT NULL,
+                end_date TEXT NOT NULL,
+                page_id TEXT NOT NULL,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (user_id, database_id, project_name, start_date, end_date)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Per-session hours cap (falls back to daily_work_hours, then 8h)
+        sqlx::query("ALTER TABLE users ADD COLUMN max_session_hours REAL")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Model that generated a cached project summary
+        sqlx::query("ALTER TABLE project_summaries ADD COLUMN llm_model TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Full-text index over work item titles and descriptions, kept in sync by triggers
+        let has_fts: bool =
+            sqlx::query_scalar("SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'work_items_fts'")
+                .fetch_one(&self.pool)
+                .await?;
+        sqlx::query(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS work_items_fts
+            USING fts5(title, description, content='work_items', content_rowid='rowid')
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        for trigger in [
+            r#"
+            CREATE TRIGGER IF NOT EXISTS work_items_fts_insert AFTER INSERT ON work_items BEGIN
+                INSERT INTO work_items_fts (rowid, title, description)
+                VALUES (new.rowid, new.title, new.description);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS work_items_fts_delete AFTER DELETE ON work_items BEGIN
+                INSERT INTO work_items_fts (work_items_fts, rowid, title, description)
+                VALUES ('delete', old.rowid, old.title, old.description);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS work_items_fts_update AFTER UPDATE OF title, description ON work_items BEGIN
+                INSERT INTO work_items_fts (work_items_fts, rowid, title, description)
+                VALUES ('delete', old.rowid, old.title, old.description);
+                INSERT INTO work_items_fts (rowid, title, description)
+                VALUES (new.rowid, new.title, new.description);
+            END
+            "#,
+        ] {
+            sqlx::query(trigger).execute(&self.pool).await?;
+        }
+        if !has_fts {
+            // Index the rows that existed before the table did
+            sqlx::query("INSERT INTO work_items_fts (work_items_fts) VALUES ('rebuild')")
+                .execute(&self.pool)
+                .await?;
+        }
+
+        // Newest session file mtime seen by incremental sync
+        sqlx::query("ALTER TABLE sync_status ADD COLUMN cursor_modified_at DATETIME")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Per-hour state of immediate-mode compaction, so interrupted runs resume
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS compaction_progress (
+                user_id TEXT NOT NULL,
+                project_path TEXT NOT NULL,
+                hour_bucket TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'pending'
+                    CHECK (status IN ('pending', 'in_progress', 'done', 'failed')),
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (user_id, project_path, hour_bucket)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_compaction_progress_status ON compaction_progress(status, updated_at)")
+            .execute(&self.pool)
+            .await?;
+
+        // Soft-deleted work items, restorable until purged after trash_retention_days
+        sqlx::query("ALTER TABLE work_items ADD COLUMN deleted_at DATETIME")
+            .execute(&self.pool)
+            .await
+            .ok();
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_work_items_deleted_at ON work_items(deleted_at)")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("ALTER TABLE users ADD COLUMN trash_retention_days INTEGER DEFAULT 30")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Number of GitLab projects synced at once
+        sqlx::query("ALTER TABLE users ADD COLUMN gitlab_sync_concurrency INTEGER DEFAULT 4")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Per-repo commit hours estimation (NULL = commit_interval)
+        sqlx::query("ALTER TABLE git_repos ADD COLUMN estimation_strategy TEXT")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Fold the hours of skipped trivial commits into kept ones (off by default)
+        sqlx::query("ALTER TABLE users ADD COLUMN fold_trivial_commit_hours BOOLEAN DEFAULT 0")
+            .execute(&self.pool)
+            .await
+            .ok();
+
+        // Content hashes moved to one SHA-256 algorithm (compute_content_hash).
+        // Recompute the hashes older versions stored, once, so the next sync
+        // or import matches them instead of inserting duplicates.
+        let data_version: i64 = sqlx::query_scalar("PRAGMA user_version").fetch_one(&self.pool).await?;
+        if data_version < CONTENT_HASH_DATA_VERSION {
+            let plan = crate::services::apply_hash_repair(&self.pool)
+                .await
+                .map_err(crate::Error::Internal)?;
+            log::info!(
+                "Recomputed {} of {} content hashes ({} duplicates tagged)",
+                plan.updates.len(),
+                plan.checked,
+                plan.duplicate_count()
+            );
+            sqlx::query(&format!("PRAGMA user_version = {}", CONTENT_HASH_DATA_VERSION))
+                .execute(&self.pool)
+                .await?;
+        }
+
         log::info!("Database migrations completed");
         Ok(())
     }
@@ -918,9 +1264,32 @@ pub fn get_db_path() -> Result<PathBuf> {
     Ok(dirs.data_dir().join("recap.db"))
 }
 
+/// Positive number from env var `name`, or `default` when unset or invalid
+fn env_setting<T: FromStr + PartialOrd + Default + std::fmt::Display + Copy>(name: &str, default: T) -> T {
+    parse_setting(name, std::env::var(name).ok().as_deref(), default)
+}
+
+fn parse_setting<T: FromStr + PartialOrd + Default + std::fmt::Display + Copy>(
+    name: &str,
+    value: Option<&str>,
+    default: T,
+) -> T {
+    let Some(value) = value else {
+        return default;
+    };
+    match value.trim().parse::<T>() {
+        Ok(n) if n > T::default() => n,
+        _ => {
+            log::warn!("Ignoring {}={:?}: expected a positive number, using {}", name, value, default);
+            default
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::db_with_user;
     use std::sync::Mutex;
 
     // Mutex to ensure env var tests don't run in parallel
@@ -944,4 +1313,58 @@ mod tests {
         assert_eq!(path.to_string_lossy(), test_path);
         std::env::remove_var("RECAP_DB_PATH");
     }
+
+    #[test]
+    fn test_parse_setting() {
+        assert_eq!(parse_setting("RECAP_DB_MAX_CONNECTIONS", None, 5u32), 5);
+        assert_eq!(parse_setting("RECAP_DB_MAX_CONNECTIONS", Some(" 12 "), 5u32), 12);
+        assert_eq!(parse_setting("RECAP_DB_MAX_CONNECTIONS", Some("0"), 5u32), 5);
+        assert_eq!(parse_setting("RECAP_DB_BUSY_TIMEOUT_MS", Some("soon"), 5000u64), 5000);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_truncates_wal() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(db_path.clone()).await.unwrap();
+
+        let (mode,): (String,) = sqlx::query_as("PRAGMA journal_mode").fetch_one(&db.pool).await.unwrap();
+        assert_eq!(mode, "wal");
+
+        let result = db.checkpoint().await.unwrap();
+        assert!(!result.busy);
+        let wal = dir.path().join("test.db-wal");
+        assert_eq!(std::fs::metadata(&wal).map(|m| m.len()).unwrap_or(0), 0);
+    }
+
+    #[tokio::test]
+    async fn test_open_recomputes_legacy_content_hashes_once() {
+        let (db, dir) = db_with_user().await;
+        let db_path = dir.path().join("test.db");
+        sqlx::query(
+            r#"INSERT INTO work_items (id, user_id, source, source_id, title, hours, date, content_hash)
+               VALUES ('w1', 'u1', 'git', 'abc123', 'Fix login', 1.0, '2026-01-15', 'legacy-hash')"#,
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let stored = || async {
+            sqlx::query_scalar::<_, String>("SELECT content_hash FROM work_items WHERE id = 'w1'")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap()
+        };
+
+        // Already migrated: reopening leaves hashes alone
+        Database::open(db_path.clone()).await.unwrap();
+        assert_eq!(stored().await, "legacy-hash");
+
+        // A database from before the hash change is migrated on open
+        sqlx::query("PRAGMA user_version = 0").execute(&db.pool).await.unwrap();
+        Database::open(db_path).await.unwrap();
+        assert_eq!(
+            stored().await,
+            crate::services::compute_content_hash("u1", "git", "abc123", "Fix login", "2026-01-15")
+        );
+    }
 }
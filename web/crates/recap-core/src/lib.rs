@@ -16,8 +16,14 @@ pub mod models;
 pub mod services;
 pub mod utils;
 
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 // Re-export utils for convenience
-pub use utils::create_command;
+pub use utils::{
+    create_command, date_in_timezone, local_work_date, parse_date_arg, parse_timestamp, parse_timezone,
+    timestamp_in_timezone, today_in_timezone,
+};
 
 // Re-exports for convenience
 pub use db::Database;
@@ -25,7 +31,7 @@ pub use error::{Error, Result};
 
 // Re-export commonly used types from models
 pub use models::{
-    AppConfig, Claims, CreateWorkItem, GitLabProject, GitRepo, GitRepoInfo, HoursSource,
+    AppConfig, Claims, CreateWorkItem, GitHubProject, GitLabProject, GitRepo, GitRepoInfo, HoursSource,
     PaginatedResponse, SnapshotRawData, SourcesResponse, SyncResult, SyncStatus,
     SyncStatusResponse, SyncWorklogsRequest, SyncWorklogsResponse, UpdateWorkItem, User,
     UserResponse, WorkItem, WorkItemFilters, WorkSummary, WorklogEntry, WorklogSyncResult,
@@ -33,17 +39,23 @@ pub use models::{
 
 // Re-export commonly used types from services
 pub use services::{
-    build_rule_based_outcome, calculate_session_hours, capture_snapshots_for_project,
-    compact_daily, compact_hourly, compact_period, create_llm_service, create_sync_service,
-    estimate_commit_hours, estimate_from_diff, extract_cwd, extract_tool_detail,
+    build_rule_based_outcome, calculate_session_hours, calculate_session_hours_in_timezone,
+    capture_snapshots_for_project, compact_daily, compact_hourly, compact_period,
+    create_llm_service, create_sync_service,
+    estimate_commit_hours, estimate_from_diff, EstimationStrategy, extract_cwd, extract_tool_detail,
+    filter_trivial_commits,
     generate_daily_hash, get_commits_for_date, get_commits_in_time_range, get_git_user_email,
+    get_user_commits_for_date,
     is_meaningful_message,
     parse_session_fast, parse_session_full, parse_session_into_hourly_buckets, resolve_git_root,
-    run_compaction_cycle, save_hourly_snapshots, sync_claude_projects, sync_discovered_projects,
+    run_compaction_cycle, save_hourly_snapshots, sync_claude_projects, sync_claude_projects_incremental,
+    sync_claude_projects_with_progress, sync_discovered_projects, sync_discovered_projects_incremental,
+    sync_discovered_projects_with_progress,
     ClaudeSyncResult, CommitRecord, CommitSnapshot, CompactionResult, DailyWorklog,
-    DiscoveredProject, ExcelReportGenerator, ExcelWorkItem, FileChange, HoursEstimate,
-    HourlyBucket, JiraAuthType, JiraClient, ParsedSession, ProjectSummary, ReportMetadata,
-    SessionBrief, SessionMetadata, SnapshotCaptureResult, StandaloneSession, SyncService,
+    DiscoveredProject, ExcelReportGenerator, ExcelTemplate, ExcelWorkItem, FileChange, HoursEstimate,
+    HourlyBucket, JiraAuthType, JiraClient, ParsedSession, PdfReportGenerator, ProjectSummary, ProjectSyncResult,
+    ReportMetadata,
+    SessionBrief, SessionMetadata, SnapshotCaptureResult, StandaloneSession, SyncProgressUpdate, SyncService,
     TempoClient, TimelineCommit, ToolCallRecord, ToolUsage,
     WorklogEntry as TempoWorklogEntry, WorklogUploader,
 };
@@ -105,6 +105,7 @@ pub enum HoursSource {
     Session,         // Calculated from linked Claude session
     CommitInterval,  // Estimated from time between commits
     Heuristic,       // Estimated from lines/files changed
+    Fixed,           // Repo configured with a fixed estimate per commit
     Manual,          // Default for manually created items
 }
 
@@ -115,6 +116,7 @@ impl HoursSource {
             HoursSource::Session => "session",
             HoursSource::CommitInterval => "commit_interval",
             HoursSource::Heuristic => "heuristic",
+            HoursSource::Fixed => "fixed",
             HoursSource::Manual => "manual",
         }
     }
@@ -125,6 +127,7 @@ impl HoursSource {
             "session" => HoursSource::Session,
             "commit_interval" => HoursSource::CommitInterval,
             "heuristic" => HoursSource::Heuristic,
+            "fixed" => HoursSource::Fixed,
             _ => HoursSource::Manual,
         }
     }
@@ -145,6 +148,22 @@ pub struct GitLabProject {
     pub created_at: DateTime<Utc>,
 }
 
+/// GitHub repository tracked for sync
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GitHubProject {
+    pub id: String,
+    pub user_id: String,
+    pub github_repo_id: i64,
+    pub name: String,
+    /// `owner/name`
+    pub full_name: String,
+    pub html_url: String,
+    pub default_branch: String,
+    pub enabled: bool,
+    pub last_synced: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
 /// App configuration (stored in config file, not DB)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppConfig {
@@ -162,6 +181,10 @@ pub struct Claims {
     pub sub: String,  // user id
     pub email: String,
     pub exp: i64,
+    /// "access" or "refresh"; tokens issued before refresh support have none
+    /// and are treated as access tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
 }
 
 /// Create work item request
@@ -202,6 +225,7 @@ pub struct WorkItemFilters {
     pub page: Option<i64>,
     pub per_page: Option<i64>,
     pub source: Option<String>,
+    pub exclude_source: Option<Vec<String>>,  // Sources to leave out
     pub category: Option<String>,
     pub jira_mapped: Option<bool>,
     pub synced_to_tempo: Option<bool>,
@@ -210,6 +234,60 @@ pub struct WorkItemFilters {
     pub search: Option<String>,
     pub parent_id: Option<String>,  // Filter by parent (get children)
     pub show_all: Option<bool>,     // Show all items including children
+    pub synced_after: Option<DateTime<Utc>>,   // Synced to Tempo after this time
+    pub synced_before: Option<DateTime<Utc>>,  // Synced to Tempo before this time
+    pub commit_hashes: Option<Vec<String>>,    // Linked to one of these commits (short hashes)
+}
+
+impl WorkItemFilters {
+    /// Reject a `source` that is also excluded, which could never match
+    pub fn validate_sources(&self) -> Result<(), String> {
+        match (&self.source, &self.exclude_source) {
+            (Some(source), Some(excluded)) if excluded.contains(source) => Err(format!(
+                "Source '{}' is both included and excluded",
+                source
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `item` passes the `source` and `exclude_source` filters
+    pub fn matches_source(&self, item: &WorkItem) -> bool {
+        self.source.as_ref().is_none_or(|s| &item.source == s)
+            && self
+                .exclude_source
+                .as_ref()
+                .is_none_or(|excluded| !excluded.contains(&item.source))
+    }
+
+    /// Whether `item` is linked to one of `commit_hashes`, compared on the
+    /// first 8 characters (the length work items store)
+    pub fn matches_commit(&self, item: &WorkItem) -> bool {
+        let short = |hash: &str| hash.get(..8).unwrap_or(hash).to_string();
+        self.commit_hashes.as_ref().is_none_or(|hashes| {
+            item.commit_hash
+                .as_deref()
+                .is_some_and(|hash| hashes.iter().any(|h| short(h) == short(hash)))
+        })
+    }
+
+    /// Whether a synced-at bound is set; such filters only match synced items
+    pub fn has_synced_range(&self) -> bool {
+        self.synced_after.is_some() || self.synced_before.is_some()
+    }
+
+    /// Whether `item` was synced to Tempo within the synced-at bounds (both exclusive)
+    pub fn matches_synced_range(&self, item: &WorkItem) -> bool {
+        if !self.has_synced_range() {
+            return true;
+        }
+        let synced_at = match item.synced_at {
+            Some(t) if item.synced_to_tempo => t,
+            _ => return false,
+        };
+        self.synced_after.is_none_or(|after| synced_at > after)
+            && self.synced_before.is_none_or(|before| synced_at < before)
+    }
 }
 
 /// Paginated response
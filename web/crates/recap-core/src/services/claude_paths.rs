@@ -0,0 +1,194 @@
+//! Claude Paths
+//!
+//! Locates the Claude Code data directories (`~/.claude`) and their
+//! `projects` subdirectories. A user's `claude_session_path` setting wins and
+//! may list several comma-separated roots (e.g. `~/.claude` plus a mounted
+//! work machine); otherwise the platform default is used:
+//! `%USERPROFILE%\.claude` on Windows and `$HOME/.claude` elsewhere.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use sqlx::SqlitePool;
+
+/// The user's home directory from the platform's environment variables.
+///
+/// Windows reads `USERPROFILE`, then `HOMEDRIVE` + `HOMEPATH`; other
+/// platforms read `HOME`. Falls back to the OS lookup when these are unset.
+pub fn home_dir() -> Option<PathBuf> {
+    home_dir_from_env(cfg!(windows), |key| std::env::var_os(key)).or_else(dirs::home_dir)
+}
+
+/// Home directory from environment variables for a Windows or Unix layout
+fn home_dir_from_env<F>(windows: bool, var: F) -> Option<PathBuf>
+where
+    F: Fn(&str) -> Option<OsString>,
+{
+    let non_empty = |key: &str| var(key).filter(|v| !v.is_empty());
+
+    if windows {
+        if let Some(profile) = non_empty("USERPROFILE") {
+            return Some(PathBuf::from(profile));
+        }
+        let drive = non_empty("HOMEDRIVE")?;
+        let path = non_empty("HOMEPATH")?;
+        let mut home = drive;
+        home.push(path);
+        Some(PathBuf::from(home))
+    } else {
+        non_empty("HOME").map(PathBuf::from)
+    }
+}
+
+/// Platform default Claude home (`.claude` in the home directory)
+pub fn default_claude_home() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(".claude"))
+}
+
+/// Platform default Claude projects directory
+pub fn default_claude_projects_dir() -> Option<PathBuf> {
+    default_claude_home().map(|h| h.join("projects"))
+}
+
+/// Separator between roots in `claude_session_path`
+pub const CLAUDE_PATH_SEPARATOR: char = ',';
+
+/// Claude homes from a configured comma-separated list, or the platform
+/// default if none is set
+pub fn claude_homes_or_default(configured: Option<&str>) -> Vec<PathBuf> {
+    let homes: Vec<PathBuf> = configured
+        .unwrap_or_default()
+        .split(CLAUDE_PATH_SEPARATOR)
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .collect();
+    if homes.is_empty() {
+        default_claude_home().into_iter().collect()
+    } else {
+        homes
+    }
+}
+
+/// First Claude home from a configured path list, or the platform default if none is set
+pub fn claude_home_or_default(configured: Option<&str>) -> Option<PathBuf> {
+    claude_homes_or_default(configured).into_iter().next()
+}
+
+/// The user's `claude_session_path` setting, if set
+pub async fn get_claude_session_path(pool: &SqlitePool, user_id: &str) -> Result<Option<String>, String> {
+    let path: Option<Option<String>> = sqlx::query_scalar("SELECT claude_session_path FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(path.flatten())
+}
+
+/// Claude homes for `user_id`: the configured `claude_session_path` roots, else the platform default
+pub async fn resolve_claude_homes(pool: &SqlitePool, user_id: &str) -> Result<Vec<PathBuf>, String> {
+    let configured = get_claude_session_path(pool, user_id).await?;
+    Ok(claude_homes_or_default(configured.as_deref()))
+}
+
+/// Claude projects directories for `user_id`, see [`resolve_claude_homes`]
+pub async fn resolve_claude_projects_dirs(pool: &SqlitePool, user_id: &str) -> Result<Vec<PathBuf>, String> {
+    Ok(resolve_claude_homes(pool, user_id)
+        .await?
+        .into_iter()
+        .map(|h| h.join("projects"))
+        .collect())
+}
+
+/// First Claude home for `user_id`, see [`resolve_claude_homes`]
+pub async fn resolve_claude_home(pool: &SqlitePool, user_id: &str) -> Result<Option<PathBuf>, String> {
+    Ok(resolve_claude_homes(pool, user_id).await?.into_iter().next())
+}
+
+/// First Claude projects directory for `user_id`, see [`resolve_claude_homes`]
+pub async fn resolve_claude_projects_dir(pool: &SqlitePool, user_id: &str) -> Result<Option<PathBuf>, String> {
+    Ok(resolve_claude_home(pool, user_id).await?.map(|h| h.join("projects")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<OsString> {
+        let vars: HashMap<String, OsString> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), OsString::from(v)))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn test_windows_layout_uses_userprofile() {
+        let vars = env(&[("USERPROFILE", r"C:\Users\dev"), ("HOME", "/ignored")]);
+        assert_eq!(home_dir_from_env(true, vars), Some(PathBuf::from(r"C:\Users\dev")));
+
+        let vars = env(&[("USERPROFILE", ""), ("HOMEDRIVE", "D:"), ("HOMEPATH", r"\Users\dev")]);
+        assert_eq!(home_dir_from_env(true, vars), Some(PathBuf::from(r"D:\Users\dev")));
+
+        // HOME alone is not a Windows home
+        assert_eq!(home_dir_from_env(true, env(&[("HOME", "/home/dev")])), None);
+    }
+
+    #[test]
+    fn test_unix_layout_uses_home() {
+        let vars = env(&[("HOME", "/home/dev"), ("USERPROFILE", r"C:\Users\dev")]);
+        assert_eq!(home_dir_from_env(false, vars), Some(PathBuf::from("/home/dev")));
+        assert_eq!(home_dir_from_env(false, env(&[("HOME", "")])), None);
+        assert_eq!(home_dir_from_env(false, env(&[])), None);
+    }
+
+    #[test]
+    fn test_configured_path_wins() {
+        assert_eq!(
+            claude_home_or_default(Some("/data/claude")),
+            Some(PathBuf::from("/data/claude"))
+        );
+        assert_eq!(claude_home_or_default(Some("  ")), default_claude_home());
+        assert_eq!(claude_home_or_default(None), default_claude_home());
+    }
+
+    #[test]
+    fn test_configured_paths_split_on_commas() {
+        assert_eq!(
+            claude_homes_or_default(Some("/home/dev/.claude, /mnt/work/.claude,")),
+            vec![PathBuf::from("/home/dev/.claude"), PathBuf::from("/mnt/work/.claude")]
+        );
+        assert_eq!(
+            claude_home_or_default(Some(" , /mnt/work/.claude")),
+            Some(PathBuf::from("/mnt/work/.claude"))
+        );
+        assert_eq!(claude_homes_or_default(Some(" , ")), default_claude_home().into_iter().collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_claude_projects_dir_reads_user_setting() {
+        let (db, _dir) = db_with_user().await;
+
+        let resolved = resolve_claude_projects_dir(&db.pool, "u1").await.unwrap();
+        assert_eq!(resolved, default_claude_projects_dir());
+
+        sqlx::query("UPDATE users SET claude_session_path = '/data/claude' WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let resolved = resolve_claude_projects_dir(&db.pool, "u1").await.unwrap();
+        assert_eq!(resolved, Some(PathBuf::from("/data/claude/projects")));
+
+        sqlx::query("UPDATE users SET claude_session_path = '/data/claude,/mnt/work/.claude' WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let resolved = resolve_claude_projects_dirs(&db.pool, "u1").await.unwrap();
+        assert_eq!(
+            resolved,
+            vec![PathBuf::from("/data/claude/projects"), PathBuf::from("/mnt/work/.claude/projects")]
+        );
+    }
+}
@@ -0,0 +1,571 @@
+//! Commit diffs
+//!
+//! Loads a commit's metadata, per-file stats and diff from git, with the diff
+//! split into per-file hunks tagged with a language for syntax highlighting.
+//! Commits are immutable, so results are cached by hash in a bounded cache.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use serde::Serialize;
+
+use crate::utils::create_command;
+
+/// Maximum size of diff output parsed into hunks (100KB)
+const MAX_DIFF_SIZE: usize = 100 * 1024;
+
+/// Number of commit diffs kept in the process-wide cache
+pub const COMMIT_DIFF_CACHE_CAPACITY: usize = 64;
+
+/// A commit with its file changes and diff
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitDiff {
+    pub hash: String,
+    pub message: String,
+    pub author: String,
+    pub date: String,
+    pub files: Vec<CommitFileChange>,
+    /// Per-file hunks, empty if the diff could not be read
+    pub diffs: Vec<FileDiff>,
+    /// The diff exceeded the size limit and later files were dropped
+    pub truncated: bool,
+    pub stats: CommitStats,
+}
+
+/// File change in a commit
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitFileChange {
+    pub path: String,
+    pub status: String, // "added" | "modified" | "deleted" | "renamed"
+    pub old_path: Option<String>, // For renamed files
+    pub insertions: i32,
+    pub deletions: i32,
+}
+
+/// Commit statistics
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitStats {
+    pub files_changed: i32,
+    pub insertions: i32,
+    pub deletions: i32,
+}
+
+/// Diff of one file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileDiff {
+    pub path: String,
+    /// Language detected from the file extension, e.g. `rust`
+    pub language: Option<String>,
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// One `@@ ... @@` hunk
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffHunk {
+    /// The full `@@ -a,b +c,d @@ context` line
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    /// Hunk lines, each prefixed with ` `, `+` or `-`
+    pub lines: Vec<String>,
+}
+
+/// Bounded cache of commit diffs keyed by commit hash; least recently used is evicted
+pub struct CommitDiffCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    diffs: HashMap<String, Arc<CommitDiff>>,
+    /// Hashes from least to most recently used
+    order: VecDeque<String>,
+}
+
+impl CommitDiffCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Cached diff for `hash`, loading it with `load` on a miss. Errors are not cached.
+    pub fn get_or_load<F>(&self, hash: &str, load: F) -> Result<Arc<CommitDiff>, String>
+    where
+        F: FnOnce() -> Result<CommitDiff, String>,
+    {
+        let mut guard = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let CacheEntries { diffs, order } = &mut *guard;
+
+        if let Some(diff) = diffs.get(hash) {
+            let diff = Arc::clone(diff);
+            order.retain(|h| h != hash);
+            order.push_back(hash.to_string());
+            return Ok(diff);
+        }
+
+        let diff = Arc::new(load()?);
+        if self.capacity > 0 {
+            while diffs.len() >= self.capacity {
+                match order.pop_front() {
+                    Some(oldest) => diffs.remove(&oldest),
+                    None => break,
+                };
+            }
+            diffs.insert(hash.to_string(), Arc::clone(&diff));
+            order.push_back(hash.to_string());
+        }
+        Ok(diff)
+    }
+}
+
+/// Process-wide cache used by the commit diff viewer
+pub fn commit_diff_cache() -> &'static CommitDiffCache {
+    static CACHE: OnceLock<CommitDiffCache> = OnceLock::new();
+    CACHE.get_or_init(|| CommitDiffCache::new(COMMIT_DIFF_CACHE_CAPACITY))
+}
+
+/// Get the diff of `commit_hash` in the repository at `git_root`, from cache when possible
+pub fn get_commit_diff(git_root: &Path, commit_hash: &str) -> Result<Arc<CommitDiff>, String> {
+    commit_diff_cache().get_or_load(commit_hash, || load_commit_diff(git_root, commit_hash))
+}
+
+/// Read a commit diff from git
+fn load_commit_diff(git_root: &Path, commit_hash: &str) -> Result<CommitDiff, String> {
+    let commit_info = get_commit_info(git_root, commit_hash)?;
+    let files = get_commit_files(git_root, commit_hash)?;
+
+    let stats = CommitStats {
+        files_changed: files.len() as i32,
+        insertions: files.iter().map(|f| f.insertions).sum(),
+        deletions: files.iter().map(|f| f.deletions).sum(),
+    };
+
+    let (diffs, truncated) = match run_git_command(git_root, &["show", "--format=", commit_hash]) {
+        Ok(output) => {
+            let (text, truncated) = truncate_diff(&output);
+            (parse_unified_diff(text), truncated)
+        }
+        Err(e) => {
+            log::warn!("Failed to read diff for {}: {}", commit_hash, e);
+            (Vec::new(), false)
+        }
+    };
+
+    Ok(CommitDiff {
+        hash: commit_hash.to_string(),
+        message: commit_info.message,
+        author: commit_info.author,
+        date: commit_info.date,
+        files,
+        diffs,
+        truncated,
+        stats,
+    })
+}
+
+/// Commit info from git log
+struct CommitInfo {
+    message: String,
+    author: String,
+    date: String,
+}
+
+/// Get basic commit info (message, author, date)
+fn get_commit_info(git_root: &Path, commit_hash: &str) -> Result<CommitInfo, String> {
+    let output = run_git_command(
+        git_root,
+        &[
+            "log",
+            "-1",
+            "--format=%s%n%an <%ae>%n%aI",
+            commit_hash,
+        ],
+    )?;
+
+    let lines: Vec<&str> = output.trim().lines().collect();
+    if lines.len() < 3 {
+        return Err(format!("Invalid git log output for commit: {}", commit_hash));
+    }
+
+    Ok(CommitInfo {
+        message: lines[0].to_string(),
+        author: lines[1].to_string(),
+        date: lines[2].to_string(),
+    })
+}
+
+/// Get file changes for a commit
+fn get_commit_files(git_root: &Path, commit_hash: &str) -> Result<Vec<CommitFileChange>, String> {
+    // Use --numstat for insertions/deletions and --name-status for status
+    let numstat_output = run_git_command(
+        git_root,
+        &["show", "--numstat", "--format=", commit_hash],
+    )?;
+
+    let name_status_output = run_git_command(
+        git_root,
+        &["show", "--name-status", "--format=", commit_hash],
+    )?;
+
+    // Parse numstat output (insertions, deletions, path)
+    let mut files: Vec<CommitFileChange> = Vec::new();
+    let numstat_lines: Vec<&str> = numstat_output.trim().lines().collect();
+    let status_lines: Vec<&str> = name_status_output.trim().lines().collect();
+
+    for (numstat_line, status_line) in numstat_lines.iter().zip(status_lines.iter()) {
+        let numstat_parts: Vec<&str> = numstat_line.split('\t').collect();
+        let status_parts: Vec<&str> = status_line.split('\t').collect();
+
+        if numstat_parts.len() < 3 || status_parts.is_empty() {
+            continue;
+        }
+
+        let insertions = numstat_parts[0].parse::<i32>().unwrap_or(0);
+        let deletions = numstat_parts[1].parse::<i32>().unwrap_or(0);
+
+        let status_char = status_parts[0].chars().next().unwrap_or('M');
+        let (status, old_path) = match status_char {
+            'A' => ("added".to_string(), None),
+            'D' => ("deleted".to_string(), None),
+            'M' => ("modified".to_string(), None),
+            'R' => {
+                // Renamed: status_parts[1] is old name, status_parts[2] is new name
+                let old = if status_parts.len() > 1 {
+                    Some(status_parts[1].to_string())
+                } else {
+                    None
+                };
+                ("renamed".to_string(), old)
+            }
+            'C' => ("copied".to_string(), None),
+            _ => ("modified".to_string(), None),
+        };
+
+        // For renamed files, the path in numstat includes both old and new paths
+        let path = if status_char == 'R' && status_parts.len() > 2 {
+            status_parts[2].to_string()
+        } else if status_parts.len() > 1 {
+            status_parts[1].to_string()
+        } else {
+            numstat_parts[2].to_string()
+        };
+
+        files.push(CommitFileChange {
+            path,
+            status,
+            old_path,
+            insertions,
+            deletions,
+        });
+    }
+
+    // If the zip didn't work well (different line counts), fall back to numstat only
+    if files.is_empty() && !numstat_lines.is_empty() {
+        for line in numstat_lines {
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.len() >= 3 {
+                let insertions = parts[0].parse::<i32>().unwrap_or(0);
+                let deletions = parts[1].parse::<i32>().unwrap_or(0);
+                let path = parts[2].to_string();
+
+                files.push(CommitFileChange {
+                    path,
+                    status: "modified".to_string(),
+                    old_path: None,
+                    insertions,
+                    deletions,
+                });
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Cut `output` to [`MAX_DIFF_SIZE`] at a line boundary
+fn truncate_diff(output: &str) -> (&str, bool) {
+    if output.len() <= MAX_DIFF_SIZE {
+        return (output, false);
+    }
+
+    let mut end = MAX_DIFF_SIZE;
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    let truncated = &output[..end];
+    // Find the last newline to avoid cutting in the middle of a line
+    match truncated.rfind('\n') {
+        Some(last_newline) => (&truncated[..last_newline], true),
+        None => (truncated, true),
+    }
+}
+
+/// Split `git show` / `git diff` output into per-file hunks
+pub fn parse_unified_diff(text: &str) -> Vec<FileDiff> {
+    let mut files: Vec<FileDiff> = Vec::new();
+
+    for line in text.lines() {
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            // "a/old b/new": the new path follows the last " b/"
+            let path = paths
+                .rfind(" b/")
+                .map(|i| &paths[i + 3..])
+                .unwrap_or(paths)
+                .to_string();
+            files.push(FileDiff {
+                language: detect_language(&path).map(|l| l.to_string()),
+                path,
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+
+        if line.starts_with("@@") {
+            if let Some(hunk) = parse_hunk_header(line) {
+                file.hunks.push(hunk);
+            }
+        } else if let Some(hunk) = file.hunks.last_mut() {
+            if line.starts_with(['+', '-', ' ']) || line.is_empty() || line.starts_with('\\') {
+                hunk.lines.push(line.to_string());
+            }
+        }
+    }
+
+    files
+}
+
+/// Parse `@@ -a,b +c,d @@ context`; a missing count means 1
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let ranges = line.strip_prefix("@@ ")?.split(" @@").next()?;
+    let mut parts = ranges.split_whitespace();
+    let (old_start, old_lines) = parse_range(parts.next()?.strip_prefix('-')?)?;
+    let (new_start, new_lines) = parse_range(parts.next()?.strip_prefix('+')?)?;
+
+    Some(DiffHunk {
+        header: line.to_string(),
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        lines: Vec::new(),
+    })
+}
+
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Language name for syntax highlighting, from the file extension
+pub fn detect_language(path: &str) -> Option<&'static str> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    match file_name {
+        "Dockerfile" => return Some("dockerfile"),
+        "Makefile" => return Some("makefile"),
+        _ => {}
+    }
+
+    let extension = file_name.rsplit_once('.')?.1.to_lowercase();
+    let language = match extension.as_str() {
+        "rs" => "rust",
+        "ts" | "mts" | "cts" => "typescript",
+        "tsx" => "tsx",
+        "js" | "mjs" | "cjs" => "javascript",
+        "jsx" => "jsx",
+        "py" => "python",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "swift" => "swift",
+        "rb" => "ruby",
+        "php" => "php",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hpp" => "cpp",
+        "cs" => "csharp",
+        "sh" | "bash" | "zsh" => "bash",
+        "sql" => "sql",
+        "json" => "json",
+        "toml" => "toml",
+        "yaml" | "yml" => "yaml",
+        "xml" => "xml",
+        "html" | "htm" => "html",
+        "css" => "css",
+        "scss" => "scss",
+        "md" | "markdown" => "markdown",
+        "vue" => "vue",
+        "svelte" => "svelte",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Run a git command in `git_root`
+fn run_git_command(git_root: &Path, args: &[&str]) -> Result<String, String> {
+    let output = create_command("git")
+        .args(args)
+        .current_dir(git_root)
+        .output()
+        .map_err(|e| format!("Failed to spawn git command: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Git command failed: {}", stderr));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|e| format!("Invalid UTF-8 in git output: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::fs;
+
+    const SAMPLE_DIFF: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,4 @@ mod tests
+ fn a() {}
+-fn b() {}
++fn b() -> i32 { 1 }
++fn c() {}
+ fn d() {}
+@@ -10 +11 @@
+-old
++new
+diff --git a/notes b/notes
+new file mode 100644
+--- /dev/null
++++ b/notes
+@@ -0,0 +1 @@
++hello
+";
+
+    fn sample_commit(hash: &str) -> CommitDiff {
+        CommitDiff {
+            hash: hash.to_string(),
+            message: "msg".to_string(),
+            author: "dev <dev@example.com>".to_string(),
+            date: "2026-01-15T10:00:00+00:00".to_string(),
+            files: Vec::new(),
+            diffs: Vec::new(),
+            truncated: false,
+            stats: CommitStats { files_changed: 0, insertions: 0, deletions: 0 },
+        }
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = create_command("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_parse_unified_diff_splits_files_and_hunks() {
+        let files = parse_unified_diff(SAMPLE_DIFF);
+        assert_eq!(files.len(), 2);
+
+        assert_eq!(files[0].path, "src/lib.rs");
+        assert_eq!(files[0].language.as_deref(), Some("rust"));
+        assert_eq!(files[0].hunks.len(), 2);
+        let hunk = &files[0].hunks[0];
+        assert_eq!((hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines), (1, 3, 1, 4));
+        assert_eq!(hunk.lines.len(), 5);
+        assert_eq!(hunk.lines[1], "-fn b() {}");
+        let hunk = &files[0].hunks[1];
+        assert_eq!((hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines), (10, 1, 11, 1));
+
+        assert_eq!(files[1].path, "notes");
+        assert_eq!(files[1].language, None);
+        assert_eq!(files[1].hunks[0].lines, vec!["+hello"]);
+    }
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(detect_language("src/main.rs"), Some("rust"));
+        assert_eq!(detect_language("web/src/App.TSX"), Some("tsx"));
+        assert_eq!(detect_language("docker/Dockerfile"), Some("dockerfile"));
+        assert_eq!(detect_language("LICENSE"), None);
+        assert_eq!(detect_language("archive.xyz"), None);
+    }
+
+    #[test]
+    fn test_cache_serves_second_request_and_evicts_oldest() {
+        let cache = CommitDiffCache::new(2);
+        let loads = Cell::new(0);
+        let load = |hash: &str| {
+            loads.set(loads.get() + 1);
+            Ok(sample_commit(hash))
+        };
+
+        let first = cache.get_or_load("aaa", || load("aaa")).unwrap();
+        let second = cache.get_or_load("aaa", || load("aaa")).unwrap();
+        assert_eq!(loads.get(), 1);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // "aaa" was used last, so "bbb" is evicted when "ccc" arrives
+        cache.get_or_load("bbb", || load("bbb")).unwrap();
+        cache.get_or_load("aaa", || load("aaa")).unwrap();
+        cache.get_or_load("ccc", || load("ccc")).unwrap();
+        assert_eq!(loads.get(), 3);
+        cache.get_or_load("aaa", || load("aaa")).unwrap();
+        assert_eq!(loads.get(), 3);
+        cache.get_or_load("bbb", || load("bbb")).unwrap();
+        assert_eq!(loads.get(), 4);
+
+        // Errors are not cached
+        assert!(cache.get_or_load("bad", || Err("boom".to_string())).is_err());
+        cache.get_or_load("bad", || load("bad")).unwrap();
+        assert_eq!(loads.get(), 5);
+    }
+
+    #[test]
+    fn test_get_commit_diff_tags_rust_and_serves_from_cache() {
+        let repo = tempfile::TempDir::new().unwrap();
+        git(repo.path(), &["init", "-q"]);
+        fs::create_dir(repo.path().join("src")).unwrap();
+        fs::write(repo.path().join("src/lib.rs"), "pub fn answer() -> i32 {\n    42\n}\n").unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-q", "-m", "Add answer"]);
+
+        let output = create_command("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        let hash = String::from_utf8(output.stdout).unwrap().trim().to_string();
+
+        let first = get_commit_diff(repo.path(), &hash).unwrap();
+        assert_eq!(first.message, "Add answer");
+        assert_eq!(first.stats.insertions, 3);
+        assert_eq!(first.diffs.len(), 1);
+        assert_eq!(first.diffs[0].path, "src/lib.rs");
+        assert_eq!(first.diffs[0].language.as_deref(), Some("rust"));
+        assert_eq!(first.diffs[0].hunks[0].lines[0], "+pub fn answer() -> i32 {");
+
+        // Served from cache even though the repository is gone
+        drop(repo);
+        let second = get_commit_diff(Path::new("/nonexistent"), &hash).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+}
@@ -13,6 +13,11 @@
 //! Supports two modes:
 //! - **Immediate mode**: Process each hourly summary synchronously (default)
 //! - **Batch mode**: Collect all hourly prompts, submit to OpenAI Batch API (50% cheaper, 24h delay)
+//!
+//! Immediate-mode hourly compaction records its progress per hour in
+//! `compaction_progress`, so a run killed midway picks up where it stopped:
+//! an hour is `in_progress` while its summary is generated and becomes `done`
+//! in the same transaction that saves the summary.
 
 use chrono::{Duration, NaiveDateTime};
 #[cfg(test)]
@@ -24,13 +29,17 @@ use uuid::Uuid;
 use crate::models::{SnapshotRawData, WorkSummary};
 
 use super::llm::{LlmService, parse_error_usage};
-use super::llm_batch::{BatchRequest, HourlyCompactionRequest, LlmBatchService};
+use super::llm_batch::{BatchCostEstimate, BatchRequest, HourlyCompactionRequest, LlmBatchService};
+use super::llm_cache::{get_llm_cache_config, get_or_complete, prompt_cache_key};
 use super::llm_usage::save_usage_log;
 use super::snapshot::{CommitSnapshot, ToolCallRecord};
 
 /// Maximum number of concurrent compaction tasks (limits LLM API parallelism)
 pub const COMPACTION_CONCURRENCY: usize = 10;
 
+/// Minutes after which an `in_progress` hour is assumed abandoned by a crashed run
+pub const STALE_COMPACTION_MINUTES: i64 = 10;
+
 // ============ Types ============
 
 /// Result of a compaction cycle
@@ -48,6 +57,27 @@ pub struct CompactionResult {
     pub latest_compacted_date: Option<String>,
 }
 
+/// State of an hour bucket in `compaction_progress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompactionStatus {
+    Pending,
+    InProgress,
+    Done,
+    Failed,
+}
+
+impl CompactionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CompactionStatus::Pending => "pending",
+            CompactionStatus::InProgress => "in_progress",
+            CompactionStatus::Done => "done",
+            CompactionStatus::Failed => "failed",
+        }
+    }
+}
+
 // ============ Helpers (time) ============
 
 /// Check if an hour bucket is in the past (completed).
@@ -115,11 +145,17 @@ pub async fn compact_hourly(
     .await
     .map_err(|e| format!("Failed to check existing summary: {}", e))?;
 
-    if existing.is_some() && is_hour_completed(hour_bucket) {
+    // An hour left pending or failed by an interrupted run is redone even if
+    // an older summary for it exists
+    let progress = get_hour_progress(pool, user_id, project_path, hour_bucket).await?;
+    let needs_retry = matches!(progress, Some(CompactionStatus::Pending | CompactionStatus::Failed));
+    if existing.is_some() && is_hour_completed(hour_bucket) && !needs_retry {
         log::trace!("  Summary exists and hour completed, skipping");
         return Ok(()); // Only skip if hour is finished; in-progress hours get re-compacted
     }
 
+    set_hour_progress(pool, user_id, project_path, hour_bucket, CompactionStatus::InProgress).await?;
+
     log::trace!("  Processing {} snapshots (existing: {}, hour_completed: {})",
         snapshots.len(), existing.is_some(), is_hour_completed(hour_bucket));
 
@@ -147,23 +183,21 @@ pub async fn compact_hourly(
     let (summary, llm_model) = match llm {
         Some(llm_svc) if llm_svc.is_configured() => {
             log::trace!("  Using LLM for summarization");
-            let result = llm_svc
-                .summarize_work_period(
-                    &previous_context.as_deref().unwrap_or(""),
-                    &current_data,
-                    "hourly",
-                )
-                .await;
+            let result = summarize_with_cache(
+                pool,
+                llm_svc,
+                user_id,
+                previous_context.as_deref().unwrap_or(""),
+                &current_data,
+                "hourly",
+            )
+            .await;
             match result {
-                Ok((s, usage)) => {
+                Ok(s) => {
                     log::trace!("  LLM summarization successful");
-                    let _ = save_usage_log(pool, user_id, &usage).await;
                     (s, Some("llm".to_string()))
                 }
                 Err(e) => {
-                    if let Some(usage) = parse_error_usage(&e) {
-                        let _ = save_usage_log(pool, user_id, &usage).await;
-                    }
                     log::warn!("LLM summarization failed, using rule-based: {}", e);
                     (build_rule_based_summary(&current_data, &key_activities, &git_summary), None)
                 }
@@ -177,22 +211,146 @@ pub async fn compact_hourly(
 
     log::trace!("  Saving summary (length: {} chars, model: {:?})", summary.len(), llm_model);
 
-    // Save summary
-    save_summary(
-        pool,
-        user_id,
-        Some(project_path),
-        "hourly",
-        hour_bucket,
-        &period_end,
-        &summary,
-        &key_activities,
-        &git_summary,
-        previous_context.as_deref(),
-        &snapshot_ids,
-        llm_model.as_deref(),
+    // Save the summary and mark the hour done together, so a crash leaves it
+    // either fully compacted or still in progress
+    let saved = async {
+        let mut tx = pool.begin().await.map_err(|e| format!("Failed to begin transaction: {}", e))?;
+        save_summary(
+            &mut *tx,
+            user_id,
+            Some(project_path),
+            "hourly",
+            hour_bucket,
+            &period_end,
+            &summary,
+            &key_activities,
+            &git_summary,
+            previous_context.as_deref(),
+            &snapshot_ids,
+            llm_model.as_deref(),
+        )
+        .await?;
+        set_hour_progress(&mut *tx, user_id, project_path, hour_bucket, CompactionStatus::Done).await?;
+        tx.commit().await.map_err(|e| format!("Failed to commit hourly summary: {}", e))
+    }
+    .await;
+
+    if saved.is_err() {
+        let _ = set_hour_progress(pool, user_id, project_path, hour_bucket, CompactionStatus::Failed).await;
+    }
+    saved
+}
+
+async fn get_hour_progress(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_path: &str,
+    hour_bucket: &str,
+) -> Result<Option<CompactionStatus>, String> {
+    let status: Option<String> = sqlx::query_scalar(
+        "SELECT status FROM compaction_progress WHERE user_id = ? AND project_path = ? AND hour_bucket = ?",
+    )
+    .bind(user_id)
+    .bind(project_path)
+    .bind(hour_bucket)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to read compaction progress: {}", e))?;
+
+    Ok(status.and_then(|s| match s.as_str() {
+        "pending" => Some(CompactionStatus::Pending),
+        "in_progress" => Some(CompactionStatus::InProgress),
+        "done" => Some(CompactionStatus::Done),
+        "failed" => Some(CompactionStatus::Failed),
+        _ => None,
+    }))
+}
+
+async fn set_hour_progress<'e, E: sqlx::SqliteExecutor<'e>>(
+    executor: E,
+    user_id: &str,
+    project_path: &str,
+    hour_bucket: &str,
+    status: CompactionStatus,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO compaction_progress (user_id, project_path, hour_bucket, status, updated_at)
+        VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(user_id, project_path, hour_bucket) DO UPDATE SET
+            status = excluded.status,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(project_path)
+    .bind(hour_bucket)
+    .bind(status.as_str())
+    .execute(executor)
+    .await
+    .map_err(|e| format!("Failed to record compaction progress: {}", e))?;
+    Ok(())
+}
+
+/// Put hours stuck `in_progress` for longer than [`STALE_COMPACTION_MINUTES`]
+/// back to `pending`, so the next cycle redoes them. Run on startup: a crash
+/// mid-compaction would otherwise strand them.
+pub async fn reset_stale_compaction_progress(pool: &SqlitePool) -> Result<u64, String> {
+    let reset = sqlx::query(
+        r#"
+        UPDATE compaction_progress
+        SET status = 'pending', updated_at = CURRENT_TIMESTAMP
+        WHERE status = 'in_progress' AND updated_at < datetime('now', ?)
+        "#,
     )
+    .bind(format!("-{} minutes", STALE_COMPACTION_MINUTES))
+    .execute(pool)
     .await
+    .map_err(|e| format!("Failed to reset stale compaction progress: {}", e))?
+    .rows_affected();
+
+    if reset > 0 {
+        log::info!("Reset {} interrupted hourly compaction(s) to pending", reset);
+    }
+    Ok(reset)
+}
+
+/// Summarize through the LLM, serving identical prompts from the response cache.
+///
+/// Usage (including failed calls) is logged only when the LLM is actually called.
+async fn summarize_with_cache(
+    pool: &SqlitePool,
+    llm: &LlmService,
+    user_id: &str,
+    context: &str,
+    current_data: &str,
+    scale: &str,
+) -> Result<String, String> {
+    let (prompt, max_tokens) = llm.work_period_prompt(context, current_data, scale);
+    let prompt_hash = prompt_cache_key(llm.provider(), llm.model(), &prompt);
+    let cache_config = get_llm_cache_config(pool, user_id).await;
+    let purpose = format!("{}_compaction", scale);
+
+    let (summary, cached) = get_or_complete(pool, &prompt_hash, &cache_config, || async {
+        match llm.complete_with_usage(&prompt, &purpose, max_tokens).await {
+            Ok((summary, usage)) => {
+                let _ = save_usage_log(pool, user_id, &usage).await;
+                Ok(summary)
+            }
+            Err(e) => {
+                if let Some(usage) = parse_error_usage(&e) {
+                    let _ = save_usage_log(pool, user_id, &usage).await;
+                }
+                Err(e)
+            }
+        }
+    })
+    .await?;
+
+    if cached {
+        log::trace!("  {} summary served from LLM response cache", scale);
+    }
+    Ok(summary)
 }
 
 // ============ Daily Compaction ============
@@ -275,23 +433,21 @@ pub async fn compact_daily(
     let (summary, llm_model) = match llm {
         Some(llm_svc) if llm_svc.is_configured() => {
             log::trace!("  Using LLM for daily summarization");
-            let result = llm_svc
-                .summarize_work_period(
-                    &previous_context.as_deref().unwrap_or(""),
-                    &current_data,
-                    "daily",
-                )
-                .await;
+            let result = summarize_with_cache(
+                pool,
+                llm_svc,
+                user_id,
+                previous_context.as_deref().unwrap_or(""),
+                &current_data,
+                "daily",
+            )
+            .await;
             match result {
-                Ok((s, usage)) => {
+                Ok(s) => {
                     log::trace!("  LLM daily summarization successful");
-                    let _ = save_usage_log(pool, user_id, &usage).await;
                     (s, Some("llm".to_string()))
                 }
                 Err(e) => {
-                    if let Some(usage) = parse_error_usage(&e) {
-                        let _ = save_usage_log(pool, user_id, &usage).await;
-                    }
                     log::warn!("LLM daily summarization failed: {}", e);
                     (build_rule_based_summary(&current_data, &key_activities, &git_summary), None)
                 }
@@ -413,23 +569,21 @@ pub async fn compact_period(
     let (summary, llm_model) = match llm {
         Some(llm_svc) if llm_svc.is_configured() => {
             log::trace!("  Using LLM for {} summarization", scale);
-            let result = llm_svc
-                .summarize_work_period(
-                    &previous_context.as_deref().unwrap_or(""),
-                    &current_data,
-                    scale,
-                )
-                .await;
+            let result = summarize_with_cache(
+                pool,
+                llm_svc,
+                user_id,
+                previous_context.as_deref().unwrap_or(""),
+                &current_data,
+                scale,
+            )
+            .await;
             match result {
-                Ok((s, usage)) => {
+                Ok(s) => {
                     log::trace!("  LLM {} summarization successful", scale);
-                    let _ = save_usage_log(pool, user_id, &usage).await;
                     (s, Some("llm".to_string()))
                 }
                 Err(e) => {
-                    if let Some(usage) = parse_error_usage(&e) {
-                        let _ = save_usage_log(pool, user_id, &usage).await;
-                    }
                     log::warn!("LLM {} summarization failed: {}", scale, e);
                     (build_rule_based_summary(&current_data, &key_activities, &git_summary), None)
                 }
@@ -823,6 +977,20 @@ pub async fn submit_hourly_batch(
     })
 }
 
+/// Estimate the cost of submitting the pending hourly compactions as a batch
+///
+/// Builds the same requests as [`submit_hourly_batch`] without creating a job,
+/// so the user can confirm the spend first.
+pub async fn estimate_hourly_batch_cost(
+    pool: &SqlitePool,
+    batch_service: &LlmBatchService,
+    user_id: &str,
+) -> Result<BatchCostEstimate, String> {
+    let pending = collect_pending_hourly(pool, user_id).await?;
+    let requests = prepare_hourly_batch_requests(pool, user_id, &pending).await?;
+    Ok(batch_service.estimate_cost(&requests))
+}
+
 /// Process completed batch and run remaining compaction (Phase 2)
 ///
 /// This should be called after the batch job completes. It:
@@ -1001,10 +1169,20 @@ pub async fn run_compaction_cycle(
             AND ws.period_start = s.hour_bucket
         WHERE s.user_id = ? AND ws.id IS NULL
             AND s.project_path NOT LIKE '%manual-projects%'
-        ORDER BY s.hour_bucket
+        UNION
+        SELECT p.project_path, p.hour_bucket
+        FROM compaction_progress p
+        WHERE p.user_id = ? AND p.status IN ('pending', 'failed')
+            AND EXISTS (
+                SELECT 1 FROM snapshot_raw_data s
+                WHERE s.user_id = p.user_id AND s.project_path = p.project_path
+                    AND s.hour_bucket = p.hour_bucket
+            )
+        ORDER BY hour_bucket
         "#,
     )
     .bind(user_id)
+    .bind(user_id)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to find uncompacted snapshots: {}", e))?;
@@ -1567,8 +1745,8 @@ fn merge_json_arrays(arrays: &[String]) -> String {
 }
 
 /// Save a summary to the work_summaries table.
-async fn save_summary(
-    pool: &SqlitePool,
+async fn save_summary<'e, E: sqlx::SqliteExecutor<'e>>(
+    executor: E,
     user_id: &str,
     project_path: Option<&str>,
     scale: &str,
@@ -1618,7 +1796,7 @@ async fn save_summary(
     .bind(previous_context)
     .bind(&source_ids_json)
     .bind(llm_model)
-    .execute(pool)
+    .execute(executor)
     .await
     .map_err(|e| format!("Failed to save {} summary: {}", scale, e))?;
 
@@ -0,0 +1,262 @@
+//! Database Backups
+//!
+//! Point-in-time snapshots of the SQLite database, taken with `VACUUM INTO`
+//! so the copy is consistent even while other connections are writing.
+//! Backups live in `~/.recap/backups` as `recap-YYYYMMDD-HHMM.db`; the
+//! background service takes one a day when `db_backup_enabled` is set and
+//! keeps the newest `db_backup_keep`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use chrono::Local;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+
+use super::claude_paths::home_dir;
+
+/// Default number of scheduled backups to keep
+pub const DEFAULT_DB_BACKUP_KEEP: i64 = 7;
+
+/// Minimum age of the newest backup before the scheduler takes another
+pub const DB_BACKUP_INTERVAL_HOURS: u64 = 24;
+
+const BACKUP_PREFIX: &str = "recap-";
+const BACKUP_EXTENSION: &str = ".db";
+
+/// Backup settings from `db_backup_enabled` / `db_backup_keep`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DbBackupConfig {
+    /// Whether the background service takes scheduled backups
+    pub enabled: bool,
+    /// Number of backups kept after pruning
+    pub keep: i64,
+}
+
+impl Default for DbBackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            keep: DEFAULT_DB_BACKUP_KEEP,
+        }
+    }
+}
+
+/// Load the user's backup settings, falling back to the defaults
+pub async fn get_db_backup_config(pool: &SqlitePool, user_id: &str) -> DbBackupConfig {
+    let row: Option<(Option<bool>, Option<i64>)> =
+        sqlx::query_as("SELECT db_backup_enabled, db_backup_keep FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+
+    let defaults = DbBackupConfig::default();
+    match row {
+        Some((enabled, keep)) => DbBackupConfig {
+            enabled: enabled.unwrap_or(defaults.enabled),
+            keep: keep.unwrap_or(defaults.keep),
+        },
+        None => defaults,
+    }
+}
+
+/// Default backup directory (`~/.recap/backups`)
+pub fn default_backup_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(".recap").join("backups"))
+}
+
+/// Unused path in `dir` for a backup taken now, e.g. `recap-20260301-0930.db`.
+///
+/// Later backups in the same minute get a `-1`, `-2`, ... suffix rather than
+/// overwriting, so a restore never clobbers the file it is restoring from.
+fn next_backup_path(dir: &Path) -> PathBuf {
+    let stem = format!("{}{}", BACKUP_PREFIX, Local::now().format("%Y%m%d-%H%M"));
+    let mut path = dir.join(format!("{}{}", stem, BACKUP_EXTENSION));
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("{}-{}{}", stem, n, BACKUP_EXTENSION));
+        n += 1;
+    }
+    path
+}
+
+/// Snapshot the database into `dir` and return the backup's path
+pub async fn backup_database(pool: &SqlitePool, dir: &Path) -> Result<PathBuf, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let path = next_backup_path(dir);
+
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to back up database: {}", e))?;
+
+    Ok(path)
+}
+
+/// Backups in `dir`, oldest first
+pub fn list_backups(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {}", dir.display(), e)),
+    };
+
+    // Timestamped stems sort chronologically (`-0930` before `-0930-1`)
+    let mut backups: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(BACKUP_PREFIX) && n.ends_with(BACKUP_EXTENSION))
+        })
+        .collect();
+    backups.sort_by(|a, b| a.file_stem().cmp(&b.file_stem()));
+    Ok(backups)
+}
+
+/// Delete the oldest backups in `dir` beyond `keep`, returning how many were removed
+pub fn prune_backups(dir: &Path, keep: i64) -> Result<usize, String> {
+    let backups = list_backups(dir)?;
+    let excess = backups.len().saturating_sub(keep.max(1) as usize);
+    for path in &backups[..excess] {
+        std::fs::remove_file(path).map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+    }
+    Ok(excess)
+}
+
+/// Whether the newest backup in `dir` is older than the schedule interval
+pub fn backup_due(dir: &Path) -> bool {
+    let newest = list_backups(dir).ok().and_then(|b| b.last().cloned());
+    let Some(newest) = newest else {
+        return true;
+    };
+    let age = std::fs::metadata(&newest)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    age.is_none_or(|age| age >= Duration::from_secs(DB_BACKUP_INTERVAL_HOURS * 3600))
+}
+
+/// Take a scheduled backup if enabled and due, then prune to the keep count.
+///
+/// Returns the new backup's path, or `None` when nothing was taken.
+pub async fn run_scheduled_backup(pool: &SqlitePool, user_id: &str) -> Result<Option<PathBuf>, String> {
+    let config = get_db_backup_config(pool, user_id).await;
+    if !config.enabled {
+        return Ok(None);
+    }
+    let dir = default_backup_dir().ok_or("Could not determine home directory")?;
+    if !backup_due(&dir) {
+        return Ok(None);
+    }
+
+    let path = backup_database(pool, &dir).await?;
+    let pruned = prune_backups(&dir, config.keep)?;
+    log::info!("Backed up database to {} (pruned {} old backups)", path.display(), pruned);
+    Ok(Some(path))
+}
+
+/// Check that `path` is an SQLite database that passes `PRAGMA integrity_check`
+pub async fn verify_backup(path: &Path) -> Result<(), String> {
+    if !path.is_file() {
+        return Err(format!("Backup not found: {}", path.display()));
+    }
+
+    let options = SqliteConnectOptions::new().filename(path).read_only(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+
+    let result: Result<String, _> = sqlx::query_scalar("PRAGMA integrity_check").fetch_one(&pool).await;
+    pool.close().await;
+
+    match result {
+        Ok(status) if status == "ok" => Ok(()),
+        Ok(status) => Err(format!("{} failed integrity check: {}", path.display(), status)),
+        Err(e) => Err(format!("{} is not a valid database: {}", path.display(), e)),
+    }
+}
+
+/// Replace the database at `db_path` with `backup`.
+///
+/// All connections to `db_path` must be closed first. Stale WAL and
+/// shared-memory files are removed so they aren't replayed onto the restored copy.
+pub async fn restore_database(backup: &Path, db_path: &Path) -> Result<(), String> {
+    verify_backup(backup).await?;
+
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = db_path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let sidecar = PathBuf::from(sidecar);
+        if sidecar.exists() {
+            std::fs::remove_file(&sidecar)
+                .map_err(|e| format!("Failed to remove {}: {}", sidecar.display(), e))?;
+        }
+    }
+
+    std::fs::copy(backup, db_path)
+        .map_err(|e| format!("Failed to restore {} to {}: {}", backup.display(), db_path.display(), e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+    use crate::Database;
+
+    #[tokio::test]
+    async fn test_vacuum_into_produces_openable_copy() {
+        let (db, dir) = db_with_user().await;
+        for i in 0..3 {
+            sqlx::query("INSERT INTO work_items (id, user_id, title, hours, date) VALUES (?, 'u1', ?, 1.5, '2026-03-02')")
+                .bind(format!("item-{}", i))
+                .bind(format!("Task {}", i))
+                .execute(&db.pool)
+                .await
+                .unwrap();
+        }
+
+        let backups = dir.path().join("backups");
+        let backup = backup_database(&db.pool, &backups).await.unwrap();
+        assert!(backup.file_name().unwrap().to_str().unwrap().starts_with("recap-"));
+        verify_backup(&backup).await.unwrap();
+
+        // A second backup never overwrites the first
+        let second = backup_database(&db.pool, &backups).await.unwrap();
+        assert_ne!(second, backup);
+        assert_eq!(list_backups(&backups).unwrap().last(), Some(&second));
+
+        let copy = Database::open(backup).await.unwrap();
+        let titles: Vec<String> = sqlx::query_scalar("SELECT title FROM work_items ORDER BY title")
+            .fetch_all(&copy.pool)
+            .await
+            .unwrap();
+        assert_eq!(titles, vec!["Task 0", "Task 1", "Task 2"]);
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_backups() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["recap-20260301-0900.db", "recap-20260302-0900.db", "recap-20260303-0900.db", "notes.txt"] {
+            std::fs::write(dir.path().join(name), b"").unwrap();
+        }
+
+        assert_eq!(prune_backups(dir.path(), 2).unwrap(), 1);
+        let names: Vec<String> = list_backups(dir.path())
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["recap-20260302-0900.db", "recap-20260303-0900.db"]);
+        assert!(dir.path().join("notes.txt").exists());
+        assert!(!backup_due(dir.path()));
+        assert!(backup_due(&dir.path().join("missing")));
+    }
+}
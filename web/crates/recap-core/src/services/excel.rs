@@ -37,9 +37,125 @@ pub struct ProjectSummary {
     pub item_count: usize,
 }
 
+/// Work item fields that can be mapped to template columns
+pub const EXCEL_TEMPLATE_FIELDS: &[&str] = &[
+    "date", "title", "description", "hours", "project", "jira_key", "source", "synced_to_tempo",
+];
+
+/// Column layout for the Details sheet, loaded from JSON:
+///
+/// ```json
+/// {"columns": [{"header": "Date", "field": "date"}, {"header": "Ticket", "field": "jira_key"}]}
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcelTemplate {
+    pub columns: Vec<ExcelTemplateColumn>,
+}
+
+/// One output column: header text and the work item field it shows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcelTemplateColumn {
+    pub header: String,
+    pub field: String,
+    /// Column width in characters (defaults by field)
+    #[serde(default)]
+    pub width: Option<f64>,
+}
+
+/// Value written to a Details sheet cell
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExcelCell {
+    Text(String),
+    Number(f64),
+}
+
+impl ExcelTemplate {
+    /// Parse and validate a template from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        let template: Self = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Invalid Excel template: {}", e))?;
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// The built-in Details layout
+    pub fn default_layout() -> Self {
+        let columns = [
+            ("Date", "date"),
+            ("Title", "title"),
+            ("Hours", "hours"),
+            ("Project", "project"),
+            ("Jira", "jira_key"),
+            ("Source", "source"),
+            ("Synced", "synced_to_tempo"),
+        ];
+        Self {
+            columns: columns
+                .iter()
+                .map(|(header, field)| ExcelTemplateColumn {
+                    header: header.to_string(),
+                    field: field.to_string(),
+                    width: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Check the template has columns and every field exists
+    pub fn validate(&self) -> Result<()> {
+        if self.columns.is_empty() {
+            anyhow::bail!("Excel template must define at least one column");
+        }
+        for column in &self.columns {
+            if !EXCEL_TEMPLATE_FIELDS.contains(&column.field.as_str()) {
+                anyhow::bail!(
+                    "Unknown field '{}' in Excel template column '{}'. Available fields: {}",
+                    column.field,
+                    column.header,
+                    EXCEL_TEMPLATE_FIELDS.join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Cell values for one work item, in column order
+    pub fn cells(&self, item: &ExcelWorkItem) -> Vec<ExcelCell> {
+        self.columns
+            .iter()
+            .map(|column| match column.field.as_str() {
+                "date" => ExcelCell::Text(item.date.clone()),
+                "title" => ExcelCell::Text(item.title.clone()),
+                "description" => ExcelCell::Text(item.description.clone().unwrap_or_default()),
+                "hours" => ExcelCell::Number(item.hours),
+                "project" => ExcelCell::Text(item.project.clone().unwrap_or_default()),
+                "jira_key" => ExcelCell::Text(item.jira_key.clone().unwrap_or_default()),
+                "source" => ExcelCell::Text(item.source.clone()),
+                "synced_to_tempo" => {
+                    ExcelCell::Text(if item.synced_to_tempo { "Yes" } else { "No" }.to_string())
+                }
+                _ => ExcelCell::Text(String::new()),
+            })
+            .collect()
+    }
+}
+
+/// Default column width for a field
+fn default_column_width(field: &str) -> f64 {
+    match field {
+        "title" | "description" => 50.0,
+        "project" => 20.0,
+        "jira_key" => 15.0,
+        "date" | "source" => 12.0,
+        _ => 10.0,
+    }
+}
+
 /// Excel report generator
 pub struct ExcelReportGenerator {
     workbook: Workbook,
+    /// Details sheet layout
+    template: ExcelTemplate,
     // Styles
     header_format: Format,
     total_format: Format,
@@ -80,6 +196,7 @@ impl ExcelReportGenerator {
 
         Ok(Self {
             workbook,
+            template: ExcelTemplate::default_layout(),
             header_format,
             total_format,
             date_format,
@@ -87,6 +204,13 @@ impl ExcelReportGenerator {
         })
     }
 
+    /// Use a custom column layout for the Details sheet
+    pub fn with_template(mut self, template: ExcelTemplate) -> Result<Self> {
+        template.validate()?;
+        self.template = template;
+        Ok(self)
+    }
+
     /// Create a personal work report
     pub fn create_personal_report(
         &mut self,
@@ -174,31 +298,34 @@ impl ExcelReportGenerator {
         worksheet.set_name("Details")?;
 
         // Headers
-        let headers = ["Date", "Title", "Hours", "Project", "Jira", "Source", "Synced"];
-        for (col, header) in headers.iter().enumerate() {
-            worksheet.write_with_format(0, col as u16, *header, &self.header_format)?;
+        for (col, column) in self.template.columns.iter().enumerate() {
+            worksheet.write_with_format(0, col as u16, &column.header, &self.header_format)?;
         }
 
         // Data rows
         for (idx, item) in items.iter().enumerate() {
             let row = 1 + idx as u32;
-            worksheet.write_with_format(row, 0, &item.date, &self.date_format)?;
-            worksheet.write(row, 1, &item.title)?;
-            worksheet.write_with_format(row, 2, item.hours, &self.number_format)?;
-            worksheet.write(row, 3, item.project.as_deref().unwrap_or(""))?;
-            worksheet.write(row, 4, item.jira_key.as_deref().unwrap_or(""))?;
-            worksheet.write(row, 5, &item.source)?;
-            worksheet.write(row, 6, if item.synced_to_tempo { "Yes" } else { "No" })?;
+            for (col, (column, cell)) in self.template.columns.iter().zip(self.template.cells(item)).enumerate() {
+                let col = col as u16;
+                match cell {
+                    ExcelCell::Number(value) => {
+                        worksheet.write_with_format(row, col, value, &self.number_format)?;
+                    }
+                    ExcelCell::Text(value) if column.field == "date" => {
+                        worksheet.write_with_format(row, col, value, &self.date_format)?;
+                    }
+                    ExcelCell::Text(value) => {
+                        worksheet.write(row, col, value)?;
+                    }
+                }
+            }
         }
 
         // Column widths
-        worksheet.set_column_width(0, 12)?;
-        worksheet.set_column_width(1, 50)?;
-        worksheet.set_column_width(2, 10)?;
-        worksheet.set_column_width(3, 20)?;
-        worksheet.set_column_width(4, 15)?;
-        worksheet.set_column_width(5, 12)?;
-        worksheet.set_column_width(6, 10)?;
+        for (col, column) in self.template.columns.iter().enumerate() {
+            let width = column.width.unwrap_or_else(|| default_column_width(&column.field));
+            worksheet.set_column_width(col as u16, width)?;
+        }
 
         Ok(())
     }
@@ -317,4 +444,78 @@ mod tests {
         assert!(buffer.is_ok());
         assert!(!buffer.unwrap().is_empty());
     }
+
+    fn sample_item() -> ExcelWorkItem {
+        ExcelWorkItem {
+            date: "2025-01-15".to_string(),
+            title: "Fix login flow".to_string(),
+            description: Some("Handled expired tokens".to_string()),
+            hours: 2.5,
+            project: Some("Project A".to_string()),
+            jira_key: Some("PROJ-123".to_string()),
+            source: "claude_code".to_string(),
+            synced_to_tempo: true,
+        }
+    }
+
+    #[test]
+    fn test_create_report_with_custom_template() {
+        let template = ExcelTemplate::from_json(
+            r#"{"columns": [
+                {"header": "Date", "field": "date"},
+                {"header": "Ticket", "field": "jira_key"},
+                {"header": "Hours", "field": "hours"},
+                {"header": "Notes", "field": "description", "width": 60}
+            ]}"#,
+        )
+        .unwrap();
+
+        let headers: Vec<&str> = template.columns.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(headers, vec!["Date", "Ticket", "Hours", "Notes"]);
+        assert_eq!(
+            template.cells(&sample_item()),
+            vec![
+                ExcelCell::Text("2025-01-15".to_string()),
+                ExcelCell::Text("PROJ-123".to_string()),
+                ExcelCell::Number(2.5),
+                ExcelCell::Text("Handled expired tokens".to_string()),
+            ]
+        );
+
+        let metadata = ReportMetadata {
+            user_name: "Test User".to_string(),
+            start_date: "2025-01-01".to_string(),
+            end_date: "2025-01-31".to_string(),
+            generated_at: "2025-01-31 10:00:00".to_string(),
+        };
+        let projects = vec![ProjectSummary {
+            project_name: "Project A".to_string(),
+            total_hours: 2.5,
+            item_count: 1,
+        }];
+
+        let mut generator = ExcelReportGenerator::new().unwrap().with_template(template).unwrap();
+        generator
+            .create_personal_report(&metadata, &[sample_item()], &projects)
+            .unwrap();
+        assert!(!generator.save_to_buffer().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_template_matches_builtin_layout() {
+        let template = ExcelTemplate::default_layout();
+        let headers: Vec<&str> = template.columns.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(headers, vec!["Date", "Title", "Hours", "Project", "Jira", "Source", "Synced"]);
+        assert_eq!(template.cells(&sample_item())[6], ExcelCell::Text("Yes".to_string()));
+    }
+
+    #[test]
+    fn test_template_rejects_unknown_field() {
+        let err = ExcelTemplate::from_json(r#"{"columns": [{"header": "Ticket", "field": "ticket"}]}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown field 'ticket'"));
+
+        assert!(ExcelTemplate::from_json(r#"{"columns": []}"#).is_err());
+        assert!(ExcelTemplate::from_json("not json").is_err());
+    }
 }
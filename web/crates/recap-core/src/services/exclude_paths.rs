@@ -0,0 +1,89 @@
+//! Excluded Paths
+//!
+//! Glob patterns for files that are noise in file-level reports (lockfiles,
+//! generated code). Set with the `exclude_paths` user setting as a
+//! comma-separated list; lockfiles are excluded when the setting is unset.
+
+use glob::Pattern;
+use sqlx::SqlitePool;
+
+/// Patterns used when `exclude_paths` is not set
+pub const DEFAULT_EXCLUDE_PATHS: &[&str] = &[
+    "*.lock",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "go.sum",
+];
+
+/// Matches file paths against exclude patterns.
+///
+/// A pattern matches either the whole path or just the file name, so `*.lock`
+/// excludes `Cargo.lock` anywhere while `dist/*` only matches paths under `dist`.
+#[derive(Debug, Clone, Default)]
+pub struct PathExcludeFilter {
+    patterns: Vec<Pattern>,
+}
+
+impl PathExcludeFilter {
+    pub fn new(patterns: &[String]) -> Result<Self, String> {
+        let patterns = patterns
+            .iter()
+            .map(|p| Pattern::new(p).map_err(|e| format!("Invalid exclude pattern '{}': {}", p, e)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let file_name = std::path::Path::new(path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(path);
+        self.patterns.iter().any(|p| p.matches(path) || p.matches(file_name))
+    }
+}
+
+/// Split a comma-separated `exclude_paths` value into patterns
+pub fn parse_exclude_paths(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The user's exclude patterns, or [`DEFAULT_EXCLUDE_PATHS`] if unset
+pub async fn get_exclude_paths(pool: &SqlitePool, user_id: &str) -> Vec<String> {
+    let value: Option<Option<String>> = sqlx::query_scalar("SELECT exclude_paths FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten();
+
+    match value.flatten() {
+        Some(value) => parse_exclude_paths(&value),
+        None => DEFAULT_EXCLUDE_PATHS.iter().map(|p| p.to_string()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_match_path_or_file_name() {
+        let defaults: Vec<String> = DEFAULT_EXCLUDE_PATHS.iter().map(|p| p.to_string()).collect();
+        let filter = PathExcludeFilter::new(&defaults).unwrap();
+        assert!(filter.is_excluded("/home/dev/recap/Cargo.lock"));
+        assert!(filter.is_excluded("web/package-lock.json"));
+        assert!(!filter.is_excluded("/home/dev/recap/src/lib.rs"));
+
+        let filter = PathExcludeFilter::new(&parse_exclude_paths(" dist/*, ,*.snap")).unwrap();
+        assert!(filter.is_excluded("dist/app.js"));
+        assert!(filter.is_excluded("tests/__snapshots__/view.snap"));
+        assert!(!filter.is_excluded("src/dist.rs"));
+        assert!(PathExcludeFilter::new(&["[".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! GitLab API helpers
+//!
+//! Minimal calls against the GitLab REST API (v4) with a personal access
+//! token. Project and commit sync still live in the desktop app; this is
+//! shared by anything that only needs to know whether the token works.
+
+use reqwest::Client;
+use serde::Deserialize;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// The user a GitLab token belongs to
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabUser {
+    pub id: i64,
+    pub username: String,
+    pub name: Option<String>,
+}
+
+/// Fetch the token owner from `GET /api/v4/user`, a cheap authenticated ping
+pub async fn get_gitlab_user(gitlab_url: &str, pat: &str) -> Result<GitLabUser, String> {
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let response = client
+        .get(user_url(gitlab_url))
+        .header("PRIVATE-TOKEN", pat)
+        .send()
+        .await
+        .map_err(|e| format!("GitLab API error: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitLab API returned: {}", response.status()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitLab user: {}", e))
+}
+
+fn user_url(gitlab_url: &str) -> String {
+    format!("{}/api/v4/user", gitlab_url.trim_end_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_url_ignores_trailing_slash() {
+        assert_eq!(user_url("https://gitlab.com/"), "https://gitlab.com/api/v4/user");
+        assert_eq!(user_url("https://git.example.com"), "https://git.example.com/api/v4/user");
+    }
+}
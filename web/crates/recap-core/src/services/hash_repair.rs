@@ -0,0 +1,319 @@
+//! Content Hash Repair
+//!
+//! Sync deduplicates work items on `content_hash`, so rows written by an older
+//! hash algorithm (e.g. the project-path based session hash, or the daily
+//! hash of the first Claude import) no longer match what a new sync computes
+//! and end up duplicated. This recomputes every stored hash with
+//! [`compute_content_hash`]. Rows that would share a hash are collisions: the most recently
+//! updated one keeps the hash, the rest lose theirs and are tagged with
+//! [`HASH_COLLISION_TAG`] for review.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::sources::work_item::compute_content_hash;
+
+/// Tag added to items that lost their hash to a more recent duplicate
+pub const HASH_COLLISION_TAG: &str = "hash-collision";
+
+/// A stored hash that differs from the current algorithm's
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HashUpdate {
+    pub id: String,
+    pub old_hash: String,
+    pub new_hash: String,
+}
+
+/// Items that recompute to the same hash for one user
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HashCollision {
+    pub content_hash: String,
+    /// Most recently updated item, which keeps the hash
+    pub kept_id: String,
+    /// Items whose hash is cleared and that get [`HASH_COLLISION_TAG`]
+    pub duplicate_ids: Vec<String>,
+}
+
+/// What a repair changes (or changed, once applied)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HashRepairPlan {
+    /// Items with a stored hash that were recomputed
+    pub checked: usize,
+    pub updates: Vec<HashUpdate>,
+    pub collisions: Vec<HashCollision>,
+}
+
+impl HashRepairPlan {
+    /// Whether every stored hash already matches the current algorithm
+    pub fn is_clean(&self) -> bool {
+        self.updates.is_empty() && self.collisions.is_empty()
+    }
+
+    /// Items that duplicate another one (each collision keeps one item)
+    pub fn duplicate_count(&self) -> usize {
+        self.collisions.iter().map(|c| c.duplicate_ids.len()).sum()
+    }
+}
+
+/// `(id, user_id, source, session_id, source_id, title, date, content_hash, updated_at)`
+type HashRow = (
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+    String,
+    String,
+    DateTime<Utc>,
+);
+
+/// `(id, stored hash, updated_at)` of items sharing a recomputed hash
+type HashGroup = Vec<(String, String, DateTime<Utc>)>;
+
+/// Recompute all stored hashes without writing anything
+pub async fn plan_hash_repair(pool: &SqlitePool) -> Result<HashRepairPlan, String> {
+    let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+    Ok(build_plan(load_rows(&mut conn).await?))
+}
+
+/// Recompute all stored hashes and write them in one transaction
+pub async fn apply_hash_repair(pool: &SqlitePool) -> Result<HashRepairPlan, String> {
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+    let plan = build_plan(load_rows(&mut tx).await?);
+
+    // Clear every hash that changes first, so swapped or collapsing hashes
+    // never trip the (user_id, content_hash) unique index midway
+    let cleared = plan
+        .updates
+        .iter()
+        .map(|u| &u.id)
+        .chain(plan.collisions.iter().flat_map(|c| &c.duplicate_ids));
+    for id in cleared {
+        sqlx::query("UPDATE work_items SET content_hash = NULL WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    for update in &plan.updates {
+        sqlx::query("UPDATE work_items SET content_hash = ? WHERE id = ?")
+            .bind(&update.new_hash)
+            .bind(&update.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let now = Utc::now();
+    for id in plan.collisions.iter().flat_map(|c| &c.duplicate_ids) {
+        let tags: Option<String> = sqlx::query_scalar("SELECT tags FROM work_items WHERE id = ?")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        sqlx::query("UPDATE work_items SET tags = ?, updated_at = ? WHERE id = ?")
+            .bind(add_tag(tags.as_deref(), HASH_COLLISION_TAG))
+            .bind(now)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok(plan)
+}
+
+async fn load_rows(conn: &mut SqliteConnection) -> Result<Vec<HashRow>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, user_id, source, session_id, source_id, title, date, content_hash, updated_at
+        FROM work_items
+        WHERE content_hash IS NOT NULL
+        "#,
+    )
+    .fetch_all(conn)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+fn build_plan(rows: Vec<HashRow>) -> HashRepairPlan {
+    let checked = rows.len();
+    let mut groups: HashMap<(String, String), HashGroup> = HashMap::new();
+    for (id, user_id, source, session_id, source_id, title, date, old_hash, updated_at) in rows {
+        // Same key `upsert_work_item` hashes: the session, else the source ID
+        let key = session_id.or(source_id).unwrap_or_default();
+        let new_hash = compute_content_hash(&user_id, &source, &key, &title, &date);
+        groups.entry((user_id, new_hash)).or_default().push((id, old_hash, updated_at));
+    }
+
+    let mut plan = HashRepairPlan {
+        checked,
+        ..Default::default()
+    };
+    for ((_, new_hash), mut items) in groups {
+        // Newest first; ID breaks ties so the plan is deterministic
+        items.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        let mut items = items.into_iter();
+        let Some((kept_id, old_hash, _)) = items.next() else {
+            continue;
+        };
+        let duplicate_ids: Vec<String> = items.map(|(id, _, _)| id).collect();
+
+        if old_hash != new_hash {
+            plan.updates.push(HashUpdate {
+                id: kept_id.clone(),
+                old_hash,
+                new_hash: new_hash.clone(),
+            });
+        }
+        if !duplicate_ids.is_empty() {
+            plan.collisions.push(HashCollision {
+                content_hash: new_hash,
+                kept_id,
+                duplicate_ids,
+            });
+        }
+    }
+    plan.updates.sort_by(|a, b| a.id.cmp(&b.id));
+    plan.collisions.sort_by(|a, b| a.kept_id.cmp(&b.kept_id));
+    plan
+}
+
+/// Add `tag` to a JSON tag array, keeping existing tags
+fn add_tag(tags: Option<&str>, tag: &str) -> String {
+    let mut list: Vec<String> = tags
+        .and_then(|t| serde_json::from_str(t).ok())
+        .unwrap_or_default();
+    if !list.iter().any(|t| t == tag) {
+        list.push(tag.to_string());
+    }
+    serde_json::to_string(&list).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    async fn insert_item(pool: &SqlitePool, id: &str, session_id: Option<&str>, hash: Option<&str>, updated_at: &str) {
+        sqlx::query(
+            r#"
+            INSERT INTO work_items (id, user_id, source, title, hours, date, session_id, content_hash, updated_at)
+            VALUES (?, 'u1', 'claude_code', ?, 1.0, '2026-03-02', ?, ?, ?)
+            "#,
+        )
+        .bind(id)
+        .bind(format!("Item {}", id))
+        .bind(session_id)
+        .bind(hash)
+        .bind(updated_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn item_state(pool: &SqlitePool, id: &str) -> (Option<String>, Option<String>) {
+        sqlx::query_as("SELECT content_hash, tags FROM work_items WHERE id = ?")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_repair_is_idempotent() {
+        let (db, _dir) = db_with_user().await;
+        let current = compute_content_hash("u1", "claude_code", "sess-a", "Item a", "2026-03-02");
+        insert_item(&db.pool, "a", Some("sess-a"), Some(&current), "2026-03-02T10:00:00Z").await;
+        insert_item(&db.pool, "b", Some("sess-b"), Some("sess_legacy_b"), "2026-03-02T10:00:00Z").await;
+        // Manual item without a hash is left alone
+        insert_item(&db.pool, "c", None, None, "2026-03-02T10:00:00Z").await;
+
+        let plan = plan_hash_repair(&db.pool).await.unwrap();
+        assert_eq!(plan.checked, 2);
+        assert_eq!(
+            plan.updates,
+            vec![HashUpdate {
+                id: "b".to_string(),
+                old_hash: "sess_legacy_b".to_string(),
+                new_hash: compute_content_hash("u1", "claude_code", "sess-b", "Item b", "2026-03-02"),
+            }]
+        );
+        assert!(plan.collisions.is_empty());
+
+        // Dry run wrote nothing
+        assert_eq!(item_state(&db.pool, "b").await.0.as_deref(), Some("sess_legacy_b"));
+
+        assert_eq!(apply_hash_repair(&db.pool).await.unwrap(), plan);
+        assert!(plan_hash_repair(&db.pool).await.unwrap().is_clean());
+        assert!(apply_hash_repair(&db.pool).await.unwrap().is_clean());
+        assert_eq!(item_state(&db.pool, "c").await, (None, None));
+    }
+
+    #[tokio::test]
+    async fn test_repair_flags_collision_and_keeps_newest() {
+        let (db, _dir) = db_with_user().await;
+        // The same session stored twice under two legacy hashes
+        insert_item(&db.pool, "old", Some("sess-a"), Some("sess_legacy_1"), "2026-03-02T10:00:00Z").await;
+        insert_item(&db.pool, "new", Some("sess-a"), Some("sess_legacy_2"), "2026-03-03T10:00:00Z").await;
+        let current = compute_content_hash("u1", "claude_code", "sess-a", "Item new", "2026-03-02");
+
+        let plan = plan_hash_repair(&db.pool).await.unwrap();
+        assert_eq!(
+            plan.collisions,
+            vec![HashCollision {
+                content_hash: current.clone(),
+                kept_id: "new".to_string(),
+                duplicate_ids: vec!["old".to_string()],
+            }]
+        );
+        assert_eq!(plan.duplicate_count(), 1);
+
+        apply_hash_repair(&db.pool).await.unwrap();
+        assert_eq!(item_state(&db.pool, "new").await, (Some(current), None));
+        assert_eq!(
+            item_state(&db.pool, "old").await,
+            (None, Some(format!("[\"{}\"]", HASH_COLLISION_TAG)))
+        );
+        assert!(plan_hash_repair(&db.pool).await.unwrap().is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_repair_matches_items_without_ids_by_title_and_date() {
+        let (db, _dir) = db_with_user().await;
+        for (id, hash, updated_at) in [("x", "legacy_x", "2026-03-02T10:00:00Z"), ("y", "legacy_y", "2026-03-03T10:00:00Z")] {
+            sqlx::query(
+                r#"
+                INSERT INTO work_items (id, user_id, source, title, hours, date, content_hash, updated_at)
+                VALUES (?, 'u1', 'imported', 'Planning', 1.0, '2026-03-02', ?, ?)
+                "#,
+            )
+            .bind(id)
+            .bind(hash)
+            .bind(updated_at)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        }
+
+        let plan = plan_hash_repair(&db.pool).await.unwrap();
+        let expected = compute_content_hash("u1", "imported", "", "Planning", "2026-03-02");
+        assert_eq!(plan.updates.len(), 1);
+        assert_eq!(plan.updates[0].new_hash, expected);
+        assert_eq!(plan.collisions[0].kept_id, "y");
+        assert_eq!(plan.duplicate_count(), 1);
+    }
+
+    #[test]
+    fn test_add_tag_keeps_existing_tags() {
+        assert_eq!(add_tag(Some(r#"["billing"]"#), "x"), r#"["billing","x"]"#);
+        assert_eq!(add_tag(Some(r#"["x"]"#), "x"), r#"["x"]"#);
+        assert_eq!(add_tag(None, "x"), r#"["x"]"#);
+    }
+}
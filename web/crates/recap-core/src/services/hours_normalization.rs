@@ -0,0 +1,259 @@
+//! Hours Normalization
+//!
+//! Scale a day's item hours toward the user's `daily_work_hours` target when
+//! `normalize_hours` is on. `normalize_weighting` decides how the difference
+//! is shared out:
+//!
+//! - `even`: every item gets the same share
+//! - `hours`: shares are proportional to each item's estimated hours
+//! - `category`: like `hours`, with each share multiplied by the item's
+//!   category weight from `category_weights` (`feature=2,chore=0.5`)
+
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+/// How the difference to the target is distributed across a day's items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizeWeighting {
+    #[default]
+    Even,
+    Hours,
+    Category,
+}
+
+impl NormalizeWeighting {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "even" => Ok(Self::Even),
+            "hours" => Ok(Self::Hours),
+            "category" => Ok(Self::Category),
+            other => Err(format!("Invalid normalize_weighting: {}. Use even, hours or category.", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Even => "even",
+            Self::Hours => "hours",
+            Self::Category => "category",
+        }
+    }
+}
+
+/// Normalization settings for one user
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizeConfig {
+    pub enabled: bool,
+    /// Target hours per day
+    pub target: f64,
+    pub weighting: NormalizeWeighting,
+    /// Per-category weight multipliers; categories not listed weigh 1.0
+    pub category_weights: HashMap<String, f64>,
+}
+
+impl Default for NormalizeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            target: 8.0,
+            weighting: NormalizeWeighting::Even,
+            category_weights: HashMap::new(),
+        }
+    }
+}
+
+/// One item of a day to normalize
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeItem<'a> {
+    pub hours: f64,
+    pub category: Option<&'a str>,
+}
+
+/// Parse `category=weight` pairs separated by commas
+pub fn parse_category_weights(s: &str) -> Result<HashMap<String, f64>, String> {
+    let mut weights = HashMap::new();
+    for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (category, weight) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid category weight: {}. Use category=weight.", pair))?;
+        let weight: f64 = weight
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid weight for {}: {}", category.trim(), weight.trim()))?;
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(format!("Weight for {} must be 0 or greater", category.trim()));
+        }
+        weights.insert(category.trim().to_lowercase(), weight);
+    }
+    Ok(weights)
+}
+
+/// Hours for each item after distributing `target - total` per `config`.
+///
+/// Returns the hours unchanged when normalization is disabled or the day is
+/// empty. Weighted modes fall back to `even` when every weight is zero.
+/// Results never go below zero.
+pub fn normalize_day_hours(items: &[NormalizeItem], config: &NormalizeConfig) -> Vec<f64> {
+    let hours: Vec<f64> = items.iter().map(|i| i.hours).collect();
+    if !config.enabled || items.is_empty() {
+        return hours;
+    }
+
+    let total = hours.iter().fold(0.0, |acc, h| acc + h);
+    let diff = config.target - total;
+
+    let weights: Vec<f64> = match config.weighting {
+        NormalizeWeighting::Even => vec![1.0; items.len()],
+        NormalizeWeighting::Hours => hours.iter().map(|h| h.max(0.0)).collect(),
+        NormalizeWeighting::Category => items
+            .iter()
+            .map(|i| i.hours.max(0.0) * category_weight(&config.category_weights, i.category))
+            .collect(),
+    };
+    let weight_total = weights.iter().fold(0.0, |acc, w| acc + w);
+    let weights = if weight_total > 0.0 {
+        weights.into_iter().map(|w| w / weight_total).collect()
+    } else {
+        vec![1.0 / items.len() as f64; items.len()]
+    };
+
+    hours
+        .iter()
+        .zip(weights)
+        .map(|(h, w)| (h + diff * w).max(0.0))
+        .collect()
+}
+
+fn category_weight(weights: &HashMap<String, f64>, category: Option<&str>) -> f64 {
+    category
+        .and_then(|c| weights.get(&c.trim().to_lowercase()))
+        .copied()
+        .unwrap_or(1.0)
+}
+
+#[derive(sqlx::FromRow)]
+struct NormalizeSettingsRow {
+    normalize_hours: Option<bool>,
+    daily_work_hours: Option<f64>,
+    normalize_weighting: Option<String>,
+    category_weights: Option<String>,
+}
+
+/// Load normalization settings for `user_id`, defaulting unset values
+pub async fn get_normalize_config(pool: &SqlitePool, user_id: &str) -> Result<NormalizeConfig, String> {
+    let row: Option<NormalizeSettingsRow> = sqlx::query_as(
+        "SELECT normalize_hours, daily_work_hours, normalize_weighting, category_weights FROM users WHERE id = ?",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let defaults = NormalizeConfig::default();
+    let Some(row) = row else {
+        return Ok(defaults);
+    };
+
+    Ok(NormalizeConfig {
+        enabled: row.normalize_hours.unwrap_or(defaults.enabled),
+        target: row.daily_work_hours.filter(|t| *t > 0.0).unwrap_or(defaults.target),
+        weighting: row
+            .normalize_weighting
+            .as_deref()
+            .map(NormalizeWeighting::parse)
+            .transpose()?
+            .unwrap_or_default(),
+        category_weights: row
+            .category_weights
+            .as_deref()
+            .map(parse_category_weights)
+            .transpose()?
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    fn items(hours: &[(f64, Option<&'static str>)]) -> Vec<NormalizeItem<'static>> {
+        hours
+            .iter()
+            .map(|&(hours, category)| NormalizeItem { hours, category })
+            .collect()
+    }
+
+    fn config(weighting: NormalizeWeighting) -> NormalizeConfig {
+        NormalizeConfig {
+            weighting,
+            ..NormalizeConfig::default()
+        }
+    }
+
+    fn approx(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_hours_weighting_gives_most_of_the_increase_to_substantial_work() {
+        let day = items(&[(3.0, None), (0.2, None)]);
+
+        let even = normalize_day_hours(&day, &config(NormalizeWeighting::Even));
+        approx(&even, &[5.4, 2.6]);
+
+        let weighted = normalize_day_hours(&day, &config(NormalizeWeighting::Hours));
+        approx(&weighted, &[7.5, 0.5]);
+        assert!(weighted[0] - 3.0 > 10.0 * (weighted[1] - 0.2));
+        assert!((weighted.iter().sum::<f64>() - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_category_weights_and_disabled() {
+        let day = items(&[(2.0, Some("feature")), (2.0, Some("chore"))]);
+        let mut cfg = config(NormalizeWeighting::Category);
+        cfg.category_weights = parse_category_weights("Feature=3, chore=1").unwrap();
+        approx(&normalize_day_hours(&day, &cfg), &[5.0, 3.0]);
+
+        // Over target is scaled down the same way, never below zero
+        let long_day = items(&[(9.0, None), (1.0, None)]);
+        approx(&normalize_day_hours(&long_day, &config(NormalizeWeighting::Hours)), &[7.2, 0.8]);
+        approx(&normalize_day_hours(&long_day, &config(NormalizeWeighting::Even)), &[8.0, 0.0]);
+
+        cfg.enabled = false;
+        approx(&normalize_day_hours(&day, &cfg), &[2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        assert_eq!(NormalizeWeighting::parse("Hours").unwrap(), NormalizeWeighting::Hours);
+        assert!(NormalizeWeighting::parse("random").is_err());
+        assert!(parse_category_weights("feature").is_err());
+        assert!(parse_category_weights("feature=-1").is_err());
+        assert!(parse_category_weights("").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_normalize_config_reads_user_settings() {
+        let (db, _dir) = db_with_user().await;
+
+        let cfg = get_normalize_config(&db.pool, "u1").await.unwrap();
+        assert_eq!(cfg.weighting, NormalizeWeighting::Even);
+        assert_eq!(cfg.target, 8.0);
+
+        sqlx::query(
+            "UPDATE users SET daily_work_hours = 6.0, normalize_weighting = 'category', category_weights = 'bugfix=2' WHERE id = 'u1'",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let cfg = get_normalize_config(&db.pool, "u1").await.unwrap();
+        assert_eq!(cfg.target, 6.0);
+        assert_eq!(cfg.weighting, NormalizeWeighting::Category);
+        assert_eq!(cfg.category_weights.get("bugfix"), Some(&2.0));
+    }
+}
@@ -6,9 +6,12 @@
 use anyhow::{anyhow, Result};
 use reqwest::{header, Client, Method};
 use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::time::Duration;
 
+use super::excel::{ProjectSummary, ReportMetadata};
+
 // ── Template Engine ──────────────────────────────────────────
 
 /// Available template fields and their descriptions
@@ -194,6 +197,27 @@ pub struct HttpExportConfig {
     pub timeout_seconds: u32,
 }
 
+impl HttpExportConfig {
+    /// POST endpoint with optional bearer token and default settings
+    pub fn bearer_post(url: &str, token: Option<String>) -> Self {
+        Self {
+            id: String::new(),
+            name: url.to_string(),
+            url: url.to_string(),
+            method: "POST".to_string(),
+            auth_type: if token.is_some() { "bearer" } else { "none" }.to_string(),
+            auth_token: token,
+            auth_header_name: None,
+            custom_headers: None,
+            payload_template: String::new(),
+            llm_prompt: None,
+            batch_mode: false,
+            batch_wrapper_key: String::new(),
+            timeout_seconds: 30,
+        }
+    }
+}
+
 /// Result of exporting a single item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportItemResult {
@@ -223,6 +247,18 @@ pub struct TestConnectionResult {
     pub message: String,
 }
 
+/// Result of pushing one JSON document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushResult {
+    pub success: bool,
+    pub http_status: Option<u16>,
+    pub attempts: u32,
+    pub message: String,
+    /// Parsed JSON body of a successful response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<serde_json::Value>,
+}
+
 /// HTTP export client
 pub struct HttpExportClient {
     config: HttpExportConfig,
@@ -508,6 +544,82 @@ impl HttpExportClient {
         }
     }
 
+    /// Send one JSON document to the configured endpoint.
+    ///
+    /// Connection errors, HTTP 429 and 5xx responses are retried up to
+    /// `max_attempts` times, doubling `initial_backoff` after each attempt.
+    /// Other 4xx responses fail immediately.
+    pub async fn push_json(
+        &self,
+        payload: &serde_json::Value,
+        max_attempts: u32,
+        initial_backoff: Duration,
+    ) -> PushResult {
+        let method = match self.config.method.to_uppercase().as_str() {
+            "PUT" => Method::PUT,
+            "PATCH" => Method::PATCH,
+            _ => Method::POST,
+        };
+
+        let max_attempts = max_attempts.max(1);
+        let mut backoff = initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let (result, retryable) = match self
+                .client
+                .request(method.clone(), &self.config.url)
+                .json(payload)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    let status = response.status();
+                    let full_body = response.text().await.unwrap_or_default();
+                    let body = full_body.chars().take(500).collect::<String>();
+                    let retryable = status.is_server_error() || status.as_u16() == 429;
+                    let result = PushResult {
+                        success: status.is_success(),
+                        http_status: Some(status.as_u16()),
+                        attempts: attempt,
+                        message: if body.is_empty() {
+                            format!("HTTP {}", status.as_u16())
+                        } else {
+                            format!("HTTP {}: {}", status.as_u16(), body)
+                        },
+                        response: status
+                            .is_success()
+                            .then(|| serde_json::from_str(&full_body).ok())
+                            .flatten(),
+                    };
+                    (result, retryable)
+                }
+                Err(e) => (
+                    PushResult {
+                        success: false,
+                        http_status: None,
+                        attempts: attempt,
+                        message: format!("Connection failed: {}", e),
+                        response: None,
+                    },
+                    true,
+                ),
+            };
+
+            if result.success || !retryable || attempt >= max_attempts {
+                return result;
+            }
+
+            log::warn!(
+                "Push to {} failed (attempt {}/{}): {}; retrying in {:?}",
+                self.config.url, attempt, max_attempts, result.message, backoff
+            );
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+
     /// Test connection by sending a small test payload
     pub async fn test_connection(&self) -> TestConnectionResult {
         let sample = serde_json::json!({"test": true, "source": "recap"});
@@ -557,9 +669,208 @@ impl HttpExportClient {
     }
 }
 
+// ── Notion Export ────────────────────────────────────────────
+
+pub const NOTION_API_BASE: &str = "https://api.notion.com/v1";
+pub const NOTION_VERSION: &str = "2022-06-28";
+
+/// Notion integration settings for report export.
+///
+/// The target database needs a title property `Name`, number properties
+/// `Hours` and `Items`, and a date property `Date`.
+#[derive(Debug, Clone)]
+pub struct NotionExportConfig {
+    pub token: String,
+    pub database_id: String,
+    pub api_base: String,
+    /// Delay before the first retry of a rate-limited (429) request
+    pub retry_backoff: Duration,
+}
+
+impl NotionExportConfig {
+    pub fn new(token: &str, database_id: &str) -> Self {
+        Self {
+            token: token.to_string(),
+            database_id: database_id.to_string(),
+            api_base: NOTION_API_BASE.to_string(),
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Load the user's Notion token and database id; `None` if either is unset
+    pub async fn load(pool: &SqlitePool, user_id: &str) -> Result<Option<Self>> {
+        let row: Option<(Option<String>, Option<String>)> =
+            sqlx::query_as("SELECT notion_token, notion_database_id FROM users WHERE id = ?")
+                .bind(user_id)
+                .fetch_optional(pool)
+                .await?;
+        Ok(match row {
+            Some((Some(token), Some(database_id)))
+                if !token.is_empty() && !database_id.is_empty() =>
+            {
+                Some(Self::new(&token, &database_id))
+            }
+            _ => None,
+        })
+    }
+
+    fn client(&self, method: &str, url: String) -> Result<HttpExportClient> {
+        let mut config = HttpExportConfig::bearer_post(&url, Some(self.token.clone()));
+        config.method = method.to_string();
+        config.custom_headers =
+            Some(serde_json::json!({ "Notion-Version": NOTION_VERSION }).to_string());
+        HttpExportClient::new(config)
+    }
+}
+
+/// Outcome for one project row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotionRowResult {
+    pub project_name: String,
+    pub status: String, // "created" | "updated" | "error"
+    pub page_id: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// Result of exporting a report to Notion
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotionExportResult {
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub rows: Vec<NotionRowResult>,
+}
+
+/// Map a project summary to Notion page properties
+pub fn notion_page_properties(
+    project: &ProjectSummary,
+    metadata: &ReportMetadata,
+) -> serde_json::Value {
+    serde_json::json!({
+        "Name": { "title": [{ "text": { "content": project.project_name } }] },
+        "Hours": { "number": (project.total_hours * 100.0).round() / 100.0 },
+        "Items": { "number": project.item_count },
+        "Date": { "date": { "start": metadata.start_date, "end": metadata.end_date } },
+    })
+}
+
+/// Push one page per project into the configured Notion database.
+///
+/// The page id of each created row is stored per (database, project, date
+/// range), so exporting the same report again updates those pages instead
+/// of adding duplicates. A stored page that no longer exists is recreated.
+pub async fn export_report_to_notion(
+    pool: &SqlitePool,
+    user_id: &str,
+    config: &NotionExportConfig,
+    metadata: &ReportMetadata,
+    projects: &[ProjectSummary],
+) -> Result<NotionExportResult> {
+    const MAX_ATTEMPTS: u32 = 3;
+    let mut result = NotionExportResult::default();
+
+    for project in projects {
+        let properties = notion_page_properties(project, metadata);
+        let stored: Option<String> = sqlx::query_scalar(
+            "SELECT page_id FROM notion_report_pages \
+             WHERE user_id = ? AND database_id = ? AND project_name = ? AND start_date = ? AND end_date = ?",
+        )
+        .bind(user_id)
+        .bind(&config.database_id)
+        .bind(&project.project_name)
+        .bind(&metadata.start_date)
+        .bind(&metadata.end_date)
+        .fetch_optional(pool)
+        .await?;
+
+        let mut push = None;
+        if let Some(page_id) = stored {
+            let client = config.client("PATCH", format!("{}/pages/{}", config.api_base, page_id))?;
+            let patched = client
+                .push_json(&serde_json::json!({ "properties": properties }), MAX_ATTEMPTS, config.retry_backoff)
+                .await;
+            if patched.success {
+                result.updated += 1;
+                result.rows.push(NotionRowResult {
+                    project_name: project.project_name.clone(),
+                    status: "updated".to_string(),
+                    page_id: Some(page_id),
+                    error_message: None,
+                });
+                continue;
+            }
+            if patched.http_status != Some(404) {
+                push = Some(patched);
+            }
+        }
+
+        let pushed = match push {
+            Some(failed) => failed,
+            None => {
+                let client = config.client("POST", format!("{}/pages", config.api_base))?;
+                let payload = serde_json::json!({
+                    "parent": { "database_id": config.database_id },
+                    "properties": properties,
+                });
+                client.push_json(&payload, MAX_ATTEMPTS, config.retry_backoff).await
+            }
+        };
+
+        let page_id = pushed
+            .response
+            .as_ref()
+            .and_then(|body| body.get("id"))
+            .and_then(|id| id.as_str())
+            .map(str::to_string);
+        match (pushed.success, page_id) {
+            (true, Some(page_id)) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO notion_report_pages (user_id, database_id, project_name, start_date, end_date, page_id)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(user_id, database_id, project_name, start_date, end_date)
+                    DO UPDATE SET page_id = excluded.page_id, updated_at = CURRENT_TIMESTAMP
+                    "#,
+                )
+                .bind(user_id)
+                .bind(&config.database_id)
+                .bind(&project.project_name)
+                .bind(&metadata.start_date)
+                .bind(&metadata.end_date)
+                .bind(&page_id)
+                .execute(pool)
+                .await?;
+                result.created += 1;
+                result.rows.push(NotionRowResult {
+                    project_name: project.project_name.clone(),
+                    status: "created".to_string(),
+                    page_id: Some(page_id),
+                    error_message: None,
+                });
+            }
+            (success, _) => {
+                result.failed += 1;
+                result.rows.push(NotionRowResult {
+                    project_name: project.project_name.clone(),
+                    status: "error".to_string(),
+                    page_id: None,
+                    error_message: Some(if success {
+                        "Notion response did not include a page id".to_string()
+                    } else {
+                        pushed.message
+                    }),
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::db_with_user;
 
     #[test]
     fn test_extract_placeholders() {
@@ -662,4 +973,160 @@ mod tests {
         assert_eq!(json_escape_string("a\"b"), "a\\\"b");
         assert_eq!(json_escape_string("a\nb"), "a\\nb");
     }
+
+    // ── Notion ──
+
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    /// Serve the scripted `(status, body)` responses in order and return the
+    /// request lines and bodies received.
+    fn notion_mock(
+        responses: Vec<(&'static str, String)>,
+    ) -> (String, std::thread::JoinHandle<Vec<(String, String)>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+                requests.push((
+                    request_line.trim().to_string(),
+                    String::from_utf8(request_body).unwrap(),
+                ));
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn notion_report() -> (ReportMetadata, Vec<ProjectSummary>) {
+        let metadata = ReportMetadata {
+            user_name: "Test".to_string(),
+            start_date: "2026-03-01".to_string(),
+            end_date: "2026-03-07".to_string(),
+            generated_at: "2026-03-08 09:00".to_string(),
+        };
+        let projects = vec![ProjectSummary {
+            project_name: "recap".to_string(),
+            total_hours: 12.5,
+            item_count: 4,
+        }];
+        (metadata, projects)
+    }
+
+    #[test]
+    fn test_notion_page_properties() {
+        let (metadata, projects) = notion_report();
+        let props = notion_page_properties(&projects[0], &metadata);
+        assert_eq!(props["Name"]["title"][0]["text"]["content"], "recap");
+        assert_eq!(props["Hours"]["number"], 12.5);
+        assert_eq!(props["Items"]["number"], 4);
+        assert_eq!(props["Date"]["date"]["start"], "2026-03-01");
+        assert_eq!(props["Date"]["date"]["end"], "2026-03-07");
+    }
+
+    #[tokio::test]
+    async fn test_notion_config_requires_token_and_database() {
+        let (db, _dir) = db_with_user().await;
+        assert!(NotionExportConfig::load(&db.pool, "u1").await.unwrap().is_none());
+
+        sqlx::query("UPDATE users SET notion_token = 'secret_x', notion_database_id = 'db1' WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let config = NotionExportConfig::load(&db.pool, "u1").await.unwrap().unwrap();
+        assert_eq!(config.database_id, "db1");
+        assert_eq!(config.api_base, NOTION_API_BASE);
+    }
+
+    #[tokio::test]
+    async fn test_notion_export_retries_rate_limit_and_updates_on_reexport() {
+        let (db, _dir) = db_with_user().await;
+        let (metadata, projects) = notion_report();
+        let (base_url, handle) = notion_mock(vec![
+            ("429 Too Many Requests", r#"{"code":"rate_limited"}"#.to_string()),
+            ("200 OK", r#"{"object":"page","id":"page-1"}"#.to_string()),
+            ("200 OK", r#"{"object":"page","id":"page-1"}"#.to_string()),
+        ]);
+        let mut config = NotionExportConfig::new("secret_x", "db1");
+        config.api_base = base_url;
+        config.retry_backoff = Duration::from_millis(10);
+
+        let first = export_report_to_notion(&db.pool, "u1", &config, &metadata, &projects)
+            .await
+            .unwrap();
+        assert_eq!((first.created, first.updated, first.failed), (1, 0, 0));
+        assert_eq!(first.rows[0].page_id.as_deref(), Some("page-1"));
+
+        let second = export_report_to_notion(&db.pool, "u1", &config, &metadata, &projects)
+            .await
+            .unwrap();
+        assert_eq!((second.created, second.updated, second.failed), (0, 1, 0));
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].0.starts_with("POST /pages "));
+        assert!(requests[1].0.starts_with("POST /pages "));
+        assert!(requests[2].0.starts_with("PATCH /pages/page-1 "));
+        let created: serde_json::Value = serde_json::from_str(&requests[1].1).unwrap();
+        assert_eq!(created["parent"]["database_id"], "db1");
+        let patched: serde_json::Value = serde_json::from_str(&requests[2].1).unwrap();
+        assert!(patched.get("parent").is_none());
+        assert_eq!(patched["properties"]["Hours"]["number"], 12.5);
+    }
+
+    #[tokio::test]
+    async fn test_notion_export_recreates_deleted_page() {
+        let (db, _dir) = db_with_user().await;
+        let (metadata, projects) = notion_report();
+        sqlx::query(
+            "INSERT INTO notion_report_pages (user_id, database_id, project_name, start_date, end_date, page_id) \
+             VALUES ('u1', 'db1', 'recap', '2026-03-01', '2026-03-07', 'gone')",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        let (base_url, handle) = notion_mock(vec![
+            ("404 Not Found", r#"{"code":"object_not_found"}"#.to_string()),
+            ("200 OK", r#"{"object":"page","id":"page-2"}"#.to_string()),
+        ]);
+        let mut config = NotionExportConfig::new("secret_x", "db1");
+        config.api_base = base_url;
+
+        let result = export_report_to_notion(&db.pool, "u1", &config, &metadata, &projects)
+            .await
+            .unwrap();
+        assert_eq!(result.created, 1);
+        let stored: String = sqlx::query_scalar("SELECT page_id FROM notion_report_pages WHERE project_name = 'recap'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(stored, "page-2");
+        assert_eq!(handle.join().unwrap().len(), 2);
+    }
 }
@@ -0,0 +1,179 @@
+//! iCalendar Export
+//!
+//! Serializes timeline sessions as an RFC 5545 calendar so they can be
+//! overlaid on a calendar app. Each event's UID is derived from its session
+//! ID, so importing a re-export updates the same events instead of
+//! duplicating them.
+
+use chrono::{DateTime, Utc};
+
+use crate::utils::parse_timestamp;
+
+/// Suffix that makes session IDs globally unique UIDs
+const UID_DOMAIN: &str = "recap";
+
+/// Longest content line in octets before it is folded (RFC 5545 §3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// One session on the calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    /// Session ID, stable across exports
+    pub id: String,
+    pub summary: String,
+    /// Project name
+    pub location: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl CalendarEvent {
+    /// Build an event from stored `start_time`/`end_time` strings.
+    ///
+    /// Timestamps without an offset are read in `timezone` (system timezone
+    /// if `None`). Returns `None` when either time can't be parsed.
+    pub fn from_times(
+        id: &str,
+        summary: &str,
+        location: Option<&str>,
+        start_time: &str,
+        end_time: &str,
+        timezone: Option<&str>,
+    ) -> Option<Self> {
+        Some(Self {
+            id: id.to_string(),
+            summary: summary.to_string(),
+            location: location.map(str::to_string),
+            start: parse_timestamp(start_time, timezone)?,
+            end: parse_timestamp(end_time, timezone)?,
+        })
+    }
+}
+
+/// Render `events` as a VCALENDAR with CRLF line endings.
+///
+/// `dtstamp` is written to every event's required DTSTAMP; pass the export
+/// time.
+pub fn build_ics(events: &[CalendarEvent], dtstamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Recap//Timeline//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@{}", escape_text(&event.id), UID_DOMAIN));
+        lines.push(format!("DTSTAMP:{}", format_utc(dtstamp)));
+        lines.push(format!("DTSTART:{}", format_utc(event.start)));
+        lines.push(format!("DTEND:{}", format_utc(event.end)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value: backslash, semicolon, comma and newlines
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Split a content line into CRLF + space continuations of at most
+/// [`MAX_LINE_OCTETS`] octets, never inside a UTF-8 character
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str, summary: &str) -> CalendarEvent {
+        CalendarEvent::from_times(
+            id,
+            summary,
+            Some("recap"),
+            "2026-03-02T09:15:00+08:00",
+            "2026-03-02T11:00:00+08:00",
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_ics_event_fields() {
+        let dtstamp = "2026-03-03T00:00:00Z".parse().unwrap();
+        let ics = build_ics(&[event("sess-1", "Fix login, again; really")], dtstamp);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(!ics.replace("\r\n", "").contains('\n'));
+        for line in [
+            "UID:sess-1@recap",
+            "DTSTAMP:20260303T000000Z",
+            "DTSTART:20260302T011500Z",
+            "DTEND:20260302T030000Z",
+            "SUMMARY:Fix login\\, again\\; really",
+            "LOCATION:recap",
+        ] {
+            assert!(ics.contains(&format!("\r\n{}\r\n", line)), "missing {}", line);
+        }
+    }
+
+    #[test]
+    fn test_uid_is_stable_across_exports() {
+        let first = build_ics(&[event("sess-1", "Old title")], Utc::now());
+        let second = build_ics(&[event("sess-1", "New title")], Utc::now());
+        let uid = |ics: &str| ics.lines().find(|l| l.starts_with("UID:")).unwrap().to_string();
+        assert_eq!(uid(&first), uid(&second));
+    }
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape_text("a\\b\nc\r\n"), "a\\\\b\\nc\\n");
+
+        let line = format!("SUMMARY:{}", "修正登入流程".repeat(10));
+        let folded = fold_line(&line);
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= MAX_LINE_OCTETS, "{} octets", part.len());
+        }
+        // Unfolding restores the original line
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn test_from_times_rejects_unparsable_time() {
+        assert!(CalendarEvent::from_times("s", "t", None, "yesterday", "2026-03-02T11:00:00Z", None).is_none());
+    }
+}
@@ -17,6 +17,13 @@ use sqlx::SqlitePool;
 use uuid::Uuid;
 
 use super::llm::LlmConfig;
+use super::llm_pricing::{estimate_cost, estimate_tokens};
+
+/// Completion token limit sent with each batch request
+pub const BATCH_MAX_COMPLETION_TOKENS: u32 = 500;
+
+/// Fraction of the regular price charged for Batch API requests
+pub const BATCH_PRICE_DISCOUNT: f64 = 0.5;
 
 // ============================================================================
 // Types
@@ -234,6 +241,18 @@ pub struct BatchSubmitResult {
     pub total_requests: usize,
 }
 
+/// Projected spend of a batch before it is submitted
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchCostEstimate {
+    pub total_requests: usize,
+    /// Estimated from the prompt text (see [`estimate_tokens`])
+    pub prompt_tokens: i64,
+    /// Upper bound: every request using [`BATCH_MAX_COMPLETION_TOKENS`]
+    pub completion_tokens: i64,
+    /// USD, with [`BATCH_PRICE_DISCOUNT`] applied
+    pub estimated_cost: f64,
+}
+
 /// Result of processing completed batch
 #[derive(Debug, Clone, Serialize)]
 pub struct BatchProcessResult {
@@ -269,6 +288,24 @@ impl LlmBatchService {
         self.config.provider == "openai" && self.config.api_key.is_some()
     }
 
+    /// Estimate what submitting `requests` as one batch would cost with the configured model
+    pub fn estimate_cost(&self, requests: &[HourlyCompactionRequest]) -> BatchCostEstimate {
+        let prompt_tokens: i64 = requests.iter().map(|r| estimate_tokens(&r.prompt)).sum();
+        let completion_tokens = requests.len() as i64 * BATCH_MAX_COMPLETION_TOKENS as i64;
+        let full_price = estimate_cost(
+            &self.config.provider,
+            &self.config.model,
+            Some(prompt_tokens),
+            Some(completion_tokens),
+        );
+        BatchCostEstimate {
+            total_requests: requests.len(),
+            prompt_tokens,
+            completion_tokens,
+            estimated_cost: full_price * BATCH_PRICE_DISCOUNT,
+        }
+    }
+
     /// Create a new batch job for hourly compaction
     pub async fn create_batch_job(
         &self,
@@ -360,14 +397,14 @@ impl LlmBatchService {
                 serde_json::to_value(BatchRequestBodyNewNoTemp {
                     model: self.config.model.clone(),
                     messages,
-                    max_completion_tokens: 500,
+                    max_completion_tokens: BATCH_MAX_COMPLETION_TOKENS,
                 }).map_err(|e| e.to_string())?
             } else if use_new_param {
                 // Models like gpt-4.1, gpt-4o use max_completion_tokens with temperature
                 serde_json::to_value(BatchRequestBodyNew {
                     model: self.config.model.clone(),
                     messages,
-                    max_completion_tokens: 500,
+                    max_completion_tokens: BATCH_MAX_COMPLETION_TOKENS,
                     temperature: 0.3,
                 }).map_err(|e| e.to_string())?
             } else {
@@ -375,7 +412,7 @@ impl LlmBatchService {
                 serde_json::to_value(BatchRequestBodyLegacy {
                     model: self.config.model.clone(),
                     messages,
-                    max_tokens: 500,
+                    max_tokens: BATCH_MAX_COMPLETION_TOKENS,
                     temperature: 0.3,
                 }).map_err(|e| e.to_string())?
             };
@@ -696,6 +733,36 @@ mod tests {
         assert_eq!(BatchJobStatus::from("failed"), BatchJobStatus::Failed);
     }
 
+    #[test]
+    fn test_estimate_cost_applies_batch_discount() {
+        let service = LlmBatchService::new(LlmConfig {
+            provider: "openai".to_string(),
+            model: "gpt-4o-mini".to_string(),
+            api_key: None,
+            base_url: None,
+            summary_max_chars: 2000,
+            reasoning_effort: None,
+            summary_prompt: None,
+            debug_log_path: None,
+        });
+        let request = HourlyCompactionRequest {
+            project_path: "/repo".to_string(),
+            hour_bucket: "2026-01-15T10:00:00".to_string(),
+            prompt: "abcd".repeat(250),
+            snapshot_ids: Vec::new(),
+            key_activities: String::new(),
+            git_summary: String::new(),
+            previous_context: None,
+        };
+
+        let estimate = service.estimate_cost(&[request.clone(), request]);
+        assert_eq!(estimate.total_requests, 2);
+        assert_eq!(estimate.prompt_tokens, 500);
+        assert_eq!(estimate.completion_tokens, 1000);
+        // (500 * 0.15 + 1000 * 0.60) / 1M at half price
+        assert!((estimate.estimated_cost - 0.0003375).abs() < 1e-10);
+    }
+
     #[test]
     fn test_batch_job_status_display() {
         assert_eq!(BatchJobStatus::Pending.to_string(), "pending");
@@ -0,0 +1,209 @@
+//! LLM Response Cache
+//!
+//! Content-addressed cache of LLM responses keyed by a hash of the provider,
+//! model and prompt. Compaction consults it before calling the LLM, so
+//! identical buckets (e.g. repeated boilerplate sessions) and recompaction of
+//! unchanged data don't pay for the same prompt twice.
+
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::future::Future;
+
+/// Default lifetime of a cached response (30 days)
+pub const DEFAULT_LLM_CACHE_TTL_HOURS: i64 = 720;
+
+/// Default maximum number of cached responses
+pub const DEFAULT_LLM_CACHE_MAX_ENTRIES: i64 = 1000;
+
+/// Cache settings from `llm_cache_ttl_hours` / `llm_cache_max_entries`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LlmCacheConfig {
+    /// Hours a response stays valid; 0 disables the cache
+    pub ttl_hours: i64,
+    /// Oldest entries beyond this count are evicted on insert
+    pub max_entries: i64,
+}
+
+impl Default for LlmCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_hours: DEFAULT_LLM_CACHE_TTL_HOURS,
+            max_entries: DEFAULT_LLM_CACHE_MAX_ENTRIES,
+        }
+    }
+}
+
+impl LlmCacheConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.ttl_hours > 0 && self.max_entries > 0
+    }
+}
+
+/// Load the user's cache settings, falling back to the defaults
+pub async fn get_llm_cache_config(pool: &SqlitePool, user_id: &str) -> LlmCacheConfig {
+    let row: Option<(Option<i64>, Option<i64>)> =
+        sqlx::query_as("SELECT llm_cache_ttl_hours, llm_cache_max_entries FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+
+    let defaults = LlmCacheConfig::default();
+    match row {
+        Some((ttl_hours, max_entries)) => LlmCacheConfig {
+            ttl_hours: ttl_hours.unwrap_or(defaults.ttl_hours),
+            max_entries: max_entries.unwrap_or(defaults.max_entries),
+        },
+        None => defaults,
+    }
+}
+
+/// Cache key for a prompt sent to `provider`/`model`
+pub fn prompt_cache_key(provider: &str, model: &str, prompt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(provider.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(model.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(prompt.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Cached response for `prompt_hash`, if one was stored within the TTL
+pub async fn get_cached_response(
+    pool: &SqlitePool,
+    prompt_hash: &str,
+    config: &LlmCacheConfig,
+) -> Result<Option<String>, String> {
+    let cutoff = Utc::now() - Duration::hours(config.ttl_hours);
+    sqlx::query_scalar("SELECT response FROM llm_response_cache WHERE prompt_hash = ? AND created_at >= ?")
+        .bind(prompt_hash)
+        .bind(cutoff)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Store a response and evict the oldest entries beyond `max_entries`
+pub async fn save_cached_response(
+    pool: &SqlitePool,
+    prompt_hash: &str,
+    response: &str,
+    config: &LlmCacheConfig,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"INSERT INTO llm_response_cache (prompt_hash, response, created_at)
+           VALUES (?, ?, ?)
+           ON CONFLICT(prompt_hash) DO UPDATE SET
+               response = excluded.response,
+               created_at = excluded.created_at"#,
+    )
+    .bind(prompt_hash)
+    .bind(response)
+    .bind(Utc::now())
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    sqlx::query(
+        r#"DELETE FROM llm_response_cache WHERE prompt_hash NOT IN (
+               SELECT prompt_hash FROM llm_response_cache ORDER BY created_at DESC, rowid DESC LIMIT ?
+           )"#,
+    )
+    .bind(config.max_entries)
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Return the cached response for `prompt_hash`, or run `complete` and cache it.
+///
+/// The flag is `true` when the response came from the cache. Failed
+/// completions are not cached. With the cache disabled `complete` always runs.
+pub async fn get_or_complete<F, Fut>(
+    pool: &SqlitePool,
+    prompt_hash: &str,
+    config: &LlmCacheConfig,
+    complete: F,
+) -> Result<(String, bool), String>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<String, String>>,
+{
+    if !config.is_enabled() {
+        return complete().await.map(|response| (response, false));
+    }
+
+    match get_cached_response(pool, prompt_hash, config).await {
+        Ok(Some(response)) => return Ok((response, true)),
+        Ok(None) => {}
+        Err(e) => log::warn!("LLM cache lookup failed: {}", e),
+    }
+
+    let response = complete().await?;
+    if let Err(e) = save_cached_response(pool, prompt_hash, &response, config).await {
+        log::warn!("Failed to cache LLM response: {}", e);
+    }
+    Ok((response, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_identical_prompts_call_llm_once() {
+        let (db, _dir) = db_with_user().await;
+        let config = LlmCacheConfig::default();
+        let calls = AtomicUsize::new(0);
+        let complete = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, String>("- 修正 `sync.rs`".to_string())
+        };
+
+        let key = prompt_cache_key("openai", "gpt-5-nano", "same prompt");
+        let first = get_or_complete(&db.pool, &key, &config, complete).await.unwrap();
+        let second = get_or_complete(&db.pool, &key, &config, complete).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first, ("- 修正 `sync.rs`".to_string(), false));
+        assert_eq!(second, ("- 修正 `sync.rs`".to_string(), true));
+
+        // A different model is a different prompt
+        let other = prompt_cache_key("openai", "gpt-5", "same prompt");
+        get_or_complete(&db.pool, &other, &config, complete).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_and_size_limit() {
+        let (db, _dir) = db_with_user().await;
+        let calls = AtomicUsize::new(0);
+        let complete = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, String>("summary".to_string())
+        };
+
+        let disabled = LlmCacheConfig { ttl_hours: 0, ..Default::default() };
+        get_or_complete(&db.pool, "k", &disabled, complete).await.unwrap();
+        get_or_complete(&db.pool, "k", &disabled, complete).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let small = LlmCacheConfig { ttl_hours: 1, max_entries: 2 };
+        for key in ["a", "b", "c"] {
+            save_cached_response(&db.pool, key, key, &small).await.unwrap();
+        }
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM llm_response_cache")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(get_cached_response(&db.pool, "c", &small).await.unwrap().as_deref(), Some("c"));
+    }
+}
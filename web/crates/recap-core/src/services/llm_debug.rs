@@ -0,0 +1,164 @@
+//! LLM Debug Log Module
+//!
+//! Opt-in JSONL log of the prompts sent to and responses received from the
+//! LLM, for debugging compaction and summary quality. Enabled per user with
+//! the `llm_debug_log` setting.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::llm::LlmUsageRecord;
+
+/// Maximum characters kept from each prompt or response
+pub const LLM_DEBUG_MAX_CHARS: usize = 8000;
+
+/// File name of the debug log inside the logs directory
+const LLM_DEBUG_LOG_FILE: &str = "llm-debug.jsonl";
+
+/// Prefixes of well-known API tokens that are masked before writing
+const SECRET_PREFIXES: &[&str] = &["sk-", "ghp_", "gho_", "glpat-", "xoxb-", "xoxp-", "AKIA"];
+
+/// One logged LLM call
+#[derive(Debug, Serialize)]
+struct LlmDebugEntry<'a> {
+    timestamp: String,
+    purpose: &'a str,
+    provider: &'a str,
+    model: &'a str,
+    prompt_tokens: Option<i64>,
+    completion_tokens: Option<i64>,
+    total_tokens: Option<i64>,
+    duration_ms: i64,
+    status: &'a str,
+    prompt_chars: usize,
+    prompt: String,
+    response: Option<String>,
+    error_message: Option<String>,
+}
+
+/// Default debug log location: `logs/llm-debug.jsonl` next to the database
+pub fn default_llm_debug_log_path() -> Option<PathBuf> {
+    let db_path = crate::db::get_db_path().ok()?;
+    let data_dir = db_path.parent()?;
+    Some(data_dir.join("logs").join(LLM_DEBUG_LOG_FILE))
+}
+
+/// Append one call to the debug log at `path`.
+///
+/// The prompt and response are redacted with [`sanitize_for_log`]; `api_key`
+/// is masked wherever it appears. `response` is `None` for failed calls.
+pub fn write_llm_debug_entry(
+    path: &Path,
+    usage: &LlmUsageRecord,
+    prompt: &str,
+    response: Option<&str>,
+    api_key: Option<&str>,
+) -> Result<(), String> {
+    let entry = LlmDebugEntry {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        purpose: &usage.purpose,
+        provider: &usage.provider,
+        model: &usage.model,
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+        duration_ms: usage.duration_ms,
+        status: &usage.status,
+        prompt_chars: prompt.chars().count(),
+        prompt: sanitize_for_log(prompt, api_key),
+        response: response.map(|r| sanitize_for_log(r, api_key)),
+        error_message: usage.error_message.as_deref().map(|e| sanitize_for_log(e, api_key)),
+    };
+
+    let line = serde_json::to_string(&entry)
+        .map_err(|e| format!("Failed to serialize LLM debug entry: {}", e))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create LLM debug log directory: {}", e))?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open LLM debug log: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write LLM debug log: {}", e))
+}
+
+/// Mask secrets and email addresses, then truncate to [`LLM_DEBUG_MAX_CHARS`]
+pub fn sanitize_for_log(text: &str, api_key: Option<&str>) -> String {
+    let mut text = text.to_string();
+    if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+        text = text.replace(key, "[REDACTED]");
+    }
+
+    let redacted: Vec<String> = text
+        .split_inclusive(char::is_whitespace)
+        .map(redact_word)
+        .collect();
+    truncate_chars(&redacted.concat(), LLM_DEBUG_MAX_CHARS)
+}
+
+/// Redact a single whitespace-delimited word, keeping its trailing whitespace
+fn redact_word(word: &str) -> String {
+    let trimmed = word.trim_end();
+    let trailing = &word[trimmed.len()..];
+    let core = trimmed.trim_matches(|c: char| "\"'`()[]{}<>,;:.!?".contains(c));
+
+    let is_secret = SECRET_PREFIXES
+        .iter()
+        .any(|p| core.starts_with(p) && core.len() >= p.len() + 8);
+    let is_email = core
+        .split_once('@')
+        .map(|(user, domain)| !user.is_empty() && domain.contains('.') && !domain.starts_with('.'))
+        .unwrap_or(false);
+
+    if is_secret {
+        format!("{}{}", trimmed.replace(core, "[REDACTED]"), trailing)
+    } else if is_email {
+        format!("{}{}", trimmed.replace(core, "[EMAIL]"), trailing)
+    } else {
+        word.to_string()
+    }
+}
+
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((idx, _)) => format!(
+            "{}… [truncated {} chars]",
+            &text[..idx],
+            text[idx..].chars().count()
+        ),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_masks_api_key_and_tokens() {
+        let text = "key=my-secret-key token sk-abcdefghijklmnop and \"glpat-1234567890abc\".";
+        let sanitized = sanitize_for_log(text, Some("my-secret-key"));
+        assert_eq!(sanitized, "key=[REDACTED] token [REDACTED] and \"[REDACTED]\".");
+    }
+
+    #[test]
+    fn test_sanitize_masks_emails() {
+        let sanitized = sanitize_for_log("Co-authored-by: Me <me@example.com>\nnext", None);
+        assert_eq!(sanitized, "Co-authored-by: Me <[EMAIL]>\nnext");
+        assert_eq!(sanitize_for_log("@mention and user@localhost", None), "@mention and user@localhost");
+    }
+
+    #[test]
+    fn test_sanitize_truncates_long_text() {
+        let text = "測".repeat(LLM_DEBUG_MAX_CHARS + 5);
+        let sanitized = sanitize_for_log(&text, None);
+        assert!(sanitized.starts_with(&"測".repeat(LLM_DEBUG_MAX_CHARS)));
+        assert!(sanitized.ends_with("… [truncated 5 chars]"));
+    }
+}
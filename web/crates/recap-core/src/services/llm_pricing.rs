@@ -2,6 +2,17 @@
 //!
 //! Estimates cost for LLM API calls based on provider, model, and token counts.
 
+/// Rough token count of `text`, for estimating cost before a request is sent.
+///
+/// Non-ASCII characters (mostly CJK in our prompts) count as one token each
+/// and ASCII text as one token per four characters.
+pub fn estimate_tokens(text: &str) -> i64 {
+    let (ascii, other) = text
+        .chars()
+        .fold((0i64, 0i64), |(ascii, other), c| if c.is_ascii() { (ascii + 1, other) } else { (ascii, other + 1) });
+    other + (ascii + 3) / 4
+}
+
 /// Estimate the cost (in USD) given provider, model, and token counts.
 pub fn estimate_cost(
     provider: &str,
@@ -42,6 +53,13 @@ fn get_pricing(provider: &str, model: &str) -> (f64, f64) {
             m if m.contains("claude-3-haiku") => (0.25, 1.25),
             _ => (3.00, 15.00), // Default to sonnet pricing
         },
+        "gemini" => match model {
+            m if m.starts_with("gemini-2.5-pro") => (1.25, 10.00),
+            m if m.starts_with("gemini-2.5-flash-lite") => (0.10, 0.40),
+            m if m.starts_with("gemini-2.5-flash") => (0.30, 2.50),
+            m if m.starts_with("gemini-2.0-flash") => (0.10, 0.40),
+            _ => (0.30, 2.50), // Default to flash pricing
+        },
         "ollama" => (0.0, 0.0), // Local, no cost
         _ => (0.0, 0.0),
     }
@@ -65,12 +83,27 @@ mod tests {
         assert!((cost - 0.0105).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_estimate_cost_gemini_flash() {
+        let cost = estimate_cost("gemini", "gemini-2.5-flash", Some(1000), Some(500));
+        // 1000 * 0.30 / 1M + 500 * 2.50 / 1M = 0.0003 + 0.00125 = 0.00155
+        assert!((cost - 0.00155).abs() < 1e-10);
+    }
+
     #[test]
     fn test_estimate_cost_ollama_free() {
         let cost = estimate_cost("ollama", "llama3", Some(10000), Some(5000));
         assert_eq!(cost, 0.0);
     }
 
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        assert_eq!(estimate_tokens("整理摘要 done"), 6);
+    }
+
     #[test]
     fn test_estimate_cost_none_tokens() {
         let cost = estimate_cost("openai", "gpt-4o-mini", None, None);
@@ -0,0 +1,1374 @@
+//! LLM provider abstraction
+//!
+//! Each provider turns a prompt into text and token usage over its own wire
+//! format. [`create_llm_provider`] picks the implementation from the user's
+//! `llm_provider` setting:
+//!
+//! - `openai`, `openai-compatible`: Chat Completions, or the Responses API for GPT-5
+//! - `ollama`: Ollama's OpenAI-compatible endpoint, no API key
+//! - `anthropic`: Messages API
+//! - `gemini`: Gemini `generateContent`
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::llm::LlmConfig;
+
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const OLLAMA_BASE_URL: &str = "http://localhost:11434";
+const ANTHROPIC_BASE_URL: &str = "https://api.anthropic.com/v1";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+/// Token usage reported by a provider, normalized across APIs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub prompt_tokens: Option<i64>,
+    pub completion_tokens: Option<i64>,
+    pub total_tokens: Option<i64>,
+}
+
+impl TokenUsage {
+    /// Usage from reported counts; the total is derived when the API omits it
+    pub fn new(prompt_tokens: Option<i64>, completion_tokens: Option<i64>, total_tokens: Option<i64>) -> Self {
+        let total_tokens = total_tokens.or(match (prompt_tokens, completion_tokens) {
+            (Some(p), Some(c)) => Some(p + c),
+            _ => None,
+        });
+        Self {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens,
+        }
+    }
+}
+
+/// A completion API
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Provider id as stored in `llm_provider`
+    fn name(&self) -> &'static str;
+
+    /// Complete `prompt`, producing at most `max_tokens` output tokens
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String>;
+}
+
+/// Build the provider selected by `config.provider`
+pub fn create_llm_provider(config: &LlmConfig, client: reqwest::Client) -> Result<Box<dyn LlmProvider>, String> {
+    match config.provider.as_str() {
+        "openai" | "openai-compatible" => Ok(Box::new(OpenAiCompatibleProvider::openai(config, client)?)),
+        "ollama" => Ok(Box::new(OpenAiCompatibleProvider::ollama(config, client))),
+        "anthropic" => Ok(Box::new(AnthropicProvider::new(config, client)?)),
+        "gemini" => Ok(Box::new(GeminiProvider::new(config, client)?)),
+        _ => Err(format!("Unsupported LLM provider: {}", config.provider)),
+    }
+}
+
+/// Read the body of a failed response as `"<label> <status>: <body>"`
+async fn api_error(label: &str, response: reqwest::Response) -> String {
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    format!("{} {}: {}", label, status, text)
+}
+
+// ============ OpenAI-compatible ============
+
+/// OpenAI request for newer models (gpt-5-nano, o1, o3) that don't support temperature
+#[derive(Debug, Serialize)]
+struct OpenAIRequestNewNoTemp {
+    model: String,
+    messages: Vec<OpenAIMessageRequest>,
+    max_completion_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning_effort: Option<String>,
+}
+
+/// OpenAI request for newer models (gpt-4.1, gpt-4o) that use max_completion_tokens with temperature
+#[derive(Debug, Serialize)]
+struct OpenAIRequestNew {
+    model: String,
+    messages: Vec<OpenAIMessageRequest>,
+    max_completion_tokens: u32,
+    temperature: f32,
+}
+
+/// OpenAI request for legacy models (gpt-4-turbo, gpt-4, gpt-3.5) that use max_tokens
+#[derive(Debug, Serialize)]
+struct OpenAIRequestLegacy {
+    model: String,
+    messages: Vec<OpenAIMessageRequest>,
+    max_tokens: u32,
+    temperature: f32,
+}
+
+/// Check if a model should use the Responses API (GPT-5 series)
+fn uses_responses_api(model: &str) -> bool {
+    model.starts_with("gpt-5")
+}
+
+/// Check if a model uses the new max_completion_tokens parameter
+fn uses_max_completion_tokens(model: &str) -> bool {
+    model.starts_with("gpt-5") ||
+    model.starts_with("gpt-4.1") ||
+    model.starts_with("gpt-4o") ||
+    model.starts_with("o1") ||
+    model.starts_with("o3")
+}
+
+/// Check if a model doesn't support custom temperature (only default 1)
+fn no_temperature_support(model: &str) -> bool {
+    model.starts_with("gpt-5") ||  // All GPT-5 models (gpt-5, gpt-5-mini, gpt-5-nano)
+    model.starts_with("o1") ||
+    model.starts_with("o3")
+}
+
+// ============ Responses API types (for GPT-5 series) ============
+
+/// OpenAI Responses API request
+#[derive(Debug, Serialize)]
+struct ResponsesApiRequest {
+    model: String,
+    input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<ResponsesTextConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning: Option<ReasoningConfig>,
+}
+
+/// Reasoning configuration for Responses API
+#[derive(Debug, Serialize)]
+struct ReasoningConfig {
+    effort: String,
+}
+
+/// Text output configuration for Responses API
+#[derive(Debug, Serialize)]
+struct ResponsesTextConfig {
+    format: ResponsesTextFormat,
+}
+
+/// Text format specification
+#[derive(Debug, Serialize)]
+struct ResponsesTextFormat {
+    #[serde(rename = "type")]
+    format_type: String,
+}
+
+/// OpenAI Responses API response
+#[derive(Debug, Deserialize)]
+struct ResponsesApiResponse {
+    #[allow(dead_code)]
+    id: String,
+    #[allow(dead_code)]
+    status: String,
+    output: Vec<ResponsesOutputItem>,
+    usage: Option<ResponsesUsage>,
+}
+
+/// Output item in Responses API (can be message or reasoning)
+#[derive(Debug, Deserialize)]
+struct ResponsesOutputItem {
+    #[serde(rename = "type")]
+    item_type: String,
+    #[serde(default)]
+    content: Option<Vec<ResponsesContent>>,
+}
+
+/// Content block in Responses API output
+#[derive(Debug, Deserialize)]
+struct ResponsesContent {
+    #[serde(rename = "type")]
+    content_type: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// Usage info in Responses API
+#[derive(Debug, Deserialize)]
+struct ResponsesUsage {
+    input_tokens: Option<i64>,
+    output_tokens: Option<i64>,
+}
+
+/// OpenAI message for requests (only role and content)
+#[derive(Debug, Serialize)]
+struct OpenAIMessageRequest {
+    role: String,
+    content: String,
+}
+
+/// OpenAI message in responses (may include reasoning_content for o-series models)
+#[derive(Debug, Deserialize)]
+struct OpenAIMessage {
+    #[allow(dead_code)]
+    role: String,
+    #[serde(default)]
+    content: String,
+    /// For o-series models that use reasoning
+    #[serde(default)]
+    reasoning_content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIUsage {
+    prompt_tokens: Option<i64>,
+    completion_tokens: Option<i64>,
+    total_tokens: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIResponse {
+    choices: Vec<OpenAIChoice>,
+    usage: Option<OpenAIUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIChoice {
+    message: OpenAIMessage,
+}
+
+
+/// OpenAI Chat Completions and Responses API.
+///
+/// Also serves Ollama and self-hosted OpenAI-compatible servers; Ollama always
+/// gets a legacy `max_tokens` Chat Completions request without auth.
+pub struct OpenAiCompatibleProvider {
+    client: reqwest::Client,
+    name: &'static str,
+    model: String,
+    api_key: Option<String>,
+    base_url: String,
+    reasoning_effort: Option<String>,
+}
+
+impl OpenAiCompatibleProvider {
+    /// OpenAI or an OpenAI-compatible server at `config.base_url`
+    pub fn openai(config: &LlmConfig, client: reqwest::Client) -> Result<Self, String> {
+        let api_key = config.api_key.clone().ok_or("OpenAI API key not configured")?;
+        Ok(Self {
+            client,
+            name: if config.provider == "openai-compatible" { "openai-compatible" } else { "openai" },
+            model: config.model.clone(),
+            api_key: Some(api_key),
+            base_url: config.base_url.clone().unwrap_or_else(|| OPENAI_BASE_URL.to_string()),
+            reasoning_effort: config.reasoning_effort.clone(),
+        })
+    }
+
+    /// Local Ollama server
+    pub fn ollama(config: &LlmConfig, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            name: "ollama",
+            model: config.model.clone(),
+            api_key: None,
+            base_url: format!("{}/v1", config.base_url.as_deref().unwrap_or(OLLAMA_BASE_URL)),
+            reasoning_effort: None,
+        }
+    }
+
+    fn is_ollama(&self) -> bool {
+        self.name == "ollama"
+    }
+
+    /// Request body for Chat Completions, shaped by what the model accepts
+    fn chat_request(&self, prompt: &str, max_tokens: u32) -> Result<serde_json::Value, String> {
+        let messages = vec![OpenAIMessageRequest {
+            role: "user".to_string(),
+            content: prompt.to_string(),
+        }];
+
+        let request = if self.is_ollama() || !uses_max_completion_tokens(&self.model) {
+            // Legacy models and Ollama use max_tokens with temperature
+            serde_json::to_value(OpenAIRequestLegacy {
+                model: self.model.clone(),
+                messages,
+                max_tokens,
+                temperature: 0.3,
+            })
+        } else if no_temperature_support(&self.model) {
+            // Models like o1, o3 don't support custom temperature
+            serde_json::to_value(OpenAIRequestNewNoTemp {
+                model: self.model.clone(),
+                messages,
+                max_completion_tokens: max_tokens,
+                reasoning_effort: self.reasoning_effort.clone(),
+            })
+        } else {
+            // Models like gpt-4.1, gpt-4o use max_completion_tokens with temperature
+            serde_json::to_value(OpenAIRequestNew {
+                model: self.model.clone(),
+                messages,
+                max_completion_tokens: max_tokens,
+                temperature: 0.3,
+            })
+        };
+        request.map_err(|e| format!("Failed to build request: {}", e))
+    }
+
+    async fn complete_chat(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String> {
+        log::info!("{} request: model={}, max_tokens={}, no_temp={}, uses_mct={}",
+            self.name, self.model, max_tokens,
+            no_temperature_support(&self.model),
+            uses_max_completion_tokens(&self.model));
+
+        let mut request = self.client
+            .post(format!("{}/chat/completions", self.base_url))
+            .header("Content-Type", "application/json")
+            .json(&self.chat_request(prompt, max_tokens)?);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", format!("Bearer {}", api_key));
+        }
+
+        let response = request.send().await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let label = if self.is_ollama() { "Ollama error" } else { "API error" };
+            return Err(api_error(label, response).await);
+        }
+
+        // Get raw response text first for debugging
+        let response_text = response.text().await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        log::info!("{} raw response (first 2000 chars): {}", self.name, &response_text.chars().take(2000).collect::<String>());
+
+        let result: OpenAIResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse response: {}. Raw: {}", e, &response_text.chars().take(200).collect::<String>()))?;
+
+        let text = result.choices.first()
+            .map(|c| {
+                // Always prefer content (the actual answer).
+                // reasoning_content is the internal chain-of-thought for o-series models — never use it as output.
+                if !c.message.content.is_empty() {
+                    return c.message.content.clone();
+                }
+                // Fallback: if content is empty but reasoning exists (shouldn't normally happen)
+                if let Some(ref reasoning) = c.message.reasoning_content {
+                    if !reasoning.is_empty() {
+                        log::warn!("OpenAI response has empty content but non-empty reasoning_content, falling back to reasoning");
+                        return reasoning.clone();
+                    }
+                }
+                String::new()
+            })
+            .ok_or_else(|| format!("No response from LLM. Choices: {:?}", result.choices))?;
+
+        log::info!("{} extracted text length: {} chars, content_empty: {}, has_reasoning: {}, text_preview: '{}'",
+            self.name,
+            text.len(),
+            result.choices.first().map(|c| c.message.content.is_empty()).unwrap_or(true),
+            result.choices.first().and_then(|c| c.message.reasoning_content.as_ref()).is_some(),
+            &text.chars().take(200).collect::<String>()
+        );
+
+        let usage = result.usage
+            .map(|u| TokenUsage::new(u.prompt_tokens, u.completion_tokens, u.total_tokens))
+            .unwrap_or_default();
+
+        Ok((text, usage))
+    }
+
+    /// Use OpenAI Responses API for GPT-5 series models
+    async fn complete_responses_api(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String> {
+        // Build request with explicit text format to ensure message output
+        let reasoning = self.reasoning_effort.as_ref().map(|effort| ReasoningConfig {
+            effort: effort.clone(),
+        });
+
+        // For Responses API, max_output_tokens covers BOTH reasoning + text tokens.
+        // Add headroom so reasoning doesn't consume the entire budget.
+        let reasoning_headroom: u32 = if reasoning.is_some() { 2000 } else { 0 };
+        let effective_max_tokens = max_tokens + reasoning_headroom;
+
+        let request = ResponsesApiRequest {
+            model: self.model.clone(),
+            input: prompt.to_string(),
+            max_output_tokens: Some(effective_max_tokens),
+            text: Some(ResponsesTextConfig {
+                format: ResponsesTextFormat {
+                    format_type: "text".to_string(),
+                },
+            }),
+            reasoning,
+        };
+
+        log::info!("Using Responses API for model: {}", self.model);
+
+        let mut builder = self.client
+            .post(format!("{}/responses", self.base_url))
+            .header("Content-Type", "application/json")
+            .json(&request);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("Bearer {}", api_key));
+        }
+
+        let response = builder.send().await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Responses API error", response).await);
+        }
+
+        // Get raw response for debugging
+        let response_text = response.text().await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        log::info!("Responses API raw response (first 1000 chars): {}",
+            &response_text.chars().take(1000).collect::<String>());
+
+        let result: ResponsesApiResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse Responses API response: {}. Raw: {}",
+                e, &response_text.chars().take(500).collect::<String>()))?;
+
+        // Extract text from output items
+        let output_text = extract_responses_text(&result.output);
+
+        log::info!("Responses API extracted text length: {} chars, preview: '{}'",
+            output_text.len(),
+            &output_text.chars().take(200).collect::<String>()
+        );
+
+        // Check for empty or trivial responses (like "OK", "好的", etc.)
+        let trimmed = output_text.trim();
+        if trimmed.is_empty() {
+            log::warn!("Responses API returned empty text. Output items: {:?}", result.output);
+            return Err("Responses API returned no text content. The model may need more output tokens.".to_string());
+        }
+
+        // Treat very short responses (< 20 chars) as failures - model likely didn't understand the task
+        if trimmed.len() < 20 {
+            log::warn!("Responses API returned trivial response: '{}'. Treating as failure.", trimmed);
+            return Err(format!("Responses API returned trivial response: '{}'. The model may need clearer instructions.", trimmed));
+        }
+
+        let usage = result.usage
+            .map(|u| TokenUsage::new(u.input_tokens, u.output_tokens, None))
+            .unwrap_or_default();
+
+        Ok((output_text, usage))
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String> {
+        // Use Responses API for GPT-5 series models
+        if !self.is_ollama() && uses_responses_api(&self.model) {
+            return self.complete_responses_api(prompt, max_tokens).await;
+        }
+        self.complete_chat(prompt, max_tokens).await
+    }
+}
+
+/// Extract text content from a Responses API output array.
+/// Returns the concatenated text from all message items with output_text/text content.
+fn extract_responses_text(output: &[ResponsesOutputItem]) -> String {
+    let mut text = String::new();
+    for item in output {
+        if item.item_type == "message" {
+            if let Some(contents) = &item.content {
+                for content in contents {
+                    if content.content_type == "output_text" || content.content_type == "text" {
+                        if let Some(t) = &content.text {
+                            text.push_str(t);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    text
+}
+
+// ============ Anthropic ============
+
+#[derive(Debug, Serialize)]
+struct AnthropicRequest {
+    model: String,
+    max_tokens: u32,
+    messages: Vec<AnthropicMessage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AnthropicMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicUsage {
+    input_tokens: Option<i64>,
+    output_tokens: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+    usage: Option<AnthropicUsage>,
+}
+
+/// Content block; only `text` blocks carry the answer
+#[derive(Debug, Deserialize)]
+struct AnthropicContent {
+    #[serde(rename = "type")]
+    content_type: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// Anthropic Messages API
+pub struct AnthropicProvider {
+    client: reqwest::Client,
+    model: String,
+    api_key: String,
+    base_url: String,
+}
+
+impl AnthropicProvider {
+    pub fn new(config: &LlmConfig, client: reqwest::Client) -> Result<Self, String> {
+        let api_key = config.api_key.clone().ok_or("Anthropic API key not configured")?;
+        Ok(Self {
+            client,
+            model: config.model.clone(),
+            api_key,
+            base_url: config.base_url.clone().unwrap_or_else(|| ANTHROPIC_BASE_URL.to_string()),
+        })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    fn name(&self) -> &'static str {
+        "anthropic"
+    }
+
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String> {
+        let request = AnthropicRequest {
+            model: self.model.clone(),
+            max_tokens,
+            messages: vec![AnthropicMessage {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+        };
+
+        let response = self.client
+            .post(format!("{}/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("API error", response).await);
+        }
+
+        let result: AnthropicResponse = response.json().await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        let text: String = result.content.iter()
+            .filter(|c| c.content_type == "text")
+            .filter_map(|c| c.text.as_deref())
+            .collect();
+        if text.is_empty() {
+            return Err("No response from LLM".to_string());
+        }
+
+        let usage = result.usage
+            .map(|u| TokenUsage::new(u.input_tokens, u.output_tokens, None))
+            .unwrap_or_default();
+
+        Ok((text, usage))
+    }
+}
+
+// ============ Gemini ============
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiRequest {
+    contents: Vec<GeminiContent>,
+    generation_config: GeminiGenerationConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiContent {
+    #[serde(default)]
+    role: Option<String>,
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiPart {
+    #[serde(default)]
+    text: Option<String>,
+    /// Set on thinking-model reasoning parts, which are not part of the answer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    thought: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiGenerationConfig {
+    max_output_tokens: u32,
+    temperature: f32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiResponse {
+    #[serde(default)]
+    candidates: Vec<GeminiCandidate>,
+    usage_metadata: Option<GeminiUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiCandidate {
+    content: Option<GeminiContent>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiUsage {
+    prompt_token_count: Option<i64>,
+    candidates_token_count: Option<i64>,
+    total_token_count: Option<i64>,
+}
+
+/// Google Gemini `generateContent` API
+pub struct GeminiProvider {
+    client: reqwest::Client,
+    model: String,
+    api_key: String,
+    base_url: String,
+}
+
+impl GeminiProvider {
+    pub fn new(config: &LlmConfig, client: reqwest::Client) -> Result<Self, String> {
+        let api_key = config.api_key.clone().ok_or("Gemini API key not configured")?;
+        Ok(Self {
+            client,
+            model: config.model.clone(),
+            api_key,
+            base_url: config.base_url.clone().unwrap_or_else(|| GEMINI_BASE_URL.to_string()),
+        })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for GeminiProvider {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<(String, TokenUsage), String> {
+        let request = GeminiRequest {
+            contents: vec![GeminiContent {
+                role: Some("user".to_string()),
+                parts: vec![GeminiPart {
+                    text: Some(prompt.to_string()),
+                    thought: None,
+                }],
+            }],
+            generation_config: GeminiGenerationConfig {
+                max_output_tokens: max_tokens,
+                temperature: 0.3,
+            },
+        };
+
+        let response = self.client
+            .post(format!("{}/models/{}:generateContent", self.base_url, self.model))
+            .header("x-goog-api-key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Gemini error", response).await);
+        }
+
+        let result: GeminiResponse = response.json().await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        let candidate = result.candidates.first()
+            .ok_or_else(|| "No response from Gemini".to_string())?;
+        let text: String = candidate.content.iter()
+            .flat_map(|c| c.parts.iter())
+            .filter(|p| !p.thought.unwrap_or(false))
+            .filter_map(|p| p.text.as_deref())
+            .collect();
+        if text.is_empty() {
+            return Err(format!(
+                "No response from Gemini (finish reason: {})",
+                candidate.finish_reason.as_deref().unwrap_or("unknown")
+            ));
+        }
+
+        let usage = result.usage_metadata
+            .map(|u| TokenUsage::new(u.prompt_token_count, u.candidates_token_count, u.total_token_count))
+            .unwrap_or_default();
+
+        Ok((text, usage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Model detection tests ====================
+
+    #[test]
+    fn test_uses_responses_api_gpt5_models() {
+        assert!(uses_responses_api("gpt-5"));
+        assert!(uses_responses_api("gpt-5-mini"));
+        assert!(uses_responses_api("gpt-5-nano"));
+        assert!(uses_responses_api("gpt-5-turbo"));
+        assert!(uses_responses_api("gpt-5-0601"));
+    }
+
+    #[test]
+    fn test_uses_responses_api_non_gpt5_models() {
+        assert!(!uses_responses_api("gpt-4o"));
+        assert!(!uses_responses_api("gpt-4o-mini"));
+        assert!(!uses_responses_api("gpt-4.1"));
+        assert!(!uses_responses_api("gpt-4-turbo"));
+        assert!(!uses_responses_api("o1"));
+        assert!(!uses_responses_api("o3-mini"));
+        assert!(!uses_responses_api("claude-3-5-sonnet"));
+        assert!(!uses_responses_api("llama3"));
+    }
+
+    #[test]
+    fn test_uses_max_completion_tokens() {
+        // Should use max_completion_tokens
+        assert!(uses_max_completion_tokens("gpt-5"));
+        assert!(uses_max_completion_tokens("gpt-5-nano"));
+        assert!(uses_max_completion_tokens("gpt-4.1"));
+        assert!(uses_max_completion_tokens("gpt-4o"));
+        assert!(uses_max_completion_tokens("gpt-4o-mini"));
+        assert!(uses_max_completion_tokens("o1"));
+        assert!(uses_max_completion_tokens("o1-mini"));
+        assert!(uses_max_completion_tokens("o3"));
+        assert!(uses_max_completion_tokens("o3-mini"));
+
+        // Should NOT use max_completion_tokens (legacy models)
+        assert!(!uses_max_completion_tokens("gpt-4-turbo"));
+        assert!(!uses_max_completion_tokens("gpt-4"));
+        assert!(!uses_max_completion_tokens("gpt-3.5-turbo"));
+        assert!(!uses_max_completion_tokens("claude-3-5-sonnet"));
+    }
+
+    #[test]
+    fn test_no_temperature_support() {
+        // No temperature support
+        assert!(no_temperature_support("gpt-5"));
+        assert!(no_temperature_support("gpt-5-mini"));
+        assert!(no_temperature_support("gpt-5-nano"));
+        assert!(no_temperature_support("o1"));
+        assert!(no_temperature_support("o1-mini"));
+        assert!(no_temperature_support("o3"));
+        assert!(no_temperature_support("o3-mini"));
+
+        // Has temperature support
+        assert!(!no_temperature_support("gpt-4o"));
+        assert!(!no_temperature_support("gpt-4o-mini"));
+        assert!(!no_temperature_support("gpt-4.1"));
+        assert!(!no_temperature_support("gpt-4-turbo"));
+        assert!(!no_temperature_support("gpt-3.5-turbo"));
+    }
+
+    // ==================== Request serialization tests ====================
+
+    #[test]
+    fn test_responses_api_request_serialization() {
+        let request = ResponsesApiRequest {
+            model: "gpt-5-nano".to_string(),
+            input: "Hello world".to_string(),
+            max_output_tokens: Some(500),
+            text: Some(ResponsesTextConfig {
+                format: ResponsesTextFormat {
+                    format_type: "text".to_string(),
+                },
+            }),
+            reasoning: None,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["model"], "gpt-5-nano");
+        assert_eq!(json["input"], "Hello world");
+        assert_eq!(json["max_output_tokens"], 500);
+        assert_eq!(json["text"]["format"]["type"], "text");
+        // reasoning should be skipped when None
+        assert!(json.get("reasoning").is_none());
+    }
+
+    #[test]
+    fn test_responses_api_request_with_reasoning() {
+        let request = ResponsesApiRequest {
+            model: "gpt-5".to_string(),
+            input: "Summarize this".to_string(),
+            max_output_tokens: Some(1000),
+            text: None,
+            reasoning: Some(ReasoningConfig {
+                effort: "medium".to_string(),
+            }),
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["reasoning"]["effort"], "medium");
+        // text should be skipped when None
+        assert!(json.get("text").is_none());
+    }
+
+    #[test]
+    fn test_chat_completions_request_no_temp() {
+        let request = OpenAIRequestNewNoTemp {
+            model: "o1".to_string(),
+            messages: vec![OpenAIMessageRequest {
+                role: "user".to_string(),
+                content: "test".to_string(),
+            }],
+            max_completion_tokens: 500,
+            reasoning_effort: Some("high".to_string()),
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["model"], "o1");
+        assert_eq!(json["max_completion_tokens"], 500);
+        assert_eq!(json["reasoning_effort"], "high");
+        // Should NOT have temperature or max_tokens
+        assert!(json.get("temperature").is_none());
+        assert!(json.get("max_tokens").is_none());
+    }
+
+    #[test]
+    fn test_chat_completions_request_new() {
+        let request = OpenAIRequestNew {
+            model: "gpt-4o".to_string(),
+            messages: vec![OpenAIMessageRequest {
+                role: "user".to_string(),
+                content: "test".to_string(),
+            }],
+            max_completion_tokens: 500,
+            temperature: 0.3,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["model"], "gpt-4o");
+        assert_eq!(json["max_completion_tokens"], 500);
+        let temp = json["temperature"].as_f64().unwrap();
+        assert!((temp - 0.3).abs() < 0.001, "temperature should be ~0.3, got {}", temp);
+        // Should NOT have max_tokens
+        assert!(json.get("max_tokens").is_none());
+    }
+
+    #[test]
+    fn test_chat_completions_request_legacy() {
+        let request = OpenAIRequestLegacy {
+            model: "gpt-4-turbo".to_string(),
+            messages: vec![OpenAIMessageRequest {
+                role: "user".to_string(),
+                content: "test".to_string(),
+            }],
+            max_tokens: 500,
+            temperature: 0.3,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["model"], "gpt-4-turbo");
+        assert_eq!(json["max_tokens"], 500);
+        let temp = json["temperature"].as_f64().unwrap();
+        assert!((temp - 0.3).abs() < 0.001, "temperature should be ~0.3, got {}", temp);
+        // Should NOT have max_completion_tokens
+        assert!(json.get("max_completion_tokens").is_none());
+    }
+
+    // ==================== Response parsing tests ====================
+
+    #[test]
+    fn test_parse_responses_api_response() {
+        let json = r#"{
+            "id": "resp_abc",
+            "status": "completed",
+            "output": [
+                {
+                    "type": "message",
+                    "content": [
+                        {"type": "output_text", "text": "This is the summary output."}
+                    ]
+                }
+            ],
+            "usage": {"input_tokens": 100, "output_tokens": 50}
+        }"#;
+        let result: ResponsesApiResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(result.output.len(), 1);
+        assert_eq!(result.output[0].item_type, "message");
+        let usage = result.usage.unwrap();
+        assert_eq!(usage.input_tokens, Some(100));
+        assert_eq!(usage.output_tokens, Some(50));
+    }
+
+    #[test]
+    fn test_parse_responses_api_response_with_reasoning() {
+        let json = r#"{
+            "id": "resp_xyz",
+            "status": "completed",
+            "output": [
+                {
+                    "type": "reasoning",
+                    "content": [
+                        {"type": "reasoning_text", "text": "thinking..."}
+                    ]
+                },
+                {
+                    "type": "message",
+                    "content": [
+                        {"type": "output_text", "text": "Final answer here."}
+                    ]
+                }
+            ],
+            "usage": {"input_tokens": 200, "output_tokens": 100}
+        }"#;
+        let result: ResponsesApiResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(result.output.len(), 2);
+        assert_eq!(result.output[0].item_type, "reasoning");
+        assert_eq!(result.output[1].item_type, "message");
+    }
+
+    #[test]
+    fn test_parse_chat_completions_response() {
+        let json = r#"{
+            "choices": [
+                {"message": {"role": "assistant", "content": "Hello there!"}}
+            ],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15}
+        }"#;
+        let result: OpenAIResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(result.choices.len(), 1);
+        assert_eq!(result.choices[0].message.content, "Hello there!");
+        let usage = result.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, Some(10));
+        assert_eq!(usage.completion_tokens, Some(5));
+        assert_eq!(usage.total_tokens, Some(15));
+    }
+
+    #[test]
+    fn test_parse_chat_completions_response_with_reasoning() {
+        let json = r#"{
+            "choices": [
+                {"message": {"role": "assistant", "content": "Result", "reasoning_content": "thinking step..."}}
+            ],
+            "usage": null
+        }"#;
+        let result: OpenAIResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(result.choices[0].message.content, "Result");
+        assert_eq!(
+            result.choices[0].message.reasoning_content.as_deref(),
+            Some("thinking step...")
+        );
+    }
+
+    // ==================== extract_responses_text tests ====================
+
+    #[test]
+    fn test_extract_responses_text_single_message() {
+        let output = vec![ResponsesOutputItem {
+            item_type: "message".to_string(),
+            content: Some(vec![ResponsesContent {
+                content_type: "output_text".to_string(),
+                text: Some("Hello world".to_string()),
+            }]),
+        }];
+        assert_eq!(extract_responses_text(&output), "Hello world");
+    }
+
+    #[test]
+    fn test_extract_responses_text_skips_reasoning() {
+        let output = vec![
+            ResponsesOutputItem {
+                item_type: "reasoning".to_string(),
+                content: Some(vec![ResponsesContent {
+                    content_type: "reasoning_text".to_string(),
+                    text: Some("I should think about this...".to_string()),
+                }]),
+            },
+            ResponsesOutputItem {
+                item_type: "message".to_string(),
+                content: Some(vec![ResponsesContent {
+                    content_type: "output_text".to_string(),
+                    text: Some("The actual answer".to_string()),
+                }]),
+            },
+        ];
+        assert_eq!(extract_responses_text(&output), "The actual answer");
+    }
+
+    #[test]
+    fn test_extract_responses_text_handles_text_content_type() {
+        let output = vec![ResponsesOutputItem {
+            item_type: "message".to_string(),
+            content: Some(vec![ResponsesContent {
+                content_type: "text".to_string(),
+                text: Some("Text type content".to_string()),
+            }]),
+        }];
+        assert_eq!(extract_responses_text(&output), "Text type content");
+    }
+
+    #[test]
+    fn test_extract_responses_text_empty_output() {
+        let output: Vec<ResponsesOutputItem> = vec![];
+        assert_eq!(extract_responses_text(&output), "");
+    }
+
+    #[test]
+    fn test_extract_responses_text_no_text_field() {
+        let output = vec![ResponsesOutputItem {
+            item_type: "message".to_string(),
+            content: Some(vec![ResponsesContent {
+                content_type: "output_text".to_string(),
+                text: None,
+            }]),
+        }];
+        assert_eq!(extract_responses_text(&output), "");
+    }
+
+    #[test]
+    fn test_extract_responses_text_concatenates_multiple_content() {
+        let output = vec![ResponsesOutputItem {
+            item_type: "message".to_string(),
+            content: Some(vec![
+                ResponsesContent {
+                    content_type: "output_text".to_string(),
+                    text: Some("Part 1".to_string()),
+                },
+                ResponsesContent {
+                    content_type: "output_text".to_string(),
+                    text: Some(" Part 2".to_string()),
+                },
+            ]),
+        }];
+        assert_eq!(extract_responses_text(&output), "Part 1 Part 2");
+    }
+
+    #[test]
+    fn test_extract_responses_text_ignores_unknown_content_types() {
+        let output = vec![ResponsesOutputItem {
+            item_type: "message".to_string(),
+            content: Some(vec![
+                ResponsesContent {
+                    content_type: "image".to_string(),
+                    text: Some("should be ignored".to_string()),
+                },
+                ResponsesContent {
+                    content_type: "output_text".to_string(),
+                    text: Some("kept".to_string()),
+                },
+            ]),
+        }];
+        assert_eq!(extract_responses_text(&output), "kept");
+    }
+
+
+    // ==================== Responses API usage token calculation tests ====================
+
+    #[test]
+    fn test_responses_usage_total_tokens_calculation() {
+        let usage = ResponsesUsage {
+            input_tokens: Some(100),
+            output_tokens: Some(50),
+        };
+        let total = match (usage.input_tokens, usage.output_tokens) {
+            (Some(i), Some(o)) => Some(i + o),
+            _ => None,
+        };
+        assert_eq!(total, Some(150));
+    }
+
+    #[test]
+    fn test_responses_usage_partial_tokens() {
+        let usage = ResponsesUsage {
+            input_tokens: Some(100),
+            output_tokens: None,
+        };
+        let total = match (usage.input_tokens, usage.output_tokens) {
+            (Some(i), Some(o)) => Some(i + o),
+            _ => None,
+        };
+        assert_eq!(total, None);
+    }
+
+    // ==================== Request routing integration tests ====================
+
+    #[test]
+    fn test_model_routing_gpt5_uses_responses_api_and_no_temp() {
+        let model = "gpt-5-nano";
+        assert!(uses_responses_api(model), "gpt-5 should use Responses API");
+        assert!(no_temperature_support(model), "gpt-5 should not support temperature");
+        assert!(uses_max_completion_tokens(model), "gpt-5 should use max_completion_tokens");
+    }
+
+    #[test]
+    fn test_model_routing_gpt4o_uses_chat_completions_with_temp() {
+        let model = "gpt-4o";
+        assert!(!uses_responses_api(model), "gpt-4o should NOT use Responses API");
+        assert!(!no_temperature_support(model), "gpt-4o SHOULD support temperature");
+        assert!(uses_max_completion_tokens(model), "gpt-4o should use max_completion_tokens");
+    }
+
+    #[test]
+    fn test_model_routing_o1_uses_chat_completions_no_temp() {
+        let model = "o1";
+        assert!(!uses_responses_api(model), "o1 should NOT use Responses API");
+        assert!(no_temperature_support(model), "o1 should NOT support temperature");
+        assert!(uses_max_completion_tokens(model), "o1 should use max_completion_tokens");
+    }
+
+    #[test]
+    fn test_model_routing_legacy_gpt4_turbo() {
+        let model = "gpt-4-turbo";
+        assert!(!uses_responses_api(model));
+        assert!(!no_temperature_support(model));
+        assert!(!uses_max_completion_tokens(model));
+    }
+
+    // ==================== Provider HTTP tests ====================
+
+    /// Request captured by [`mock_server`]
+    struct CapturedRequest {
+        request_line: String,
+        headers: Vec<(String, String)>,
+        body: serde_json::Value,
+    }
+
+    impl CapturedRequest {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// Serve one HTTP request on a local port with `status` and `body`.
+    ///
+    /// Returns the base URL and a handle yielding the captured request.
+    fn mock_server(status: &'static str, body: &'static str) -> (String, std::thread::JoinHandle<CapturedRequest>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                match line.trim().split_once(':') {
+                    Some((name, value)) => headers.push((name.to_string(), value.trim().to_string())),
+                    None => break,
+                }
+            }
+            let length: usize = headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, v)| v.parse().ok())
+                .unwrap_or(0);
+            let mut raw_body = vec![0u8; length];
+            reader.read_exact(&mut raw_body).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            CapturedRequest {
+                request_line: request_line.trim().to_string(),
+                headers,
+                body: serde_json::from_slice(&raw_body).unwrap_or(serde_json::Value::Null),
+            }
+        });
+        (base_url, handle)
+    }
+
+    fn config(provider: &str, model: &str, api_key: Option<&str>, base_url: Option<String>) -> LlmConfig {
+        LlmConfig {
+            provider: provider.to_string(),
+            model: model.to_string(),
+            api_key: api_key.map(str::to_string),
+            base_url,
+            summary_max_chars: 2000,
+            reasoning_effort: None,
+            summary_prompt: None,
+            debug_log_path: None,
+        }
+    }
+
+    #[test]
+    fn test_token_usage_derives_missing_total() {
+        assert_eq!(TokenUsage::new(Some(10), Some(5), None).total_tokens, Some(15));
+        assert_eq!(TokenUsage::new(Some(10), Some(5), Some(20)).total_tokens, Some(20));
+        assert_eq!(TokenUsage::new(Some(10), None, None).total_tokens, None);
+    }
+
+    #[test]
+    fn test_create_llm_provider_dispatch() {
+        let client = reqwest::Client::new();
+        let name = |provider: &str, key: Option<&str>| {
+            create_llm_provider(&config(provider, "m", key, None), client.clone()).map(|p| p.name())
+        };
+        assert_eq!(name("openai", Some("k")), Ok("openai"));
+        assert_eq!(name("openai-compatible", Some("k")), Ok("openai-compatible"));
+        assert_eq!(name("ollama", None), Ok("ollama"));
+        assert_eq!(name("anthropic", Some("k")), Ok("anthropic"));
+        assert_eq!(name("gemini", Some("k")), Ok("gemini"));
+        assert_eq!(name("anthropic", None).unwrap_err(), "Anthropic API key not configured");
+        assert_eq!(name("gemini", None).unwrap_err(), "Gemini API key not configured");
+        assert!(name("mistral", Some("k")).unwrap_err().contains("Unsupported LLM provider"));
+    }
+
+    #[tokio::test]
+    async fn test_openai_provider_chat_completions() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            r#"{"choices": [{"message": {"role": "assistant", "content": "Refactored the parser"}}],
+                "usage": {"prompt_tokens": 12, "completion_tokens": 4, "total_tokens": 16}}"#,
+        );
+        let provider = OpenAiCompatibleProvider::openai(
+            &config("openai", "gpt-4o", Some("sk-test"), Some(base_url)),
+            reqwest::Client::new(),
+        )
+        .unwrap();
+
+        let (text, usage) = provider.complete("Summarize", 300).await.unwrap();
+        assert_eq!(text, "Refactored the parser");
+        assert_eq!(usage, TokenUsage::new(Some(12), Some(4), Some(16)));
+
+        let request = server.join().unwrap();
+        assert!(request.request_line.starts_with("POST /chat/completions "));
+        assert_eq!(request.header("authorization"), Some("Bearer sk-test"));
+        assert_eq!(request.body["model"], "gpt-4o");
+        assert_eq!(request.body["max_completion_tokens"], 300);
+        assert_eq!(request.body["messages"][0]["role"], "user");
+        assert_eq!(request.body["messages"][0]["content"], "Summarize");
+    }
+
+    #[tokio::test]
+    async fn test_ollama_provider_legacy_request_without_auth() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            r#"{"choices": [{"message": {"role": "assistant", "content": "done"}}],
+                "usage": {"prompt_tokens": 7, "completion_tokens": 3}}"#,
+        );
+        let provider = OpenAiCompatibleProvider::ollama(
+            &config("ollama", "llama3", None, Some(base_url)),
+            reqwest::Client::new(),
+        );
+
+        let (text, usage) = provider.complete("Summarize", 200).await.unwrap();
+        assert_eq!(text, "done");
+        assert_eq!(usage.total_tokens, Some(10));
+
+        let request = server.join().unwrap();
+        assert!(request.request_line.starts_with("POST /v1/chat/completions "));
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(request.body["max_tokens"], 200);
+        assert!(request.body.get("max_completion_tokens").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_provider_messages_api() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            r#"{"id": "msg_1", "type": "message", "role": "assistant",
+                "content": [
+                    {"type": "thinking", "thinking": "internal"},
+                    {"type": "text", "text": "Fixed the "},
+                    {"type": "text", "text": "login bug"}
+                ],
+                "usage": {"input_tokens": 20, "output_tokens": 6}}"#,
+        );
+        let provider = AnthropicProvider::new(
+            &config("anthropic", "claude-sonnet-4-20250514", Some("sk-ant-test"), Some(base_url)),
+            reqwest::Client::new(),
+        )
+        .unwrap();
+
+        let (text, usage) = provider.complete("Summarize", 500).await.unwrap();
+        assert_eq!(text, "Fixed the login bug");
+        assert_eq!(usage, TokenUsage::new(Some(20), Some(6), Some(26)));
+
+        let request = server.join().unwrap();
+        assert!(request.request_line.starts_with("POST /messages "));
+        assert_eq!(request.header("x-api-key"), Some("sk-ant-test"));
+        assert_eq!(request.header("anthropic-version"), Some(ANTHROPIC_VERSION));
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(request.body["model"], "claude-sonnet-4-20250514");
+        assert_eq!(request.body["max_tokens"], 500);
+        assert_eq!(request.body["messages"][0]["content"], "Summarize");
+    }
+
+    #[tokio::test]
+    async fn test_gemini_provider_generate_content() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            r#"{"candidates": [{"content": {"role": "model", "parts": [
+                    {"text": "planning...", "thought": true},
+                    {"text": "Added CSV export"}
+                ]}, "finishReason": "STOP"}],
+                "usageMetadata": {"promptTokenCount": 30, "candidatesTokenCount": 5, "totalTokenCount": 41}}"#,
+        );
+        let provider = GeminiProvider::new(
+            &config("gemini", "gemini-2.5-flash", Some("gm-test"), Some(base_url)),
+            reqwest::Client::new(),
+        )
+        .unwrap();
+
+        let (text, usage) = provider.complete("Summarize", 400).await.unwrap();
+        assert_eq!(text, "Added CSV export");
+        // Thinking tokens make the reported total larger than prompt + output
+        assert_eq!(usage, TokenUsage::new(Some(30), Some(5), Some(41)));
+
+        let request = server.join().unwrap();
+        assert!(request.request_line.starts_with("POST /models/gemini-2.5-flash:generateContent "));
+        assert_eq!(request.header("x-goog-api-key"), Some("gm-test"));
+        assert_eq!(request.body["contents"][0]["role"], "user");
+        assert_eq!(request.body["contents"][0]["parts"][0]["text"], "Summarize");
+        assert!(request.body["contents"][0]["parts"][0].get("thought").is_none());
+        assert_eq!(request.body["generationConfig"]["maxOutputTokens"], 400);
+    }
+
+    #[tokio::test]
+    async fn test_gemini_provider_reports_api_error() {
+        let (base_url, server) = mock_server("400 Bad Request", r#"{"error": {"message": "API key not valid"}}"#);
+        let provider = GeminiProvider::new(
+            &config("gemini", "gemini-2.5-flash", Some("bad"), Some(base_url)),
+            reqwest::Client::new(),
+        )
+        .unwrap();
+
+        let err = provider.complete("Summarize", 400).await.unwrap_err();
+        assert!(err.starts_with("Gemini error 400"), "{}", err);
+        assert!(err.contains("API key not valid"));
+        server.join().unwrap();
+    }
+}
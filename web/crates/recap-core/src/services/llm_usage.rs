@@ -2,10 +2,12 @@
 //!
 //! Provides functions to save and query LLM usage records.
 
-use serde::Serialize;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
+use crate::models::PaginatedResponse;
 use super::llm::LlmUsageRecord;
 use super::llm_pricing::estimate_cost;
 
@@ -226,35 +228,113 @@ pub struct LlmUsageLog {
     pub created_at: String,
 }
 
-/// Get paginated usage logs for a date range.
+/// Page size of [`get_usage_logs`] when none is given
+pub const DEFAULT_USAGE_LOGS_PER_PAGE: i64 = 50;
+
+/// Largest page [`get_usage_logs`] returns
+pub const MAX_USAGE_LOGS_PER_PAGE: i64 = 100;
+
+/// Filters and page for [`get_usage_logs`]; unset filters match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LlmUsageLogQuery {
+    /// First day (YYYY-MM-DD, inclusive)
+    pub start_date: Option<String>,
+    /// Last day (YYYY-MM-DD, inclusive)
+    pub end_date: Option<String>,
+    pub provider: Option<String>,
+    pub purpose: Option<String>,
+    /// 1-based page number
+    pub page: Option<i64>,
+    /// Capped at [`MAX_USAGE_LOGS_PER_PAGE`]
+    pub per_page: Option<i64>,
+}
+
+/// One page of usage logs
+#[derive(Debug, Serialize)]
+pub struct LlmUsageLogPage {
+    #[serde(flatten)]
+    pub logs: PaginatedResponse<LlmUsageLog>,
+    /// Estimated cost of every log matching the filters, not only this page
+    pub total_cost: f64,
+}
+
+type UsageLogRow = (
+    String, String, String, Option<i64>, Option<i64>, Option<i64>, Option<f64>, String, Option<i64>, String,
+    Option<String>, String,
+);
+
+/// Get a page of usage logs, newest first.
+///
+/// Dates compare against the raw `created_at` so the
+/// `idx_llm_usage_user_date` index covers the range.
 pub async fn get_usage_logs(
     pool: &SqlitePool,
     user_id: &str,
-    start_date: &str,
-    end_date: &str,
-    limit: i64,
-    offset: i64,
-) -> Result<Vec<LlmUsageLog>, String> {
-    let rows: Vec<(String, String, String, Option<i64>, Option<i64>, Option<i64>, Option<f64>, String, Option<i64>, String, Option<String>, String)> = sqlx::query_as(
+    query: &LlmUsageLogQuery,
+) -> Result<LlmUsageLogPage, String> {
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(DEFAULT_USAGE_LOGS_PER_PAGE)
+        .clamp(1, MAX_USAGE_LOGS_PER_PAGE);
+
+    let mut conditions = vec!["user_id = ?"];
+    let mut bindings = vec![user_id.to_string()];
+    if let Some(start) = &query.start_date {
+        conditions.push("created_at >= ?");
+        bindings.push(parse_log_date(start)?.to_string());
+    }
+    if let Some(end) = &query.end_date {
+        // Before the next day, so the whole end date is included
+        conditions.push("created_at < ?");
+        bindings.push((parse_log_date(end)? + Duration::days(1)).to_string());
+    }
+    if let Some(provider) = &query.provider {
+        conditions.push("provider = ?");
+        bindings.push(provider.clone());
+    }
+    if let Some(purpose) = &query.purpose {
+        conditions.push("purpose = ?");
+        bindings.push(purpose.clone());
+    }
+    let where_clause = conditions.join(" AND ");
+
+    let totals_sql = format!(
+        "SELECT COUNT(*), SUM(estimated_cost) FROM llm_usage_logs WHERE {}",
+        where_clause
+    );
+    let mut totals_query = sqlx::query_as::<_, (i64, Option<f64>)>(&totals_sql);
+    for value in &bindings {
+        totals_query = totals_query.bind(value);
+    }
+    let (total, total_cost) = totals_query
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("Failed to count usage logs: {}", e))?;
+
+    let logs_sql = format!(
         r#"SELECT
             id, provider, model, prompt_tokens, completion_tokens, total_tokens,
             estimated_cost, purpose, duration_ms, status, error_message,
             datetime(created_at) as created_at
            FROM llm_usage_logs
-           WHERE user_id = ? AND DATE(created_at) >= ? AND DATE(created_at) <= ?
+           WHERE {}
            ORDER BY created_at DESC
            LIMIT ? OFFSET ?"#,
-    )
-    .bind(user_id)
-    .bind(start_date)
-    .bind(end_date)
-    .bind(limit)
-    .bind(offset)
-    .fetch_all(pool)
-    .await
-    .map_err(|e| format!("Failed to get usage logs: {}", e))?;
+        where_clause
+    );
+    let mut logs_query = sqlx::query_as::<_, UsageLogRow>(&logs_sql);
+    for value in &bindings {
+        logs_query = logs_query.bind(value);
+    }
+    let rows = logs_query
+        .bind(per_page)
+        .bind((page - 1) * per_page)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to get usage logs: {}", e))?;
 
-    Ok(rows
+    let items = rows
         .into_iter()
         .map(|(id, provider, model, pt, ct, tt, cost, purpose, dur, status, err, created_at)| {
             LlmUsageLog {
@@ -272,5 +352,130 @@ pub async fn get_usage_logs(
                 created_at,
             }
         })
-        .collect())
+        .collect();
+
+    Ok(LlmUsageLogPage {
+        logs: PaginatedResponse {
+            items,
+            total,
+            page,
+            per_page,
+            pages: (total + per_page - 1) / per_page,
+        },
+        total_cost: total_cost.unwrap_or(0.0),
+    })
+}
+
+fn parse_log_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    fn record(provider: &str, model: &str, prompt: i64, completion: i64) -> LlmUsageRecord {
+        LlmUsageRecord {
+            provider: provider.to_string(),
+            model: model.to_string(),
+            prompt_tokens: Some(prompt),
+            completion_tokens: Some(completion),
+            total_tokens: Some(prompt + completion),
+            duration_ms: 100,
+            purpose: "project_summary".to_string(),
+            status: "success".to_string(),
+            error_message: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ollama_usage_is_logged_at_zero_cost() {
+        let (db, _dir) = db_with_user().await;
+        save_usage_log(&db.pool, "u1", &record("ollama", "llama3", 10_000, 5_000)).await.unwrap();
+        save_usage_log(&db.pool, "u1", &record("openai", "gpt-4o-mini", 1_000, 500)).await.unwrap();
+
+        let costs: Vec<(String, f64)> =
+            sqlx::query_as("SELECT provider, estimated_cost FROM llm_usage_logs ORDER BY provider")
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(costs[0], ("ollama".to_string(), 0.0));
+        assert!((costs[1].1 - 0.00045).abs() < 1e-10);
+
+        // Local calls count toward tokens but not toward cost
+        let stats = get_usage_stats(&db.pool, "u1", "2000-01-01", "2100-01-01").await.unwrap();
+        assert_eq!(stats.total_calls, 2);
+        assert_eq!(stats.total_tokens, 16_500);
+        assert!((stats.total_cost - 0.00045).abs() < 1e-10);
+    }
+
+    async fn insert_log(pool: &SqlitePool, id: &str, provider: &str, purpose: &str, cost: f64, created_at: &str) {
+        sqlx::query(
+            r#"INSERT INTO llm_usage_logs (id, user_id, provider, model, estimated_cost, purpose, created_at)
+               VALUES (?, 'u1', ?, 'model', ?, ?, ?)"#,
+        )
+        .bind(id)
+        .bind(provider)
+        .bind(cost)
+        .bind(purpose)
+        .bind(created_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn ids(page: &LlmUsageLogPage) -> Vec<&str> {
+        page.logs.items.iter().map(|l| l.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_usage_logs_filter_and_paginate() {
+        let (db, _dir) = db_with_user().await;
+        insert_log(&db.pool, "a", "openai", "project_summary", 0.10, "2026-03-01 09:00:00").await;
+        insert_log(&db.pool, "b", "openai", "session_summary", 0.20, "2026-03-02 09:00:00").await;
+        insert_log(&db.pool, "c", "anthropic", "project_summary", 0.40, "2026-03-02 23:59:59").await;
+        insert_log(&db.pool, "d", "openai", "project_summary", 0.80, "2026-03-03 00:00:00").await;
+
+        // The end date is inclusive through the end of the day
+        let query = LlmUsageLogQuery {
+            start_date: Some("2026-03-02".to_string()),
+            end_date: Some("2026-03-02".to_string()),
+            ..Default::default()
+        };
+        let page = get_usage_logs(&db.pool, "u1", &query).await.unwrap();
+        assert_eq!(ids(&page), vec!["c", "b"]);
+        assert!((page.total_cost - 0.60).abs() < 1e-10);
+
+        let query = LlmUsageLogQuery {
+            provider: Some("openai".to_string()),
+            purpose: Some("project_summary".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&get_usage_logs(&db.pool, "u1", &query).await.unwrap()), vec!["d", "a"]);
+
+        // The cost covers every match, not only the page
+        let query = LlmUsageLogQuery {
+            page: Some(2),
+            per_page: Some(3),
+            ..Default::default()
+        };
+        let page = get_usage_logs(&db.pool, "u1", &query).await.unwrap();
+        assert_eq!(ids(&page), vec!["a"]);
+        assert_eq!((page.logs.total, page.logs.pages), (4, 2));
+        assert!((page.total_cost - 1.50).abs() < 1e-10);
+
+        let query = LlmUsageLogQuery {
+            per_page: Some(10_000),
+            ..Default::default()
+        };
+        let page = get_usage_logs(&db.pool, "u1", &query).await.unwrap();
+        assert_eq!(page.logs.per_page, MAX_USAGE_LOGS_PER_PAGE);
+
+        let query = LlmUsageLogQuery {
+            start_date: Some("March".to_string()),
+            ..Default::default()
+        };
+        assert!(get_usage_logs(&db.pool, "u1", &query).await.is_err());
+    }
 }
@@ -1,62 +1,133 @@
 //! Services module
 
+pub mod claude_paths;
+pub mod commit_diff;
 pub mod compaction;
+pub mod db_backup;
 pub mod excel;
+pub mod gitlab;
+pub mod exclude_paths;
+pub mod hash_repair;
+pub mod hours_normalization;
+pub mod ics;
 pub mod http_export;
 pub mod llm;
 pub mod llm_batch;
+pub mod llm_cache;
+pub mod llm_debug;
+pub mod llm_provider;
 pub mod llm_pricing;
 pub mod llm_usage;
+pub mod pdf;
+pub mod period;
+pub mod project_dirs;
+pub mod project_issue_mappings;
+pub mod project_visibility;
 pub mod session_parser;
 pub mod snapshot;
 pub mod sources;
 pub mod sync;
 pub mod tempo;
+pub mod trash;
+pub mod work_item_search;
 pub mod worklog;
 
-pub use excel::{ExcelReportGenerator, ExcelWorkItem, ProjectSummary, ReportMetadata};
+pub use claude_paths::{
+    claude_home_or_default, claude_homes_or_default, default_claude_home, default_claude_projects_dir,
+    resolve_claude_home, resolve_claude_homes, resolve_claude_projects_dir, resolve_claude_projects_dirs,
+    CLAUDE_PATH_SEPARATOR,
+};
+pub use commit_diff::{
+    commit_diff_cache, get_commit_diff, CommitDiff, CommitDiffCache, CommitFileChange, CommitStats, DiffHunk,
+    FileDiff,
+};
+pub use db_backup::{
+    backup_database, default_backup_dir, get_db_backup_config, list_backups, prune_backups, restore_database,
+    run_scheduled_backup, verify_backup, DbBackupConfig, DEFAULT_DB_BACKUP_KEEP,
+};
+pub use exclude_paths::{get_exclude_paths, parse_exclude_paths, PathExcludeFilter, DEFAULT_EXCLUDE_PATHS};
+pub use excel::{ExcelReportGenerator, ExcelTemplate, ExcelTemplateColumn, ExcelWorkItem, ProjectSummary, ReportMetadata};
+pub use pdf::{find_report_font, PdfReportGenerator, REPORT_FONT_ENV};
+pub use gitlab::{get_gitlab_user, GitLabUser};
+pub use hash_repair::{apply_hash_repair, plan_hash_repair, HashCollision, HashRepairPlan, HashUpdate, HASH_COLLISION_TAG};
+pub use hours_normalization::{
+    get_normalize_config, normalize_day_hours, parse_category_weights, NormalizeConfig, NormalizeItem,
+    NormalizeWeighting,
+};
+pub use ics::{build_ics, CalendarEvent};
 pub use llm::create_llm_service;
+pub use period::{iter_periods, period_bounds, PeriodUnit};
+pub use project_dirs::{
+    project_dir_cache, scan_claude_project_dirs, ClaudeProjectDir, ProjectDirCache, ProjectDirFilter,
+};
+pub use project_issue_mappings::{
+    delete_project_issue_mapping, get_project_issue_key, is_valid_issue_key, list_project_issue_mappings,
+    save_project_issue_mapping, ProjectIssueMapping,
+};
+pub use project_visibility::set_projects_visibility;
 pub use sync::{
-    create_sync_service, resolve_git_root, sync_claude_projects, sync_discovered_projects,
-    ClaudeSyncResult, DiscoveredProject, SyncService,
+    create_sync_service, discover_user_projects, load_max_session_hours, load_user_timezone, resolve_claude_dir_project, resolve_git_root, same_project_path,
+    sync_claude_projects, sync_claude_projects_incremental, sync_claude_projects_with_progress, sync_discovered_projects, sync_discovered_projects_incremental,
+    sync_discovered_projects_with_progress, ClaudeSyncResult, DiscoveredProject, SessionCursor,
+    SyncProgressUpdate, SyncService, CLAUDE_SYNC_SOURCE,
 };
-pub use tempo::{JiraClient, TempoClient, WorklogUploader, WorklogEntry, JiraAuthType};
+pub use work_item_search::{fts_match_query, search_work_items, WORK_ITEMS_FTS_JOIN};
+pub use trash::{
+    get_trash_retention_days, list_trash, purge_trash, restore_work_item, run_trash_purge, trash_work_item,
+    TrashedWorkItem, DEFAULT_TRASH_RETENTION_DAYS,
+};
+pub use tempo::{JiraClient, JiraIssueLookup, TempoClient, WorklogUploader, WorklogEntry, JiraAuthType};
 pub use worklog::{
     CommitRecord, DailyWorklog, FileChange, HoursEstimate, SessionBrief,
     StandaloneSession, TimelineCommit, estimate_commit_hours, estimate_from_diff,
     get_commits_for_date, get_commits_in_time_range, get_git_user_email,
-    calculate_session_hours, build_rule_based_outcome,
+    calculate_session_hours, calculate_session_hours_in_timezone, calculate_session_hours_capped,
+    build_rule_based_outcome, filter_trivial_commits, DEFAULT_MAX_SESSION_HOURS,
+    substantive_line_count, get_user_commits_for_date, co_author_emails, round_hours,
+    classify_category, classify_uncategorized_items, CategoryAssignment,
+    EstimationStrategy, get_repo_estimation_strategy, set_repo_estimation_strategy,
 };
 pub use session_parser::{
     extract_cwd, generate_daily_hash, is_meaningful_message, extract_tool_detail,
-    parse_session_fast, parse_session_full,
-    SessionMetadata, ParsedSession, ToolUsage,
+    parse_session_fast, parse_session_full, extract_session_content, build_rule_based_session_summary,
+    find_session_file, parse_session_active_blocks, split_active_blocks,
+    SessionMetadata, ParsedSession, ToolCall, ToolUsage, ActiveBlock, IDLE_GAP_MINUTES,
+    TOOL_CALL_COMMAND_MAX_CHARS,
 };
 pub use snapshot::{
-    capture_snapshots_for_project, parse_session_into_hourly_buckets,
-    save_hourly_snapshots, CommitSnapshot, HourlyBucket, SnapshotCaptureResult,
-    ToolCallRecord,
+    capture_snapshots_for_project, parse_project_buckets, parse_session_into_hourly_buckets,
+    save_hourly_snapshots, validate_bucket_minutes, CommitSnapshot, HourlyBucket, SnapshotCaptureResult,
+    ToolCallRecord, HOURLY_BUCKET_MINUTES, SUPPORTED_BUCKET_MINUTES,
 };
 pub use compaction::{
-    compact_daily, compact_hourly, compact_period, run_compaction_cycle,
-    CompactionResult, ForceRecompactOptions, ForceRecompactResult,
+    compact_daily, compact_hourly, compact_period, reset_stale_compaction_progress, run_compaction_cycle,
+    CompactionResult, CompactionStatus, ForceRecompactOptions, ForceRecompactResult,
     // Batch mode
     collect_pending_hourly, prepare_hourly_batch_requests, save_batch_results_as_summaries,
-    submit_hourly_batch, process_completed_batch,
+    submit_hourly_batch, process_completed_batch, estimate_hourly_batch_cost,
     PendingHourlyCompaction, BatchCompactionSubmitResult, BatchCompactionProcessResult,
 };
 pub use llm::{LlmUsageRecord, parse_error_usage};
-pub use llm_pricing::estimate_cost;
+pub use llm_cache::{
+    get_llm_cache_config, get_or_complete, prompt_cache_key, LlmCacheConfig,
+    DEFAULT_LLM_CACHE_MAX_ENTRIES, DEFAULT_LLM_CACHE_TTL_HOURS,
+};
+pub use llm_debug::{default_llm_debug_log_path, sanitize_for_log, write_llm_debug_entry};
+pub use llm_provider::{create_llm_provider, LlmProvider, TokenUsage};
+pub use llm_pricing::{estimate_cost, estimate_tokens};
 pub use llm_usage::{
     save_usage_log, get_usage_stats, get_usage_by_day, get_usage_by_model, get_usage_logs,
-    LlmUsageStats, DailyUsage, ModelUsage, LlmUsageLog,
+    LlmUsageStats, DailyUsage, ModelUsage, LlmUsageLog, LlmUsageLogPage, LlmUsageLogQuery,
+    DEFAULT_USAGE_LOGS_PER_PAGE, MAX_USAGE_LOGS_PER_PAGE,
 };
 pub use llm_batch::{
     LlmBatchService, BatchJob, BatchRequest, BatchJobStatus, BatchSubmitResult, BatchProcessResult,
-    HourlyCompactionRequest,
+    BatchCostEstimate, HourlyCompactionRequest, BATCH_MAX_COMPLETION_TOKENS, BATCH_PRICE_DISCOUNT,
 };
 pub use sources::{
-    SyncSource, SourceProject, SourceSyncResult, WorkItemParams,
-    ClaudeSource, SyncConfig,
-    get_enabled_sources, upsert_work_item, UpsertResult,
+    SyncSource, SourceProject, SourceSyncResult, ProjectSyncResult, WorkItemParams, CO_AUTHORED_TAG,
+    ClaudeSource, GitHubSource, SyncConfig,
+    compute_content_hash, get_enabled_sources, register_source, upsert_work_item, UpsertResult,
+    get_github_config, sync_github_projects, GitHubClient, GitHubConfig, GitHubRepoInfo, GitHubSyncResult,
+    purge_git_repo_items, purge_github_project_items, purge_gitlab_project_items,
 };
@@ -0,0 +1,431 @@
+//! PDF export service
+//!
+//! Render the personal report as an A4 PDF: a title block, a per-project
+//! hours table that continues across pages, and a totals footer. Uses the
+//! same [`ReportMetadata`]/[`ProjectSummary`] data as the Excel export.
+//!
+//! The built-in PDF fonts only cover Latin text, so a TrueType font is
+//! embedded to render Chinese project names; see [`find_report_font`].
+//! Rendering fails when that font lacks glyphs for any of the report text,
+//! rather than producing a PDF with blank names.
+
+use anyhow::Result;
+use printpdf::{IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use super::excel::{ProjectSummary, ReportMetadata};
+
+/// Environment variable naming the font file to embed
+pub const REPORT_FONT_ENV: &str = "RECAP_PDF_FONT";
+
+/// TrueType fonts tried in order when no font is given. Collections (.ttc)
+/// and CFF-based .otf files can't be embedded, so only .ttf files are listed.
+#[cfg(target_os = "macos")]
+const FONT_CANDIDATES: &[&str] = &[
+    "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+    "/Library/Fonts/Arial Unicode.ttf",
+];
+#[cfg(target_os = "windows")]
+const FONT_CANDIDATES: &[&str] = &[
+    "C:\\Windows\\Fonts\\arialuni.ttf",
+    "C:\\Windows\\Fonts\\simhei.ttf",
+    "C:\\Windows\\Fonts\\kaiu.ttf",
+];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const FONT_CANDIDATES: &[&str] = &[
+    "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansTC-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansSC-Regular.ttf",
+    "/usr/share/fonts/truetype/unifont/unifont.ttf",
+    // Latin only: reports with CJK text fail with a hint to set RECAP_PDF_FONT
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+];
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+const ROW_HEIGHT: f32 = 7.0;
+/// Lowest baseline a table row may use; the page number sits below it
+const TABLE_BOTTOM: f32 = 25.0;
+/// Table header baseline on the first page, below the title block
+const FIRST_PAGE_TABLE_TOP: f32 = 235.0;
+/// Table header baseline on continuation pages
+const TABLE_TOP: f32 = PAGE_HEIGHT - MARGIN;
+
+const ITEMS_X: f32 = 135.0;
+const HOURS_X: f32 = 165.0;
+/// Project names longer than this (CJK characters count double) are cut
+const MAX_NAME_WIDTH: usize = 60;
+
+const TITLE_SIZE: f32 = 18.0;
+const TEXT_SIZE: f32 = 10.0;
+
+/// Find a font for PDF reports: `RECAP_PDF_FONT` if set, else the first
+/// installed system font from a platform list
+pub fn find_report_font() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(REPORT_FONT_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    FONT_CANDIDATES.iter().map(PathBuf::from).find(|p| p.is_file())
+}
+
+/// PDF report generator
+pub struct PdfReportGenerator {
+    font: Vec<u8>,
+}
+
+impl PdfReportGenerator {
+    /// Create a generator embedding the given TrueType font data
+    pub fn new(font: Vec<u8>) -> Self {
+        Self { font }
+    }
+
+    /// Create a generator embedding the font at `path`
+    pub fn from_font_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let font = std::fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read font {}: {}", path.display(), e))?;
+        Ok(Self::new(font))
+    }
+
+    /// Create a generator with the font from [`find_report_font`]
+    pub fn with_system_font() -> Result<Self> {
+        let path = find_report_font().ok_or_else(|| {
+            anyhow::anyhow!("No TrueType font found for the PDF. Set {} to a .ttf file", REPORT_FONT_ENV)
+        })?;
+        Self::from_font_file(path)
+    }
+
+    /// Render the report, busiest projects first
+    pub fn render(&self, metadata: &ReportMetadata, projects: &[ProjectSummary]) -> Result<Vec<u8>> {
+        let texts = [
+            metadata.user_name.as_str(),
+            metadata.start_date.as_str(),
+            metadata.end_date.as_str(),
+            metadata.generated_at.as_str(),
+        ];
+        let names = projects.iter().map(|p| p.project_name.as_str());
+        self.check_glyphs(texts.into_iter().chain(names))?;
+
+        let title = format!("Work Report {} ~ {}", metadata.start_date, metadata.end_date);
+        let (doc, first_page, first_layer) = PdfDocument::new(&title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Report");
+        let font = doc
+            .add_external_font(Cursor::new(&self.font))
+            .map_err(|e| anyhow::anyhow!("Failed to embed font: {}", e))?;
+
+        let mut projects = projects.to_vec();
+        projects.sort_by(|a, b| {
+            b.total_hours
+                .total_cmp(&a.total_hours)
+                .then_with(|| a.project_name.cmp(&b.project_name))
+        });
+
+        // The totals footer takes the row after the last project
+        let pages = paginate(
+            projects.len() + 1,
+            rows_below(FIRST_PAGE_TABLE_TOP),
+            rows_below(TABLE_TOP),
+        );
+        let page_count = pages.len();
+
+        for (page_idx, rows) in pages.into_iter().enumerate() {
+            let layer = if page_idx == 0 {
+                doc.get_page(first_page).get_layer(first_layer)
+            } else {
+                let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Report");
+                doc.get_page(page).get_layer(layer)
+            };
+
+            let mut y = if page_idx == 0 {
+                draw_title_block(&layer, &font, metadata);
+                FIRST_PAGE_TABLE_TOP
+            } else {
+                TABLE_TOP
+            };
+
+            draw_row(&layer, &font, y, "Project", "Items", "Hours");
+            draw_rule(&layer, y - 2.0);
+            for row in rows {
+                y -= ROW_HEIGHT;
+                match projects.get(row) {
+                    Some(project) => draw_row(
+                        &layer,
+                        &font,
+                        y,
+                        &truncate_to_width(&project.project_name, MAX_NAME_WIDTH),
+                        &project.item_count.to_string(),
+                        &format!("{:.1}", project.total_hours),
+                    ),
+                    None => {
+                        let total_hours: f64 = projects.iter().map(|p| p.total_hours).sum();
+                        let total_items: usize = projects.iter().map(|p| p.item_count).sum();
+                        draw_rule(&layer, y + ROW_HEIGHT - 2.0);
+                        draw_row(
+                            &layer,
+                            &font,
+                            y,
+                            "Total",
+                            &total_items.to_string(),
+                            &format!("{:.1}", total_hours),
+                        );
+                    }
+                }
+            }
+
+            layer.use_text(
+                format!("Page {} / {}", page_idx + 1, page_count),
+                TEXT_SIZE - 2.0,
+                Mm(HOURS_X),
+                Mm(MARGIN / 2.0),
+                &font,
+            );
+        }
+
+        doc.save_to_bytes()
+            .map_err(|e| anyhow::anyhow!("Failed to write PDF: {}", e))
+    }
+
+    /// Fail if the font has no glyph for a character in `texts`; PDF viewers
+    /// would otherwise show those characters as blanks
+    fn check_glyphs<'a>(&self, texts: impl Iterator<Item = &'a str>) -> Result<()> {
+        let face = ttf_parser::Face::parse(&self.font, 0)
+            .map_err(|e| anyhow::anyhow!("Failed to parse font: {}", e))?;
+        let mut missing: Vec<char> = texts
+            .flat_map(str::chars)
+            .filter(|c| !c.is_whitespace() && face.glyph_index(*c).is_none())
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        missing.sort_unstable();
+        missing.dedup();
+        let shown: String = missing.iter().take(10).collect();
+        anyhow::bail!(
+            "The PDF font has no glyphs for \"{}\". Set {} to a .ttf file covering them (e.g. Noto Sans TC)",
+            shown,
+            REPORT_FONT_ENV
+        )
+    }
+
+    /// Render the report to a file
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        metadata: &ReportMetadata,
+        projects: &[ProjectSummary],
+        path: P,
+    ) -> Result<()> {
+        std::fs::write(path, self.render(metadata, projects)?)?;
+        Ok(())
+    }
+}
+
+fn draw_title_block(layer: &PdfLayerReference, font: &IndirectFontRef, metadata: &ReportMetadata) {
+    let top = PAGE_HEIGHT - MARGIN;
+    layer.use_text("Work Report", TITLE_SIZE, Mm(MARGIN), Mm(top), font);
+    let lines = [
+        format!("Employee: {}", metadata.user_name),
+        format!("Period: {} ~ {}", metadata.start_date, metadata.end_date),
+        format!("Generated: {}", metadata.generated_at),
+    ];
+    for (i, line) in lines.iter().enumerate() {
+        layer.use_text(line, TEXT_SIZE, Mm(MARGIN), Mm(top - 12.0 - ROW_HEIGHT * i as f32), font);
+    }
+}
+
+fn draw_row(layer: &PdfLayerReference, font: &IndirectFontRef, y: f32, name: &str, items: &str, hours: &str) {
+    layer.use_text(name, TEXT_SIZE, Mm(MARGIN), Mm(y), font);
+    layer.use_text(items, TEXT_SIZE, Mm(ITEMS_X), Mm(y), font);
+    layer.use_text(hours, TEXT_SIZE, Mm(HOURS_X), Mm(y), font);
+}
+
+fn draw_rule(layer: &PdfLayerReference, y: f32) {
+    layer.add_line(Line {
+        points: vec![
+            (Point::new(Mm(MARGIN), Mm(y)), false),
+            (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+        ],
+        is_closed: false,
+    });
+}
+
+/// Table rows that fit under a header at `header_y`
+fn rows_below(header_y: f32) -> usize {
+    ((header_y - ROW_HEIGHT - TABLE_BOTTOM) / ROW_HEIGHT).floor() as usize + 1
+}
+
+/// Split `rows` rows into per-page index ranges; the first page holds
+/// `first_page` rows and every later page `per_page`
+fn paginate(rows: usize, first_page: usize, per_page: usize) -> Vec<std::ops::Range<usize>> {
+    let first = rows.min(first_page);
+    let mut pages = Vec::new();
+    pages.push(0..first);
+    let mut start = first;
+    while start < rows {
+        let end = (start + per_page).min(rows);
+        pages.push(start..end);
+        start = end;
+    }
+    pages
+}
+
+/// Cut `text` to `max_width` columns, counting wide (CJK) characters as two
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    let char_width = |c: char| if c as u32 >= 0x1100 { 2 } else { 1 };
+    if text.chars().map(char_width).sum::<usize>() <= max_width {
+        return text.to_string();
+    }
+    let mut width = 0;
+    let mut truncated = String::new();
+    for c in text.chars() {
+        if width + char_width(c) > max_width - 3 {
+            break;
+        }
+        width += char_width(c);
+        truncated.push(c);
+    }
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> ReportMetadata {
+        ReportMetadata {
+            user_name: "王小明".to_string(),
+            start_date: "2026-03-01".to_string(),
+            end_date: "2026-03-31".to_string(),
+            generated_at: "2026-03-31 18:00:00".to_string(),
+        }
+    }
+
+    fn projects(count: usize) -> Vec<ProjectSummary> {
+        (0..count)
+            .map(|i| ProjectSummary {
+                project_name: format!("專案 {}", i),
+                total_hours: 1.5,
+                item_count: 2,
+            })
+            .collect()
+    }
+
+    /// Build a minimal TrueType font (no outlines) mapping each of `chars`
+    /// to its own glyph, so rendering doesn't depend on installed fonts
+    fn test_font(chars: &str) -> Vec<u8> {
+        let mut chars: Vec<char> = chars.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        // Glyph 0 is .notdef
+        let num_glyphs = chars.len() as u16 + 1;
+
+        // cmap: a single Unicode format 12 subtable, one group per char
+        let mut cmap = Vec::new();
+        for v in [0u16, 1, 0, 4] {
+            cmap.extend(v.to_be_bytes());
+        }
+        cmap.extend(12u32.to_be_bytes());
+        cmap.extend([0, 12, 0, 0]);
+        cmap.extend((16 + 12 * chars.len() as u32).to_be_bytes());
+        cmap.extend(0u32.to_be_bytes());
+        cmap.extend((chars.len() as u32).to_be_bytes());
+        for (i, c) in chars.iter().enumerate() {
+            cmap.extend((*c as u32).to_be_bytes());
+            cmap.extend((*c as u32).to_be_bytes());
+            cmap.extend((i as u32 + 1).to_be_bytes());
+        }
+
+        let mut head = vec![0u8; 54];
+        head[0..4].copy_from_slice(&0x0001_0000u32.to_be_bytes());
+        head[12..16].copy_from_slice(&0x5F0F_3CF5u32.to_be_bytes());
+        head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+
+        let mut hhea = vec![0u8; 36];
+        hhea[0..4].copy_from_slice(&0x0001_0000u32.to_be_bytes());
+        hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&num_glyphs.to_be_bytes());
+
+        let hmtx: Vec<u8> = (0..num_glyphs).flat_map(|_| [0x01, 0xF4, 0, 0]).collect();
+
+        let mut maxp = 0x0000_5000u32.to_be_bytes().to_vec();
+        maxp.extend(num_glyphs.to_be_bytes());
+
+        // Table records must be sorted by tag
+        let tables: [(&[u8; 4], Vec<u8>); 5] =
+            [(b"cmap", cmap), (b"head", head), (b"hhea", hhea), (b"hmtx", hmtx), (b"maxp", maxp)];
+        let mut font = 0x0001_0000u32.to_be_bytes().to_vec();
+        for v in [tables.len() as u16, 64, 2, 16] {
+            font.extend(v.to_be_bytes());
+        }
+        let mut offset = 12 + 16 * tables.len();
+        for (tag, data) in &tables {
+            font.extend(*tag);
+            font.extend(0u32.to_be_bytes());
+            font.extend((offset as u32).to_be_bytes());
+            font.extend((data.len() as u32).to_be_bytes());
+            offset += data.len().div_ceil(4) * 4;
+        }
+        for (_, data) in &tables {
+            font.extend(data);
+            font.resize(font.len().div_ceil(4) * 4, 0);
+        }
+        font
+    }
+
+    /// Printable ASCII plus the CJK characters in [`metadata`] and [`projects`]
+    fn report_chars() -> String {
+        (' '..='~').chain("王小明專案".chars()).collect()
+    }
+
+    #[test]
+    fn test_paginate_spreads_rows_across_pages() {
+        assert_eq!(paginate(1, 30, 36), vec![0..1]);
+        assert_eq!(paginate(30, 30, 36), vec![0..30]);
+        assert_eq!(paginate(31, 30, 36), vec![0..30, 30..31]);
+        assert_eq!(paginate(100, 30, 36), vec![0..30, 30..66, 66..100]);
+    }
+
+    #[test]
+    fn test_truncate_counts_cjk_as_wide() {
+        assert_eq!(truncate_to_width("recap", 10), "recap");
+        assert_eq!(truncate_to_width("資料同步服務", 8), "資料...");
+        assert_eq!(truncate_to_width("abcdefghijk", 8), "abcde...");
+    }
+
+    #[test]
+    fn test_render_long_report() {
+        let generator = PdfReportGenerator::new(test_font(&report_chars()));
+        let pdf = generator.render(&metadata(), &projects(80)).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+
+        let expected = paginate(81, rows_below(FIRST_PAGE_TABLE_TOP), rows_below(TABLE_TOP)).len();
+        assert!(expected > 1);
+        let doc = printpdf::lopdf::Document::load_mem(&pdf).unwrap();
+        assert_eq!(doc.get_pages().len(), expected);
+    }
+
+    #[test]
+    fn test_render_rejects_font_without_cjk_glyphs() {
+        let latin_only: String = (' '..='~').collect();
+        let generator = PdfReportGenerator::new(test_font(&latin_only));
+        let err = generator.render(&metadata(), &projects(3)).unwrap_err().to_string();
+        assert!(err.contains('王') && err.contains('專'), "{}", err);
+        assert!(err.contains(REPORT_FONT_ENV));
+
+        let mut metadata = metadata();
+        metadata.user_name = "Alice".to_string();
+        let english = vec![ProjectSummary {
+            project_name: "recap".to_string(),
+            total_hours: 2.0,
+            item_count: 1,
+        }];
+        assert!(generator.render(&metadata, &english).is_ok());
+    }
+
+    #[test]
+    fn test_from_font_file_missing() {
+        assert!(PdfReportGenerator::from_font_file("/no/such/font.ttf").is_err());
+    }
+}
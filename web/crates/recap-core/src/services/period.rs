@@ -0,0 +1,125 @@
+//! Period Bucketing
+//!
+//! Calendar period boundaries shared by the dashboard and reports. Weeks
+//! follow the user's `week_start_day` setting (0=Sun, 1=Mon, ..., 6=Sat);
+//! months, quarters and halves follow the calendar.
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Length of a reporting period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodUnit {
+    Week,
+    Month,
+    Quarter,
+    /// January–June or July–December
+    Half,
+}
+
+impl PeriodUnit {
+    fn months(self) -> u32 {
+        match self {
+            PeriodUnit::Week => 0,
+            PeriodUnit::Month => 1,
+            PeriodUnit::Quarter => 3,
+            PeriodUnit::Half => 6,
+        }
+    }
+}
+
+/// First and last day of the `unit` containing `date`
+pub fn period_bounds(date: NaiveDate, unit: PeriodUnit, week_start_day: u32) -> (NaiveDate, NaiveDate) {
+    if unit == PeriodUnit::Week {
+        let offset = (date.weekday().num_days_from_sunday() + 7 - week_start_day % 7) % 7;
+        let start = date - Duration::days(offset as i64);
+        return (start, start + Duration::days(6));
+    }
+
+    let months = unit.months();
+    let start_month = (date.month0() / months) * months + 1;
+    let start = NaiveDate::from_ymd_opt(date.year(), start_month, 1).expect("valid period start");
+    let end = start
+        .checked_add_months(chrono::Months::new(months))
+        .expect("valid period end")
+        - Duration::days(1);
+    (start, end)
+}
+
+/// Consecutive periods from the one starting at `start` through the one
+/// containing `end`, oldest first.
+///
+/// `start` should be a period start from [`period_bounds`]; weeks step
+/// seven days from it, so they keep its weekday.
+pub fn iter_periods(start: NaiveDate, end: NaiveDate, unit: PeriodUnit) -> impl Iterator<Item = (NaiveDate, NaiveDate)> {
+    std::iter::successors(Some(start), move |prev| Some(period_end(*prev, unit) + Duration::days(1)))
+        .take_while(move |period_start| *period_start <= end)
+        .map(move |period_start| (period_start, period_end(period_start, unit)))
+}
+
+fn period_end(start: NaiveDate, unit: PeriodUnit) -> NaiveDate {
+    match unit {
+        PeriodUnit::Week => start + Duration::days(6),
+        _ => period_bounds(start, unit, 0).1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn bounds(date: &str, unit: PeriodUnit, week_start_day: u32) -> (String, String) {
+        let (start, end) = period_bounds(d(date), unit, week_start_day);
+        (start.to_string(), end.to_string())
+    }
+
+    fn pair(start: &str, end: &str) -> (String, String) {
+        (start.to_string(), end.to_string())
+    }
+
+    #[test]
+    fn test_week_bounds_follow_week_start_day() {
+        // 2026-03-04 is a Wednesday
+        assert_eq!(bounds("2026-03-04", PeriodUnit::Week, 1), pair("2026-03-02", "2026-03-08"));
+        assert_eq!(bounds("2026-03-04", PeriodUnit::Week, 0), pair("2026-03-01", "2026-03-07"));
+        assert_eq!(bounds("2026-03-04", PeriodUnit::Week, 6), pair("2026-02-28", "2026-03-06"));
+        // A Sunday starts its own week with Sunday-start but ends a Monday-start one
+        assert_eq!(bounds("2026-03-08", PeriodUnit::Week, 0), pair("2026-03-08", "2026-03-14"));
+        assert_eq!(bounds("2026-03-08", PeriodUnit::Week, 1), pair("2026-03-02", "2026-03-08"));
+    }
+
+    #[test]
+    fn test_month_quarter_and_half_edges() {
+        assert_eq!(bounds("2024-02-10", PeriodUnit::Month, 1), pair("2024-02-01", "2024-02-29"));
+        assert_eq!(bounds("2026-12-31", PeriodUnit::Month, 1), pair("2026-12-01", "2026-12-31"));
+        assert_eq!(bounds("2026-03-31", PeriodUnit::Quarter, 1), pair("2026-01-01", "2026-03-31"));
+        assert_eq!(bounds("2026-04-01", PeriodUnit::Quarter, 1), pair("2026-04-01", "2026-06-30"));
+        assert_eq!(bounds("2026-11-15", PeriodUnit::Quarter, 1), pair("2026-10-01", "2026-12-31"));
+        assert_eq!(bounds("2026-06-30", PeriodUnit::Half, 1), pair("2026-01-01", "2026-06-30"));
+        assert_eq!(bounds("2026-07-01", PeriodUnit::Half, 1), pair("2026-07-01", "2026-12-31"));
+    }
+
+    #[test]
+    fn test_iter_periods() {
+        let weeks: Vec<_> = iter_periods(d("2026-03-01"), d("2026-03-15"), PeriodUnit::Week).collect();
+        assert_eq!(
+            weeks,
+            vec![
+                (d("2026-03-01"), d("2026-03-07")),
+                (d("2026-03-08"), d("2026-03-14")),
+                (d("2026-03-15"), d("2026-03-21")),
+            ]
+        );
+
+        let quarters: Vec<_> = iter_periods(d("2025-10-01"), d("2026-02-01"), PeriodUnit::Quarter).collect();
+        assert_eq!(
+            quarters,
+            vec![(d("2025-10-01"), d("2025-12-31")), (d("2026-01-01"), d("2026-03-31"))]
+        );
+
+        assert_eq!(iter_periods(d("2026-03-01"), d("2026-02-01"), PeriodUnit::Month).count(), 0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Claude project directory scanning
+//!
+//! Enumerates `<claude_base>/projects/*` with session counts and the git repo
+//! path recorded in `sessions-index.json`. Scans are cached for a short TTL
+//! because the Projects page asks for directories on every navigation.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use glob::Pattern;
+
+/// How long a directory scan is reused
+pub const PROJECT_DIR_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// One directory under `<claude_base>/projects`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaudeProjectDir {
+    /// Directory name, e.g. `-Users-foo-recap`
+    pub name: String,
+    pub path: String,
+    pub session_count: i64,
+    /// First `projectPath` found in `sessions-index.json`
+    pub git_repo_path: Option<String>,
+}
+
+/// Scan every project directory under `projects_dir`
+pub fn scan_claude_project_dirs(projects_dir: &Path) -> Vec<ClaudeProjectDir> {
+    let entries = match fs::read_dir(projects_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dirs: Vec<ClaudeProjectDir> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|entry| {
+            let dir_path = entry.path();
+            let (session_count, git_repo_path) = read_session_info(&dir_path);
+            ClaudeProjectDir {
+                name: entry.file_name().to_string_lossy().to_string(),
+                path: dir_path.to_string_lossy().to_string(),
+                session_count,
+                git_repo_path,
+            }
+        })
+        .collect();
+    dirs.sort_by(|a, b| a.name.cmp(&b.name));
+    dirs
+}
+
+/// Session count and repo path from `sessions-index.json`, else count `.jsonl` files
+fn read_session_info(dir_path: &Path) -> (i64, Option<String>) {
+    let index_path = dir_path.join("sessions-index.json");
+    if index_path.exists() {
+        let entries = fs::read_to_string(&index_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|index| index.get("entries").and_then(|e| e.as_array()).cloned())
+            .unwrap_or_default();
+
+        let git_repo_path = entries
+            .iter()
+            .find_map(|sess| sess.get("projectPath").and_then(|v| v.as_str()))
+            .map(|p| p.to_string());
+        return (entries.len() as i64, git_repo_path);
+    }
+
+    let count = fs::read_dir(dir_path)
+        .map(|files| {
+            files
+                .flatten()
+                .filter(|f| f.path().extension().map(|e| e == "jsonl").unwrap_or(false))
+                .count() as i64
+        })
+        .unwrap_or(0);
+    (count, None)
+}
+
+/// Include/exclude glob filter on directory names
+#[derive(Debug, Clone, Default)]
+pub struct ProjectDirFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl ProjectDirFilter {
+    /// Build a filter; an empty include list matches everything
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self, String> {
+        let compile = |globs: &[String]| -> Result<Vec<Pattern>, String> {
+            globs
+                .iter()
+                .map(|g| Pattern::new(g).map_err(|e| format!("Invalid glob '{}': {}", g, e)))
+                .collect()
+        };
+        Ok(Self {
+            include: compile(include)?,
+            exclude: compile(exclude)?,
+        })
+    }
+
+    pub fn matches(&self, dir_name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches(dir_name)))
+            && !self.exclude.iter().any(|p| p.matches(dir_name))
+    }
+}
+
+/// A scan and when it was taken
+type CachedScan = (Instant, Arc<Vec<ClaudeProjectDir>>);
+
+/// TTL cache of directory scans, keyed by projects directory
+pub struct ProjectDirCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<PathBuf, CachedScan>>,
+}
+
+impl ProjectDirCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cached scan of `projects_dir`; `refresh` forces a new scan
+    pub fn get(&self, projects_dir: &Path, refresh: bool) -> Arc<Vec<ClaudeProjectDir>> {
+        self.get_or_scan(projects_dir, refresh, scan_claude_project_dirs)
+    }
+
+    /// Like [`get`](Self::get) with a custom scanner
+    pub fn get_or_scan<F>(&self, projects_dir: &Path, refresh: bool, scan: F) -> Arc<Vec<ClaudeProjectDir>>
+    where
+        F: FnOnce(&Path) -> Vec<ClaudeProjectDir>,
+    {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if !refresh {
+            if let Some((scanned_at, dirs)) = entries.get(projects_dir) {
+                if scanned_at.elapsed() < self.ttl {
+                    return Arc::clone(dirs);
+                }
+            }
+        }
+
+        let dirs = Arc::new(scan(projects_dir));
+        entries.insert(projects_dir.to_path_buf(), (Instant::now(), Arc::clone(&dirs)));
+        dirs
+    }
+}
+
+/// Process-wide cache used by the Projects page
+pub fn project_dir_cache() -> &'static ProjectDirCache {
+    static CACHE: OnceLock<ProjectDirCache> = OnceLock::new();
+    CACHE.get_or_init(|| ProjectDirCache::new(PROJECT_DIR_CACHE_TTL))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    fn create_projects_dir() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for name in ["-work-recap", "-work-recap-web", "-tmp-scratch", "-work-recap-node_modules"] {
+            let project = dir.path().join(name);
+            fs::create_dir(&project).unwrap();
+            fs::write(project.join("a.jsonl"), "{}").unwrap();
+        }
+        fs::write(
+            dir.path().join("-work-recap").join("sessions-index.json"),
+            r#"{"entries": [{"projectPath": "/work/recap"}, {}]}"#,
+        )
+        .unwrap();
+        fs::write(dir.path().join("not-a-dir.txt"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_scan_reads_session_info() {
+        let dir = create_projects_dir();
+        let dirs = scan_claude_project_dirs(dir.path());
+
+        let names: Vec<&str> = dirs.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["-tmp-scratch", "-work-recap", "-work-recap-node_modules", "-work-recap-web"]);
+        assert_eq!(dirs[1].session_count, 2);
+        assert_eq!(dirs[1].git_repo_path.as_deref(), Some("/work/recap"));
+        assert_eq!(dirs[3].session_count, 1);
+        assert_eq!(dirs[3].git_repo_path, None);
+    }
+
+    #[test]
+    fn test_cache_reuses_scan_within_ttl() {
+        let dir = create_projects_dir();
+        let cache = ProjectDirCache::new(Duration::from_secs(60));
+        let scans = Cell::new(0);
+        let counting_scan = |path: &Path| {
+            scans.set(scans.get() + 1);
+            scan_claude_project_dirs(path)
+        };
+
+        let first = cache.get_or_scan(dir.path(), false, counting_scan);
+        let second = cache.get_or_scan(dir.path(), false, counting_scan);
+        assert_eq!(scans.get(), 1);
+        assert_eq!(first, second);
+
+        cache.get_or_scan(dir.path(), true, counting_scan);
+        assert_eq!(scans.get(), 2);
+    }
+
+    #[test]
+    fn test_cache_rescans_after_ttl() {
+        let dir = create_projects_dir();
+        let cache = ProjectDirCache::new(Duration::ZERO);
+        let scans = Cell::new(0);
+        let counting_scan = |path: &Path| {
+            scans.set(scans.get() + 1);
+            scan_claude_project_dirs(path)
+        };
+
+        cache.get_or_scan(dir.path(), false, counting_scan);
+        cache.get_or_scan(dir.path(), false, counting_scan);
+        assert_eq!(scans.get(), 2);
+    }
+
+    #[test]
+    fn test_filter_exclude_globs_drop_matching_dirs() {
+        let dir = create_projects_dir();
+        let filter = ProjectDirFilter::new(
+            &["-work-*".to_string()],
+            &["*node_modules*".to_string()],
+        )
+        .unwrap();
+
+        let kept: Vec<String> = scan_claude_project_dirs(dir.path())
+            .into_iter()
+            .filter(|d| filter.matches(&d.name))
+            .map(|d| d.name)
+            .collect();
+        assert_eq!(kept, vec!["-work-recap", "-work-recap-web"]);
+
+        assert!(ProjectDirFilter::default().matches("-tmp-scratch"));
+        assert!(ProjectDirFilter::new(&[], &["[".to_string()]).is_err());
+    }
+}
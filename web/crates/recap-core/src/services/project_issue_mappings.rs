@@ -0,0 +1,167 @@
+//! Project Issue Mappings
+//!
+//! Default Jira issue per project path, stored in `project_issue_mappings`.
+//! Tempo sync pre-fills a project's worklogs with its mapped issue.
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectIssueMapping {
+    pub project_path: String,
+    pub user_id: String,
+    pub jira_issue_key: String,
+    pub updated_at: String,
+}
+
+/// Strip trailing path separators so `/repo/` and `/repo` map to the same row
+pub fn normalize_project_path(path: &str) -> String {
+    let trimmed = path.trim().trim_end_matches(['/', '\\']);
+    if trimmed.is_empty() {
+        path.trim().to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Whether `key` looks like a Jira issue key (`PROJ-123`)
+pub fn is_valid_issue_key(key: &str) -> bool {
+    let Some((project, number)) = key.split_once('-') else {
+        return false;
+    };
+    project.starts_with(|c: char| c.is_ascii_uppercase())
+        && project.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// All mappings for `user_id`, ordered by project path
+pub async fn list_project_issue_mappings(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<ProjectIssueMapping>, String> {
+    let mappings = sqlx::query_as::<_, (String, String, String, String)>(
+        "SELECT project_path, user_id, jira_issue_key, COALESCE(updated_at, '') FROM project_issue_mappings WHERE user_id = ? ORDER BY project_path",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(mappings
+        .into_iter()
+        .map(|(project_path, user_id, jira_issue_key, updated_at)| ProjectIssueMapping {
+            project_path,
+            user_id,
+            jira_issue_key,
+            updated_at,
+        })
+        .collect())
+}
+
+/// Insert or replace the mapping for `project_path`
+pub async fn save_project_issue_mapping(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_path: &str,
+    jira_issue_key: &str,
+) -> Result<ProjectIssueMapping, String> {
+    let project_path = normalize_project_path(project_path);
+
+    sqlx::query(
+        r#"
+        INSERT INTO project_issue_mappings (project_path, user_id, jira_issue_key, updated_at)
+        VALUES (?, ?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(project_path, user_id) DO UPDATE SET
+            jira_issue_key = excluded.jira_issue_key,
+            updated_at = CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(&project_path)
+    .bind(user_id)
+    .bind(jira_issue_key)
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(ProjectIssueMapping {
+        project_path,
+        user_id: user_id.to_string(),
+        jira_issue_key: jira_issue_key.to_string(),
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Remove the mapping for `project_path`. Returns whether one existed.
+pub async fn delete_project_issue_mapping(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_path: &str,
+) -> Result<bool, String> {
+    let result = sqlx::query("DELETE FROM project_issue_mappings WHERE user_id = ? AND project_path IN (?, ?)")
+        .bind(user_id)
+        .bind(normalize_project_path(project_path))
+        .bind(project_path)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// The issue key mapped to `project_path`, if any
+pub async fn get_project_issue_key(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_path: &str,
+) -> Result<Option<String>, String> {
+    sqlx::query_scalar(
+        "SELECT jira_issue_key FROM project_issue_mappings WHERE user_id = ? AND project_path IN (?, ?) LIMIT 1",
+    )
+    .bind(user_id)
+    .bind(normalize_project_path(project_path))
+    .bind(project_path)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Database;
+
+    #[test]
+    fn test_is_valid_issue_key() {
+        assert!(is_valid_issue_key("PROJ-123"));
+        assert!(is_valid_issue_key("AB2_X-1"));
+        assert!(!is_valid_issue_key("proj-123"));
+        assert!(!is_valid_issue_key("PROJ-"));
+        assert!(!is_valid_issue_key("PROJ123"));
+        assert!(!is_valid_issue_key("1PROJ-2"));
+    }
+
+    #[tokio::test]
+    async fn test_mapping_persists_and_is_looked_up_by_project_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = Database::open(dir.path().join("test.db")).await.unwrap();
+
+        save_project_issue_mapping(&db.pool, "u1", "/home/dev/recap/", "PROJ-1").await.unwrap();
+        // Re-mapping replaces the key
+        save_project_issue_mapping(&db.pool, "u1", "/home/dev/recap", "PROJ-42").await.unwrap();
+
+        let mappings = list_project_issue_mappings(&db.pool, "u1").await.unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].project_path, "/home/dev/recap");
+        assert_eq!(mappings[0].jira_issue_key, "PROJ-42");
+
+        // The commit-centric worklog looks up its project path this way
+        let key = get_project_issue_key(&db.pool, "u1", "/home/dev/recap").await.unwrap();
+        assert_eq!(key.as_deref(), Some("PROJ-42"));
+        assert_eq!(get_project_issue_key(&db.pool, "u2", "/home/dev/recap").await.unwrap(), None);
+        assert_eq!(get_project_issue_key(&db.pool, "u1", "/home/dev/other").await.unwrap(), None);
+
+        assert!(delete_project_issue_mapping(&db.pool, "u1", "/home/dev/recap/").await.unwrap());
+        assert!(!delete_project_issue_mapping(&db.pool, "u1", "/home/dev/recap").await.unwrap());
+        assert!(list_project_issue_mappings(&db.pool, "u1").await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! Project Visibility
+//!
+//! Hide or show projects via `project_preferences`. Hidden projects are
+//! filtered out of work item, report and snapshot queries.
+
+use sqlx::SqlitePool;
+
+/// Set `hidden` for every project in `names` in one transaction.
+///
+/// Projects without a preference row get one. Returns how many projects
+/// changed visibility; names already in the requested state are not counted.
+pub async fn set_projects_visibility(
+    pool: &SqlitePool,
+    user_id: &str,
+    names: &[String],
+    hidden: bool,
+) -> Result<usize, String> {
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+    let mut changed = 0;
+    let mut seen: Vec<&str> = Vec::with_capacity(names.len());
+
+    for name in names.iter().map(|n| n.trim()).filter(|n| !n.is_empty()) {
+        if seen.contains(&name) {
+            continue;
+        }
+        seen.push(name);
+
+        let current: Option<Option<bool>> = sqlx::query_scalar(
+            "SELECT hidden FROM project_preferences WHERE user_id = ? AND project_name = ?",
+        )
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match current {
+            Some(current) if current.unwrap_or(false) == hidden => {}
+            Some(_) => {
+                sqlx::query(
+                    "UPDATE project_preferences SET hidden = ?, updated_at = CURRENT_TIMESTAMP WHERE user_id = ? AND project_name = ?",
+                )
+                .bind(hidden)
+                .bind(user_id)
+                .bind(name)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+                changed += 1;
+            }
+            None => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO project_preferences (id, user_id, project_name, hidden, updated_at)
+                    VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)
+                    "#,
+                )
+                .bind(uuid::Uuid::new_v4().to_string())
+                .bind(user_id)
+                .bind(name)
+                .bind(hidden)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+                // A new row only changes anything when it hides the project
+                if hidden {
+                    changed += 1;
+                }
+            }
+        }
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    async fn hidden_projects(pool: &SqlitePool) -> Vec<String> {
+        sqlx::query_scalar(
+            "SELECT project_name FROM project_preferences WHERE user_id = 'u1' AND hidden = 1 ORDER BY project_name",
+        )
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_hide_three_projects_in_one_call() {
+        let (db, _dir) = db_with_user().await;
+        // One project already has a (visible) preference row
+        sqlx::query(
+            "INSERT INTO project_preferences (id, user_id, project_name, hidden, display_name) VALUES ('p1', 'u1', 'beta', 0, 'Beta')",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let names = vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()];
+        let changed = set_projects_visibility(&db.pool, "u1", &names, true).await.unwrap();
+        assert_eq!(changed, 3);
+        assert_eq!(hidden_projects(&db.pool).await, vec!["alpha", "beta", "gamma"]);
+
+        // Existing preferences are kept
+        let display_name: Option<String> =
+            sqlx::query_scalar("SELECT display_name FROM project_preferences WHERE project_name = 'beta'")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(display_name.as_deref(), Some("Beta"));
+
+        // Repeating is a no-op
+        let changed = set_projects_visibility(&db.pool, "u1", &names, true).await.unwrap();
+        assert_eq!(changed, 0);
+
+        let changed = set_projects_visibility(&db.pool, "u1", &names[..2], false).await.unwrap();
+        assert_eq!(changed, 2);
+        assert_eq!(hidden_projects(&db.pool).await, vec!["gamma"]);
+    }
+}
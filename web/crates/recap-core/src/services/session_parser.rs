@@ -5,12 +5,15 @@
 //! - Sync service (services/sync.rs)
 //! - Work items (commands/work_items.rs)
 
-use serde::Deserialize;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
 use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::utils::parse_timestamp;
 
 // ============ Hash Generation ============
 
@@ -85,6 +88,23 @@ pub fn extract_tool_detail(tool_name: &str, input: &serde_json::Value) -> Option
     }
 }
 
+/// Longest Bash command kept in a [`ToolCall`] detail
+pub const TOOL_CALL_COMMAND_MAX_CHARS: usize = 120;
+
+/// Detail of one tool call for a timeline: the Bash command on one line,
+/// capped at [`TOOL_CALL_COMMAND_MAX_CHARS`], otherwise [`extract_tool_detail`]
+fn tool_call_detail(tool_name: &str, input: &serde_json::Value) -> Option<String> {
+    if tool_name != "Bash" {
+        return extract_tool_detail(tool_name, input);
+    }
+    let command = input.get("command")?.as_str()?.split_whitespace().collect::<Vec<_>>().join(" ");
+    if command.chars().count() <= TOOL_CALL_COMMAND_MAX_CHARS {
+        return Some(command);
+    }
+    let truncated: String = command.chars().take(TOOL_CALL_COMMAND_MAX_CHARS - 3).collect();
+    Some(format!("{}...", truncated))
+}
+
 // ============ Session Parsing Types ============
 
 /// Session message for parsing JSONL
@@ -118,6 +138,15 @@ pub struct ToolUsage {
     pub count: usize,
 }
 
+/// A single tool call, in session order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    pub timestamp: Option<String>,
+    pub tool_name: String,
+    /// Key input: file path for Edit/Write, command for Bash
+    pub detail: Option<String>,
+}
+
 /// Parsed session metadata (lightweight)
 #[derive(Debug, Clone)]
 pub struct SessionMetadata {
@@ -132,14 +161,41 @@ pub struct SessionMetadata {
 #[derive(Debug, Clone)]
 pub struct ParsedSession {
     pub cwd: String,
+    /// Every distinct `cwd` in the session, in the order first seen
+    pub cwds: Vec<String>,
     pub first_timestamp: Option<String>,
     pub last_timestamp: Option<String>,
     pub message_count: usize,
     pub tool_usage: Vec<ToolUsage>,
+    /// Every tool call in the order it was made
+    pub tool_calls: Vec<ToolCall>,
     pub files_modified: Vec<String>,
     pub first_message: Option<String>,
 }
 
+impl ParsedSession {
+    /// Project directories the session worked in: distinct `cwd`s, leaving
+    /// out those nested inside another (a `cd` into a subdirectory)
+    pub fn project_paths(&self) -> Vec<String> {
+        self.cwds
+            .iter()
+            .filter(|cwd| {
+                !self
+                    .cwds
+                    .iter()
+                    .any(|other| other != *cwd && Path::new(cwd).starts_with(other))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Whether the `cwd` changed between projects mid-session, so attributing
+    /// it to `cwd` alone would be wrong
+    pub fn is_multi_project(&self) -> bool {
+        self.project_paths().len() > 1
+    }
+}
+
 // ============ CWD Extraction ============
 
 /// Lightweight extraction of `cwd` from a JSONL session file.
@@ -237,19 +293,22 @@ pub fn parse_session_full(path: &PathBuf) -> Option<ParsedSession> {
     let file = fs::File::open(path).ok()?;
     let reader = BufReader::new(file);
 
-    let mut cwd: Option<String> = None;
+    let mut cwds: Vec<String> = Vec::new();
     let mut first_message: Option<String> = None;
     let mut first_timestamp: Option<String> = None;
     let mut last_timestamp: Option<String> = None;
     let mut meaningful_message_count: usize = 0;
 
     let mut tool_counts: HashMap<String, usize> = HashMap::new();
+    let mut tool_calls: Vec<ToolCall> = Vec::new();
     let mut files_modified: Vec<String> = Vec::new();
 
     for line in reader.lines().flatten() {
         if let Ok(msg) = serde_json::from_str::<SessionMessage>(&line) {
-            if cwd.is_none() {
-                cwd = msg.cwd;
+            if let Some(cwd) = msg.cwd.filter(|c| !c.is_empty()) {
+                if !cwds.contains(&cwd) {
+                    cwds.push(cwd);
+                }
             }
 
             if let Some(ts) = &msg.timestamp {
@@ -285,6 +344,14 @@ pub fn parse_session_full(path: &PathBuf) -> Option<ParsedSession> {
                                     if tool_use.content_type.as_deref() == Some("tool_use") {
                                         if let Some(tool_name) = &tool_use.name {
                                             *tool_counts.entry(tool_name.clone()).or_insert(0) += 1;
+                                            tool_calls.push(ToolCall {
+                                                timestamp: msg.timestamp.clone(),
+                                                tool_name: tool_name.clone(),
+                                                detail: tool_use
+                                                    .input
+                                                    .as_ref()
+                                                    .and_then(|input| tool_call_detail(tool_name, input)),
+                                            });
 
                                             // Track file modifications
                                             if let Some(input) = &tool_use.input {
@@ -321,16 +388,136 @@ pub fn parse_session_full(path: &PathBuf) -> Option<ParsedSession> {
         .collect();
 
     Some(ParsedSession {
-        cwd: cwd.unwrap_or_default(),
+        cwd: cwds.first().cloned().unwrap_or_default(),
+        cwds,
         first_timestamp,
         last_timestamp,
         message_count: meaningful_message_count,
         tool_usage,
+        tool_calls,
         files_modified,
         first_message,
     })
 }
 
+// ============ Active Blocks ============
+
+/// Minutes between messages after which a session is considered idle
+pub const IDLE_GAP_MINUTES: i64 = 30;
+
+/// Continuous working interval within a session (RFC 3339 timestamps)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActiveBlock {
+    pub start: String,
+    pub end: String,
+}
+
+/// Split message timestamps into active blocks, starting a new block
+/// wherever consecutive messages are more than `idle_gap` apart
+pub fn split_active_blocks(timestamps: &[DateTime<Utc>], idle_gap: Duration) -> Vec<ActiveBlock> {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort();
+
+    let mut blocks: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for ts in sorted {
+        match blocks.last_mut() {
+            Some((_, end)) if ts - *end <= idle_gap => *end = ts,
+            _ => blocks.push((ts, ts)),
+        }
+    }
+
+    blocks
+        .into_iter()
+        .map(|(start, end)| ActiveBlock {
+            start: start.to_rfc3339(),
+            end: end.to_rfc3339(),
+        })
+        .collect()
+}
+
+/// Active blocks of a session file, split at gaps over [`IDLE_GAP_MINUTES`]
+pub fn parse_session_active_blocks(path: &Path) -> Vec<ActiveBlock> {
+    let Ok(file) = fs::File::open(path) else {
+        return Vec::new();
+    };
+
+    let timestamps: Vec<DateTime<Utc>> = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<SessionMessage>(&line).ok())
+        .filter_map(|msg| msg.timestamp.and_then(|ts| parse_timestamp(&ts, None)))
+        .collect();
+
+    split_active_blocks(&timestamps, Duration::minutes(IDLE_GAP_MINUTES))
+}
+
+/// Find `<session_id>.jsonl` in any project directory under `projects_dir`
+pub fn find_session_file(projects_dir: &Path, session_id: &str) -> Option<PathBuf> {
+    let file_name = format!("{}.jsonl", session_id);
+    fs::read_dir(projects_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join(&file_name))
+        .find(|path| path.is_file())
+}
+
+// ============ Summary Helpers ============
+
+/// Extract up to 20 meaningful user messages from a session, for LLM summarization
+pub fn extract_session_content(path: &PathBuf) -> String {
+    let file = match fs::File::open(path) {
+        Ok(f) => f,
+        Err(_) => return String::new(),
+    };
+    let reader = BufReader::new(file);
+
+    let mut content_parts: Vec<String> = Vec::new();
+
+    for line in reader.lines().map_while(Result::ok) {
+        if let Ok(msg) = serde_json::from_str::<serde_json::Value>(&line) {
+            if let Some(message) = msg.get("message") {
+                if message.get("role").and_then(|r| r.as_str()) == Some("user") {
+                    if let Some(text) = message.get("content").and_then(|c| c.as_str()) {
+                        let trimmed = text.trim();
+                        if trimmed.len() >= 10
+                            && !trimmed.to_lowercase().starts_with("warmup")
+                            && !trimmed.starts_with("<command-")
+                        {
+                            content_parts.push(format!("User: {}", trimmed.chars().take(200).collect::<String>()));
+                        }
+                    }
+                }
+            }
+        }
+
+        if content_parts.len() >= 20 {
+            break;
+        }
+    }
+
+    content_parts.join("\n\n")
+}
+
+/// Build a rule-based session summary when LLM is not available:
+/// the first request, plus how many files were modified.
+pub fn build_rule_based_session_summary(session: &ParsedSession) -> String {
+    let first: String = session
+        .first_message
+        .as_deref()
+        .map(|m| m.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+        .chars()
+        .take(100)
+        .collect();
+
+    match (first.is_empty(), session.files_modified.len()) {
+        (true, 0) => format!("{} 則訊息", session.message_count),
+        (true, files) => format!("修改 {} 個檔案", files),
+        (false, 0) => first,
+        (false, files) => format!("{}（修改 {} 個檔案）", first, files),
+    }
+}
+
 // ============ Tests ============
 
 #[cfg(test)]
@@ -466,4 +653,121 @@ mod tests {
         assert!(detail.len() <= 63); // 60 + "..."
         assert!(detail.ends_with("..."));
     }
+
+    #[test]
+    fn test_parse_session_full_keeps_tool_call_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let long_command = format!("cargo test --workspace\n  -- {}", "x".repeat(200));
+        let lines = [
+            serde_json::json!({"cwd": "/work/recap", "timestamp": "2026-01-01T09:00:00Z",
+                "message": {"role": "user", "content": "Fix the sync bug"}}),
+            serde_json::json!({"timestamp": "2026-01-01T09:01:00Z", "message": {"role": "assistant", "content": [
+                {"type": "tool_use", "name": "Edit", "input": {"file_path": "/work/recap/src/sync.rs"}},
+                {"type": "tool_use", "name": "Bash", "input": {"command": long_command}},
+            ]}}),
+            serde_json::json!({"timestamp": "2026-01-01T09:05:00Z", "message": {"role": "assistant", "content": [
+                {"type": "tool_use", "name": "Bash", "input": {"command": "git status"}},
+            ]}}),
+        ];
+        let body: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        fs::write(&path, body.join("\n")).unwrap();
+
+        let calls = parse_session_full(&path).unwrap().tool_calls;
+        let names: Vec<&str> = calls.iter().map(|c| c.tool_name.as_str()).collect();
+        assert_eq!(names, vec!["Edit", "Bash", "Bash"]);
+        assert_eq!(calls[0].timestamp.as_deref(), Some("2026-01-01T09:01:00Z"));
+        assert_eq!(calls[0].detail.as_deref(), Some(".../recap/src/sync.rs"));
+
+        let capped = calls[1].detail.as_deref().unwrap();
+        assert_eq!(capped.chars().count(), TOOL_CALL_COMMAND_MAX_CHARS);
+        assert!(capped.starts_with("cargo test --workspace -- xxx"));
+        assert!(capped.ends_with("..."));
+        assert_eq!(calls[2].detail.as_deref(), Some("git status"));
+        assert_eq!(calls[2].timestamp.as_deref(), Some("2026-01-01T09:05:00Z"));
+    }
+
+    fn parsed_session(first_message: Option<&str>, files: &[&str]) -> ParsedSession {
+        ParsedSession {
+            cwd: "/work/recap".to_string(),
+            cwds: vec!["/work/recap".to_string()],
+            first_timestamp: None,
+            last_timestamp: None,
+            message_count: 3,
+            tool_usage: Vec::new(),
+            tool_calls: Vec::new(),
+            files_modified: files.iter().map(|f| f.to_string()).collect(),
+            first_message: first_message.map(|m| m.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_session_with_two_cwds_is_multi_project() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.jsonl");
+        fs::write(
+            &path,
+            r#"{"cwd":"/work/recap","timestamp":"2026-01-01T09:00:00Z","message":{"role":"user","content":"Fix the sync bug"}}
+{"cwd":"/work/recap/web","timestamp":"2026-01-01T09:10:00Z","message":{"role":"user","content":"Run the web tests"}}
+{"cwd":"/work/funnel","timestamp":"2026-01-01T09:30:00Z","message":{"role":"user","content":"Now update the funnel config"}}
+"#,
+        )
+        .unwrap();
+
+        let session = parse_session_full(&path).unwrap();
+        assert_eq!(session.cwd, "/work/recap");
+        assert_eq!(session.cwds.len(), 3);
+        // The subdirectory belongs to the first project
+        assert_eq!(session.project_paths(), vec!["/work/recap", "/work/funnel"]);
+        assert!(session.is_multi_project());
+
+        assert!(!parsed_session(None, &[]).is_multi_project());
+    }
+
+    #[test]
+    fn test_build_rule_based_session_summary() {
+        let session = parsed_session(Some("Fix the\nlogin bug"), &["src/a.rs", "src/b.rs"]);
+        assert_eq!(build_rule_based_session_summary(&session), "Fix the login bug（修改 2 個檔案）");
+
+        let session = parsed_session(Some("Explain the sync flow"), &[]);
+        assert_eq!(build_rule_based_session_summary(&session), "Explain the sync flow");
+
+        let session = parsed_session(None, &[]);
+        assert_eq!(build_rule_based_session_summary(&session), "3 則訊息");
+    }
+
+    #[test]
+    fn test_ninety_minute_gap_splits_into_two_active_blocks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let project = dir.path().join("-home-dev-recap");
+        fs::create_dir(&project).unwrap();
+        fs::write(
+            project.join("abc123.jsonl"),
+            r#"{"type":"user","timestamp":"2026-01-15T09:00:00Z"}
+{"type":"assistant","timestamp":"2026-01-15T09:20:00Z"}
+{"type":"user","timestamp":"2026-01-15T09:45:00Z"}
+{"type":"user","timestamp":"2026-01-15T11:15:00Z"}
+{"type":"assistant","timestamp":"2026-01-15T11:40:00Z"}
+"#,
+        )
+        .unwrap();
+
+        let path = find_session_file(dir.path(), "abc123").unwrap();
+        assert!(find_session_file(dir.path(), "missing").is_none());
+
+        let blocks = parse_session_active_blocks(&path);
+        assert_eq!(
+            blocks,
+            vec![
+                ActiveBlock {
+                    start: "2026-01-15T09:00:00+00:00".to_string(),
+                    end: "2026-01-15T09:45:00+00:00".to_string(),
+                },
+                ActiveBlock {
+                    start: "2026-01-15T11:15:00+00:00".to_string(),
+                    end: "2026-01-15T11:40:00+00:00".to_string(),
+                },
+            ]
+        );
+    }
 }
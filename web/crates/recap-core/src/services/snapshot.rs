@@ -4,6 +4,9 @@
 //! to the `snapshot_raw_data` table. Each bucket contains user messages,
 //! assistant responses, tool calls, files modified, and git commits
 //! for a specific session within a one-hour window.
+//!
+//! Stored snapshots are always hourly. Finer buckets (15 or 30 minutes) are
+//! only parsed on demand via [`parse_project_buckets`].
 
 use chrono::{DateTime, Local, Timelike};
 use serde::{Deserialize, Serialize};
@@ -42,10 +45,19 @@ pub struct CommitSnapshot {
     pub deletions: i32,
 }
 
-/// An hourly bucket of session data
+/// Bucket length of stored snapshots
+pub const HOURLY_BUCKET_MINUTES: u32 = 60;
+
+/// Bucket lengths accepted by [`parse_session_into_hourly_buckets`]
+pub const SUPPORTED_BUCKET_MINUTES: [u32; 3] = [15, 30, 60];
+
+/// A bucket of session data, one hour long unless parsed at a finer resolution
 #[derive(Debug, Clone)]
 pub struct HourlyBucket {
+    /// Local start of the bucket, e.g. "2026-01-26T10:30:00"
     pub hour_bucket: String,
+    /// Bucket length: 60 for stored snapshots, 15 or 30 for finer breakdowns
+    pub bucket_minutes: u32,
     pub user_messages: Vec<String>,
     pub assistant_summaries: Vec<String>,
     pub tool_calls: Vec<ToolCallRecord>,
@@ -64,26 +76,40 @@ pub struct SnapshotCaptureResult {
 
 // ============ Parsing ============
 
-/// Truncate an ISO 8601 timestamp to its hour boundary in local timezone.
+/// Check that `minutes` is one of [`SUPPORTED_BUCKET_MINUTES`]
+pub fn validate_bucket_minutes(minutes: u32) -> Result<u32, String> {
+    if SUPPORTED_BUCKET_MINUTES.contains(&minutes) {
+        Ok(minutes)
+    } else {
+        Err(format!(
+            "Unsupported bucket size: {} minutes (expected one of {:?})",
+            minutes, SUPPORTED_BUCKET_MINUTES
+        ))
+    }
+}
+
+/// Truncate an ISO 8601 timestamp to its bucket boundary in local timezone.
 /// e.g., "2026-01-26T02:35:00Z" → "2026-01-26T10:00:00" (if local is UTC+8)
+/// with 60-minute buckets, or "2026-01-26T10:30:00" with 30-minute buckets.
 ///
 /// Converts UTC to local timezone so that hour bucketing and date grouping
 /// align with the user's actual working hours.
-fn truncate_to_hour(timestamp: &str) -> Option<String> {
+fn truncate_to_bucket(timestamp: &str, bucket_minutes: u32) -> Option<String> {
     let dt = DateTime::parse_from_rfc3339(timestamp).ok()?;
     let local_dt = dt.with_timezone(&Local);
     let truncated = local_dt
-        .with_minute(0)
+        .with_minute(local_dt.minute() - local_dt.minute() % bucket_minutes)
         .and_then(|d| d.with_second(0))
         .and_then(|d| d.with_nanosecond(0))?;
     Some(truncated.format("%Y-%m-%dT%H:%M:%S").to_string())
 }
 
-/// Parse a JSONL session file into hourly buckets.
+/// Parse a JSONL session file into buckets of `bucket_minutes` (one of
+/// [`SUPPORTED_BUCKET_MINUTES`]; snapshots use [`HOURLY_BUCKET_MINUTES`]).
 ///
 /// Each bucket contains messages, tool calls, and file modifications
-/// that occurred within that hour. Timestamps are truncated to hour boundaries.
-pub fn parse_session_into_hourly_buckets(path: &PathBuf) -> Vec<HourlyBucket> {
+/// that occurred within it. Timestamps are truncated to bucket boundaries.
+pub fn parse_session_into_hourly_buckets(path: &PathBuf, bucket_minutes: u32) -> Vec<HourlyBucket> {
     let file = match fs::File::open(path) {
         Ok(f) => f,
         Err(_) => return Vec::new(),
@@ -103,13 +129,14 @@ pub fn parse_session_into_hourly_buckets(path: &PathBuf) -> Vec<HourlyBucket> {
             None => continue,
         };
 
-        let hour_key = match truncate_to_hour(&timestamp) {
+        let hour_key = match truncate_to_bucket(&timestamp, bucket_minutes) {
             Some(h) => h,
             None => continue,
         };
 
         let bucket = buckets.entry(hour_key.clone()).or_insert_with(|| HourlyBucket {
             hour_bucket: hour_key,
+            bucket_minutes,
             user_messages: Vec::new(),
             assistant_summaries: Vec::new(),
             tool_calls: Vec::new(),
@@ -213,7 +240,7 @@ pub fn parse_session_into_hourly_buckets(path: &PathBuf) -> Vec<HourlyBucket> {
     result
 }
 
-/// Enrich hourly buckets with git commit data from the project repository.
+/// Enrich buckets with git commit data from the project repository.
 pub fn enrich_buckets_with_git_commits(
     buckets: &mut [HourlyBucket],
     project_path: &str,
@@ -232,9 +259,10 @@ pub fn enrich_buckets_with_git_commits(
         // - RFC3339 with timezone: "2026-01-30T10:00:00+08:00"
         // - Local time without timezone: "2026-01-30T10:00:00"
         let start = &bucket.hour_bucket;
+        let length = chrono::Duration::minutes(bucket.bucket_minutes.into());
         let (start_str, end_str) = match DateTime::parse_from_rfc3339(start) {
             Ok(dt) => {
-                let end_dt = dt + chrono::Duration::hours(1);
+                let end_dt = dt + length;
                 (dt.to_rfc3339(), end_dt.to_rfc3339())
             }
             Err(_) => {
@@ -242,7 +270,7 @@ pub fn enrich_buckets_with_git_commits(
                 match NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S") {
                     Ok(ndt) => {
                         let local_start = Local.from_local_datetime(&ndt).single();
-                        let local_end = Local.from_local_datetime(&(ndt + chrono::Duration::hours(1))).single();
+                        let local_end = Local.from_local_datetime(&(ndt + length)).single();
                         match (local_start, local_end) {
                             (Some(s), Some(e)) => (s.to_rfc3339(), e.to_rfc3339()),
                             _ => continue,
@@ -409,7 +437,7 @@ pub async fn capture_snapshots_for_project(
             let session_id = extract_session_id(jsonl_path);
 
             // Parse session into hourly buckets
-            let mut buckets = parse_session_into_hourly_buckets(jsonl_path);
+            let mut buckets = parse_session_into_hourly_buckets(jsonl_path, HOURLY_BUCKET_MINUTES);
 
             if buckets.is_empty() {
                 continue;
@@ -431,6 +459,20 @@ pub async fn capture_snapshots_for_project(
     Ok(total_saved)
 }
 
+/// Parse every session of `project` into buckets of `bucket_minutes`,
+/// without reading or writing stored snapshots. Buckets from different
+/// sessions stay separate, like snapshot rows; sorted by start time.
+pub fn parse_project_buckets(project: &DiscoveredProject, bucket_minutes: u32) -> Vec<HourlyBucket> {
+    let mut buckets: Vec<HourlyBucket> = project
+        .claude_dirs
+        .iter()
+        .flat_map(find_jsonl_files)
+        .flat_map(|path| parse_session_into_hourly_buckets(&path, bucket_minutes))
+        .collect();
+    buckets.sort_by(|a, b| a.hour_bucket.cmp(&b.hour_bucket));
+    buckets
+}
+
 /// Find all .jsonl files in a directory (non-recursive)
 fn find_jsonl_files(dir: &PathBuf) -> Vec<PathBuf> {
     let mut files = Vec::new();
@@ -484,7 +526,7 @@ mod tests {
     fn test_truncate_to_hour() {
         // Converts to local timezone and truncates to hour boundary.
         // Output is naive local time (no offset), format: YYYY-MM-DDTHH:00:00
-        let result = truncate_to_hour("2026-01-26T14:35:22+00:00");
+        let result = truncate_to_bucket("2026-01-26T14:35:22+00:00", 60);
         assert!(result.is_some());
         let r = result.unwrap();
         // Should end with :00:00 (truncated to hour)
@@ -496,13 +538,13 @@ mod tests {
         assert_eq!(r.len(), 19, "Expected 19 chars, got: {} ({})", r.len(), r);
 
         // Another input
-        let result2 = truncate_to_hour("2026-01-26T00:59:59+00:00");
+        let result2 = truncate_to_bucket("2026-01-26T00:59:59+00:00", 60);
         assert!(result2.is_some());
         let r2 = result2.unwrap();
         assert!(r2.ends_with(":00:00"));
         assert_eq!(r2.len(), 19);
 
-        assert!(truncate_to_hour("invalid").is_none());
+        assert!(truncate_to_bucket("invalid", 60).is_none());
     }
 
     #[test]
@@ -518,7 +560,7 @@ mod tests {
         writeln!(file, "{}", make_tool_use_line("Edit", "/src/test.rs", "2026-01-26T15:11:00+00:00")).unwrap();
 
         let path = file.path().to_path_buf();
-        let buckets = parse_session_into_hourly_buckets(&path);
+        let buckets = parse_session_into_hourly_buckets(&path, HOURLY_BUCKET_MINUTES);
 
         assert_eq!(buckets.len(), 2, "Should have 2 hourly buckets");
         assert_eq!(buckets[0].user_messages.len(), 1);
@@ -528,18 +570,51 @@ mod tests {
         assert_eq!(buckets[1].files_modified.len(), 1);
     }
 
+    #[test]
+    fn test_parse_session_into_finer_buckets() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{}", make_jsonl_line("user", "Help me implement login", "2026-01-26T14:05:00+00:00")).unwrap();
+        writeln!(file, "{}", make_jsonl_line("user", "Add the logout button too", "2026-01-26T14:20:00+00:00")).unwrap();
+        writeln!(file, "{}", make_jsonl_line("user", "Now fix the tests please", "2026-01-26T14:40:00+00:00")).unwrap();
+        let path = file.path().to_path_buf();
+
+        let hourly = parse_session_into_hourly_buckets(&path, 60);
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].message_count, 3);
+
+        let half_hours = parse_session_into_hourly_buckets(&path, 30);
+        assert_eq!(half_hours.len(), 2);
+        assert_eq!(half_hours.iter().map(|b| b.message_count).collect::<Vec<_>>(), vec![2, 1]);
+        assert!(half_hours[1].hour_bucket.ends_with(":30:00"));
+        assert!(half_hours.iter().all(|b| b.bucket_minutes == 30));
+
+        let quarters = parse_session_into_hourly_buckets(&path, 15);
+        assert_eq!(quarters.len(), 3);
+        assert!(quarters[1].hour_bucket.ends_with(":15:00"));
+    }
+
+    #[test]
+    fn test_validate_bucket_minutes() {
+        for minutes in SUPPORTED_BUCKET_MINUTES {
+            assert_eq!(validate_bucket_minutes(minutes), Ok(minutes));
+        }
+        assert!(validate_bucket_minutes(0).is_err());
+        assert!(validate_bucket_minutes(20).is_err());
+        assert!(validate_bucket_minutes(120).is_err());
+    }
+
     #[test]
     fn test_parse_session_empty_file() {
         let file = NamedTempFile::new().unwrap();
         let path = file.path().to_path_buf();
-        let buckets = parse_session_into_hourly_buckets(&path);
+        let buckets = parse_session_into_hourly_buckets(&path, HOURLY_BUCKET_MINUTES);
         assert!(buckets.is_empty());
     }
 
     #[test]
     fn test_parse_session_nonexistent_file() {
         let path = PathBuf::from("/nonexistent/file.jsonl");
-        let buckets = parse_session_into_hourly_buckets(&path);
+        let buckets = parse_session_into_hourly_buckets(&path, HOURLY_BUCKET_MINUTES);
         assert!(buckets.is_empty());
     }
 
@@ -556,7 +631,7 @@ mod tests {
         .unwrap();
 
         let path = file.path().to_path_buf();
-        let buckets = parse_session_into_hourly_buckets(&path);
+        let buckets = parse_session_into_hourly_buckets(&path, HOURLY_BUCKET_MINUTES);
 
         assert_eq!(buckets.len(), 1);
         assert_eq!(buckets[0].assistant_summaries[0].len(), 200);
@@ -575,7 +650,7 @@ mod tests {
         .unwrap();
 
         let path = file.path().to_path_buf();
-        let buckets = parse_session_into_hourly_buckets(&path);
+        let buckets = parse_session_into_hourly_buckets(&path, HOURLY_BUCKET_MINUTES);
 
         assert_eq!(buckets.len(), 1);
         assert_eq!(buckets[0].user_messages[0].len(), 500);
@@ -630,6 +705,7 @@ mod tests {
         // Create a bucket with a known time range that has commits
         let mut buckets = vec![HourlyBucket {
             hour_bucket: "2026-01-30T09:00:00".to_string(), // Local time without timezone
+            bucket_minutes: HOURLY_BUCKET_MINUTES,
             user_messages: vec![],
             assistant_summaries: vec![],
             tool_calls: vec![],
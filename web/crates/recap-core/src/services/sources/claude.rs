@@ -1,22 +1,29 @@
 //! Claude Code Source Implementation
 //!
 //! This module implements the SyncSource trait for Claude Code sessions.
-//! It discovers Claude Code projects from ~/.claude/projects and syncs
-//! sessions to work items.
+//! It discovers Claude Code projects under each of the user's Claude roots
+//! (`claude_session_path`, default ~/.claude) and syncs sessions to work items.
 
 use async_trait::async_trait;
 use sqlx::SqlitePool;
 use std::fs;
 use std::path::Path;
 
-use super::{SyncSource, SourceProject, SourceSyncResult, WorkItemParams, upsert_work_item, UpsertResult};
-use crate::services::sync::{SyncService, DiscoveredProject, resolve_git_root};
+use super::{
+    SyncSource, SourceProject, SourceSyncResult, ProjectSyncResult, WorkItemParams,
+    upsert_work_item, UpsertResult,
+};
+use crate::services::sync::{
+    SyncService, DiscoveredProject, SessionCursor, CLAUDE_SYNC_SOURCE, discover_user_projects, find_claude_project_dirs,
+    load_max_session_hours, load_user_timezone, resolve_git_root,
+};
 use crate::services::session_parser::parse_session_full;
-use crate::services::worklog::calculate_session_hours;
+use crate::services::worklog::calculate_session_hours_capped;
 
 /// Claude Code data source
 ///
-/// Syncs work items from local Claude Code sessions stored in ~/.claude/projects
+/// Syncs work items from local Claude Code sessions stored under each of the
+/// user's Claude roots. Without a user, discovery falls back to ~/.claude/projects.
 pub struct ClaudeSource;
 
 impl ClaudeSource {
@@ -73,105 +80,18 @@ impl SyncSource for ClaudeSource {
         pool: &SqlitePool,
         user_id: &str,
     ) -> Result<SourceSyncResult, String> {
-        let projects = SyncService::discover_project_paths();
+        let projects = discover_user_projects(pool, user_id).await?;
         let mut result = SourceSyncResult::new(self.source_name());
         result.projects_scanned = projects.len();
 
         log::debug!("Claude Code: 發現 {} 個專案", projects.len());
 
-        for (idx, project) in projects.iter().enumerate() {
-            // Skip root path projects (MCP/no-context sessions)
-            if project.canonical_path == "/" || project.canonical_path.is_empty() {
-                log::debug!("[{}/{}] 跳過根路徑專案: {}", idx + 1, projects.len(), project.name);
-                continue;
-            }
-
-            log::debug!("[{}/{}] 處理專案: {} ({})", idx + 1, projects.len(), project.name, project.canonical_path);
-
-            for claude_dir in &project.claude_dirs {
-                if !claude_dir.is_dir() {
-                    continue;
-                }
-
-                let files = match fs::read_dir(claude_dir) {
-                    Ok(f) => f,
-                    Err(_) => continue,
-                };
-
-                for file_entry in files.flatten() {
-                    let file_path = file_entry.path();
-                    if !file_path.extension().map(|e| e == "jsonl").unwrap_or(false) {
-                        continue;
-                    }
-
-                    if let Some(session) = parse_session_full(&file_path) {
-                        if session.message_count == 0 {
-                            result.sessions_skipped += 1;
-                            continue;
-                        }
-
-                        let hours = session_hours_from_options(
-                            &session.first_timestamp,
-                            &session.last_timestamp,
-                        );
-
-                        // Extract session ID from filename
-                        let session_id = file_path
-                            .file_stem()
-                            .and_then(|s| s.to_str())
-                            .unwrap_or("unknown")
-                            .to_string();
-
-                        let date = session
-                            .first_timestamp
-                            .as_ref()
-                            .and_then(|ts| ts.split('T').next())
-                            .unwrap_or("2026-01-01")
-                            .to_string();
-
-                        // Build title from first message
-                        let title_content = session
-                            .first_message
-                            .as_ref()
-                            .map(|m| {
-                                let truncated: String = m.chars().take(60).collect();
-                                if m.len() > 60 {
-                                    format!("{}...", truncated)
-                                } else {
-                                    truncated
-                                }
-                            })
-                            .unwrap_or_else(|| "Claude Code session".to_string());
-
-                        let title = format!("[{}] {}", project.name, title_content);
-                        let description = build_session_description(&session);
-
-                        let params = WorkItemParams::new(
-                            user_id,
-                            self.source_name(),
-                            &session_id,
-                            title,
-                            hours,
-                            &date,
-                        )
-                        .with_description(description)
-                        .with_project_path(&project.canonical_path)
-                        .with_session_id(&session_id)
-                        .with_time_range(session.first_timestamp.clone(), session.last_timestamp.clone());
-
-                        match upsert_work_item(pool, params).await {
-                            Ok(UpsertResult::Created(_)) => result.work_items_created += 1,
-                            Ok(UpsertResult::Updated(_)) => result.work_items_updated += 1,
-                            Ok(UpsertResult::Skipped(_)) => result.sessions_skipped += 1,
-                            Err(e) => {
-                                log::error!("Failed to upsert work item: {}", e);
-                                result.sessions_skipped += 1;
-                            }
-                        }
-                        result.sessions_processed += 1;
-                    }
-                }
-            }
+        // Only files modified since the last sync are parsed
+        let service = SyncService::new(pool.clone());
+        let mut cursor = SessionCursor::new(service.get_cursor(user_id, CLAUDE_SYNC_SOURCE).await?);
+        sync_projects_into(pool, user_id, &projects, &mut cursor, &mut result).await;
+        if let Some(max_seen) = cursor.max_seen {
+            service.advance_cursor(user_id, CLAUDE_SYNC_SOURCE, max_seen).await?;
         }
 
         Ok(result)
@@ -186,11 +106,10 @@ pub async fn sync_claude_projects(
     user_id: &str,
     project_paths: &[String],
 ) -> Result<SourceSyncResult, String> {
-    let claude_home = dirs::home_dir()
-        .map(|h| h.join(".claude"))
-        .ok_or("Claude home directory not found")?;
-
-    let projects_dir = claude_home.join("projects");
+    let projects_dirs = crate::services::claude_paths::resolve_claude_projects_dirs(pool, user_id).await?;
+    if projects_dirs.is_empty() {
+        return Err("Claude home directory not found".to_string());
+    }
     let mut result = SourceSyncResult::new("claude_code");
 
     // Convert project_paths into DiscoveredProject structs
@@ -198,28 +117,17 @@ pub async fn sync_claude_projects(
         std::collections::HashMap::new();
 
     for project_path in project_paths {
-        // Handle path encoding: /Users/foo -> -Users-foo or Users-foo
-        let dir_name_with_dash = project_path.replace('/', "-");
-        let dir_name_without_dash = project_path.trim_start_matches('/').replace('/', "-");
-
-        let project_dir = if projects_dir.join(&dir_name_with_dash).exists() {
-            projects_dir.join(&dir_name_with_dash)
-        } else if projects_dir.join(&dir_name_without_dash).exists() {
-            projects_dir.join(&dir_name_without_dash)
-        } else {
+        let project_dirs = find_claude_project_dirs(&projects_dirs, project_path);
+        if project_dirs.is_empty() {
             log::debug!(
                 "Claude project directory not found for path: {}",
                 project_path
             );
             continue;
-        };
-
-        if !project_dir.is_dir() {
-            continue;
         }
 
         let git_root = resolve_git_root(project_path);
-        grouped.entry(git_root).or_default().push(project_dir);
+        grouped.entry(git_root).or_default().extend(project_dirs);
     }
 
     let projects: Vec<DiscoveredProject> = grouped
@@ -238,13 +146,34 @@ pub async fn sync_claude_projects(
         .collect();
 
     result.projects_scanned = projects.len();
+    sync_projects_into(pool, user_id, &projects, &mut SessionCursor::default(), &mut result).await;
 
-    // Sync each project
-    for project in &projects {
+    Ok(result)
+}
+
+/// Sync each project's sessions that pass `cursor`, accumulating totals and
+/// per-project counts into `result`
+async fn sync_projects_into(
+    pool: &SqlitePool,
+    user_id: &str,
+    projects: &[DiscoveredProject],
+    cursor: &mut SessionCursor,
+    result: &mut SourceSyncResult,
+) {
+    let timezone = load_user_timezone(pool, user_id).await;
+    let max_session_hours = load_max_session_hours(pool, user_id).await;
+
+    for (idx, project) in projects.iter().enumerate() {
+        // Skip root path projects (MCP/no-context sessions)
         if project.canonical_path == "/" || project.canonical_path.is_empty() {
+            log::debug!("[{}/{}] 跳過根路徑專案: {}", idx + 1, projects.len(), project.name);
             continue;
         }
 
+        log::debug!("[{}/{}] 處理專案: {} ({})", idx + 1, projects.len(), project.name, project.canonical_path);
+
+        let mut project_result = ProjectSyncResult::new(&project.name, &project.canonical_path);
+
         for claude_dir in &project.claude_dirs {
             if !claude_dir.is_dir() {
                 continue;
@@ -260,18 +189,32 @@ pub async fn sync_claude_projects(
                 if !file_path.extension().map(|e| e == "jsonl").unwrap_or(false) {
                     continue;
                 }
+                if !cursor.should_scan(&file_path) {
+                    continue;
+                }
 
                 if let Some(session) = parse_session_full(&file_path) {
+                    if session.is_multi_project() {
+                        // Attributed to the first cwd's project; flag it rather than misattribute silently
+                        log::warn!(
+                            "Session {} spans multiple projects: {}",
+                            file_path.display(),
+                            session.project_paths().join(", ")
+                        );
+                    }
                     if session.message_count == 0 {
-                        result.sessions_skipped += 1;
+                        project_result.skipped += 1;
                         continue;
                     }
 
                     let hours = session_hours_from_options(
                         &session.first_timestamp,
                         &session.last_timestamp,
+                        timezone.as_deref(),
+                        max_session_hours,
                     );
 
+                    // Extract session ID from filename
                     let session_id = file_path
                         .file_stem()
                         .and_then(|s| s.to_str())
@@ -285,6 +228,7 @@ pub async fn sync_claude_projects(
                         .unwrap_or("2026-01-01")
                         .to_string();
 
+                    // Build title from first message
                     let title_content = session
                         .first_message
                         .as_ref()
@@ -303,7 +247,7 @@ pub async fn sync_claude_projects(
 
                     let params = WorkItemParams::new(
                         user_id,
-                        "claude_code",
+                        &result.source,
                         &session_id,
                         title,
                         hours,
@@ -315,27 +259,35 @@ pub async fn sync_claude_projects(
                     .with_time_range(session.first_timestamp.clone(), session.last_timestamp.clone());
 
                     match upsert_work_item(pool, params).await {
-                        Ok(UpsertResult::Created(_)) => result.work_items_created += 1,
-                        Ok(UpsertResult::Updated(_)) => result.work_items_updated += 1,
-                        Ok(UpsertResult::Skipped(_)) => result.sessions_skipped += 1,
+                        Ok(UpsertResult::Created(_)) => project_result.created += 1,
+                        Ok(UpsertResult::Updated(_)) => project_result.updated += 1,
+                        Ok(UpsertResult::Skipped(_)) => project_result.skipped += 1,
                         Err(e) => {
                             log::error!("Failed to upsert work item: {}", e);
-                            result.sessions_skipped += 1;
+                            project_result.skipped += 1;
                         }
                     }
                     result.sessions_processed += 1;
                 }
             }
         }
-    }
 
-    Ok(result)
+        result.work_items_created += project_result.created;
+        result.work_items_updated += project_result.updated;
+        result.sessions_skipped += project_result.skipped;
+        result.per_project.push(project_result);
+    }
 }
 
 /// Helper to calculate session hours with Option handling
-fn session_hours_from_options(first: &Option<String>, last: &Option<String>) -> f64 {
+fn session_hours_from_options(
+    first: &Option<String>,
+    last: &Option<String>,
+    timezone: Option<&str>,
+    max_hours: f64,
+) -> f64 {
     match (first, last) {
-        (Some(start), Some(end)) => calculate_session_hours(start, end),
+        (Some(start), Some(end)) => calculate_session_hours_capped(start, end, timezone, max_hours),
         _ => 0.5,
     }
 }
@@ -387,6 +339,7 @@ fn build_session_description(session: &crate::services::session_parser::ParsedSe
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::worklog::DEFAULT_MAX_SESSION_HOURS;
 
     #[test]
     fn test_claude_source_name() {
@@ -400,19 +353,31 @@ mod tests {
         // Both timestamps present
         let first = Some("2026-01-15T09:00:00+08:00".to_string());
         let last = Some("2026-01-15T11:00:00+08:00".to_string());
-        let hours = session_hours_from_options(&first, &last);
+        let hours = session_hours_from_options(&first, &last, None, DEFAULT_MAX_SESSION_HOURS);
         assert!((hours - 2.0).abs() < 0.1);
 
         // Missing first timestamp
-        let hours = session_hours_from_options(&None, &last);
+        let hours = session_hours_from_options(&None, &last, None, DEFAULT_MAX_SESSION_HOURS);
         assert!((hours - 0.5).abs() < 0.01);
 
         // Missing last timestamp
-        let hours = session_hours_from_options(&first, &None);
+        let hours = session_hours_from_options(&first, &None, None, DEFAULT_MAX_SESSION_HOURS);
         assert!((hours - 0.5).abs() < 0.01);
 
         // Both missing
-        let hours = session_hours_from_options(&None, &None);
+        let hours = session_hours_from_options(&None, &None, None, DEFAULT_MAX_SESSION_HOURS);
         assert!((hours - 0.5).abs() < 0.01);
+
+        // Offset-less timestamps are read in the user's timezone
+        let first = Some("2026-01-15T09:00:00.000".to_string());
+        let last = Some("2026-01-15T03:00:00Z".to_string());
+        let hours = session_hours_from_options(&first, &last, Some("Asia/Taipei"), DEFAULT_MAX_SESSION_HOURS);
+        assert!((hours - 2.0).abs() < 0.01);
+
+        // Long sessions are capped at the configured limit
+        let first = Some("2026-01-15T08:00:00+08:00".to_string());
+        let last = Some("2026-01-15T18:00:00+08:00".to_string());
+        assert_eq!(session_hours_from_options(&first, &last, None, DEFAULT_MAX_SESSION_HOURS), 8.0);
+        assert_eq!(session_hours_from_options(&first, &last, None, 12.0), 10.0);
     }
 }
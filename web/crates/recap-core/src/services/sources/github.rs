@@ -0,0 +1,615 @@
+//! GitHub Source Implementation
+//!
+//! Syncs merged pull requests and commits from the repositories a user tracks
+//! in `github_projects` to work items, using the GitHub REST API with the
+//! user's `github_pat`. Items are stored with `source = "github"` and the
+//! GitHub node id as `source_id`, and deduplicated on `(source, source_id)`.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use super::{compute_content_hash, SourceProject, SourceSyncResult, SyncSource};
+use crate::models::GitHubProject;
+use crate::services::worklog;
+
+/// `source` of work items synced from GitHub
+pub const GITHUB_SOURCE: &str = "github";
+
+/// API base used when the user has no `github_url` (GitHub Enterprise) set
+pub const DEFAULT_GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Repository information from the GitHub API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubRepoInfo {
+    pub id: i64,
+    pub name: String,
+    /// `owner/name`
+    pub full_name: String,
+    pub html_url: String,
+    pub default_branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubUser {
+    pub login: String,
+}
+
+/// Pull request from the GitHub API; the diff stats are only set on single-PR responses
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubPullRequest {
+    pub node_id: String,
+    pub number: i64,
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+    pub merged_at: Option<String>,
+    pub merge_commit_sha: Option<String>,
+    pub user: Option<GitHubUser>,
+    pub additions: Option<i32>,
+    pub deletions: Option<i32>,
+    pub changed_files: Option<usize>,
+}
+
+/// Commit from the GitHub API; stats and files are only set on single-commit responses
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommit {
+    pub sha: String,
+    pub node_id: String,
+    pub html_url: String,
+    pub commit: GitHubCommitDetail,
+    pub stats: Option<GitHubCommitStats>,
+    pub files: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitDetail {
+    pub message: String,
+    pub author: Option<GitHubCommitAuthor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitAuthor {
+    pub date: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitStats {
+    pub additions: i32,
+    pub deletions: i32,
+}
+
+/// Minimal GitHub REST API client authenticated with a personal access token
+pub struct GitHubClient {
+    http: reqwest::Client,
+    api_url: String,
+    token: String,
+}
+
+impl GitHubClient {
+    pub fn new(api_url: &str, token: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T, String> {
+        let response = self
+            .http
+            .get(format!("{}{}", self.api_url, path))
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            // GitHub rejects requests without a User-Agent
+            .header("User-Agent", "recap")
+            .query(query)
+            .send()
+            .await
+            .map_err(|e| format!("GitHub API error: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("GitHub API returned: {}", response.status()));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse GitHub response: {}", e))
+    }
+
+    /// Login of the token's owner
+    pub async fn authenticated_login(&self) -> Result<String, String> {
+        let user: GitHubUser = self.get("/user", &[]).await?;
+        Ok(user.login)
+    }
+
+    /// Repository by `owner/name`
+    pub async fn get_repo(&self, full_name: &str) -> Result<GitHubRepoInfo, String> {
+        self.get(&format!("/repos/{}", full_name), &[]).await
+    }
+
+    /// Repositories the token's owner can access, most recently updated first
+    pub async fn list_repos(&self) -> Result<Vec<GitHubRepoInfo>, String> {
+        self.get("/user/repos", &[("per_page", "100"), ("sort", "updated")]).await
+    }
+
+    /// Recently closed pull requests by `author` that were merged
+    pub async fn merged_pull_requests(&self, full_name: &str, author: &str) -> Result<Vec<GitHubPullRequest>, String> {
+        let pulls: Vec<GitHubPullRequest> = self
+            .get(
+                &format!("/repos/{}/pulls", full_name),
+                &[("state", "closed"), ("sort", "updated"), ("direction", "desc"), ("per_page", "100")],
+            )
+            .await?;
+        Ok(pulls
+            .into_iter()
+            .filter(|pr| pr.merged_at.is_some())
+            .filter(|pr| pr.user.as_ref().is_some_and(|u| u.login.eq_ignore_ascii_case(author)))
+            .collect())
+    }
+
+    /// Single pull request, including its diff stats
+    pub async fn pull_request(&self, full_name: &str, number: i64) -> Result<GitHubPullRequest, String> {
+        self.get(&format!("/repos/{}/pulls/{}", full_name, number), &[]).await
+    }
+
+    /// Recent commits by `author` on the default branch
+    pub async fn commits(&self, full_name: &str, author: &str) -> Result<Vec<GitHubCommit>, String> {
+        self.get(
+            &format!("/repos/{}/commits", full_name),
+            &[("author", author), ("per_page", "100")],
+        )
+        .await
+    }
+
+    /// Single commit, including its stats and files
+    pub async fn commit(&self, full_name: &str, sha: &str) -> Result<GitHubCommit, String> {
+        self.get(&format!("/repos/{}/commits/{}", full_name, sha), &[]).await
+    }
+}
+
+/// The user's GitHub API base and personal access token
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitHubConfig {
+    pub api_url: String,
+    pub pat: String,
+}
+
+/// GitHub settings for `user_id`, or `None` if no PAT is configured
+pub async fn get_github_config(pool: &SqlitePool, user_id: &str) -> Result<Option<GitHubConfig>, String> {
+    let row: Option<(Option<String>, Option<String>)> =
+        sqlx::query_as("SELECT github_url, github_pat FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+    Ok(row.and_then(|(url, pat)| {
+        let pat = pat.filter(|p| !p.trim().is_empty())?;
+        let api_url = url
+            .filter(|u| !u.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_GITHUB_API_URL.to_string());
+        Some(GitHubConfig { api_url, pat })
+    }))
+}
+
+/// A merged pull request or commit, ready to insert as a work item
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitHubWorkItem {
+    /// GitHub node id
+    pub source_id: String,
+    pub source_url: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub hours: f64,
+    pub date: String,
+    /// Short hash of the commit (or the PR's merge commit), for cross-source dedup
+    pub commit_hash: Option<String>,
+}
+
+impl GitHubWorkItem {
+    /// Work item for a merged pull request; `None` if it was not merged
+    pub fn from_pull_request(pr: &GitHubPullRequest) -> Option<Self> {
+        let merged_at = pr.merged_at.as_deref()?;
+        let hours = worklog::estimate_from_diff(
+            pr.additions.unwrap_or(0),
+            pr.deletions.unwrap_or(0),
+            pr.changed_files.unwrap_or(1),
+        );
+        Some(Self {
+            source_id: pr.node_id.clone(),
+            source_url: pr.html_url.clone(),
+            title: format!("{} (#{})", pr.title, pr.number),
+            description: pr.body.clone().filter(|b| !b.trim().is_empty()),
+            hours,
+            date: merged_at.split('T').next().unwrap_or(merged_at).to_string(),
+            // A squash or merge commit on the default branch is the same work
+            commit_hash: pr.merge_commit_sha.as_ref().map(|sha| short_hash(sha)),
+        })
+    }
+
+    /// Work item for a commit
+    pub fn from_commit(commit: &GitHubCommit) -> Self {
+        let message = commit.commit.message.trim();
+        let title = message.lines().next().unwrap_or_default().to_string();
+        let (additions, deletions) = commit
+            .stats
+            .as_ref()
+            .map(|s| (s.additions, s.deletions))
+            .unwrap_or((0, 0));
+        let files = commit.files.as_ref().map_or(1, Vec::len);
+        let date = commit
+            .commit
+            .author
+            .as_ref()
+            .and_then(|a| a.date.split('T').next())
+            .map(str::to_string)
+            .unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string());
+
+        Self {
+            source_id: commit.node_id.clone(),
+            source_url: commit.html_url.clone(),
+            title,
+            description: (message.lines().count() > 1).then(|| message.to_string()),
+            hours: worklog::estimate_from_diff(additions, deletions, files),
+            date,
+            commit_hash: Some(short_hash(&commit.sha)),
+        }
+    }
+}
+
+fn short_hash(sha: &str) -> String {
+    sha.chars().take(8).collect()
+}
+
+/// GitHub node ids in `ids` that already have a work item
+async fn existing_source_ids(pool: &SqlitePool, user_id: &str, ids: &[&str]) -> Result<HashSet<String>, String> {
+    if ids.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let placeholders = vec!["?"; ids.len()].join(",");
+    let query = format!(
+        "SELECT source_id FROM work_items WHERE source = ? AND source_id IN ({}) AND user_id = ?",
+        placeholders
+    );
+    let mut q = sqlx::query_scalar::<_, String>(&query).bind(GITHUB_SOURCE);
+    for id in ids {
+        q = q.bind(*id);
+    }
+    let found = q.bind(user_id).fetch_all(pool).await.map_err(|e| e.to_string())?;
+    Ok(found.into_iter().collect())
+}
+
+/// Short commit hashes in `hashes` already recorded by any source
+async fn existing_commit_hashes(pool: &SqlitePool, user_id: &str, hashes: &[&str]) -> Result<HashSet<String>, String> {
+    if hashes.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let placeholders = vec!["?"; hashes.len()].join(",");
+    let query = format!(
+        "SELECT commit_hash FROM work_items WHERE commit_hash IN ({}) AND user_id = ?",
+        placeholders
+    );
+    let mut q = sqlx::query_scalar::<_, String>(&query);
+    for hash in hashes {
+        q = q.bind(*hash);
+    }
+    let found = q.bind(user_id).fetch_all(pool).await.map_err(|e| e.to_string())?;
+    Ok(found.into_iter().collect())
+}
+
+/// Insert `items` as GitHub work items, skipping any whose node id or commit
+/// hash is already recorded. Returns the number of items created.
+pub async fn insert_github_items(
+    pool: &SqlitePool,
+    user_id: &str,
+    items: Vec<GitHubWorkItem>,
+) -> Result<usize, String> {
+    let ids: Vec<&str> = items.iter().map(|i| i.source_id.as_str()).collect();
+    let mut seen_ids = existing_source_ids(pool, user_id, &ids).await?;
+    let hashes: Vec<&str> = items.iter().filter_map(|i| i.commit_hash.as_deref()).collect();
+    let mut seen_hashes = existing_commit_hashes(pool, user_id, &hashes).await?;
+
+    let mut created = 0;
+    for item in items {
+        let duplicate_hash = item.commit_hash.as_ref().is_some_and(|h| seen_hashes.contains(h));
+        if seen_ids.contains(&item.source_id) || duplicate_hash {
+            continue;
+        }
+
+        let now = Utc::now();
+        let content_hash = compute_content_hash(user_id, GITHUB_SOURCE, &item.source_id, &item.title, &item.date);
+        let result = sqlx::query(
+            r#"
+            INSERT INTO work_items (id, user_id, source, source_id, source_url, title,
+                description, hours, date, content_hash, hours_source, hours_estimated, commit_hash,
+                created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'heuristic', ?, ?, ?, ?)
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(user_id)
+        .bind(GITHUB_SOURCE)
+        .bind(&item.source_id)
+        .bind(&item.source_url)
+        .bind(&item.title)
+        .bind(&item.description)
+        .bind(item.hours)
+        .bind(&item.date)
+        .bind(&content_hash)
+        .bind(item.hours)
+        .bind(&item.commit_hash)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await;
+
+        if let Err(e) = result {
+            log::warn!("Failed to insert GitHub item {}: {}", item.source_id, e);
+            continue;
+        }
+
+        seen_ids.insert(item.source_id);
+        if let Some(hash) = item.commit_hash {
+            seen_hashes.insert(hash);
+        }
+        created += 1;
+    }
+
+    Ok(created)
+}
+
+/// Counts from a GitHub sync
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitHubSyncResult {
+    pub projects_scanned: usize,
+    pub synced_pull_requests: i64,
+    pub synced_commits: i64,
+    pub work_items_created: i64,
+}
+
+/// Sync merged pull requests and commits of the user's enabled GitHub projects,
+/// or only `project_id` if given.
+///
+/// Only the token owner's PRs and commits are synced. A project that fails is
+/// logged and skipped so the others still sync.
+pub async fn sync_github_projects(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_id: Option<&str>,
+) -> Result<GitHubSyncResult, String> {
+    let config = get_github_config(pool, user_id)
+        .await?
+        .ok_or("GitHub PAT not configured")?;
+
+    let projects: Vec<GitHubProject> = match project_id {
+        Some(id) => sqlx::query_as("SELECT * FROM github_projects WHERE id = ? AND user_id = ? AND enabled = 1")
+            .bind(id)
+            .bind(user_id)
+            .fetch_all(pool)
+            .await,
+        None => sqlx::query_as("SELECT * FROM github_projects WHERE user_id = ? AND enabled = 1")
+            .bind(user_id)
+            .fetch_all(pool)
+            .await,
+    }
+    .map_err(|e| e.to_string())?;
+
+    let client = GitHubClient::new(&config.api_url, &config.pat);
+    let login = client.authenticated_login().await?;
+    let mut result = GitHubSyncResult {
+        projects_scanned: projects.len(),
+        ..Default::default()
+    };
+
+    for project in projects {
+        match sync_github_project(pool, user_id, &client, &login, &project).await {
+            Ok((pull_requests, commits)) => {
+                result.synced_pull_requests += pull_requests as i64;
+                result.synced_commits += commits as i64;
+                result.work_items_created += (pull_requests + commits) as i64;
+            }
+            Err(e) => {
+                log::warn!("Failed to sync GitHub project {}: {}", project.full_name, e);
+                continue;
+            }
+        }
+
+        if let Err(e) = sqlx::query("UPDATE github_projects SET last_synced = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(&project.id)
+            .execute(pool)
+            .await
+        {
+            log::warn!("Failed to update last_synced for project {}: {}", project.id, e);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sync one project, returning the number of PR and commit work items created
+async fn sync_github_project(
+    pool: &SqlitePool,
+    user_id: &str,
+    client: &GitHubClient,
+    login: &str,
+    project: &GitHubProject,
+) -> Result<(usize, usize), String> {
+    // PRs first, so their merge commits dedup the matching default-branch commits
+    let pulls = client.merged_pull_requests(&project.full_name, login).await?;
+    let ids: Vec<&str> = pulls.iter().map(|pr| pr.node_id.as_str()).collect();
+    let known = existing_source_ids(pool, user_id, &ids).await?;
+
+    let mut pr_items = Vec::new();
+    for pr in pulls.iter().filter(|pr| !known.contains(&pr.node_id)) {
+        // The list endpoint has no diff stats
+        let detail = client.pull_request(&project.full_name, pr.number).await?;
+        pr_items.extend(GitHubWorkItem::from_pull_request(&detail));
+    }
+    let pull_requests = insert_github_items(pool, user_id, pr_items).await?;
+
+    let commits = client.commits(&project.full_name, login).await?;
+    let ids: Vec<&str> = commits.iter().map(|c| c.node_id.as_str()).collect();
+    let known = existing_source_ids(pool, user_id, &ids).await?;
+    let hashes: Vec<String> = commits.iter().map(|c| short_hash(&c.sha)).collect();
+    let hash_refs: Vec<&str> = hashes.iter().map(String::as_str).collect();
+    let known_hashes = existing_commit_hashes(pool, user_id, &hash_refs).await?;
+
+    let mut commit_items = Vec::new();
+    for commit in commits
+        .iter()
+        .filter(|c| !known.contains(&c.node_id) && !known_hashes.contains(&short_hash(&c.sha)))
+    {
+        let detail = client.commit(&project.full_name, &commit.sha).await?;
+        commit_items.push(GitHubWorkItem::from_commit(&detail));
+    }
+    let commits = insert_github_items(pool, user_id, commit_items).await?;
+
+    Ok((pull_requests, commits))
+}
+
+/// GitHub data source
+///
+/// Syncs merged pull requests and commits from the repositories tracked in
+/// `github_projects`. Users without a GitHub PAT are skipped.
+pub struct GitHubSource;
+
+impl GitHubSource {
+    /// Create a new GitHub source
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GitHubSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SyncSource for GitHubSource {
+    fn source_name(&self) -> &'static str {
+        GITHUB_SOURCE
+    }
+
+    fn display_name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    async fn discover_projects(&self) -> Result<Vec<SourceProject>, String> {
+        // Repositories are tracked per user in `github_projects`, not discovered
+        Ok(Vec::new())
+    }
+
+    async fn sync_sessions(
+        &self,
+        pool: &SqlitePool,
+        user_id: &str,
+    ) -> Result<SourceSyncResult, String> {
+        let mut result = SourceSyncResult::new(self.source_name());
+        if get_github_config(pool, user_id).await?.is_none() {
+            return Ok(result);
+        }
+
+        let synced = sync_github_projects(pool, user_id, None).await?;
+        result.projects_scanned = synced.projects_scanned;
+        result.sessions_processed = (synced.synced_pull_requests + synced.synced_commits) as usize;
+        result.work_items_created = synced.work_items_created as usize;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    fn pull_request() -> GitHubPullRequest {
+        serde_json::from_value(serde_json::json!({
+            "node_id": "PR_kwDOAbc123",
+            "number": 42,
+            "title": "Add GitHub source",
+            "body": "Syncs merged PRs",
+            "html_url": "https://github.com/acme/recap/pull/42",
+            "merged_at": "2026-03-02T15:04:05Z",
+            "merge_commit_sha": "0123456789abcdef",
+            "user": { "login": "dev" },
+            "additions": 120,
+            "deletions": 30,
+            "changed_files": 4
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pull_request_maps_to_work_item() {
+        let item = GitHubWorkItem::from_pull_request(&pull_request()).unwrap();
+        assert_eq!(item.source_id, "PR_kwDOAbc123");
+        assert_eq!(item.source_url, "https://github.com/acme/recap/pull/42");
+        assert_eq!(item.title, "Add GitHub source (#42)");
+        assert_eq!(item.date, "2026-03-02");
+        assert_eq!(item.commit_hash.as_deref(), Some("01234567"));
+        assert_eq!(item.hours, worklog::estimate_from_diff(120, 30, 4));
+
+        let unmerged = GitHubPullRequest { merged_at: None, ..pull_request() };
+        assert!(GitHubWorkItem::from_pull_request(&unmerged).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_insert_github_items_dedups_on_source_id_and_commit_hash() {
+        let (db, _dir) = db_with_user().await;
+        let pr = GitHubWorkItem::from_pull_request(&pull_request()).unwrap();
+
+        assert_eq!(insert_github_items(&db.pool, "u1", vec![pr.clone()]).await.unwrap(), 1);
+        assert_eq!(insert_github_items(&db.pool, "u1", vec![pr.clone()]).await.unwrap(), 0);
+
+        // The PR's merge commit on the default branch is the same work
+        let merge_commit = GitHubWorkItem {
+            source_id: "C_kwDOAbc999".to_string(),
+            commit_hash: pr.commit_hash.clone(),
+            ..pr.clone()
+        };
+        let direct_push = GitHubWorkItem {
+            source_id: "C_kwDOAbc777".to_string(),
+            commit_hash: Some("fedcba98".to_string()),
+            ..pr
+        };
+        let created = insert_github_items(&db.pool, "u1", vec![merge_commit, direct_push]).await.unwrap();
+        assert_eq!(created, 1);
+
+        let sources: Vec<(String, String)> =
+            sqlx::query_as("SELECT source, source_id FROM work_items WHERE user_id = 'u1' ORDER BY source_id")
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            sources,
+            vec![
+                ("github".to_string(), "C_kwDOAbc777".to_string()),
+                ("github".to_string(), "PR_kwDOAbc123".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_github_config_requires_pat() {
+        let (db, _dir) = db_with_user().await;
+        assert_eq!(get_github_config(&db.pool, "u1").await.unwrap(), None);
+
+        sqlx::query("UPDATE users SET github_pat = 'ghp_test' WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let config = get_github_config(&db.pool, "u1").await.unwrap().unwrap();
+        assert_eq!(config.api_url, DEFAULT_GITHUB_API_URL);
+        assert_eq!(config.pat, "ghp_test");
+    }
+}
@@ -24,7 +24,7 @@
 //!     ┌────┴────┐
 //!     ▼         ▼
 //! ┌──────┐  ┌──────┐
-//! │Claude│  │ Git  │
+//! │Claude│  │GitHub│
 //! └──────┘  └──────┘
 //! ```
 //!
@@ -32,17 +32,25 @@
 //!
 //! 1. Create a new module (e.g., `git.rs`)
 //! 2. Implement the `SyncSource` trait
-//! 3. Add it to the registry in `registry.rs`
+//! 3. Add it to the built-ins in `registry.rs`, or call
+//!    `register_source` at startup for a source defined outside this crate
 
 pub mod types;
 pub mod work_item;
 pub mod claude;
+pub mod github;
 pub mod registry;
+pub mod purge;
 
-pub use types::{SourceProject, SourceSyncResult, WorkItemParams};
-pub use work_item::{upsert_work_item, UpsertResult};
+pub use types::{ProjectSyncResult, SourceProject, SourceSyncResult, WorkItemParams, CO_AUTHORED_TAG};
+pub use work_item::{compute_content_hash, upsert_work_item, UpsertResult};
 pub use claude::ClaudeSource;
-pub use registry::{get_enabled_sources, SyncConfig};
+pub use github::{
+    get_github_config, sync_github_projects, GitHubClient, GitHubConfig, GitHubRepoInfo, GitHubSource,
+    GitHubSyncResult,
+};
+pub use registry::{get_enabled_sources, register_source, SyncConfig};
+pub use purge::{purge_git_repo_items, purge_github_project_items, purge_gitlab_project_items};
 
 use async_trait::async_trait;
 use sqlx::SqlitePool;
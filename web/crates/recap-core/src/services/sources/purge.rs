@@ -0,0 +1,90 @@
+//! Source Work Item Purge
+//!
+//! Deletes the work items that originated from a data source when that source
+//! is removed. Callers run these inside the same transaction that deletes the
+//! source row, so either both are removed or neither is.
+
+use sqlx::SqliteConnection;
+
+/// Work item sources produced by local git repositories
+const GIT_ITEM_SOURCES: [&str; 2] = ["git", "commit"];
+
+/// Delete work items synced from a local git repository.
+///
+/// Matches items whose `project_path` is the repo path or a subdirectory of it.
+/// Returns the number of deleted items.
+pub async fn purge_git_repo_items(
+    conn: &mut SqliteConnection,
+    user_id: &str,
+    repo_path: &str,
+) -> Result<u64, String> {
+    let repo_path = repo_path.trim_end_matches('/');
+    let subdir_prefix = format!("{}/", repo_path);
+    let result = sqlx::query(
+        r#"DELETE FROM work_items
+           WHERE user_id = ? AND source IN (?, ?)
+             AND (project_path = ? OR substr(project_path, 1, ?) = ?)"#,
+    )
+    .bind(user_id)
+    .bind(GIT_ITEM_SOURCES[0])
+    .bind(GIT_ITEM_SOURCES[1])
+    .bind(repo_path)
+    .bind(subdir_prefix.chars().count() as i64)
+    .bind(&subdir_prefix)
+    .execute(conn)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(result.rows_affected())
+}
+
+/// Delete work items synced from a GitHub repository.
+///
+/// GitHub items link to a pull request or commit under the repo's
+/// `html_url`. Returns the number of deleted items.
+pub async fn purge_github_project_items(
+    conn: &mut SqliteConnection,
+    user_id: &str,
+    html_url: &str,
+) -> Result<u64, String> {
+    let prefix = format!("{}/", html_url.trim_end_matches('/'));
+    let result = sqlx::query(
+        "DELETE FROM work_items WHERE user_id = ? AND source = 'github' AND substr(source_url, 1, ?) = ?",
+    )
+    .bind(user_id)
+    .bind(prefix.chars().count() as i64)
+    .bind(&prefix)
+    .execute(conn)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(result.rows_affected())
+}
+
+/// Delete work items synced from a GitLab project.
+///
+/// GitLab items are identified by their `source_url`, which is built as
+/// `{gitlab_url}/{path_with_namespace}/-/...`. Returns the number of deleted items.
+pub async fn purge_gitlab_project_items(
+    conn: &mut SqliteConnection,
+    user_id: &str,
+    gitlab_url: &str,
+    path_with_namespace: &str,
+) -> Result<u64, String> {
+    let prefix = format!(
+        "{}/{}/-/",
+        gitlab_url.trim_end_matches('/'),
+        path_with_namespace.trim_matches('/')
+    );
+    let result = sqlx::query(
+        "DELETE FROM work_items WHERE user_id = ? AND source = 'gitlab' AND substr(source_url, 1, ?) = ?",
+    )
+    .bind(user_id)
+    .bind(prefix.chars().count() as i64)
+    .bind(&prefix)
+    .execute(conn)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(result.rows_affected())
+}
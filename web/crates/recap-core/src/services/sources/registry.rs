@@ -2,10 +2,23 @@
 //!
 //! This module provides a registry of available sync sources and functions
 //! to get enabled sources based on configuration.
+//!
+//! The registry starts with the built-in sources; more can be added at
+//! runtime with [`register_source`].
+//!
+//! # Thread safety
+//!
+//! The registry is process-wide and guarded by a lock, so sources can be
+//! registered from any thread. Registered instances are shared (`Arc`) by
+//! every sync run that starts afterwards, possibly concurrently, so a source
+//! must not rely on exclusive access to its own state. Runs already in
+//! progress keep the sources they started with; register before starting
+//! background sync for a source to take part in the first run.
 
 use std::collections::HashSet;
+use std::sync::{Arc, OnceLock, RwLock};
 
-use super::{SyncSource, ClaudeSource};
+use super::{SyncSource, ClaudeSource, GitHubSource};
 
 /// Configuration for which sources to sync
 #[derive(Debug, Clone, Default)]
@@ -78,14 +91,38 @@ impl SyncConfig {
     }
 }
 
+/// Registered sources in registration order, built-ins first
+fn registry() -> &'static RwLock<Vec<Arc<dyn SyncSource>>> {
+    static REGISTRY: OnceLock<RwLock<Vec<Arc<dyn SyncSource>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        RwLock::new(vec![
+            Arc::new(ClaudeSource::new()),
+            Arc::new(GitHubSource::new()),
+        ])
+    })
+}
+
+/// Register an additional sync source
+///
+/// The source syncs whenever its `source_name` is enabled in the
+/// [`SyncConfig`] and it reports itself available. Fails if a source with
+/// the same name is already registered.
+pub fn register_source(source: impl SyncSource + 'static) -> Result<(), String> {
+    let mut sources = registry().write().unwrap_or_else(|e| e.into_inner());
+    let name = source.source_name();
+    if sources.iter().any(|s| s.source_name() == name) {
+        return Err(format!("Sync source '{}' is already registered", name));
+    }
+    sources.push(Arc::new(source));
+    Ok(())
+}
+
 /// Get all registered sync sources
 ///
-/// Returns all available sync sources regardless of whether they are enabled
+/// Returns all registered sync sources regardless of whether they are enabled
 /// or currently available.
-pub fn get_all_sources() -> Vec<Box<dyn SyncSource>> {
-    vec![
-        Box::new(ClaudeSource::new()),
-    ]
+pub fn get_all_sources() -> Vec<Arc<dyn SyncSource>> {
+    registry().read().unwrap_or_else(|e| e.into_inner()).clone()
 }
 
 /// Get enabled sources based on configuration
@@ -95,35 +132,30 @@ pub fn get_all_sources() -> Vec<Box<dyn SyncSource>> {
 /// 2. Currently available
 ///
 /// This is the main entry point for background sync to get sources to sync.
-pub async fn get_enabled_sources(config: &SyncConfig) -> Vec<Box<dyn SyncSource>> {
-    let mut sources: Vec<Box<dyn SyncSource>> = Vec::new();
+pub async fn get_enabled_sources(config: &SyncConfig) -> Vec<Arc<dyn SyncSource>> {
+    // Snapshot first so the lock isn't held across availability checks
+    let candidates: Vec<Arc<dyn SyncSource>> = get_all_sources()
+        .into_iter()
+        .filter(|source| config.is_source_enabled(source.source_name()))
+        .collect();
 
-    if config.is_source_enabled("claude_code") {
-        let source = ClaudeSource::new();
+    let mut sources = Vec::with_capacity(candidates.len());
+    for source in candidates {
         if source.is_available().await {
-            sources.push(Box::new(source));
+            sources.push(source);
         }
     }
-
-    // Future sources can be added here:
-    // if config.is_source_enabled("git") {
-    //     sources.push(Box::new(GitSource::new()));
-    // }
-
     sources
 }
 
 /// Get source by name
-pub fn get_source_by_name(name: &str) -> Option<Box<dyn SyncSource>> {
-    match name {
-        "claude_code" => Some(Box::new(ClaudeSource::new())),
-        _ => None,
-    }
+pub fn get_source_by_name(name: &str) -> Option<Arc<dyn SyncSource>> {
+    get_all_sources().into_iter().find(|s| s.source_name() == name)
 }
 
 /// Get all registered source names
 pub fn get_source_names() -> Vec<&'static str> {
-    vec!["claude_code"]
+    get_all_sources().iter().map(|s| s.source_name()).collect()
 }
 
 #[cfg(test)]
@@ -170,11 +202,13 @@ mod tests {
 
     #[test]
     fn test_get_all_sources() {
+        // Other tests may register extra sources; the built-ins come first
         let sources = get_all_sources();
-        assert_eq!(sources.len(), 1);
+        assert!(sources.len() >= 2);
 
         let names: Vec<_> = sources.iter().map(|s| s.source_name()).collect();
         assert!(names.contains(&"claude_code"));
+        assert!(names.contains(&"github"));
     }
 
     #[test]
@@ -192,4 +226,52 @@ mod tests {
         let names = get_source_names();
         assert!(names.contains(&"claude_code"));
     }
+
+    use crate::services::sources::{SourceProject, SourceSyncResult};
+    use async_trait::async_trait;
+    use sqlx::SqlitePool;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockSource {
+        syncs: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl SyncSource for MockSource {
+        fn source_name(&self) -> &'static str {
+            "mock_registry"
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Mock"
+        }
+
+        async fn discover_projects(&self) -> Result<Vec<SourceProject>, String> {
+            Ok(Vec::new())
+        }
+
+        async fn sync_sessions(&self, _pool: &SqlitePool, _user_id: &str) -> Result<SourceSyncResult, String> {
+            self.syncs.fetch_add(1, Ordering::SeqCst);
+            let mut result = SourceSyncResult::new(self.source_name());
+            result.work_items_created = 1;
+            Ok(result)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_source_is_enabled_by_name() {
+        let syncs = Arc::new(AtomicUsize::new(0));
+        register_source(MockSource { syncs: syncs.clone() }).unwrap();
+        assert!(register_source(MockSource { syncs: syncs.clone() }).is_err());
+        assert!(get_source_names().contains(&"mock_registry"));
+
+        let mut config = SyncConfig::default();
+        assert!(get_enabled_sources(&config).await.is_empty());
+
+        config.enable_source("mock_registry");
+        let enabled = get_enabled_sources(&config).await;
+        let names: Vec<_> = enabled.iter().map(|s| s.source_name()).collect();
+        assert_eq!(names, ["mock_registry"]);
+        assert_eq!(syncs.load(Ordering::SeqCst), 0);
+    }
 }
@@ -4,6 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Tag applied to work items from commits the user co-authored
+pub const CO_AUTHORED_TAG: &str = "co-authored";
+
 /// A discovered project from a data source
 #[derive(Debug, Clone)]
 pub struct SourceProject {
@@ -32,6 +35,35 @@ pub struct SourceSyncResult {
     pub work_items_updated: usize,
     /// Error message if sync failed
     pub error: Option<String>,
+    /// Per-project breakdown of the counts above
+    #[serde(default)]
+    pub per_project: Vec<ProjectSyncResult>,
+}
+
+/// Work item counts for a single project within a sync
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSyncResult {
+    /// Project display name
+    pub project: String,
+    /// Canonical project path (git root)
+    pub project_path: String,
+    /// Number of new work items created
+    pub created: usize,
+    /// Number of existing work items updated
+    pub updated: usize,
+    /// Number of sessions skipped
+    pub skipped: usize,
+}
+
+impl ProjectSyncResult {
+    /// Create an empty result for a project
+    pub fn new(project: &str, project_path: &str) -> Self {
+        Self {
+            project: project.to_string(),
+            project_path: project_path.to_string(),
+            ..Default::default()
+        }
+    }
 }
 
 impl SourceSyncResult {
@@ -78,6 +110,8 @@ pub struct WorkItemParams {
     pub start_time: Option<String>,
     /// End time (ISO 8601)
     pub end_time: Option<String>,
+    /// Tags (stored as a JSON array)
+    pub tags: Option<Vec<String>>,
 }
 
 impl WorkItemParams {
@@ -102,9 +136,53 @@ impl WorkItemParams {
             session_id: None,
             start_time: None,
             end_time: None,
+            tags: None,
         }
     }
 
+    /// Create params for a git commit.
+    ///
+    /// Co-authored commits are tagged `co-authored` so reports can show collaboration.
+    pub fn from_commit(
+        user_id: impl Into<String>,
+        repo_path: impl Into<String>,
+        commit: &crate::services::worklog::CommitRecord,
+    ) -> Self {
+        let params = Self::new(
+            user_id,
+            "git",
+            commit.hash.clone(),
+            commit.message.clone(),
+            commit.hours,
+            commit.date.clone(),
+        )
+        .with_project_path(repo_path)
+        .with_time_range(Some(commit.time.clone()), None);
+
+        if commit.co_authored {
+            params.with_tags(vec![CO_AUTHORED_TAG.to_string()])
+        } else {
+            params
+        }
+    }
+
+    /// Check that the params describe a storable work item.
+    ///
+    /// Rejects empty titles, negative (or non-finite) hours and dates that
+    /// are not in YYYY-MM-DD format.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.title.trim().is_empty() {
+            return Err("Work item title must not be empty".to_string());
+        }
+        if !self.hours.is_finite() || self.hours < 0.0 {
+            return Err(format!("Work item hours must be non-negative, got {}", self.hours));
+        }
+        if chrono::NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").is_err() {
+            return Err(format!("Invalid work item date: {:?}. Use YYYY-MM-DD", self.date));
+        }
+        Ok(())
+    }
+
     /// Set description
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -133,6 +211,12 @@ impl WorkItemParams {
         self.end_time = end_time;
         self
     }
+
+    /// Set tags
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -181,4 +265,42 @@ mod tests {
         assert!(params.start_time.is_some());
         assert!(params.end_time.is_some());
     }
+
+    fn valid_params() -> WorkItemParams {
+        WorkItemParams::new("user123", "claude_code", "session-abc", "Valid item", 1.5, "2026-01-15")
+    }
+
+    #[test]
+    fn test_work_item_params_validate_ok() {
+        assert!(valid_params().validate().is_ok());
+
+        let zero_hours = WorkItemParams { hours: 0.0, ..valid_params() };
+        assert!(zero_hours.validate().is_ok());
+    }
+
+    #[test]
+    fn test_work_item_params_validate_empty_title() {
+        let params = WorkItemParams { title: "   ".to_string(), ..valid_params() };
+        let err = params.validate().unwrap_err();
+        assert!(err.contains("title"));
+    }
+
+    #[test]
+    fn test_work_item_params_validate_negative_hours() {
+        let params = WorkItemParams { hours: -1.0, ..valid_params() };
+        let err = params.validate().unwrap_err();
+        assert!(err.contains("hours"));
+
+        let nan = WorkItemParams { hours: f64::NAN, ..valid_params() };
+        assert!(nan.validate().is_err());
+    }
+
+    #[test]
+    fn test_work_item_params_validate_bad_date() {
+        for date in ["", "2026/01/15", "2026-13-01", "unknown"] {
+            let params = WorkItemParams { date: date.to_string(), ..valid_params() };
+            let err = params.validate().unwrap_err();
+            assert!(err.contains("date"), "expected date error for {:?}", date);
+        }
+    }
 }
@@ -5,6 +5,7 @@
 //! user modifications.
 
 use chrono::Utc;
+use sha2::{Digest, Sha256};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
@@ -42,29 +43,40 @@ impl UpsertResult {
     }
 }
 
-/// Generate a content hash for session-based deduplication.
+/// Content hash that deduplicates work items across every source.
 ///
-/// Uses user_id + session_id for uniqueness, as session_id is a UUID
-/// and already globally unique.
-fn generate_session_hash(user_id: &str, session_id: &str) -> String {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-
-    let mut hasher = DefaultHasher::new();
-    format!("session:{}:{}", user_id, session_id).hash(&mut hasher);
-    format!("sess_{:x}", hasher.finish())
+/// Returns the lowercase hex SHA-256 of the fields joined with the ASCII
+/// unit separator (`\x1f`):
+///
+/// - with a `source_id`: `user_id \x1f source \x1f source_id`
+/// - without one: `user_id \x1f source \x1f \x1f title \x1f date`
+///
+/// `source_id` and `title` are trimmed and `date` is `YYYY-MM-DD`. The ID is
+/// unique within its source (a session UUID, a commit SHA), so an item that
+/// has one keeps its hash when its title changes; title and date only
+/// identify items without an ID, such as imported rows. Session-based items
+/// pass their session ID as `source_id`.
+pub fn compute_content_hash(user_id: &str, source: &str, source_id: &str, title: &str, date: &str) -> String {
+    let source_id = source_id.trim();
+    let key = if source_id.is_empty() {
+        format!("{}\x1f{}\x1f\x1f{}\x1f{}", user_id, source, title.trim(), date)
+    } else {
+        format!("{}\x1f{}\x1f{}", user_id, source, source_id)
+    };
+    format!("{:x}", Sha256::digest(key.as_bytes()))
 }
 
-/// Find an existing work item by content hash or session_id fallback.
+/// Find an existing work item by content hash, then by session_id, then by source_id.
 ///
-/// This handles the transition from old hashes to new hashes by also
-/// checking session_id for backward compatibility.
+/// The fallbacks match rows stored under an older hash (or none), which then
+/// get the current hash on update.
 async fn find_existing_work_item(
     pool: &SqlitePool,
     user_id: &str,
     content_hash: &str,
     session_id: Option<&str>,
     source: &str,
+    source_id: &str,
 ) -> Result<Option<(String, Option<String>, Option<String>)>, String> {
     // First try: exact content_hash match
     let existing: Option<(String, Option<String>, Option<String>)> = sqlx::query_as(
@@ -95,6 +107,21 @@ async fn find_existing_work_item(
         return Ok(fallback);
     }
 
+    // Third try: the ID is unique within its source (e.g. a commit SHA)
+    if !source_id.trim().is_empty() {
+        let fallback: Option<(String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT id, hours_source, content_hash FROM work_items WHERE source_id = ? AND source = ? AND user_id = ?",
+        )
+        .bind(source_id)
+        .bind(source)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        return Ok(fallback);
+    }
+
     Ok(None)
 }
 
@@ -116,16 +143,19 @@ async fn find_existing_work_item(
 /// * `Ok(UpsertResult::Created(id))` - A new work item was created
 /// * `Ok(UpsertResult::Updated(id))` - An existing work item was updated
 /// * `Ok(UpsertResult::Skipped(id))` - The work item already exists unchanged
-/// * `Err(String)` - An error occurred
+/// * `Err(String)` - The params failed validation or a database error occurred
 pub async fn upsert_work_item(
     pool: &SqlitePool,
     params: WorkItemParams,
 ) -> Result<UpsertResult, String> {
-    // Generate content hash based on session_id if available, otherwise use source_id
+    params.validate()?;
+
+    // Session-based items are identified by their session, others by source_id
     let hash_key = params.session_id.as_deref().unwrap_or(&params.source_id);
-    let content_hash = generate_session_hash(&params.user_id, hash_key);
+    let content_hash =
+        compute_content_hash(&params.user_id, &params.source, hash_key, &params.title, &params.date);
 
-    let now = Utc::now();
+    let tags_json = params.tags.as_ref().map(|t| serde_json::to_string(t).unwrap_or_default());
 
     // Check if work item already exists
     let existing = find_existing_work_item(
@@ -134,6 +164,7 @@ pub async fn upsert_work_item(
         &content_hash,
         params.session_id.as_deref(),
         &params.source,
+        &params.source_id,
     )
     .await?;
 
@@ -144,57 +175,26 @@ pub async fn upsert_work_item(
         // Preserve user-modified hours
         let user_modified = existing_hours_source.as_deref() == Some("user_modified");
 
-        if user_modified {
-            // Update without changing hours
-            sqlx::query(
-                r#"UPDATE work_items SET
-                   title = ?, description = ?, hours_estimated = ?,
-                   start_time = ?, end_time = ?, project_path = ?,
-                   session_id = ?, content_hash = ?, updated_at = ?
-                   WHERE id = ?"#,
-            )
-            .bind(&params.title)
-            .bind(&params.description)
-            .bind(params.hours)
-            .bind(&params.start_time)
-            .bind(&params.end_time)
-            .bind(&params.project_path)
-            .bind(&params.session_id)
-            .bind(&content_hash)
-            .bind(now)
-            .bind(&existing_id)
-            .execute(pool)
-            .await
-            .map_err(|e| e.to_string())?;
-        } else {
-            // Update including hours
-            sqlx::query(
-                r#"UPDATE work_items SET
-                   title = ?, description = ?, hours = ?, hours_source = 'session',
-                   hours_estimated = ?, start_time = ?, end_time = ?, project_path = ?,
-                   session_id = ?, content_hash = ?, updated_at = ?
-                   WHERE id = ?"#,
-            )
-            .bind(&params.title)
-            .bind(&params.description)
-            .bind(params.hours)
-            .bind(params.hours)
-            .bind(&params.start_time)
-            .bind(&params.end_time)
-            .bind(&params.project_path)
-            .bind(&params.session_id)
-            .bind(&content_hash)
-            .bind(now)
-            .bind(&existing_id)
-            .execute(pool)
-            .await
-            .map_err(|e| e.to_string())?;
+        let result =
+            update_work_item(pool, &existing_id, &params, &content_hash, tags_json.as_deref(), user_modified).await;
+        match result {
+            Ok(()) => {}
+            // Another item already holds the new hash; leave this one as it is
+            Err(e) if is_unique_violation(&e) => {
+                log::warn!(
+                    "Skipped hash migration of {} to {}: already used by another work item",
+                    existing_id,
+                    content_hash
+                );
+                return Ok(UpsertResult::Skipped(existing_id));
+            }
+            Err(e) => return Err(e.to_string()),
         }
 
         if needs_hash_migration {
             log::info!(
-                "Migrated hash for session {:?} from {:?} to {}",
-                params.session_id,
+                "Migrated hash for {:?} from {:?} to {}",
+                params.session_id.as_deref().unwrap_or(&params.source_id),
                 old_hash,
                 content_hash
             );
@@ -203,15 +203,28 @@ pub async fn upsert_work_item(
         return Ok(UpsertResult::Updated(existing_id));
     }
 
-    // Create new work item
+    insert_work_item(pool, &params, &content_hash, tags_json.as_deref()).await
+}
+
+/// Insert a new work item.
+///
+/// If a concurrent sync inserted the same content hash first, the unique index
+/// rejects the insert and the existing row is updated instead.
+async fn insert_work_item(
+    pool: &SqlitePool,
+    params: &WorkItemParams,
+    content_hash: &str,
+    tags_json: Option<&str>,
+) -> Result<UpsertResult, String> {
     let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
 
-    sqlx::query(
+    let result = sqlx::query(
         r#"INSERT INTO work_items
         (id, user_id, source, source_id, title, description, hours, date,
          content_hash, hours_source, hours_estimated, session_id,
-         start_time, end_time, project_path, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'session', ?, ?, ?, ?, ?, ?, ?)"#,
+         start_time, end_time, project_path, tags, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'session', ?, ?, ?, ?, ?, ?, ?, ?)"#,
     )
     .bind(&id)
     .bind(&params.user_id)
@@ -221,50 +234,166 @@ pub async fn upsert_work_item(
     .bind(&params.description)
     .bind(params.hours)
     .bind(&params.date)
-    .bind(&content_hash)
+    .bind(content_hash)
     .bind(params.hours)
     .bind(&params.session_id)
     .bind(&params.start_time)
     .bind(&params.end_time)
     .bind(&params.project_path)
+    .bind(tags_json)
     .bind(now)
     .bind(now)
     .execute(pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    .await;
+
+    match result {
+        Ok(_) => Ok(UpsertResult::Created(id)),
+        Err(e) if is_unique_violation(&e) => {
+            let existing: Option<(String, Option<String>)> = sqlx::query_as(
+                "SELECT id, hours_source FROM work_items WHERE content_hash = ? AND user_id = ?",
+            )
+            .bind(content_hash)
+            .bind(&params.user_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+            // The conflict was on another unique index; nothing to merge into
+            let (existing_id, hours_source) = existing.ok_or_else(|| e.to_string())?;
+            log::info!("Work item with hash {} already exists, updating {}", content_hash, existing_id);
+
+            let user_modified = hours_source.as_deref() == Some("user_modified");
+            update_work_item(pool, &existing_id, params, content_hash, tags_json, user_modified)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(UpsertResult::Updated(existing_id))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Overwrite an existing work item with `params`, keeping its hours if the user edited them
+async fn update_work_item(
+    pool: &SqlitePool,
+    id: &str,
+    params: &WorkItemParams,
+    content_hash: &str,
+    tags_json: Option<&str>,
+    user_modified: bool,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    if user_modified {
+        // Update without changing hours
+        sqlx::query(
+            r#"UPDATE work_items SET
+               title = ?, description = ?, hours_estimated = ?,
+               start_time = ?, end_time = ?, project_path = ?,
+               session_id = ?, content_hash = ?, tags = COALESCE(?, tags), updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(&params.title)
+        .bind(&params.description)
+        .bind(params.hours)
+        .bind(&params.start_time)
+        .bind(&params.end_time)
+        .bind(&params.project_path)
+        .bind(&params.session_id)
+        .bind(content_hash)
+        .bind(tags_json)
+        .bind(now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+    } else {
+        // Update including hours
+        sqlx::query(
+            r#"UPDATE work_items SET
+               title = ?, description = ?, hours = ?, hours_source = 'session',
+               hours_estimated = ?, start_time = ?, end_time = ?, project_path = ?,
+               session_id = ?, content_hash = ?, tags = COALESCE(?, tags), updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(&params.title)
+        .bind(&params.description)
+        .bind(params.hours)
+        .bind(params.hours)
+        .bind(&params.start_time)
+        .bind(&params.end_time)
+        .bind(&params.project_path)
+        .bind(&params.session_id)
+        .bind(content_hash)
+        .bind(tags_json)
+        .bind(now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+    }
 
-    Ok(UpsertResult::Created(id))
+    Ok(())
+}
+
+/// Whether `err` is a unique constraint violation
+fn is_unique_violation(err: &sqlx::Error) -> bool {
+    matches!(err, sqlx::Error::Database(db_err) if db_err.is_unique_violation())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::db_with_user;
+
+    fn session_hash(user_id: &str, session_id: &str) -> String {
+        compute_content_hash(user_id, "claude_code", session_id, "Title", "2026-01-15")
+    }
 
     #[test]
-    fn test_generate_session_hash_consistent() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user1", "session-abc");
+    fn test_compute_content_hash_consistent() {
+        let hash1 = session_hash("user1", "session-abc");
+        let hash2 = session_hash("user1", "session-abc");
         assert_eq!(hash1, hash2, "Same inputs should produce same hash");
     }
 
     #[test]
-    fn test_generate_session_hash_different_sessions() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user1", "session-def");
+    fn test_compute_content_hash_different_sessions() {
+        let hash1 = session_hash("user1", "session-abc");
+        let hash2 = session_hash("user1", "session-def");
         assert_ne!(hash1, hash2, "Different sessions should produce different hashes");
     }
 
     #[test]
-    fn test_generate_session_hash_different_users() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user2", "session-abc");
+    fn test_compute_content_hash_different_users() {
+        let hash1 = session_hash("user1", "session-abc");
+        let hash2 = session_hash("user2", "session-abc");
         assert_ne!(hash1, hash2, "Different users should produce different hashes");
     }
 
     #[test]
-    fn test_generate_session_hash_prefix() {
-        let hash = generate_session_hash("user1", "session-abc");
-        assert!(hash.starts_with("sess_"), "Hash should start with sess_ prefix");
+    fn test_compute_content_hash_is_sha256_hex() {
+        // sha256("u1\x1fgithub\x1fabc123")
+        assert_eq!(
+            compute_content_hash("u1", "github", "abc123", "Fix login", "2026-01-15"),
+            "26f0138463cdb2444f3d4c360496ea1cf894e974b9ce16e036d67d5266870b57"
+        );
+    }
+
+    #[test]
+    fn test_compute_content_hash_title_only_without_source_id() {
+        // With an ID, title and date don't matter
+        assert_eq!(
+            compute_content_hash("u1", "claude_code", "sess-1", "First", "2026-01-15"),
+            compute_content_hash("u1", "claude_code", " sess-1 ", "Renamed", "2026-01-16"),
+        );
+        assert_ne!(
+            compute_content_hash("u1", "claude_code", "sess-1", "First", "2026-01-15"),
+            compute_content_hash("u1", "github", "sess-1", "First", "2026-01-15"),
+        );
+
+        // Without one, they identify the item
+        let imported = compute_content_hash("u1", "imported", "", "Planning", "2026-01-15");
+        assert_eq!(imported, compute_content_hash("u1", "imported", "", " Planning ", "2026-01-15"));
+        assert_ne!(imported, compute_content_hash("u1", "imported", "", "Review", "2026-01-15"));
+        assert_ne!(imported, compute_content_hash("u1", "imported", "", "Planning", "2026-01-16"));
     }
 
     #[test]
@@ -293,4 +422,78 @@ mod tests {
         assert!(!skipped.is_created());
         assert!(!skipped.is_updated());
     }
+
+    async fn count_items(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM work_items")
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upsert_same_session_twice_updates() {
+        let (db, _dir) = db_with_user().await;
+        let params = WorkItemParams::new("u1", "claude_code", "s1", "First", 1.0, "2026-01-15")
+            .with_session_id("sess-1");
+
+        let first = upsert_work_item(&db.pool, params.clone()).await.unwrap();
+        assert!(first.is_created());
+
+        let second = upsert_work_item(&db.pool, WorkItemParams { title: "Second".to_string(), ..params })
+            .await
+            .unwrap();
+        assert_eq!(second, UpsertResult::Updated(first.id().to_string()));
+        assert_eq!(count_items(&db.pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_matches_row_with_legacy_hash_by_source_id() {
+        let (db, _dir) = db_with_user().await;
+        sqlx::query(
+            r#"INSERT INTO work_items (id, user_id, source, source_id, title, hours, date, content_hash)
+               VALUES ('old', 'u1', 'git', 'abc123', 'Fix login', 1.0, '2026-01-15', 'legacy-hash')"#,
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let params = WorkItemParams::new("u1", "git", "abc123", "Fix login", 1.5, "2026-01-15");
+        let result = upsert_work_item(&db.pool, params).await.unwrap();
+
+        assert_eq!(result, UpsertResult::Updated("old".to_string()));
+        assert_eq!(count_items(&db.pool).await, 1);
+        let hash: String = sqlx::query_scalar("SELECT content_hash FROM work_items WHERE id = 'old'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(hash, compute_content_hash("u1", "git", "abc123", "Fix login", "2026-01-15"));
+    }
+
+    #[tokio::test]
+    async fn test_insert_hash_conflict_updates_existing_row() {
+        let (db, _dir) = db_with_user().await;
+        let params = WorkItemParams::new("u1", "claude_code", "s1", "First", 1.0, "2026-01-15")
+            .with_session_id("sess-1");
+        let content_hash = compute_content_hash("u1", "claude_code", "sess-1", "First", "2026-01-15");
+
+        // Simulate losing a race: the row appeared after the existence check
+        let first = insert_work_item(&db.pool, &params, &content_hash, None).await.unwrap();
+        let second = insert_work_item(
+            &db.pool,
+            &WorkItemParams { title: "Second".to_string(), hours: 2.0, ..params },
+            &content_hash,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(second, UpsertResult::Updated(first.id().to_string()));
+        assert_eq!(count_items(&db.pool).await, 1);
+        let (title, hours): (String, f64) = sqlx::query_as("SELECT title, hours FROM work_items")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(title, "Second");
+        assert_eq!(hours, 2.0);
+    }
 }
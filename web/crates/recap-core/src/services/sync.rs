@@ -6,7 +6,7 @@
 //! - Periodic background sync
 //! - Sync status tracking
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::fs;
@@ -14,8 +14,9 @@ use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use crate::models::{SyncStatus, SyncStatusResponse};
+use super::sources::{compute_content_hash, ProjectSyncResult};
 use super::session_parser::{extract_cwd, parse_session_full, ParsedSession};
-use super::worklog::calculate_session_hours;
+use super::worklog::{calculate_session_hours_capped, DEFAULT_MAX_SESSION_HOURS};
 
 /// Sync Service for managing background synchronization
 pub struct SyncService {
@@ -160,10 +161,38 @@ impl SyncService {
         Ok(())
     }
 
+    /// Session file mtime cursor of `source`, stored on its `source_path IS NULL` row
+    pub async fn get_cursor(&self, user_id: &str, source: &str) -> Result<Option<DateTime<Utc>>, String> {
+        let cursor: Option<Option<DateTime<Utc>>> = sqlx::query_scalar(
+            "SELECT cursor_modified_at FROM sync_status WHERE user_id = ? AND source = ? AND source_path IS NULL"
+        )
+        .bind(user_id)
+        .bind(source)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(cursor.flatten())
+    }
+
+    /// Move the cursor of `source` forward to `modified_at`; it never moves back
+    pub async fn advance_cursor(&self, user_id: &str, source: &str, modified_at: DateTime<Utc>) -> Result<(), String> {
+        if self.get_cursor(user_id, source).await?.is_some_and(|c| c >= modified_at) {
+            return Ok(());
+        }
+        let status = self.get_or_create_status(user_id, source, None).await?;
+        sqlx::query("UPDATE sync_status SET cursor_modified_at = ?, updated_at = ? WHERE id = ?")
+            .bind(modified_at)
+            .bind(Utc::now())
+            .bind(&status.id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
     /// Get Claude projects directory path
     pub fn get_claude_projects_dir() -> Option<PathBuf> {
-        let home = dirs::home_dir()?;
-        let claude_dir = home.join(".claude").join("projects");
+        let claude_dir = super::claude_paths::default_claude_projects_dir()?;
         if claude_dir.exists() {
             Some(claude_dir)
         } else {
@@ -173,10 +202,15 @@ impl SyncService {
 
     /// List all Claude project directories
     pub fn list_claude_projects() -> Vec<PathBuf> {
+        Self::list_claude_projects_in(&Self::get_claude_projects_dir().into_iter().collect::<Vec<_>>())
+    }
+
+    /// List the Claude project directories with sessions under each of `projects_dirs`
+    pub fn list_claude_projects_in(projects_dirs: &[PathBuf]) -> Vec<PathBuf> {
         let mut projects = Vec::new();
 
-        if let Some(claude_dir) = Self::get_claude_projects_dir() {
-            if let Ok(entries) = std::fs::read_dir(&claude_dir) {
+        for claude_dir in projects_dirs {
+            if let Ok(entries) = std::fs::read_dir(claude_dir) {
                 for entry in entries.flatten() {
                     let path = entry.path();
                     if path.is_dir() {
@@ -229,6 +263,34 @@ pub fn resolve_git_root(path: &str) -> String {
     path.to_string()
 }
 
+/// Project a Claude project directory belongs to: the path its sessions were
+/// recorded in, resolved to the git root.
+///
+/// This is the same `cwd`→project resolution sync uses to group directories
+/// into projects, so callers attribute sessions the way sync does.
+pub fn resolve_claude_dir_project(dir_path: &Path) -> Option<String> {
+    SyncService::extract_project_path_from_dir(dir_path).map(|raw| resolve_git_root(&raw))
+}
+
+/// Whether two project paths name the same directory.
+///
+/// Paths that exist are compared canonicalized (symlinks, `..`, case on
+/// case-insensitive file systems); otherwise `\` is read as `/` and trailing
+/// separators are ignored. Never a substring match.
+pub fn same_project_path(a: &str, b: &str) -> bool {
+    fn normalize(path: &str) -> String {
+        match fs::canonicalize(path) {
+            Ok(canonical) => canonical.to_string_lossy().to_string(),
+            Err(_) => {
+                let path = path.replace('\\', "/");
+                let trimmed = path.trim_end_matches('/');
+                if trimmed.is_empty() { path } else { trimmed.to_string() }
+            }
+        }
+    }
+    normalize(a) == normalize(b)
+}
+
 // ============ Project Discovery ============
 
 /// A discovered Claude project, potentially grouping multiple Claude dirs
@@ -252,15 +314,19 @@ impl SyncService {
     /// After extracting raw paths, calls `resolve_git_root()` to canonicalize.
     /// Groups all dirs that resolve to the same git root into one `DiscoveredProject`.
     pub fn discover_project_paths() -> Vec<DiscoveredProject> {
-        let claude_dir = match Self::get_claude_projects_dir() {
-            Some(dir) => dir,
-            None => return vec![],
-        };
+        Self::discover_project_paths_in(&Self::get_claude_projects_dir().into_iter().collect::<Vec<_>>())
+    }
 
-        let entries = match fs::read_dir(&claude_dir) {
-            Ok(e) => e,
-            Err(_) => return vec![],
-        };
+    /// [`discover_project_paths`](Self::discover_project_paths) across several
+    /// Claude projects directories.
+    ///
+    /// Dirs from different roots that resolve to the same git root are grouped
+    /// into one project.
+    pub fn discover_project_paths_in(projects_dirs: &[PathBuf]) -> Vec<DiscoveredProject> {
+        let entries = projects_dirs
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten();
 
         // Map: git_root -> (Vec<claude_dirs>)
         let mut grouped: HashMap<String, Vec<PathBuf>> = HashMap::new();
@@ -287,12 +353,7 @@ impl SyncService {
                 continue;
             }
 
-            // Try to extract the project path using multiple strategies
-            let raw_path = Self::extract_project_path_from_dir(&dir_path);
-
-            if let Some(raw) = raw_path {
-                let git_root = resolve_git_root(&raw);
-
+            if let Some(git_root) = resolve_claude_dir_project(&dir_path) {
                 // Skip root filesystem path — these are MCP/no-context sessions
                 // stored in ~/.claude/projects/-/ with no real project directory
                 if git_root == "/" || git_root.is_empty() {
@@ -388,7 +449,7 @@ fn decode_dir_name_to_path(dir_name: &str) -> String {
 // ============ Claude Sync Logic ============
 
 // Shared functions from session_parser: parse_session_full, ParsedSession
-// Shared from worklog: calculate_session_hours
+// Shared from worklog: calculate_session_hours_capped
 
 /// Sync result for Claude projects
 #[derive(Debug, serde::Serialize)]
@@ -398,16 +459,93 @@ pub struct ClaudeSyncResult {
     pub sessions_skipped: usize,
     pub work_items_created: usize,
     pub work_items_updated: usize,
+    pub per_project: Vec<ProjectSyncResult>,
+}
+
+/// `sync_status` source whose cursor tracks Claude session files
+pub const CLAUDE_SYNC_SOURCE: &str = "claude";
+
+/// Skips session files not modified since the last sync and records the
+/// newest mtime among the files scanned, to become the next cursor
+#[derive(Debug, Clone, Default)]
+pub struct SessionCursor {
+    /// Files modified at or before this are skipped; `None` scans everything
+    pub since: Option<DateTime<Utc>>,
+    /// Latest mtime of the files scanned so far
+    pub max_seen: Option<DateTime<Utc>>,
+}
+
+impl SessionCursor {
+    pub fn new(since: Option<DateTime<Utc>>) -> Self {
+        Self { since, max_seen: None }
+    }
+
+    /// Whether `path` should be parsed, recording its mtime if so.
+    ///
+    /// Files whose mtime can't be read are always scanned. Touched but
+    /// unchanged files are scanned again; the `content_hash` lookup keeps
+    /// them from becoming duplicates.
+    pub fn should_scan(&mut self, path: &Path) -> bool {
+        let modified = match fs::metadata(path).and_then(|m| m.modified()) {
+            Ok(t) => DateTime::<Utc>::from(t),
+            Err(_) => return true,
+        };
+        if self.since.is_some_and(|since| modified <= since) {
+            return false;
+        }
+        self.max_seen = self.max_seen.max(Some(modified));
+        true
+    }
+}
+
+/// Progress of a running sync, reported as each project finishes
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SyncProgressUpdate {
+    pub source: String,
+    pub projects_scanned: usize,
+    pub projects_total: usize,
+    /// Work items created or updated so far
+    pub items_found: usize,
 }
 
 /// Helper to calculate session hours with Option handling
-fn session_hours_from_options(first: &Option<String>, last: &Option<String>) -> f64 {
+fn session_hours_from_options(
+    first: &Option<String>,
+    last: &Option<String>,
+    timezone: Option<&str>,
+    max_hours: f64,
+) -> f64 {
     match (first, last) {
-        (Some(start), Some(end)) => calculate_session_hours(start, end),
+        (Some(start), Some(end)) => calculate_session_hours_capped(start, end, timezone, max_hours),
         _ => 0.5,
     }
 }
 
+/// Longest a single session may count for: the user's `max_session_hours`,
+/// else their `daily_work_hours`, else 8h
+pub async fn load_max_session_hours(pool: &SqlitePool, user_id: &str) -> f64 {
+    let row: Option<(Option<f64>, Option<f64>)> =
+        sqlx::query_as("SELECT max_session_hours, daily_work_hours FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+    row.and_then(|(max_session, daily)| max_session.filter(|h| *h > 0.0).or(daily.filter(|h| *h > 0.0)))
+        .unwrap_or(DEFAULT_MAX_SESSION_HOURS)
+}
+
+/// User's timezone setting, used to read session timestamps that lack an offset
+pub async fn load_user_timezone(pool: &SqlitePool, user_id: &str) -> Option<String> {
+    sqlx::query_scalar("SELECT timezone FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten()
+}
+
 /// Build description for a single session work item
 fn build_session_description(session: &ParsedSession) -> String {
     let mut parts = vec![];
@@ -463,19 +601,6 @@ fn generate_session_hash_legacy(user_id: &str, session_id: &str, project_path: &
     format!("sess_{:x}", hasher.finish())
 }
 
-/// Generate a unique hash for session-based deduplication (v2, uses only user_id + session_id).
-/// Since session_id is a UUID and already globally unique, including project_path
-/// is unnecessary and causes duplicate work items when the same session is seen
-/// from different sub-folder cwd values.
-fn generate_session_hash(user_id: &str, session_id: &str) -> String {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-
-    let mut hasher = DefaultHasher::new();
-    format!("session:{}:{}", user_id, session_id).hash(&mut hasher);
-    format!("sess_{:x}", hasher.finish())
-}
-
 /// Find an existing work item by either the new hash or by session_id fallback.
 /// This handles the transition from old hashes (which included project_path)
 /// to new hashes (user_id + session_id only).
@@ -520,18 +645,89 @@ pub async fn sync_discovered_projects(
     user_id: &str,
     projects: &[DiscoveredProject],
 ) -> Result<ClaudeSyncResult, String> {
+    sync_discovered_projects_with_progress(pool, user_id, projects, |_| {}).await
+}
+
+/// [`sync_discovered_projects`], calling `on_progress` once before the first
+/// project and again after each project is scanned
+pub async fn sync_discovered_projects_with_progress<F>(
+    pool: &SqlitePool,
+    user_id: &str,
+    projects: &[DiscoveredProject],
+    on_progress: F,
+) -> Result<ClaudeSyncResult, String>
+where
+    F: FnMut(&SyncProgressUpdate),
+{
+    sync_discovered_projects_with_cursor(pool, user_id, projects, &mut SessionCursor::default(), on_progress).await
+}
+
+/// [`sync_discovered_projects_with_progress`] that skips session files not
+/// modified since the previous incremental sync, then advances the cursor.
+/// `full` scans every file but still advances the cursor.
+pub async fn sync_discovered_projects_incremental<F>(
+    pool: &SqlitePool,
+    user_id: &str,
+    projects: &[DiscoveredProject],
+    full: bool,
+    on_progress: F,
+) -> Result<ClaudeSyncResult, String>
+where
+    F: FnMut(&SyncProgressUpdate),
+{
+    let service = SyncService::new(pool.clone());
+    let since = if full {
+        None
+    } else {
+        service.get_cursor(user_id, CLAUDE_SYNC_SOURCE).await?
+    };
+
+    let mut cursor = SessionCursor::new(since);
+    let result = sync_discovered_projects_with_cursor(pool, user_id, projects, &mut cursor, on_progress).await?;
+    if let Some(max_seen) = cursor.max_seen {
+        service.advance_cursor(user_id, CLAUDE_SYNC_SOURCE, max_seen).await?;
+    }
+    Ok(result)
+}
+
+async fn sync_discovered_projects_with_cursor<F>(
+    pool: &SqlitePool,
+    user_id: &str,
+    projects: &[DiscoveredProject],
+    cursor: &mut SessionCursor,
+    mut on_progress: F,
+) -> Result<ClaudeSyncResult, String>
+where
+    F: FnMut(&SyncProgressUpdate),
+{
     let mut sessions_processed = 0;
     let mut sessions_skipped = 0;
     let mut created = 0;
     let mut updated = 0;
+    let mut per_project = Vec::new();
     let now = Utc::now();
+    let timezone = load_user_timezone(pool, user_id).await;
+    let max_session_hours = load_max_session_hours(pool, user_id).await;
+
+    let mut progress = SyncProgressUpdate {
+        source: "claude".to_string(),
+        projects_scanned: 0,
+        projects_total: projects.len(),
+        items_found: 0,
+    };
+    on_progress(&progress);
 
     for project in projects {
+        progress.projects_scanned += 1;
+
         // Skip root path projects (MCP/no-context sessions)
         if project.canonical_path == "/" || project.canonical_path.is_empty() {
+            on_progress(&progress);
             continue;
         }
 
+        let mut project_result = ProjectSyncResult::new(&project.name, &project.canonical_path);
+
         for claude_dir in &project.claude_dirs {
             if !claude_dir.is_dir() {
                 continue;
@@ -551,15 +747,31 @@ pub async fn sync_discovered_projects(
                 {
                     continue;
                 }
+                if !cursor.should_scan(&file_path) {
+                    continue;
+                }
 
                 if let Some(session) = parse_session_full(&file_path) {
+                    if session.is_multi_project() {
+                        // Attributed to the first cwd's project; flag it rather than misattribute silently
+                        log::warn!(
+                            "Session {} spans multiple projects: {}",
+                            file_path.display(),
+                            session.project_paths().join(", ")
+                        );
+                    }
                     if session.message_count == 0 {
                         sessions_skipped += 1;
+                        project_result.skipped += 1;
                         continue;
                     }
 
-                    let hours =
-                        session_hours_from_options(&session.first_timestamp, &session.last_timestamp);
+                    let hours = session_hours_from_options(
+                        &session.first_timestamp,
+                        &session.last_timestamp,
+                        timezone.as_deref(),
+                        max_session_hours,
+                    );
 
                     // Extract session ID from filename (UUID.jsonl -> UUID)
                     let session_id = file_path
@@ -598,8 +810,9 @@ pub async fn sync_discovered_projects(
                     // Use canonical path (git root) as project_path
                     let project_path = &project.canonical_path;
 
-                    // Generate new content hash (user_id + session_id only)
-                    let content_hash = generate_session_hash(user_id, &session_id);
+                    // The session ID identifies the item, not the project path it was seen from
+                    let content_hash =
+                        compute_content_hash(user_id, "claude_code", &session_id, &title, &date);
 
                     // Find existing work item with dual lookup (new hash + session_id fallback)
                     let existing =
@@ -669,6 +882,7 @@ pub async fn sync_discovered_projects(
                         }
 
                         updated += 1;
+                        project_result.updated += 1;
                     } else {
                         let id = Uuid::new_v4().to_string();
                         sqlx::query(
@@ -697,12 +911,17 @@ pub async fn sync_discovered_projects(
                         .map_err(|e| e.to_string())?;
 
                         created += 1;
+                        project_result.created += 1;
                     }
 
                     sessions_processed += 1;
                 }
             }
         }
+
+        per_project.push(project_result);
+        progress.items_found = created + updated;
+        on_progress(&progress);
     }
 
     Ok(ClaudeSyncResult {
@@ -711,6 +930,7 @@ pub async fn sync_discovered_projects(
         sessions_skipped,
         work_items_created: created,
         work_items_updated: updated,
+        per_project,
     })
 }
 
@@ -722,38 +942,92 @@ pub async fn sync_claude_projects(
     user_id: &str,
     project_paths: &[String],
 ) -> Result<ClaudeSyncResult, String> {
-    let claude_home = dirs::home_dir()
-        .map(|h| h.join(".claude"))
-        .ok_or("Claude home directory not found")?;
+    sync_claude_projects_with_progress(pool, user_id, project_paths, |_| {}).await
+}
+
+/// [`sync_claude_projects`] with progress reported to `on_progress`,
+/// see [`sync_discovered_projects_with_progress`]
+pub async fn sync_claude_projects_with_progress<F>(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_paths: &[String],
+    on_progress: F,
+) -> Result<ClaudeSyncResult, String>
+where
+    F: FnMut(&SyncProgressUpdate),
+{
+    let projects = discover_from_paths(pool, user_id, project_paths).await?;
+    sync_discovered_projects_with_progress(pool, user_id, &projects, on_progress).await
+}
+
+/// [`sync_claude_projects_with_progress`] using the session file cursor,
+/// see [`sync_discovered_projects_incremental`].
+///
+/// The cursor is shared by all projects, so only sync every project this way.
+pub async fn sync_claude_projects_incremental<F>(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_paths: &[String],
+    full: bool,
+    on_progress: F,
+) -> Result<ClaudeSyncResult, String>
+where
+    F: FnMut(&SyncProgressUpdate),
+{
+    let projects = discover_from_paths(pool, user_id, project_paths).await?;
+    sync_discovered_projects_incremental(pool, user_id, &projects, full, on_progress).await
+}
+
+/// Discover the projects under every Claude root configured for `user_id`
+pub async fn discover_user_projects(pool: &SqlitePool, user_id: &str) -> Result<Vec<DiscoveredProject>, String> {
+    let projects_dirs = super::claude_paths::resolve_claude_projects_dirs(pool, user_id).await?;
+    Ok(SyncService::discover_project_paths_in(&projects_dirs))
+}
 
-    let projects_dir = claude_home.join("projects");
+/// The Claude project dir for `project_path` in each of `projects_dirs`.
+///
+/// Claude encodes `/Users/foo` as `-Users-foo` (older versions `Users-foo`).
+pub(crate) fn find_claude_project_dirs(projects_dirs: &[PathBuf], project_path: &str) -> Vec<PathBuf> {
+    let dir_name_with_dash = project_path.replace('/', "-");
+    let dir_name_without_dash = project_path.trim_start_matches('/').replace('/', "-");
+
+    projects_dirs
+        .iter()
+        .filter_map(|projects_dir| {
+            [&dir_name_with_dash, &dir_name_without_dash]
+                .into_iter()
+                .map(|name| projects_dir.join(name))
+                .find(|dir| dir.is_dir())
+        })
+        .collect()
+}
+
+/// Group the user's Claude project dirs for `project_paths` by git root
+async fn discover_from_paths(
+    pool: &SqlitePool,
+    user_id: &str,
+    project_paths: &[String],
+) -> Result<Vec<DiscoveredProject>, String> {
+    let projects_dirs = super::claude_paths::resolve_claude_projects_dirs(pool, user_id).await?;
+    if projects_dirs.is_empty() {
+        return Err("Claude home directory not found".to_string());
+    }
 
     // Convert project_paths into DiscoveredProject structs
     let mut grouped: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
     for project_path in project_paths {
-        // Handle path encoding: /Users/foo -> -Users-foo or Users-foo
-        let dir_name_with_dash = project_path.replace('/', "-");
-        let dir_name_without_dash = project_path.trim_start_matches('/').replace('/', "-");
-
-        let project_dir = if projects_dir.join(&dir_name_with_dash).exists() {
-            projects_dir.join(&dir_name_with_dash)
-        } else if projects_dir.join(&dir_name_without_dash).exists() {
-            projects_dir.join(&dir_name_without_dash)
-        } else {
+        let project_dirs = find_claude_project_dirs(&projects_dirs, project_path);
+        if project_dirs.is_empty() {
             log::debug!(
                 "Claude project directory not found for path: {}",
                 project_path
             );
             continue;
-        };
-
-        if !project_dir.is_dir() {
-            continue;
         }
 
         let git_root = resolve_git_root(project_path);
-        grouped.entry(git_root).or_default().push(project_dir);
+        grouped.entry(git_root).or_default().extend(project_dirs);
     }
 
     let projects: Vec<DiscoveredProject> = grouped
@@ -771,7 +1045,7 @@ pub async fn sync_claude_projects(
         })
         .collect();
 
-    sync_discovered_projects(pool, user_id, &projects).await
+    Ok(projects)
 }
 
 // ============ Tests ============
@@ -779,6 +1053,7 @@ pub async fn sync_claude_projects(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::db_with_user;
     use std::fs;
 
     #[test]
@@ -852,6 +1127,43 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn test_resolve_claude_dir_project_keeps_similar_names_apart() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let recap = dir.path().join("work").join("recap");
+        let recap_web = dir.path().join("work").join("recap-web");
+        for repo in [&recap, &recap_web] {
+            fs::create_dir_all(repo.join(".git")).unwrap();
+        }
+
+        // Session cwd in a subdirectory resolves to the repo root
+        let claude_dir = dir.path().join("-work-recap-web");
+        fs::create_dir(&claude_dir).unwrap();
+        fs::write(
+            claude_dir.join("s1.jsonl"),
+            format!("{{\"cwd\":\"{}\"}}\n", recap_web.join("src").display()),
+        )
+        .unwrap();
+
+        let project = resolve_claude_dir_project(&claude_dir).unwrap();
+        assert!(same_project_path(&project, &recap_web.to_string_lossy()));
+        assert!(!same_project_path(&project, &recap.to_string_lossy()));
+    }
+
+    #[test]
+    fn test_same_project_path() {
+        assert!(same_project_path("/work/recap/", "/work/recap"));
+        assert!(same_project_path("C:\\work\\recap", "C:/work/recap"));
+        assert!(!same_project_path("/work/recap", "/work/recap-web"));
+        assert!(!same_project_path("/work/recap-web", "/work/recap"));
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = dir.path().join("recap");
+        fs::create_dir(&repo).unwrap();
+        let dotted = format!("{}/../recap", repo.display());
+        assert!(same_project_path(&dotted, &repo.to_string_lossy()));
+    }
+
     #[test]
     fn test_decode_dir_name_to_path_with_leading_dash() {
         assert_eq!(
@@ -874,35 +1186,8 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_session_hash_consistent() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user1", "session-abc");
-        assert_eq!(hash1, hash2, "Same inputs should produce same hash");
-    }
-
-    #[test]
-    fn test_generate_session_hash_different_sessions() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user1", "session-def");
-        assert_ne!(hash1, hash2, "Different sessions should produce different hashes");
-    }
-
-    #[test]
-    fn test_generate_session_hash_different_users() {
-        let hash1 = generate_session_hash("user1", "session-abc");
-        let hash2 = generate_session_hash("user2", "session-abc");
-        assert_ne!(hash1, hash2, "Different users should produce different hashes");
-    }
-
-    #[test]
-    fn test_generate_session_hash_prefix() {
-        let hash = generate_session_hash("user1", "session-abc");
-        assert!(hash.starts_with("sess_"), "Hash should start with sess_ prefix");
-    }
-
-    #[test]
-    fn test_generate_session_hash_v2_differs_from_legacy() {
-        let new_hash = generate_session_hash("user1", "session-abc");
+    fn test_content_hash_differs_from_legacy() {
+        let new_hash = compute_content_hash("user1", "claude_code", "session-abc", "Title", "2026-01-15");
         let legacy_hash = generate_session_hash_legacy("user1", "session-abc", "/some/path");
         assert_ne!(
             new_hash, legacy_hash,
@@ -919,4 +1204,172 @@ mod tests {
         };
         assert_eq!(project.name, "MyProject");
     }
+
+    #[tokio::test]
+    async fn test_session_hours_cap_follows_user_settings() {
+        let (db, dir) = db_with_user().await;
+
+        // daily_work_hours defaults to 8
+        assert_eq!(load_max_session_hours(&db.pool, "u1").await, 8.0);
+        assert_eq!(load_max_session_hours(&db.pool, "missing").await, DEFAULT_MAX_SESSION_HOURS);
+        sqlx::query("UPDATE users SET daily_work_hours = 10 WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(load_max_session_hours(&db.pool, "u1").await, 10.0);
+        sqlx::query("UPDATE users SET max_session_hours = 12 WHERE id = 'u1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(load_max_session_hours(&db.pool, "u1").await, 12.0);
+
+        // A 10h session is kept whole under a 12h cap
+        let claude_dir = dir.path().join("-home-dev-alpha");
+        fs::create_dir_all(&claude_dir).unwrap();
+        fs::write(
+            claude_dir.join("long.jsonl"),
+            "{\"timestamp\":\"2026-01-15T08:00:00Z\",\"cwd\":\"/home/dev/alpha\",\"message\":{\"role\":\"user\",\"content\":\"Implement the alpha feature\"}}\n\
+             {\"timestamp\":\"2026-01-15T18:00:00Z\",\"message\":{\"role\":\"user\",\"content\":\"Now add tests for it\"}}\n",
+        )
+        .unwrap();
+        let projects = vec![DiscoveredProject {
+            canonical_path: "/home/dev/alpha".to_string(),
+            claude_dirs: vec![claude_dir],
+            name: "alpha".to_string(),
+        }];
+        sync_discovered_projects_with_progress(&db.pool, "u1", &projects, |_| {})
+            .await
+            .unwrap();
+        let hours: f64 = sqlx::query_scalar("SELECT hours FROM work_items WHERE user_id = 'u1'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(hours, 10.0);
+    }
+
+    #[tokio::test]
+    async fn test_discover_user_projects_spans_all_roots() {
+        let (db, dir) = db_with_user().await;
+        let laptop = tempfile::TempDir::new().unwrap();
+        let work_machine = tempfile::TempDir::new().unwrap();
+        sqlx::query("UPDATE users SET claude_session_path = ? WHERE id = 'u1'")
+            .bind(format!("{}, {}", laptop.path().display(), work_machine.path().display()))
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let api = dir.path().join("api");
+        let web = dir.path().join("web");
+        // `api` has sessions on both machines, `web` only on the work machine
+        for (root, repo) in [(&laptop, &api), (&work_machine, &api), (&work_machine, &web)] {
+            fs::create_dir_all(repo.join(".git")).unwrap();
+            let claude_dir = root.path().join("projects").join(repo.to_string_lossy().replace('/', "-"));
+            fs::create_dir_all(&claude_dir).unwrap();
+            fs::write(claude_dir.join("s1.jsonl"), format!("{{\"cwd\":\"{}\"}}\n", repo.display())).unwrap();
+        }
+
+        let mut projects = discover_user_projects(&db.pool, "u1").await.unwrap();
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        let found: Vec<(&str, usize)> = projects.iter().map(|p| (p.name.as_str(), p.claude_dirs.len())).collect();
+        assert_eq!(found, vec![("api", 2), ("web", 1)]);
+
+        // Explicit paths are looked up in every root too
+        let projects = discover_from_paths(&db.pool, "u1", &[web.to_string_lossy().to_string()]).await.unwrap();
+        assert_eq!(projects.len(), 1);
+        assert!(projects[0].claude_dirs[0].starts_with(work_machine.path()));
+    }
+
+    #[tokio::test]
+    async fn test_progress_callback_reports_increasing_counts() {
+        let (db, dir) = db_with_user().await;
+
+        // Three fake projects with one session each
+        let projects: Vec<DiscoveredProject> = ["alpha", "beta", "gamma"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let claude_dir = dir.path().join(format!("-home-dev-{}", name));
+                fs::create_dir_all(&claude_dir).unwrap();
+                fs::write(
+                    claude_dir.join(format!("session-{}.jsonl", i)),
+                    format!(
+                        "{{\"timestamp\":\"2026-01-15T09:00:00Z\",\"cwd\":\"/home/dev/{name}\",\"message\":{{\"role\":\"user\",\"content\":\"Implement the {name} feature\"}}}}\n\
+                         {{\"timestamp\":\"2026-01-15T10:00:00Z\",\"message\":{{\"role\":\"user\",\"content\":\"Now add tests for it\"}}}}\n"
+                    ),
+                )
+                .unwrap();
+                DiscoveredProject {
+                    canonical_path: format!("/home/dev/{}", name),
+                    claude_dirs: vec![claude_dir],
+                    name: name.to_string(),
+                }
+            })
+            .collect();
+
+        let mut updates: Vec<SyncProgressUpdate> = Vec::new();
+        let result = sync_discovered_projects_with_progress(&db.pool, "u1", &projects, |p| updates.push(p.clone()))
+            .await
+            .unwrap();
+        assert_eq!(result.work_items_created, 3);
+
+        let scanned: Vec<usize> = updates.iter().map(|u| u.projects_scanned).collect();
+        let found: Vec<usize> = updates.iter().map(|u| u.items_found).collect();
+        assert_eq!(scanned, vec![0, 1, 2, 3]);
+        assert_eq!(found, vec![0, 1, 2, 3]);
+        assert!(updates.iter().all(|u| u.source == "claude" && u.projects_total == 3));
+    }
+
+    #[tokio::test]
+    async fn test_incremental_sync_skips_files_older_than_cursor() {
+        let (db, dir) = db_with_user().await;
+
+        let claude_dir = dir.path().join("-home-dev-alpha");
+        fs::create_dir_all(&claude_dir).unwrap();
+        let set_age = |path: &Path, age_hours: u64| {
+            let mtime = std::time::SystemTime::now() - std::time::Duration::from_secs(age_hours * 3600);
+            fs::File::options().write(true).open(path).unwrap().set_modified(mtime).unwrap();
+        };
+        let write_session = |name: &str, age_hours: u64| {
+            let path = claude_dir.join(name);
+            fs::write(
+                &path,
+                "{\"timestamp\":\"2026-01-15T09:00:00Z\",\"cwd\":\"/home/dev/alpha\",\"message\":{\"role\":\"user\",\"content\":\"Fix the sync\"}}\n",
+            )
+            .unwrap();
+            set_age(&path, age_hours);
+        };
+        let projects = vec![DiscoveredProject {
+            canonical_path: "/home/dev/alpha".to_string(),
+            claude_dirs: vec![claude_dir.clone()],
+            name: "alpha".to_string(),
+        }];
+        let sync = |full: bool| sync_discovered_projects_incremental(&db.pool, "u1", &projects, full, |_| {});
+
+        write_session("first.jsonl", 2);
+        let result = sync(false).await.unwrap();
+        assert_eq!((result.sessions_processed, result.work_items_created), (1, 1));
+        let cursor = SyncService::new(db.pool.clone()).get_cursor("u1", CLAUDE_SYNC_SOURCE).await.unwrap();
+        assert!(cursor.is_some());
+
+        // Nothing modified since the cursor
+        let result = sync(false).await.unwrap();
+        assert_eq!(result.sessions_processed, 0);
+
+        // A touched but unchanged file is re-read without creating a duplicate
+        set_age(&claude_dir.join("first.jsonl"), 1);
+        let result = sync(false).await.unwrap();
+        assert_eq!((result.sessions_processed, result.work_items_created), (1, 0));
+
+        // A file older than the cursor is only picked up by a full sync
+        write_session("second.jsonl", 3);
+        assert_eq!(sync(false).await.unwrap().sessions_processed, 0);
+        let result = sync(true).await.unwrap();
+        assert_eq!((result.sessions_processed, result.work_items_created), (2, 1));
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM work_items WHERE user_id = 'u1'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
 }
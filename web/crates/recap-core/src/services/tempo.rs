@@ -349,44 +349,92 @@ impl JiraClient {
         Ok(result)
     }
 
-    /// Batch get full issue details for multiple issue keys
-    pub async fn batch_get_issues(&self, issue_keys: &[String]) -> Result<Vec<JiraIssue>> {
-        let mut all_issues = Vec::new();
-        if issue_keys.is_empty() {
-            return Ok(all_issues);
-        }
+    /// Batch get full issue details for multiple issue keys.
+    ///
+    /// Returns a result per requested key, so one stale or mistyped key
+    /// doesn't fail the rest of the batch. Searches use `validateQuery=warn`,
+    /// which makes Jira skip unknown keys instead of rejecting the whole JQL.
+    pub async fn batch_get_issues(&self, issue_keys: &[String]) -> JiraIssueLookup {
+        let mut result = JiraIssueLookup::new();
 
         let batch_size = 50;
         for chunk in issue_keys.chunks(batch_size) {
             let jql = format!("key in ({})", chunk.join(","));
             let url = format!("{}/rest/api/2/search", self.base_url);
 
-            match self.client
+            let response = self.client
                 .get(&url)
                 .query(&[
                     ("jql", jql.as_str()),
                     ("fields", "summary,description,assignee,issuetype"),
                     ("maxResults", &batch_size.to_string()),
+                    ("validateQuery", "warn"),
                 ])
                 .send()
-                .await
-            {
+                .await;
+
+            let data = match response {
                 Ok(response) if response.status().is_success() => {
-                    if let Ok(data) = response.json::<serde_json::Value>().await {
-                        if let Some(issues) = data.get("issues").and_then(|v| v.as_array()) {
-                            for item in issues {
-                                if let Ok(issue) = serde_json::from_value::<JiraIssue>(item.clone()) {
-                                    all_issues.push(issue);
-                                }
-                            }
-                        }
+                    response.json::<serde_json::Value>().await.map_err(|e| e.to_string())
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    let text = response.text().await.unwrap_or_default();
+                    Err(format!("Jira API error {}: {}", status, text))
+                }
+                Err(e) => Err(e.to_string()),
+            };
+
+            match data {
+                Ok(data) => collect_batch_issues(chunk, &data, &mut result),
+                Err(e) => {
+                    for key in chunk {
+                        result.insert(key.clone(), Err(e.clone()));
                     }
                 }
-                _ => {} // skip failed batches
             }
         }
 
-        Ok(all_issues)
+        result
+    }
+}
+
+/// Per-key result of [`JiraClient::batch_get_issues`]: the issue, or why it
+/// couldn't be loaded
+pub type JiraIssueLookup = std::collections::HashMap<String, std::result::Result<JiraIssue, String>>;
+
+/// Match a search response's issues back to the requested keys.
+///
+/// Keys compare case-insensitively (Jira returns them upper-cased). Keys
+/// missing from the response get the Jira warning that mentions them, if any.
+fn collect_batch_issues(keys: &[String], data: &serde_json::Value, result: &mut JiraIssueLookup) {
+    let mut found: std::collections::HashMap<String, JiraIssue> = data
+        .get("issues")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|item| serde_json::from_value::<JiraIssue>(item.clone()).ok())
+        .map(|issue| (issue.key.to_uppercase(), issue))
+        .collect();
+
+    let warnings: Vec<&str> = data
+        .get("warningMessages")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|w| w.as_str())
+        .collect();
+
+    for key in keys {
+        let entry = match found.remove(&key.to_uppercase()) {
+            Some(issue) => Ok(issue),
+            None => Err(warnings
+                .iter()
+                .find(|w| w.to_uppercase().contains(&key.to_uppercase()))
+                .map(|w| w.to_string())
+                .unwrap_or_else(|| format!("Issue {} not found", key))),
+        };
+        result.insert(key.clone(), entry);
     }
 }
 
@@ -527,6 +575,15 @@ impl TempoClient {
         let members: Vec<serde_json::Value> = response.json().await?;
         Ok(members)
     }
+
+    /// Test the Tempo token by listing today's worklogs
+    pub async fn test_connection(&self) -> Result<(bool, String)> {
+        let today = chrono::Local::now().date_naive().to_string();
+        match self.get_worklogs(&today, &today).await {
+            Ok(_) => Ok((true, "Tempo token accepted".to_string())),
+            Err(e) => Ok((false, format!("Connection failed: {}", e))),
+        }
+    }
 }
 
 /// Worklog uploader - unified interface for Jira and Tempo
@@ -718,4 +775,71 @@ mod tests {
         let jql = build_search_jql("proj-123");
         assert_eq!(jql, r#"summary ~ "proj-123" ORDER BY updated DESC"#);
     }
+
+    /// Serve one HTTP request on a local port with a canned JSON response.
+    ///
+    /// Returns the base URL and a handle yielding the request line.
+    fn mock_server(status: &'static str, body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request_line.trim().to_string()
+        });
+        (base_url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_issues_tolerates_invalid_key() {
+        let (base_url, handle) = mock_server(
+            "200 OK",
+            r#"{"issues":[
+                {"key":"PROJ-1","fields":{"summary":"Fix login","issuetype":{"name":"Bug"}}},
+                {"key":"PROJ-2","fields":{"summary":"Add export"}}
+            ],"warningMessages":["The issue key 'GONE-9' for field 'key' is invalid."]}"#,
+        );
+        let client = JiraClient::new(&base_url, "token", None, JiraAuthType::Pat).unwrap();
+
+        let keys = vec!["PROJ-1".to_string(), "proj-2".to_string(), "GONE-9".to_string()];
+        let result = client.batch_get_issues(&keys).await;
+
+        assert!(handle.join().unwrap().contains("validateQuery=warn"));
+        assert_eq!(result.len(), 3);
+        assert_eq!(result["PROJ-1"].as_ref().unwrap().fields.summary.as_deref(), Some("Fix login"));
+        assert_eq!(result["proj-2"].as_ref().unwrap().key, "PROJ-2");
+        assert!(result["GONE-9"].as_ref().unwrap_err().contains("GONE-9"));
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_issues_failed_request_errors_each_key() {
+        let (base_url, handle) = mock_server("401 Unauthorized", r#"{"errorMessages":["Unauthorized"]}"#);
+        let client = JiraClient::new(&base_url, "token", None, JiraAuthType::Pat).unwrap();
+
+        let keys = vec!["PROJ-1".to_string(), "PROJ-2".to_string()];
+        let result = client.batch_get_issues(&keys).await;
+        handle.join().unwrap();
+
+        assert!(result.values().all(|r| r.as_ref().is_err_and(|e| e.contains("401"))));
+        assert_eq!(result.len(), 2);
+    }
 }
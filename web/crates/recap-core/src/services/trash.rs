@@ -0,0 +1,353 @@
+//! Work Item Trash
+//!
+//! Deleting a work item sets `deleted_at` instead of removing the row, so a
+//! misclick can be undone. Trashed items (and the children trashed with
+//! them) are hidden from lists, stats and reports, but still dedup re-synced
+//! sessions so a sync doesn't bring them back. The background service
+//! hard-deletes items trashed longer than the user's `trash_retention_days`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+/// Default number of days a trashed item can still be restored
+pub const DEFAULT_TRASH_RETENTION_DAYS: i64 = 30;
+
+/// A work item in the trash
+#[derive(Debug, Clone, PartialEq, Serialize, sqlx::FromRow)]
+pub struct TrashedWorkItem {
+    pub id: String,
+    pub title: String,
+    pub source: String,
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Load the user's trash retention, falling back to the default
+pub async fn get_trash_retention_days(pool: &SqlitePool, user_id: &str) -> i64 {
+    sqlx::query_scalar::<_, Option<i64>>("SELECT trash_retention_days FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten()
+        .unwrap_or(DEFAULT_TRASH_RETENTION_DAYS)
+}
+
+/// Move an item and its children to the trash.
+///
+/// Returns `false` when the user has no such item outside the trash.
+pub async fn trash_work_item(pool: &SqlitePool, user_id: &str, id: &str) -> Result<bool, String> {
+    let now = Utc::now();
+    let result = sqlx::query(
+        r#"
+        UPDATE work_items SET deleted_at = ?, updated_at = ?
+        WHERE (id = ? OR parent_id = ?) AND user_id = ? AND deleted_at IS NULL
+        "#,
+    )
+    .bind(now)
+    .bind(now)
+    .bind(id)
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Take an item out of the trash, along with the children trashed with it.
+///
+/// Children trashed separately stay in the trash. Returns `false` when the
+/// user has no such item in the trash.
+pub async fn restore_work_item(pool: &SqlitePool, user_id: &str, id: &str) -> Result<bool, String> {
+    let deleted_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT deleted_at FROM work_items WHERE id = ? AND user_id = ? AND deleted_at IS NOT NULL",
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let Some(deleted_at) = deleted_at else {
+        return Ok(false);
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE work_items SET deleted_at = NULL, updated_at = ?
+        WHERE user_id = ? AND (id = ? OR (parent_id = ? AND deleted_at = ?))
+        "#,
+    )
+    .bind(Utc::now())
+    .bind(user_id)
+    .bind(id)
+    .bind(id)
+    .bind(deleted_at)
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(true)
+}
+
+/// The user's trashed items, most recently deleted first
+pub async fn list_trash(pool: &SqlitePool, user_id: &str) -> Result<Vec<TrashedWorkItem>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, title, source, date, hours, deleted_at FROM work_items
+        WHERE user_id = ? AND deleted_at IS NOT NULL
+        ORDER BY deleted_at DESC, date DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Hard-delete the user's items trashed more than `older_than_days` ago,
+/// returning how many were removed.
+///
+/// Snapshots backing trashed manual items go with them, as do their entries
+/// in the manual project's `items.jsonl`.
+pub async fn purge_trash(pool: &SqlitePool, user_id: &str, older_than_days: i64) -> Result<u64, String> {
+    let cutoff = Utc::now() - Duration::days(older_than_days.max(0));
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+
+    let manual_items: Vec<(String, Option<String>)> = sqlx::query_as(
+        "SELECT id, project_path FROM work_items WHERE source = 'manual' AND user_id = ? AND deleted_at < ?",
+    )
+    .bind(user_id)
+    .bind(cutoff)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM snapshot_raw_data WHERE user_id = ? AND session_id IN (
+            SELECT 'manual:' || id FROM work_items WHERE source = 'manual' AND user_id = ? AND deleted_at < ?
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(user_id)
+    .bind(cutoff)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    // Detach anything still pointing at a purged parent before removing it
+    sqlx::query(
+        r#"
+        UPDATE work_items SET parent_id = NULL
+        WHERE user_id = ? AND parent_id IN (SELECT id FROM work_items WHERE user_id = ? AND deleted_at < ?)
+        "#,
+    )
+    .bind(user_id)
+    .bind(user_id)
+    .bind(cutoff)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let purged = sqlx::query("DELETE FROM work_items WHERE user_id = ? AND deleted_at < ?")
+        .bind(user_id)
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?
+        .rows_affected();
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    // The rows are gone either way; a stale JSONL entry is only logged
+    let mut by_project: HashMap<String, HashSet<String>> = HashMap::new();
+    for (id, project_path) in manual_items {
+        if let Some(project_path) = project_path {
+            by_project.entry(project_path).or_default().insert(id);
+        }
+    }
+    for (project_path, ids) in by_project {
+        if let Err(e) = remove_manual_jsonl_entries(Path::new(&project_path), &ids) {
+            log::warn!("Failed to remove purged items from {}/items.jsonl: {}", project_path, e);
+        }
+    }
+
+    Ok(purged)
+}
+
+/// Drop the lines of `<project_path>/items.jsonl` whose `id` is in `ids`
+fn remove_manual_jsonl_entries(project_path: &Path, ids: &HashSet<String>) -> Result<(), String> {
+    let file_path = project_path.join("items.jsonl");
+    if !file_path.exists() {
+        return Ok(());
+    }
+    let content = std::fs::read_to_string(&file_path).map_err(|e| e.to_string())?;
+
+    let mut kept = String::new();
+    let mut removed = false;
+    for line in content.lines() {
+        let id = serde_json::from_str::<serde_json::Value>(line)
+            .ok()
+            .and_then(|entry| entry.get("id").and_then(|id| id.as_str()).map(str::to_string));
+        if id.is_some_and(|id| ids.contains(&id)) {
+            removed = true;
+            continue;
+        }
+        kept.push_str(line);
+        kept.push('\n');
+    }
+
+    if removed {
+        std::fs::write(&file_path, kept).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Purge the user's trash using their retention setting
+pub async fn run_trash_purge(pool: &SqlitePool, user_id: &str) -> Result<u64, String> {
+    let purged = purge_trash(pool, user_id, get_trash_retention_days(pool, user_id).await).await?;
+    if purged > 0 {
+        log::info!("Purged {} work item(s) from the trash", purged);
+    }
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{db_with_user, insert_user};
+
+    async fn insert_item(pool: &SqlitePool, id: &str, parent_id: Option<&str>) {
+        insert_user_item(pool, "u1", id, parent_id, None).await;
+    }
+
+    async fn insert_user_item(
+        pool: &SqlitePool,
+        user_id: &str,
+        id: &str,
+        parent_id: Option<&str>,
+        project_path: Option<&str>,
+    ) {
+        sqlx::query(
+            r#"
+            INSERT INTO work_items (id, user_id, source, title, hours, date, parent_id, project_path)
+            VALUES (?, ?, 'manual', ?, 1.0, '2026-03-02', ?, ?)
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(format!("Item {}", id))
+        .bind(parent_id)
+        .bind(project_path)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn expire(pool: &SqlitePool, id: &str) {
+        sqlx::query("UPDATE work_items SET deleted_at = ? WHERE id = ?")
+            .bind(Utc::now() - Duration::days(DEFAULT_TRASH_RETENTION_DAYS + 1))
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    async fn trashed_ids(pool: &SqlitePool) -> Vec<String> {
+        let mut ids: Vec<String> = list_trash(pool, "u1").await.unwrap().into_iter().map(|i| i.id).collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_trash_and_restore_with_children() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "parent", None).await;
+        insert_item(&db.pool, "child", Some("parent")).await;
+        insert_item(&db.pool, "other", None).await;
+
+        assert!(trash_work_item(&db.pool, "u1", "parent").await.unwrap());
+        assert_eq!(trashed_ids(&db.pool).await, vec!["child", "parent"]);
+        // Already trashed, or someone else's
+        assert!(!trash_work_item(&db.pool, "u1", "parent").await.unwrap());
+        assert!(!trash_work_item(&db.pool, "u2", "other").await.unwrap());
+
+        assert!(restore_work_item(&db.pool, "u1", "parent").await.unwrap());
+        assert!(trashed_ids(&db.pool).await.is_empty());
+        assert!(!restore_work_item(&db.pool, "u1", "other").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_purge_only_removes_expired_items() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "old", None).await;
+        insert_item(&db.pool, "recent", None).await;
+        insert_item(&db.pool, "kept", None).await;
+        trash_work_item(&db.pool, "u1", "old").await.unwrap();
+        trash_work_item(&db.pool, "u1", "recent").await.unwrap();
+        expire(&db.pool, "old").await;
+
+        assert_eq!(run_trash_purge(&db.pool, "u1").await.unwrap(), 1);
+        assert_eq!(trashed_ids(&db.pool).await, vec!["recent"]);
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM work_items")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 2);
+    }
+
+    #[tokio::test]
+    async fn test_purge_leaves_other_users_trash() {
+        let (db, _dir) = db_with_user().await;
+        insert_user(&db, "u2").await;
+        insert_user_item(&db.pool, "u1", "mine", None, None).await;
+        insert_user_item(&db.pool, "u2", "theirs", None, None).await;
+        insert_user_item(&db.pool, "u2", "their-child", Some("theirs"), None).await;
+        trash_work_item(&db.pool, "u1", "mine").await.unwrap();
+        trash_work_item(&db.pool, "u2", "theirs").await.unwrap();
+        restore_work_item(&db.pool, "u2", "their-child").await.unwrap();
+        expire(&db.pool, "mine").await;
+        expire(&db.pool, "theirs").await;
+
+        assert_eq!(purge_trash(&db.pool, "u1", DEFAULT_TRASH_RETENTION_DAYS).await.unwrap(), 1);
+        let theirs: Vec<String> = list_trash(&db.pool, "u2").await.unwrap().into_iter().map(|i| i.id).collect();
+        assert_eq!(theirs, vec!["theirs"]);
+        let parent: Option<String> = sqlx::query_scalar("SELECT parent_id FROM work_items WHERE id = 'their-child'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(parent.as_deref(), Some("theirs"));
+    }
+
+    #[tokio::test]
+    async fn test_purge_removes_manual_jsonl_entries() {
+        let (db, dir) = db_with_user().await;
+        let project = dir.path().join("manual-project");
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(
+            project.join("items.jsonl"),
+            "{\"id\":\"gone\",\"title\":\"Gone\"}\n{\"id\":\"kept\",\"title\":\"Kept\"}\n",
+        )
+        .unwrap();
+        let project_path = project.to_str().unwrap();
+        insert_user_item(&db.pool, "u1", "gone", None, Some(project_path)).await;
+        insert_user_item(&db.pool, "u1", "kept", None, Some(project_path)).await;
+        trash_work_item(&db.pool, "u1", "gone").await.unwrap();
+        expire(&db.pool, "gone").await;
+
+        assert_eq!(run_trash_purge(&db.pool, "u1").await.unwrap(), 1);
+        assert_eq!(
+            std::fs::read_to_string(project.join("items.jsonl")).unwrap(),
+            "{\"id\":\"kept\",\"title\":\"Kept\"}\n"
+        );
+    }
+}
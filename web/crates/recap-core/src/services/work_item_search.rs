@@ -0,0 +1,119 @@
+//! Work Item Search
+//!
+//! Full-text search over work item titles and descriptions, backed by the
+//! `work_items_fts` FTS5 index that triggers keep in sync with `work_items`.
+
+use sqlx::SqlitePool;
+
+use crate::models::WorkItem;
+
+/// Join that puts the FTS index next to `work_items` for a `MATCH` query
+pub const WORK_ITEMS_FTS_JOIN: &str = "work_items JOIN work_items_fts ON work_items_fts.rowid = work_items.rowid";
+
+/// Turn free text into an FTS5 query that matches items containing every word.
+///
+/// Each word is quoted, so operators and punctuation in user input
+/// (`-`, `:`, `"`, `AND`) are searched for literally rather than parsed.
+/// Returns `None` when the input has no words.
+pub fn fts_match_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Work items of `user_id` matching `query`, best match (lowest bm25) first
+pub async fn search_work_items(
+    pool: &SqlitePool,
+    user_id: &str,
+    query: &str,
+    limit: i64,
+) -> Result<Vec<WorkItem>, String> {
+    let Some(fts_query) = fts_match_query(query) else {
+        return Ok(Vec::new());
+    };
+
+    let sql = format!(
+        r#"
+        SELECT work_items.* FROM {}
+        WHERE work_items_fts MATCH ? AND work_items.user_id = ? AND work_items.deleted_at IS NULL
+        ORDER BY bm25(work_items_fts), work_items.date DESC
+        LIMIT ?
+        "#,
+        WORK_ITEMS_FTS_JOIN
+    );
+    sqlx::query_as(&sql)
+        .bind(&fts_query)
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::db_with_user;
+
+    async fn insert_item(pool: &SqlitePool, id: &str, title: &str, description: &str) {
+        sqlx::query(
+            "INSERT INTO work_items (id, user_id, title, description, hours, date) VALUES (?, 'u1', ?, ?, 1.0, '2026-03-02')",
+        )
+        .bind(id)
+        .bind(title)
+        .bind(description)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn search_ids(pool: &SqlitePool, query: &str) -> Vec<String> {
+        search_work_items(pool, "u1", query, 20)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_fts_match_query_quotes_words() {
+        assert_eq!(fts_match_query("invoice rounding").as_deref(), Some("\"invoice\" \"rounding\""));
+        assert_eq!(fts_match_query("say \"hi\"").as_deref(), Some("\"say\" \"\"\"hi\"\"\""));
+        assert_eq!(fts_match_query("   "), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_by_relevance() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "a", "Fix login redirect", "Session cookie was dropped").await;
+        insert_item(&db.pool, "b", "Invoice rounding", "Invoice totals were off by one cent on every invoice").await;
+        insert_item(&db.pool, "c", "Billing cleanup", "Touched one invoice template").await;
+
+        assert_eq!(search_ids(&db.pool, "invoice").await, vec!["b", "c"]);
+        assert_eq!(search_ids(&db.pool, "invoice template").await, vec!["c"]);
+        // Operators in user input are searched literally instead of failing to parse
+        assert!(search_ids(&db.pool, "login-redirect OR").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_index_follows_updates_and_deletes() {
+        let (db, _dir) = db_with_user().await;
+        insert_item(&db.pool, "a", "Fix login redirect", "").await;
+
+        sqlx::query("UPDATE work_items SET title = 'Fix signup redirect' WHERE id = 'a'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert!(search_ids(&db.pool, "login").await.is_empty());
+        assert_eq!(search_ids(&db.pool, "signup").await, vec!["a"]);
+
+        sqlx::query("DELETE FROM work_items WHERE id = 'a'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert!(search_ids(&db.pool, "signup").await.is_empty());
+    }
+}
@@ -3,11 +3,12 @@
 //! Provides functionality for generating work logs based on git commits
 //! with session data as supplementary information.
 
-use chrono::{DateTime, FixedOffset, NaiveDate};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use serde::Serialize;
-use std::collections::HashMap;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use crate::utils::create_command;
+use crate::utils::{create_command, parse_timestamp};
 
 use crate::models::HoursSource;
 
@@ -50,6 +51,8 @@ pub struct CommitRecord {
     pub hours_estimated: f64,
     // Related session (if any)
     pub related_session: Option<SessionBrief>,
+    /// The user is listed in a `Co-authored-by:` trailer rather than being the author
+    pub co_authored: bool,
 }
 
 /// File change in a commit
@@ -101,7 +104,93 @@ pub struct HoursEstimate {
     pub source: HoursSource,
 }
 
-/// Estimate hours for a commit based on available data
+/// How a repository's commits are estimated when there is no user override
+/// or linked session. Stored per repo in `git_repos.estimation_strategy`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EstimationStrategy {
+    /// Time since the previous commit, falling back to diff size
+    #[default]
+    CommitInterval,
+    /// Lines and files changed
+    DiffSize,
+    /// The same hours for every commit
+    Fixed(f64),
+}
+
+impl EstimationStrategy {
+    /// Parse `commit_interval`, `diff_size` or `fixed:<hours>`; dashes work
+    /// in place of underscores
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let normalized = s.trim().to_lowercase().replace('-', "_");
+        match normalized.as_str() {
+            "commit_interval" => Ok(Self::CommitInterval),
+            "diff_size" => Ok(Self::DiffSize),
+            other => {
+                let hours = other
+                    .strip_prefix("fixed:")
+                    .ok_or_else(|| {
+                        format!("Unknown estimation strategy '{}'. Use commit_interval, diff_size or fixed:<hours>", s)
+                    })?
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid fixed hours in '{}'", s))?;
+                if !(hours > 0.0 && hours <= 24.0) {
+                    return Err(format!("Fixed hours must be between 0 and 24, got {}", hours));
+                }
+                Ok(Self::Fixed(hours))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for EstimationStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CommitInterval => write!(f, "commit_interval"),
+            Self::DiffSize => write!(f, "diff_size"),
+            Self::Fixed(hours) => write!(f, "fixed:{}", hours),
+        }
+    }
+}
+
+/// Load the estimation strategy configured for a repo, falling back to the
+/// default for unknown repos and unreadable values
+pub async fn get_repo_estimation_strategy(pool: &SqlitePool, user_id: &str, repo_path: &str) -> EstimationStrategy {
+    sqlx::query_scalar::<_, Option<String>>(
+        "SELECT estimation_strategy FROM git_repos WHERE user_id = ? AND path = ?",
+    )
+    .bind(user_id)
+    .bind(repo_path)
+    .fetch_optional(pool)
+    .await
+    .ok()
+    .flatten()
+    .flatten()
+    .and_then(|s| EstimationStrategy::parse(&s).ok())
+    .unwrap_or_default()
+}
+
+/// Set a repo's estimation strategy. Returns `false` when the user has no
+/// such repo.
+pub async fn set_repo_estimation_strategy(
+    pool: &SqlitePool,
+    user_id: &str,
+    repo_path: &str,
+    strategy: EstimationStrategy,
+) -> Result<bool, String> {
+    let result = sqlx::query("UPDATE git_repos SET estimation_strategy = ? WHERE user_id = ? AND path = ?")
+        .bind(strategy.to_string())
+        .bind(user_id)
+        .bind(repo_path)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Estimate hours for a commit based on available data.
+///
+/// A user override or linked session wins; otherwise `strategy` decides.
+#[allow(clippy::too_many_arguments)]
 pub fn estimate_commit_hours(
     commit_time: &DateTime<FixedOffset>,
     prev_commit_time: Option<&DateTime<FixedOffset>>,
@@ -110,6 +199,7 @@ pub fn estimate_commit_hours(
     deletions: i32,
     files_count: usize,
     user_override: Option<f64>,
+    strategy: EstimationStrategy,
 ) -> HoursEstimate {
     // Priority 1: User manually set hours
     if let Some(hours) = user_override {
@@ -127,16 +217,22 @@ pub fn estimate_commit_hours(
         };
     }
 
+    if let EstimationStrategy::Fixed(hours) = strategy {
+        return HoursEstimate {
+            hours,
+            source: HoursSource::Fixed,
+        };
+    }
+
     // Priority 3: Commit interval (if previous commit exists and gap is reasonable)
-    if let Some(prev_time) = prev_commit_time {
+    if let (EstimationStrategy::CommitInterval, Some(prev_time)) = (strategy, prev_commit_time) {
         let gap = commit_time.signed_duration_since(*prev_time);
         let gap_minutes = gap.num_minutes();
 
         // Only use interval if gap is between 5 minutes and 4 hours
         if gap_minutes > 5 && gap_minutes < 240 {
             let raw_hours = (gap_minutes as f64 / 60.0).min(4.0).max(0.25);
-            // Round to nearest 0.25
-            let hours = (raw_hours * 4.0).round() / 4.0;
+            let hours = round_hours(raw_hours);
             return HoursEstimate {
                 hours,
                 source: HoursSource::CommitInterval,
@@ -171,13 +267,32 @@ pub fn estimate_from_diff(additions: i32, deletions: i32, files_count: usize) ->
     // Combine and clamp
     let hours = (line_factor + file_factor).max(0.25).min(4.0);
 
-    // Round to nearest 0.25
+    round_hours(hours)
+}
+
+/// Round hours to the nearest 0.25h, the granularity used for all estimates
+pub fn round_hours(hours: f64) -> f64 {
     (hours * 4.0).round() / 4.0
 }
 
 /// Get commits for a specific date from a git repository.
 /// If `author_filter` is Some, only commits by the matching author (email) are returned.
 pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Option<&str>) -> Vec<CommitRecord> {
+    get_user_commits_for_date(repo_path, date, author_filter, false, EstimationStrategy::default())
+}
+
+/// Get commits for a specific date, optionally including co-authored ones.
+///
+/// With `include_coauthored`, commits whose `Co-authored-by:` trailers list
+/// `user_email` are returned as well and marked `co_authored`. Hours are
+/// estimated with the repo's `strategy`.
+pub fn get_user_commits_for_date(
+    repo_path: &str,
+    date: &NaiveDate,
+    user_email: Option<&str>,
+    include_coauthored: bool,
+    strategy: EstimationStrategy,
+) -> Vec<CommitRecord> {
     let repo_dir = PathBuf::from(repo_path);
 
     if !repo_dir.exists() || !repo_dir.join(".git").exists() {
@@ -194,9 +309,10 @@ pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Op
         .arg(&since)
         .arg("--until")
         .arg(&until)
-        .arg("--format=%H|%h|%an|%aI|%s")
+        .arg("--format=%H|%h|%an|%ae|%aI|%(trailers:key=Co-authored-by,valueonly,separator=%x1f)|%s")
         .arg("--all");
-    if let Some(author) = author_filter {
+    // Co-authored commits have another author, so filter after parsing instead
+    if let (Some(author), false) = (user_email, include_coauthored) {
         cmd.arg("--author").arg(author);
     }
     let output = cmd
@@ -214,16 +330,28 @@ pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Op
 
     for line in stdout.lines().rev() {
         // Parse in reverse order (oldest first) for interval calculation
-        let parts: Vec<&str> = line.splitn(5, '|').collect();
-        if parts.len() < 5 {
+        let parts: Vec<&str> = line.splitn(7, '|').collect();
+        if parts.len() < 7 {
             continue;
         }
 
         let hash = parts[0].to_string();
         let short_hash = parts[1].to_string();
         let author = parts[2].to_string();
-        let time_str = parts[3].to_string();
-        let message = parts[4].to_string();
+        let author_email = parts[3];
+        let time_str = parts[4].to_string();
+        let co_authors = co_author_emails(parts[5]);
+        let message = parts[6].to_string();
+
+        let co_authored = match user_email {
+            Some(email) if include_coauthored && !author_email.eq_ignore_ascii_case(email) => {
+                if !co_authors.iter().any(|c| c.eq_ignore_ascii_case(email)) {
+                    continue;
+                }
+                true
+            }
+            _ => false,
+        };
 
         // Parse commit time
         let commit_time = match DateTime::parse_from_rfc3339(&time_str) {
@@ -243,6 +371,7 @@ pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Op
             deletions,
             files_changed.len(),
             None, // No user override
+            strategy,
         );
 
         commits.push(CommitRecord {
@@ -259,6 +388,7 @@ pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Op
             hours_source: estimate.source.as_str().to_string(),
             hours_estimated: estimate.hours,
             related_session: None,
+            co_authored,
         });
 
         prev_time = Some(commit_time);
@@ -269,6 +399,93 @@ pub fn get_commits_for_date(repo_path: &str, date: &NaiveDate, author_filter: Op
     commits
 }
 
+/// Extract emails from `Co-authored-by:` trailer values.
+///
+/// Values look like `Name <email>` and are separated by `\x1f` (as produced by
+/// the `--format` used in [`get_user_commits_for_date`]) or newlines.
+pub fn co_author_emails(trailers: &str) -> Vec<String> {
+    trailers
+        .split(['\x1f', '\n'])
+        .filter_map(|value| {
+            let start = value.find('<')?;
+            let end = value[start..].find('>')? + start;
+            let email = value[start + 1..end].trim();
+            (!email.is_empty()).then(|| email.to_string())
+        })
+        .collect()
+}
+
+/// Files whose changes never make a commit substantive on their own
+/// (lockfiles are regenerated by version bumps and dependency updates)
+const TRIVIAL_FILE_NAMES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "poetry.lock",
+    "Gemfile.lock",
+    "go.sum",
+];
+
+fn is_trivial_file(path: &str) -> bool {
+    std::path::Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(|name| TRIVIAL_FILE_NAMES.contains(&name))
+        .unwrap_or(false)
+}
+
+/// Count changed lines in a commit, ignoring lockfiles
+pub fn substantive_line_count(commit: &CommitRecord) -> i32 {
+    commit
+        .files_changed
+        .iter()
+        .filter(|f| !is_trivial_file(&f.path))
+        .map(|f| f.additions + f.deletions)
+        .sum()
+}
+
+/// Drop commits whose substantive changed lines fall below `min_lines`.
+///
+/// Commits are expected newest first (as returned by `get_commits_for_date`).
+/// When `fold_hours` is set, the hours of a skipped commit are added to the
+/// next kept commit in chronological order, or to the last kept commit when
+/// no real commit follows it. A `min_lines` of 0 keeps everything.
+pub fn filter_trivial_commits(
+    commits: Vec<CommitRecord>,
+    min_lines: i32,
+    fold_hours: bool,
+) -> Vec<CommitRecord> {
+    if min_lines <= 0 {
+        return commits;
+    }
+
+    let mut kept = Vec::with_capacity(commits.len());
+    let mut carried_hours = 0.0;
+
+    // Walk oldest first so skipped hours fold forward into the next real commit
+    for mut commit in commits.into_iter().rev() {
+        if substantive_line_count(&commit) < min_lines {
+            if fold_hours {
+                carried_hours += commit.hours;
+            }
+            continue;
+        }
+        if carried_hours > 0.0 {
+            commit.hours += carried_hours;
+            carried_hours = 0.0;
+        }
+        kept.push(commit);
+    }
+    // Trivial commits after the last real one fold back into it
+    if let Some(last) = kept.last_mut() {
+        last.hours += carried_hours;
+    }
+
+    kept.reverse();
+    kept
+}
+
 /// Get file changes for a specific commit
 fn get_commit_file_changes(repo_dir: &PathBuf, hash: &str) -> (Vec<FileChange>, i32, i32) {
     let output = create_command("git")
@@ -312,18 +529,38 @@ fn get_commit_file_changes(repo_dir: &PathBuf, hash: &str) -> (Vec<FileChange>,
     (files, total_add, total_del)
 }
 
+/// Session length cap used when the user has not configured one
+pub const DEFAULT_MAX_SESSION_HOURS: f64 = 8.0;
+
 /// Calculate session hours from start and end timestamps
 /// Returns hours capped between 0.25 and 8.0, rounded to nearest 0.25h
+///
+/// Naive timestamps are taken to be in the system timezone; see
+/// [`calculate_session_hours_in_timezone`].
 pub fn calculate_session_hours(start: &str, end: &str) -> f64 {
-    if let (Ok(start_dt), Ok(end_dt)) = (
-        DateTime::parse_from_rfc3339(start),
-        DateTime::parse_from_rfc3339(end),
+    calculate_session_hours_in_timezone(start, end, None)
+}
+
+/// Calculate session hours, reading naive timestamps in `timezone`.
+///
+/// Timestamps may use `Z` or an explicit offset, carry fractional seconds,
+/// or omit the offset entirely (see [`parse_timestamp`]).
+pub fn calculate_session_hours_in_timezone(start: &str, end: &str, timezone: Option<&str>) -> f64 {
+    calculate_session_hours_capped(start, end, timezone, DEFAULT_MAX_SESSION_HOURS)
+}
+
+/// Calculate session hours capped at `max_hours` instead of the default 8h
+/// (see [`load_max_session_hours`](super::sync::load_max_session_hours)).
+pub fn calculate_session_hours_capped(start: &str, end: &str, timezone: Option<&str>, max_hours: f64) -> f64 {
+    if let (Some(start_dt), Some(end_dt)) = (
+        parse_timestamp(start, timezone),
+        parse_timestamp(end, timezone),
     ) {
         let duration = end_dt.signed_duration_since(start_dt);
         let hours = duration.num_minutes() as f64 / 60.0;
-        let capped = hours.min(8.0).max(0.25);
-        // Round to nearest 0.25h for consistency with commit hours
-        (capped * 4.0).round() / 4.0
+        let capped = hours.min(max_hours).max(0.25);
+        // Round for consistency with commit hours
+        round_hours(capped)
     } else {
         0.5 // Default fallback
     }
@@ -440,9 +677,101 @@ pub fn build_rule_based_outcome(
     parts.join("; ")
 }
 
+/// Keyword rules for [`classify_category`], checked in order
+const CATEGORY_RULES: &[(&str, &[&str])] = &[
+    ("Bugfix", &["fix", "fixed", "fixes", "fixing", "bug", "bugs", "bugfix", "hotfix", "crash", "regression"]),
+    ("Testing", &["test", "tests", "testing", "tested", "e2e", "spec", "specs", "coverage"]),
+    ("Docs", &["doc", "docs", "documentation", "document", "readme", "changelog"]),
+    ("Refactor", &["refactor", "refactored", "refactoring", "refactors", "cleanup", "restructure"]),
+];
+
+/// Build a rule-based category for a work item from keywords in its text.
+///
+/// The fallback when no LLM is configured. The title is checked before the
+/// description, and earlier rules win when a text matches several.
+pub fn classify_category(title: &str, description: Option<&str>) -> Option<&'static str> {
+    [Some(title), description]
+        .into_iter()
+        .flatten()
+        .find_map(match_category_rule)
+}
+
+fn match_category_rule(text: &str) -> Option<&'static str> {
+    let words: HashSet<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    CATEGORY_RULES
+        .iter()
+        .find(|(_, keywords)| keywords.iter().any(|k| words.contains(*k)))
+        .map(|(category, _)| *category)
+}
+
+/// A category picked by [`classify_category`] for an uncategorized item
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CategoryAssignment {
+    pub id: String,
+    pub date: NaiveDate,
+    pub title: String,
+    pub category: String,
+}
+
+/// Classify the uncategorized work items of `user_id` between `start` and
+/// `end` (inclusive). Items no rule matches are left out. With `apply` the
+/// categories are written in one transaction; otherwise nothing changes.
+pub async fn classify_uncategorized_items(
+    pool: &SqlitePool,
+    user_id: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    apply: bool,
+) -> Result<Vec<CategoryAssignment>, String> {
+    let rows: Vec<(String, NaiveDate, String, Option<String>)> = sqlx::query_as(
+        r#"SELECT id, date, title, description FROM work_items
+           WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
+             AND (category IS NULL OR category = '')
+           ORDER BY date, created_at"#,
+    )
+    .bind(user_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let assignments: Vec<CategoryAssignment> = rows
+        .into_iter()
+        .filter_map(|(id, date, title, description)| {
+            let category = classify_category(&title, description.as_deref())?;
+            Some(CategoryAssignment { id, date, title, category: category.to_string() })
+        })
+        .collect();
+
+    if apply && !assignments.is_empty() {
+        let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+        let now = Utc::now();
+        for assignment in &assignments {
+            sqlx::query(
+                "UPDATE work_items SET category = ?, updated_at = ? WHERE id = ? AND (category IS NULL OR category = '')",
+            )
+            .bind(&assignment.category)
+            .bind(now)
+            .bind(&assignment.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+        tx.commit().await.map_err(|e| e.to_string())?;
+    }
+
+    Ok(assignments)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::db_with_user;
 
     #[test]
     fn test_estimate_from_diff_small_change() {
@@ -475,7 +804,7 @@ mod tests {
     #[test]
     fn test_estimate_commit_hours_user_override() {
         let time = DateTime::parse_from_rfc3339("2026-01-11T10:00:00+08:00").unwrap();
-        let estimate = estimate_commit_hours(&time, None, None, 100, 10, 2, Some(3.5));
+        let estimate = estimate_commit_hours(&time, None, None, 100, 10, 2, Some(3.5), EstimationStrategy::default());
         assert_eq!(estimate.hours, 3.5);
         assert_eq!(estimate.source, HoursSource::UserModified);
     }
@@ -489,7 +818,7 @@ mod tests {
             first_message: None,
             tools_used: HashMap::new(),
         };
-        let estimate = estimate_commit_hours(&time, None, Some(&session), 100, 10, 2, None);
+        let estimate = estimate_commit_hours(&time, None, Some(&session), 100, 10, 2, None, EstimationStrategy::default());
         assert_eq!(estimate.hours, 2.5);
         assert_eq!(estimate.source, HoursSource::Session);
     }
@@ -498,7 +827,7 @@ mod tests {
     fn test_estimate_commit_hours_interval() {
         let prev_time = DateTime::parse_from_rfc3339("2026-01-11T09:00:00+08:00").unwrap();
         let time = DateTime::parse_from_rfc3339("2026-01-11T10:30:00+08:00").unwrap();
-        let estimate = estimate_commit_hours(&time, Some(&prev_time), None, 100, 10, 2, None);
+        let estimate = estimate_commit_hours(&time, Some(&prev_time), None, 100, 10, 2, None, EstimationStrategy::default());
         assert_eq!(estimate.hours, 1.5);
         assert_eq!(estimate.source, HoursSource::CommitInterval);
     }
@@ -506,11 +835,41 @@ mod tests {
     #[test]
     fn test_estimate_commit_hours_heuristic_fallback() {
         let time = DateTime::parse_from_rfc3339("2026-01-11T10:00:00+08:00").unwrap();
-        let estimate = estimate_commit_hours(&time, None, None, 100, 10, 2, None);
+        let estimate = estimate_commit_hours(&time, None, None, 100, 10, 2, None, EstimationStrategy::default());
         assert_eq!(estimate.source, HoursSource::Heuristic);
         assert!(estimate.hours > 0.0);
     }
 
+    #[test]
+    fn test_estimate_commit_hours_strategies() {
+        let prev_time = DateTime::parse_from_rfc3339("2026-01-11T09:00:00+08:00").unwrap();
+        let time = DateTime::parse_from_rfc3339("2026-01-11T10:30:00+08:00").unwrap();
+
+        // Diff size ignores the interval
+        let estimate = estimate_commit_hours(&time, Some(&prev_time), None, 100, 10, 2, None, EstimationStrategy::DiffSize);
+        assert_eq!(estimate.source, HoursSource::Heuristic);
+        assert_eq!(estimate.hours, estimate_from_diff(100, 10, 2));
+
+        let estimate = estimate_commit_hours(&time, Some(&prev_time), None, 100, 10, 2, None, EstimationStrategy::Fixed(0.5));
+        assert_eq!(estimate.hours, 0.5);
+        assert_eq!(estimate.source, HoursSource::Fixed);
+
+        // A user override still wins
+        let estimate = estimate_commit_hours(&time, None, None, 100, 10, 2, Some(2.0), EstimationStrategy::Fixed(0.5));
+        assert_eq!(estimate.source, HoursSource::UserModified);
+    }
+
+    #[test]
+    fn test_estimation_strategy_parse_round_trip() {
+        for strategy in [EstimationStrategy::CommitInterval, EstimationStrategy::DiffSize, EstimationStrategy::Fixed(0.75)] {
+            assert_eq!(EstimationStrategy::parse(&strategy.to_string()), Ok(strategy));
+        }
+        assert_eq!(EstimationStrategy::parse("Diff-Size"), Ok(EstimationStrategy::DiffSize));
+        assert!(EstimationStrategy::parse("fixed:0").is_err());
+        assert!(EstimationStrategy::parse("fixed:abc").is_err());
+        assert!(EstimationStrategy::parse("vibes").is_err());
+    }
+
     #[test]
     fn test_build_rule_based_outcome_files() {
         let files = vec![
@@ -542,6 +901,91 @@ mod tests {
 
     // Tests for shared functions (used by Timeline and commit-centric worklog)
 
+    fn commit_with_changes(hash: &str, hours: f64, files: Vec<(&str, i32, i32)>) -> CommitRecord {
+        let files_changed: Vec<FileChange> = files
+            .into_iter()
+            .map(|(path, additions, deletions)| FileChange {
+                path: path.to_string(),
+                additions,
+                deletions,
+            })
+            .collect();
+        CommitRecord {
+            hash: hash.to_string(),
+            short_hash: hash.to_string(),
+            message: hash.to_string(),
+            author: "dev".to_string(),
+            time: "2026-01-16T10:00:00+08:00".to_string(),
+            date: "2026-01-16".to_string(),
+            total_additions: files_changed.iter().map(|f| f.additions).sum(),
+            total_deletions: files_changed.iter().map(|f| f.deletions).sum(),
+            files_changed,
+            hours,
+            hours_source: "heuristic".to_string(),
+            hours_estimated: hours,
+            related_session: None,
+            co_authored: false,
+        }
+    }
+
+    #[test]
+    fn test_filter_trivial_commits_drops_version_bump() {
+        // Newest first: substantive commit came after the bump
+        let commits = vec![
+            commit_with_changes("feature", 1.5, vec![("src/lib.rs", 40, 12)]),
+            commit_with_changes("bump", 0.25, vec![("Cargo.toml", 1, 1)]),
+        ];
+
+        let kept = filter_trivial_commits(commits, 5, false);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].hash, "feature");
+        assert_eq!(kept[0].hours, 1.5);
+    }
+
+    #[test]
+    fn test_filter_trivial_commits_folds_hours_forward() {
+        let commits = vec![
+            commit_with_changes("feature", 1.5, vec![("src/lib.rs", 40, 12)]),
+            commit_with_changes("bump", 0.25, vec![("Cargo.toml", 1, 0)]),
+        ];
+
+        let kept = filter_trivial_commits(commits, 5, true);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].hours, 1.75);
+    }
+
+    #[test]
+    fn test_filter_trivial_commits_folds_trailing_hours_back() {
+        // Newest first: two bumps after the last substantive commit
+        let commits = vec![
+            commit_with_changes("bump-2", 0.25, vec![("Cargo.toml", 1, 0)]),
+            commit_with_changes("bump-1", 0.5, vec![("package.json", 1, 1)]),
+            commit_with_changes("feature", 1.5, vec![("src/lib.rs", 40, 12)]),
+            commit_with_changes("first", 1.0, vec![("src/main.rs", 20, 3)]),
+        ];
+
+        let kept = filter_trivial_commits(commits.clone(), 5, true);
+        let hours: Vec<(&str, f64)> = kept.iter().map(|c| (c.hash.as_str(), c.hours)).collect();
+        assert_eq!(hours, [("feature", 2.25), ("first", 1.0)]);
+
+        let kept = filter_trivial_commits(commits, 5, false);
+        assert_eq!(kept[0].hours, 1.5);
+    }
+
+    #[test]
+    fn test_filter_trivial_commits_ignores_lockfiles() {
+        let commits = vec![
+            commit_with_changes("deps", 0.5, vec![("Cargo.lock", 300, 120), ("Cargo.toml", 1, 1)]),
+        ];
+        assert!(filter_trivial_commits(commits, 5, false).is_empty());
+    }
+
+    #[test]
+    fn test_filter_trivial_commits_zero_threshold_keeps_all() {
+        let commits = vec![commit_with_changes("bump", 0.25, vec![("Cargo.toml", 1, 0)])];
+        assert_eq!(filter_trivial_commits(commits, 0, false).len(), 1);
+    }
+
     #[test]
     fn test_calculate_session_hours_valid() {
         let hours = calculate_session_hours(
@@ -571,6 +1015,55 @@ mod tests {
         assert_eq!(hours, 0.25, "Should cap at minimum 0.25 hours");
     }
 
+    #[test]
+    fn test_calculate_session_hours_configured_cap() {
+        let start = "2026-01-11T08:00:00+08:00";
+        let ten_hours = "2026-01-11T18:00:00+08:00";
+        let six_hours = "2026-01-11T14:00:00+08:00";
+
+        // A 10h day isn't truncated when the cap allows it
+        assert_eq!(calculate_session_hours_capped(start, ten_hours, None, 12.0), 10.0);
+        // Still capped when the session runs past the configured limit
+        assert_eq!(calculate_session_hours_capped(start, ten_hours, None, 9.0), 9.0);
+        // Sessions below the cap are unaffected
+        assert_eq!(calculate_session_hours_capped(start, six_hours, None, 9.0), 6.0);
+        assert_eq!(calculate_session_hours_capped(start, six_hours, None, 4.0), 4.0);
+    }
+
+    #[test]
+    fn test_calculate_session_hours_z_suffix() {
+        let hours = calculate_session_hours("2026-01-11T01:00:00Z", "2026-01-11T03:30:00Z");
+        assert_eq!(hours, 2.5);
+    }
+
+    #[test]
+    fn test_calculate_session_hours_milliseconds() {
+        let hours = calculate_session_hours("2026-01-11T01:00:00.123Z", "2026-01-11T02:45:59.999Z");
+        assert_eq!(hours, 1.75);
+
+        // Mixed precision and offsets
+        let hours = calculate_session_hours("2026-01-11T09:00:00.123456789+08:00", "2026-01-11T03:00:00Z");
+        assert_eq!(hours, 2.0);
+    }
+
+    #[test]
+    fn test_calculate_session_hours_without_offset() {
+        let hours = calculate_session_hours_in_timezone(
+            "2026-01-11T09:00:00",
+            "2026-01-11T12:15:00.500",
+            Some("Asia/Taipei"),
+        );
+        assert_eq!(hours, 3.25);
+
+        // Naive start in the user's timezone, offset end
+        let hours = calculate_session_hours_in_timezone(
+            "2026-01-11T09:00:00",
+            "2026-01-11T02:00:00Z",
+            Some("Asia/Taipei"),
+        );
+        assert_eq!(hours, 1.0);
+    }
+
     #[test]
     fn test_calculate_session_hours_invalid() {
         // Invalid timestamps should return default 0.5h
@@ -652,4 +1145,67 @@ mod tests {
         // Based on git log, there should be a commit at 09:28:59
         assert!(!commits.is_empty(), "Should find commit at 09:28:59 in 09:00-10:00 range");
     }
+
+    #[test]
+    fn test_co_author_emails() {
+        let trailers = "Alice <alice@example.com>\x1fBob Smith <BOB@example.com>\x1fno email here";
+        assert_eq!(
+            co_author_emails(trailers),
+            vec!["alice@example.com".to_string(), "BOB@example.com".to_string()]
+        );
+        assert!(co_author_emails("").is_empty());
+    }
+
+    #[test]
+    fn test_classify_category() {
+        assert_eq!(classify_category("fix: login redirect loop", None), Some("Bugfix"));
+        assert_eq!(classify_category("Add e2e tests for checkout", None), Some("Testing"));
+        assert_eq!(classify_category("Update README", None), Some("Docs"));
+        assert_eq!(classify_category("Refactoring the sync loop", None), Some("Refactor"));
+        // Whole words only: "prefix" and "docker" are not "fix" and "doc"
+        assert_eq!(classify_category("Prefix docker image names", None), None);
+        // The title wins over the description; earlier rules win within a text
+        assert_eq!(classify_category("Tidy docs", Some("fix typo")), Some("Docs"));
+        assert_eq!(classify_category("Fix flaky test", None), Some("Bugfix"));
+        assert_eq!(classify_category("Session work", Some("Wrote the changelog")), Some("Docs"));
+    }
+
+    #[tokio::test]
+    async fn test_classify_uncategorized_items_dry_run_and_apply() {
+        let (db, _dir) = db_with_user().await;
+        for (id, title, category) in [
+            ("a", "Fix invoice rounding", None),
+            ("b", "Write tests for billing", Some("Billing")),
+            ("c", "Pairing session", None),
+        ] {
+            sqlx::query("INSERT INTO work_items (id, user_id, title, hours, date, category) VALUES (?, 'u1', ?, 1.0, '2026-03-02', ?)")
+                .bind(id)
+                .bind(title)
+                .bind(category)
+                .execute(&db.pool)
+                .await
+                .unwrap();
+        }
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let category = |id: &'static str| {
+            let pool = db.pool.clone();
+            async move {
+                sqlx::query_scalar::<_, Option<String>>("SELECT category FROM work_items WHERE id = ?")
+                    .bind(id)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let preview = classify_uncategorized_items(&db.pool, "u1", day, day, false).await.unwrap();
+        // Already categorized and unmatched items are left out
+        assert_eq!(preview.iter().map(|a| (a.id.as_str(), a.category.as_str())).collect::<Vec<_>>(), vec![("a", "Bugfix")]);
+        assert_eq!(category("a").await, None);
+
+        assert_eq!(classify_uncategorized_items(&db.pool, "u1", day, day, true).await.unwrap(), preview);
+        assert_eq!(category("a").await.as_deref(), Some("Bugfix"));
+        assert_eq!(category("b").await.as_deref(), Some("Billing"));
+        assert!(classify_uncategorized_items(&db.pool, "u1", day, day, true).await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,26 @@
+//! Shared test fixtures
+//!
+//! Available to this crate's unit tests and, through the `test-support`
+//! feature, to the tests of crates built on it.
+
+use crate::Database;
+
+/// Open a migrated database in a fresh temp dir with user `u1` inserted.
+///
+/// Keep the returned `TempDir` alive as long as the database is used.
+pub async fn db_with_user() -> (Database, tempfile::TempDir) {
+    let dir = tempfile::TempDir::new().unwrap();
+    let db = Database::open(dir.path().join("test.db")).await.unwrap();
+    insert_user(&db, "u1").await;
+    (db, dir)
+}
+
+/// Insert user `id` (email `<id>@example.com`, name "Test", no password)
+pub async fn insert_user(db: &Database, id: &str) {
+    sqlx::query("INSERT INTO users (id, email, password_hash, name) VALUES (?, ?, '', 'Test')")
+        .bind(id)
+        .bind(format!("{}@example.com", id))
+        .execute(&db.pool)
+        .await
+        .unwrap();
+}
@@ -2,6 +2,8 @@
 
 use std::process::Command;
 
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
 /// Creates a Command that hides the console window on Windows.
 ///
 /// On Windows, GUI applications spawning console processes (like `git`) will
@@ -33,6 +35,94 @@ pub fn create_command(program: &str) -> Command {
     cmd
 }
 
+/// Current date in the user's timezone.
+///
+/// `timezone` is an IANA name (e.g. "Asia/Taipei") as stored in the user
+/// settings; when unset or unrecognized the system local timezone is used.
+pub fn today_in_timezone(timezone: Option<&str>, now: DateTime<Utc>) -> NaiveDate {
+    match timezone.and_then(|tz| tz.parse::<chrono_tz::Tz>().ok()) {
+        Some(tz) => now.with_timezone(&tz).date_naive(),
+        None => now.with_timezone(&Local).date_naive(),
+    }
+}
+
+/// Parse an IANA timezone name such as "Asia/Taipei" or "UTC".
+pub fn parse_timezone(name: &str) -> Result<chrono_tz::Tz, String> {
+    name.trim().parse::<chrono_tz::Tz>().map_err(|_| {
+        format!("Unknown timezone: {}. Use an IANA name such as UTC or Asia/Taipei", name)
+    })
+}
+
+/// Calendar date of a timestamp in `timezone` (system timezone if unset).
+///
+/// Accepts the formats of [`parse_timestamp`]; returns `None` when the
+/// timestamp cannot be parsed.
+pub fn date_in_timezone(timestamp: &str, timezone: Option<&str>) -> Option<NaiveDate> {
+    let instant = parse_timestamp(timestamp, timezone)?;
+    Some(match timezone.and_then(|tz| tz.parse::<chrono_tz::Tz>().ok()) {
+        Some(tz) => instant.with_timezone(&tz).date_naive(),
+        None => instant.with_timezone(&Local).date_naive(),
+    })
+}
+
+/// Day a work item falls on in `timezone` (system timezone if unset): the
+/// local date of its start time, or the stored `date` when it has none.
+///
+/// Stored dates come from the UTC session timestamp, so a session started at
+/// 00:30 in Taipei is stored on the previous day.
+pub fn local_work_date(date: NaiveDate, start_time: Option<&str>, timezone: Option<&str>) -> NaiveDate {
+    start_time
+        .and_then(|start| date_in_timezone(start, timezone))
+        .unwrap_or(date)
+}
+
+/// `timestamp` as RFC 3339 in `timezone` (system timezone if unset).
+pub fn timestamp_in_timezone(timestamp: &str, timezone: Option<&str>) -> Option<String> {
+    let instant = parse_timestamp(timestamp, timezone)?;
+    Some(match timezone.and_then(|tz| tz.parse::<chrono_tz::Tz>().ok()) {
+        Some(tz) => instant.with_timezone(&tz).to_rfc3339(),
+        None => instant.with_timezone(&Local).to_rfc3339(),
+    })
+}
+
+/// Parse a session timestamp into a UTC instant.
+///
+/// Accepts RFC 3339 (`Z` or `+08:00`, any fractional-second precision),
+/// offsets without a colon (`+0800`), and naive timestamps such as
+/// `2026-01-15T09:00:00.123` or `2026-01-15 09:00:00`, which are taken to be
+/// in `timezone` (system timezone if unset).
+pub fn parse_timestamp(timestamp: &str, timezone: Option<&str>) -> Option<DateTime<Utc>> {
+    let timestamp = timestamp.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(timestamp) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    let naive = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(timestamp, fmt).ok())?;
+
+    // Ambiguous local times (DST fall-back) resolve to the earlier instant
+    match timezone.and_then(|tz| tz.parse::<chrono_tz::Tz>().ok()) {
+        Some(tz) => tz.from_local_datetime(&naive).earliest().map(|dt| dt.with_timezone(&Utc)),
+        None => Local.from_local_datetime(&naive).earliest().map(|dt| dt.with_timezone(&Utc)),
+    }
+}
+
+/// Parse a date argument: `today`, `yesterday` or `YYYY-MM-DD`.
+///
+/// Keywords are resolved relative to `today` (see [`today_in_timezone`]).
+pub fn parse_date_arg(s: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    match s.trim().to_lowercase().as_str() {
+        "today" => Ok(today),
+        "yesterday" => Ok(today - Duration::days(1)),
+        other => NaiveDate::parse_from_str(other, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date format: {}. Use YYYY-MM-DD, today or yesterday", s)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +143,91 @@ mod tests {
         // The exact format may vary, but we just verify it compiles and runs
         assert!(debug_str.contains("cmd"));
     }
+
+    #[test]
+    fn test_parse_date_arg_yesterday_near_midnight_plus_eight() {
+        // 16:30 UTC is 00:30 the next day in Asia/Taipei (UTC+8)
+        let now = DateTime::parse_from_rfc3339("2026-01-15T16:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let today = today_in_timezone(Some("Asia/Taipei"), now);
+        assert_eq!(today, NaiveDate::from_ymd_opt(2026, 1, 16).unwrap());
+
+        assert_eq!(parse_date_arg("yesterday", today).unwrap(), NaiveDate::from_ymd_opt(2026, 1, 15).unwrap());
+        assert_eq!(parse_date_arg("Today", today).unwrap(), today);
+    }
+
+    #[test]
+    fn test_parse_date_arg_explicit_and_invalid() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 16).unwrap();
+        assert_eq!(parse_date_arg("2025-12-31", today).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+        assert!(parse_date_arg("2025/12/31", today).is_err());
+        assert!(parse_date_arg("tomorrow", today).is_err());
+    }
+
+    #[test]
+    fn test_today_in_timezone_unknown_falls_back_to_local() {
+        let now = Utc::now();
+        assert_eq!(today_in_timezone(Some("Not/AZone"), now), now.with_timezone(&Local).date_naive());
+        assert_eq!(today_in_timezone(None, now), now.with_timezone(&Local).date_naive());
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(parse_timezone("Asia/Taipei").unwrap(), chrono_tz::Asia::Taipei);
+        assert_eq!(parse_timezone("UTC").unwrap(), chrono_tz::UTC);
+
+        let err = parse_timezone("Mars/Olympus").unwrap_err();
+        assert!(err.contains("Mars/Olympus"));
+    }
+
+    #[test]
+    fn test_local_work_date_session_spanning_utc_midnight() {
+        // 23:30–00:30 UTC is 07:30–08:30 on the next day in Taipei
+        let stored = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let start = Some("2026-03-01T23:30:00Z");
+        assert_eq!(
+            local_work_date(stored, start, Some("Asia/Taipei")),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+        );
+        assert_eq!(local_work_date(stored, start, Some("UTC")), stored);
+        assert_eq!(local_work_date(stored, None, Some("Asia/Taipei")), stored);
+        assert_eq!(local_work_date(stored, Some("garbage"), Some("Asia/Taipei")), stored);
+    }
+
+    #[test]
+    fn test_timestamp_in_timezone() {
+        assert_eq!(
+            timestamp_in_timezone("2026-03-02T00:30:00Z", Some("Asia/Taipei")).as_deref(),
+            Some("2026-03-02T08:30:00+08:00")
+        );
+        assert_eq!(timestamp_in_timezone("nope", Some("UTC")), None);
+    }
+
+    #[test]
+    fn test_date_in_timezone_crosses_midnight() {
+        let ts = "2026-01-15T17:30:00Z";
+        assert_eq!(date_in_timezone(ts, Some("UTC")), NaiveDate::from_ymd_opt(2026, 1, 15));
+        assert_eq!(date_in_timezone(ts, Some("Asia/Taipei")), NaiveDate::from_ymd_opt(2026, 1, 16));
+        assert_eq!(date_in_timezone("not a timestamp", Some("UTC")), None);
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let expected = Utc.with_ymd_and_hms(2026, 1, 15, 1, 0, 0).unwrap();
+        let tz = Some("Asia/Taipei");
+
+        assert_eq!(parse_timestamp("2026-01-15T01:00:00Z", tz), Some(expected));
+        assert_eq!(parse_timestamp("2026-01-15T09:00:00+08:00", tz), Some(expected));
+        assert_eq!(parse_timestamp("2026-01-15T09:00:00+0800", tz), Some(expected));
+        assert_eq!(parse_timestamp("2026-01-15T01:00:00.000Z", tz), Some(expected));
+        assert_eq!(parse_timestamp("2026-01-15T01:00:00.123456789Z", tz).map(|t| t.timestamp()), Some(expected.timestamp()));
+
+        // Naive timestamps are in the given timezone
+        assert_eq!(parse_timestamp("2026-01-15T09:00:00", tz), Some(expected));
+        assert_eq!(parse_timestamp("2026-01-15 09:00:00.250", tz).map(|t| t.timestamp()), Some(expected.timestamp()));
+        assert_eq!(parse_timestamp("2026-01-15T01:00:00", Some("UTC")), Some(expected));
+
+        assert_eq!(parse_timestamp("not a timestamp", tz), None);
+    }
 }
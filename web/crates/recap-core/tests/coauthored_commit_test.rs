@@ -0,0 +1,102 @@
+//! Integration test for Co-authored-by commit attribution
+
+use std::path::Path;
+use std::process::Command;
+
+use chrono::NaiveDate;
+use recap_core::db::Database;
+use recap_core::services::{
+    get_user_commits_for_date, upsert_work_item, EstimationStrategy, WorkItemParams, CO_AUTHORED_TAG,
+};
+use tempfile::TempDir;
+
+const USER_EMAIL: &str = "me@example.com";
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .env("GIT_AUTHOR_DATE", "2026-01-15T10:00:00")
+        .env("GIT_COMMITTER_DATE", "2026-01-15T10:00:00")
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Repo with one commit by the user, one pair-programmed commit by a
+/// teammate listing the user as co-author, and one unrelated commit.
+fn create_test_repo() -> TempDir {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let repo = dir.path();
+    git(repo, &["init", "-q"]);
+    git(repo, &["config", "user.name", "Me"]);
+    git(repo, &["config", "user.email", USER_EMAIL]);
+
+    std::fs::write(repo.join("a.txt"), "mine\n").unwrap();
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-q", "-m", "Own commit"]);
+
+    std::fs::write(repo.join("b.txt"), "paired\n").unwrap();
+    git(repo, &["add", "."]);
+    git(repo, &[
+        "commit", "-q",
+        "--author", "Teammate <teammate@example.com>",
+        "-m", "Pair on parser\n\nCo-authored-by: Me <ME@example.com>",
+    ]);
+
+    std::fs::write(repo.join("c.txt"), "theirs\n").unwrap();
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-q", "--author", "Teammate <teammate@example.com>", "-m", "Solo work"]);
+
+    dir
+}
+
+#[test]
+fn test_coauthored_commits_included_only_when_enabled() {
+    let repo = create_test_repo();
+    let path = repo.path().to_str().unwrap();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+    let with = get_user_commits_for_date(path, &date, Some(USER_EMAIL), true, EstimationStrategy::default());
+    let mut messages: Vec<(&str, bool)> = with.iter().map(|c| (c.message.as_str(), c.co_authored)).collect();
+    messages.sort();
+    assert_eq!(messages, vec![("Own commit", false), ("Pair on parser", true)]);
+
+    let without = get_user_commits_for_date(path, &date, Some(USER_EMAIL), false, EstimationStrategy::default());
+    assert_eq!(without.len(), 1);
+    assert_eq!(without[0].message, "Own commit");
+}
+
+#[tokio::test]
+async fn test_coauthored_commit_creates_tagged_work_item() {
+    let repo = create_test_repo();
+    let path = repo.path().to_str().unwrap();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+    let db_dir = TempDir::new().unwrap();
+    let db = Database::open(db_dir.path().join("test.db")).await.unwrap();
+    sqlx::query("INSERT INTO users (id, email, password_hash, name) VALUES ('test-user-1', ?, '', 'Me')")
+        .bind(USER_EMAIL)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+    let commits = get_user_commits_for_date(path, &date, Some(USER_EMAIL), true, EstimationStrategy::default());
+    for commit in &commits {
+        let params = WorkItemParams::from_commit("test-user-1", path, commit);
+        upsert_work_item(&db.pool, params).await.unwrap();
+    }
+
+    let rows: Vec<(String, Option<String>)> =
+        sqlx::query_as("SELECT title, tags FROM work_items WHERE source = 'git' ORDER BY title")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].0, "Own commit");
+    assert_eq!(rows[0].1, None);
+    assert_eq!(rows[1].0, "Pair on parser");
+    let tags: Vec<String> = serde_json::from_str(rows[1].1.as_deref().unwrap()).unwrap();
+    assert_eq!(tags, vec![CO_AUTHORED_TAG.to_string()]);
+}
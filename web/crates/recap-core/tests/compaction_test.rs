@@ -2,7 +2,7 @@
 
 use chrono::Utc;
 use recap_core::db::Database;
-use recap_core::services::compaction::run_compaction_cycle;
+use recap_core::services::compaction::{reset_stale_compaction_progress, run_compaction_cycle};
 use sqlx::Row;
 use tempfile::TempDir;
 
@@ -123,3 +123,52 @@ async fn test_compaction_no_data_returns_none() {
     // latest_compacted_date should be None when nothing was compacted
     assert!(result.latest_compacted_date.is_none(), "Should have no latest_compacted_date when nothing compacted");
 }
+
+async fn progress_status(pool: &sqlx::SqlitePool, user_id: &str, hour_bucket: &str) -> Option<String> {
+    sqlx::query_scalar("SELECT status FROM compaction_progress WHERE user_id = ? AND hour_bucket = ?")
+        .bind(user_id)
+        .bind(hour_bucket)
+        .fetch_optional(pool)
+        .await
+        .expect("Query should succeed")
+}
+
+#[tokio::test]
+async fn test_compaction_resumes_after_crash() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    let user_id = "test-user-crash";
+    let project_path = "/test/project";
+
+    insert_test_snapshot(pool, user_id, project_path, "2024-01-10T10:00:00").await;
+    insert_test_snapshot(pool, user_id, project_path, "2024-01-10T11:00:00").await;
+    run_compaction_cycle(pool, None, user_id)
+        .await
+        .expect("Compaction should succeed");
+    assert_eq!(progress_status(pool, user_id, "2024-01-10T10:00:00").await.as_deref(), Some("done"));
+
+    // A run killed mid-LLM call 20 minutes ago, and one still working now
+    sqlx::query(
+        "UPDATE compaction_progress SET status = 'in_progress', updated_at = datetime('now', '-20 minutes') WHERE hour_bucket = ?",
+    )
+    .bind("2024-01-10T10:00:00")
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("UPDATE compaction_progress SET status = 'in_progress', updated_at = CURRENT_TIMESTAMP WHERE hour_bucket = ?")
+        .bind("2024-01-10T11:00:00")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    assert_eq!(reset_stale_compaction_progress(pool).await.unwrap(), 1);
+    assert_eq!(progress_status(pool, user_id, "2024-01-10T10:00:00").await.as_deref(), Some("pending"));
+    assert_eq!(progress_status(pool, user_id, "2024-01-10T11:00:00").await.as_deref(), Some("in_progress"));
+
+    // The pending hour is redone even though its earlier summary exists
+    let result = run_compaction_cycle(pool, None, user_id)
+        .await
+        .expect("Compaction should succeed");
+    assert_eq!(result.hourly_compacted, 1);
+    assert_eq!(progress_status(pool, user_id, "2024-01-10T10:00:00").await.as_deref(), Some("done"));
+}
@@ -0,0 +1,98 @@
+//! Integration tests for the opt-in LLM prompt/response debug log
+
+use std::path::PathBuf;
+
+use chrono::Utc;
+use recap_core::db::Database;
+use recap_core::services::compaction::compact_hourly;
+use recap_core::services::llm::{LlmConfig, LlmService};
+use tempfile::TempDir;
+
+const USER_ID: &str = "test-user-1";
+const PROJECT_PATH: &str = "/test/project";
+const HOUR_BUCKET: &str = "2024-01-10T10:00:00";
+
+async fn create_test_db() -> (Database, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::open(db_path).await.expect("Failed to create test database");
+    (db, temp_dir)
+}
+
+async fn insert_test_snapshot(pool: &sqlx::SqlitePool) {
+    sqlx::query(
+        r#"
+        INSERT INTO snapshot_raw_data (
+            id, user_id, session_id, project_path, hour_bucket,
+            user_messages, assistant_messages, tool_calls, files_modified, git_commits,
+            message_count, raw_size_bytes, created_at
+        ) VALUES (
+            'snap-1', ?, 'sess-1', ?, ?,
+            '["Ping me at dev@example.com about the parser"]', '["response"]', '[]', '["test.rs"]', '[]',
+            1, 100, ?
+        )
+        "#,
+    )
+    .bind(USER_ID)
+    .bind(PROJECT_PATH)
+    .bind(HOUR_BUCKET)
+    .bind(Utc::now())
+    .execute(pool)
+    .await
+    .expect("Failed to insert test snapshot");
+}
+
+/// Ollama service pointed at a closed port, so the call fails fast without network access
+fn unreachable_llm(debug_log_path: Option<PathBuf>) -> LlmService {
+    LlmService::new(LlmConfig {
+        provider: "ollama".to_string(),
+        model: "llama3".to_string(),
+        api_key: None,
+        base_url: Some("http://127.0.0.1:9".to_string()),
+        summary_max_chars: 2000,
+        reasoning_effort: None,
+        summary_prompt: None,
+        debug_log_path,
+    })
+}
+
+#[tokio::test]
+async fn test_compaction_writes_debug_entry_when_enabled() {
+    let (db, temp_dir) = create_test_db().await;
+    insert_test_snapshot(&db.pool).await;
+    let log_path = temp_dir.path().join("logs").join("llm-debug.jsonl");
+    let llm = unreachable_llm(Some(log_path.clone()));
+
+    compact_hourly(&db.pool, Some(&llm), USER_ID, PROJECT_PATH, HOUR_BUCKET)
+        .await
+        .unwrap();
+
+    let content = std::fs::read_to_string(&log_path).expect("debug log should exist");
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 1);
+
+    let entry: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(entry["purpose"], "hourly_compaction");
+    assert_eq!(entry["provider"], "ollama");
+    assert_eq!(entry["model"], "llama3");
+    assert_eq!(entry["status"], "error");
+    assert!(entry["response"].is_null());
+    assert!(entry.get("total_tokens").is_some());
+
+    let prompt = entry["prompt"].as_str().unwrap();
+    assert!(prompt.contains("the parser"));
+    assert!(!prompt.contains("dev@example.com"));
+}
+
+#[tokio::test]
+async fn test_compaction_writes_nothing_when_disabled() {
+    let (db, temp_dir) = create_test_db().await;
+    insert_test_snapshot(&db.pool).await;
+    let llm = unreachable_llm(None);
+
+    compact_hourly(&db.pool, Some(&llm), USER_ID, PROJECT_PATH, HOUR_BUCKET)
+        .await
+        .unwrap();
+
+    assert!(!temp_dir.path().join("logs").exists());
+}
@@ -0,0 +1,145 @@
+//! Integration tests for purging work items when a source is removed
+
+use recap_core::db::Database;
+use recap_core::services::{purge_git_repo_items, purge_github_project_items, purge_gitlab_project_items};
+use tempfile::TempDir;
+
+async fn create_test_db() -> (Database, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::open(db_path).await.expect("Failed to create test database");
+    (db, temp_dir)
+}
+
+async fn insert_test_user(pool: &sqlx::SqlitePool, user_id: &str) {
+    sqlx::query("INSERT INTO users (id, email, password_hash, name) VALUES (?, ?, '', 'Test')")
+        .bind(user_id)
+        .bind(format!("{}@example.com", user_id))
+        .execute(pool)
+        .await
+        .expect("Failed to insert test user");
+}
+
+async fn insert_item(
+    pool: &sqlx::SqlitePool,
+    id: &str,
+    source: &str,
+    project_path: Option<&str>,
+    source_url: Option<&str>,
+) {
+    let now = chrono::Utc::now();
+    sqlx::query(
+        r#"INSERT INTO work_items (id, user_id, source, source_url, title, hours, date, project_path, created_at, updated_at)
+           VALUES (?, 'test-user-1', ?, ?, 'work', 1.0, '2026-01-15', ?, ?, ?)"#,
+    )
+    .bind(id)
+    .bind(source)
+    .bind(source_url)
+    .bind(project_path)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await
+    .expect("Failed to insert work item");
+}
+
+async fn remaining_ids(pool: &sqlx::SqlitePool) -> Vec<String> {
+    sqlx::query_scalar("SELECT id FROM work_items ORDER BY id")
+        .fetch_all(pool)
+        .await
+        .expect("Failed to list work items")
+}
+
+#[tokio::test]
+async fn test_remove_git_repo_with_purge_deletes_only_its_items() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+
+    sqlx::query(
+        "INSERT INTO git_repos (id, user_id, path, name, enabled, created_at) VALUES ('repo-1', 'test-user-1', '/home/dev/recap', 'recap', 1, ?)",
+    )
+    .bind(chrono::Utc::now())
+    .execute(pool)
+    .await
+    .unwrap();
+
+    insert_item(pool, "a-repo", "git", Some("/home/dev/recap"), None).await;
+    insert_item(pool, "b-subdir", "commit", Some("/home/dev/recap/web"), None).await;
+    insert_item(pool, "c-sibling", "git", Some("/home/dev/recap-old"), None).await;
+    insert_item(pool, "d-claude", "claude_code", Some("/home/dev/recap"), None).await;
+    insert_item(pool, "e-manual", "manual", None, None).await;
+
+    let mut tx = pool.begin().await.unwrap();
+    sqlx::query("DELETE FROM git_repos WHERE id = 'repo-1'")
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+    let purged = purge_git_repo_items(&mut tx, "test-user-1", "/home/dev/recap/")
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(purged, 2);
+    assert_eq!(remaining_ids(pool).await, vec!["c-sibling", "d-claude", "e-manual"]);
+
+    let repos: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM git_repos")
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    assert_eq!(repos, 0);
+}
+
+#[tokio::test]
+async fn test_purge_rolled_back_keeps_items() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+    insert_item(pool, "a-repo", "git", Some("/home/dev/recap"), None).await;
+
+    let mut tx = pool.begin().await.unwrap();
+    purge_git_repo_items(&mut tx, "test-user-1", "/home/dev/recap")
+        .await
+        .unwrap();
+    tx.rollback().await.unwrap();
+
+    assert_eq!(remaining_ids(pool).await, vec!["a-repo"]);
+}
+
+#[tokio::test]
+async fn test_purge_gitlab_project_items() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+
+    insert_item(pool, "a-proj", "gitlab", None, Some("https://gitlab.example.com/team/app/-/commit/abc")).await;
+    insert_item(pool, "b-other", "gitlab", None, Some("https://gitlab.example.com/team/app2/-/commit/def")).await;
+    insert_item(pool, "c-git", "git", Some("/home/dev/app"), None).await;
+
+    let mut conn = pool.acquire().await.unwrap();
+    let purged = purge_gitlab_project_items(&mut conn, "test-user-1", "https://gitlab.example.com/", "team/app")
+        .await
+        .unwrap();
+
+    assert_eq!(purged, 1);
+    assert_eq!(remaining_ids(pool).await, vec!["b-other", "c-git"]);
+}
+
+#[tokio::test]
+async fn test_purge_github_project_items() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+
+    insert_item(pool, "a-pr", "github", None, Some("https://github.com/acme/app/pull/42")).await;
+    insert_item(pool, "b-commit", "github", None, Some("https://github.com/acme/app/commit/abc")).await;
+    insert_item(pool, "c-other", "github", None, Some("https://github.com/acme/app2/pull/1")).await;
+
+    let mut conn = pool.acquire().await.unwrap();
+    let purged = purge_github_project_items(&mut conn, "test-user-1", "https://github.com/acme/app")
+        .await
+        .unwrap();
+
+    assert_eq!(purged, 2);
+    assert_eq!(remaining_ids(pool).await, vec!["c-other"]);
+}
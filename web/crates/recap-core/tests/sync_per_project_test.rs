@@ -0,0 +1,145 @@
+//! Integration tests for the per-project breakdown of Claude session syncs
+
+use std::path::{Path, PathBuf};
+
+use recap_core::db::Database;
+use recap_core::services::sources::claude::sync_claude_projects;
+use recap_core::services::{sync_discovered_projects, DiscoveredProject, ProjectSyncResult};
+use tempfile::TempDir;
+
+async fn create_test_db() -> (Database, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::open(db_path).await.expect("Failed to create test database");
+    (db, temp_dir)
+}
+
+async fn insert_test_user(pool: &sqlx::SqlitePool, user_id: &str) {
+    sqlx::query("INSERT INTO users (id, email, password_hash, name) VALUES (?, ?, '', 'Test')")
+        .bind(user_id)
+        .bind(format!("{}@example.com", user_id))
+        .execute(pool)
+        .await
+        .expect("Failed to insert test user");
+}
+
+/// Write a session file; `meaningful` controls whether it has a real user message
+fn write_session(dir: &Path, session_id: &str, cwd: &str, meaningful: bool) {
+    let content = if meaningful { "Refactor the sync loop please" } else { "ok" };
+    let lines = [
+        format!(
+            r#"{{"cwd":"{}","timestamp":"2026-01-15T09:00:00+08:00","message":{{"role":"user","content":"{}"}}}}"#,
+            cwd, content
+        ),
+        format!(
+            r#"{{"cwd":"{}","timestamp":"2026-01-15T10:00:00+08:00","message":{{"role":"assistant","content":"Done"}}}}"#,
+            cwd
+        ),
+    ];
+    std::fs::write(dir.join(format!("{}.jsonl", session_id)), lines.join("\n")).unwrap();
+}
+
+/// Create `~/.claude/projects` with two projects: alpha (2 sessions) and beta (1 session + 1 empty)
+fn create_claude_home() -> (TempDir, PathBuf, PathBuf) {
+    let home = TempDir::new().unwrap();
+    let projects_dir = home.path().join(".claude").join("projects");
+
+    let alpha = projects_dir.join("-work-alpha");
+    let beta = projects_dir.join("-work-beta");
+    std::fs::create_dir_all(&alpha).unwrap();
+    std::fs::create_dir_all(&beta).unwrap();
+
+    write_session(&alpha, "alpha-1", "/work/alpha", true);
+    write_session(&alpha, "alpha-2", "/work/alpha", true);
+    write_session(&beta, "beta-1", "/work/beta", true);
+    write_session(&beta, "beta-empty", "/work/beta", false);
+
+    (home, alpha, beta)
+}
+
+fn sorted(mut per_project: Vec<ProjectSyncResult>) -> Vec<ProjectSyncResult> {
+    per_project.sort_by(|a, b| a.project.cmp(&b.project));
+    per_project
+}
+
+#[tokio::test]
+async fn test_discovered_projects_per_project_counts_sum_to_totals() {
+    let (db, _db_dir) = create_test_db().await;
+    insert_test_user(&db.pool, "test-user-1").await;
+    let (_home, alpha, beta) = create_claude_home();
+
+    let projects = vec![
+        DiscoveredProject {
+            canonical_path: "/work/alpha".to_string(),
+            claude_dirs: vec![alpha],
+            name: "alpha".to_string(),
+        },
+        DiscoveredProject {
+            canonical_path: "/work/beta".to_string(),
+            claude_dirs: vec![beta],
+            name: "beta".to_string(),
+        },
+    ];
+
+    let result = sync_discovered_projects(&db.pool, "test-user-1", &projects)
+        .await
+        .unwrap();
+    let per_project = sorted(result.per_project.clone());
+
+    assert_eq!(per_project.len(), 2);
+    assert_eq!((per_project[0].project.as_str(), per_project[0].created, per_project[0].skipped), ("alpha", 2, 0));
+    assert_eq!((per_project[1].project.as_str(), per_project[1].created, per_project[1].skipped), ("beta", 1, 1));
+    assert_eq!(per_project.iter().map(|p| p.created).sum::<usize>(), result.work_items_created);
+    assert_eq!(per_project.iter().map(|p| p.updated).sum::<usize>(), result.work_items_updated);
+    assert_eq!(per_project.iter().map(|p| p.skipped).sum::<usize>(), result.sessions_skipped);
+
+    // Re-syncing reports updates for the same projects
+    let result = sync_discovered_projects(&db.pool, "test-user-1", &projects)
+        .await
+        .unwrap();
+    let per_project = sorted(result.per_project);
+    assert_eq!(per_project[0].updated, 2);
+    assert_eq!(per_project[1].updated, 1);
+    assert_eq!(result.work_items_updated, 3);
+    assert_eq!(result.work_items_created, 0);
+}
+
+#[tokio::test]
+async fn test_source_sync_per_project_counts_sum_to_totals() {
+    let (db, _db_dir) = create_test_db().await;
+    insert_test_user(&db.pool, "test-user-1").await;
+    let (home, _alpha, _beta) = create_claude_home();
+
+    // Only this test reads the Claude home directory
+    std::env::set_var("HOME", home.path());
+
+    let paths = vec!["/work/alpha".to_string(), "/work/beta".to_string()];
+    let result = sync_claude_projects(&db.pool, "test-user-1", &paths)
+        .await
+        .unwrap();
+    let per_project = sorted(result.per_project.clone());
+
+    assert_eq!(result.projects_scanned, 2);
+    assert_eq!(
+        per_project,
+        vec![
+            ProjectSyncResult {
+                project: "alpha".to_string(),
+                project_path: "/work/alpha".to_string(),
+                created: 2,
+                updated: 0,
+                skipped: 0,
+            },
+            ProjectSyncResult {
+                project: "beta".to_string(),
+                project_path: "/work/beta".to_string(),
+                created: 1,
+                updated: 0,
+                skipped: 1,
+            },
+        ]
+    );
+    assert_eq!(per_project.iter().map(|p| p.created).sum::<usize>(), result.work_items_created);
+    assert_eq!(per_project.iter().map(|p| p.updated).sum::<usize>(), result.work_items_updated);
+    assert_eq!(per_project.iter().map(|p| p.skipped).sum::<usize>(), result.sessions_skipped);
+}
@@ -0,0 +1,67 @@
+//! Integration test for work item upsert validation
+
+use recap_core::db::Database;
+use recap_core::services::{upsert_work_item, WorkItemParams};
+use tempfile::TempDir;
+
+/// Helper to create a test database
+async fn create_test_db() -> (Database, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::open(db_path).await.expect("Failed to create test database");
+    (db, temp_dir)
+}
+
+/// Insert the user that owns test work items
+async fn insert_test_user(pool: &sqlx::SqlitePool, user_id: &str) {
+    sqlx::query("INSERT INTO users (id, email, password_hash, name) VALUES (?, ?, '', 'Test')")
+        .bind(user_id)
+        .bind(format!("{}@example.com", user_id))
+        .execute(pool)
+        .await
+        .expect("Failed to insert test user");
+}
+
+async fn count_work_items(pool: &sqlx::SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM work_items")
+        .fetch_one(pool)
+        .await
+        .expect("Failed to count work items")
+}
+
+fn params(title: &str, hours: f64, date: &str) -> WorkItemParams {
+    WorkItemParams::new("test-user-1", "claude_code", "session-1", title, hours, date)
+        .with_session_id("session-1")
+}
+
+#[tokio::test]
+async fn test_upsert_rejects_invalid_params() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+
+    for invalid in [
+        params("", 1.0, "2026-01-15"),
+        params("Work", -2.0, "2026-01-15"),
+        params("Work", 1.0, "15/01/2026"),
+    ] {
+        let result = upsert_work_item(pool, invalid).await;
+        assert!(result.is_err(), "Invalid params should be rejected");
+    }
+
+    assert_eq!(count_work_items(pool).await, 0, "No rows should be inserted");
+}
+
+#[tokio::test]
+async fn test_upsert_accepts_valid_params() {
+    let (db, _temp_dir) = create_test_db().await;
+    let pool = &db.pool;
+    insert_test_user(pool, "test-user-1").await;
+
+    let result = upsert_work_item(pool, params("Work", 1.5, "2026-01-15"))
+        .await
+        .expect("Valid params should be upserted");
+
+    assert!(result.is_created());
+    assert_eq!(count_work_items(pool).await, 1);
+}
@@ -5,14 +5,14 @@
 use recap_core::models::UserResponse;
 use tauri::State;
 
-use crate::commands::AppState;
+use crate::commands::{AppState, CommandError};
 use super::repository::SqliteUserRepository;
 use super::service;
 use super::types::{AppStatus, LoginRequest, RegisterRequest, TokenResponse};
 
 /// Get app status (has_users, local_mode, etc.)
 #[tauri::command]
-pub async fn get_app_status(state: State<'_, AppState>) -> Result<AppStatus, String> {
+pub async fn get_app_status(state: State<'_, AppState>) -> Result<AppStatus, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteUserRepository::new(&db.pool);
     service::get_app_status_impl(&repo).await
@@ -23,7 +23,7 @@ pub async fn get_app_status(state: State<'_, AppState>) -> Result<AppStatus, Str
 pub async fn register_user(
     state: State<'_, AppState>,
     request: RegisterRequest,
-) -> Result<UserResponse, String> {
+) -> Result<UserResponse, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteUserRepository::new(&db.pool);
     service::register_user_impl(&repo, request).await
@@ -34,7 +34,7 @@ pub async fn register_user(
 pub async fn login(
     state: State<'_, AppState>,
     request: LoginRequest,
-) -> Result<TokenResponse, String> {
+) -> Result<TokenResponse, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteUserRepository::new(&db.pool);
     service::login_impl(&repo, request).await
@@ -42,7 +42,7 @@ pub async fn login(
 
 /// Auto-login for local mode (uses first user)
 #[tauri::command]
-pub async fn auto_login(state: State<'_, AppState>) -> Result<TokenResponse, String> {
+pub async fn auto_login(state: State<'_, AppState>) -> Result<TokenResponse, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteUserRepository::new(&db.pool);
     service::auto_login_impl(&repo).await
@@ -53,8 +53,19 @@ pub async fn auto_login(state: State<'_, AppState>) -> Result<TokenResponse, Str
 pub async fn get_current_user(
     state: State<'_, AppState>,
     token: String,
-) -> Result<UserResponse, String> {
+) -> Result<UserResponse, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteUserRepository::new(&db.pool);
     service::get_current_user_impl(&repo, &token).await
 }
+
+/// Exchange a refresh token for a new access token
+#[tauri::command]
+pub async fn refresh_token(
+    state: State<'_, AppState>,
+    refresh_token: String,
+) -> Result<TokenResponse, CommandError> {
+    let db = state.db.lock().await;
+    let repo = SqliteUserRepository::new(&db.pool);
+    service::refresh_token_impl(&repo, &refresh_token).await
+}
@@ -3,16 +3,18 @@
 //! Core authentication operations that are testable and independent of the framework.
 
 use recap_core::{
-    auth::{create_token, hash_password, verify_password},
+    auth::{create_refresh_token, create_token, hash_password, verify_password},
     models::UserResponse,
 };
 use uuid::Uuid;
 
+use crate::commands::CommandError;
+use crate::models::User;
 use super::repository::UserRepository;
 use super::types::{AppStatus, LoginRequest, NewUser, RegisterRequest, TokenResponse};
 
 /// Get app status - testable business logic
-pub async fn get_app_status_impl<R: UserRepository>(repo: &R) -> Result<AppStatus, String> {
+pub async fn get_app_status_impl<R: UserRepository>(repo: &R) -> Result<AppStatus, CommandError> {
     let count = repo.get_user_count().await?;
     let first_user = if count > 0 {
         repo.get_first_user().await?
@@ -32,10 +34,10 @@ pub async fn get_app_status_impl<R: UserRepository>(repo: &R) -> Result<AppStatu
 pub async fn register_user_impl<R: UserRepository>(
     repo: &R,
     request: RegisterRequest,
-) -> Result<UserResponse, String> {
+) -> Result<UserResponse, CommandError> {
     // Check if username already exists
     if repo.username_exists(&request.username).await? {
-        return Err("Username already exists".to_string());
+        return Err(CommandError::already_exists("Username already exists"));
     }
 
     // Generate email if not provided
@@ -46,14 +48,14 @@ pub async fn register_user_impl<R: UserRepository>(
 
     // Check if email already exists
     if repo.email_exists(&email).await? {
-        return Err("Email already registered".to_string());
+        return Err(CommandError::already_exists("Email already registered"));
     }
 
     // Check if this is the first user (will be admin)
     let is_first_user = repo.get_user_count().await? == 0;
 
     // Hash password
-    let password_hash = hash_password(&request.password).map_err(|e| e.to_string())?;
+    let password_hash = hash_password(&request.password).map_err(|e| CommandError::internal(e.to_string()))?;
 
     // Create user
     let new_user = NewUser {
@@ -70,72 +72,103 @@ pub async fn register_user_impl<R: UserRepository>(
     Ok(UserResponse::from(user))
 }
 
+/// Access token lifetime reported in `TokenResponse::expires_in`
+const ACCESS_TOKEN_EXPIRES_IN: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+/// Create an access/refresh token pair for a user
+fn issue_tokens(user: &User) -> Result<TokenResponse, CommandError> {
+    let access_token = create_token(user).map_err(|e| CommandError::internal(e.to_string()))?;
+    let refresh_token = create_refresh_token(user).map_err(|e| CommandError::internal(e.to_string()))?;
+
+    Ok(TokenResponse {
+        access_token,
+        token_type: "bearer".to_string(),
+        expires_in: ACCESS_TOKEN_EXPIRES_IN,
+        refresh_token,
+    })
+}
+
 /// Login - testable business logic
 pub async fn login_impl<R: UserRepository>(
     repo: &R,
     request: LoginRequest,
-) -> Result<TokenResponse, String> {
+) -> Result<TokenResponse, CommandError> {
     // Find user by username
     let user = repo
         .find_by_username(&request.username)
         .await?
-        .ok_or_else(|| "Invalid credentials".to_string())?;
+        .ok_or_else(|| CommandError::auth_failed("Invalid credentials"))?;
 
     // Verify password
-    let valid = verify_password(&request.password, &user.password_hash).map_err(|e| e.to_string())?;
+    let valid = verify_password(&request.password, &user.password_hash)
+        .map_err(|e| CommandError::internal(e.to_string()))?;
 
     if !valid {
-        return Err("Invalid credentials".to_string());
+        return Err(CommandError::auth_failed("Invalid credentials"));
     }
 
     if !user.is_active {
-        return Err("Account is disabled".to_string());
+        return Err(CommandError::account_disabled("Account is disabled"));
     }
 
-    // Create token
-    let token = create_token(&user).map_err(|e| e.to_string())?;
-
-    Ok(TokenResponse {
-        access_token: token,
-        token_type: "bearer".to_string(),
-        expires_in: 7 * 24 * 60 * 60, // 7 days in seconds
-    })
+    issue_tokens(&user)
 }
 
 /// Auto-login - testable business logic
-pub async fn auto_login_impl<R: UserRepository>(repo: &R) -> Result<TokenResponse, String> {
+pub async fn auto_login_impl<R: UserRepository>(repo: &R) -> Result<TokenResponse, CommandError> {
     // Get first user
     let user = repo
         .get_first_user()
         .await?
-        .ok_or_else(|| "No user found".to_string())?;
+        .ok_or_else(|| CommandError::not_found("No user found"))?;
 
     if !user.is_active {
-        return Err("Account is disabled".to_string());
+        return Err(CommandError::account_disabled("Account is disabled"));
     }
 
-    // Create token
-    let token = create_token(&user).map_err(|e| e.to_string())?;
-
-    Ok(TokenResponse {
-        access_token: token,
-        token_type: "bearer".to_string(),
-        expires_in: 7 * 24 * 60 * 60,
-    })
+    issue_tokens(&user)
 }
 
 /// Get current user - testable business logic
 pub async fn get_current_user_impl<R: UserRepository>(
     repo: &R,
     token: &str,
-) -> Result<UserResponse, String> {
+) -> Result<UserResponse, CommandError> {
     // Verify token and get claims
-    let claims = recap_core::auth::verify_token(token).map_err(|e| e.to_string())?;
+    let claims = recap_core::auth::verify_token(token)?;
 
     let user = repo
         .find_by_id(&claims.sub)
         .await?
-        .ok_or_else(|| "User not found".to_string())?;
+        .ok_or_else(|| CommandError::not_found("User not found"))?;
 
     Ok(UserResponse::from(user))
 }
+
+/// Refresh - testable business logic
+///
+/// Exchanges a refresh token for a new access token. The refresh token is
+/// returned unchanged; the user has to log in again once it expires.
+pub async fn refresh_token_impl<R: UserRepository>(
+    repo: &R,
+    refresh_token: &str,
+) -> Result<TokenResponse, CommandError> {
+    let access_token = recap_core::auth::refresh_access_token(refresh_token)?;
+    let claims = recap_core::auth::verify_token(&access_token)?;
+
+    // Don't keep a deleted or disabled account signed in
+    let user = repo
+        .find_by_id(&claims.sub)
+        .await?
+        .ok_or_else(|| CommandError::not_found("User not found"))?;
+    if !user.is_active {
+        return Err(CommandError::account_disabled("Account is disabled"));
+    }
+
+    Ok(TokenResponse {
+        access_token,
+        token_type: "bearer".to_string(),
+        expires_in: ACCESS_TOKEN_EXPIRES_IN,
+        refresh_token: refresh_token.to_string(),
+    })
+}
@@ -4,14 +4,16 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
-use recap_core::auth::{create_token, hash_password};
+use recap_core::auth::{create_refresh_token, create_token, hash_password};
 use std::collections::HashMap;
 use std::sync::Mutex;
 
+use crate::commands::CommandError;
 use crate::models::User;
 use super::repository::UserRepository;
 use super::service::{
-    auto_login_impl, get_app_status_impl, get_current_user_impl, login_impl, register_user_impl,
+    auto_login_impl, get_app_status_impl, get_current_user_impl, login_impl, refresh_token_impl,
+    register_user_impl,
 };
 use super::types::{LoginRequest, NewUser, RegisterRequest};
 
@@ -206,7 +208,9 @@ async fn test_register_user_duplicate_username() {
     let result = register_user_impl(&repo, request).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Username already exists");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::ALREADY_EXISTS);
+    assert_eq!(err.message, "Username already exists");
 }
 
 #[tokio::test]
@@ -226,7 +230,9 @@ async fn test_register_user_duplicate_email() {
     let result = register_user_impl(&repo, request).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Email already registered");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::ALREADY_EXISTS);
+    assert_eq!(err.message, "Email already registered");
 }
 
 #[tokio::test]
@@ -269,6 +275,8 @@ async fn test_login_success() {
     assert!(!result.access_token.is_empty());
     assert_eq!(result.token_type, "bearer");
     assert_eq!(result.expires_in, 7 * 24 * 60 * 60);
+    assert!(!result.refresh_token.is_empty());
+    assert_ne!(result.refresh_token, result.access_token);
 }
 
 #[tokio::test]
@@ -283,7 +291,9 @@ async fn test_login_invalid_username() {
     let result = login_impl(&repo, request).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Invalid credentials");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::AUTH_FAILED);
+    assert_eq!(err.message, "Invalid credentials");
 }
 
 #[tokio::test]
@@ -300,7 +310,9 @@ async fn test_login_invalid_password() {
     let result = login_impl(&repo, request).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Invalid credentials");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::AUTH_FAILED);
+    assert_eq!(err.message, "Invalid credentials");
 }
 
 #[tokio::test]
@@ -318,7 +330,9 @@ async fn test_login_disabled_account() {
     let result = login_impl(&repo, request).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Account is disabled");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::ACCOUNT_DISABLED);
+    assert_eq!(err.message, "Account is disabled");
 }
 
 // ============================================================================
@@ -343,7 +357,9 @@ async fn test_auto_login_no_users() {
     let result = auto_login_impl(&repo).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "No user found");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::NOT_FOUND);
+    assert_eq!(err.message, "No user found");
 }
 
 #[tokio::test]
@@ -355,7 +371,9 @@ async fn test_auto_login_disabled_user() {
     let result = auto_login_impl(&repo).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Account is disabled");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::ACCOUNT_DISABLED);
+    assert_eq!(err.message, "Account is disabled");
 }
 
 // ============================================================================
@@ -383,6 +401,7 @@ async fn test_get_current_user_invalid_token() {
     let result = get_current_user_impl(&repo, "invalid-token").await;
 
     assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code, CommandError::AUTH_INVALID_TOKEN);
 }
 
 #[tokio::test]
@@ -395,5 +414,51 @@ async fn test_get_current_user_user_not_found() {
     let result = get_current_user_impl(&repo, &token).await;
 
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "User not found");
+    let err = result.unwrap_err();
+    assert_eq!(err.code, CommandError::NOT_FOUND);
+    assert_eq!(err.message, "User not found");
+}
+
+// ============================================================================
+// refresh_token Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_refresh_token_success() {
+    let user = MockUserRepository::create_test_user("user-1", "testuser", "hash");
+    let repo = MockUserRepository::new().with_user(user.clone());
+    let refresh = create_refresh_token(&user).unwrap();
+
+    let result = refresh_token_impl(&repo, &refresh).await.unwrap();
+
+    assert_eq!(result.refresh_token, refresh);
+    let current = get_current_user_impl(&repo, &result.access_token).await.unwrap();
+    assert_eq!(current.id, "user-1");
+
+    // The refresh token itself isn't accepted for normal auth
+    let err = get_current_user_impl(&repo, &refresh).await.unwrap_err();
+    assert_eq!(err.code, CommandError::AUTH_INVALID_TOKEN);
+}
+
+#[tokio::test]
+async fn test_refresh_token_rejects_access_token() {
+    let user = MockUserRepository::create_test_user("user-1", "testuser", "hash");
+    let repo = MockUserRepository::new().with_user(user.clone());
+    let access = create_token(&user).unwrap();
+
+    let err = refresh_token_impl(&repo, &access).await.unwrap_err();
+
+    assert_eq!(err.code, CommandError::AUTH_INVALID_TOKEN);
+}
+
+#[tokio::test]
+async fn test_refresh_token_disabled_account() {
+    let mut user = MockUserRepository::create_test_user("user-1", "testuser", "hash");
+    user.is_active = false;
+    let repo = MockUserRepository::new().with_user(user.clone());
+    let refresh = create_refresh_token(&user).unwrap();
+
+    let err = refresh_token_impl(&repo, &refresh).await.unwrap_err();
+
+    assert_eq!(err.code, CommandError::ACCOUNT_DISABLED);
 }
@@ -22,12 +22,15 @@ pub struct LoginRequest {
     pub password: String,
 }
 
-/// Response containing access token
+/// Response containing access and refresh tokens
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct TokenResponse {
     pub access_token: String,
     pub token_type: String,
     pub expires_in: i64,
+    /// Longer-lived token for `refresh_token`, to get a new access token
+    /// once this one expires
+    pub refresh_token: String,
 }
 
 /// Application status information
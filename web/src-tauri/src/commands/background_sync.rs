@@ -36,6 +36,8 @@ pub struct UpdateBackgroundSyncConfigRequest {
     pub summary_max_chars: Option<u32>,
     pub summary_reasoning_effort: Option<String>,
     pub summary_prompt: Option<String>,
+    pub sync_retry_attempts: Option<u32>,
+    pub sync_retry_base_delay_ms: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -51,6 +53,8 @@ pub struct BackgroundSyncConfigResponse {
     pub summary_max_chars: u32,
     pub summary_reasoning_effort: String,
     pub summary_prompt: Option<String>,
+    pub sync_retry_attempts: u32,
+    pub sync_retry_base_delay_ms: u64,
 }
 
 impl From<BackgroundSyncConfig> for BackgroundSyncConfigResponse {
@@ -67,6 +71,8 @@ impl From<BackgroundSyncConfig> for BackgroundSyncConfigResponse {
             summary_max_chars: config.summary_max_chars,
             summary_reasoning_effort: config.summary_reasoning_effort,
             summary_prompt: config.summary_prompt,
+            sync_retry_attempts: config.sync_retry_attempts,
+            sync_retry_base_delay_ms: config.sync_retry_base_delay_ms,
         }
     }
 }
@@ -83,6 +89,7 @@ pub struct BackgroundSyncStatusResponse {
     pub next_compaction_at: Option<String>,
     pub last_result: Option<String>,
     pub last_error: Option<String>,
+    pub last_recovery_at: Option<String>,
 }
 
 impl From<SyncServiceStatus> for BackgroundSyncStatusResponse {
@@ -98,6 +105,7 @@ impl From<SyncServiceStatus> for BackgroundSyncStatusResponse {
             next_compaction_at: status.next_compaction_at,
             last_result: status.last_result,
             last_error: status.last_error,
+            last_recovery_at: status.last_recovery_at,
         }
     }
 }
@@ -163,10 +171,14 @@ pub async fn update_background_sync_config(
         sync_claude: config.sync_claude.unwrap_or(current.sync_claude),
         sync_gitlab: config.sync_gitlab.unwrap_or(current.sync_gitlab),
         sync_jira: config.sync_jira.unwrap_or(current.sync_jira),
+        // Registered sources are enabled by the code that registers them
+        extra_sources: current.extra_sources.clone(),
         auto_generate_summaries: config.auto_generate_summaries.unwrap_or(current.auto_generate_summaries),
         summary_max_chars: config.summary_max_chars.unwrap_or(current.summary_max_chars),
         summary_reasoning_effort: config.summary_reasoning_effort.unwrap_or(current.summary_reasoning_effort.clone()),
         summary_prompt: if config.summary_prompt.is_some() { config.summary_prompt } else { current.summary_prompt.clone() },
+        sync_retry_attempts: config.sync_retry_attempts.unwrap_or(current.sync_retry_attempts),
+        sync_retry_base_delay_ms: config.sync_retry_base_delay_ms.unwrap_or(current.sync_retry_base_delay_ms),
     };
 
     // Validate data sync interval
@@ -189,6 +201,14 @@ pub async fn update_background_sync_config(
         return Err("推理強度必須是 low、medium 或 high".to_string());
     }
 
+    // Validate retry settings (1..=10 attempts, base delay up to 60s)
+    if !(1..=10).contains(&new_config.sync_retry_attempts) {
+        return Err("重試次數必須在 1 到 10 之間".to_string());
+    }
+    if new_config.sync_retry_base_delay_ms > 60_000 {
+        return Err("重試延遲不可超過 60000 毫秒".to_string());
+    }
+
     // Update in-memory config
     state.background_sync.update_config(new_config.clone()).await;
 
@@ -207,9 +227,13 @@ pub async fn update_background_sync_config(
             auto_generate_summaries = ?,
             sync_git = ?,
             sync_claude = ?,
+            sync_gitlab = ?,
+            sync_jira = ?,
             summary_max_chars = ?,
             summary_reasoning_effort = ?,
-            summary_prompt = ?
+            summary_prompt = ?,
+            sync_retry_attempts = ?,
+            sync_retry_base_delay_ms = ?
         WHERE id = ?
         "#
     )
@@ -219,9 +243,13 @@ pub async fn update_background_sync_config(
     .bind(new_config.auto_generate_summaries)
     .bind(new_config.sync_git)
     .bind(new_config.sync_claude)
+    .bind(new_config.sync_gitlab)
+    .bind(new_config.sync_jira)
     .bind(new_config.summary_max_chars)
     .bind(&new_config.summary_reasoning_effort)
     .bind(&new_config.summary_prompt)
+    .bind(new_config.sync_retry_attempts)
+    .bind(new_config.sync_retry_base_delay_ms as i64)
     .execute(&pool)
     .await
     .map_err(|e| format!("Failed to persist sync config: {}", e))?;
@@ -268,9 +296,13 @@ pub async fn start_background_sync(
         Option<bool>,
         Option<bool>,
         Option<bool>,
+        Option<bool>,
+        Option<bool>,
         Option<i32>,
         Option<String>,
         Option<String>,
+        Option<i32>,
+        Option<i64>,
     )> = sqlx::query_as(
         r#"
         SELECT
@@ -280,9 +312,13 @@ pub async fn start_background_sync(
             auto_generate_summaries,
             sync_git,
             sync_claude,
+            sync_gitlab,
+            sync_jira,
             summary_max_chars,
             summary_reasoning_effort,
-            summary_prompt
+            summary_prompt,
+            sync_retry_attempts,
+            sync_retry_base_delay_ms
         FROM users WHERE id = ?
         "#
     )
@@ -292,7 +328,7 @@ pub async fn start_background_sync(
     .ok()
     .flatten();
 
-    if let Some((enabled, interval, compaction, auto_summaries, git, claude, max_chars, reasoning_effort, summary_prompt)) = config_row {
+    if let Some((enabled, interval, compaction, auto_summaries, git, claude, gitlab, jira, max_chars, reasoning_effort, summary_prompt, retry_attempts, retry_delay_ms)) = config_row {
         let config = BackgroundSyncConfig {
             enabled: enabled.unwrap_or(true),
             interval_minutes: interval.unwrap_or(15) as u32,
@@ -300,11 +336,14 @@ pub async fn start_background_sync(
             auto_generate_summaries: auto_summaries.unwrap_or(true),
             sync_git: git.unwrap_or(true),
             sync_claude: claude.unwrap_or(true),
-            sync_gitlab: false,
-            sync_jira: false,
+            sync_gitlab: gitlab.unwrap_or(false),
+            sync_jira: jira.unwrap_or(false),
+            extra_sources: state.background_sync.get_config().await.extra_sources,
             summary_max_chars: max_chars.unwrap_or(2000) as u32,
             summary_reasoning_effort: reasoning_effort.unwrap_or_else(|| "medium".to_string()),
             summary_prompt: summary_prompt.filter(|s| !s.is_empty()),
+            sync_retry_attempts: retry_attempts.unwrap_or(3) as u32,
+            sync_retry_base_delay_ms: retry_delay_ms.unwrap_or(1000) as u64,
         };
         state.background_sync.update_config(config).await;
         log::info!("Loaded sync config from database");
@@ -457,7 +496,12 @@ pub async fn trigger_sync_with_progress(
     emit("snapshots", None, 0, 100, "正在捕獲快照...");
 
     if config.sync_claude {
-        let projects = recap_core::services::SyncService::discover_project_paths();
+        let projects = recap_core::services::discover_user_projects(&pool, &user_id)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to discover Claude projects: {}", e);
+                Vec::new()
+            });
         let total_projects = projects.len();
         let mut snapshot_count = 0;
 
@@ -705,10 +749,13 @@ mod tests {
             sync_claude: true,
             sync_gitlab: false,
             sync_jira: false,
+            extra_sources: Default::default(),
             auto_generate_summaries: true,
             summary_max_chars: 2000,
             summary_reasoning_effort: "medium".to_string(),
             summary_prompt: None,
+            sync_retry_attempts: 3,
+            sync_retry_base_delay_ms: 1000,
         };
 
         let response: BackgroundSyncConfigResponse = config.into();
@@ -722,6 +769,8 @@ mod tests {
         assert!(response.auto_generate_summaries);
         assert_eq!(response.summary_max_chars, 2000);
         assert_eq!(response.summary_reasoning_effort, "medium");
+        assert_eq!(response.sync_retry_attempts, 3);
+        assert_eq!(response.sync_retry_base_delay_ms, 1000);
     }
 
     #[test]
@@ -737,6 +786,7 @@ mod tests {
             next_compaction_at: Some("2026-01-16T16:00:00Z".to_string()),
             last_result: Some("成功同步 5 筆項目".to_string()),
             last_error: None,
+            last_recovery_at: Some("2026-01-16T11:30:00Z".to_string()),
         };
 
         let response: BackgroundSyncStatusResponse = status.into();
@@ -746,6 +796,7 @@ mod tests {
         assert_eq!(response.last_sync_at, Some("2026-01-16T12:00:00Z".to_string()));
         assert_eq!(response.last_compaction_at, Some("2026-01-16T10:00:00Z".to_string()));
         assert_eq!(response.next_compaction_at, Some("2026-01-16T16:00:00Z".to_string()));
+        assert_eq!(response.last_recovery_at, Some("2026-01-16T11:30:00Z".to_string()));
     }
 
     #[test]
@@ -8,7 +8,7 @@ use recap_core::auth::verify_token;
 use recap_core::services::{
     llm::LlmConfig,
     llm_batch::LlmBatchService,
-    compaction::{submit_hourly_batch, process_completed_batch, collect_pending_hourly},
+    compaction::{submit_hourly_batch, process_completed_batch, collect_pending_hourly, estimate_hourly_batch_cost},
 };
 use serde::Serialize;
 use tauri::State;
@@ -39,6 +39,15 @@ pub struct BatchSubmitResponse {
     pub message: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct BatchCostEstimateResponse {
+    pub total_requests: usize,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    /// USD, batch discount applied
+    pub estimated_cost: f64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct BatchProcessResponse {
     pub success: bool,
@@ -90,6 +99,7 @@ async fn get_llm_config(pool: &sqlx::SqlitePool, user_id: &str) -> Result<LlmCon
         summary_max_chars: 2000,
         reasoning_effort: None,
         summary_prompt: None,
+        debug_log_path: None,
     })
 }
 
@@ -188,6 +198,31 @@ pub async fn get_batch_job_status(
     }
 }
 
+/// Estimate the cost of submitting pending hourly compactions as a batch job
+#[tauri::command]
+pub async fn estimate_batch_cost(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<BatchCostEstimateResponse, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+
+    let pool = {
+        let db = state.db.lock().await;
+        db.pool.clone()
+    };
+
+    let config = get_llm_config(&pool, &claims.sub).await?;
+    let batch_service = LlmBatchService::new(config);
+
+    let estimate = estimate_hourly_batch_cost(&pool, &batch_service, &claims.sub).await?;
+    Ok(BatchCostEstimateResponse {
+        total_requests: estimate.total_requests,
+        prompt_tokens: estimate.prompt_tokens,
+        completion_tokens: estimate.completion_tokens,
+        estimated_cost: estimate.estimated_cost,
+    })
+}
+
 /// Submit hourly compactions as a batch job
 #[tauri::command]
 pub async fn submit_batch_compaction(
@@ -12,8 +12,8 @@ use tauri::State;
 
 use recap_core::auth::verify_token;
 use recap_core::services::{
-    generate_daily_hash, is_meaningful_message, extract_tool_detail,
-    calculate_session_hours,
+    compute_content_hash, is_meaningful_message, extract_tool_detail,
+    calculate_session_hours, extract_session_content,
 };
 
 use super::AppState;
@@ -115,11 +115,7 @@ pub use recap_core::services::SourceSyncResult as SyncResult;
 
 // Helper functions
 
-pub(crate) fn get_claude_home() -> Option<PathBuf> {
-    dirs::home_dir().map(|h| h.join(".claude"))
-}
-
-// generate_daily_hash, is_meaningful_message, extract_tool_detail, calculate_session_hours
+// compute_content_hash, is_meaningful_message, extract_tool_detail, calculate_session_hours
 // are imported from crate::services
 
 /// Helper to calculate session hours with Option handling
@@ -325,62 +321,33 @@ pub(crate) fn build_session_description(session: &ClaudeSession, hours: f64) ->
 
 // get_git_commits_for_date and build_daily_description moved to services/sync.rs
 
-pub(crate) fn extract_session_content(path: &PathBuf) -> String {
-    let file = match fs::File::open(path) {
-        Ok(f) => f,
-        Err(_) => return String::new(),
-    };
-    let reader = BufReader::new(file);
-
-    let mut content_parts: Vec<String> = Vec::new();
-
-    for line in reader.lines().flatten() {
-        if let Ok(msg) = serde_json::from_str::<serde_json::Value>(&line) {
-            if let Some(message) = msg.get("message") {
-                if message.get("role").and_then(|r| r.as_str()) == Some("user") {
-                    if let Some(text) = message.get("content").and_then(|c| c.as_str()) {
-                        let trimmed = text.trim();
-                        if trimmed.len() >= 10
-                            && !trimmed.to_lowercase().starts_with("warmup")
-                            && !trimmed.starts_with("<command-")
-                        {
-                            content_parts.push(format!("User: {}", trimmed.chars().take(200).collect::<String>()));
-                        }
-                    }
-                }
-            }
-        }
-
-        if content_parts.len() >= 20 {
-            break;
-        }
-    }
-
-    content_parts.join("\n\n")
-}
+// extract_session_content moved to services/session_parser.rs
 
 // Commands
 
 /// List all Claude Code sessions from local machine
 #[tauri::command]
 pub async fn list_claude_sessions(
-    _state: State<'_, AppState>,
+    state: State<'_, AppState>,
     token: String,
 ) -> Result<Vec<ClaudeProject>, String> {
-    let _claims = verify_token(&token).map_err(|e| e.to_string())?;
-
-    let claude_home = get_claude_home()
-        .ok_or_else(|| "Claude home directory not found".to_string())?;
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
 
-    let projects_dir = claude_home.join("projects");
-    if !projects_dir.exists() {
-        return Ok(Vec::new());
+    let projects_dirs = {
+        let db = state.db.lock().await;
+        recap_core::services::resolve_claude_projects_dirs(&db.pool, &claims.sub).await?
+    };
+    if projects_dirs.is_empty() {
+        return Err("Claude home directory not found".to_string());
     }
 
     let mut projects: Vec<ClaudeProject> = Vec::new();
 
-    let entries = fs::read_dir(&projects_dir)
-        .map_err(|e| e.to_string())?;
+    // Roots that don't exist (e.g. an unmounted work machine) are skipped
+    let entries = projects_dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten();
 
     for entry in entries.flatten() {
         let path = entry.path();
@@ -452,16 +419,20 @@ pub async fn import_claude_sessions(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    let claude_home = get_claude_home()
-        .ok_or_else(|| "Claude home directory not found".to_string())?;
+    let projects_dirs = recap_core::services::resolve_claude_projects_dirs(&db.pool, &claims.sub).await?;
+    if projects_dirs.is_empty() {
+        return Err("Claude home directory not found".to_string());
+    }
 
-    let projects_dir = claude_home.join("projects");
     let mut imported = 0;
     let mut work_items_created = 0;
 
     let mut session_files: HashMap<String, PathBuf> = HashMap::new();
 
-    if let Ok(projects) = fs::read_dir(&projects_dir) {
+    for projects_dir in &projects_dirs {
+        let Ok(projects) = fs::read_dir(projects_dir) else {
+            continue;
+        };
         for project_entry in projects.flatten() {
             let project_path = project_entry.path();
             if !project_path.is_dir() {
@@ -516,13 +487,21 @@ pub async fn import_claude_sessions(
                     .and_then(|ts| ts.split('T').next())
                     .unwrap_or("2026-01-01");
 
-                let content_hash = generate_daily_hash(&claims.sub, &session.cwd, date);
+                let content_hash = compute_content_hash(&claims.sub, "claude_code", session_id, &title, date);
 
+                // Rows imported before hashes were per session have no session_id;
+                // the same session reproduces their title and date
                 let existing: Option<(String,)> = sqlx::query_as(
-                    "SELECT id FROM work_items WHERE content_hash = ? AND user_id = ?"
+                    r#"SELECT id FROM work_items
+                       WHERE user_id = ? AND (content_hash = ? OR session_id = ?
+                           OR (session_id IS NULL AND source = 'claude_code' AND title = ? AND date = ?))
+                       LIMIT 1"#
                 )
-                .bind(&content_hash)
                 .bind(&claims.sub)
+                .bind(&content_hash)
+                .bind(session_id)
+                .bind(&title)
+                .bind(date)
                 .fetch_optional(&db.pool)
                 .await
                 .map_err(|e| e.to_string())?;
@@ -538,12 +517,13 @@ pub async fn import_claude_sessions(
 
                 sqlx::query(
                     r#"INSERT INTO work_items
-                    (id, user_id, source, source_id, title, description, hours, date, content_hash, hours_source, hours_estimated, created_at, updated_at)
-                    VALUES (?, ?, 'claude_code', ?, ?, ?, ?, ?, ?, 'session', ?, ?, ?)"#
+                    (id, user_id, source, source_id, session_id, title, description, hours, date, content_hash, hours_source, hours_estimated, created_at, updated_at)
+                    VALUES (?, ?, 'claude_code', ?, ?, ?, ?, ?, ?, ?, 'session', ?, ?, ?)"#
                 )
                 .bind(&id)
                 .bind(&claims.sub)
                 .bind(&session.agent_id)
+                .bind(session_id)
                 .bind(&title)
                 .bind(&description)
                 .bind(hours)
@@ -645,13 +625,13 @@ mod tests {
     use std::io::Write;
     use tempfile::NamedTempFile;
 
-    // ==================== get_claude_home Tests ====================
+    // ==================== default_claude_home Tests ====================
 
     #[test]
-    fn test_get_claude_home() {
+    fn test_default_claude_home() {
         // This test verifies the function works without crashing
         // Result depends on whether HOME env var is set
-        let result = get_claude_home();
+        let result = recap_core::services::default_claude_home();
         if let Some(path) = result {
             assert!(path.to_string_lossy().contains(".claude"));
         }
@@ -376,7 +376,7 @@ pub(crate) fn determine_auth_type(jira_email: &Option<String>, jira_pat: &Option
 
 /// Validate LLM provider
 pub(crate) fn validate_llm_provider(provider: &str) -> Result<(), String> {
-    let valid_providers = ["openai", "anthropic", "ollama", "openai-compatible"];
+    let valid_providers = ["openai", "anthropic", "gemini", "ollama", "openai-compatible"];
     if valid_providers.contains(&provider) {
         Ok(())
     } else {
@@ -646,6 +646,7 @@ pub async fn test_llm_connection(
         summary_max_chars: 2000,
         reasoning_effort: None,
         summary_prompt: None,
+        debug_log_path: None,
     };
 
     // Check if configured
@@ -1126,6 +1127,7 @@ mod tests {
     fn test_validate_llm_provider_valid() {
         assert!(validate_llm_provider("openai").is_ok());
         assert!(validate_llm_provider("anthropic").is_ok());
+        assert!(validate_llm_provider("gemini").is_ok());
         assert!(validate_llm_provider("ollama").is_ok());
         assert!(validate_llm_provider("openai-compatible").is_ok());
     }
@@ -0,0 +1,74 @@
+//! GitHub configuration commands
+//!
+//! Commands for managing GitHub configuration.
+
+use chrono::Utc;
+use tauri::State;
+
+use recap_core::auth::verify_token;
+use recap_core::services::get_github_config;
+
+use crate::commands::AppState;
+use super::types::{ConfigureGitHubRequest, GitHubConfigStatus};
+
+/// Get GitHub configuration status
+#[tauri::command]
+pub async fn get_github_status(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<GitHubConfigStatus, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let config = get_github_config(&db.pool, &claims.sub).await?;
+
+    Ok(GitHubConfigStatus {
+        configured: config.is_some(),
+        github_url: config.map(|c| c.api_url),
+    })
+}
+
+/// Configure GitHub
+#[tauri::command]
+pub async fn configure_github(
+    state: State<'_, AppState>,
+    token: String,
+    request: ConfigureGitHubRequest,
+) -> Result<serde_json::Value, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+    let now = Utc::now();
+
+    let github_url = request.github_url.filter(|u| !u.trim().is_empty());
+
+    sqlx::query("UPDATE users SET github_url = ?, github_pat = ?, updated_at = ? WHERE id = ?")
+        .bind(&github_url)
+        .bind(&request.github_pat)
+        .bind(now)
+        .bind(&claims.sub)
+        .execute(&db.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(serde_json::json!({ "message": "GitHub configured successfully" }))
+}
+
+/// Remove GitHub configuration
+#[tauri::command]
+pub async fn remove_github_config(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<serde_json::Value, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+    let now = Utc::now();
+
+    sqlx::query("UPDATE users SET github_url = NULL, github_pat = NULL, updated_at = ? WHERE id = ?")
+        .bind(now)
+        .bind(&claims.sub)
+        .execute(&db.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(serde_json::json!({ "message": "GitHub configuration removed" }))
+}
@@ -0,0 +1,14 @@
+//! GitHub module
+//!
+//! Tauri commands for GitHub integration operations.
+//!
+//! ## Structure
+//! - `types.rs` - Request/response data types
+//! - `config.rs` - Configuration commands (status, configure, remove)
+//! - `projects.rs` - Project management (list, add, remove, search)
+//! - `sync.rs` - Sync GitHub data to work items
+
+pub mod config;
+pub mod projects;
+pub mod sync;
+pub mod types;
@@ -0,0 +1,161 @@
+//! GitHub project management commands
+//!
+//! Commands for managing tracked GitHub repositories.
+
+use chrono::Utc;
+use tauri::State;
+use uuid::Uuid;
+
+use recap_core::auth::verify_token;
+use recap_core::models::GitHubProject;
+use recap_core::services::{get_github_config, purge_github_project_items, GitHubClient, GitHubRepoInfo};
+
+use crate::commands::AppState;
+use super::types::{AddGitHubProjectRequest, SearchGitHubProjectsRequest};
+
+/// List user's tracked GitHub repositories
+#[tauri::command]
+pub async fn list_github_projects(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<Vec<GitHubProject>, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let projects: Vec<GitHubProject> =
+        sqlx::query_as("SELECT * FROM github_projects WHERE user_id = ? ORDER BY full_name")
+            .bind(&claims.sub)
+            .fetch_all(&db.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+    Ok(projects)
+}
+
+/// Add a GitHub repository to track
+#[tauri::command]
+pub async fn add_github_project(
+    state: State<'_, AppState>,
+    token: String,
+    request: AddGitHubProjectRequest,
+) -> Result<GitHubProject, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let config = get_github_config(&db.pool, &claims.sub)
+        .await?
+        .ok_or("GitHub PAT not configured".to_string())?;
+
+    let full_name = request.full_name.trim().trim_matches('/');
+    let repo = GitHubClient::new(&config.api_url, &config.pat)
+        .get_repo(full_name)
+        .await?;
+
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO github_projects (id, user_id, github_repo_id, name, full_name,
+            html_url, default_branch, enabled, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, 1, ?)
+        ON CONFLICT(user_id, github_repo_id) DO UPDATE SET
+            name = excluded.name,
+            full_name = excluded.full_name,
+            html_url = excluded.html_url,
+            enabled = 1
+        "#,
+    )
+    .bind(&id)
+    .bind(&claims.sub)
+    .bind(repo.id)
+    .bind(&repo.name)
+    .bind(&repo.full_name)
+    .bind(&repo.html_url)
+    .bind(repo.default_branch.as_deref().unwrap_or("main"))
+    .bind(now)
+    .execute(&db.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let project: GitHubProject = sqlx::query_as(
+        "SELECT * FROM github_projects WHERE user_id = ? AND github_repo_id = ?",
+    )
+    .bind(&claims.sub)
+    .bind(repo.id)
+    .fetch_one(&db.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(project)
+}
+
+/// Remove a GitHub repository from tracking
+///
+/// With `purge_items`, work items synced from the repository are deleted as well.
+#[tauri::command]
+pub async fn remove_github_project(
+    state: State<'_, AppState>,
+    token: String,
+    id: String,
+    purge_items: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let project: Option<GitHubProject> =
+        sqlx::query_as("SELECT * FROM github_projects WHERE id = ? AND user_id = ?")
+            .bind(&id)
+            .bind(&claims.sub)
+            .fetch_optional(&db.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+    let Some(project) = project else {
+        return Err("Project not found".to_string());
+    };
+
+    let mut tx = db.pool.begin().await.map_err(|e| e.to_string())?;
+
+    sqlx::query("DELETE FROM github_projects WHERE id = ? AND user_id = ?")
+        .bind(&id)
+        .bind(&claims.sub)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let purged = if purge_items.unwrap_or(false) {
+        purge_github_project_items(&mut tx, &claims.sub, &project.html_url).await?
+    } else {
+        0
+    };
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    Ok(serde_json::json!({ "message": "Project removed", "purged_items": purged }))
+}
+
+/// Search the user's GitHub repositories by name
+#[tauri::command]
+pub async fn search_github_projects(
+    state: State<'_, AppState>,
+    token: String,
+    request: SearchGitHubProjectsRequest,
+) -> Result<Vec<GitHubRepoInfo>, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let config = get_github_config(&db.pool, &claims.sub)
+        .await?
+        .ok_or("GitHub PAT not configured".to_string())?;
+
+    let repos = GitHubClient::new(&config.api_url, &config.pat)
+        .list_repos()
+        .await?;
+
+    let search = request.search.map(|s| s.to_lowercase());
+    Ok(repos
+        .into_iter()
+        .filter(|r| search.as_ref().is_none_or(|s| r.full_name.to_lowercase().contains(s)))
+        .collect())
+}
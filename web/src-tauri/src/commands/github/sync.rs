@@ -0,0 +1,30 @@
+//! GitHub sync commands
+//!
+//! Commands for syncing GitHub data to work items.
+
+use tauri::State;
+
+use recap_core::auth::verify_token;
+use recap_core::services::sync_github_projects;
+
+use crate::commands::AppState;
+use super::types::{SyncGitHubRequest, SyncGitHubResponse};
+
+/// Sync merged pull requests and commits from GitHub to work items
+#[tauri::command]
+pub async fn sync_github(
+    state: State<'_, AppState>,
+    token: String,
+    request: SyncGitHubRequest,
+) -> Result<SyncGitHubResponse, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let result = sync_github_projects(&db.pool, &claims.sub, request.project_id.as_deref()).await?;
+
+    Ok(SyncGitHubResponse {
+        synced_commits: result.synced_commits,
+        synced_pull_requests: result.synced_pull_requests,
+        work_items_created: result.work_items_created,
+    })
+}
@@ -0,0 +1,47 @@
+//! GitHub types
+//!
+//! Request/response types for GitHub integration.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to add a GitHub repository to tracking
+#[derive(Debug, Deserialize)]
+pub struct AddGitHubProjectRequest {
+    /// Repository as `owner/name`; details are fetched from the GitHub API
+    pub full_name: String,
+}
+
+/// Request to sync GitHub data
+#[derive(Debug, Deserialize)]
+pub struct SyncGitHubRequest {
+    pub project_id: Option<String>,
+}
+
+/// Response from GitHub sync operation
+#[derive(Debug, Serialize)]
+pub struct SyncGitHubResponse {
+    pub synced_commits: i64,
+    pub synced_pull_requests: i64,
+    pub work_items_created: i64,
+}
+
+/// Request to search GitHub repositories
+#[derive(Debug, Deserialize)]
+pub struct SearchGitHubProjectsRequest {
+    pub search: Option<String>,
+}
+
+/// GitHub configuration status
+#[derive(Debug, Serialize)]
+pub struct GitHubConfigStatus {
+    pub configured: bool,
+    pub github_url: Option<String>,
+}
+
+/// Request to configure GitHub
+#[derive(Debug, Deserialize)]
+pub struct ConfigureGitHubRequest {
+    /// API base for GitHub Enterprise; defaults to https://api.github.com
+    pub github_url: Option<String>,
+    pub github_pat: String,
+}
@@ -8,6 +8,7 @@ use tauri::State;
 use recap_core::auth::verify_token;
 
 use crate::commands::AppState;
+use super::sync::{get_sync_concurrency, MAX_SYNC_CONCURRENCY};
 use super::types::{ConfigureGitLabRequest, GitLabConfigStatus};
 
 /// Get GitLab configuration status
@@ -28,6 +29,7 @@ pub async fn get_gitlab_status(
     Ok(GitLabConfigStatus {
         configured: user.gitlab_pat.is_some(),
         gitlab_url: user.gitlab_url,
+        sync_concurrency: get_sync_concurrency(&db.pool, &claims.sub).await as i64,
     })
 }
 
@@ -42,14 +44,27 @@ pub async fn configure_gitlab(
     let db = state.db.lock().await;
     let now = Utc::now();
 
-    sqlx::query("UPDATE users SET gitlab_url = ?, gitlab_pat = ?, updated_at = ? WHERE id = ?")
-        .bind(&request.gitlab_url)
-        .bind(&request.gitlab_pat)
-        .bind(now)
-        .bind(&claims.sub)
-        .execute(&db.pool)
-        .await
-        .map_err(|e| e.to_string())?;
+    if let Some(concurrency) = request.sync_concurrency {
+        if !(1..=MAX_SYNC_CONCURRENCY).contains(&concurrency) {
+            return Err(format!("Sync concurrency must be between 1 and {}", MAX_SYNC_CONCURRENCY));
+        }
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE users SET gitlab_url = ?, gitlab_pat = ?,
+            gitlab_sync_concurrency = COALESCE(?, gitlab_sync_concurrency), updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&request.gitlab_url)
+    .bind(&request.gitlab_pat)
+    .bind(request.sync_concurrency)
+    .bind(now)
+    .bind(&claims.sub)
+    .execute(&db.pool)
+    .await
+    .map_err(|e| e.to_string())?;
 
     Ok(serde_json::json!({ "message": "GitLab configured successfully" }))
 }
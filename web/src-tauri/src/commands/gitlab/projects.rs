@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use recap_core::auth::verify_token;
 use recap_core::models::GitLabProject;
+use recap_core::services::purge_gitlab_project_items;
 
 use crate::commands::AppState;
 use super::types::{AddProjectRequest, GitLabProjectInfo, SearchProjectsRequest};
@@ -138,27 +139,54 @@ pub async fn add_gitlab_project(
 }
 
 /// Remove a GitLab project from tracking
+///
+/// With `purge_items`, work items synced from the project are deleted as well.
 #[tauri::command]
 pub async fn remove_gitlab_project(
     state: State<'_, AppState>,
     token: String,
     id: String,
+    purge_items: Option<bool>,
 ) -> Result<serde_json::Value, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    let result = sqlx::query("DELETE FROM gitlab_projects WHERE id = ? AND user_id = ?")
+    let project: Option<GitLabProject> =
+        sqlx::query_as("SELECT * FROM gitlab_projects WHERE id = ? AND user_id = ?")
+            .bind(&id)
+            .bind(&claims.sub)
+            .fetch_optional(&db.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+    let Some(project) = project else {
+        return Err("Project not found".to_string());
+    };
+
+    let mut tx = db.pool.begin().await.map_err(|e| e.to_string())?;
+
+    sqlx::query("DELETE FROM gitlab_projects WHERE id = ? AND user_id = ?")
         .bind(&id)
         .bind(&claims.sub)
-        .execute(&db.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
 
-    if result.rows_affected() == 0 {
-        return Err("Project not found".to_string());
-    }
-
-    Ok(serde_json::json!({ "message": "Project removed" }))
+    let purged = if purge_items.unwrap_or(false) {
+        purge_gitlab_project_items(
+            &mut tx,
+            &claims.sub,
+            &project.gitlab_url,
+            &project.path_with_namespace,
+        )
+        .await?
+    } else {
+        0
+    };
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    Ok(serde_json::json!({ "message": "Project removed", "purged_items": purged }))
 }
 
 /// Search GitLab projects
@@ -3,18 +3,56 @@
 //! Commands for syncing GitLab data to work items.
 
 use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
 use std::collections::HashSet;
+use std::time::Duration;
 use tauri::State;
 use uuid::Uuid;
 
 use recap_core::auth::verify_token;
 use recap_core::models::GitLabProject;
-use recap_core::services::worklog;
+use recap_core::services::{compute_content_hash, worklog};
 
 use crate::commands::AppState;
-use super::types::{GitLabCommit, SyncGitLabRequest, SyncGitLabResponse};
+use super::types::{GitLabCommit, GitLabProjectSyncResult, SyncGitLabRequest, SyncGitLabResponse};
+
+/// Projects synced at once unless the user configured otherwise
+pub const DEFAULT_SYNC_CONCURRENCY: i64 = 4;
+
+/// Highest concurrency `configure_gitlab` accepts
+pub const MAX_SYNC_CONCURRENCY: i64 = 16;
+
+/// Pause once this few requests remain in the rate limit window
+const RATE_LIMIT_LOW_WATER: u64 = 5;
+
+/// Pause when GitLab asks us to slow down without saying for how long
+const DEFAULT_RATE_LIMIT_PAUSE: Duration = Duration::from_secs(1);
+
+/// Longest pause honored from a rate limit header
+const MAX_RATE_LIMIT_PAUSE: Duration = Duration::from_secs(60);
+
+/// Retries after a 429 before the project is reported as failed
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Load the user's GitLab sync concurrency, falling back to the default
+pub async fn get_sync_concurrency(pool: &sqlx::SqlitePool, user_id: &str) -> usize {
+    sqlx::query_scalar::<_, Option<i64>>("SELECT gitlab_sync_concurrency FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()
+        .flatten()
+        .unwrap_or(DEFAULT_SYNC_CONCURRENCY)
+        .clamp(1, MAX_SYNC_CONCURRENCY) as usize
+}
 
 /// Sync GitLab data to work items
+///
+/// Projects are fetched a few at a time (`gitlab_sync_concurrency`), and a
+/// failing project is reported in the response without stopping the rest.
 #[tauri::command]
 pub async fn sync_gitlab(
     state: State<'_, AppState>,
@@ -55,86 +93,145 @@ pub async fn sync_gitlab(
             .map_err(|e| e.to_string())?
     };
 
+    let concurrency = get_sync_concurrency(&db.pool, &claims.sub).await;
+    let client = reqwest::Client::new();
+
+    let results: Vec<(GitLabProjectSyncResult, i64)> = stream::iter(projects)
+        .map(|project| {
+            sync_project(&db.pool, &client, &claims.sub, &gitlab_url, &gitlab_pat, project)
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
     let mut synced_commits = 0i64;
-    #[allow(unused_mut)]
-    let mut synced_merge_requests = 0i64;
     let mut work_items_created = 0i64;
+    let mut project_results = Vec::with_capacity(results.len());
+    for (result, created) in results {
+        synced_commits += result.synced_commits;
+        work_items_created += created;
+        project_results.push(result);
+    }
 
-    let client = reqwest::Client::new();
+    Ok(SyncGitLabResponse {
+        synced_commits,
+        synced_merge_requests: 0,
+        work_items_created,
+        projects: project_results,
+    })
+}
 
-    for project in projects {
-        // Sync commits
-        let commits_url = format!(
-            "{}/api/v4/projects/{}/repository/commits",
-            gitlab_url, project.gitlab_project_id
-        );
+/// Sync one project's commits, returning its outcome and the work items created
+async fn sync_project(
+    pool: &sqlx::SqlitePool,
+    client: &reqwest::Client,
+    user_id: &str,
+    gitlab_url: &str,
+    gitlab_pat: &str,
+    project: GitLabProject,
+) -> (GitLabProjectSyncResult, i64) {
+    let mut result = GitLabProjectSyncResult {
+        project_id: project.id.clone(),
+        path_with_namespace: project.path_with_namespace.clone(),
+        success: false,
+        synced_commits: 0,
+        error: None,
+    };
 
-        let commits_result = client
-            .get(&commits_url)
-            .header("PRIVATE-TOKEN", &gitlab_pat)
+    let commits_url = format!(
+        "{}/api/v4/projects/{}/repository/commits",
+        gitlab_url, project.gitlab_project_id
+    );
+    let commits = match fetch_commits(client, &commits_url, gitlab_pat).await {
+        Ok(commits) => commits,
+        Err(e) => {
+            log::warn!("Failed to sync GitLab project {}: {}", project.path_with_namespace, e);
+            result.error = Some(e);
+            return (result, 0);
+        }
+    };
+
+    let (synced, created) = process_commits(pool, user_id, gitlab_url, &project, commits).await;
+    result.success = true;
+    result.synced_commits = synced;
+
+    // Update last_synced
+    if let Err(e) = sqlx::query("UPDATE gitlab_projects SET last_synced = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(&project.id)
+        .execute(pool)
+        .await
+    {
+        log::warn!("Failed to update last_synced for project {}: {}", project.id, e);
+    }
+
+    (result, created)
+}
+
+/// Fetch a project's recent commits, waiting out GitLab's rate limit
+async fn fetch_commits(
+    client: &reqwest::Client,
+    commits_url: &str,
+    gitlab_pat: &str,
+) -> Result<Vec<GitLabCommit>, String> {
+    let mut retries = 0;
+    loop {
+        let response = client
+            .get(commits_url)
+            .header("PRIVATE-TOKEN", gitlab_pat)
             .query(&[("per_page", "100"), ("with_stats", "true")])
             .send()
-            .await;
-
-        match commits_result {
-            Ok(response) => {
-                if !response.status().is_success() {
-                    log::warn!(
-                        "GitLab API returned status {} for project {}",
-                        response.status(),
-                        project.path_with_namespace
-                    );
-                    continue;
-                }
-
-                match response.json::<Vec<GitLabCommit>>().await {
-                    Ok(commits) => {
-                        let (synced, created) = process_commits(
-                            &db.pool,
-                            &claims.sub,
-                            &gitlab_url,
-                            &project,
-                            commits,
-                        )
-                        .await;
-                        synced_commits += synced;
-                        work_items_created += created;
-                    }
-                    Err(e) => {
-                        log::warn!(
-                            "Failed to parse commits JSON for project {}: {}",
-                            project.path_with_namespace,
-                            e
-                        );
-                    }
-                }
-            }
-            Err(e) => {
-                log::warn!(
-                    "Failed to fetch commits for project {}: {}",
-                    project.path_with_namespace,
-                    e
-                );
-            }
+            .await
+            .map_err(|e| format!("Failed to fetch commits: {}", e))?;
+
+        let status = response.status();
+        let pause = rate_limit_pause(status, response.headers(), Utc::now().timestamp());
+
+        if status == StatusCode::TOO_MANY_REQUESTS && retries < MAX_RATE_LIMIT_RETRIES {
+            retries += 1;
+            tokio::time::sleep(pause.unwrap_or(DEFAULT_RATE_LIMIT_PAUSE)).await;
+            continue;
+        }
+        if !status.is_success() {
+            return Err(format!("GitLab API returned status {}", status));
         }
 
-        // Update last_synced
-        let now = Utc::now();
-        if let Err(e) = sqlx::query("UPDATE gitlab_projects SET last_synced = ? WHERE id = ?")
-            .bind(now)
-            .bind(&project.id)
-            .execute(&db.pool)
+        let commits = response
+            .json::<Vec<GitLabCommit>>()
             .await
-        {
-            log::warn!("Failed to update last_synced for project {}: {}", project.id, e);
+            .map_err(|e| format!("Failed to parse commits JSON: {}", e))?;
+        // Near the limit: hold this worker back before its next project
+        if let Some(pause) = pause {
+            tokio::time::sleep(pause).await;
         }
+        return Ok(commits);
     }
+}
 
-    Ok(SyncGitLabResponse {
-        synced_commits,
-        synced_merge_requests,
-        work_items_created,
-    })
+/// How long to wait after a response, from `Retry-After` or the
+/// `RateLimit-Remaining`/`RateLimit-Reset` headers; `None` when there is
+/// headroom left. `now` is the current Unix time in seconds.
+fn rate_limit_pause(status: StatusCode, headers: &HeaderMap, now: i64) -> Option<Duration> {
+    let header_u64 = |name: &str| -> Option<u64> {
+        headers.get(name)?.to_str().ok()?.trim().parse().ok()
+    };
+
+    if let Some(seconds) = header_u64(RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(seconds).min(MAX_RATE_LIMIT_PAUSE));
+    }
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Some(DEFAULT_RATE_LIMIT_PAUSE);
+    }
+
+    let remaining = header_u64("ratelimit-remaining")?;
+    if remaining > RATE_LIMIT_LOW_WATER {
+        return None;
+    }
+    let pause = match header_u64("ratelimit-reset") {
+        Some(reset) => Duration::from_secs(reset.saturating_sub(now.max(0) as u64)),
+        None => DEFAULT_RATE_LIMIT_PAUSE,
+    };
+    Some(pause.min(MAX_RATE_LIMIT_PAUSE))
 }
 
 /// Process commits and create work items
@@ -226,12 +323,14 @@ async fn process_commits(
             .unwrap_or((0, 0));
         // Use 1 file as estimate since GitLab list doesn't give file count
         let estimated_hours = worklog::estimate_from_diff(additions, deletions, 1);
+        let content_hash = compute_content_hash(user_id, "gitlab", &commit.id, &commit.title, commit_date);
 
         if let Err(e) = sqlx::query(
             r#"
             INSERT INTO work_items (id, user_id, source, source_id, source_url, title,
-                description, hours, date, hours_source, hours_estimated, commit_hash, created_at, updated_at)
-            VALUES (?, ?, 'gitlab', ?, ?, ?, ?, ?, ?, 'heuristic', ?, ?, ?, ?)
+                description, hours, date, content_hash, hours_source, hours_estimated, commit_hash,
+                created_at, updated_at)
+            VALUES (?, ?, 'gitlab', ?, ?, ?, ?, ?, ?, ?, 'heuristic', ?, ?, ?, ?)
             "#,
         )
         .bind(&work_item_id)
@@ -242,6 +341,7 @@ async fn process_commits(
         .bind(&commit.message)
         .bind(estimated_hours)
         .bind(commit_date)
+        .bind(&content_hash)
         .bind(estimated_hours)
         .bind(&short_hash)
         .bind(now)
@@ -259,3 +359,43 @@ async fn process_commits(
 
     (synced_commits, work_items_created)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_rate_limit_pause_with_headroom() {
+        let h = headers(&[("ratelimit-remaining", "500"), ("ratelimit-reset", "1000")]);
+        assert_eq!(rate_limit_pause(StatusCode::OK, &h, 990), None);
+        assert_eq!(rate_limit_pause(StatusCode::OK, &HeaderMap::new(), 990), None);
+    }
+
+    #[test]
+    fn test_rate_limit_pause_near_limit_waits_for_reset() {
+        let h = headers(&[("ratelimit-remaining", "2"), ("ratelimit-reset", "1010")]);
+        assert_eq!(rate_limit_pause(StatusCode::OK, &h, 1000), Some(Duration::from_secs(10)));
+
+        // A far-off reset is capped
+        let h = headers(&[("ratelimit-remaining", "0"), ("ratelimit-reset", "9999")]);
+        assert_eq!(rate_limit_pause(StatusCode::OK, &h, 1000), Some(MAX_RATE_LIMIT_PAUSE));
+    }
+
+    #[test]
+    fn test_rate_limit_pause_honors_retry_after() {
+        let h = headers(&[("retry-after", "7")]);
+        assert_eq!(rate_limit_pause(StatusCode::TOO_MANY_REQUESTS, &h, 0), Some(Duration::from_secs(7)));
+        assert_eq!(
+            rate_limit_pause(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), 0),
+            Some(DEFAULT_RATE_LIMIT_PAUSE)
+        );
+    }
+}
@@ -27,6 +27,18 @@ pub struct SyncGitLabResponse {
     pub synced_commits: i64,
     pub synced_merge_requests: i64,
     pub work_items_created: i64,
+    /// Outcome of each project, in the order they finished
+    pub projects: Vec<GitLabProjectSyncResult>,
+}
+
+/// Outcome of syncing a single GitLab project
+#[derive(Debug, Serialize)]
+pub struct GitLabProjectSyncResult {
+    pub project_id: String,
+    pub path_with_namespace: String,
+    pub success: bool,
+    pub synced_commits: i64,
+    pub error: Option<String>,
 }
 
 /// Request to search GitLab projects
@@ -67,6 +79,8 @@ pub struct CommitStats {
 pub struct GitLabConfigStatus {
     pub configured: bool,
     pub gitlab_url: Option<String>,
+    /// Projects synced at once
+    pub sync_concurrency: i64,
 }
 
 /// Request to configure GitLab
@@ -74,4 +88,7 @@ pub struct GitLabConfigStatus {
 pub struct ConfigureGitLabRequest {
     pub gitlab_url: String,
     pub gitlab_pat: String,
+    /// Projects synced at once; unchanged when omitted
+    #[serde(default)]
+    pub sync_concurrency: Option<i64>,
 }
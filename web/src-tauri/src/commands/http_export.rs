@@ -347,7 +347,7 @@ pub async fn execute_http_export(
             r#"SELECT id, title, description, hours, date, source,
                       jira_issue_key, category, project_path
                FROM work_items
-               WHERE id IN ({}) AND user_id = ?"#,
+               WHERE id IN ({}) AND user_id = ? AND deleted_at IS NULL"#,
             placeholders
         );
 
@@ -3,7 +3,7 @@
 //! Provides commands for querying LLM token usage statistics and logs.
 
 use recap_core::auth::verify_token;
-use recap_core::services::llm_usage;
+use recap_core::services::llm_usage::{self, LlmUsageLogPage, LlmUsageLogQuery};
 use serde::Serialize;
 use tauri::State;
 
@@ -44,23 +44,6 @@ pub struct ModelUsageResponse {
     pub cost: f64,
 }
 
-/// Response for usage log entry
-#[derive(Debug, Serialize)]
-pub struct LlmUsageLogResponse {
-    pub id: String,
-    pub provider: String,
-    pub model: String,
-    pub prompt_tokens: Option<i64>,
-    pub completion_tokens: Option<i64>,
-    pub total_tokens: Option<i64>,
-    pub estimated_cost: Option<f64>,
-    pub purpose: String,
-    pub duration_ms: Option<i64>,
-    pub status: String,
-    pub error_message: Option<String>,
-    pub created_at: String,
-}
-
 /// Get aggregated LLM usage statistics for a date range.
 #[tauri::command(rename_all = "snake_case")]
 pub async fn get_llm_usage_stats(
@@ -138,44 +121,16 @@ pub async fn get_llm_usage_by_model(
         .collect())
 }
 
-/// Get paginated LLM usage logs for a date range.
+/// Get a page of LLM usage logs, filtered by date range, provider and
+/// purpose, with the total cost of every matching log.
 #[tauri::command(rename_all = "snake_case")]
 pub async fn get_llm_usage_logs(
     state: State<'_, AppState>,
     token: String,
-    start_date: String,
-    end_date: String,
-    limit: Option<i64>,
-    offset: Option<i64>,
-) -> Result<Vec<LlmUsageLogResponse>, String> {
+    query: LlmUsageLogQuery,
+) -> Result<LlmUsageLogPage, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    let logs = llm_usage::get_usage_logs(
-        &db.pool,
-        &claims.sub,
-        &start_date,
-        &end_date,
-        limit.unwrap_or(50),
-        offset.unwrap_or(0),
-    )
-    .await?;
-
-    Ok(logs
-        .into_iter()
-        .map(|l| LlmUsageLogResponse {
-            id: l.id,
-            provider: l.provider,
-            model: l.model,
-            prompt_tokens: l.prompt_tokens,
-            completion_tokens: l.completion_tokens,
-            total_tokens: l.total_tokens,
-            estimated_cost: l.estimated_cost,
-            purpose: l.purpose,
-            duration_ms: l.duration_ms,
-            status: l.status,
-            error_message: l.error_message,
-            created_at: l.created_at,
-        })
-        .collect())
+    llm_usage::get_usage_logs(&db.pool, &claims.sub, &query).await
 }
@@ -9,6 +9,7 @@ pub mod batch_compaction;
 pub mod claude;
 pub mod config;
 pub mod danger_zone;
+pub mod github;
 pub mod gitlab;
 pub mod http_export;
 pub mod llm_usage;
@@ -26,6 +27,7 @@ pub mod worklog_sync;
 
 use crate::services::BackgroundSyncService;
 use recap_core::Database;
+use serde::Serialize;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -44,3 +46,135 @@ impl AppState {
         }
     }
 }
+
+/// Structured error returned to the frontend by migrated commands.
+///
+/// Serializes as `{ code, message, retryable }` so the UI can branch on
+/// `code` (e.g. send the user back to login on `AUTH_INVALID_TOKEN`) instead
+/// of matching message text. Commands still returning `String` are unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandError {
+    /// Stable machine-readable code, one of the `CommandError::*` constants
+    pub code: String,
+    /// Human-readable message for display
+    pub message: String,
+    /// Whether retrying the same call may succeed
+    pub retryable: bool,
+}
+
+impl CommandError {
+    pub const AUTH_INVALID_TOKEN: &'static str = "AUTH_INVALID_TOKEN";
+    pub const AUTH_FAILED: &'static str = "AUTH_FAILED";
+    pub const ACCOUNT_DISABLED: &'static str = "ACCOUNT_DISABLED";
+    pub const NOT_FOUND: &'static str = "NOT_FOUND";
+    pub const ALREADY_EXISTS: &'static str = "ALREADY_EXISTS";
+    pub const VALIDATION: &'static str = "VALIDATION";
+    pub const NOT_CONFIGURED: &'static str = "NOT_CONFIGURED";
+    pub const SOURCE_UNAVAILABLE: &'static str = "SOURCE_UNAVAILABLE";
+    pub const SYNC_FAILED: &'static str = "SYNC_FAILED";
+    pub const DATABASE: &'static str = "DATABASE";
+    pub const INTERNAL: &'static str = "INTERNAL";
+
+    pub fn new(code: &str, message: impl Into<String>, retryable: bool) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            retryable,
+        }
+    }
+
+    pub fn auth_failed(message: impl Into<String>) -> Self {
+        Self::new(Self::AUTH_FAILED, message, false)
+    }
+
+    pub fn account_disabled(message: impl Into<String>) -> Self {
+        Self::new(Self::ACCOUNT_DISABLED, message, false)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(Self::NOT_FOUND, message, false)
+    }
+
+    pub fn already_exists(message: impl Into<String>) -> Self {
+        Self::new(Self::ALREADY_EXISTS, message, false)
+    }
+
+    /// A sync source failed; the next attempt may succeed
+    pub fn sync_failed(message: impl Into<String>) -> Self {
+        Self::new(Self::SYNC_FAILED, message, true)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(Self::INTERNAL, message, false)
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<recap_core::Error> for CommandError {
+    fn from(err: recap_core::Error) -> Self {
+        use recap_core::Error;
+
+        let message = err.to_string();
+        match err {
+            Error::Jwt(_) => Self::new(Self::AUTH_INVALID_TOKEN, message, false),
+            Error::Auth(_) => Self::new(Self::AUTH_FAILED, message, false),
+            Error::NotFound(_) => Self::new(Self::NOT_FOUND, message, false),
+            Error::Validation(_) => Self::new(Self::VALIDATION, message, false),
+            Error::Config(_) => Self::new(Self::NOT_CONFIGURED, message, false),
+            Error::Http(_) => Self::new(Self::SOURCE_UNAVAILABLE, message, true),
+            Error::Database(_) => Self::new(Self::DATABASE, message, true),
+            Error::Bcrypt(_) | Error::Io(_) | Error::Json(_) | Error::Internal(_) => {
+                Self::new(Self::INTERNAL, message, false)
+            }
+        }
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for CommandError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        Self::new(Self::AUTH_INVALID_TOKEN, format!("Invalid token: {}", err), false)
+    }
+}
+
+/// Errors from helpers that still return `String` (repositories, services)
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::internal(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_error_serializes_code_message_retryable() {
+        let err = CommandError::sync_failed("Sync error");
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "code": "SYNC_FAILED", "message": "Sync error", "retryable": true })
+        );
+    }
+
+    #[test]
+    fn test_command_error_from_core_error() {
+        let err = CommandError::from(recap_core::Error::NotFound("Project x".to_string()));
+        assert_eq!(err.code, CommandError::NOT_FOUND);
+        assert!(!err.retryable);
+
+        let err = CommandError::from(recap_core::Error::Config("GitLab URL not set".to_string()));
+        assert_eq!(err.code, CommandError::NOT_CONFIGURED);
+
+        let err = CommandError::from(recap_core::auth::verify_token("not-a-token").unwrap_err());
+        assert_eq!(err.code, CommandError::AUTH_INVALID_TOKEN);
+        assert!(err.message.starts_with("Invalid token"));
+    }
+}
@@ -8,12 +8,14 @@ use tauri::State;
 
 use recap_core::auth::verify_token;
 use recap_core::models::WorkItem;
+use recap_core::services::{project_dir_cache, ProjectDirFilter};
 
 use crate::commands::AppState;
 use super::types::{
     AddManualProjectRequest, ClaudeCodeDirEntry,
     ClaudeSessionPathResponse, ProjectDetail, ProjectDirectories, ProjectInfo,
-    ProjectSourceInfo, ProjectStats, SetProjectVisibilityRequest, WorkItemSummary,
+    ProjectSourceInfo, ProjectStats, SetProjectVisibilityRequest, SetProjectsVisibilityRequest,
+    WorkItemSummary,
 };
 
 /// Check if a path is a manual project path (~/.recap/manual-projects/xxx)
@@ -79,7 +81,7 @@ pub async fn list_projects(
 
     // Fetch all work items for this user
     let items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? ORDER BY date DESC",
+        "SELECT * FROM work_items WHERE user_id = ? AND deleted_at IS NULL ORDER BY date DESC",
     )
     .bind(&claims.sub)
     .fetch_all(&db.pool)
@@ -254,7 +256,7 @@ pub async fn get_project_detail(
 
     // Fetch all work items for this user
     let items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? ORDER BY date DESC",
+        "SELECT * FROM work_items WHERE user_id = ? AND deleted_at IS NULL ORDER BY date DESC",
     )
     .bind(&claims.sub)
     .fetch_all(&db.pool)
@@ -292,7 +294,7 @@ pub async fn get_project_detail(
         if item.source == "aggregated" {
             // Find children of this aggregated item
             let children: Vec<WorkItem> = sqlx::query_as(
-                "SELECT * FROM work_items WHERE parent_id = ? AND user_id = ?",
+                "SELECT * FROM work_items WHERE parent_id = ? AND user_id = ? AND deleted_at IS NULL",
             )
             .bind(&item.id)
             .bind(&claims.sub)
@@ -429,6 +431,26 @@ pub async fn set_project_visibility(
     Ok("ok".to_string())
 }
 
+/// Set visibility for several projects in one transaction.
+/// Returns how many projects changed.
+#[tauri::command]
+pub async fn set_projects_visibility(
+    state: State<'_, AppState>,
+    token: String,
+    request: SetProjectsVisibilityRequest,
+) -> Result<usize, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    recap_core::services::set_projects_visibility(
+        &db.pool,
+        &claims.sub,
+        &request.project_names,
+        request.hidden,
+    )
+    .await
+}
+
 /// Get list of hidden project names for global filtering
 #[tauri::command]
 pub async fn get_hidden_projects(
@@ -453,29 +475,29 @@ pub async fn get_hidden_projects(
 ///
 /// Scans the user's Claude session path (default ~/.claude) /projects/ to find
 /// directories matching the project, reads sessions-index.json for git repo path.
+/// Scans are cached briefly; `refresh` forces a rescan. `include`/`exclude` are
+/// globs on the directory name (e.g. `*-node_modules*`) to hide noise directories.
 #[tauri::command]
 pub async fn get_project_directories(
     state: State<'_, AppState>,
     token: String,
     project_name: String,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    refresh: Option<bool>,
 ) -> Result<ProjectDirectories, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let filter = ProjectDirFilter::new(
+        &include.unwrap_or_default(),
+        &exclude.unwrap_or_default(),
+    )?;
     let db = state.db.lock().await;
 
-    // 0. Get user's Claude session path (or default)
-    let claude_base: Option<String> = sqlx::query_as::<_, (Option<String>,)>(
-        "SELECT claude_session_path FROM users WHERE id = ?",
-    )
-    .bind(&claims.sub)
-    .fetch_optional(&db.pool)
-    .await
-    .map_err(|e| e.to_string())?
-    .and_then(|(path,)| path);
-
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
-    let claude_base_path = claude_base
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|| home.join(".claude"));
+    // 0. Get user's Claude session roots (or default)
+    let claude_projects_dirs = recap_core::services::resolve_claude_projects_dirs(&db.pool, &claims.sub).await?;
+    if claude_projects_dirs.is_empty() {
+        return Err("Cannot find home directory".to_string());
+    }
 
     // 0b. Check if project has a manual git_repo_path in preferences
     let manual_git_repo: Option<String> = sqlx::query_as::<_, (Option<String>,)>(
@@ -490,7 +512,7 @@ pub async fn get_project_directories(
 
     // 1. Get project_path from work items
     let items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? ORDER BY date DESC",
+        "SELECT * FROM work_items WHERE user_id = ? AND deleted_at IS NULL ORDER BY date DESC",
     )
     .bind(&claims.sub)
     .fetch_all(&db.pool)
@@ -502,82 +524,44 @@ pub async fn get_project_directories(
         .filter(|item| derive_project_name(item) == project_name)
         .find_map(|item| item.project_path.clone());
 
-    // 2. Scan <claude_base>/projects/ for ALL matching directories
-    let claude_projects_dir = claude_base_path.join("projects");
-
+    // 2. Scan <claude_root>/projects/ of every root for ALL matching directories
     let mut claude_code_dirs: Vec<ClaudeCodeDirEntry> = Vec::new();
     let mut git_repo_path: Option<String> = None;
 
-    if claude_projects_dir.exists() {
-        // Encode the project_path to match Claude Code's directory naming:
-        // /Users/foo/bar → -Users-foo-bar
-        let encoded_prefix: Option<String> = project_path.as_ref().map(|p| {
-            p.replace(['/', '\\'], "-")
-        });
-
-        // Fallback: match dirs ending with -<project_name>
-        let target_suffix = format!("-{}", project_name);
+    // Encode the project_path to match Claude Code's directory naming:
+    // /Users/foo/bar → -Users-foo-bar
+    let encoded_prefix: Option<String> = project_path.as_ref().map(|p| {
+        p.replace(['/', '\\'], "-")
+    });
 
-        let entries = std::fs::read_dir(&claude_projects_dir)
-            .map_err(|e| e.to_string())?;
+    // Fallback: match dirs ending with -<project_name>
+    let target_suffix = format!("-{}", project_name);
 
-        for entry in entries.flatten() {
-            let dir_name = entry.file_name().to_string_lossy().to_string();
-            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                continue;
-            }
+    for claude_projects_dir in claude_projects_dirs.iter().filter(|dir| dir.exists()) {
+        let scanned = project_dir_cache().get(claude_projects_dir, refresh.unwrap_or(false));
 
+        for dir in scanned.iter() {
             // Match: exact encoded path OR starts with encoded path + "-" (subdirs)
             // Fallback: ends with -<project_name> or starts with -...-<project_name>-
             let matched = if let Some(ref enc) = encoded_prefix {
-                dir_name == *enc || dir_name.starts_with(&format!("{}-", enc))
+                dir.name == *enc || dir.name.starts_with(&format!("{}-", enc))
             } else {
-                dir_name.ends_with(&target_suffix)
-                    || dir_name.contains(&format!("{}-", target_suffix))
+                dir.name.ends_with(&target_suffix)
+                    || dir.name.contains(&format!("{}-", target_suffix))
             };
 
-            if !matched {
+            if !matched || !filter.matches(&dir.name) {
                 continue;
             }
 
-            let dir_path = entry.path();
-            let mut dir_session_count: i64 = 0;
-
-            // Read sessions-index.json
-            let index_path = dir_path.join("sessions-index.json");
-            if index_path.exists() {
-                if let Ok(content) = std::fs::read_to_string(&index_path) {
-                    if let Ok(index) = serde_json::from_str::<serde_json::Value>(&content) {
-                        if let Some(sess_entries) = index.get("entries").and_then(|e| e.as_array()) {
-                            dir_session_count = sess_entries.len() as i64;
-
-                            // Try to get git repo path from the first session with projectPath
-                            if git_repo_path.is_none() {
-                                for sess in sess_entries {
-                                    if let Some(pp) = sess.get("projectPath").and_then(|v| v.as_str()) {
-                                        git_repo_path = Some(pp.to_string());
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            } else {
-                // Count .jsonl files as sessions if no index
-                if let Ok(files) = std::fs::read_dir(&dir_path) {
-                    dir_session_count = files
-                        .flatten()
-                        .filter(|f| {
-                            f.path().extension().map(|e| e == "jsonl").unwrap_or(false)
-                        })
-                        .count() as i64;
-                }
+            // Take the git repo path from the first directory whose index has one
+            if git_repo_path.is_none() {
+                git_repo_path = dir.git_repo_path.clone();
             }
 
             claude_code_dirs.push(ClaudeCodeDirEntry {
-                path: dir_path.to_string_lossy().to_string(),
-                session_count: dir_session_count,
+                path: dir.path.clone(),
+                session_count: dir.session_count,
             });
         }
     }
@@ -629,8 +613,10 @@ pub async fn get_claude_session_path(
     .map_err(|e| e.to_string())?;
 
     let custom_path = row.and_then(|(p,)| p);
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
-    let default_path = home.join(".claude").to_string_lossy().to_string();
+    let default_path = recap_core::services::default_claude_home()
+        .ok_or("Cannot find home directory")?
+        .to_string_lossy()
+        .to_string();
 
     let is_default = custom_path.is_none() || custom_path.as_deref() == Some(&default_path);
     let path = custom_path.unwrap_or_else(|| default_path);
@@ -641,7 +627,7 @@ pub async fn get_claude_session_path(
     })
 }
 
-/// Update the user's Claude session path
+/// Update the user's Claude session path (one or more comma-separated roots)
 #[tauri::command]
 pub async fn update_claude_session_path(
     state: State<'_, AppState>,
@@ -651,11 +637,12 @@ pub async fn update_claude_session_path(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    // Validate path exists and is a directory
+    // Validate every root exists and is a directory
     if let Some(ref p) = path {
-        let path_buf = std::path::PathBuf::from(p);
-        if !path_buf.is_dir() {
-            return Err(format!("Path is not a valid directory: {}", p));
+        for root in p.split(recap_core::services::CLAUDE_PATH_SEPARATOR).map(str::trim) {
+            if !std::path::Path::new(root).is_dir() {
+                return Err(format!("Path is not a valid directory: {}", root));
+            }
         }
     }
 
@@ -790,7 +777,7 @@ pub async fn get_project_readme(
     // Fall back to work items if no preference
     if project_path.is_none() {
         let items: Vec<WorkItem> = sqlx::query_as(
-            "SELECT * FROM work_items WHERE user_id = ? ORDER BY date DESC LIMIT 100",
+            "SELECT * FROM work_items WHERE user_id = ? AND deleted_at IS NULL ORDER BY date DESC LIMIT 100",
         )
         .bind(&claims.sub)
         .fetch_all(&db.pool)
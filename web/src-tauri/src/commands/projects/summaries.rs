@@ -123,7 +123,7 @@ async fn fetch_work_items_for_project(
 ) -> Result<Vec<WorkItem>, String> {
     let all_items: Vec<WorkItem> = sqlx::query_as(
         r#"SELECT * FROM work_items
-           WHERE user_id = ? AND date >= ? AND date <= ?
+           WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
            ORDER BY date DESC, created_at DESC"#,
     )
     .bind(user_id)
@@ -515,12 +515,13 @@ pub async fn trigger_summaries_generation(
 
                     let id = Uuid::new_v4().to_string();
                     let _ = sqlx::query(
-                        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash)
-                           VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash, llm_model)
+                           VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                            ON CONFLICT(user_id, project_name, summary_type, time_unit, period_start) DO UPDATE SET
                                summary = excluded.summary,
                                data_hash = excluded.data_hash,
                                period_label = excluded.period_label,
+                               llm_model = excluded.llm_model,
                                orphaned = 0,
                                orphaned_at = NULL,
                                created_at = CURRENT_TIMESTAMP"#,
@@ -535,6 +536,7 @@ pub async fn trigger_summaries_generation(
                     .bind(&period.period_label)
                     .bind(&summary)
                     .bind(&data_hash)
+                    .bind(&usage.model)
                     .execute(&pool)
                     .await;
 
@@ -632,7 +634,7 @@ pub async fn generate_completed_summaries(
     // Get work items to determine which periods have activity
     let work_items: Vec<(String,)> = sqlx::query_as(
         r#"SELECT DISTINCT date FROM work_items
-           WHERE user_id = ? AND date >= ? AND date <= ?
+           WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
            AND (title LIKE ? OR project_path LIKE ?)
            ORDER BY date DESC"#,
     )
@@ -803,12 +805,13 @@ pub async fn generate_completed_summaries(
 
                     let id = Uuid::new_v4().to_string();
                     let _ = sqlx::query(
-                        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash)
-                           VALUES (?, ?, ?, 'timeline', ?, ?, ?, ?, ?, ?)
+                        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash, llm_model)
+                           VALUES (?, ?, ?, 'timeline', ?, ?, ?, ?, ?, ?, ?)
                            ON CONFLICT(user_id, project_name, summary_type, time_unit, period_start) DO UPDATE SET
                                summary = excluded.summary,
                                data_hash = excluded.data_hash,
                                period_label = excluded.period_label,
+                               llm_model = excluded.llm_model,
                                orphaned = 0,
                                orphaned_at = NULL,
                                created_at = CURRENT_TIMESTAMP"#,
@@ -822,6 +825,7 @@ pub async fn generate_completed_summaries(
                     .bind(&period.period_label)
                     .bind(&summary)
                     .bind(&data_hash)
+                    .bind(&usage.model)
                     .execute(&pool)
                     .await;
 
@@ -1029,11 +1033,12 @@ pub async fn generate_project_summary(
 
     let id = Uuid::new_v4().to_string();
     sqlx::query(
-        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, summary, data_hash)
-           VALUES (?, ?, ?, 'report', ?, ?, ?, ?, ?)
+        r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, summary, data_hash, llm_model)
+           VALUES (?, ?, ?, 'report', ?, ?, ?, ?, ?, ?)
            ON CONFLICT(user_id, project_name, summary_type, time_unit, period_start) DO UPDATE SET
                summary = excluded.summary,
                data_hash = excluded.data_hash,
+               llm_model = excluded.llm_model,
                orphaned = 0,
                orphaned_at = NULL,
                created_at = CURRENT_TIMESTAMP"#,
@@ -1046,6 +1051,7 @@ pub async fn generate_project_summary(
     .bind(&request.period_end)
     .bind(&summary)
     .bind(&data_hash)
+    .bind(&usage.model)
     .execute(&pool)
     .await
     .map_err(|e| e.to_string())?;
@@ -1155,7 +1161,7 @@ pub async fn generate_all_completed_summaries(
                ELSE 'unknown'
            END as project_name
            FROM work_items
-           WHERE user_id = ?
+           WHERE user_id = ? AND deleted_at IS NULL
            HAVING project_name != 'unknown' AND project_name != ''"#,
     )
     .bind(user_id)
@@ -1254,12 +1260,13 @@ pub async fn generate_all_completed_summaries(
 
                         let id = Uuid::new_v4().to_string();
                         let _ = sqlx::query(
-                            r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash)
-                               VALUES (?, ?, ?, 'timeline', ?, ?, ?, ?, ?, ?)
+                            r#"INSERT INTO project_summaries (id, user_id, project_name, summary_type, time_unit, period_start, period_end, period_label, summary, data_hash, llm_model)
+                               VALUES (?, ?, ?, 'timeline', ?, ?, ?, ?, ?, ?, ?)
                                ON CONFLICT(user_id, project_name, summary_type, time_unit, period_start) DO UPDATE SET
                                    summary = excluded.summary,
                                    data_hash = excluded.data_hash,
                                    period_label = excluded.period_label,
+                                   llm_model = excluded.llm_model,
                                    orphaned = 0,
                                    orphaned_at = NULL,
                                    created_at = CURRENT_TIMESTAMP"#,
@@ -1273,6 +1280,7 @@ pub async fn generate_all_completed_summaries(
                         .bind(&period.period_label)
                         .bind(&summary)
                         .bind(&data_hash)
+                        .bind(&usage.model)
                         .execute(pool)
                         .await;
 
@@ -1319,7 +1327,7 @@ async fn find_missing_completed_periods(
     // Get all dates with work items for this project in the range
     let dates: Vec<(String,)> = sqlx::query_as(
         r#"SELECT DISTINCT date FROM work_items
-           WHERE user_id = ? AND date >= ? AND date <= ?
+           WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
            AND (title LIKE ? OR project_path LIKE ?)
            ORDER BY date"#,
     )
@@ -221,7 +221,7 @@ pub async fn get_project_timeline(
     // Query work items for this project within date range
     let items: Vec<WorkItem> = sqlx::query_as(
         r#"SELECT * FROM work_items
-           WHERE user_id = ? AND date >= ? AND date <= ?
+           WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
            ORDER BY date DESC, created_at DESC"#,
     )
     .bind(&claims.sub)
@@ -65,6 +65,13 @@ pub struct SetProjectVisibilityRequest {
     pub hidden: bool,
 }
 
+/// Request to set visibility for several projects at once
+#[derive(Debug, Deserialize)]
+pub struct SetProjectsVisibilityRequest {
+    pub project_names: Vec<String>,
+    pub hidden: bool,
+}
+
 /// A single Claude Code project directory entry
 #[derive(Debug, Serialize)]
 pub struct ClaudeCodeDirEntry {
@@ -214,35 +221,9 @@ pub struct GetCommitDiffRequest {
     pub commit_hash: String,
 }
 
-/// Response for commit diff
-#[derive(Debug, Serialize)]
-pub struct CommitDiffResponse {
-    pub hash: String,
-    pub message: String,
-    pub author: String,
-    pub date: String,
-    pub files: Vec<CommitFileChange>,
-    pub diff_text: Option<String>, // Full diff output, null if repo unavailable
-    pub stats: CommitStats,
-}
-
-/// File change in a commit
-#[derive(Debug, Serialize)]
-pub struct CommitFileChange {
-    pub path: String,
-    pub status: String, // "added" | "modified" | "deleted" | "renamed"
-    pub old_path: Option<String>, // For renamed files
-    pub insertions: i32,
-    pub deletions: i32,
-}
-
-/// Commit statistics
-#[derive(Debug, Serialize)]
-pub struct CommitStats {
-    pub files_changed: i32,
-    pub insertions: i32,
-    pub deletions: i32,
-}
+/// Response for commit diff, with per-file hunks tagged by language
+pub use recap_core::services::CommitDiff as CommitDiffResponse;
+pub use recap_core::services::{CommitFileChange, CommitStats, DiffHunk, FileDiff};
 
 #[cfg(test)]
 mod tests {
@@ -256,6 +237,14 @@ mod tests {
         assert!(req.hidden);
     }
 
+    #[test]
+    fn test_set_projects_visibility_request_deserialize() {
+        let json = r#"{"project_names": ["recap", "infra"], "hidden": false}"#;
+        let req: SetProjectsVisibilityRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.project_names, vec!["recap", "infra"]);
+        assert!(!req.hidden);
+    }
+
     #[test]
     fn test_project_info_serialize() {
         let info = ProjectInfo {
@@ -400,7 +389,19 @@ mod tests {
                 insertions: 50,
                 deletions: 10,
             }],
-            diff_text: Some("+added line\n-deleted line".to_string()),
+            diffs: vec![FileDiff {
+                path: "src/main.rs".to_string(),
+                language: Some("rust".to_string()),
+                hunks: vec![DiffHunk {
+                    header: "@@ -1 +1 @@".to_string(),
+                    old_start: 1,
+                    old_lines: 1,
+                    new_start: 1,
+                    new_lines: 1,
+                    lines: vec!["-deleted line".to_string(), "+added line".to_string()],
+                }],
+            }],
+            truncated: false,
             stats: CommitStats {
                 files_changed: 1,
                 insertions: 50,
@@ -411,6 +412,8 @@ mod tests {
         assert!(json.contains("\"hash\":\"abc123def456\""));
         assert!(json.contains("\"files_changed\":1"));
         assert!(json.contains("\"status\":\"modified\""));
+        assert!(json.contains("\"language\":\"rust\""));
+        assert!(json.contains("\"truncated\":false"));
     }
 
     #[test]
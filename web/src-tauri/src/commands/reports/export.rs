@@ -1,6 +1,6 @@
 //! Reports export commands
 //!
-//! Commands for exporting reports to Excel and generating Tempo reports.
+//! Commands for exporting reports to Excel, PDF or Notion and generating Tempo reports.
 
 use chrono::{Datelike, Duration, NaiveDate, Utc};
 use std::collections::HashMap;
@@ -9,6 +9,9 @@ use tauri::State;
 use recap_core::auth::verify_token;
 use recap_core::models::WorkItem;
 use recap_core::services::excel::{ExcelReportGenerator, ExcelWorkItem, ProjectSummary, ReportMetadata};
+use recap_core::services::http_export::{export_report_to_notion, NotionExportConfig, NotionExportResult};
+use recap_core::services::pdf::PdfReportGenerator;
+use recap_core::Database;
 
 use crate::commands::AppState;
 use super::helpers::{clean_title, extract_project_name, generate_fallback_summary, parse_half, parse_quarter};
@@ -24,6 +27,124 @@ pub async fn export_excel_report(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
+    let (metadata, excel_items, projects) = load_report_data(&db, &claims.sub, &query).await?;
+
+    // Generate Excel
+    let mut generator = match ExcelReportGenerator::new() {
+        Ok(g) => g,
+        Err(e) => return Ok(ExportResult {
+            success: false,
+            file_path: None,
+            error: Some(e.to_string()),
+        }),
+    };
+
+    if let Err(e) = generator.create_personal_report(&metadata, &excel_items, &projects) {
+        return Ok(ExportResult {
+            success: false,
+            file_path: None,
+            error: Some(e.to_string()),
+        });
+    }
+
+    // Get downloads directory
+    let downloads_dir = dirs::download_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join("Downloads")))
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+
+    let filename = format!(
+        "work_report_{}_{}.xlsx",
+        query.start_date.replace('-', ""),
+        query.end_date.replace('-', "")
+    );
+    let file_path = downloads_dir.join(&filename);
+
+    if let Err(e) = generator.save(&file_path) {
+        return Ok(ExportResult {
+            success: false,
+            file_path: None,
+            error: Some(e.to_string()),
+        });
+    }
+
+    Ok(ExportResult {
+        success: true,
+        file_path: Some(file_path.to_string_lossy().to_string()),
+        error: None,
+    })
+}
+
+/// Export per-project hours to a PDF file and return the file path.
+///
+/// Embeds the font from `RECAP_PDF_FONT` or a system font so non-Latin
+/// project names render.
+#[tauri::command]
+pub async fn export_pdf_report(
+    state: State<'_, AppState>,
+    token: String,
+    query: ReportQuery,
+) -> Result<ExportResult, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let (metadata, _, projects) = load_report_data(&db, &claims.sub, &query).await?;
+
+    let downloads_dir = dirs::download_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join("Downloads")))
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+    let filename = format!(
+        "work_report_{}_{}.pdf",
+        query.start_date.replace('-', ""),
+        query.end_date.replace('-', "")
+    );
+    let file_path = downloads_dir.join(&filename);
+
+    let result = PdfReportGenerator::with_system_font()
+        .and_then(|generator| generator.save(&metadata, &projects, &file_path));
+    Ok(match result {
+        Ok(()) => ExportResult {
+            success: true,
+            file_path: Some(file_path.to_string_lossy().to_string()),
+            error: None,
+        },
+        Err(e) => ExportResult {
+            success: false,
+            file_path: None,
+            error: Some(e.to_string()),
+        },
+    })
+}
+
+/// Export per-project totals to the user's Notion database.
+///
+/// Re-exporting the same date range updates the pages created earlier.
+#[tauri::command]
+pub async fn export_notion_report(
+    state: State<'_, AppState>,
+    token: String,
+    query: ReportQuery,
+) -> Result<NotionExportResult, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    let config = NotionExportConfig::load(&db.pool, &claims.sub)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Notion token and database id are not configured".to_string())?;
+
+    let (metadata, _, projects) = load_report_data(&db, &claims.sub, &query).await?;
+
+    export_report_to_notion(&db.pool, &claims.sub, &config, &metadata, &projects)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Work items, per-project totals and metadata for a report query
+async fn load_report_data(
+    db: &Database,
+    user_id: &str,
+    query: &ReportQuery,
+) -> Result<(ReportMetadata, Vec<ExcelWorkItem>, Vec<ProjectSummary>), String> {
     let start_date = NaiveDate::parse_from_str(&query.start_date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid start_date: {}", e))?;
     let end_date = NaiveDate::parse_from_str(&query.end_date, "%Y-%m-%d")
@@ -31,16 +152,16 @@ pub async fn export_excel_report(
 
     // Get user info
     let user_name: String = sqlx::query_scalar("SELECT name FROM users WHERE id = ?")
-        .bind(&claims.sub)
+        .bind(user_id)
         .fetch_one(&db.pool)
         .await
         .map_err(|e| e.to_string())?;
 
     // Get work items
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND parent_id IS NULL ORDER BY date DESC",
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL AND parent_id IS NULL ORDER BY date DESC",
     )
-    .bind(&claims.sub)
+    .bind(user_id)
     .bind(&start_date)
     .bind(&end_date)
     .fetch_all(&db.pool)
@@ -89,49 +210,7 @@ pub async fn export_excel_report(
         generated_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
     };
 
-    // Generate Excel
-    let mut generator = match ExcelReportGenerator::new() {
-        Ok(g) => g,
-        Err(e) => return Ok(ExportResult {
-            success: false,
-            file_path: None,
-            error: Some(e.to_string()),
-        }),
-    };
-
-    if let Err(e) = generator.create_personal_report(&metadata, &excel_items, &projects) {
-        return Ok(ExportResult {
-            success: false,
-            file_path: None,
-            error: Some(e.to_string()),
-        });
-    }
-
-    // Get downloads directory
-    let downloads_dir = dirs::download_dir()
-        .or_else(|| dirs::home_dir().map(|h| h.join("Downloads")))
-        .unwrap_or_else(|| std::path::PathBuf::from("."));
-
-    let filename = format!(
-        "work_report_{}_{}.xlsx",
-        query.start_date.replace('-', ""),
-        query.end_date.replace('-', "")
-    );
-    let file_path = downloads_dir.join(&filename);
-
-    if let Err(e) = generator.save(&file_path) {
-        return Ok(ExportResult {
-            success: false,
-            file_path: None,
-            error: Some(e.to_string()),
-        });
-    }
-
-    Ok(ExportResult {
-        success: true,
-        file_path: Some(file_path.to_string_lossy().to_string()),
-        error: None,
-    })
+    Ok((metadata, excel_items, projects))
 }
 
 /// Generate smart Tempo report with LLM summaries
@@ -235,7 +314,7 @@ pub async fn generate_tempo_report(
 
     // Fetch work items
     let items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? ORDER BY date"
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL ORDER BY date"
     )
     .bind(&claims.sub)
     .bind(start_date.to_string())
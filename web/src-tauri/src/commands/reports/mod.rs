@@ -6,7 +6,7 @@
 //! - `types`: Type definitions for requests/responses
 //! - `helpers`: Helper functions for report generation
 //! - `queries`: Basic report query commands
-//! - `export`: Excel export and Tempo report generation
+//! - `export`: Excel and Notion export, Tempo report generation
 
 // Declare all submodules as public so their #[tauri::command] items are accessible
 pub mod export;
@@ -8,6 +8,7 @@ use tauri::State;
 
 use recap_core::auth::verify_token;
 use recap_core::models::WorkItem;
+use recap_core::services::{classify_uncategorized_items, create_llm_service};
 
 use crate::commands::AppState;
 use super::helpers::extract_project_name;
@@ -33,7 +34,7 @@ pub async fn get_personal_report(
         .map_err(|e| format!("Invalid end_date: {}", e))?;
 
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? ORDER BY date DESC, created_at DESC",
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL ORDER BY date DESC, created_at DESC",
     )
     .bind(&claims.sub)
     .bind(&start_date)
@@ -103,7 +104,7 @@ pub async fn get_summary_report(
         .map_err(|e| format!("Invalid end_date: {}", e))?;
 
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?",
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL",
     )
     .bind(&claims.sub)
     .bind(&start_date)
@@ -164,7 +165,7 @@ pub async fn get_category_report(
         .map_err(|e| format!("Invalid end_date: {}", e))?;
 
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?",
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL",
     )
     .bind(&claims.sub)
     .bind(&start_date)
@@ -224,7 +225,7 @@ pub async fn get_source_report(
         .map_err(|e| format!("Invalid end_date: {}", e))?;
 
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?",
+        "SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL",
     )
     .bind(&claims.sub)
     .bind(&start_date)
@@ -285,8 +286,16 @@ pub async fn analyze_work_items(
     let end_date = NaiveDate::parse_from_str(&query.end_date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid end_date: {}", e))?;
 
+    // Without an LLM, fall back to keyword rules for uncategorized items
+    let llm_configured = create_llm_service(&db.pool, &claims.sub)
+        .await
+        .is_ok_and(|llm| llm.is_configured());
+    if !llm_configured {
+        classify_uncategorized_items(&db.pool, &claims.sub, start_date, end_date, true).await?;
+    }
+
     let work_items: Vec<WorkItem> = sqlx::query_as(
-        r#"SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ?
+        r#"SELECT * FROM work_items WHERE user_id = ? AND date >= ? AND date <= ? AND deleted_at IS NULL
            AND NOT EXISTS (
                SELECT 1 FROM project_preferences pp
                WHERE pp.user_id = work_items.user_id
@@ -302,9 +311,18 @@ pub async fn analyze_work_items(
     .await
     .map_err(|e| e.to_string())?;
 
+    Ok(build_analyze_response(query.start_date, query.end_date, &work_items))
+}
+
+/// Group work items by project and date into an AnalyzeResponse
+fn build_analyze_response(
+    start_date: String,
+    end_date: String,
+    work_items: &[WorkItem],
+) -> AnalyzeResponse {
     // Collect unique dates
     let mut dates_set: std::collections::HashSet<String> = std::collections::HashSet::new();
-    for item in &work_items {
+    for item in work_items {
         dates_set.insert(item.date.to_string());
     }
     let mut dates_covered: Vec<String> = dates_set.into_iter().collect();
@@ -312,7 +330,7 @@ pub async fn analyze_work_items(
 
     // Group by project
     let mut project_map: HashMap<String, Vec<&WorkItem>> = HashMap::new();
-    for item in &work_items {
+    for item in work_items {
         let project_name = extract_project_name(&item.title);
         project_map.entry(project_name).or_default().push(item);
     }
@@ -402,13 +420,13 @@ pub async fn analyze_work_items(
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    Ok(AnalyzeResponse {
-        start_date: query.start_date,
-        end_date: query.end_date,
+    AnalyzeResponse {
+        start_date,
+        end_date,
         total_minutes,
         total_hours,
         dates_covered,
         projects,
         mode: "tauri".to_string(),
-    })
+    }
 }
@@ -7,7 +7,11 @@ use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, Timelike};
 use recap_core::auth::verify_token;
 use recap_core::models::{SnapshotRawData, WorkSummary};
 use recap_core::get_commits_for_date;
-use serde::Serialize;
+use recap_core::services::snapshot::{
+    enrich_buckets_with_git_commits, parse_project_buckets, validate_bucket_minutes, HourlyBucket,
+    HOURLY_BUCKET_MINUTES,
+};
+use serde::{Deserialize, Serialize};
 use tauri::State;
 
 use super::AppState;
@@ -35,6 +39,77 @@ fn next_hour(hour_str: &str) -> String {
     format!("{:02}:00", (h + 1).min(23))
 }
 
+/// Source label for zero-activity buckets added by gap filling.
+const IDLE_SOURCE: &str = "idle";
+
+/// How `get_hourly_breakdown` fills hours without activity.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HourlyGapFill {
+    /// Every hour of the day (00:00–23:00)
+    Full,
+    /// Hours between the first and last active hour
+    ActiveRange,
+}
+
+/// End (HH:MM) of the bucket starting at `hour_start`. Hourly buckets keep
+/// the [`next_hour`] convention; finer buckets end at most at 23:59.
+fn bucket_end(hour_start: &str, bucket_minutes: u32) -> String {
+    if bucket_minutes == HOURLY_BUCKET_MINUTES {
+        return next_hour(hour_start);
+    }
+    let end = minute_of_day(hour_start).unwrap_or(0) + bucket_minutes;
+    let end = end.min(24 * 60 - 1);
+    format!("{:02}:{:02}", end / 60, end % 60)
+}
+
+/// Minutes since midnight of a local HH:MM string.
+fn minute_of_day(hour_start: &str) -> Option<u32> {
+    let hour: u32 = hour_start.get(..2)?.parse().ok()?;
+    let minute: u32 = hour_start.get(3..5)?.parse().ok()?;
+    Some(hour * 60 + minute)
+}
+
+/// Index of a breakdown item's bucket within the day (the hour, for hourly buckets).
+fn bucket_slot(item: &HourlyBreakdownItem, bucket_minutes: u32) -> Option<u32> {
+    minute_of_day(&item.hour_start).map(|m| m / bucket_minutes)
+}
+
+/// Add empty "idle" buckets for slots without activity so charts get a
+/// contiguous series. Items stay sorted by `hour_start` descending.
+fn fill_hour_gaps(
+    mut items: Vec<HourlyBreakdownItem>,
+    mode: HourlyGapFill,
+    bucket_minutes: u32,
+) -> Vec<HourlyBreakdownItem> {
+    let active: std::collections::BTreeSet<u32> =
+        items.iter().filter_map(|i| bucket_slot(i, bucket_minutes)).collect();
+
+    let (first, last) = match mode {
+        HourlyGapFill::Full => (0, 24 * 60 / bucket_minutes - 1),
+        HourlyGapFill::ActiveRange => match (active.first(), active.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return items,
+        },
+    };
+
+    for slot in (first..=last).filter(|s| !active.contains(s)) {
+        let start = slot * bucket_minutes;
+        let hour_start = format!("{:02}:{:02}", start / 60, start % 60);
+        items.push(HourlyBreakdownItem {
+            hour_end: bucket_end(&hour_start, bucket_minutes),
+            hour_start,
+            summary: String::new(),
+            files_modified: Vec::new(),
+            git_commits: Vec::new(),
+            source: IDLE_SOURCE.to_string(),
+        });
+    }
+
+    items.sort_by(|a, b| b.hour_start.cmp(&a.hour_start));
+    items
+}
+
 /// Extract local date (YYYY-MM-DD) from a timestamp string.
 /// Handles both RFC3339 and naive datetime formats.
 fn extract_local_date(ts: &str) -> String {
@@ -358,7 +433,7 @@ pub async fn get_worklog_overview(
     // 4. Fetch manual work items
     let manual_items: Vec<recap_core::WorkItem> = sqlx::query_as(
         r#"SELECT * FROM work_items
-           WHERE user_id = ? AND source = 'manual' AND date >= ? AND date <= ?
+           WHERE user_id = ? AND source = 'manual' AND date >= ? AND date <= ? AND deleted_at IS NULL
            ORDER BY date DESC"#,
     )
     .bind(&claims.sub)
@@ -516,17 +591,87 @@ pub async fn get_worklog_overview(
     Ok(WorklogOverviewResponse { days })
 }
 
+/// Breakdown at a sub-hour resolution, re-parsed from the project's Claude
+/// sessions. Stored snapshots and hourly summaries are left untouched.
+async fn session_breakdown(
+    pool: &sqlx::SqlitePool,
+    user_id: &str,
+    date: &str,
+    project_path: &str,
+    bucket_minutes: u32,
+) -> Result<Vec<HourlyBreakdownItem>, String> {
+    let projects = recap_core::services::discover_user_projects(pool, user_id).await?;
+    let Some(project) = projects.iter().find(|p| p.canonical_path == project_path) else {
+        return Ok(Vec::new());
+    };
+
+    let mut buckets: Vec<HourlyBucket> = parse_project_buckets(project, bucket_minutes)
+        .into_iter()
+        .filter(|b| extract_local_date(&b.hour_bucket) == date)
+        .collect();
+    enrich_buckets_with_git_commits(&mut buckets, project_path);
+
+    let mut items: Vec<HourlyBreakdownItem> = buckets
+        .into_iter()
+        .map(|b| {
+            let hour_start = extract_local_hour(&b.hour_bucket);
+            let summary = if b.user_messages.is_empty() {
+                "工作進行中".to_string()
+            } else {
+                b.user_messages.join("; ")
+            };
+            HourlyBreakdownItem {
+                hour_end: bucket_end(&hour_start, bucket_minutes),
+                hour_start,
+                summary,
+                files_modified: b.files_modified,
+                git_commits: b
+                    .git_commits
+                    .into_iter()
+                    .map(|c| GitCommitRef {
+                        hash: c.hash,
+                        message: c.message,
+                        timestamp: c.timestamp,
+                    })
+                    .collect(),
+                source: "claude_code".to_string(),
+            }
+        })
+        .collect();
+    items.sort_by(|a, b| b.hour_start.cmp(&a.hour_start));
+    Ok(items)
+}
+
 /// Get hourly breakdown for a specific day and project.
+///
+/// By default only hours with activity are returned. With `fill_gaps`, idle
+/// hours (local time) are included as empty buckets with source "idle".
+/// `bucket_minutes` (15, 30 or 60, default 60) sets the bucket length;
+/// finer buckets are re-parsed from the Claude sessions.
 #[tauri::command(rename_all = "snake_case")]
 pub async fn get_hourly_breakdown(
     state: State<'_, AppState>,
     token: String,
     date: String,
     project_path: String,
+    fill_gaps: Option<HourlyGapFill>,
+    bucket_minutes: Option<u32>,
 ) -> Result<Vec<HourlyBreakdownItem>, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let bucket_minutes = bucket_minutes
+        .map(validate_bucket_minutes)
+        .transpose()?
+        .unwrap_or(HOURLY_BUCKET_MINUTES);
     let db = state.db.lock().await;
 
+    if bucket_minutes != HOURLY_BUCKET_MINUTES {
+        let items = session_breakdown(&db.pool, &claims.sub, &date, &project_path, bucket_minutes).await?;
+        return Ok(match fill_gaps {
+            Some(mode) => fill_hour_gaps(items, mode, bucket_minutes),
+            None => items,
+        });
+    }
+
     // Widen query range by 1 day on each side to handle UTC-offset period_start,
     // then filter by local date in Rust.
     let prev_date_summary = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
@@ -738,6 +883,10 @@ pub async fn get_hourly_breakdown(
     items.sort_by(|a, b| {
         b.hour_start.cmp(&a.hour_start)
     });
+
+    if let Some(mode) = fill_gaps {
+        items = fill_hour_gaps(items, mode, HOURLY_BUCKET_MINUTES);
+    }
     Ok(items)
 }
 
@@ -749,7 +898,7 @@ pub struct HourlyBreakdownItem {
     pub summary: String,
     pub files_modified: Vec<String>,
     pub git_commits: Vec<GitCommitRef>,
-    /// Data source: "claude_code"
+    /// Data source: "claude_code", or "idle" for gap-filled hours
     pub source: String,
 }
 
@@ -1033,4 +1182,75 @@ mod tests {
         let expected = format!("{:02}:{:02}", local.hour(), local.minute());
         assert_eq!(result, expected);
     }
+
+    // ── fill_hour_gaps ──
+
+    fn active_item(hour_start: &str) -> HourlyBreakdownItem {
+        HourlyBreakdownItem {
+            hour_start: hour_start.to_string(),
+            hour_end: next_hour(hour_start),
+            summary: "work".to_string(),
+            files_modified: vec!["src/main.rs".to_string()],
+            git_commits: Vec::new(),
+            source: "claude_code".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fill_hour_gaps_full_day() {
+        let items = vec![active_item("14:00"), active_item("09:00")];
+        let filled = fill_hour_gaps(items, HourlyGapFill::Full, HOURLY_BUCKET_MINUTES);
+
+        assert_eq!(filled.len(), 24);
+        let hours: Vec<String> = filled.iter().map(|i| i.hour_start.clone()).collect();
+        let expected: Vec<String> = (0..24).rev().map(|h| format!("{:02}:00", h)).collect();
+        assert_eq!(hours, expected);
+
+        for item in &filled {
+            let active = item.hour_start == "09:00" || item.hour_start == "14:00";
+            assert_eq!(item.source, if active { "claude_code" } else { IDLE_SOURCE });
+            if !active {
+                assert!(item.summary.is_empty());
+                assert!(item.files_modified.is_empty());
+                assert!(item.git_commits.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_hour_gaps_active_range() {
+        let items = vec![active_item("14:00"), active_item("09:00")];
+        let filled = fill_hour_gaps(items, HourlyGapFill::ActiveRange, HOURLY_BUCKET_MINUTES);
+
+        let hours: Vec<&str> = filled.iter().map(|i| i.hour_start.as_str()).collect();
+        assert_eq!(hours, vec!["14:00", "13:00", "12:00", "11:00", "10:00", "09:00"]);
+        assert_eq!(filled.iter().filter(|i| i.source == IDLE_SOURCE).count(), 4);
+        assert_eq!(filled[1].hour_end, "14:00");
+    }
+
+    #[test]
+    fn test_fill_hour_gaps_active_range_empty_day() {
+        assert!(fill_hour_gaps(Vec::new(), HourlyGapFill::ActiveRange, HOURLY_BUCKET_MINUTES).is_empty());
+        assert_eq!(fill_hour_gaps(Vec::new(), HourlyGapFill::Full, HOURLY_BUCKET_MINUTES).len(), 24);
+    }
+
+    #[test]
+    fn test_fill_hour_gaps_half_hour_buckets() {
+        let items = vec![active_item("10:30"), active_item("09:00")];
+        let filled = fill_hour_gaps(items, HourlyGapFill::ActiveRange, 30);
+
+        let starts: Vec<&str> = filled.iter().map(|i| i.hour_start.as_str()).collect();
+        assert_eq!(starts, vec!["10:30", "10:00", "09:30", "09:00"]);
+        assert_eq!(filled[1].hour_end, "10:30");
+        assert_eq!(fill_hour_gaps(Vec::new(), HourlyGapFill::Full, 15).len(), 96);
+    }
+
+    #[test]
+    fn test_bucket_end() {
+        assert_eq!(bucket_end("09:00", HOURLY_BUCKET_MINUTES), "10:00");
+        assert_eq!(bucket_end("23:00", HOURLY_BUCKET_MINUTES), "23:00");
+        assert_eq!(bucket_end("09:15", 15), "09:30");
+        assert_eq!(bucket_end("09:30", 30), "10:00");
+        assert_eq!(bucket_end("23:45", 15), "23:59");
+    }
 }
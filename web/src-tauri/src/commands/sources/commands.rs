@@ -7,6 +7,7 @@ use uuid::Uuid;
 
 use recap_core::auth::verify_token;
 use recap_core::models::{GitRepo, GitRepoInfo, SourcesResponse};
+use recap_core::services::purge_git_repo_items;
 
 use crate::commands::AppState;
 use super::helpers::{extract_repo_name, get_claude_projects_path, get_last_commit_info, is_valid_git_repo};
@@ -65,7 +66,8 @@ pub async fn get_sources(
         .collect();
 
     // Check Claude connection
-    let claude_path = get_claude_projects_path();
+    let claude_home = recap_core::services::resolve_claude_home(&db.pool, &claims.sub).await?;
+    let claude_path = get_claude_projects_path(claude_home);
     let claude_connected = claude_path.is_some();
 
     Ok(SourcesResponse {
@@ -153,35 +155,55 @@ pub async fn add_git_repo(
 }
 
 /// Remove a local Git repository
+///
+/// With `purge_items`, work items synced from the repo are deleted as well.
 #[tauri::command]
 pub async fn remove_git_repo(
     state: State<'_, AppState>,
     token: String,
     repo_id: String,
+    purge_items: Option<bool>,
 ) -> Result<MessageResponse, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    // Delete (or disable) the repo
-    let result = sqlx::query(
-        "DELETE FROM git_repos WHERE id = ? AND user_id = ?"
+    let repo_path: Option<String> = sqlx::query_scalar(
+        "SELECT path FROM git_repos WHERE id = ? AND user_id = ?"
     )
     .bind(&repo_id)
     .bind(&claims.sub)
-    .execute(&db.pool)
+    .fetch_optional(&db.pool)
     .await
     .map_err(|e| e.to_string())?;
 
-    if result.rows_affected() == 0 {
+    let Some(repo_path) = repo_path else {
         return Ok(MessageResponse {
             success: false,
             message: "找不到指定的 Git 倉庫".to_string(),
         });
-    }
+    };
+
+    let mut tx = db.pool.begin().await.map_err(|e| e.to_string())?;
+
+    sqlx::query("DELETE FROM git_repos WHERE id = ? AND user_id = ?")
+        .bind(&repo_id)
+        .bind(&claims.sub)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let message = if purge_items.unwrap_or(false) {
+        let purged = purge_git_repo_items(&mut tx, &claims.sub, &repo_path).await?;
+        format!("已移除 Git 倉庫及 {} 筆工作項目", purged)
+    } else {
+        "已移除 Git 倉庫".to_string()
+    };
+
+    tx.commit().await.map_err(|e| e.to_string())?;
 
     Ok(MessageResponse {
         success: true,
-        message: "已移除 Git 倉庫".to_string(),
+        message,
     })
 }
 
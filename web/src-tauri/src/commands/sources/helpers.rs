@@ -2,7 +2,7 @@
 //!
 //! Utilities for Git repository validation and path operations.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use recap_core::utils::create_command;
 
@@ -47,10 +47,9 @@ pub fn extract_repo_name(path: &str) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
-/// Check if Claude projects directory exists and return the path
-pub fn get_claude_projects_path() -> Option<String> {
-    let home = dirs::home_dir()?;
-    let claude_path = home.join(".claude").join("projects");
+/// Check if the Claude projects directory under `claude_home` exists and return the path
+pub fn get_claude_projects_path(claude_home: Option<PathBuf>) -> Option<String> {
+    let claude_path = claude_home?.join("projects");
     if claude_path.exists() {
         Some(claude_path.to_string_lossy().to_string())
     } else {
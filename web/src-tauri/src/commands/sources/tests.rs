@@ -86,7 +86,7 @@ fn test_get_last_commit_info_invalid_path() {
 fn test_get_claude_projects_path() {
     // This test verifies the function works without crashing
     // Result depends on whether ~/.claude/projects exists
-    let result = get_claude_projects_path();
+    let result = get_claude_projects_path(recap_core::services::default_claude_home());
     if let Some(path) = result {
         assert!(path.contains(".claude"), "Path should contain .claude");
         assert!(path.contains("projects"), "Path should contain projects");
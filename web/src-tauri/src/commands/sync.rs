@@ -10,9 +10,9 @@ use tauri::State;
 
 use recap_core::auth::verify_token;
 use recap_core::models::SyncResult;
-use recap_core::services::SyncService;
+use recap_core::services::{ProjectSyncResult, SyncService};
 
-use super::AppState;
+use super::{AppState, CommandError};
 
 // ============================================================================
 // Request/Response types
@@ -41,6 +41,7 @@ pub struct AutoSyncResponse {
     pub total_items: i32,
     pub projects_scanned: i32,
     pub items_created: i32,
+    pub per_project: Vec<ProjectSyncResult>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -71,6 +72,7 @@ pub struct ClaudeSyncResult {
     pub sessions_processed: i32,
     pub work_items_created: i32,
     pub work_items_updated: i32,
+    pub per_project: Vec<ProjectSyncResult>,
 }
 
 // ============================================================================
@@ -107,11 +109,11 @@ pub trait SyncRepository: Send + Sync {
         project_paths: &[String],
     ) -> Result<ClaudeSyncResult, String>;
 
-    /// List available Claude projects
-    fn list_claude_projects(&self) -> Vec<PathBuf>;
+    /// List available Claude projects under each of the user's Claude roots
+    async fn list_claude_projects(&self, user_id: &str) -> Vec<PathBuf>;
 
     /// Discover Claude projects with git root resolution and multi-source discovery
-    fn discover_projects(&self) -> Vec<recap_core::DiscoveredProject>;
+    async fn discover_projects(&self, user_id: &str) -> Vec<recap_core::DiscoveredProject>;
 
     /// Sync Claude projects using discovered projects (with git root grouping)
     async fn sync_discovered_projects(
@@ -218,15 +220,27 @@ impl SyncRepository for SqliteSyncRepository {
             sessions_processed: result.sessions_processed as i32,
             work_items_created: result.work_items_created as i32,
             work_items_updated: result.work_items_updated as i32,
+            per_project: result.per_project,
         })
     }
 
-    fn list_claude_projects(&self) -> Vec<PathBuf> {
-        SyncService::list_claude_projects()
+    async fn list_claude_projects(&self, user_id: &str) -> Vec<PathBuf> {
+        match recap_core::services::resolve_claude_projects_dirs(&self.pool, user_id).await {
+            Ok(dirs) => SyncService::list_claude_projects_in(&dirs),
+            Err(e) => {
+                log::warn!("Failed to resolve Claude projects directories: {}", e);
+                Vec::new()
+            }
+        }
     }
 
-    fn discover_projects(&self) -> Vec<recap_core::DiscoveredProject> {
-        SyncService::discover_project_paths()
+    async fn discover_projects(&self, user_id: &str) -> Vec<recap_core::DiscoveredProject> {
+        recap_core::services::discover_user_projects(&self.pool, user_id)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to discover Claude projects: {}", e);
+                Vec::new()
+            })
     }
 
     async fn sync_discovered_projects(
@@ -241,6 +255,7 @@ impl SyncRepository for SqliteSyncRepository {
             sessions_processed: result.sessions_processed as i32,
             work_items_created: result.work_items_created as i32,
             work_items_updated: result.work_items_updated as i32,
+            per_project: result.per_project,
         })
     }
 }
@@ -296,6 +311,7 @@ pub(crate) fn build_empty_response() -> AutoSyncResponse {
         total_items: 0,
         projects_scanned: 0,
         items_created: 0,
+        per_project: vec![],
     }
 }
 
@@ -309,6 +325,7 @@ pub(crate) fn build_success_response(sync_result: &ClaudeSyncResult) -> AutoSync
         total_items: item_count,
         projects_scanned: sync_result.projects_scanned,
         items_created: sync_result.work_items_created,
+        per_project: sync_result.per_project.clone(),
     }
 }
 
@@ -337,8 +354,8 @@ pub(crate) fn extract_cwd_from_session(content: &str) -> Option<String> {
 pub async fn get_sync_status_impl<R: SyncRepository>(
     repo: &R,
     token: &str,
-) -> Result<Vec<SyncStatus>, String> {
-    let claims = verify_token(token).map_err(|e| e.to_string())?;
+) -> Result<Vec<SyncStatus>, CommandError> {
+    let claims = verify_token(token)?;
     let statuses = repo.get_sync_statuses(&claims.sub).await?;
     Ok(statuses.into_iter().map(convert_sync_status).collect())
 }
@@ -348,8 +365,8 @@ pub async fn auto_sync_impl<R: SyncRepository>(
     repo: &R,
     token: &str,
     request: AutoSyncRequest,
-) -> Result<AutoSyncResponse, String> {
-    let claims = verify_token(token).map_err(|e| e.to_string())?;
+) -> Result<AutoSyncResponse, CommandError> {
+    let claims = verify_token(token)?;
 
     // Get or create sync status for tracking
     let status = repo
@@ -370,12 +387,12 @@ pub async fn auto_sync_impl<R: SyncRepository>(
             Ok(result) => result,
             Err(e) => {
                 let _ = repo.mark_error(&status.id, &e).await;
-                return Err(e);
+                return Err(CommandError::sync_failed(e));
             }
         }
     } else {
         // Use discovery-based sync with git root resolution
-        let projects = repo.discover_projects();
+        let projects = repo.discover_projects(&claims.sub).await;
 
         if projects.is_empty() {
             let _ = repo.mark_success(&status.id, 0).await;
@@ -389,7 +406,7 @@ pub async fn auto_sync_impl<R: SyncRepository>(
             Ok(result) => result,
             Err(e) => {
                 let _ = repo.mark_error(&status.id, &e).await;
-                return Err(e);
+                return Err(CommandError::sync_failed(e));
             }
         }
     };
@@ -405,8 +422,8 @@ pub async fn auto_sync_impl<R: SyncRepository>(
 /// Extract CWDs from project directories (helper).
 /// Uses sessions-index.json first, then extract_cwd() as fallback.
 #[allow(dead_code)]
-fn extract_project_cwds<R: SyncRepository>(repo: &R) -> Vec<String> {
-    repo.list_claude_projects()
+fn extract_project_cwds(project_dirs: Vec<PathBuf>) -> Vec<String> {
+    project_dirs
         .into_iter()
         .filter_map(|p| {
             // Strategy 1: Read sessions-index.json
@@ -464,11 +481,12 @@ fn extract_project_cwds<R: SyncRepository>(repo: &R) -> Vec<String> {
 pub async fn list_available_projects_impl<R: SyncRepository>(
     repo: &R,
     token: &str,
-) -> Result<Vec<AvailableProject>, String> {
-    let _claims = verify_token(token).map_err(|e| e.to_string())?;
+) -> Result<Vec<AvailableProject>, CommandError> {
+    let claims = verify_token(token)?;
 
     let projects = repo
-        .list_claude_projects()
+        .list_claude_projects(&claims.sub)
+        .await
         .into_iter()
         .map(|path| AvailableProject {
             name: extract_project_name(&path),
@@ -489,7 +507,7 @@ pub async fn list_available_projects_impl<R: SyncRepository>(
 pub async fn get_sync_status(
     state: State<'_, AppState>,
     token: String,
-) -> Result<Vec<SyncStatus>, String> {
+) -> Result<Vec<SyncStatus>, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteSyncRepository::new(db.pool.clone());
     get_sync_status_impl(&repo, &token).await
@@ -501,7 +519,7 @@ pub async fn auto_sync(
     state: State<'_, AppState>,
     token: String,
     request: AutoSyncRequest,
-) -> Result<AutoSyncResponse, String> {
+) -> Result<AutoSyncResponse, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteSyncRepository::new(db.pool.clone());
     auto_sync_impl(&repo, &token, request).await
@@ -512,7 +530,7 @@ pub async fn auto_sync(
 pub async fn list_available_projects(
     state: State<'_, AppState>,
     token: String,
-) -> Result<Vec<AvailableProject>, String> {
+) -> Result<Vec<AvailableProject>, CommandError> {
     let db = state.db.lock().await;
     let repo = SqliteSyncRepository::new(db.pool.clone());
     list_available_projects_impl(&repo, &token).await
@@ -625,11 +643,11 @@ mod tests {
             Ok(self.sync_result.clone().unwrap_or_default())
         }
 
-        fn list_claude_projects(&self) -> Vec<PathBuf> {
+        async fn list_claude_projects(&self, _user_id: &str) -> Vec<PathBuf> {
             self.projects.clone()
         }
 
-        fn discover_projects(&self) -> Vec<recap_core::DiscoveredProject> {
+        async fn discover_projects(&self, _user_id: &str) -> Vec<recap_core::DiscoveredProject> {
             // For mock: convert projects to DiscoveredProject (no git root resolution)
             self.projects
                 .iter()
@@ -744,6 +762,7 @@ mod tests {
             sessions_processed: 10,
             work_items_created: 5,
             work_items_updated: 3,
+            ..Default::default()
         };
 
         let result = build_sync_result(&sync_result);
@@ -771,6 +790,7 @@ mod tests {
             sessions_processed: 5,
             work_items_created: 2,
             work_items_updated: 1,
+            ..Default::default()
         };
 
         let response = build_success_response(&sync_result);
@@ -881,6 +901,7 @@ mod tests {
         let result = get_sync_status_impl(&repo, "invalid-token").await;
 
         assert!(result.is_err());
+        assert_eq!(result.unwrap_err().code, CommandError::AUTH_INVALID_TOKEN);
     }
 
     // ========================================================================
@@ -896,6 +917,7 @@ mod tests {
             sessions_processed: 5,
             work_items_created: 3,
             work_items_updated: 2,
+            ..Default::default()
         };
         let repo = MockSyncRepository::new().with_sync_result(sync_result);
 
@@ -949,8 +971,10 @@ mod tests {
 
         let result = auto_sync_impl(&repo, &token, request).await;
 
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Sync error"));
+        let err = result.unwrap_err();
+        assert_eq!(err.code, CommandError::SYNC_FAILED);
+        assert!(err.retryable);
+        assert!(err.message.contains("Sync error"));
     }
 
     // ========================================================================
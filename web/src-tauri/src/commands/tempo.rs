@@ -3,6 +3,7 @@
 //! Tauri commands for Jira/Tempo integration operations.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::State;
 
 use recap_core::auth::verify_token;
@@ -107,22 +108,29 @@ pub struct JiraIssueDetail {
     pub issue_type: Option<String>,
 }
 
+/// Issue details that resolved, plus the error for each key that didn't
+#[derive(Debug, Serialize, Default)]
+pub struct BatchJiraIssuesResponse {
+    pub issues: Vec<JiraIssueDetail>,
+    pub errors: HashMap<String, String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SummarizeDescriptionResponse {
     pub summary: String,
 }
 
 /// Resolved Jira/Tempo config with auth type determined from stored credentials
-struct JiraConfig {
-    jira_url: String,
-    jira_email: Option<String>,
-    jira_pat: String,
-    tempo_token: Option<String>,
-    auth_type: JiraAuthType,
+pub(crate) struct JiraConfig {
+    pub(crate) jira_url: String,
+    pub(crate) jira_email: Option<String>,
+    pub(crate) jira_pat: String,
+    pub(crate) tempo_token: Option<String>,
+    pub(crate) auth_type: JiraAuthType,
 }
 
 // Helper function to get user's Jira/Tempo config
-async fn get_user_config(
+pub(crate) async fn get_user_config(
     pool: &sqlx::SqlitePool,
     user_id: &str,
 ) -> Result<JiraConfig, String> {
@@ -537,9 +545,9 @@ pub async fn batch_get_jira_issues(
     state: State<'_, AppState>,
     token: String,
     issue_keys: Vec<String>,
-) -> Result<Vec<JiraIssueDetail>, String> {
+) -> Result<BatchJiraIssuesResponse, String> {
     if issue_keys.is_empty() {
-        return Ok(Vec::new());
+        return Ok(BatchJiraIssuesResponse::default());
     }
 
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
@@ -555,21 +563,24 @@ pub async fn batch_get_jira_issues(
     )
     .map_err(|e| e.to_string())?;
 
-    let issues = client
-        .batch_get_issues(&issue_keys)
-        .await
-        .map_err(|e| e.to_string())?;
+    let mut response = BatchJiraIssuesResponse::default();
+    for (key, lookup) in client.batch_get_issues(&issue_keys).await {
+        match lookup {
+            Ok(issue) => response.issues.push(JiraIssueDetail {
+                key: issue.key,
+                summary: issue.fields.summary.unwrap_or_default(),
+                description: issue.fields.description,
+                assignee: issue.fields.assignee.and_then(|a| a.display_name),
+                issue_type: issue.fields.issue_type.map(|t| t.name),
+            }),
+            Err(e) => {
+                response.errors.insert(key, e);
+            }
+        }
+    }
+    response.issues.sort_by(|a, b| a.key.cmp(&b.key));
 
-    Ok(issues
-        .into_iter()
-        .map(|issue| JiraIssueDetail {
-            key: issue.key,
-            summary: issue.fields.summary.unwrap_or_default(),
-            description: issue.fields.description,
-            assignee: issue.fields.assignee.and_then(|a| a.display_name),
-            issue_type: issue.fields.issue_type.map(|t| t.name),
-        })
-        .collect())
+    Ok(response)
 }
 
 /// Summarize a single worklog description using LLM (or fallback).
@@ -3,10 +3,13 @@
 //! Commands for generating commit-centric worklogs.
 
 use std::collections::HashMap;
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use tauri::State;
 
-use recap_core::services::{build_rule_based_outcome, get_commits_for_date, is_meaningful_message, StandaloneSession};
+use recap_core::services::{
+    build_rule_based_outcome, filter_trivial_commits, get_user_commits_for_date, is_meaningful_message,
+    resolve_claude_dir_project, resolve_git_root, same_project_path, StandaloneSession,
+};
 
 use crate::commands::AppState;
 use super::types::{CommitCentricQuery, CommitCentricWorklog};
@@ -15,14 +18,37 @@ use super::types::{CommitCentricQuery, CommitCentricWorklog};
 /// Returns commits as primary records with session data as supplementary
 #[tauri::command]
 pub async fn get_commit_centric_worklog(
-    _state: State<'_, AppState>,
+    state: State<'_, AppState>,
     token: String,
     query: CommitCentricQuery,
 ) -> Result<CommitCentricWorklog, String> {
-    let _claims = recap_core::auth::verify_token(&token).map_err(|e| e.to_string())?;
+    let claims = recap_core::auth::verify_token(&token).map_err(|e| e.to_string())?;
+
+    // User settings: timezone for date keywords, trivial-commit threshold and
+    // hour folding, co-author attribution and the Claude session path
+    let (timezone, min_commit_lines, fold_trivial_hours, include_coauthored, claude_session_path): (
+        Option<String>,
+        Option<i64>,
+        Option<bool>,
+        Option<bool>,
+        Option<String>,
+    ) = {
+        let db = state.db.lock().await;
+        sqlx::query_as(
+            r#"SELECT timezone, min_commit_lines, fold_trivial_commit_hours, include_coauthored, claude_session_path
+               FROM users WHERE id = ?"#,
+        )
+        .bind(&claims.sub)
+        .fetch_optional(&db.pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .unwrap_or((None, None, None, None, None))
+    };
 
-    let date = NaiveDate::parse_from_str(&query.date, "%Y-%m-%d")
-        .map_err(|e| format!("Invalid date format: {}", e))?;
+    // Accepts YYYY-MM-DD or today/yesterday in the user's timezone
+    let today = recap_core::today_in_timezone(timezone.as_deref(), Utc::now());
+    let date = recap_core::parse_date_arg(&query.date, today)?;
+    let date_str = date.to_string();
 
     // Determine project path
     let project_path = query.project_path.unwrap_or_else(|| {
@@ -31,34 +57,61 @@ pub async fn get_commit_centric_worklog(
             .unwrap_or_default()
     });
 
+    // Default issue for Tempo sync from the project's mapping, and the session hours cap
+    let (jira_issue_key, max_session_hours) = {
+        let db = state.db.lock().await;
+        (
+            recap_core::services::get_project_issue_key(&db.pool, &claims.sub, &project_path).await?,
+            recap_core::services::load_max_session_hours(&db.pool, &claims.sub).await,
+        )
+    };
+
     let project_name = std::path::Path::new(&project_path)
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("unknown")
         .to_string();
 
-    // Get commits for the date (filtered by git user)
+    // Get commits for the date (filtered by git user, including co-authored ones)
     let author = recap_core::get_git_user_email(&project_path);
-    let commits = get_commits_for_date(&project_path, &date, author.as_deref());
+    let commits = get_user_commits_for_date(
+        &project_path,
+        &date,
+        author.as_deref(),
+        include_coauthored.unwrap_or(true),
+    );
+
+    // Skip trivial commits (version bumps, whitespace) below the configured size
+    let commits = filter_trivial_commits(
+        commits,
+        min_commit_lines.unwrap_or(0) as i32,
+        fold_trivial_hours.unwrap_or(false),
+    );
     let total_commits = commits.len() as i32;
 
     // Calculate total hours from commits
     let commit_hours: f64 = commits.iter().map(|c| c.hours).sum();
 
     // Find Claude sessions for this project and date that don't have commits
-    let standalone_sessions = find_standalone_sessions(&project_path, &query.date)?;
+    let standalone_sessions = find_standalone_sessions(
+        &project_path,
+        &date_str,
+        claude_session_path.as_deref(),
+        max_session_hours,
+    )?;
 
     // Calculate total hours (commits + standalone sessions)
     let session_hours: f64 = standalone_sessions.iter().map(|s| s.hours).sum();
     let total_hours = commit_hours + session_hours;
 
     Ok(CommitCentricWorklog {
-        date: query.date,
+        date: date_str,
         project: project_name,
         commits,
         standalone_sessions,
         total_commits,
         total_hours,
+        jira_issue_key,
     })
 }
 
@@ -66,81 +119,77 @@ pub async fn get_commit_centric_worklog(
 fn find_standalone_sessions(
     project_path: &str,
     date: &str,
+    claude_session_path: Option<&str>,
+    max_session_hours: f64,
 ) -> Result<Vec<StandaloneSession>, String> {
     let target_date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date: {}", e))?;
 
-    let claude_home = dirs::home_dir()
-        .map(|h| h.join(".claude").join("projects"));
-
-    let projects_dir = match claude_home {
-        Some(dir) if dir.exists() => dir,
-        _ => return Ok(Vec::new()),
-    };
+    // Every configured Claude root is scanned (`claude_session_path` may list several)
+    let projects_dirs: Vec<std::path::PathBuf> = recap_core::services::claude_homes_or_default(claude_session_path)
+        .into_iter()
+        .map(|h| h.join("projects"))
+        .collect();
 
     let mut standalone = Vec::new();
 
-    // Find the Claude project directory for this project
-    let project_dir_name = project_path.replace(['/', '\\'], "-");
-
-    if let Ok(entries) = std::fs::read_dir(&projects_dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if !path.is_dir() {
-                continue;
-            }
+    // Claude project directories are attributed the way sync does: session cwd -> git root
+    let target_project = resolve_git_root(project_path);
 
-            let dir_name = path.file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
+    let entries = projects_dirs.iter().filter_map(|dir| std::fs::read_dir(dir).ok()).flatten();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
 
-            // Check if this directory matches our project
-            if !dir_name.contains(&project_dir_name) && !project_dir_name.contains(&dir_name) {
-                continue;
-            }
+        // Check if this directory belongs to our project
+        let belongs = resolve_claude_dir_project(&path)
+            .is_some_and(|dir_project| same_project_path(&dir_project, &target_project));
+        if !belongs {
+            continue;
+        }
 
-            // Read session files
-            if let Ok(files) = std::fs::read_dir(&path) {
-                for file_entry in files.flatten() {
-                    let file_path = file_entry.path();
-                    if !file_path.extension().map(|e| e == "jsonl").unwrap_or(false) {
-                        continue;
-                    }
+        // Read session files
+        if let Ok(files) = std::fs::read_dir(&path) {
+            for file_entry in files.flatten() {
+                let file_path = file_entry.path();
+                if !file_path.extension().map(|e| e == "jsonl").unwrap_or(false) {
+                    continue;
+                }
 
-                    // Check file modification date
-                    if let Ok(metadata) = file_entry.metadata() {
-                        if let Ok(modified) = metadata.modified() {
-                            let modified_date: DateTime<Local> = modified.into();
-                            let file_date = modified_date.date_naive();
-                            if file_date != target_date {
-                                continue;
-                            }
+                // Check file modification date
+                if let Ok(metadata) = file_entry.metadata() {
+                    if let Ok(modified) = metadata.modified() {
+                        let modified_date: DateTime<Local> = modified.into();
+                        let file_date = modified_date.date_naive();
+                        if file_date != target_date {
+                            continue;
                         }
                     }
+                }
 
-                    // Parse session to check if it has commits
-                    if let Some(session_data) = parse_session_for_worklog(&file_path, &target_date) {
-                        // Only include if no commits were made during this session
-                        if session_data.commit_count == 0 {
-                            let outcome = build_rule_based_outcome(
-                                &session_data.files_modified,
-                                &session_data.tools_used,
-                                session_data.first_message.as_deref(),
-                            );
-
-                            standalone.push(StandaloneSession {
-                                session_id: session_data.session_id,
-                                project: std::path::Path::new(&project_path).file_name().and_then(|n| n.to_str()).unwrap_or("unknown").to_string(),
-                                start_time: session_data.start_time,
-                                end_time: session_data.end_time,
-                                hours: session_data.hours,
-                                outcome,
-                                outcome_source: "rule".to_string(),
-                                tools_used: session_data.tools_used,
-                                files_modified: session_data.files_modified,
-                            });
-                        }
+                // Parse session to check if it has commits
+                if let Some(session_data) = parse_session_for_worklog(&file_path, &target_date, max_session_hours) {
+                    // Only include if no commits were made during this session
+                    if session_data.commit_count == 0 {
+                        let outcome = build_rule_based_outcome(
+                            &session_data.files_modified,
+                            &session_data.tools_used,
+                            session_data.first_message.as_deref(),
+                        );
+
+                        standalone.push(StandaloneSession {
+                            session_id: session_data.session_id,
+                            project: std::path::Path::new(&project_path).file_name().and_then(|n| n.to_str()).unwrap_or("unknown").to_string(),
+                            start_time: session_data.start_time,
+                            end_time: session_data.end_time,
+                            hours: session_data.hours,
+                            outcome,
+                            outcome_source: "rule".to_string(),
+                            tools_used: session_data.tools_used,
+                            files_modified: session_data.files_modified,
+                        });
                     }
                 }
             }
@@ -166,6 +215,7 @@ struct SessionWorklogData {
 fn parse_session_for_worklog(
     path: &std::path::PathBuf,
     target_date: &NaiveDate,
+    max_session_hours: f64,
 ) -> Option<SessionWorklogData> {
     use std::io::{BufRead, BufReader};
 
@@ -260,7 +310,7 @@ fn parse_session_for_worklog(
         }
 
         let duration = end.signed_duration_since(start);
-        (duration.num_minutes() as f64 / 60.0).max(0.1).min(8.0)
+        (duration.num_minutes() as f64 / 60.0).max(0.1).min(max_session_hours)
     } else {
         return None;
     };
@@ -276,3 +326,85 @@ fn parse_session_for_worklog(
         commit_count,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_parse_session_for_worklog_respects_hours_cap() {
+        let mut file = tempfile::Builder::new().suffix(".jsonl").tempfile().unwrap();
+        writeln!(file, r#"{{"timestamp":"2026-01-15T08:00:00+08:00","message":{{"role":"user","content":"Refactor the sync scheduler"}}}}"#).unwrap();
+        writeln!(file, r#"{{"timestamp":"2026-01-15T18:00:00+08:00","message":{{"role":"user","content":"Wrap up"}}}}"#).unwrap();
+        let path = file.path().to_path_buf();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+        let capped = parse_session_for_worklog(&path, &date, 8.0).unwrap();
+        assert_eq!(capped.hours, 8.0);
+        let uncapped = parse_session_for_worklog(&path, &date, 12.0).unwrap();
+        assert_eq!(uncapped.hours, 10.0);
+    }
+
+    #[test]
+    fn test_find_standalone_sessions_keeps_similar_projects_apart() {
+        let root = tempfile::TempDir::new().unwrap();
+        let recap = root.path().join("work").join("recap");
+        let recap_web = root.path().join("work").join("recap-web");
+        let claude_home = root.path().join("claude");
+        let today = Local::now().date_naive();
+
+        // `-work-recap` is a substring of `-work-recap-web`; each holds one session
+        for (repo, dir_name, session) in [(&recap, "-work-recap", "api"), (&recap_web, "-work-recap-web", "web")] {
+            std::fs::create_dir_all(repo.join(".git")).unwrap();
+            let dir = claude_home.join("projects").join(dir_name);
+            std::fs::create_dir_all(&dir).unwrap();
+            let mut file = std::fs::File::create(dir.join(format!("{}.jsonl", session))).unwrap();
+            writeln!(file, r#"{{"cwd":"{}","timestamp":"{}T09:00:00+00:00"}}"#, repo.display(), today).unwrap();
+            writeln!(file, r#"{{"timestamp":"{}T10:00:00+00:00"}}"#, today).unwrap();
+        }
+
+        let sessions_for = |repo: &std::path::Path| -> Vec<String> {
+            find_standalone_sessions(
+                &repo.to_string_lossy(),
+                &today.to_string(),
+                Some(&claude_home.to_string_lossy()),
+                8.0,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|s| s.session_id)
+            .collect()
+        };
+        assert_eq!(sessions_for(&recap), vec!["api"]);
+        assert_eq!(sessions_for(&recap_web), vec!["web"]);
+    }
+
+    #[test]
+    fn test_find_standalone_sessions_scans_every_root() {
+        let root = tempfile::TempDir::new().unwrap();
+        let laptop = tempfile::TempDir::new().unwrap();
+        let work_machine = tempfile::TempDir::new().unwrap();
+        let repo = root.path().join("work").join("recap");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        let today = Local::now().date_naive();
+
+        for (claude_home, session) in [(&laptop, "laptop"), (&work_machine, "mounted")] {
+            let dir = claude_home.path().join("projects").join("-work-recap");
+            std::fs::create_dir_all(&dir).unwrap();
+            let mut file = std::fs::File::create(dir.join(format!("{}.jsonl", session))).unwrap();
+            writeln!(file, r#"{{"cwd":"{}","timestamp":"{}T09:00:00+00:00"}}"#, repo.display(), today).unwrap();
+            writeln!(file, r#"{{"timestamp":"{}T10:00:00+00:00"}}"#, today).unwrap();
+        }
+
+        let roots = format!("{},{}", laptop.path().display(), work_machine.path().display());
+        let mut sessions: Vec<String> =
+            find_standalone_sessions(&repo.to_string_lossy(), &today.to_string(), Some(&roots), 8.0)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.session_id)
+                .collect();
+        sessions.sort();
+        assert_eq!(sessions, vec!["laptop", "mounted"]);
+    }
+}
@@ -9,6 +9,7 @@ use recap_core::auth::verify_token;
 use recap_core::models::WorkItem;
 
 use crate::commands::AppState;
+use super::helpers::{localize_item_dates, shift_date};
 use super::query_builder::SafeQueryBuilder;
 use super::types::{
     DateGroup, GroupedQuery, GroupedWorkItemsResponse, JiraIssueGroup, ProjectGroup, WorkLogItem,
@@ -40,17 +41,20 @@ pub async fn get_grouped_work_items(
 ) -> Result<GroupedWorkItemsResponse, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
+    let timezone = recap_core::services::load_user_timezone(&db.pool, &claims.sub).await;
 
     // Build parameterized query safely
     let mut builder = SafeQueryBuilder::new();
     builder.add_string_condition("user_id", "=", &claims.sub);
     builder.add_null_condition("parent_id", true);
+    builder.add_null_condition("deleted_at", true);
 
+    // One extra day on each side; items are narrowed to local dates below
     if let Some(start) = &query.start_date {
-        builder.add_string_condition("date", ">=", start);
+        builder.add_string_condition("date", ">=", &shift_date(start, -1));
     }
     if let Some(end) = &query.end_date {
-        builder.add_string_condition("date", "<=", end);
+        builder.add_string_condition("date", "<=", &shift_date(end, 1));
     }
 
     let items: Vec<WorkItem> = builder
@@ -62,6 +66,12 @@ pub async fn get_grouped_work_items(
             None,
         )
         .await?;
+    let items = localize_item_dates(
+        items,
+        timezone.as_deref(),
+        query.start_date.as_deref(),
+        query.end_date.as_deref(),
+    );
 
     let total_items = items.len() as i64;
     let total_hours: f64 = items.iter().map(|i| i.hours).sum();
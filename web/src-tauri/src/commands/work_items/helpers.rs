@@ -1,9 +1,40 @@
 //! Work Items helpers
 //!
-//! Helper functions for session parsing (used for tests and internal operations).
+//! Helper functions for session parsing (used for tests and internal operations)
+//! and for bucketing work items by the user's local date.
 
+use recap_core::models::WorkItem;
 use recap_core::services::is_meaningful_message;
 
+/// `date` (YYYY-MM-DD) moved by `days`; unparsable input is returned as is
+pub fn shift_date(date: &str, days: i64) -> String {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|d| (d + chrono::Duration::days(days)).format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+/// Keep items whose day in `timezone` (see [`recap_core::local_work_date`]) is
+/// within `start..=end`, with `date` set to that day.
+///
+/// Stored dates follow the UTC session start, so callers query one extra day
+/// on each side (see [`shift_date`]) and narrow the result here.
+pub fn localize_item_dates(
+    items: Vec<WorkItem>,
+    timezone: Option<&str>,
+    start: Option<&str>,
+    end: Option<&str>,
+) -> Vec<WorkItem> {
+    items
+        .into_iter()
+        .filter_map(|mut item| {
+            item.date = recap_core::local_work_date(item.date, item.start_time.as_deref(), timezone);
+            let date = item.date.to_string();
+            let in_range = start.map_or(true, |s| date.as_str() >= s) && end.map_or(true, |e| date.as_str() <= e);
+            in_range.then_some(item)
+        })
+        .collect()
+}
+
 /// Session metadata extracted from JSONL files
 #[allow(dead_code)]
 pub struct SessionMetadata {
@@ -220,6 +251,64 @@ mod tests {
         file
     }
 
+    fn session_item(date: &str, start_time: Option<&str>) -> WorkItem {
+        let now = chrono::Utc::now();
+        WorkItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: "user".to_string(),
+            source: "claude_code".to_string(),
+            source_id: None,
+            source_url: None,
+            title: "[recap] work".to_string(),
+            description: None,
+            hours: 1.0,
+            date: chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            jira_issue_key: None,
+            jira_issue_suggested: None,
+            jira_issue_title: None,
+            category: None,
+            tags: None,
+            yearly_goal_id: None,
+            synced_to_tempo: false,
+            tempo_worklog_id: None,
+            synced_at: None,
+            created_at: now,
+            updated_at: now,
+            parent_id: None,
+            hours_source: None,
+            hours_estimated: None,
+            commit_hash: None,
+            session_id: None,
+            start_time: start_time.map(String::from),
+            end_time: None,
+            project_path: None,
+        }
+    }
+
+    #[test]
+    fn test_localize_item_dates_session_spanning_utc_midnight() {
+        // Stored on the UTC start date; 23:30–00:30 UTC is the next morning in Taipei
+        let items = vec![
+            session_item("2026-03-01", Some("2026-03-01T23:30:00Z")),
+            session_item("2026-03-01", Some("2026-03-01T03:00:00Z")),
+            session_item("2026-03-02", None),
+        ];
+
+        let taipei = localize_item_dates(items.clone(), Some("Asia/Taipei"), Some("2026-03-02"), Some("2026-03-02"));
+        assert_eq!(taipei.len(), 2);
+        assert!(taipei.iter().all(|i| i.date.to_string() == "2026-03-02"));
+
+        let utc = localize_item_dates(items, Some("UTC"), Some("2026-03-01"), Some("2026-03-01"));
+        assert_eq!(utc.len(), 2);
+    }
+
+    #[test]
+    fn test_shift_date() {
+        assert_eq!(shift_date("2026-03-01", -1), "2026-02-28");
+        assert_eq!(shift_date("2026-12-31", 1), "2027-01-01");
+        assert_eq!(shift_date("bad", 1), "bad");
+    }
+
     #[test]
     fn test_calculate_hours_valid() {
         let start = "2025-01-10T09:00:00+08:00";
@@ -150,24 +150,6 @@ async fn update_manual_snapshot(
     Ok(())
 }
 
-/// Delete the snapshot record for a manual work item
-async fn delete_manual_snapshot(
-    pool: &sqlx::SqlitePool,
-    user_id: &str,
-    work_item_id: &str,
-) -> Result<(), String> {
-    let session_id = format!("manual:{}", work_item_id);
-
-    sqlx::query("DELETE FROM snapshot_raw_data WHERE session_id = ? AND user_id = ?")
-        .bind(&session_id)
-        .bind(user_id)
-        .execute(pool)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    Ok(())
-}
-
 /// Get the manual projects directory path
 fn get_manual_projects_dir() -> Result<std::path::PathBuf, String> {
     let home = dirs::home_dir().ok_or("Cannot find home directory")?;
@@ -448,7 +430,7 @@ pub async fn get_work_item(
     let db = state.db.lock().await;
 
     let item: Option<WorkItem> =
-        sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ?")
+        sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ? AND deleted_at IS NULL")
             .bind(&id)
             .bind(&claims.sub)
             .fetch_optional(&db.pool)
@@ -469,9 +451,9 @@ pub async fn update_work_item(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    // Check ownership
+    // Check ownership; items in the trash can't be edited until restored
     let existing: Option<WorkItem> =
-        sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ?")
+        sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ? AND deleted_at IS NULL")
             .bind(&id)
             .bind(&claims.sub)
             .fetch_optional(&db.pool)
@@ -625,7 +607,10 @@ pub async fn update_work_item(
     Ok(item)
 }
 
-/// Delete a work item
+/// Move a work item (and its children) to the trash.
+///
+/// Manual items keep their snapshot until the trash is purged, so a restore
+/// brings them back whole.
 #[tauri::command]
 pub async fn delete_work_item(
     state: State<'_, AppState>,
@@ -635,42 +620,32 @@ pub async fn delete_work_item(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    // Check if it's a manual item before deleting
-    let existing: Option<WorkItem> = sqlx::query_as(
-        "SELECT * FROM work_items WHERE id = ? AND user_id = ?"
-    )
-    .bind(&id)
-    .bind(&claims.sub)
-    .fetch_optional(&db.pool)
-    .await
-    .map_err(|e| e.to_string())?;
-
-    let is_manual = existing.as_ref().map(|w| w.source == "manual").unwrap_or(false);
-
-    let result = sqlx::query("DELETE FROM work_items WHERE id = ? AND user_id = ?")
-        .bind(&id)
-        .bind(&claims.sub)
-        .execute(&db.pool)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    if result.rows_affected() == 0 {
+    if !recap_core::services::trash_work_item(&db.pool, &claims.sub, &id).await? {
         return Err("Work item not found".to_string());
     }
 
-    // Delete associated snapshot and file for manual items
-    if is_manual {
-        delete_manual_snapshot(&db.pool, &claims.sub, &id).await?;
+    Ok(())
+}
 
-        // Delete from items.jsonl
-        if let Some(ref item) = existing {
-            if let Some(ref path) = item.project_path {
-                let _ = delete_manual_item_jsonl(path, &id);
-            }
-        }
+/// Take a work item out of the trash
+#[tauri::command]
+pub async fn restore_work_item(
+    state: State<'_, AppState>,
+    token: String,
+    id: String,
+) -> Result<WorkItem, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+
+    if !recap_core::services::restore_work_item(&db.pool, &claims.sub, &id).await? {
+        return Err("Work item not in trash".to_string());
     }
 
-    Ok(())
+    sqlx::query_as("SELECT * FROM work_items WHERE id = ?")
+        .bind(&id)
+        .fetch_one(&db.pool)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Map a work item to a Jira issue
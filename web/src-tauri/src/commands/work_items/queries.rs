@@ -2,16 +2,21 @@
 //!
 //! Commands for listing, getting, and querying work items.
 
+use chrono::Utc;
+use sqlx::SqlitePool;
 use std::collections::HashMap;
 use tauri::State;
 
 use recap_core::auth::verify_token;
 use recap_core::models::{PaginatedResponse, WorkItem};
+use recap_core::services::{build_ics, fts_match_query, CalendarEvent, WORK_ITEMS_FTS_JOIN};
 
+use crate::commands::reports::types::ExportResult;
 use crate::commands::AppState;
+use super::helpers::{localize_item_dates, shift_date};
 use super::query_builder::SafeQueryBuilder;
 use super::types::{
-    DailyHours, JiraMappingStats, StatsQuery, TempoSyncStats, TimelineQuery, TimelineResponse, TimelineSession,
+    ActiveBlock, DailyHours, JiraMappingStats, StatsQuery, TempoSyncStats, TimelineQuery, TimelineResponse, TimelineSession,
     WorkItemFilters, WorkItemStatsResponse, WorkItemWithChildren,
 };
 
@@ -32,8 +37,9 @@ pub async fn list_work_items(
     // Build parameterized query safely
     let mut builder = SafeQueryBuilder::new();
 
-    // Always filter by user_id
+    // Always filter by user_id, and leave out the trash
     builder.add_string_condition("user_id", "=", &claims.sub);
+    builder.add_null_condition("work_items.deleted_at", true);
 
     // Exclude hidden projects globally
     builder.add_raw_condition(
@@ -50,6 +56,13 @@ pub async fn list_work_items(
         builder.add_string_condition("source", "=", source);
     }
 
+    if let Some(excluded) = &filters.exclude_source {
+        if filters.source.as_ref().is_some_and(|s| excluded.contains(s)) {
+            return Err("Source is both included and excluded".to_string());
+        }
+        builder.add_not_in_condition("source", excluded);
+    }
+
     if let Some(category) = &filters.category {
         builder.add_string_condition("category", "=", category);
     }
@@ -70,15 +83,38 @@ pub async fn list_work_items(
         builder.add_string_condition("date", "<=", end_date);
     }
 
+    // synced_at holds both RFC 3339 and CURRENT_TIMESTAMP values; compare normalized
+    if filters.synced_after.is_some() || filters.synced_before.is_some() {
+        builder.add_int_condition("synced_to_tempo", "=", 1);
+    }
+
+    if let Some(synced_after) = &filters.synced_after {
+        builder.add_string_condition("datetime(synced_at)", ">", &normalize_synced_bound(synced_after)?);
+    }
+
+    if let Some(synced_before) = &filters.synced_before {
+        builder.add_string_condition("datetime(synced_at)", "<", &normalize_synced_bound(synced_before)?);
+    }
+
+    // Full-text search joins the FTS index and ranks by relevance
+    let fts_query = filters.search.as_deref().and_then(fts_match_query);
+    let (table, order_by) = match &fts_query {
+        Some(query) => {
+            builder.add_string_condition("work_items_fts", "MATCH", query);
+            (WORK_ITEMS_FTS_JOIN, "ORDER BY bm25(work_items_fts), date DESC, created_at DESC")
+        }
+        None => ("work_items", "ORDER BY date DESC, created_at DESC"),
+    };
+
     // Count total
-    let total = builder.count(&db.pool, "work_items").await?;
+    let total = builder.count(&db.pool, table).await?;
 
     // Fetch items
     let items: Vec<WorkItem> = builder
         .fetch_all(
             &db.pool,
-            "SELECT * FROM work_items",
-            "ORDER BY date DESC, created_at DESC",
+            &format!("SELECT work_items.* FROM {}", table),
+            order_by,
             Some(per_page),
             Some(offset),
         )
@@ -87,7 +123,7 @@ pub async fn list_work_items(
     // Get child counts
     let mut items_with_children: Vec<WorkItemWithChildren> = Vec::new();
     for item in items {
-        let child_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM work_items WHERE parent_id = ?")
+        let child_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM work_items WHERE parent_id = ? AND deleted_at IS NULL")
             .bind(&item.id)
             .fetch_one(&db.pool)
             .await
@@ -110,6 +146,13 @@ pub async fn list_work_items(
     })
 }
 
+/// Convert a synced-at filter bound to SQLite's `YYYY-MM-DD HH:MM:SS` UTC form
+fn normalize_synced_bound(value: &str) -> Result<String, String> {
+    recap_core::parse_timestamp(value, None)
+        .map(|ts| ts.format("%Y-%m-%d %H:%M:%S").to_string())
+        .ok_or_else(|| format!("Invalid synced-at timestamp: {}", value))
+}
+
 /// Get work item statistics summary
 #[tauri::command]
 pub async fn get_stats_summary(
@@ -119,16 +162,19 @@ pub async fn get_stats_summary(
 ) -> Result<WorkItemStatsResponse, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
+    let timezone = recap_core::services::load_user_timezone(&db.pool, &claims.sub).await;
 
     // Build parameterized query safely
     let mut builder = SafeQueryBuilder::new();
     builder.add_string_condition("user_id", "=", &claims.sub);
+    builder.add_null_condition("deleted_at", true);
 
+    // One extra day on each side; items are narrowed to local dates below
     if let Some(start) = &query.start_date {
-        builder.add_string_condition("date", ">=", start);
+        builder.add_string_condition("date", ">=", &shift_date(start, -1));
     }
     if let Some(end) = &query.end_date {
-        builder.add_string_condition("date", "<=", end);
+        builder.add_string_condition("date", "<=", &shift_date(end, 1));
     }
 
     // Exclude hidden projects
@@ -139,6 +185,12 @@ pub async fn get_stats_summary(
     let work_items: Vec<WorkItem> = builder
         .fetch_all(&db.pool, "SELECT * FROM work_items", "", None, None)
         .await?;
+    let work_items = localize_item_dates(
+        work_items,
+        timezone.as_deref(),
+        query.start_date.as_deref(),
+        query.end_date.as_deref(),
+    );
 
     let total_items = work_items.len() as i64;
     let total_hours: f64 = work_items.iter().map(|i| i.hours).sum();
@@ -231,7 +283,53 @@ pub async fn get_timeline_data(
 ) -> Result<TimelineResponse, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
+    load_timeline(&db.pool, &claims.sub, query).await
+}
 
+/// Export the day's timeline sessions as an .ics calendar in the downloads
+/// directory. Event UIDs come from session IDs, so re-exporting a day
+/// updates the events imported before.
+#[tauri::command]
+pub async fn export_timeline_ics(
+    state: State<'_, AppState>,
+    token: String,
+    query: TimelineQuery,
+) -> Result<ExportResult, String> {
+    let claims = verify_token(&token).map_err(|e| e.to_string())?;
+    let db = state.db.lock().await;
+    let timeline = load_timeline(&db.pool, &claims.sub, query).await?;
+
+    let events: Vec<CalendarEvent> = timeline
+        .sessions
+        .iter()
+        .filter_map(|s| {
+            CalendarEvent::from_times(&s.id, &s.title, Some(&s.project), &s.start_time, &s.end_time, None)
+        })
+        .collect();
+    let ics = build_ics(&events, Utc::now());
+
+    let downloads_dir = dirs::download_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join("Downloads")))
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+    let file_path = downloads_dir.join(format!("recap_timeline_{}.ics", timeline.date.replace('-', "")));
+
+    if let Err(e) = std::fs::write(&file_path, ics) {
+        return Ok(ExportResult {
+            success: false,
+            file_path: None,
+            error: Some(e.to_string()),
+        });
+    }
+
+    Ok(ExportResult {
+        success: true,
+        file_path: Some(file_path.to_string_lossy().to_string()),
+        error: None,
+    })
+}
+
+/// Timeline sessions of `user_id` for `query.date`
+async fn load_timeline(pool: &SqlitePool, user_id: &str, query: TimelineQuery) -> Result<TimelineResponse, String> {
     // Determine which sources to filter by
     // Default to claude_code if not specified or empty
     let sources = match &query.sources {
@@ -242,12 +340,15 @@ pub async fn get_timeline_data(
     // Build the source placeholders for SQL IN clause
     let source_placeholders: String = sources.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
 
-    // Query work_items for the given date with start_time (session timing)
+    let timezone = recap_core::services::load_user_timezone(pool, user_id).await;
+
+    // Query work_items around the given date with start_time (session timing);
+    // stored dates are UTC, so the day is narrowed to the local date below
     // Filter by selected sources
     // Exclude hidden projects
     let sql = format!(
         r#"SELECT * FROM work_items
-           WHERE user_id = ? AND date = ? AND source IN ({})
+           WHERE user_id = ? AND date >= ? AND date <= ? AND source IN ({}) AND deleted_at IS NULL
            AND NOT EXISTS (
                SELECT 1 FROM project_preferences pp
                WHERE pp.user_id = work_items.user_id
@@ -259,17 +360,22 @@ pub async fn get_timeline_data(
     );
 
     let mut query_builder = sqlx::query_as::<_, crate::models::WorkItem>(&sql)
-        .bind(&claims.sub)
-        .bind(&query.date);
+        .bind(user_id)
+        .bind(shift_date(&query.date, -1))
+        .bind(shift_date(&query.date, 1));
 
     for source in &sources {
         query_builder = query_builder.bind(source);
     }
 
     let items: Vec<crate::models::WorkItem> = query_builder
-        .fetch_all(&db.pool)
+        .fetch_all(pool)
         .await
         .map_err(|e| e.to_string())?;
+    let items = localize_item_dates(items, timezone.as_deref(), Some(&query.date), Some(&query.date));
+
+    // Session files give the active blocks within each Claude session
+    let claude_projects = recap_core::services::resolve_claude_projects_dirs(pool, user_id).await?;
 
     // Convert work items to timeline sessions
     let mut sessions: Vec<TimelineSession> = Vec::new();
@@ -302,10 +408,13 @@ pub async fn get_timeline_data(
             item.title.clone()
         };
 
-        // Use start_time/end_time if available, otherwise use date boundaries
-        let start_time = item.start_time.clone()
+        // Use start_time/end_time (in the user's timezone) if available, otherwise use date boundaries
+        let local_time = |ts: &String| {
+            recap_core::timestamp_in_timezone(ts, timezone.as_deref()).unwrap_or_else(|| ts.clone())
+        };
+        let start_time = item.start_time.as_ref().map(local_time)
             .unwrap_or_else(|| format!("{}T09:00:00+08:00", query.date));
-        let end_time = item.end_time.clone()
+        let end_time = item.end_time.as_ref().map(local_time)
             .unwrap_or_else(|| format!("{}T17:00:00+08:00", query.date));
 
         // Get commits for this session's time range
@@ -313,6 +422,23 @@ pub async fn get_timeline_data(
         let author = crate::core_services::get_git_user_email(&project_path);
         let commits = crate::core_services::get_commits_in_time_range(&project_path, &start_time, &end_time, author.as_deref());
 
+        let mut active_blocks = item
+            .session_id
+            .as_deref()
+            .and_then(|session_id| {
+                claude_projects
+                    .iter()
+                    .find_map(|dir| crate::core_services::find_session_file(dir, session_id))
+            })
+            .map(|path| crate::core_services::parse_session_active_blocks(&path))
+            .unwrap_or_default();
+        if active_blocks.is_empty() {
+            active_blocks.push(ActiveBlock {
+                start: start_time.clone(),
+                end: end_time.clone(),
+            });
+        }
+
         sessions.push(TimelineSession {
             id: item.session_id.unwrap_or_else(|| item.id.clone()),
             project,
@@ -321,6 +447,7 @@ pub async fn get_timeline_data(
             end_time,
             hours: item.hours,
             commits,
+            active_blocks,
         });
     }
 
@@ -3,13 +3,17 @@
 //! Commands for batch sync and aggregation of work items.
 
 use std::collections::HashMap;
-use chrono::Utc;
+use chrono::{Local, NaiveDate, Utc};
+use sqlx::SqlitePool;
 use tauri::State;
 use uuid::Uuid;
 
 use recap_core::auth::verify_token;
-use recap_core::models::WorkItem;
+use recap_core::models::{WorkItem, WorklogEntry, WorklogSyncResult};
+use recap_core::services::is_valid_issue_key;
+use recap_core::services::tempo::{self, JiraAuthType, WorklogUploader};
 
+use crate::commands::tempo::get_user_config;
 use crate::commands::AppState;
 use super::query_builder::SafeQueryBuilder;
 use super::types::{
@@ -17,6 +21,16 @@ use super::types::{
 };
 
 /// Batch sync work items to Tempo
+///
+/// Items Tempo would refuse (missing, no or malformed Jira issue, dated in
+/// the future, no hours) are rejected per item before anything is posted;
+/// the rest of the batch still goes through. With `dry_run` the worklogs are
+/// only built and returned as a preview; nothing is uploaded or marked synced.
+/// Otherwise each worklog is posted with the user's Tempo token and its item
+/// is marked synced right after. The loop deliberately does not run in one
+/// transaction: a worklog posted to Tempo can't be rolled back, so holding
+/// the local writes until the end would only lose the record of uploads that
+/// already happened if the batch stopped midway.
 #[tauri::command]
 pub async fn batch_sync_tempo(
     state: State<'_, AppState>,
@@ -26,70 +40,164 @@ pub async fn batch_sync_tempo(
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
 
-    // Get user's Tempo token
-    let user: Option<crate::models::User> = sqlx::query_as("SELECT * FROM users WHERE id = ?")
-        .bind(&claims.sub)
-        .fetch_optional(&db.pool)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    let user = user.ok_or("User not found".to_string())?;
-
-    let _tempo_token = user
-        .tempo_token
-        .ok_or("Tempo token not configured".to_string())?;
-
-    let mut synced = 0;
-    let mut failed = 0;
+    let mut results = Vec::new();
     let mut errors = Vec::new();
+    let mut worklogs: Vec<(String, WorklogEntry)> = Vec::new();
+    let today = Local::now().date_naive();
 
     for item_id in &request.work_item_ids {
         let item: Option<WorkItem> =
-            sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ?")
+            sqlx::query_as("SELECT * FROM work_items WHERE id = ? AND user_id = ? AND deleted_at IS NULL")
                 .bind(item_id)
                 .bind(&claims.sub)
                 .fetch_optional(&db.pool)
                 .await
                 .map_err(|e| e.to_string())?;
 
-        let item = match item {
-            Some(i) => i,
-            None => {
-                failed += 1;
-                errors.push(format!("Work item {} not found", item_id));
-                continue;
-            }
+        let Some(item) = item else {
+            let error = format!("Work item {} not found", item_id);
+            results.push(sync_result(None, None, "error", Some(error.clone())));
+            errors.push(error);
+            continue;
         };
-
-        if item.jira_issue_key.is_none() {
-            failed += 1;
-            errors.push(format!("Work item {} has no Jira issue mapped", item_id));
+        let entry = worklog_for_item(&item);
+        if let Some(reason) = rejection_reason(item.jira_issue_key.as_deref(), item.date, item.hours, today) {
+            let error = format!("Work item {} {}", item_id, reason);
+            results.push(sync_result(Some(&entry), None, "error", Some(error.clone())));
+            errors.push(error);
             continue;
         }
+        worklogs.push((item.id, entry));
+    }
 
-        // TODO: Call Tempo API to create worklog
-        let now = Utc::now();
-        if let Err(e) = sqlx::query("UPDATE work_items SET synced_to_tempo = 1, synced_at = ? WHERE id = ?")
-            .bind(now)
-            .bind(item_id)
-            .execute(&db.pool)
-            .await
-        {
-            failed += 1;
-            errors.push(format!("Failed to update {}: {}", item_id, e));
-            continue;
-        }
+    if request.dry_run {
+        return Ok(BatchSyncResponse {
+            synced: 0,
+            failed: results.len() as i64,
+            errors,
+            dry_run: true,
+            preview: worklogs.into_iter().map(|(_, entry)| entry).collect(),
+            results,
+        });
+    }
+
+    let cfg = get_user_config(&db.pool, &claims.sub).await?;
+    let tempo_token = cfg
+        .tempo_token
+        .as_deref()
+        .ok_or("Tempo token not configured".to_string())?;
+    let auth_type = match cfg.auth_type {
+        JiraAuthType::Basic => "basic",
+        JiraAuthType::Pat => "pat",
+    };
+    let mut uploader = WorklogUploader::new(
+        &cfg.jira_url,
+        &cfg.jira_pat,
+        cfg.jira_email.as_deref(),
+        auth_type,
+        Some(tempo_token),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut synced = 0;
+    for (item_id, entry) in worklogs {
+        let upload = tempo::WorklogEntry {
+            issue_key: entry.issue_key.clone(),
+            date: entry.date.clone(),
+            time_spent_seconds: entry.minutes as i64 * 60,
+            description: entry.description.clone(),
+            account_id: None,
+        };
+        // Tempo validation failures (unknown issue, closed period, ...) stay with the item
+        let worklog_id = match uploader.upload_worklog(upload, true).await {
+            Ok(response) => response.id.or(response.tempo_worklog_id.map(|id| id.to_string())),
+            Err(e) => {
+                let error = format!("Failed to upload {}: {}", item_id, e);
+                results.push(sync_result(Some(&entry), None, "error", Some(e.to_string())));
+                errors.push(error);
+                continue;
+            }
+        };
 
+        // Record each upload as soon as it is posted
+        if let Err(e) = mark_synced(&db.pool, &item_id, worklog_id.as_deref()).await {
+            // The worklog exists in Tempo, so report it as posted but flag the local state
+            errors.push(format!("Uploaded {} but failed to mark it synced: {}", item_id, e));
+        }
+        results.push(sync_result(Some(&entry), worklog_id, "success", None));
         synced += 1;
     }
 
     Ok(BatchSyncResponse {
         synced,
-        failed,
+        failed: results.iter().filter(|r| r.status == "error").count() as i64,
         errors,
+        dry_run: false,
+        preview: Vec::new(),
+        results,
     })
 }
 
+/// Store the Tempo worklog id of an uploaded item
+async fn mark_synced(pool: &SqlitePool, item_id: &str, worklog_id: Option<&str>) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+    sqlx::query(
+        "UPDATE work_items SET synced_to_tempo = 1, tempo_worklog_id = ?, synced_at = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(worklog_id)
+    .bind(now)
+    .bind(now)
+    .bind(item_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Why Tempo would refuse a work item's worklog, checked before posting
+fn rejection_reason(jira_issue_key: Option<&str>, date: NaiveDate, hours: f64, today: NaiveDate) -> Option<String> {
+    match jira_issue_key {
+        None => return Some("has no Jira issue mapped".to_string()),
+        Some(key) if !is_valid_issue_key(key) => return Some(format!("has an invalid Jira issue key '{}'", key)),
+        Some(_) => {}
+    }
+    if date > today {
+        return Some(format!("is dated in the future ({})", date));
+    }
+    if hours <= 0.0 {
+        return Some("has no hours to log".to_string());
+    }
+    None
+}
+
+/// The worklog posted for a work item: its hours in whole minutes, titled by the item
+fn worklog_for_item(item: &WorkItem) -> WorklogEntry {
+    WorklogEntry {
+        issue_key: item.jira_issue_key.clone().unwrap_or_default(),
+        date: item.date.to_string(),
+        minutes: (item.hours * 60.0).round() as i32,
+        description: item.title.clone(),
+    }
+}
+
+fn sync_result(
+    entry: Option<&WorklogEntry>,
+    id: Option<String>,
+    status: &str,
+    error_message: Option<String>,
+) -> WorklogSyncResult {
+    let minutes = entry.map_or(0, |e| e.minutes);
+    WorklogSyncResult {
+        id,
+        issue_key: entry.map(|e| e.issue_key.clone()).unwrap_or_default(),
+        date: entry.map(|e| e.date.clone()).unwrap_or_default(),
+        minutes,
+        hours: minutes as f64 / 60.0,
+        description: entry.map(|e| e.description.clone()).unwrap_or_default(),
+        status: status.to_string(),
+        error_message,
+    }
+}
+
 /// Aggregate work items by project + date
 #[tauri::command]
 pub async fn aggregate_work_items(
@@ -103,6 +211,7 @@ pub async fn aggregate_work_items(
     // Build parameterized query safely
     let mut builder = SafeQueryBuilder::new();
     builder.add_string_condition("user_id", "=", &claims.sub);
+    builder.add_null_condition("deleted_at", true);
 
     if let Some(start) = &request.start_date {
         builder.add_string_condition("date", ">=", start);
@@ -269,3 +378,23 @@ pub async fn aggregate_work_items(
         deleted_count: grouped_count,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejection_reason() {
+        let today: NaiveDate = "2026-03-04".parse().unwrap();
+        let past: NaiveDate = "2026-03-02".parse().unwrap();
+
+        assert_eq!(rejection_reason(Some("ACME-1"), past, 2.0, today), None);
+        assert_eq!(rejection_reason(Some("ACME-1"), today, 2.0, today), None);
+        assert_eq!(rejection_reason(None, past, 2.0, today).unwrap(), "has no Jira issue mapped");
+        assert!(rejection_reason(Some("acme 1"), past, 2.0, today).unwrap().contains("invalid"));
+        assert!(rejection_reason(Some("ACME-1"), "2026-03-05".parse().unwrap(), 2.0, today)
+            .unwrap()
+            .contains("future"));
+        assert!(rejection_reason(Some("ACME-1"), past, 0.0, today).is_some());
+    }
+}
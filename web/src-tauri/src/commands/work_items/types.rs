@@ -7,8 +7,8 @@ use std::collections::HashMap;
 
 use recap_core::models::WorkItem;
 
-// Re-export TimelineCommit from recap_core
-pub use recap_core::services::TimelineCommit;
+// Re-export TimelineCommit and ActiveBlock from recap_core
+pub use recap_core::services::{ActiveBlock, TimelineCommit};
 
 // ==================== Core Types ====================
 
@@ -24,13 +24,17 @@ pub struct WorkItemFilters {
     pub page: Option<i64>,
     pub per_page: Option<i64>,
     pub source: Option<String>,
+    pub exclude_source: Option<Vec<String>>,
     pub category: Option<String>,
     pub jira_mapped: Option<bool>,
     pub synced_to_tempo: Option<bool>,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
+    pub search: Option<String>,
     pub parent_id: Option<String>,
     pub show_all: Option<bool>,
+    pub synced_after: Option<String>,
+    pub synced_before: Option<String>,
 }
 
 // ==================== Grouped View Types ====================
@@ -140,6 +144,9 @@ pub struct TimelineSession {
     pub end_time: String,
     pub hours: f64,
     pub commits: Vec<TimelineCommit>,
+    /// Working intervals between idle gaps; one block spanning the session
+    /// when message timestamps are unavailable
+    pub active_blocks: Vec<ActiveBlock>,
 }
 
 #[derive(Debug, Serialize)]
@@ -155,6 +162,9 @@ pub struct TimelineResponse {
 #[derive(Debug, Deserialize)]
 pub struct BatchSyncRequest {
     pub work_item_ids: Vec<String>,
+    /// Build the worklogs without posting them or marking items synced
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -162,6 +172,11 @@ pub struct BatchSyncResponse {
     pub synced: i64,
     pub failed: i64,
     pub errors: Vec<String>,
+    pub dry_run: bool,
+    /// Worklogs that would be posted (dry run only)
+    pub preview: Vec<recap_core::models::WorklogEntry>,
+    /// Per-item outcome: every item on a real run, rejected items on a dry run
+    pub results: Vec<recap_core::models::WorklogSyncResult>,
 }
 
 // ==================== Aggregate Types ====================
@@ -190,6 +205,8 @@ pub struct CommitCentricWorklog {
     pub standalone_sessions: Vec<recap_core::services::StandaloneSession>,
     pub total_commits: i32,
     pub total_hours: f64,
+    /// Jira issue mapped to this project, the default for Tempo sync
+    pub jira_issue_key: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -242,6 +259,11 @@ mod tests {
         let json = r#"{"work_item_ids": ["id1", "id2", "id3"]}"#;
         let request: BatchSyncRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.work_item_ids.len(), 3);
+        assert!(!request.dry_run);
+
+        let json = r#"{"work_item_ids": ["id1"], "dry_run": true}"#;
+        let request: BatchSyncRequest = serde_json::from_str(json).unwrap();
+        assert!(request.dry_run);
     }
 
     #[test]
@@ -12,13 +12,7 @@ use super::AppState;
 
 // Types
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct ProjectIssueMapping {
-    pub project_path: String,
-    pub user_id: String,
-    pub jira_issue_key: String,
-    pub updated_at: String,
-}
+pub use recap_core::services::ProjectIssueMapping;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct WorklogSyncRecord {
@@ -65,24 +59,7 @@ pub async fn get_project_issue_mappings(
 ) -> Result<Vec<ProjectIssueMapping>, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
-
-    let mappings = sqlx::query_as::<_, (String, String, String, String)>(
-        "SELECT project_path, user_id, jira_issue_key, COALESCE(updated_at, '') FROM project_issue_mappings WHERE user_id = ?"
-    )
-    .bind(&claims.sub)
-    .fetch_all(&db.pool)
-    .await
-    .map_err(|e| e.to_string())?;
-
-    Ok(mappings
-        .into_iter()
-        .map(|(project_path, user_id, jira_issue_key, updated_at)| ProjectIssueMapping {
-            project_path,
-            user_id,
-            jira_issue_key,
-            updated_at,
-        })
-        .collect())
+    recap_core::services::list_project_issue_mappings(&db.pool, &claims.sub).await
 }
 
 /// Save or update a project-to-issue mapping
@@ -94,29 +71,13 @@ pub async fn save_project_issue_mapping(
 ) -> Result<ProjectIssueMapping, String> {
     let claims = verify_token(&token).map_err(|e| e.to_string())?;
     let db = state.db.lock().await;
-
-    sqlx::query(
-        r#"
-        INSERT INTO project_issue_mappings (project_path, user_id, jira_issue_key, updated_at)
-        VALUES (?, ?, ?, CURRENT_TIMESTAMP)
-        ON CONFLICT(project_path, user_id) DO UPDATE SET
-            jira_issue_key = excluded.jira_issue_key,
-            updated_at = CURRENT_TIMESTAMP
-        "#,
+    recap_core::services::save_project_issue_mapping(
+        &db.pool,
+        &claims.sub,
+        &request.project_path,
+        &request.jira_issue_key,
     )
-    .bind(&request.project_path)
-    .bind(&claims.sub)
-    .bind(&request.jira_issue_key)
-    .execute(&db.pool)
     .await
-    .map_err(|e| e.to_string())?;
-
-    Ok(ProjectIssueMapping {
-        project_path: request.project_path,
-        user_id: claims.sub,
-        jira_issue_key: request.jira_issue_key,
-        updated_at: chrono::Utc::now().to_rfc3339(),
-    })
 }
 
 /// Get worklog sync records for a date range
@@ -17,6 +17,12 @@ use tauri::{
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Release builds (`strict_jwt`) refuse to start without a proper secret
+    if let Err(e) = recap_core::auth::get_jwt_secret() {
+        eprintln!("ERROR: {}", e);
+        std::process::exit(1);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
@@ -30,6 +36,7 @@ pub fn run() {
             commands::auth::commands::login,
             commands::auth::commands::auto_login,
             commands::auth::commands::get_current_user,
+            commands::auth::commands::refresh_token,
             // Config
             commands::config::get_config,
             commands::config::update_config,
@@ -46,11 +53,13 @@ pub fn run() {
             commands::work_items::queries::list_work_items,
             commands::work_items::queries::get_stats_summary,
             commands::work_items::queries::get_timeline_data,
+            commands::work_items::queries::export_timeline_ics,
             // Work Items - mutations
             commands::work_items::mutations::create_work_item,
             commands::work_items::mutations::get_work_item,
             commands::work_items::mutations::update_work_item,
             commands::work_items::mutations::delete_work_item,
+            commands::work_items::mutations::restore_work_item,
             commands::work_items::mutations::map_work_item_jira,
             // Work Items - grouped
             commands::work_items::grouped::get_grouped_work_items,
@@ -77,11 +86,24 @@ pub fn run() {
             commands::reports::queries::analyze_work_items,
             // Reports - export
             commands::reports::export::export_excel_report,
+            commands::reports::export::export_pdf_report,
+            commands::reports::export::export_notion_report,
             commands::reports::export::generate_tempo_report,
             // Sync
             commands::sync::get_sync_status,
             commands::sync::auto_sync,
             commands::sync::list_available_projects,
+            // GitHub - config
+            commands::github::config::get_github_status,
+            commands::github::config::configure_github,
+            commands::github::config::remove_github_config,
+            // GitHub - projects
+            commands::github::projects::list_github_projects,
+            commands::github::projects::add_github_project,
+            commands::github::projects::remove_github_project,
+            commands::github::projects::search_github_projects,
+            // GitHub - sync
+            commands::github::sync::sync_github,
             // GitLab - config
             commands::gitlab::config::get_gitlab_status,
             commands::gitlab::config::configure_gitlab,
@@ -142,6 +164,7 @@ pub fn run() {
             commands::projects::queries::list_projects,
             commands::projects::queries::get_project_detail,
             commands::projects::queries::set_project_visibility,
+            commands::projects::queries::set_projects_visibility,
             commands::projects::queries::get_hidden_projects,
             commands::projects::queries::get_project_directories,
             commands::projects::queries::get_claude_session_path,
@@ -174,6 +197,7 @@ pub fn run() {
             commands::batch_compaction::check_batch_availability,
             commands::batch_compaction::get_pending_hourly_compactions,
             commands::batch_compaction::get_batch_job_status,
+            commands::batch_compaction::estimate_batch_cost,
             commands::batch_compaction::submit_batch_compaction,
             commands::batch_compaction::refresh_batch_status,
             commands::batch_compaction::process_completed_batch_job,
@@ -212,7 +236,14 @@ pub fn run() {
                 match recap_core::Database::new().await {
                     Ok(database) => {
                         log::info!("  ✓ Database connected and migrated");
+                        // Hours a crashed compaction left in progress get redone
+                        if let Err(e) =
+                            recap_core::services::reset_stale_compaction_progress(&database.pool).await
+                        {
+                            log::warn!("  ✗ Failed to reset stale compaction progress: {}", e);
+                        }
                         let state = commands::AppState::new(database);
+                        state.background_sync.set_app_handle(app_handle.clone()).await;
                         app_handle.manage(state);
                         log::info!("  ✓ Application state initialized");
                     }
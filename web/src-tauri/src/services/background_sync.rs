@@ -6,10 +6,13 @@
 //! This service uses the `SyncSource` trait abstraction from `recap_core::services::sources`
 //! to dynamically discover and sync work items from multiple data sources.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::pin::Pin;
 use std::future::Future;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::Duration;
 use tokio_cron_scheduler::{Job, JobScheduler};
@@ -78,6 +81,8 @@ pub struct BackgroundSyncConfig {
     pub sync_gitlab: bool,
     /// Sync Jira/Tempo (requires configuration)
     pub sync_jira: bool,
+    /// Names of sources added with `register_source` that should also sync
+    pub extra_sources: HashSet<String>,
     /// Auto-generate timeline summaries for completed periods
     pub auto_generate_summaries: bool,
     /// Maximum character count for LLM summary output (default: 2000)
@@ -86,6 +91,10 @@ pub struct BackgroundSyncConfig {
     pub summary_reasoning_effort: String,
     /// Custom summary prompt template (None = use default)
     pub summary_prompt: Option<String>,
+    /// Attempts per source before its error is recorded (default: 3)
+    pub sync_retry_attempts: u32,
+    /// Base retry delay in ms; retry n waits `base * n²` (default: 1000 → 1s, 4s, ...)
+    pub sync_retry_base_delay_ms: u64,
 }
 
 impl Default for BackgroundSyncConfig {
@@ -98,10 +107,13 @@ impl Default for BackgroundSyncConfig {
             sync_claude: true,
             sync_gitlab: false,
             sync_jira: false,
+            extra_sources: HashSet::new(),
             auto_generate_summaries: true,
             summary_max_chars: 2000,
             summary_reasoning_effort: "medium".to_string(),
             summary_prompt: None,
+            sync_retry_attempts: 3,
+            sync_retry_base_delay_ms: 1000,
         }
     }
 }
@@ -109,14 +121,18 @@ impl Default for BackgroundSyncConfig {
 impl BackgroundSyncConfig {
     /// Convert to the new SyncConfig format
     pub fn to_sync_config(&self) -> SyncConfig {
-        SyncConfig::from_legacy(
+        let mut sync_config = SyncConfig::from_legacy(
             self.enabled,
             self.interval_minutes,
             self.sync_claude,
             self.sync_git,
             self.sync_gitlab,
             self.sync_jira,
-        )
+        );
+        for name in &self.extra_sources {
+            sync_config.enable_source(name.clone());
+        }
+        sync_config
     }
 }
 
@@ -308,6 +324,8 @@ pub struct SyncServiceStatus {
     pub last_result: Option<String>,
     /// Last error message (if any)
     pub last_error: Option<String>,
+    /// When the watchdog last restarted a stalled scheduler (ISO 8601)
+    pub last_recovery_at: Option<String>,
 }
 
 impl SyncServiceStatus {
@@ -335,6 +353,7 @@ impl SyncServiceStatus {
             next_compaction_at,
             last_result,
             last_error,
+            last_recovery_at: None,
         }
     }
 }
@@ -344,7 +363,7 @@ impl SyncServiceStatus {
 // =============================================================================
 
 /// Result of a single sync operation
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct SyncOperationResult {
     pub source: String,
     pub success: bool,
@@ -368,10 +387,44 @@ impl From<SourceSyncResult> for SyncOperationResult {
     }
 }
 
+/// `sync-progress` payload emitted by scheduled data syncs, once when a
+/// source starts and once when it finishes
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceSyncProgress {
+    pub source: String,
+    /// "discover", "extract" or "done"
+    pub phase: &'static str,
+    pub projects_scanned: i32,
+    pub items_created: i32,
+}
+
+impl SourceSyncProgress {
+    fn started(source: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            phase: "discover",
+            projects_scanned: 0,
+            items_created: 0,
+        }
+    }
+
+    fn done(result: &SyncOperationResult) -> Self {
+        Self {
+            source: result.source.clone(),
+            phase: "done",
+            projects_scanned: result.projects_scanned,
+            items_created: result.items_created,
+        }
+    }
+}
+
 // =============================================================================
 // Background Sync Service
 // =============================================================================
 
+/// How often the watchdog checks that the data sync job is still ticking
+const WATCHDOG_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Background sync service that manages scheduled synchronization
 ///
 /// Uses a formal lifecycle state machine to ensure correct state transitions.
@@ -381,6 +434,9 @@ impl From<SourceSyncResult> for SyncOperationResult {
 /// Tasks are separated:
 /// - **Data Sync**: Frequent (every N minutes) - discovery and extraction
 /// - **Data Compaction**: Periodic (every N hours) - hierarchical summary generation
+///
+/// All state is behind `Arc`s, so clones share the same service.
+#[derive(Clone)]
 pub struct BackgroundSyncService {
     /// Current configuration
     config: Arc<RwLock<BackgroundSyncConfig>>,
@@ -410,6 +466,14 @@ pub struct BackgroundSyncService {
     is_compacting: Arc<AtomicBool>,
     /// When compaction started (for stuck detection)
     compaction_started_at: Arc<RwLock<Option<String>>>,
+    /// When the data sync job last fired (reset on start; read by the watchdog)
+    last_tick_at: Arc<RwLock<Option<chrono::DateTime<chrono::Utc>>>>,
+    /// When the watchdog last restarted a stalled scheduler
+    last_recovery_at: Arc<RwLock<Option<String>>>,
+    /// Whether the watchdog task has been spawned
+    watchdog_started: Arc<AtomicBool>,
+    /// App handle for emitting sync events to the frontend (unset in tests)
+    app_handle: Arc<RwLock<Option<AppHandle>>>,
 }
 
 impl BackgroundSyncService {
@@ -430,6 +494,10 @@ impl BackgroundSyncService {
             user_id: Arc::new(RwLock::new(None)),
             is_compacting: Arc::new(AtomicBool::new(false)),
             compaction_started_at: Arc::new(RwLock::new(None)),
+            last_tick_at: Arc::new(RwLock::new(None)),
+            last_recovery_at: Arc::new(RwLock::new(None)),
+            watchdog_started: Arc::new(AtomicBool::new(false)),
+            app_handle: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -557,6 +625,12 @@ impl BackgroundSyncService {
         *uid = Some(user_id);
     }
 
+    /// Set the app handle used to emit `sync-progress` and `sync-complete`
+    pub async fn set_app_handle(&self, handle: AppHandle) {
+        let mut app = self.app_handle.write().await;
+        *app = Some(handle);
+    }
+
     /// Update the sync configuration
     pub async fn update_config(&self, new_config: BackgroundSyncConfig) {
         let mut config = self.config.write().await;
@@ -595,6 +669,7 @@ impl BackgroundSyncService {
         let next_compaction_at = self.next_compaction_at.read().await.clone();
         let last_result = self.last_result.read().await.clone();
         let last_error = self.last_error.read().await.clone();
+        let last_recovery_at = self.last_recovery_at.read().await.clone();
         let is_compacting = self.is_compacting.load(Ordering::SeqCst);
 
         let syncing_started_at = match &*lifecycle {
@@ -613,6 +688,7 @@ impl BackgroundSyncService {
             next_compaction_at,
             last_result,
             last_error,
+            last_recovery_at,
         }
     }
 
@@ -813,6 +889,8 @@ impl BackgroundSyncService {
             let user_id = Arc::clone(&self.user_id);
             let scheduler_ref = Arc::clone(&self.scheduler);
             let sync_job_id_ref = Arc::clone(&self.sync_job_id);
+            let last_tick_at = Arc::clone(&self.last_tick_at);
+            let app_handle = Arc::clone(&self.app_handle);
 
             Job::new_repeated_async(
                 Duration::from_secs(interval_minutes as u64 * 60),
@@ -826,8 +904,13 @@ impl BackgroundSyncService {
                     let user_id = Arc::clone(&user_id);
                     let scheduler_ref = Arc::clone(&scheduler_ref);
                     let sync_job_id_ref = Arc::clone(&sync_job_id_ref);
+                    let last_tick_at = Arc::clone(&last_tick_at);
+                    let app_handle = Arc::clone(&app_handle);
 
                     Box::pin(async move {
+                        // Record the tick first so skipped ticks still count as alive
+                        *last_tick_at.write().await = Some(chrono::Utc::now());
+
                         // Check config.enabled
                         let cfg = config.read().await;
                         if !cfg.enabled {
@@ -884,6 +967,7 @@ impl BackgroundSyncService {
                         }
 
                         // Perform sync
+                        let app = app_handle.read().await.clone();
                         Self::perform_data_sync(
                             &db,
                             &lifecycle,
@@ -892,6 +976,7 @@ impl BackgroundSyncService {
                             &last_error,
                             &sync_config,
                             &uid,
+                            app.as_ref(),
                         ).await;
 
                         // Update next_sync_at from scheduler's real next fire time
@@ -1066,6 +1151,79 @@ impl BackgroundSyncService {
             }
         }
 
+        // ===== Job 3: Database Backup (hourly check, daily snapshot) =====
+        {
+            let db = Arc::clone(&self.db);
+            let user_id = Arc::clone(&self.user_id);
+
+            let backup_job = Job::new_repeated_async(
+                Duration::from_secs(60 * 60),
+                move |_uuid, _lock| {
+                    let db = Arc::clone(&db);
+                    let user_id = Arc::clone(&user_id);
+
+                    Box::pin(async move {
+                        let Some(uid) = user_id.read().await.clone() else {
+                            return;
+                        };
+
+                        // Clone the pool so the database lock isn't held during VACUUM INTO
+                        let pool = db.lock().await.pool.clone();
+                        if let Err(e) = recap_core::services::run_scheduled_backup(&pool, &uid).await {
+                            log::error!("Scheduled database backup failed: {}", e);
+                        }
+                    }) as Pin<Box<dyn Future<Output = ()> + Send>>
+                },
+            );
+
+            match backup_job {
+                Ok(job) => {
+                    if let Err(e) = sched.add(job).await {
+                        log::error!("Failed to add database backup job: {:?}", e);
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to create database backup job: {:?}", e);
+                }
+            }
+        }
+
+        // ===== Job 4: Trash Purge (hourly) =====
+        {
+            let db = Arc::clone(&self.db);
+            let user_id = Arc::clone(&self.user_id);
+
+            let purge_job = Job::new_repeated_async(
+                Duration::from_secs(60 * 60),
+                move |_uuid, _lock| {
+                    let db = Arc::clone(&db);
+                    let user_id = Arc::clone(&user_id);
+
+                    Box::pin(async move {
+                        let Some(uid) = user_id.read().await.clone() else {
+                            return;
+                        };
+
+                        let pool = db.lock().await.pool.clone();
+                        if let Err(e) = recap_core::services::run_trash_purge(&pool, &uid).await {
+                            log::error!("Trash purge failed: {}", e);
+                        }
+                    }) as Pin<Box<dyn Future<Output = ()> + Send>>
+                },
+            );
+
+            match purge_job {
+                Ok(job) => {
+                    if let Err(e) = sched.add(job).await {
+                        log::error!("Failed to add trash purge job: {:?}", e);
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to create trash purge job: {:?}", e);
+                }
+            }
+        }
+
         // Start the scheduler
         if let Err(e) = sched.start().await {
             log::error!("Failed to start job scheduler: {:?}", e);
@@ -1080,6 +1238,13 @@ impl BackgroundSyncService {
             *guard = Some(sched);
         }
 
+        // Baseline for the watchdog: the first tick is due one interval from now
+        {
+            let mut tick = self.last_tick_at.write().await;
+            *tick = Some(chrono::Utc::now());
+        }
+        self.spawn_watchdog();
+
         log::info!("Background sync scheduler started successfully");
     }
 
@@ -1142,6 +1307,70 @@ impl BackgroundSyncService {
         self.start().await;
     }
 
+    /// Restart the scheduler if the data sync job has stopped ticking.
+    ///
+    /// A panicking job is swallowed by the scheduler, which leaves the service
+    /// "running" without ever syncing again. When background sync is enabled
+    /// and no tick has happened within twice the interval, log an error and
+    /// restart. Returns true if a restart was attempted.
+    pub async fn check_scheduler_health(&self) -> bool {
+        let interval_minutes = {
+            let config = self.config.read().await;
+            if !config.enabled {
+                return false;
+            }
+            config.interval_minutes
+        };
+        if !self.lifecycle.read().await.is_running() {
+            return false;
+        }
+        let Some(last_tick) = *self.last_tick_at.read().await else {
+            return false;
+        };
+        if !Self::tick_overdue(last_tick, chrono::Utc::now(), interval_minutes) {
+            return false;
+        }
+
+        log::error!(
+            "Data sync has not ticked since {} ({}min interval), restarting scheduler",
+            last_tick.to_rfc3339(),
+            interval_minutes
+        );
+        {
+            let mut recovered = self.last_recovery_at.write().await;
+            *recovered = Some(chrono::Utc::now().to_rfc3339());
+        }
+        self.restart().await;
+        true
+    }
+
+    /// Whether a tick is overdue: none within twice the sync interval
+    fn tick_overdue(
+        last_tick: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+        interval_minutes: u32,
+    ) -> bool {
+        now - last_tick > chrono::Duration::minutes(2 * interval_minutes as i64)
+    }
+
+    /// Spawn the watchdog loop once; it outlives scheduler restarts
+    fn spawn_watchdog(&self) {
+        if self.watchdog_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let service = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval_at(
+                tokio::time::Instant::now() + WATCHDOG_CHECK_INTERVAL,
+                WATCHDOG_CHECK_INTERVAL,
+            );
+            loop {
+                interval.tick().await;
+                service.check_scheduler_health().await;
+            }
+        });
+    }
+
     /// Trigger an immediate sync
     pub async fn trigger_sync(&self) -> Vec<SyncOperationResult> {
         let config = self.config.read().await.clone();
@@ -1259,6 +1488,9 @@ impl BackgroundSyncService {
     ///
     /// This is the frequent task that runs every N minutes.
     /// Does NOT include compaction or timeline summary generation.
+    /// With an `app` handle, emits `sync-progress` around each source and a
+    /// final `sync-complete` carrying all results.
+    #[allow(clippy::too_many_arguments)]
     async fn perform_data_sync(
         db: &Arc<Mutex<recap_core::Database>>,
         lifecycle: &Arc<RwLock<ServiceLifecycle>>,
@@ -1267,6 +1499,7 @@ impl BackgroundSyncService {
         last_error: &Arc<RwLock<Option<String>>>,
         config: &BackgroundSyncConfig,
         user_id: &str,
+        app: Option<&AppHandle>,
     ) -> Vec<SyncOperationResult> {
         log::info!("========== 開始資料同步 ==========");
         log::info!("使用者: {}", user_id);
@@ -1307,36 +1540,35 @@ impl BackgroundSyncService {
 
         for (idx, source) in sources.iter().enumerate() {
             log::info!("[{}/{}] 開始同步: {}", idx + 1, sources.len(), source.display_name());
-
-            match source.sync_sessions(&pool, user_id).await {
-                Ok(source_result) => {
-                    let result = SyncOperationResult::from(source_result);
-                    log::info!(
-                        "[{}/{}] {} 同步完成: 掃描 {} 個專案, 發現 {} 筆資料, 新增 {} 筆",
-                        idx + 1, sources.len(),
-                        source.display_name(),
-                        result.projects_scanned,
-                        result.items_synced,
-                        result.items_created
-                    );
-                    results.push(result);
-                }
-                Err(e) => {
+            Self::emit_event(app, "sync-progress", SourceSyncProgress::started(source.source_name()));
+
+            let result = Self::sync_source_with_retry(source.as_ref(), &pool, user_id, config).await;
+            match &result.error {
+                None => log::info!(
+                    "[{}/{}] {} 同步完成: 掃描 {} 個專案, 發現 {} 筆資料, 新增 {} 筆",
+                    idx + 1, sources.len(),
+                    source.display_name(),
+                    result.projects_scanned,
+                    result.items_synced,
+                    result.items_created
+                ),
+                Some(e) => {
                     log::error!("[{}/{}] {} 同步失敗: {}", idx + 1, sources.len(), source.display_name(), e);
-                    results.push(SyncOperationResult {
-                        source: source.source_name().to_string(),
-                        success: false,
-                        error: Some(e),
-                        ..Default::default()
-                    });
                 }
             }
+            Self::emit_event(app, "sync-progress", SourceSyncProgress::done(&result));
+            results.push(result);
         }
 
         // Phase 2: Capture hourly snapshots
         log::info!("---------- Phase 2: 擷取快照 ----------");
         if config.sync_claude {
-            let projects = recap_core::services::SyncService::discover_project_paths();
+            let projects = recap_core::services::discover_user_projects(&pool, user_id)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to discover Claude projects: {}", e);
+                    Vec::new()
+                });
             log::info!("發現 {} 個專案需要擷取快照", projects.len());
             let mut snapshot_count = 0;
             let mut snapshot_errors = 0;
@@ -1442,9 +1674,60 @@ impl BackgroundSyncService {
         }
 
         log::debug!("========== 資料同步結束 ==========");
+        Self::emit_event(app, "sync-complete", &results);
         results
     }
 
+    /// Emit an event to the frontend; a no-op without an app handle
+    fn emit_event<S: Serialize + Clone>(app: Option<&AppHandle>, event: &str, payload: S) {
+        if let Some(app) = app {
+            if let Err(e) = app.emit(event, payload) {
+                log::warn!("Failed to emit {}: {}", event, e);
+            }
+        }
+    }
+
+    /// Sync one source, retrying transient failures with quadratic backoff.
+    ///
+    /// A source is retried when `sync_sessions` fails or its result carries an
+    /// error, up to `sync_retry_attempts` attempts in total. Only the final
+    /// attempt's result is returned, so earlier errors never reach `last_error`.
+    async fn sync_source_with_retry(
+        source: &dyn recap_core::services::sources::SyncSource,
+        pool: &sqlx::SqlitePool,
+        user_id: &str,
+        config: &BackgroundSyncConfig,
+    ) -> SyncOperationResult {
+        let attempts = config.sync_retry_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            let result = match source.sync_sessions(pool, user_id).await {
+                Ok(source_result) => SyncOperationResult::from(source_result),
+                Err(e) => SyncOperationResult {
+                    source: source.source_name().to_string(),
+                    success: false,
+                    error: Some(e),
+                    ..Default::default()
+                },
+            };
+
+            let Some(error) = &result.error else {
+                return result;
+            };
+            if attempt >= attempts {
+                return result;
+            }
+
+            let delay = retry_delay(config.sync_retry_base_delay_ms, attempt);
+            log::warn!(
+                "{} 同步失敗 (第 {}/{} 次): {}，{}ms 後重試",
+                source.display_name(), attempt, attempts, error, delay.as_millis()
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     /// Perform data compaction (Phase 3: Hourly/Daily, Phase 4: Timeline Summaries)
     ///
     /// This is the periodic task that runs every N hours.
@@ -1541,10 +1824,22 @@ impl BackgroundSyncService {
             *compaction_time = Some(chrono::Utc::now().to_rfc3339());
         }
 
+        Self::checkpoint_wal(db).await;
+
         // is_compacting is cleared automatically by CompactionGuard drop
         log::info!("Data compaction completed");
     }
 
+    /// Truncate the WAL after compaction's burst of writes so the `-wal`
+    /// file does not keep growing
+    async fn checkpoint_wal(db: &Arc<Mutex<recap_core::Database>>) {
+        // Clone out of the Mutex so the checkpoint does not hold the lock
+        let database = db.lock().await.clone();
+        if let Err(e) = database.checkpoint().await {
+            log::warn!("WAL checkpoint failed: {}", e);
+        }
+    }
+
     /// Perform the actual sync operation with lifecycle management (FULL SYNC)
     ///
     /// This is the internal implementation used by both the timer loop and trigger_sync.
@@ -1644,7 +1939,12 @@ impl BackgroundSyncService {
 
         // Phase 2: Capture hourly snapshots (uses pool directly, no Mutex)
         if config.sync_claude {
-            let projects = recap_core::services::SyncService::discover_project_paths();
+            let projects = recap_core::services::discover_user_projects(&pool, user_id)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to discover Claude projects: {}", e);
+                    Vec::new()
+                });
             let mut snapshot_count = 0;
             for project in &projects {
                 match recap_core::services::snapshot::capture_snapshots_for_project(
@@ -1695,6 +1995,7 @@ impl BackgroundSyncService {
                     log::warn!("Compaction cycle error: {}", e);
                 }
             }
+            Self::checkpoint_wal(db).await;
         }
 
         // Phase 4: Generate timeline summaries for completed periods
@@ -1842,6 +2143,12 @@ impl BackgroundSyncService {
     }
 }
 
+/// Wait before retry number `attempt` (1-based): `base_delay_ms * attempt²`
+fn retry_delay(base_delay_ms: u64, attempt: u32) -> Duration {
+    let attempt = u64::from(attempt);
+    Duration::from_millis(base_delay_ms.saturating_mul(attempt * attempt))
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1863,6 +2170,17 @@ mod tests {
         assert_eq!(config.summary_reasoning_effort, "medium");
     }
 
+    #[test]
+    fn test_retry_delay_backs_off_quadratically() {
+        let config = BackgroundSyncConfig::default();
+        assert_eq!(config.sync_retry_attempts, 3);
+        let delays: Vec<u128> = (1..=3)
+            .map(|attempt| retry_delay(config.sync_retry_base_delay_ms, attempt).as_millis())
+            .collect();
+        assert_eq!(delays, vec![1000, 4000, 9000]);
+        assert_eq!(retry_delay(0, 2), Duration::ZERO);
+    }
+
     #[test]
     fn test_sync_service_status_default() {
         let status = SyncServiceStatus::default();
@@ -2066,4 +2384,127 @@ mod tests {
         assert_eq!(status.last_result, Some("結果".to_string()));
         assert!(status.last_error.is_none());
     }
+
+    #[test]
+    fn test_source_sync_progress_payload() {
+        let result = SyncOperationResult {
+            source: "claude_code".to_string(),
+            success: true,
+            items_synced: 7,
+            projects_scanned: 3,
+            items_created: 5,
+            error: None,
+        };
+
+        let started = serde_json::to_value(SourceSyncProgress::started("claude_code")).unwrap();
+        assert_eq!(
+            started,
+            serde_json::json!({"source": "claude_code", "phase": "discover", "projects_scanned": 0, "items_created": 0})
+        );
+        let done = serde_json::to_value(SourceSyncProgress::done(&result)).unwrap();
+        assert_eq!(
+            done,
+            serde_json::json!({"source": "claude_code", "phase": "done", "projects_scanned": 3, "items_created": 5})
+        );
+    }
+
+    #[test]
+    fn test_tick_overdue_after_twice_the_interval() {
+        let last_tick = chrono::Utc::now();
+        let at = |minutes| last_tick + chrono::Duration::minutes(minutes);
+        assert!(!BackgroundSyncService::tick_overdue(last_tick, at(15), 15));
+        assert!(!BackgroundSyncService::tick_overdue(last_tick, at(30), 15));
+        assert!(BackgroundSyncService::tick_overdue(last_tick, at(31), 15));
+    }
+
+    #[tokio::test]
+    async fn test_watchdog_restarts_scheduler_after_missed_ticks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = recap_core::Database::open(dir.path().join("test.db")).await.unwrap();
+        let service = BackgroundSyncService::new(Arc::new(Mutex::new(db)));
+        service.start().await;
+        assert!(service.get_lifecycle().await.is_running());
+
+        // Freshly started: nothing to recover
+        assert!(!service.check_scheduler_health().await);
+        assert!(service.get_status().await.last_recovery_at.is_none());
+
+        // Simulate a dead job: last tick 31 minutes ago with a 15 minute interval
+        let stale = chrono::Utc::now() - chrono::Duration::minutes(31);
+        *service.last_tick_at.write().await = Some(stale);
+
+        assert!(service.check_scheduler_health().await);
+        let status = service.get_status().await;
+        assert!(status.is_running);
+        assert!(status.last_recovery_at.is_some());
+        assert!(service.last_tick_at.read().await.unwrap() > stale);
+
+        // Disabled service is left alone
+        *service.last_tick_at.write().await = Some(stale);
+        service.config.write().await.enabled = false;
+        assert!(!service.check_scheduler_health().await);
+
+        service.stop().await;
+    }
+
+    struct CountingSource {
+        syncs: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl recap_core::services::sources::SyncSource for CountingSource {
+        fn source_name(&self) -> &'static str {
+            "counting_background"
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Counting"
+        }
+
+        async fn discover_projects(&self) -> Result<Vec<recap_core::services::sources::SourceProject>, String> {
+            Ok(Vec::new())
+        }
+
+        async fn sync_sessions(&self, _pool: &sqlx::SqlitePool, _user_id: &str) -> Result<SourceSyncResult, String> {
+            self.syncs.fetch_add(1, Ordering::SeqCst);
+            let mut result = SourceSyncResult::new(self.source_name());
+            result.work_items_created = 1;
+            Ok(result)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_source_takes_part_in_background_sync() {
+        let syncs = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        recap_core::services::sources::register_source(CountingSource { syncs: syncs.clone() }).unwrap();
+
+        let (db, _dir) = recap_core::test_support::db_with_user().await;
+        let service = BackgroundSyncService::new(Arc::new(Mutex::new(db)));
+        service.set_user_id("u1".to_string()).await;
+        service.start().await;
+
+        // Registered but not enabled: the run leaves it alone
+        let quiet = BackgroundSyncConfig {
+            sync_git: false,
+            sync_claude: false,
+            auto_generate_summaries: false,
+            ..Default::default()
+        };
+        *service.config.write().await = quiet.clone();
+        let results = service.trigger_sync().await;
+        assert!(results.iter().all(|r| r.source != "counting_background"));
+        assert_eq!(syncs.load(Ordering::SeqCst), 0);
+
+        *service.config.write().await = BackgroundSyncConfig {
+            extra_sources: HashSet::from(["counting_background".to_string()]),
+            ..quiet
+        };
+        let results = service.trigger_sync().await;
+        let result = results.iter().find(|r| r.source == "counting_background").unwrap();
+        assert!(result.success);
+        assert_eq!(result.items_created, 1);
+        assert_eq!(syncs.load(Ordering::SeqCst), 1);
+
+        service.stop().await;
+    }
 }
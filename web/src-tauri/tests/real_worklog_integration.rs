@@ -8,7 +8,7 @@ use std::collections::HashMap;
 
 // Import actual app modules
 use recap_lib::core_services::worklog::{
-    estimate_commit_hours, estimate_from_diff, get_commits_for_date,
+    estimate_commit_hours, estimate_from_diff, get_commits_for_date, EstimationStrategy,
     build_rule_based_outcome, CommitRecord, HoursEstimate, SessionBrief,
 };
 use recap_lib::models::HoursSource;
@@ -74,6 +74,7 @@ fn test_actual_estimate_commit_hours() {
         Some(&session),
         100, 10, 3,
         Some(3.0), // User override
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "With user override (3.0h)", result.hours, result.source.as_str());
@@ -87,6 +88,7 @@ fn test_actual_estimate_commit_hours() {
         Some(&session),
         100, 10, 3,
         None, // No user override
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "With session (2.5h), no override", result.hours, result.source.as_str());
@@ -100,6 +102,7 @@ fn test_actual_estimate_commit_hours() {
         None,             // No session
         100, 10, 3,
         None,
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "With 1.5h commit interval, no session", result.hours, result.source.as_str());
@@ -117,6 +120,7 @@ fn test_actual_estimate_commit_hours() {
         None, // No session
         100, 10, 3,
         None,
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "Heuristic only (100+10 lines, 3 files)", result.hours, result.source.as_str());
@@ -131,6 +135,7 @@ fn test_actual_estimate_commit_hours() {
         None,
         50, 5, 2,
         None,
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "Short interval (2 min) -> heuristic", result.hours, result.source.as_str());
@@ -144,6 +149,7 @@ fn test_actual_estimate_commit_hours() {
         None,
         50, 5, 2,
         None,
+        EstimationStrategy::default(),
     );
     println!("{:<50} {:>8.2}h {:>15}",
         "Long interval (6h) -> heuristic", result.hours, result.source.as_str());
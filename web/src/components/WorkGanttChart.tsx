@@ -12,6 +12,12 @@ export interface TimelineSession {
   endTime: string    // ISO timestamp
   hours: number
   commits: TimelineCommit[]
+  activeBlocks?: ActiveBlock[]  // working intervals, separated by idle gaps
+}
+
+export interface ActiveBlock {
+  start: string  // ISO timestamp
+  end: string    // ISO timestamp
 }
 
 export interface TimelineCommit {
@@ -242,6 +248,10 @@ export function WorkGanttChart({ sessions, date, onDateChange }: WorkGanttChartP
                       const endTime = parseTime(session.endTime)
                       const left = getPositionPercent(startTime)
                       const width = getWidthPercent(startTime, endTime)
+                      const sessionSpan = endTime.getTime() - startTime.getTime()
+                      // With idle gaps, draw the working intervals inside a faint session outline
+                      const blocks = session.activeBlocks ?? []
+                      const hasIdleGaps = blocks.length > 1 && sessionSpan > 0
 
                       return (
                         <Tooltip key={session.id}>
@@ -249,7 +259,7 @@ export function WorkGanttChart({ sessions, date, onDateChange }: WorkGanttChartP
                             <div
                               className={cn(
                                 'absolute top-1 bottom-1 rounded cursor-pointer transition-all',
-                                getProjectColor(project, projectIndex),
+                                hasIdleGaps ? 'bg-muted/40' : getProjectColor(project, projectIndex),
                                 hoveredSession === session.id && 'ring-2 ring-foreground/20'
                               )}
                               style={{
@@ -260,6 +270,29 @@ export function WorkGanttChart({ sessions, date, onDateChange }: WorkGanttChartP
                               onMouseEnter={() => setHoveredSession(session.id)}
                               onMouseLeave={() => setHoveredSession(null)}
                             >
+                              {/* Active blocks */}
+                              {hasIdleGaps && blocks.map((block) => {
+                                const blockStart = parseTime(block.start).getTime() - startTime.getTime()
+                                const blockEnd = parseTime(block.end).getTime() - startTime.getTime()
+                                const blockLeft = Math.max(0, (blockStart / sessionSpan) * 100)
+                                const blockWidth = Math.min(100 - blockLeft, ((blockEnd - blockStart) / sessionSpan) * 100)
+
+                                return (
+                                  <div
+                                    key={block.start}
+                                    className={cn(
+                                      'absolute top-0 bottom-0 rounded',
+                                      getProjectColor(project, projectIndex)
+                                    )}
+                                    style={{
+                                      left: `${blockLeft}%`,
+                                      width: `${blockWidth}%`,
+                                      minWidth: '2px'
+                                    }}
+                                  />
+                                )
+                              })}
+
                               {/* Commit markers */}
                               {session.commits.map((commit) => {
                                 const commitTime = parseTime(commit.time)
@@ -329,6 +362,10 @@ export function WorkGanttChart({ sessions, date, onDateChange }: WorkGanttChartP
           <div className="w-6 h-2 rounded bg-warm/70" />
           <span>Session 時段</span>
         </div>
+        <div className="flex items-center gap-1.5">
+          <div className="w-6 h-2 rounded bg-muted/40" />
+          <span>閒置</span>
+        </div>
         <div className="flex items-center gap-1.5">
           <div className="w-1.5 h-1.5 rounded-full bg-foreground/80" />
           <span>Git Commit</span>
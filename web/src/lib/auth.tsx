@@ -43,17 +43,22 @@ const AuthContext = createContext<AuthContextType | undefined>(undefined)
 
 // Token storage
 const TOKEN_KEY = 'recap_auth_token'
+const REFRESH_TOKEN_KEY = 'recap_refresh_token'
 
 function getStoredToken(): string | null {
   return localStorage.getItem(TOKEN_KEY)
 }
 
-function setStoredToken(token: string): void {
+function setStoredToken(token: string, refreshToken?: string): void {
   localStorage.setItem(TOKEN_KEY, token)
+  if (refreshToken) {
+    localStorage.setItem(REFRESH_TOKEN_KEY, refreshToken)
+  }
 }
 
 function removeStoredToken(): void {
   localStorage.removeItem(TOKEN_KEY)
+  localStorage.removeItem(REFRESH_TOKEN_KEY)
 }
 
 // Provider component
@@ -90,11 +95,14 @@ export function AuthProvider({ children }: { children: ReactNode }) {
             // Check onboarding status from DB
             await checkOnboardingStatus()
           } catch {
-            // Token is invalid, try auto-login for local mode
-            removeStoredToken()
-            setToken(null)
-            if (status.local_mode && status.has_users) {
-              await performAutoLogin()
+            // Token is invalid or expired, try the refresh token first,
+            // then auto-login for local mode
+            if (!(await performRefresh())) {
+              removeStoredToken()
+              setToken(null)
+              if (status.local_mode && status.has_users) {
+                await performAutoLogin()
+              }
             }
           }
         } else if (status.local_mode && status.has_users) {
@@ -121,10 +129,26 @@ export function AuthProvider({ children }: { children: ReactNode }) {
       }
     }
 
+    async function performRefresh(): Promise<boolean> {
+      const refreshToken = localStorage.getItem(REFRESH_TOKEN_KEY)
+      if (!refreshToken) return false
+      try {
+        const data = await auth.refreshToken(refreshToken)
+        const userData = await auth.getCurrentUser(data.access_token)
+        setStoredToken(data.access_token, data.refresh_token)
+        setToken(data.access_token)
+        setUser(userData)
+        await checkOnboardingStatus()
+        return true
+      } catch {
+        return false
+      }
+    }
+
     async function performAutoLogin() {
       try {
         const data = await auth.autoLogin()
-        setStoredToken(data.access_token)
+        setStoredToken(data.access_token, data.refresh_token)
         setToken(data.access_token)
 
         // Fetch user info
@@ -144,7 +168,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
   const login = async (username: string, password: string) => {
     try {
       const data = await auth.login({ username, password })
-      setStoredToken(data.access_token)
+      setStoredToken(data.access_token, data.refresh_token)
       setToken(data.access_token)
 
       // Fetch user info
@@ -178,7 +202,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
   const autoLogin = async () => {
     try {
       const data = await auth.autoLogin()
-      setStoredToken(data.access_token)
+      setStoredToken(data.access_token, data.refresh_token)
       setToken(data.access_token)
 
       // Fetch user info
@@ -110,6 +110,7 @@ export function useDashboard(isAuthenticated: boolean) {
             time: c.time,
             author: c.author,
           })),
+          activeBlocks: s.active_blocks,
         }))
         setGanttSessions(sessions)
       } catch {
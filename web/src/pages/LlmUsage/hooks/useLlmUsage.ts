@@ -28,12 +28,12 @@ export function useLlmUsage(startDate: string, endDate: string): UseLlmUsageResu
         llmUsage.getUsageStats(startDate, endDate),
         llmUsage.getUsageDaily(startDate, endDate),
         llmUsage.getUsageByModel(startDate, endDate),
-        llmUsage.getUsageLogs(startDate, endDate, 50, 0),
+        llmUsage.getUsageLogs({ start_date: startDate, end_date: endDate, page: 1, per_page: 50 }),
       ])
       setStats(statsData)
       setDaily(dailyData)
       setModels(modelsData)
-      setLogs(logsData)
+      setLogs(logsData.items)
     } catch (e) {
       setError(e instanceof Error ? e.message : String(e))
     } finally {
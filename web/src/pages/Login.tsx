@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react'
 import { useNavigate } from 'react-router-dom'
 import { getVersion } from '@tauri-apps/api/app'
 import { useAuth } from '@/lib/auth'
+import { CommandError } from '@/services/client'
 import { Card, CardContent } from '@/components/ui/card'
 import { Input } from '@/components/ui/input'
 import { Button } from '@/components/ui/button'
@@ -51,9 +52,12 @@ export function LoginPage() {
       navigate('/')
     } catch (err) {
       const message = err instanceof Error ? err.message : 'Login failed'
+      const code = err instanceof CommandError ? err.code : null
       // Translate common error messages
-      if (message.includes('Invalid credentials') || message.includes('invalid')) {
+      if (code === 'AUTH_FAILED' || message.includes('Invalid credentials') || message.includes('invalid')) {
         setError('帳號或密碼錯誤')
+      } else if (code === 'ACCOUNT_DISABLED') {
+        setError('此帳號已停用')
       } else if (message.includes('not found') || message.includes('User not found')) {
         setError('此帳號不存在')
       } else {
@@ -94,7 +98,9 @@ export function LoginPage() {
     } catch (err) {
       const message = err instanceof Error ? err.message : 'Registration failed'
       // Translate common error messages
-      if (message.includes('already exists') || message.includes('duplicate')) {
+      if (err instanceof CommandError && err.code === 'ALREADY_EXISTS' && message.includes('Email')) {
+        setError('此 Email 已被註冊')
+      } else if (message.includes('already exists') || message.includes('duplicate')) {
         setError('此帳號已被註冊')
       } else {
         setError(message)
@@ -24,6 +24,7 @@ import { ClaudeIcon } from '@/pages/Settings/components/ProjectsSection/icons/Cl
 const LLM_PROVIDERS = [
   { id: 'openai', label: 'OpenAI', desc: 'GPT-5 系列', defaultModel: 'gpt-5-nano' },
   { id: 'anthropic', label: 'Anthropic', desc: 'Claude 系列', defaultModel: 'claude-sonnet-4-20250514' },
+  { id: 'gemini', label: 'Gemini', desc: 'Google Gemini 系列', defaultModel: 'gemini-2.5-flash' },
   { id: 'ollama', label: 'Ollama', desc: '本地部署', defaultModel: 'llama3.2' },
   { id: 'openai-compatible', label: '相容 API', desc: '自架 OpenAI 相容服務', defaultModel: '' },
 ]
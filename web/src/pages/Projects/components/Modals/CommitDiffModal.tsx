@@ -8,7 +8,7 @@ import {
 import { AlertCircle, FileText, FilePlus, FileMinus, FileEdit, ArrowRight, Plus, Minus, Clock, User } from 'lucide-react'
 import { cn } from '@/lib/utils'
 import { useCommitDiff } from '../../hooks/useCommitDiff'
-import type { CommitFileChange } from '@/types'
+import type { CommitFileChange, FileDiff } from '@/types'
 
 interface CommitDiffModalProps {
   open: boolean
@@ -101,32 +101,48 @@ function FileChangeRow({ file }: { file: CommitFileChange }) {
   )
 }
 
-function DiffViewer({ diffText }: { diffText: string }) {
-  // Parse diff lines and add coloring
-  const lines = diffText.split('\n')
-
+function DiffViewer({ diffs, truncated }: { diffs: FileDiff[]; truncated: boolean }) {
   return (
     <div className="bg-zinc-900 dark:bg-zinc-950 rounded-lg overflow-hidden">
-      <pre className="text-xs font-mono overflow-x-auto p-4 max-h-[400px] overflow-y-auto">
-        {lines.map((line, index) => {
-          let className = 'text-zinc-400'
-          if (line.startsWith('+') && !line.startsWith('+++')) {
-            className = 'text-green-400 bg-green-950/30'
-          } else if (line.startsWith('-') && !line.startsWith('---')) {
-            className = 'text-red-400 bg-red-950/30'
-          } else if (line.startsWith('@@')) {
-            className = 'text-blue-400'
-          } else if (line.startsWith('diff --git') || line.startsWith('index ') || line.startsWith('---') || line.startsWith('+++')) {
-            className = 'text-zinc-500'
-          }
-
-          return (
-            <div key={index} className={cn('whitespace-pre', className)}>
-              {line || ' '}
+      <div className="text-xs font-mono overflow-x-auto p-4 max-h-[400px] overflow-y-auto space-y-4">
+        {diffs.map((file, fileIndex) => (
+          <div key={`${file.path}-${fileIndex}`}>
+            <div className="flex items-center gap-2 text-zinc-300 pb-1">
+              <span>{file.path}</span>
+              {file.language && (
+                <span className="px-1.5 rounded bg-zinc-800 text-zinc-400">{file.language}</span>
+              )}
             </div>
-          )
-        })}
-      </pre>
+            {file.hunks.map((hunk, hunkIndex) => (
+              <pre
+                key={hunkIndex}
+                className={cn(file.language && `language-${file.language}`)}
+              >
+                <div className="whitespace-pre text-blue-400">{hunk.header}</div>
+                {hunk.lines.map((line, index) => {
+                  let className = 'text-zinc-400'
+                  if (line.startsWith('+')) {
+                    className = 'text-green-400 bg-green-950/30'
+                  } else if (line.startsWith('-')) {
+                    className = 'text-red-400 bg-red-950/30'
+                  } else if (line.startsWith('\\')) {
+                    className = 'text-zinc-500'
+                  }
+
+                  return (
+                    <div key={index} className={cn('whitespace-pre', className)}>
+                      {line || ' '}
+                    </div>
+                  )
+                })}
+              </pre>
+            ))}
+          </div>
+        ))}
+        {truncated && (
+          <div className="text-zinc-500">... (diff truncated)</div>
+        )}
+      </div>
     </div>
   )
 }
@@ -235,12 +251,12 @@ export function CommitDiffModal({
               )}
 
               {/* Diff viewer */}
-              {diff.diff_text ? (
+              {diff.diffs.length > 0 ? (
                 <div>
                   <div className="mb-2">
                     <span className="text-sm font-medium">Diff</span>
                   </div>
-                  <DiffViewer diffText={diff.diff_text} />
+                  <DiffViewer diffs={diff.diffs} truncated={diff.truncated} />
                 </div>
               ) : (
                 <div className="text-center py-8 text-muted-foreground text-sm">
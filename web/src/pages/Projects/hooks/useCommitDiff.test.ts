@@ -39,7 +39,23 @@ describe('useCommitDiff', () => {
           deletions: 5,
         },
       ],
-      diff_text: '+added line\n-deleted line',
+      diffs: [
+        {
+          path: 'test.ts',
+          language: 'typescript',
+          hunks: [
+            {
+              header: '@@ -1 +1 @@',
+              old_start: 1,
+              old_lines: 1,
+              new_start: 1,
+              new_lines: 1,
+              lines: ['-deleted line', '+added line'],
+            },
+          ],
+        },
+      ],
+      truncated: false,
       stats: {
         files_changed: 1,
         insertions: 10,
@@ -83,7 +99,8 @@ describe('useCommitDiff', () => {
       author: 'Test Author <test@example.com>',
       date: '2026-01-30T10:00:00Z',
       files: [],
-      diff_text: null,
+      diffs: [],
+      truncated: false,
       stats: {
         files_changed: 0,
         insertions: 0,
@@ -136,7 +153,8 @@ describe('useCommitDiff', () => {
         author: 'Test',
         date: '2026-01-30',
         files: [],
-        diff_text: null,
+        diffs: [],
+      truncated: false,
         stats: { files_changed: 0, insertions: 0, deletions: 0 },
       })
     })
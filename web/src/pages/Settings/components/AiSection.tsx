@@ -57,6 +57,7 @@ interface AiSectionProps {
 const LLM_PROVIDERS = [
   { id: 'openai', label: 'OpenAI', desc: 'GPT-5 系列' },
   { id: 'anthropic', label: 'Anthropic', desc: 'Claude 系列' },
+  { id: 'gemini', label: 'Gemini', desc: 'Google Gemini 系列' },
   { id: 'ollama', label: 'Ollama', desc: '本地部署' },
   { id: 'openai-compatible', label: '相容 API', desc: '自架 OpenAI 相容服務' },
 ]
@@ -173,6 +174,7 @@ export function AiSection({
               placeholder={
                 llmProvider === 'openai' ? 'gpt-5-nano' :
                 llmProvider === 'anthropic' ? 'claude-3-5-sonnet-20241022' :
+                llmProvider === 'gemini' ? 'gemini-2.5-flash' :
                 llmProvider === 'ollama' ? 'llama3.2' : '輸入模型名稱'
               }
             />
@@ -305,6 +305,13 @@ export function DataSyncStatus({
         </div>
       )}
 
+      {/* Watchdog recovery */}
+      {status.last_recovery_at && (
+        <div className="mt-2 p-2 bg-amber-500/10 text-amber-700 dark:text-amber-400 text-xs border-l-2 border-amber-500">
+          排程曾停止回應，已於 {formatDateTime(status.last_recovery_at)} 自動重新啟動
+        </div>
+      )}
+
       {/* Error */}
       {hasError && (
         <div className="mt-2 p-2 bg-destructive/10 text-destructive text-xs border-l-2 border-destructive">
@@ -38,6 +38,7 @@ export function useLlmForm(config: ConfigResponse | null) {
     setLlmProvider(providerId)
     if (providerId === 'openai') setLlmModel('gpt-5-nano')
     else if (providerId === 'anthropic') setLlmModel('claude-3-5-sonnet-20241022')
+    else if (providerId === 'gemini') setLlmModel('gemini-2.5-flash')
     else if (providerId === 'ollama') setLlmModel('llama3.2')
     else setLlmModel('')
     if (providerId === 'ollama') setLlmBaseUrl('http://localhost:11434')
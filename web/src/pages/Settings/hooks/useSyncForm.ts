@@ -74,6 +74,7 @@ export function useSyncForm() {
           next_compaction_at: null,
           last_result: null,
           last_error: null,
+          last_recovery_at: null,
         }
       }
       return null
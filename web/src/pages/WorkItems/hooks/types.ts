@@ -17,6 +17,7 @@ export interface TimelineSession {
   endTime: string
   hours: number
   commits: TimelineCommit[]
+  activeBlocks?: { start: string; end: string }[]
 }
 
 export interface ProjectGroupLog {
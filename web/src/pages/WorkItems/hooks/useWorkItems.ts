@@ -139,6 +139,7 @@ export function useWorkItems(isAuthenticated: boolean, token: string | null) {
           time: c.time,
           author: c.author,
         })),
+        activeBlocks: s.active_blocks,
       }))
       setTimelineSessions(sessions)
     } catch (err) {
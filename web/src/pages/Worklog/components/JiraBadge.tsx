@@ -12,10 +12,12 @@ export function JiraBadge({ issueKey }: JiraBadgeProps) {
   // Subscribe to cache updates so we re-render when prefetch completes
   const cacheVersion = useSyncExternalStore(
     jiraIssueCache.subscribe,
-    () => jiraIssueCache.has(issueKey),
+    () => jiraIssueCache.has(issueKey) || !!jiraIssueCache.getError(issueKey),
   )
 
   const cached = jiraIssueCache.get(issueKey)
+  // Set when the batch prefetch couldn't load this key (deleted or mistyped)
+  const loadError = cached ? undefined : jiraIssueCache.getError(issueKey)
 
   const [summary, setSummary] = useState<string | null>(cached?.summary ?? null)
   const [description, setDescription] = useState<string | null>(cached?.description ?? null)
@@ -80,7 +82,9 @@ export function JiraBadge({ issueKey }: JiraBadgeProps) {
     <TooltipProvider delayDuration={200}>
       <Tooltip onOpenChange={(open) => { if (open) fetchDetails() }}>
         <TooltipTrigger asChild>
-          <span className="inline-flex items-center gap-1 px-1.5 py-0.5 rounded text-[11px] font-medium bg-blue-50 text-blue-700 border border-blue-200 cursor-default max-w-[260px]">
+          <span className={`inline-flex items-center gap-1 px-1.5 py-0.5 rounded text-[11px] font-medium border cursor-default max-w-[260px] ${
+            loadError ? 'bg-amber-50 text-amber-700 border-amber-200' : 'bg-blue-50 text-blue-700 border-blue-200'
+          }`}>
             <svg className="w-3 h-3 shrink-0" viewBox="0 0 24 24" fill="currentColor">
               <path d="M11.53 2c-.55 0-1.06.23-1.42.6l-1.38 1.4a2 2 0 0 0 0 2.83l6.14 6.14a2 2 0 0 0 2.83 0l1.38-1.4a2 2 0 0 0 0-2.83L12.95 2.6A2 2 0 0 0 11.53 2zm-4.13 6.1a2 2 0 0 0-2.82 0L3.2 9.48a2 2 0 0 0 0 2.83l6.14 6.14a2 2 0 0 0 2.83 0l1.38-1.38a2 2 0 0 0 0-2.83L7.4 8.1z" />
             </svg>
@@ -115,7 +119,10 @@ export function JiraBadge({ issueKey }: JiraBadgeProps) {
                   {description}
                 </p>
               )}
-              {summary === '' && !loading && (
+              {loadError && !summary && (
+                <p className="text-primary-foreground/60 text-[11px]">{loadError}</p>
+              )}
+              {summary === '' && !loading && !loadError && (
                 <p className="text-primary-foreground/50 italic">No details available</p>
               )}
             </div>
@@ -158,6 +158,7 @@ export function useWorklog(isAuthenticated: boolean) {
             time: c.time,
             author: c.author,
           })),
+          activeBlocks: s.active_blocks,
         }))
         setGanttSessions(sessions)
       } catch {
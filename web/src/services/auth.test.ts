@@ -2,11 +2,13 @@ import { describe, it, expect, beforeEach } from 'vitest'
 import {
   mockInvoke,
   mockCommandValue,
+  mockCommand,
   mockCommandError,
   resetTauriMock,
 } from '@/test/mocks/tauri'
 import { mockUser, mockAppStatus, mockTokenResponse } from '@/test/fixtures'
 import * as auth from './auth'
+import { CommandError } from './client'
 
 describe('auth service', () => {
   beforeEach(() => {
@@ -78,6 +80,30 @@ describe('auth service', () => {
 
       await expect(auth.login(request)).rejects.toThrow('Invalid credentials')
     })
+
+    it('should surface structured command errors', async () => {
+      mockCommand('login', () => {
+        throw { code: 'AUTH_FAILED', message: 'Invalid credentials', retryable: false }
+      })
+
+      const error = await auth.login({ username: 'wrong', password: 'wrong' }).catch((e) => e)
+
+      expect(error).toBeInstanceOf(CommandError)
+      expect(error.code).toBe('AUTH_FAILED')
+      expect(error.message).toBe('Invalid credentials')
+      expect(error.retryable).toBe(false)
+    })
+  })
+
+  describe('refreshToken', () => {
+    it('should exchange a refresh token for a new access token', async () => {
+      mockCommandValue('refresh_token', mockTokenResponse)
+
+      const result = await auth.refreshToken('mock-refresh-token-12345')
+
+      expect(result.access_token).toBe('mock-jwt-token-12345')
+      expect(mockInvoke).toHaveBeenCalledWith('refresh_token', { refreshToken: 'mock-refresh-token-12345' })
+    })
   })
 
   describe('autoLogin', () => {
@@ -40,6 +40,13 @@ export async function autoLogin(): Promise<TokenResponse> {
   return invokeCommand<TokenResponse>('auto_login')
 }
 
+/**
+ * Exchange a refresh token for a new access token
+ */
+export async function refreshToken(refreshToken: string): Promise<TokenResponse> {
+  return invokeCommand<TokenResponse>('refresh_token', { refreshToken })
+}
+
 /**
  * Get current user by token
  * @param token - Optional token. If not provided, uses token from localStorage
@@ -34,6 +34,8 @@ const mockConfig: BackgroundSyncConfig = {
   summary_max_chars: 500,
   summary_reasoning_effort: 'medium',
   summary_prompt: null,
+  sync_retry_attempts: 3,
+  sync_retry_base_delay_ms: 1000,
 }
 
 const mockStatus: BackgroundSyncStatus = {
@@ -47,6 +49,7 @@ const mockStatus: BackgroundSyncStatus = {
   next_compaction_at: '2024-01-15T11:00:00Z',
   last_result: 'Success',
   last_error: null,
+  last_recovery_at: null,
 }
 
 const mockTriggerResponse: TriggerSyncResponse = {
@@ -23,6 +23,10 @@ export interface BackgroundSyncConfig {
   summary_max_chars: number
   summary_reasoning_effort: string
   summary_prompt: string | null
+  /** Attempts per source before its error is reported */
+  sync_retry_attempts: number
+  /** Base retry delay in ms; retry n waits base × n² */
+  sync_retry_base_delay_ms: number
 }
 
 export interface BackgroundSyncStatus {
@@ -36,6 +40,8 @@ export interface BackgroundSyncStatus {
   next_compaction_at: string | null
   last_result: string | null
   last_error: string | null
+  /** When the watchdog last restarted a stalled scheduler */
+  last_recovery_at: string | null
 }
 
 export interface SyncResult {
@@ -61,6 +67,24 @@ export interface SyncProgress {
   message: string
 }
 
+/** Per-source "sync-progress" event emitted by scheduled background syncs */
+export interface SourceSyncProgress {
+  source: string
+  phase: 'discover' | 'extract' | 'done'
+  projects_scanned: number
+  items_created: number
+}
+
+/** Result of one source, as carried by the "sync-complete" event */
+export interface SyncOperationResult {
+  source: string
+  success: boolean
+  items_synced: number
+  projects_scanned: number
+  items_created: number
+  error: string | null
+}
+
 export type UpdateConfigRequest = Partial<BackgroundSyncConfig>
 
 // =============================================================================
@@ -130,8 +154,11 @@ export async function triggerSyncWithProgress(
 
   try {
     if (onProgress) {
-      unlisten = await listen<SyncProgress>('sync-progress', (event) => {
-        onProgress(event.payload)
+      unlisten = await listen<SyncProgress | SourceSyncProgress>('sync-progress', (event) => {
+        // Scheduled syncs share the event name; skip their per-source payloads
+        if (!('source' in event.payload)) {
+          onProgress(event.payload)
+        }
       })
     }
 
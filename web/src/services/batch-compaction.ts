@@ -47,6 +47,15 @@ export interface BatchSubmitResponse {
   message: string
 }
 
+export interface BatchCostEstimate {
+  total_requests: number
+  prompt_tokens: number
+  /** Upper bound: every request using its full completion limit */
+  completion_tokens: number
+  /** USD, batch discount applied */
+  estimated_cost: number
+}
+
 export interface BatchProcessResponse {
   success: boolean
   summaries_saved: number
@@ -81,6 +90,13 @@ export async function getBatchJobStatus(): Promise<BatchJobStatus | null> {
   return invokeAuth<BatchJobStatus | null>('get_batch_job_status')
 }
 
+/**
+ * Estimate the cost of submitting pending hourly compactions as a batch job
+ */
+export async function estimateBatchCost(): Promise<BatchCostEstimate> {
+  return invokeAuth<BatchCostEstimate>('estimate_batch_cost')
+}
+
 /**
  * Submit pending hourly compactions as a batch job
  */
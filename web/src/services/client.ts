@@ -41,11 +41,51 @@ export function getRequiredToken(): string {
   return token
 }
 
+/**
+ * Structured error returned by migrated Tauri commands (auth, sync)
+ */
+export class CommandError extends Error {
+  readonly code: string
+  readonly retryable: boolean
+
+  constructor(code: string, message: string, retryable = false) {
+    super(message)
+    this.name = 'CommandError'
+    this.code = code
+    this.retryable = retryable
+  }
+}
+
+function isCommandErrorPayload(err: unknown): err is { code: string; message: string; retryable?: boolean } {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as { code?: unknown }).code === 'string' &&
+    typeof (err as { message?: unknown }).message === 'string'
+  )
+}
+
+/**
+ * Turn a rejected invoke into an Error. `{ code, message, retryable }`
+ * payloads become CommandError; plain string errors become Error.
+ */
+export function toCommandError(err: unknown): Error {
+  if (err instanceof Error) return err
+  if (isCommandErrorPayload(err)) {
+    return new CommandError(err.code, err.message, err.retryable ?? false)
+  }
+  return new Error(String(err))
+}
+
 /**
  * Invoke a Tauri command with type safety
  */
 export async function invokeCommand<T>(command: string, args?: Record<string, unknown>): Promise<T> {
-  return invoke<T>(command, args)
+  try {
+    return await invoke<T>(command, args)
+  } catch (err) {
+    throw toCommandError(err)
+  }
 }
 
 /**
@@ -54,5 +94,9 @@ export async function invokeCommand<T>(command: string, args?: Record<string, un
  */
 export async function invokeAuth<T>(command: string, args?: Record<string, unknown>): Promise<T> {
   const token = getRequiredToken()
-  return invoke<T>(command, { token, ...args })
+  try {
+    return await invoke<T>(command, { token, ...args })
+  } catch (err) {
+    throw toCommandError(err)
+  }
 }
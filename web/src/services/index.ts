@@ -13,6 +13,8 @@ export {
   getRequiredToken,
   invokeCommand,
   invokeAuth,
+  CommandError,
+  toCommandError,
 } from './client'
 
 // Re-export domain services
@@ -33,6 +35,7 @@ export * as batchCompaction from './batch-compaction'
 export * as updater from './updater'
 
 // Re-export integrations
+export * as github from './integrations/github'
 export * as gitlab from './integrations/gitlab'
 export * as tempo from './integrations/tempo'
 export * as claude from './integrations/claude'
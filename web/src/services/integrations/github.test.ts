@@ -0,0 +1,83 @@
+import { describe, it, expect, beforeEach } from 'vitest'
+import {
+  mockInvoke,
+  mockCommandValue,
+  mockCommandError,
+  resetTauriMock,
+} from '@/test/mocks/tauri'
+import * as github from './github'
+
+const mockGitHubProject = {
+  id: 'project-1',
+  user_id: 'user-1',
+  github_repo_id: 123,
+  name: 'recap',
+  full_name: 'acme/recap',
+  html_url: 'https://github.com/acme/recap',
+  default_branch: 'main',
+  enabled: true,
+  created_at: '2026-01-01T00:00:00Z',
+}
+
+describe('github service', () => {
+  beforeEach(() => {
+    resetTauriMock()
+    localStorage.setItem('recap_auth_token', 'test-token')
+  })
+
+  describe('configure', () => {
+    it('should configure GitHub', async () => {
+      mockCommandValue('configure_github', { message: 'GitHub configured successfully' })
+
+      const request = { github_pat: 'ghp_xxx' }
+      const result = await github.configure(request)
+
+      expect(result.message).toBe('GitHub configured successfully')
+      expect(mockInvoke).toHaveBeenCalledWith('configure_github', {
+        token: 'test-token',
+        request,
+      })
+    })
+  })
+
+  describe('addProject', () => {
+    it('should add a GitHub repository by full name', async () => {
+      mockCommandValue('add_github_project', mockGitHubProject)
+
+      const request = { full_name: 'acme/recap' }
+      const result = await github.addProject(request)
+
+      expect(result.github_repo_id).toBe(123)
+      expect(mockInvoke).toHaveBeenCalledWith('add_github_project', {
+        token: 'test-token',
+        request,
+      })
+    })
+
+    it('should throw when GitHub is not configured', async () => {
+      mockCommandError('add_github_project', 'GitHub PAT not configured')
+
+      await expect(github.addProject({ full_name: 'acme/recap' })).rejects.toThrow(
+        'GitHub PAT not configured'
+      )
+    })
+  })
+
+  describe('sync', () => {
+    it('should sync GitHub data', async () => {
+      mockCommandValue('sync_github', {
+        synced_commits: 2,
+        synced_pull_requests: 1,
+        work_items_created: 3,
+      })
+
+      const result = await github.sync({ project_id: 'project-1' })
+
+      expect(result.work_items_created).toBe(3)
+      expect(mockInvoke).toHaveBeenCalledWith('sync_github', {
+        token: 'test-token',
+        request: { project_id: 'project-1' },
+      })
+    })
+  })
+})
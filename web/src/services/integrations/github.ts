@@ -0,0 +1,78 @@
+/**
+ * GitHub integration service
+ */
+
+import { invokeAuth } from '../client'
+import type {
+  GitHubConfigStatus,
+  ConfigureGitHubRequest,
+  GitHubProject,
+  AddGitHubProjectRequest,
+  SyncGitHubRequest,
+  SyncGitHubResponse,
+  SearchGitHubProjectsRequest,
+  GitHubRepoInfo,
+} from '@/types'
+
+/**
+ * Get GitHub configuration status
+ */
+export async function getStatus(): Promise<GitHubConfigStatus> {
+  return invokeAuth<GitHubConfigStatus>('get_github_status')
+}
+
+/**
+ * Configure GitHub
+ */
+export async function configure(request: ConfigureGitHubRequest): Promise<{ message: string }> {
+  return invokeAuth<{ message: string }>('configure_github', { request })
+}
+
+/**
+ * Remove GitHub configuration
+ */
+export async function removeConfig(): Promise<{ message: string }> {
+  return invokeAuth<{ message: string }>('remove_github_config')
+}
+
+/**
+ * List user's tracked GitHub repositories
+ */
+export async function listProjects(): Promise<GitHubProject[]> {
+  return invokeAuth<GitHubProject[]>('list_github_projects')
+}
+
+/**
+ * Add a GitHub repository to track
+ */
+export async function addProject(request: AddGitHubProjectRequest): Promise<GitHubProject> {
+  return invokeAuth<GitHubProject>('add_github_project', { request })
+}
+
+/**
+ * Remove a GitHub repository from tracking
+ * @param purgeItems - Also delete work items synced from this repository
+ */
+export async function removeProject(
+  id: string,
+  purgeItems = false
+): Promise<{ message: string; purged_items: number }> {
+  return invokeAuth<{ message: string; purged_items: number }>('remove_github_project', {
+    id,
+    purge_items: purgeItems,
+  })
+}
+
+/**
+ * Sync merged pull requests and commits from GitHub to work items
+ */
+export async function sync(request: SyncGitHubRequest = {}): Promise<SyncGitHubResponse> {
+  return invokeAuth<SyncGitHubResponse>('sync_github', { request })
+}
+
+/**
+ * Search the user's GitHub repositories
+ */
+export async function searchProjects(request: SearchGitHubProjectsRequest = {}): Promise<GitHubRepoInfo[]> {
+  return invokeAuth<GitHubRepoInfo[]>('search_github_projects', { request })
+}
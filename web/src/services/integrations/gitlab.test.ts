@@ -11,6 +11,7 @@ import * as gitlab from './gitlab'
 const mockGitLabStatus = {
   configured: true,
   gitlab_url: 'https://gitlab.example.com',
+  sync_concurrency: 4,
 }
 
 const mockGitLabProject = {
@@ -51,6 +52,21 @@ const mockSyncResponse = {
   synced_commits: 3,
   synced_merge_requests: 2,
   work_items_created: 5,
+  projects: [
+    {
+      project_id: 'project-1',
+      path_with_namespace: 'team/test-project',
+      success: true,
+      synced_commits: 3,
+    },
+    {
+      project_id: 'project-2',
+      path_with_namespace: 'team/another-project',
+      success: false,
+      synced_commits: 0,
+      error: 'GitLab API returned status 404 Not Found',
+    },
+  ],
 }
 
 describe('gitlab service', () => {
@@ -178,6 +194,20 @@ describe('gitlab service', () => {
       expect(mockInvoke).toHaveBeenCalledWith('remove_gitlab_project', {
         token: 'test-token',
         id: 'project-1',
+        purge_items: false,
+      })
+    })
+
+    it('should pass purge_items when purging work items', async () => {
+      mockCommandValue('remove_gitlab_project', { message: 'Project removed', purged_items: 3 })
+
+      const result = await gitlab.removeProject('project-1', true)
+
+      expect(result.purged_items).toBe(3)
+      expect(mockInvoke).toHaveBeenCalledWith('remove_gitlab_project', {
+        token: 'test-token',
+        id: 'project-1',
+        purge_items: true,
       })
     })
 
@@ -196,6 +226,8 @@ describe('gitlab service', () => {
 
       expect(result.work_items_created).toBe(5)
       expect(result.synced_commits).toBe(3)
+      expect(result.projects.map((p) => p.success)).toEqual([true, false])
+      expect(result.projects[1].error).toContain('404')
       expect(mockInvoke).toHaveBeenCalledWith('sync_gitlab', {
         token: 'test-token',
         request: {},
@@ -51,9 +51,16 @@ export async function addProject(request: AddGitLabProjectRequest): Promise<GitL
 
 /**
  * Remove a GitLab project from tracking
+ * @param purgeItems - Also delete work items synced from this project
  */
-export async function removeProject(id: string): Promise<{ message: string }> {
-  return invokeAuth<{ message: string }>('remove_gitlab_project', { id })
+export async function removeProject(
+  id: string,
+  purgeItems = false
+): Promise<{ message: string; purged_items: number }> {
+  return invokeAuth<{ message: string; purged_items: number }>('remove_gitlab_project', {
+    id,
+    purge_items: purgeItems,
+  })
 }
 
 /**
@@ -2,6 +2,7 @@
  * Integrations - re-export all integration services
  */
 
+export * as github from './github'
 export * as gitlab from './gitlab'
 export * as tempo from './tempo'
 export * as claude from './claude'
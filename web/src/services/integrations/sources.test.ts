@@ -100,6 +100,19 @@ describe('sources service', () => {
       expect(mockInvoke).toHaveBeenCalledWith('remove_git_repo', {
         token: 'test-token',
         repo_id: 'repo-1',
+        purge_items: false,
+      })
+    })
+
+    it('should pass purge_items when purging work items', async () => {
+      mockCommandValue('remove_git_repo', { message: 'Repository removed' })
+
+      await sources.removeGitRepo('repo-1', true)
+
+      expect(mockInvoke).toHaveBeenCalledWith('remove_git_repo', {
+        token: 'test-token',
+        repo_id: 'repo-1',
+        purge_items: true,
       })
     })
 
@@ -25,9 +25,10 @@ export async function addGitRepo(path: string): Promise<AddGitRepoResponse> {
 
 /**
  * Remove a local Git repository
+ * @param purgeItems - Also delete work items synced from this repository
  */
-export async function removeGitRepo(repoId: string): Promise<SourceModeResponse> {
-  return invokeAuth<SourceModeResponse>('remove_git_repo', { repo_id: repoId })
+export async function removeGitRepo(repoId: string, purgeItems = false): Promise<SourceModeResponse> {
+  return invokeAuth<SourceModeResponse>('remove_git_repo', { repo_id: repoId, purge_items: purgeItems })
 }
 
 /**
@@ -197,4 +197,25 @@ describe('tempo service', () => {
       expect(result).toHaveLength(0)
     })
   })
+
+  describe('batchGetIssues', () => {
+    it('should return resolved issues and per-key errors', async () => {
+      mockCommandValue('batch_get_jira_issues', {
+        issues: [
+          { key: 'PROJ-1', summary: 'Fix login', issue_type: 'Bug' },
+          { key: 'PROJ-2', summary: 'Add export' },
+        ],
+        errors: { 'GONE-9': "The issue key 'GONE-9' for field 'key' is invalid." },
+      })
+
+      const result = await tempo.batchGetIssues(['PROJ-1', 'PROJ-2', 'GONE-9'])
+
+      expect(result.issues.map((i) => i.key)).toEqual(['PROJ-1', 'PROJ-2'])
+      expect(Object.keys(result.errors)).toEqual(['GONE-9'])
+      expect(mockInvoke).toHaveBeenCalledWith('batch_get_jira_issues', {
+        token: 'test-token',
+        issueKeys: ['PROJ-1', 'PROJ-2', 'GONE-9'],
+      })
+    })
+  })
 })
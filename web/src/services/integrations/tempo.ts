@@ -9,7 +9,7 @@ import type {
   SyncWorklogsResponse,
   GetWorklogsRequest,
   ValidateIssueResponse,
-  JiraIssueDetail,
+  BatchJiraIssuesResponse,
   SearchIssuesRequest,
   SearchIssuesResponse,
 } from '@/types'
@@ -50,10 +50,12 @@ export async function searchIssues(request: SearchIssuesRequest): Promise<Search
 }
 
 /**
- * Batch get full issue details for multiple issue keys
+ * Batch get full issue details for multiple issue keys.
+ * Keys that can't be loaded (deleted, mistyped) are reported in `errors`
+ * instead of failing the batch.
  */
-export async function batchGetIssues(issueKeys: string[]): Promise<JiraIssueDetail[]> {
-  return invokeAuth<JiraIssueDetail[]>('batch_get_jira_issues', { issueKeys })
+export async function batchGetIssues(issueKeys: string[]): Promise<BatchJiraIssuesResponse> {
+  return invokeAuth<BatchJiraIssuesResponse>('batch_get_jira_issues', { issueKeys })
 }
 
 /**
@@ -9,6 +9,9 @@
  * Read by:
  *   - JiraBadge — reads on mount to show title inline without hover-fetch
  *   - Tooltip   — reads cached details for hover popup
+ *
+ * Keys the batch couldn't load are remembered with their error (same TTL)
+ * so the UI can flag stale mappings instead of re-fetching them.
  */
 
 import { tempo } from '@/services'
@@ -29,6 +32,13 @@ interface CacheEntry {
 
 const cache = new Map<string, CacheEntry>()
 
+interface ErrorEntry {
+  error: string
+  expiresAt: number
+}
+
+const errors = new Map<string, ErrorEntry>()
+
 /** Set of keys currently being fetched (dedup in-flight requests) */
 let prefetchingKeys = new Set<string>()
 
@@ -48,6 +58,18 @@ export function get(key: string): CachedIssueDetail | undefined {
 
 export function set(key: string, detail: CachedIssueDetail): void {
   cache.set(key, { detail, expiresAt: Date.now() + TTL_MS })
+  errors.delete(key)
+}
+
+/** Why the last batch load of `key` failed, if it did */
+export function getError(key: string): string | undefined {
+  const entry = errors.get(key)
+  if (!entry) return undefined
+  if (Date.now() >= entry.expiresAt) {
+    errors.delete(key)
+    return undefined
+  }
+  return entry.error
 }
 
 export function has(key: string): boolean {
@@ -62,19 +84,22 @@ export function has(key: string): boolean {
 
 /**
  * Batch-prefetch issue details for the given keys.
- * Skips keys already cached (and not expired) or currently in-flight.
+ * Skips keys already cached or known bad (and not expired), or currently in-flight.
  * Safe to call multiple times — deduplicates automatically.
  */
 export async function prefetch(keys: string[]): Promise<void> {
-  const missing = keys.filter((k) => k && !has(k) && !prefetchingKeys.has(k))
+  const missing = keys.filter((k) => k && !has(k) && !getError(k) && !prefetchingKeys.has(k))
   if (missing.length === 0) return
 
   for (const k of missing) prefetchingKeys.add(k)
 
   try {
-    const details = await tempo.batchGetIssues(missing)
+    const { issues, errors: failed } = await tempo.batchGetIssues(missing)
     const now = Date.now()
-    for (const d of details) {
+    for (const [key, error] of Object.entries(failed)) {
+      errors.set(key, { error, expiresAt: now + TTL_MS })
+    }
+    for (const d of issues) {
       cache.set(d.key, {
         detail: {
           summary: d.summary,
@@ -103,9 +128,9 @@ export function subscribe(listener: Listener): () => void {
 
 /** Prefetch and notify listeners when done */
 export async function prefetchAndNotify(keys: string[]): Promise<void> {
-  const sizeBefore = cache.size
+  const sizeBefore = cache.size + errors.size
   await prefetch(keys)
-  if (cache.size !== sizeBefore) {
+  if (cache.size + errors.size !== sizeBefore) {
     for (const fn of listeners) fn()
   }
 }
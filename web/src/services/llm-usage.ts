@@ -5,7 +5,7 @@
  */
 
 import { invokeAuth } from './client'
-import type { LlmUsageStats, DailyUsage, ModelUsage, LlmUsageLog } from '@/types'
+import type { LlmUsageStats, DailyUsage, ModelUsage, LlmUsageLogPage, LlmUsageLogQuery } from '@/types'
 
 export async function getUsageStats(startDate: string, endDate: string): Promise<LlmUsageStats> {
   return invokeAuth<LlmUsageStats>('get_llm_usage_stats', {
@@ -28,16 +28,6 @@ export async function getUsageByModel(startDate: string, endDate: string): Promi
   })
 }
 
-export async function getUsageLogs(
-  startDate: string,
-  endDate: string,
-  limit?: number,
-  offset?: number,
-): Promise<LlmUsageLog[]> {
-  return invokeAuth<LlmUsageLog[]>('get_llm_usage_logs', {
-    start_date: startDate,
-    end_date: endDate,
-    limit: limit ?? 50,
-    offset: offset ?? 0,
-  })
+export async function getUsageLogs(query: LlmUsageLogQuery = {}): Promise<LlmUsageLogPage> {
+  return invokeAuth<LlmUsageLogPage>('get_llm_usage_logs', { query })
 }
@@ -8,6 +8,7 @@ import type {
   ProjectDetail,
   ProjectDirectories,
   SetProjectVisibilityRequest,
+  SetProjectsVisibilityRequest,
   AddManualProjectRequest,
   ClaudeSessionPathResponse,
   ProjectDescription,
@@ -40,6 +41,14 @@ export async function setProjectVisibility(projectName: string, hidden: boolean)
   return invokeAuth<string>('set_project_visibility', { request })
 }
 
+/**
+ * Set visibility for several projects at once; returns how many changed
+ */
+export async function setProjectsVisibility(projectNames: string[], hidden: boolean): Promise<number> {
+  const request: SetProjectsVisibilityRequest = { project_names: projectNames, hidden }
+  return invokeAuth<number>('set_projects_visibility', { request })
+}
+
 /**
  * Get list of hidden project names
  */
@@ -49,9 +58,15 @@ export async function getHiddenProjects(): Promise<string[]> {
 
 /**
  * Get project directories (Claude Code session dir + Git repo path)
+ *
+ * Scans are cached briefly by the backend; pass `refresh` to rescan.
+ * `include`/`exclude` are globs on the directory name.
  */
-export async function getProjectDirectories(projectName: string): Promise<ProjectDirectories> {
-  return invokeAuth<ProjectDirectories>('get_project_directories', { projectName })
+export async function getProjectDirectories(
+  projectName: string,
+  options: { include?: string[]; exclude?: string[]; refresh?: boolean } = {},
+): Promise<ProjectDirectories> {
+  return invokeAuth<ProjectDirectories>('get_project_directories', { projectName, ...options })
 }
 
 /**
@@ -9,6 +9,7 @@ import type {
   SummaryReport,
   CategoryReport,
   ExportResult,
+  NotionExportResult,
   TempoReportQuery,
   TempoReport,
   AnalyzeResponse,
@@ -66,6 +67,20 @@ export async function exportExcel(query: ReportQuery): Promise<ExportResult> {
   return invokeAuth<ExportResult>('export_excel_report', { query })
 }
 
+/**
+ * Export per-project hours to a PDF file
+ */
+export async function exportPdf(query: ReportQuery): Promise<ExportResult> {
+  return invokeAuth<ExportResult>('export_pdf_report', { query })
+}
+
+/**
+ * Export per-project totals to the configured Notion database
+ */
+export async function exportNotion(query: ReportQuery): Promise<NotionExportResult> {
+  return invokeAuth<NotionExportResult>('export_notion_report', { query })
+}
+
 /**
  * Generate smart Tempo report with LLM summaries
  */
@@ -121,6 +121,20 @@ describe('work-items service', () => {
     })
   })
 
+  describe('restore', () => {
+    it('should restore a trashed work item', async () => {
+      mockCommandValue('restore_work_item', mockWorkItem)
+
+      const result = await workItems.restore('work-item-1')
+
+      expect(mockInvoke).toHaveBeenCalledWith('restore_work_item', {
+        token: 'test-token',
+        id: 'work-item-1',
+      })
+      expect(result).toEqual(mockWorkItem)
+    })
+  })
+
   describe('mapToJira', () => {
     it('should map work item to Jira issue', async () => {
       const mappedItem = { ...mockWorkItem, jira_issue_key: 'PROJ-456' }
@@ -186,4 +200,22 @@ describe('work-items service', () => {
       })
     })
   })
+
+  describe('exportTimelineIcs', () => {
+    it('should export the timeline of a date as a calendar file', async () => {
+      const mockResult = {
+        success: true,
+        file_path: '/Users/dev/Downloads/recap_timeline_20240115.ics',
+      }
+      mockCommandValue('export_timeline_ics', mockResult)
+
+      const result = await workItems.exportTimelineIcs('2024-01-15', ['claude_code'])
+
+      expect(result.file_path).toBe('/Users/dev/Downloads/recap_timeline_20240115.ics')
+      expect(mockInvoke).toHaveBeenCalledWith('export_timeline_ics', {
+        token: 'test-token',
+        query: { date: '2024-01-15', sources: ['claude_code'] },
+      })
+    })
+  })
 })
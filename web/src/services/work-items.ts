@@ -18,6 +18,7 @@ import type {
   AggregateRequest,
   AggregateResponse,
   CommitCentricWorklogResponse,
+  ExportResult,
 } from '@/types'
 
 // ============ CRUD Operations ============
@@ -51,12 +52,19 @@ export async function update(id: string, request: UpdateWorkItemRequest): Promis
 }
 
 /**
- * Delete a work item
+ * Move a work item to the trash
  */
 export async function remove(id: string): Promise<void> {
   return invokeAuth<void>('delete_work_item', { id })
 }
 
+/**
+ * Take a work item out of the trash
+ */
+export async function restore(id: string): Promise<WorkItem> {
+  return invokeAuth<WorkItem>('restore_work_item', { id })
+}
+
 // ============ Stats & Views ============
 
 /**
@@ -82,6 +90,15 @@ export async function getTimeline(date: string, sources?: string[]): Promise<Tim
   return invokeAuth<TimelineResponse>('get_timeline_data', { query: { date, sources } })
 }
 
+/**
+ * Export the timeline sessions of a date as an .ics calendar file in Downloads
+ * @param date - The date in YYYY-MM-DD format
+ * @param sources - Optional array of sources to filter by (e.g., ['claude_code'])
+ */
+export async function exportTimelineIcs(date: string, sources?: string[]): Promise<ExportResult> {
+  return invokeAuth<ExportResult>('export_timeline_ics', { query: { date, sources } })
+}
+
 // ============ Jira Mapping ============
 
 /**
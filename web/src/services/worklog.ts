@@ -3,7 +3,7 @@
  */
 
 import { invokeAuth } from './client'
-import type { WorklogOverviewResponse, HourlyBreakdownItem } from '@/types/worklog'
+import type { WorklogOverviewResponse, HourlyBreakdownItem, HourlyGapFill, BreakdownBucketMinutes } from '@/types/worklog'
 
 /** Response from compaction operations */
 export interface CompactionResult {
@@ -54,11 +54,21 @@ export async function getOverview(startDate: string, endDate: string): Promise<W
 
 /**
  * Get hourly breakdown for a specific day and project
+ * @param fillGaps - Include idle hours as empty buckets: every hour of the day ('full')
+ *   or only between the first and last active hour ('active_range')
+ * @param bucketMinutes - Bucket length in minutes (default 60)
  */
-export async function getHourlyBreakdown(date: string, projectPath: string): Promise<HourlyBreakdownItem[]> {
+export async function getHourlyBreakdown(
+  date: string,
+  projectPath: string,
+  fillGaps?: HourlyGapFill,
+  bucketMinutes?: BreakdownBucketMinutes
+): Promise<HourlyBreakdownItem[]> {
   return invokeAuth<HourlyBreakdownItem[]>('get_hourly_breakdown', {
     date,
     project_path: projectPath,
+    ...(fillGaps ? { fill_gaps: fillGaps } : {}),
+    ...(bucketMinutes ? { bucket_minutes: bucketMinutes } : {}),
   })
 }
 
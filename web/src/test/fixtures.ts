@@ -37,6 +37,7 @@ export const mockTokenResponse: TokenResponse = {
   access_token: 'mock-jwt-token-12345',
   token_type: 'bearer',
   expires_in: 86400,
+  refresh_token: 'mock-refresh-token-12345',
 }
 
 // Work Items fixtures
@@ -28,6 +28,7 @@ export interface TokenResponse {
   access_token: string
   token_type: string
   expires_in: number
+  refresh_token: string
 }
 
 export interface RegisterRequest {
@@ -43,6 +43,7 @@ export type {
   TempoSyncStats,
   WorkItemStatsResponse,
   TimelineCommit,
+  ActiveBlock,
   TimelineSession,
   TimelineResponse,
   BatchSyncRequest,
@@ -63,6 +64,8 @@ export type {
   CategorySummary,
   CategoryReport,
   ExportResult,
+  NotionRowResult,
+  NotionExportResult,
   TempoReportPeriod,
   TempoReportQuery,
   TempoProjectSummary,
@@ -84,6 +87,7 @@ export type {
   AutoSyncRequest,
   SyncResult,
   AutoSyncResponse,
+  ProjectSyncResult,
   AvailableProject,
 } from './sync'
 
@@ -94,6 +98,15 @@ export type {
   SourcesResponse,
   AddGitRepoResponse,
   SourceModeResponse,
+  // GitHub
+  GitHubConfigStatus,
+  ConfigureGitHubRequest,
+  GitHubProject,
+  AddGitHubProjectRequest,
+  SyncGitHubRequest,
+  SyncGitHubResponse,
+  SearchGitHubProjectsRequest,
+  GitHubRepoInfo,
   // GitLab
   GitLabConfigStatus,
   ConfigureGitLabRequest,
@@ -101,6 +114,7 @@ export type {
   AddGitLabProjectRequest,
   SyncGitLabRequest,
   SyncGitLabResponse,
+  GitLabProjectSyncResult,
   SearchGitLabProjectsRequest,
   GitLabProjectInfo,
   // Tempo
@@ -111,6 +125,7 @@ export type {
   ValidateIssueResponse,
   JiraIssueItem,
   JiraIssueDetail,
+  BatchJiraIssuesResponse,
   SearchIssuesRequest,
   SearchIssuesResponse,
   // Claude
@@ -136,6 +151,8 @@ export type {
 export type {
   GitCommitRef,
   HourlyBreakdownItem,
+  HourlyGapFill,
+  BreakdownBucketMinutes,
   ManualWorkItem,
   WorklogDayProject,
   WorklogDay,
@@ -172,6 +189,8 @@ export type {
   DailyUsage,
   ModelUsage,
   LlmUsageLog,
+  LlmUsageLogQuery,
+  LlmUsageLogPage,
 } from './llm-usage'
 
 // Project types
@@ -182,6 +201,7 @@ export type {
   ProjectStats,
   ProjectDetail,
   SetProjectVisibilityRequest,
+  SetProjectsVisibilityRequest,
   ClaudeCodeDirEntry,
   ProjectDirectories,
   AddManualProjectRequest,
@@ -202,6 +222,8 @@ export type {
   // Git diff types
   CommitFileChange,
   CommitStats,
+  DiffHunk,
+  FileDiff,
   CommitDiffResponse,
   GetCommitDiffRequest,
 } from './projects'
@@ -1,5 +1,5 @@
 /**
- * Integration types for GitLab, Tempo, Claude, Sources
+ * Integration types for GitHub, GitLab, Tempo, Claude, Sources
  */
 
 // ============ Sources ============
@@ -31,16 +31,72 @@ export interface SourceModeResponse {
   message: string
 }
 
+// ============ GitHub ============
+
+export interface GitHubConfigStatus {
+  configured: boolean
+  github_url?: string
+}
+
+export interface ConfigureGitHubRequest {
+  /** API base for GitHub Enterprise; defaults to https://api.github.com */
+  github_url?: string
+  github_pat: string
+}
+
+export interface GitHubProject {
+  id: string
+  user_id: string
+  github_repo_id: number
+  name: string
+  full_name: string
+  html_url: string
+  default_branch: string
+  enabled: boolean
+  last_synced?: string
+  created_at: string
+}
+
+export interface AddGitHubProjectRequest {
+  /** Repository as `owner/name` */
+  full_name: string
+}
+
+export interface SyncGitHubRequest {
+  project_id?: string
+}
+
+export interface SyncGitHubResponse {
+  synced_commits: number
+  synced_pull_requests: number
+  work_items_created: number
+}
+
+export interface SearchGitHubProjectsRequest {
+  search?: string
+}
+
+export interface GitHubRepoInfo {
+  id: number
+  name: string
+  full_name: string
+  html_url: string
+  default_branch?: string
+}
+
 // ============ GitLab ============
 
 export interface GitLabConfigStatus {
   configured: boolean
   gitlab_url?: string
+  sync_concurrency: number
 }
 
 export interface ConfigureGitLabRequest {
   gitlab_url: string
   gitlab_pat: string
+  /** Projects synced at once (1-16); unchanged when omitted */
+  sync_concurrency?: number
 }
 
 export interface GitLabProject {
@@ -70,10 +126,19 @@ export interface SyncGitLabRequest {
   end_date?: string
 }
 
+export interface GitLabProjectSyncResult {
+  project_id: string
+  path_with_namespace: string
+  success: boolean
+  synced_commits: number
+  error?: string
+}
+
 export interface SyncGitLabResponse {
   synced_commits: number
   synced_merge_requests: number
   work_items_created: number
+  projects: GitLabProjectSyncResult[]
 }
 
 export interface SearchGitLabProjectsRequest {
@@ -156,6 +221,12 @@ export interface JiraIssueDetail {
   issue_type?: string
 }
 
+/** Resolved issues plus an error message for each key that couldn't be loaded */
+export interface BatchJiraIssuesResponse {
+  issues: JiraIssueDetail[]
+  errors: Record<string, string>
+}
+
 export interface SearchIssuesRequest {
   query: string
   max_results?: number
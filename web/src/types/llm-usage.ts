@@ -2,6 +2,8 @@
  * LLM Usage types
  */
 
+import type { PaginatedResponse } from './work-items'
+
 export interface LlmUsageStats {
   total_calls: number
   success_calls: number
@@ -45,3 +47,19 @@ export interface LlmUsageLog {
   error_message: string | null
   created_at: string
 }
+
+/** Filters and page for `get_llm_usage_logs`; unset filters match everything */
+export interface LlmUsageLogQuery {
+  start_date?: string
+  end_date?: string
+  provider?: string
+  purpose?: string
+  page?: number
+  /** Capped at 100 */
+  per_page?: number
+}
+
+export interface LlmUsageLogPage extends PaginatedResponse<LlmUsageLog> {
+  /** Estimated cost of every log matching the filters, not only this page */
+  total_cost: number
+}
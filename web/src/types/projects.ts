@@ -51,6 +51,11 @@ export interface SetProjectVisibilityRequest {
   hidden: boolean
 }
 
+export interface SetProjectsVisibilityRequest {
+  project_names: string[]
+  hidden: boolean
+}
+
 export interface ClaudeCodeDirEntry {
   path: string
   session_count: number
@@ -180,13 +185,29 @@ export interface CommitStats {
   deletions: number
 }
 
+export interface DiffHunk {
+  header: string // "@@ -a,b +c,d @@" line
+  old_start: number
+  old_lines: number
+  new_start: number
+  new_lines: number
+  lines: string[] // each prefixed with ' ', '+' or '-'
+}
+
+export interface FileDiff {
+  path: string
+  language: string | null // detected from extension, e.g. "rust"
+  hunks: DiffHunk[]
+}
+
 export interface CommitDiffResponse {
   hash: string
   message: string
   author: string
   date: string
   files: CommitFileChange[]
-  diff_text: string | null // null if repo not available locally
+  diffs: FileDiff[] // empty if repo not available locally
+  truncated: boolean
   stats: CommitStats
 }
 
@@ -57,6 +57,20 @@ export interface ExportResult {
   error?: string
 }
 
+export interface NotionRowResult {
+  project_name: string
+  status: 'created' | 'updated' | 'error'
+  page_id?: string
+  error_message?: string
+}
+
+export interface NotionExportResult {
+  created: number
+  updated: number
+  failed: number
+  rows: NotionRowResult[]
+}
+
 // Tempo Report types
 
 export type TempoReportPeriod = 'daily' | 'weekly' | 'monthly' | 'quarterly' | 'semi_annual'
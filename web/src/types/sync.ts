@@ -25,12 +25,21 @@ export interface SyncResult {
   message?: string
 }
 
+export interface ProjectSyncResult {
+  project: string
+  project_path: string
+  created: number
+  updated: number
+  skipped: number
+}
+
 export interface AutoSyncResponse {
   success: boolean
   results: SyncResult[]
   total_items: number
   projects_scanned: number
   items_created: number
+  per_project: ProjectSyncResult[]
 }
 
 export interface AvailableProject {
@@ -2,6 +2,8 @@
  * Work Items related types
  */
 
+import type { WorklogEntryRequest, WorklogEntryResponse } from './integrations'
+
 export interface WorkItem {
   id: string
   user_id: string
@@ -44,6 +46,7 @@ export interface WorkItemFilters {
   page?: number
   per_page?: number
   source?: string
+  exclude_source?: string[]
   category?: string
   jira_mapped?: boolean
   synced_to_tempo?: boolean
@@ -52,6 +55,8 @@ export interface WorkItemFilters {
   search?: string
   parent_id?: string
   show_all?: boolean
+  synced_after?: string
+  synced_before?: string
 }
 
 export interface CreateWorkItemRequest {
@@ -160,6 +165,11 @@ export interface TimelineCommit {
   author: string
 }
 
+export interface ActiveBlock {
+  start: string
+  end: string
+}
+
 export interface TimelineSession {
   id: string
   project: string
@@ -168,6 +178,8 @@ export interface TimelineSession {
   end_time: string
   hours: number
   commits: TimelineCommit[]
+  /** Working intervals between idle gaps */
+  active_blocks: ActiveBlock[]
 }
 
 export interface TimelineResponse {
@@ -181,12 +193,19 @@ export interface TimelineResponse {
 
 export interface BatchSyncRequest {
   work_item_ids: string[]
+  /** Build the worklogs without posting them or marking items synced */
+  dry_run?: boolean
 }
 
 export interface BatchSyncResponse {
   synced: number
   failed: number
   errors: string[]
+  dry_run: boolean
+  /** Worklogs that would be posted (dry run only) */
+  preview: WorklogEntryRequest[]
+  /** Per-item outcome: every item on a real run, rejected items on a dry run */
+  results: WorklogEntryResponse[]
 }
 
 export interface AggregateRequest {
@@ -14,10 +14,16 @@ export interface HourlyBreakdownItem {
   summary: string
   files_modified: string[]
   git_commits: GitCommitRef[]
-  /** Data source: "claude_code" */
+  /** Data source: "claude_code", or "idle" for gap-filled hours */
   source: string
 }
 
+/** Gap filling mode for hourly breakdowns */
+export type HourlyGapFill = 'full' | 'active_range'
+
+/** Bucket lengths accepted by `get_hourly_breakdown` */
+export type BreakdownBucketMinutes = 15 | 30 | 60
+
 export interface ManualWorkItem {
   id: string
   title: string